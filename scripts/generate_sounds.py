@@ -6,6 +6,7 @@ This script generates the notification sounds used by Vocalinux for:
 - Start recording (ascending pitch glide)
 - Stop recording (descending pitch glide)
 - Error notification (lower descending glide)
+- Utterance typed (short, quiet upward blip; optional)
 
 The sounds use smooth pitch glides with pure sine waves and soft envelopes
 to create pleasant, headphone-friendly audio feedback.
@@ -121,6 +122,16 @@ def main():
         amplitude=0.14,  # Slightly quieter
     )
 
+    # UTTERANCE: Short, quiet A4→C5 blip (played while still dictating)
+    print("Generating utterance.wav (short A4→C5)...")
+    generate_glide_tone(
+        os.path.join(sounds_dir, "utterance.wav"),
+        freq_start=440.00,  # A4
+        freq_end=523.25,    # C5
+        duration=0.15,
+        amplitude=0.08,  # Half as loud; it plays after every phrase
+    )
+
     print("\n✓ All sounds generated successfully!")
     print("\nSound characteristics:")
     print("- Pure sine waves (headphone-friendly)")
@@ -0,0 +1,143 @@
+"""
+"Dictation active" indicator for Vocalinux.
+
+Desktops show their own microphone-in-use indicator for any app recording
+through PipeWire, but it doesn't say which app is listening. While Vocalinux
+runs it owns com.vocalinux.Vocalinux on the session bus and exports a
+Capturing property, true while it is recording, on /com/vocalinux/Vocalinux
+(interface com.vocalinux.Vocalinux.Capture). PropertiesChanged is emitted on
+every change, so shell extensions and bar scripts can show their own
+indicator without polling::
+
+    gdbus call --session --dest com.vocalinux.Vocalinux \\
+        --object-path /com/vocalinux/Vocalinux \\
+        --method org.freedesktop.DBus.Properties.Get \\
+        com.vocalinux.Vocalinux.Capture Capturing
+"""
+
+import logging
+from typing import Callable, Optional
+
+from gi.repository import Gio, GLib
+
+from .common_types import RecognitionState
+
+logger = logging.getLogger(__name__)
+
+BUS_NAME = "com.vocalinux.Vocalinux"
+OBJECT_PATH = "/com/vocalinux/Vocalinux"
+INTERFACE = "com.vocalinux.Vocalinux.Capture"
+
+_PROPERTIES_INTERFACE = "org.freedesktop.DBus.Properties"
+
+_INTROSPECTION_XML = f"""
+<node>
+  <interface name="{INTERFACE}">
+    <property name="Capturing" type="b" access="read"/>
+  </interface>
+</node>
+"""
+
+# The microphone is recorded while listening and while earlier speech is transcribed
+CAPTURING_STATES = (RecognitionState.LISTENING, RecognitionState.PROCESSING)
+
+
+class CaptureIndicator:
+    """Publishes on D-Bus whether Vocalinux is recording.
+
+    Feed it the recognition state with on_state_changed(); it can be called
+    from any thread. on_capturing_changed runs on the GLib main loop thread
+    whenever recording starts or stops, e.g. to show an on-screen indicator.
+    """
+
+    def __init__(self, on_capturing_changed: Optional[Callable[[bool], None]] = None):
+        self._on_capturing_changed = on_capturing_changed
+        self.capturing = False
+        self._connection: Optional[Gio.DBusConnection] = None
+        self._registration_id: Optional[int] = None
+        self._owner_id: Optional[int] = None
+        self._connect()
+
+    def _connect(self) -> None:
+        """Own the bus name; the object is exported once the bus is acquired."""
+        try:
+            self._owner_id = Gio.bus_own_name(
+                Gio.BusType.SESSION,
+                BUS_NAME,
+                Gio.BusNameOwnerFlags.NONE,
+                self._on_bus_acquired,
+                None,
+                self._on_name_lost,
+            )
+        except Exception as exc:
+            logger.warning("Could not publish the capture indicator on D-Bus: %s", exc)
+            self._owner_id = None
+
+    def _on_bus_acquired(self, connection: Gio.DBusConnection, name: str) -> None:
+        try:
+            node_info = Gio.DBusNodeInfo.new_for_xml(_INTROSPECTION_XML)
+            self._registration_id = connection.register_object(
+                OBJECT_PATH, node_info.interfaces[0], None, self._get_property, None
+            )
+            self._connection = connection
+            logger.info("Capture indicator published on D-Bus as %s", BUS_NAME)
+        except Exception as exc:
+            logger.warning("Could not export the capture indicator on D-Bus: %s", exc)
+
+    def _on_name_lost(self, connection: Optional[Gio.DBusConnection], name: str) -> None:
+        # Another instance owns the name, or there is no session bus
+        logger.debug("D-Bus name %s not owned; capture indicator not published", name)
+
+    def _get_property(
+        self,
+        connection: Gio.DBusConnection,
+        sender: str,
+        object_path: str,
+        interface_name: str,
+        property_name: str,
+    ) -> Optional[GLib.Variant]:
+        if property_name == "Capturing":
+            return GLib.Variant("b", self.capturing)
+        return None
+
+    def on_state_changed(self, state: RecognitionState) -> None:
+        """State callback for the recognition manager."""
+        GLib.idle_add(self._set_capturing, state in CAPTURING_STATES)
+
+    def _set_capturing(self, capturing: bool) -> bool:
+        if capturing == self.capturing:
+            return False
+        self.capturing = capturing
+        if self._connection is not None:
+            try:
+                self._connection.emit_signal(
+                    None,
+                    OBJECT_PATH,
+                    _PROPERTIES_INTERFACE,
+                    "PropertiesChanged",
+                    GLib.Variant(
+                        "(sa{sv}as)",
+                        (INTERFACE, {"Capturing": GLib.Variant("b", capturing)}, []),
+                    ),
+                )
+            except Exception as exc:
+                logger.debug("Could not emit PropertiesChanged: %s", exc)
+        if self._on_capturing_changed:
+            try:
+                self._on_capturing_changed(capturing)
+            except Exception:
+                logger.error("Error in capture indicator callback", exc_info=True)
+        return False
+
+    def shutdown(self) -> None:
+        """Unexport the object and release the bus name, e.g. on quit."""
+        if self._connection is not None and self._registration_id is not None:
+            try:
+                self._connection.unregister_object(self._registration_id)
+            except Exception:
+                pass
+        self._connection = None
+        self._registration_id = None
+        if self._owner_id is not None:
+            Gio.bus_unown_name(self._owner_id)
+            self._owner_id = None
@@ -29,14 +29,38 @@ class SpeechRecognitionManagerProtocol(Protocol):
         """Stop the speech recognition process."""
         ...
 
+    def cancel_utterance(self) -> bool:
+        """Discard the utterance being captured without transcribing it."""
+        ...
+
+    def panic_stop(self) -> bool:
+        """Stop immediately, discarding all captured audio and queued work."""
+        ...
+
     def register_state_callback(self, callback: Callable[[RecognitionState], None]) -> None:
         """Register a callback for state changes."""
         ...
 
+    def unregister_state_callback(self, callback: Callable[[RecognitionState], None]) -> None:
+        """Unregister a callback for state changes."""
+        ...
+
     def register_text_callback(self, callback: Callable[[str], None]) -> None:
         """Register a callback for recognized text."""
         ...
 
+    def get_performance_summary(self) -> str:
+        """Describe decoding speed and queued utterances."""
+        ...
+
+    def can_retry(self, text: str) -> bool:
+        """Whether a recent transcript's audio is kept so it can be retried."""
+        ...
+
+    def retry_utterance(self, text: str) -> Optional[str]:
+        """Decode a recent utterance again with a larger model."""
+        ...
+
 
 class TextInjectorProtocol(Protocol):
     """Protocol defining the interface for TextInjector."""
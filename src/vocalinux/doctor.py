@@ -0,0 +1,252 @@
+"""
+Health checks for Vocalinux.
+
+Implements ``vocalinux doctor``, which reports features that are degraded in
+the current session (invalid settings, missing injection tools, no keyring,
+no GPU, microphone problems). The same checks drive the "running with limitations" notification
+shown at startup.
+"""
+
+import argparse
+import importlib.util
+import logging
+import os
+import shutil
+import sys
+from dataclasses import dataclass
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass(frozen=True)
+class HealthCheck:
+    """Result of a single health check."""
+
+    name: str
+    ok: bool
+    message: str
+    hint: str = ""
+
+
+def _is_wayland_session() -> bool:
+    session_type = os.environ.get("XDG_SESSION_TYPE", "").lower()
+    return session_type == "wayland" or bool(os.environ.get("WAYLAND_DISPLAY"))
+
+
+def check_text_injection() -> HealthCheck:
+    """Check for a tool that can type text and send editing shortcuts."""
+    name = "Text injection"
+    if _is_wayland_session():
+        tools = [tool for tool in ("ydotool", "wtype", "xdotool") if shutil.which(tool)]
+        hint = "Install ydotool (all compositors) or wtype: sudo apt install ydotool"
+    else:
+        tools = [tool for tool in ("xdotool",) if shutil.which(tool)]
+        hint = "Install xdotool: sudo apt install xdotool"
+
+    if tools:
+        return HealthCheck(name, True, f"Using {', '.join(tools)}")
+    return HealthCheck(
+        name,
+        False,
+        "No typing tool found; only IBus or the clipboard can insert text and "
+        "editing commands like 'select all' won't work",
+        hint,
+    )
+
+
+def check_keyring(settings: dict) -> HealthCheck:
+    """
+    Check where the remote API key of the configured account is stored.
+
+    Args:
+        settings: The speech_recognition config section
+    """
+    from .utils.credentials import keyring_available, resolve_remote_api_key
+
+    name = "Keyring"
+    if not resolve_remote_api_key(settings):
+        return HealthCheck(name, True, "Not needed (no API key configured)")
+    if not settings.get("remote_api_key"):
+        return HealthCheck(name, True, "The remote API key is stored in the keyring")
+    if not keyring_available():
+        return HealthCheck(
+            name,
+            False,
+            "No keyring; the remote API key is stored unencrypted in the config file",
+            "Install the keyring package: pip install keyring",
+        )
+    return HealthCheck(
+        name,
+        False,
+        "The remote API key is stored unencrypted in the config file",
+        "Enter the key again in Settings → Speech Engine to move it into the keyring",
+    )
+
+
+def check_gpu(engine: str) -> HealthCheck:
+    """Check whether the configured engine can use GPU acceleration."""
+    name = "GPU acceleration"
+    if engine == "whisper_cpp":
+        from .speech_recognition.recognition_manager import (
+            _find_pywhispercpp_shared_library_dirs,
+        )
+
+        for library_dir in _find_pywhispercpp_shared_library_dirs():
+            for backend in ("vulkan", "cuda"):
+                if any(
+                    entry.startswith(f"libggml-{backend}") for entry in os.listdir(library_dir)
+                ):
+                    return HealthCheck(name, True, f"whisper.cpp built with {backend.upper()}")
+        return HealthCheck(
+            name,
+            False,
+            "whisper.cpp is running on the CPU; larger models will be slow",
+            "Install Vulkan drivers, then rebuild: ./install.sh --rebuild-whispercpp",
+        )
+
+    if engine == "whisper":
+        if importlib.util.find_spec("torch") is None:
+            return HealthCheck(name, False, "PyTorch is not installed")
+        import torch
+
+        if torch.cuda.is_available():
+            return HealthCheck(name, True, "CUDA is available to Whisper")
+        return HealthCheck(
+            name,
+            False,
+            "Whisper is running on the CPU; larger models will be slow",
+            "Install a CUDA-enabled PyTorch build, or switch to the whisper.cpp engine",
+        )
+
+    return HealthCheck(name, True, f"Not used by the {engine} engine")
+
+
+def check_microphone(device_index: Optional[int] = None, speech_engine=None) -> HealthCheck:
+    """
+    Check that the microphone accepts a sample rate Vocalinux can capture at.
+
+    Args:
+        device_index: Configured input device (None for the system default)
+        speech_engine: The running recognition manager; once it has opened the
+            device, its negotiated format is reported instead of probing again
+    """
+    name = "Microphone"
+    capture_format = getattr(speech_engine, "capture_format", None)
+    if capture_format is not None:
+        return HealthCheck(name, True, f"Opened at {capture_format.describe()}")
+
+    from .speech_recognition.recognition_manager import get_input_device_capabilities
+
+    devices = get_input_device_capabilities()
+    if device_index is None:
+        device = next((device for device in devices if device["is_default"]), None)
+    else:
+        device = next((device for device in devices if device["index"] == device_index), None)
+    if device is None:
+        return HealthCheck(
+            name,
+            False,
+            "No usable input device found",
+            "Connect a microphone or check your audio settings",
+        )
+    if not device["rates"]:
+        return HealthCheck(
+            name,
+            False,
+            f"'{device['name']}' doesn't accept any supported sample rate; recording may fail",
+            "Pick another input device in Settings → Audio",
+        )
+    return HealthCheck(name, True, f"'{device['name']}' at {device['rates'][0]} Hz")
+
+
+def check_config(problems: list) -> HealthCheck:
+    """Check the config file's settings were all valid."""
+    name = "Settings"
+    if not problems:
+        return HealthCheck(name, True, "Config file is valid")
+    return HealthCheck(
+        name,
+        False,
+        f"{len(problems)} invalid setting(s) in the config file, defaults used instead: "
+        + "; ".join(str(problem) for problem in problems),
+        "Correct them in ~/.config/vocalinux/config.toml, or change them in Settings",
+    )
+
+
+def _safe_check(name: str, check, *args) -> HealthCheck:
+    try:
+        return check(*args)
+    except Exception as e:
+        logger.debug(f"Health check '{name}' failed: {e}")
+        return HealthCheck(name, True, f"Could not check ({e})")
+
+
+def run_health_checks(config_manager=None, speech_engine=None) -> list[HealthCheck]:
+    """
+    Run every health check against the saved configuration.
+
+    Args:
+        config_manager: ConfigManager to read settings from (loaded if None)
+        speech_engine: The running recognition manager, if called from the app
+
+    Returns:
+        One HealthCheck per feature, in display order
+    """
+    if config_manager is None:
+        from .ui.config_manager import ConfigManager
+
+        config_manager = ConfigManager()
+
+    engine = config_manager.get("speech_recognition", "engine", "whisper_cpp")
+    recognition_settings = config_manager.get_settings().get("speech_recognition", {})
+    device_index = config_manager.get("audio", "device_index", None)
+
+    return [
+        _safe_check("Settings", check_config, list(config_manager.config_problems)),
+        _safe_check("Text injection", check_text_injection),
+        _safe_check("Keyring", check_keyring, recognition_settings),
+        _safe_check("GPU acceleration", check_gpu, engine),
+        _safe_check("Microphone", check_microphone, device_index, speech_engine),
+    ]
+
+
+def get_limitations(results: list[HealthCheck]) -> list[HealthCheck]:
+    """Return the checks that failed."""
+    return [result for result in results if not result.ok]
+
+
+def format_report(results: list[HealthCheck]) -> str:
+    """Format check results as a human-readable report."""
+    lines = ["Vocalinux health report", ""]
+    for result in results:
+        status = "OK " if result.ok else "!! "
+        lines.append(f"{status} {result.name}: {result.message}")
+        if result.hint and not result.ok:
+            lines.append(f"    Fix: {result.hint}")
+
+    limitations = get_limitations(results)
+    lines.append("")
+    if limitations:
+        lines.append(f"{len(limitations)} feature(s) running with limitations.")
+    else:
+        lines.append("Everything looks good.")
+    return "\n".join(lines)
+
+
+def main(argv: Optional[list[str]] = None) -> int:
+    """
+    Entry point for ``vocalinux doctor``.
+
+    Returns:
+        0 if every check passed, 1 if some features are degraded
+    """
+    argparse.ArgumentParser(
+        prog="vocalinux doctor",
+        description="Report Vocalinux features that are degraded in this session",
+    ).parse_args(argv)
+
+    logging.basicConfig(level=logging.WARNING, stream=sys.stderr)
+    results = run_health_checks()
+    print(format_report(results))
+    return 1 if get_limitations(results) else 0
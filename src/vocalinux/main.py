@@ -15,6 +15,10 @@ logging.basicConfig(
 )
 logger = logging.getLogger(__name__)
 
+# Segments starting with these attach to the previous one without a space, so a
+# trailing "." or "," finalised on its own doesn't end up as "Hello world ."
+_ATTACHING_PUNCTUATION = ".,!?;:)]}\u2026"
+
 # Note: GTK-dependent modules (tray_indicator) are imported lazily after
 # dependency checking to provide better error messages for pip/pipx users
 
@@ -69,6 +73,36 @@ def parse_arguments():
         action="store_true",
         help="Start minimized to system tray",
     )
+    parser.add_argument(
+        "--output-profile",
+        type=str,
+        help="Output profile from the config deciding where results go (inject, file, ...)",
+    )
+    parser.add_argument(
+        "--input-file",
+        metavar="FILE",
+        help="Debug: each dictation session plays this WAV file instead of the microphone",
+    )
+    parser.add_argument(
+        "--input-speed",
+        type=float,
+        default=1.0,
+        metavar="SPEED",
+        help="Play the input file at SPEED times real time (0 = as fast as possible)",
+    )
+    parser.add_argument(
+        "--dump-audio",
+        metavar="DIR",
+        help="Debug: write each utterance to a timestamped WAV in DIR before recognition",
+    )
+    parser.add_argument(
+        "--kiosk",
+        action="store_true",
+        help=(
+            "Read-only mode for demos and captioning: show transcripts in the status window "
+            "without typing them or running voice commands"
+        ),
+    )
     return parser.parse_args()
 
 
@@ -214,11 +248,31 @@ def check_appindicator_support():
 
 def main():
     """Main entry point for the application."""
+    # Subcommands run headless and must not hold the instance lock
+    if len(sys.argv) > 1 and sys.argv[1] == "transcribe":
+        from . import transcribe
+
+        sys.exit(transcribe.main(sys.argv[2:]))
+
+    if len(sys.argv) > 1 and sys.argv[1] == "doctor":
+        from . import doctor
+
+        sys.exit(doctor.main(sys.argv[2:]))
+
+    if len(sys.argv) > 1 and sys.argv[1] == "status":
+        from . import status_output
+
+        sys.exit(status_output.main(sys.argv[2:]))
+
     # Check for single instance BEFORE any initialization
     from . import single_instance
 
     if not single_instance.acquire_lock():
-        # Another instance is already running - show notification and exit
+        # Another instance is already running - bring up its status window
+        if single_instance.signal_running_instance():
+            sys.exit(0)
+
+        # Could not reach it - show notification and exit
         try:
             import time
 
@@ -269,13 +323,19 @@ def main():
         logger.warning("After installing, log out and back in (or restart GNOME Shell).")
 
     # Now it's safe to import GTK-dependent modules
+    from . import status_output
     from .common_types import RecognitionState
-    from .speech_recognition import recognition_manager
+    from .speech_recognition import engine_availability, recognition_manager
+    from .text_injection import focus_monitor as focus_monitor_module
+    from .text_injection import live_typing
+    from .text_injection import result_router as result_router_module
     from .text_injection import text_injector
     from .ui import tray_indicator
     from .ui.action_handler import ActionHandler
     from .ui.config_manager import ConfigManager
     from .ui.logging_manager import initialize_logging
+    from .ui.voice_settings import VoiceSettingsHandler
+    from .utils.vocabulary_packs import get_vocabulary_terms
 
     # Initialize logging manager early
     initialize_logging()
@@ -357,6 +417,24 @@ def main():
     audio_device_name = audio_settings.get("device_name", None)
 
     advanced_settings = config_manager.get_settings().get("advanced", {})
+    formatting_settings = config_manager.get_settings().get("formatting", {})
+    vocabulary_settings = config_manager.get_settings().get("vocabulary", {})
+    download_settings = config_manager.get_settings().get("downloads", {})
+    output_settings = config_manager.get_settings().get("output", {})
+
+    comparison_engine = saved_settings.get("comparison_engine")
+    comparison_model_size = (
+        config_manager.get_model_size_for_engine(comparison_engine) if comparison_engine else None
+    )
+
+    # An output profile can turn translation on or off for itself
+    output_profile = (
+        result_router_module.profile_settings(output_settings, args.output_profile) or {}
+    )
+    translate = saved_settings.get("translate", False)
+    profile_translate = output_profile.get("translate")
+    if profile_translate is not None:
+        translate = bool(profile_translate)
 
     logger.info(f"Final settings: engine={engine}, language={language}, model={model_size}")
     if audio_device_index is not None:
@@ -368,32 +446,124 @@ def main():
     # Initialize main components
     logger.info("Initializing Vocalinux...")
 
+    def create_speech_engine(engine_name: str, engine_model_size: str, allow_fallback=True):
+        """
+        Create the speech recognition manager with the saved/configured settings.
+
+        If the engine is not installed, an installed one is used for this
+        session instead of exiting.
+        """
+        try:
+            return recognition_manager.SpeechRecognitionManager(
+                engine=engine_name,
+                model_size=engine_model_size,
+                language=language,
+                vad_sensitivity=vad_sensitivity,
+                vad_backend=saved_settings.get("vad_backend", "auto"),
+                silence_timeout=silence_timeout,
+                engine_vad=saved_settings.get("engine_vad", {}),
+                adaptive_silence_timeout=saved_settings.get("adaptive_silence_timeout", False),
+                max_utterance_seconds=saved_settings.get("max_utterance_seconds", 30),
+                speech_pad_before_ms=saved_settings.get("speech_pad_before_ms", 200),
+                speech_pad_after_ms=saved_settings.get("speech_pad_after_ms", 200),
+                stop_sound_guard_ms=stop_sound_guard_ms,
+                voice_commands_enabled=voice_commands_enabled,
+                disabled_commands=saved_settings.get("disabled_commands", []),
+                renamed_commands=saved_settings.get("renamed_commands", {}),
+                punctuation=saved_settings.get("punctuation"),
+                utterance_join_window=saved_settings.get("utterance_join_window", 0.0),
+                vosk_speaker_profile=saved_settings.get("vosk_speaker_profile", False),
+                long_form_mode=saved_settings.get("long_form_mode", False),
+                partial_results=saved_settings.get("partial_results", False),
+                translate=translate,
+                tag_language=saved_settings.get("tag_language", False),
+                comparison_engine=comparison_engine,
+                comparison_model_size=comparison_model_size,
+                rewrite_engine=saved_settings.get("rewrite_engine"),
+                rewrite_model_size=saved_settings.get("rewrite_model_size", "small"),
+                retry_audio=general_settings.get("retry_audio", False),
+                retry_model_size=saved_settings.get("retry_model_size", "large"),
+                offline_fallback_engine=saved_settings.get("offline_fallback_engine"),
+                offline_fallback_model_size=saved_settings.get(
+                    "offline_fallback_model_size", "tiny"
+                ),
+                offline_fallback_standby=saved_settings.get("offline_fallback_standby", False),
+                gpu_memory_reserve_mb=saved_settings.get("gpu_memory_reserve_mb", 0),
+                intent_commands_enabled=(
+                    saved_settings.get("intent_commands_enabled", False) and not args.kiosk
+                ),
+                intent_search_url=saved_settings.get("intent_search_url"),
+                format_ordinals=formatting_settings.get("ordinals", False),
+                format_fractions=formatting_settings.get("fractions", False),
+                format_roman_numerals=formatting_settings.get("roman_numerals", False),
+                preserved_terms=formatting_settings.get("preserved_terms", []),
+                profanity_filter=formatting_settings.get("profanity_filter", "off"),
+                profanity_words=formatting_settings.get("profanity_words", []),
+                text_pipeline=output_profile.get("pipeline"),
+                vocabulary_terms=get_vocabulary_terms(
+                    vocabulary_settings.get("packs", []),
+                    vocabulary_settings.get("custom_terms", []),
+                ),
+                prompt_presets=saved_settings.get("prompt_presets", {}),
+                app_prompts=saved_settings.get("app_prompts", {}),
+                audio_device_index=audio_device_index,
+                audio_device_name=audio_device_name,
+                sample_rate=audio_settings.get("sample_rate", None),
+                input_file=args.input_file,
+                input_file_speed=args.input_speed,
+                sidetone_enabled=audio_settings.get("sidetone_enabled", False),
+                sidetone_volume=audio_settings.get("sidetone_volume", 0.3),
+                duck_media=audio_settings.get("duck_media", False),
+                duck_level=audio_settings.get("duck_level", 0.3),
+                echo_cancel=audio_settings.get("echo_cancel", False),
+                mute_own_sounds=audio_settings.get("mute_own_sounds", True),
+                noise_suppression=audio_settings.get("noise_suppression", False),
+                device_gain=audio_settings.get("device_gain", {}),
+                extra_device_names=audio_settings.get("extra_device_names", []),
+                buffer_frames=audio_settings.get("buffer_frames", 1024),
+                queue_depth=audio_settings.get("queue_depth", 32),
+                download_mirror=download_settings.get("mirror", "official"),
+                vosk_mirror_url=download_settings.get("vosk_mirror_url", ""),
+                download_limit_kbps=download_settings.get("bandwidth_limit_kbps", 0),
+                session_word_target=general_settings.get("word_target", 0),
+                utterance_log=general_settings.get("utterance_log", "off"),
+                whispercpp_no_timestamps=advanced_settings.get("whispercpp_no_timestamps", True),
+                whispercpp_no_context=advanced_settings.get("whispercpp_no_context", True),
+                whispercpp_initial_prompt=advanced_settings.get("whispercpp_initial_prompt", ""),
+                whispercpp_temperature=advanced_settings.get("whispercpp_temperature", 0.0),
+                whispercpp_temperature_inc=advanced_settings.get(
+                    "whispercpp_temperature_inc", -1.0
+                ),
+                whispercpp_entropy_thold=advanced_settings.get("whispercpp_entropy_thold", 2.4),
+                whispercpp_logprob_thold=advanced_settings.get("whispercpp_logprob_thold", -1.0),
+                whispercpp_no_speech_thold=advanced_settings.get(
+                    "whispercpp_no_speech_thold", 0.6
+                ),
+                whispercpp_n_threads=advanced_settings.get("whispercpp_n_threads", 0),
+                dump_audio_dir=args.dump_audio or advanced_settings.get("dump_audio_dir", ""),
+                remote_api_url=saved_settings.get("remote_api_url", ""),
+                remote_api_key=saved_settings.get("remote_api_key", ""),
+                remote_api_account=saved_settings.get("remote_api_account", "default"),
+                remote_api_endpoint=saved_settings.get("remote_api_endpoint", "/inference"),
+                remote_api_model=saved_settings.get("remote_api_model", "whisper-1"),
+            )
+        except engine_availability.EngineNotInstalledError as e:
+            fallback = engine_availability.pick_available_engine(exclude=[engine_name])
+            if not allow_fallback or fallback is None:
+                raise
+            logger.warning(f"{e}; using {fallback} for this session")
+            recognition_manager._show_notification(
+                "Speech engine not installed",
+                f"{e}. Using {fallback} instead.",
+                icon="dialog-warning",
+            )
+            return create_speech_engine(
+                fallback, config_manager.get_model_size_for_engine(fallback), allow_fallback=False
+            )
+
     try:
         # Initialize speech recognition engine with saved/configured settings
-        speech_engine = recognition_manager.SpeechRecognitionManager(
-            engine=engine,
-            model_size=model_size,
-            language=language,
-            vad_sensitivity=vad_sensitivity,
-            silence_timeout=silence_timeout,
-            stop_sound_guard_ms=stop_sound_guard_ms,
-            voice_commands_enabled=voice_commands_enabled,
-            audio_device_index=audio_device_index,
-            audio_device_name=audio_device_name,
-            whispercpp_no_timestamps=advanced_settings.get("whispercpp_no_timestamps", True),
-            whispercpp_no_context=advanced_settings.get("whispercpp_no_context", True),
-            whispercpp_initial_prompt=advanced_settings.get("whispercpp_initial_prompt", ""),
-            whispercpp_temperature=advanced_settings.get("whispercpp_temperature", 0.0),
-            whispercpp_temperature_inc=advanced_settings.get("whispercpp_temperature_inc", -1.0),
-            whispercpp_entropy_thold=advanced_settings.get("whispercpp_entropy_thold", 2.4),
-            whispercpp_logprob_thold=advanced_settings.get("whispercpp_logprob_thold", -1.0),
-            whispercpp_no_speech_thold=advanced_settings.get("whispercpp_no_speech_thold", 0.6),
-            whispercpp_n_threads=advanced_settings.get("whispercpp_n_threads", 0),
-            remote_api_url=saved_settings.get("remote_api_url", ""),
-            remote_api_key=saved_settings.get("remote_api_key", ""),
-            remote_api_endpoint=saved_settings.get("remote_api_endpoint", "/inference"),
-            remote_api_model=saved_settings.get("remote_api_model", "whisper-1"),
-        )
+        speech_engine = create_speech_engine(engine, model_size)
 
         # Initialize text injection system
         text_system = text_injector.TextInjector(wayland_mode=args.wayland)
@@ -401,15 +571,42 @@ def main():
         # Initialize action handler
         action_handler = ActionHandler(text_system)
 
+        # Voice commands that change settings ("increase sensitivity", ...)
+        voice_settings = VoiceSettingsHandler(
+            speech_engine,
+            config_manager,
+            notify=lambda title, message: recognition_manager._show_notification(
+                title, message, icon="preferences-system"
+            ),
+        )
+        action_handler.register_actions(voice_settings.get_action_handlers())
+
+        # Optionally stop dictation when focus leaves the window it started in
+        focus_monitor = focus_monitor_module.FocusMonitor(
+            get_window_id=text_system.get_active_window_id,
+            on_focus_lost=speech_engine.stop_recognition,
+        )
+
+        # The focused app picks the Whisper prompt preset for a dictation session
+        speech_engine.set_focused_app_provider(text_system.get_active_window_class)
+
         # --- Callback wiring ---------------------------------------------------
         # The speech engine emits three kinds of events, each handled by a
         # dedicated callback registered below:
         #
         #   text_callback(text: str)
         #       Called on the recognition thread when a transcription segment
-        #       is finalised.  The wrapper below strips whitespace, inserts
-        #       inter-segment spaces, injects the text, and records it so
-        #       "delete that" can undo it.
+        #       is finalised.  The wrapper below strips whitespace and hands
+        #       the text to the result router, which delivers it to every sink
+        #       in the output profile.  The "inject" sink inserts inter-segment
+        #       spaces, types the text, and records it so "delete that" can
+        #       undo it.
+        #
+        #   partial_callback(text: str)
+        #       Called on the recognition thread with the engine's guess at the
+        #       utterance still being spoken, when partial results are enabled.
+        #       The live typer types it and corrects it in place; the final
+        #       text from text_callback then replaces it ("" withdraws it).
         #
         #   action_callback(action: str) -> bool
         #       Called when a voice command (e.g. "undo", "select all") is
@@ -421,46 +618,130 @@ def main():
         #       listening session ends.
         # ------------------------------------------------------------------
 
+        live_typer = live_typing.LiveTyper(text_system.inject_text)
+        draft_rewriter = live_typing.DraftRewriter(text_system.inject_text)
+
+        def with_separator(text: str) -> str:
+            """Prefix a segment with a space if it continues earlier dictation.
+
+            Never for the very first segment (avoids unwanted leading space
+            when starting dictation in an empty text field) or before punctuation.
+            """
+            if (
+                action_handler.last_injected_text
+                and action_handler.last_injected_text.strip()
+                and text[0] not in _ATTACHING_PUNCTUATION
+            ):
+                logger.debug("Added space separator before new segment")
+                return " " + text
+            return text
+
+        def inject_segment(text: str) -> bool:
+            """Type one segment via TextInjector, spacing it from the previous one.
+
+            Args:
+                text: Stripped, non-empty transcription segment.
+
+            Returns:
+                True if the text was injected.
+            """
+            text_to_inject = with_separator(text)
+
+            with draft_rewriter.lock:
+                if live_typer.active:
+                    # Correct the partial text already on screen instead of typing again
+                    success = live_typer.finish(text_to_inject)
+                else:
+                    success = text_system.inject_text(text_to_inject)
+                if success:
+                    action_handler.set_last_injected_text(text_to_inject)
+                    draft_rewriter.typed(text_to_inject)
+            return success
+
+        def partial_callback(text: str) -> None:
+            """Type a partial result, or withdraw it when given ""."""
+            text = text.strip()
+            if text:
+                # The next utterance is on screen after the draft now
+                draft_rewriter.forget()
+                live_typer.update(with_separator(text))
+            elif live_typer.active:
+                live_typer.discard()
+
+        def rewrite_callback(draft: str, text: str) -> None:
+            """Replace the typed draft with the rewrite model's transcript."""
+            with draft_rewriter.lock:
+                if not draft_rewriter.rewrite(draft, text):
+                    return
+                last_injected = action_handler.last_injected_text or ""
+                if last_injected.endswith(draft):
+                    action_handler.set_last_injected_text(last_injected[: -len(draft)] + text)
+
+        result_router = result_router_module.ResultRouter.from_config(
+            output_settings,
+            inject=inject_segment,
+            copy_to_clipboard=text_system._copy_to_clipboard,
+            profile=args.output_profile,
+        )
+        if args.kiosk:
+            # Read-only: nothing is typed and no voice command presses keys
+            logger.info("Kiosk mode: transcripts are shown in the status window, not typed")
+            result_router.sinks = [sink for sink in result_router.sinks if sink != "inject"]
+
         def text_callback_wrapper(text: str) -> None:
-            """Bridge between speech engine text events and the text injector.
+            """Bridge between speech engine text events and the result router.
 
             Called on the recognition thread with each finalised transcription
             segment.  Strips leading/trailing whitespace (whisper tokenizer
-            sometimes prepends spaces), inserts a single space between
-            consecutive segments, then injects via TextInjector.
+            sometimes prepends spaces), then routes the text to the sinks of
+            the active output profile.
 
             Args:
                 text: Raw transcription segment from the speech engine.
             """
-            text_to_inject = text.strip()
-            if not text_to_inject:
+            text = text.strip()
+            if not text:
                 return
-
-            # Add a separating space between consecutive dictation segments,
-            # but never for the very first segment (avoids unwanted leading space
-            # when starting dictation in an empty text field).
-            if action_handler.last_injected_text and action_handler.last_injected_text.strip():
-                text_to_inject = " " + text_to_inject
-                logger.debug("Added space separator before new segment")
-
-            success = text_system.inject_text(text_to_inject)
-            if success:
-                action_handler.set_last_injected_text(text_to_inject)
+            result_router.route(text)
 
         def on_state_change(state: RecognitionState) -> None:
-            """Reset the last-injected buffer when a listening session ends."""
+            """Reset the last-injected buffer when a listening session ends.
+
+            Also starts/stops the focus monitor around each listening session
+            when "stop on focus change" is enabled.
+            """
             if state == RecognitionState.IDLE:
+                if live_typer.active:
+                    live_typer.discard()
                 action_handler.set_last_injected_text("")
+                focus_monitor.stop()
+            elif state == RecognitionState.LISTENING and not focus_monitor.active:
+                # Shared with the tray, so toggling the option in Settings applies immediately
+                if config_manager.get_bool("text_injection", "stop_on_focus_change", False):
+                    focus_monitor.start()
+
+        # State and level for `vocalinux status`, read by waybar/polybar modules
+        status_writer = status_output.StatusWriter()
+        speech_engine.register_state_callback(status_writer.on_state_changed)
+        speech_engine.register_audio_level_callback(status_writer.on_audio_level)
+        atexit.register(status_writer.close)
 
         # Connect speech recognition to text injection and action handling
         speech_engine.register_text_callback(text_callback_wrapper)
-        speech_engine.register_action_callback(action_handler.handle_action)
+        if "inject" in result_router.sinks:
+            # Partial text is only ever typed; other sinks get the final text alone
+            speech_engine.register_partial_callback(partial_callback)
+            speech_engine.register_rewrite_callback(rewrite_callback)
+        if not args.kiosk:
+            speech_engine.register_action_callback(action_handler.handle_action)
         speech_engine.register_state_callback(on_state_change)
 
         # Initialize and start the system tray indicator
         indicator = tray_indicator.TrayIndicator(
             speech_engine=speech_engine,
             text_injector=text_system,
+            kiosk=args.kiosk,
+            config_manager=config_manager,
         )
 
         # Start the GTK main loop
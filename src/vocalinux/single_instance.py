@@ -1,25 +1,31 @@
 """
 Single instance enforcement for Vocalinux.
 
-Uses file locking to ensure only one instance runs at a time.
-Works across sessions and handles stale locks from crashed processes.
+Uses file locking to ensure only one instance runs at a time per user and
+machine. Works across sessions and handles stale locks from crashed processes.
+The lock lives in the per-user runtime directory, so the same account logged
+in on several machines sharing a home directory can run one instance on each.
 """
 
 import fcntl
 import logging
 import os
+import signal
 import sys
 from pathlib import Path
 from typing import Optional
 
-from .utils.paths import data_dir
+from .utils.paths import ensure_private_dir, runtime_dir
 
 logger = logging.getLogger(__name__)
 
-# Lock file location: $XDG_DATA_HOME/vocalinux/ (defaults to ~/.local/share/vocalinux/)
-LOCK_FILE_DIR = Path(data_dir())
+# Lock file location: $XDG_RUNTIME_DIR/vocalinux/ (defaults to /tmp/runtime-<uid>/vocalinux/)
+LOCK_FILE_DIR = Path(runtime_dir())
 LOCK_FILE_PATH = LOCK_FILE_DIR / "instance.lock"
 
+# Sent to the running instance to ask it to show its status window
+SHOW_STATUS_SIGNAL = signal.SIGUSR1
+
 # Global lock file handle
 _lock_file: Optional[int] = None
 
@@ -31,12 +37,12 @@ def _get_lock_file_fd() -> int:
     if _lock_file is not None:
         return _lock_file
 
-    # Ensure directory exists
-    LOCK_FILE_DIR.mkdir(parents=True, exist_ok=True)
+    # Ensure the directory exists and no other user can replace the lock
+    ensure_private_dir(str(LOCK_FILE_DIR))
 
     # Open lock file (create if doesn't exist)
     # O_RDWR: read/write, O_CREAT: create if not exists
-    fd = os.open(str(LOCK_FILE_PATH), os.O_RDWR | os.O_CREAT, 0o644)
+    fd = os.open(str(LOCK_FILE_PATH), os.O_RDWR | os.O_CREAT, 0o600)
     _lock_file = fd
 
     return fd
@@ -69,6 +75,10 @@ def acquire_lock() -> bool:
             )
             return False
 
+        # A second launch signals the PID below; until the tray installs its
+        # handler, that signal's default action would terminate us
+        signal.signal(SHOW_STATUS_SIGNAL, signal.SIG_IGN)
+
         # Lock acquired - write our PID for debugging purposes
         os.ftruncate(fd, 0)
         os.write(fd, f"{os.getpid()}\n".encode())
@@ -98,3 +108,35 @@ def release_lock():
             logger.warning(f"Failed to release lock: {e}")
             # Ensure clean state
             _lock_file = None
+
+
+def get_running_pid() -> Optional[int]:
+    """
+    Get the PID of the instance holding the lock, as written to the lock file.
+
+    Returns:
+        The PID, or None if the lock file is missing or unreadable.
+    """
+    try:
+        return int(LOCK_FILE_PATH.read_text().strip())
+    except (OSError, ValueError):
+        return None
+
+
+def signal_running_instance() -> bool:
+    """
+    Ask the running instance to show its status window.
+
+    Returns:
+        True if the signal was delivered, False otherwise.
+    """
+    pid = get_running_pid()
+    if pid is None or pid == os.getpid():
+        return False
+    try:
+        os.kill(pid, SHOW_STATUS_SIGNAL)
+    except (ProcessLookupError, PermissionError) as e:
+        logger.warning(f"Could not signal running instance (PID {pid}): {e}")
+        return False
+    logger.info(f"Asked running instance (PID {pid}) to show its status window")
+    return True
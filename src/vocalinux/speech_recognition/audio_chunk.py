@@ -0,0 +1,70 @@
+"""
+Captured audio chunks and where they came from.
+
+The record loop hands each chunk of audio on as an AudioChunk: the 16-bit
+mono 16 kHz bytes the engines expect, tagged with the input it was read
+from, the rate that input actually captured at and a sequence number. As
+AudioChunk is bytes, code that only needs the audio joins and slices chunks
+as before; code that cares can tell from the sequence numbers that chunks
+were dropped, and from the source that a segment spans a device change.
+"""
+
+from typing import Iterable, Optional
+
+
+class AudioChunk(bytes):
+    """A chunk of captured audio with its source, capture rate and sequence number."""
+
+    source: Optional[str]
+    capture_rate: int
+    sequence: int
+
+    def __new__(cls, data: bytes, source: Optional[str], capture_rate: int, sequence: int):
+        """
+        Create a chunk.
+
+        Args:
+            data: The 16-bit mono 16 kHz audio
+            source: The device, monitor source or file it was read from (None if unknown)
+            capture_rate: Sample rate the source captured at, before resampling
+            sequence: Number of the chunk; one higher for each chunk read
+        """
+        chunk = super().__new__(cls, data)
+        chunk.source = source
+        chunk.capture_rate = capture_rate
+        chunk.sequence = sequence
+        return chunk
+
+    def __repr__(self) -> str:
+        return (
+            f"AudioChunk(<{len(self)} bytes>, source={self.source!r}, "
+            f"capture_rate={self.capture_rate}, sequence={self.sequence})"
+        )
+
+
+def missing_chunks(chunks: Iterable[bytes]) -> int:
+    """
+    Count the chunks missing from a run of chunks, going by their sequence numbers.
+
+    Plain bytes (audio that didn't come from the record loop) are skipped.
+    """
+    missing = 0
+    previous = None
+    for chunk in chunks:
+        sequence = getattr(chunk, "sequence", None)
+        if sequence is None:
+            continue
+        if previous is not None and sequence > previous + 1:
+            missing += sequence - previous - 1
+        previous = sequence
+    return missing
+
+
+def chunk_sources(chunks: Iterable[bytes]) -> list[str]:
+    """Return the sources the chunks were read from, in the order they first appear."""
+    sources: list[str] = []
+    for chunk in chunks:
+        source = getattr(chunk, "source", None)
+        if source is not None and source not in sources:
+            sources.append(source)
+    return sources
@@ -0,0 +1,197 @@
+"""
+Audio level metering for Vocalinux.
+
+Levels are measured as RMS in dBFS (0 dBFS is a full-scale 16-bit signal)
+and mapped onto a 0-100 meter scale covering the last METER_RANGE_DB
+decibels, so level meters and the amplitude VAD threshold use the same units.
+A meter's attack and release smoothing keeps the displayed level readable.
+
+The amplitude VAD also follows the background noise: its threshold is kept
+a sensitivity-dependent margin above the quietest level of the last few
+seconds, so the same sensitivity works in a quiet office and next to a fan.
+"""
+
+import math
+from collections import deque
+from typing import Optional
+
+FULL_SCALE = 32768.0
+
+# The meter shows -60 dBFS (quiet room noise) to 0 dBFS; anything quieter reads 0
+METER_FLOOR_DBFS = -60.0
+METER_RANGE_DB = -METER_FLOOR_DBFS
+
+# Meter ballistics: rise quickly to peaks, fall slowly enough to read
+DEFAULT_ATTACK_SECONDS = 0.01
+DEFAULT_RELEASE_SECONDS = 0.3
+
+# RMS of the speech the amplitude VAD triggers on at sensitivity 1 (least
+# sensitive); higher sensitivities divide it, lowering the threshold.
+_VAD_BASE_RMS = 625.0
+
+# Speech always pauses within this window, so its quietest moment is the
+# room's noise; the floor isn't trusted until a second has been heard.
+NOISE_FLOOR_WINDOW_SECONDS = 5.0
+NOISE_FLOOR_CALIBRATION_SECONDS = 1.0
+
+# How far above the noise floor speech must be, per sensitivity 1-5
+_NOISE_MARGIN_DB = {1: 12.0, 2: 10.0, 3: 8.0, 4: 6.0, 5: 4.0}
+
+
+def _clamp_sensitivity(sensitivity) -> int:
+    try:
+        return max(1, min(5, int(sensitivity)))
+    except (TypeError, ValueError):
+        return 3
+
+
+def rms_dbfs(samples, pool=None) -> float:
+    """
+    RMS level of a numpy array of 16-bit samples in dBFS, never below METER_FLOOR_DBFS.
+
+    A BufferPool, if given, holds the squared samples so metering every
+    chunk doesn't allocate.
+    """
+    if samples.size == 0:
+        return METER_FLOOR_DBFS
+    if pool is None:
+        squares = samples.astype("float64") ** 2
+    else:
+        import numpy as np
+
+        squares = pool.take("rms", samples.size, np.float64)
+        np.square(samples, out=squares, dtype=np.float64)
+    rms = math.sqrt(float(squares.mean()))
+    if rms <= 0:
+        return METER_FLOOR_DBFS
+    return max(METER_FLOOR_DBFS, 20.0 * math.log10(rms / FULL_SCALE))
+
+
+def amplitude_dbfs(amplitude: float) -> float:
+    """A 16-bit sample amplitude in dBFS, never below METER_FLOOR_DBFS."""
+    if amplitude <= 0:
+        return METER_FLOOR_DBFS
+    return max(METER_FLOOR_DBFS, 20.0 * math.log10(amplitude / FULL_SCALE))
+
+
+def dbfs_to_percent(dbfs: float) -> float:
+    """Position of a dBFS level on the 0-100 meter scale."""
+    return min(100.0, max(0.0, (dbfs - METER_FLOOR_DBFS) / METER_RANGE_DB * 100.0))
+
+
+def percent_to_dbfs(percent: float) -> float:
+    """The dBFS level at a position on the 0-100 meter scale."""
+    return METER_FLOOR_DBFS + min(100.0, max(0.0, percent)) / 100.0 * METER_RANGE_DB
+
+
+def vad_threshold_dbfs(sensitivity) -> float:
+    """
+    Speech threshold of the amplitude VAD for a sensitivity of 1-5.
+
+    Invalid values use the default sensitivity of 3.
+    """
+    sensitivity = _clamp_sensitivity(sensitivity)
+    return 20.0 * math.log10(_VAD_BASE_RMS / sensitivity / FULL_SCALE)
+
+
+def adaptive_vad_threshold_dbfs(sensitivity, noise_floor_dbfs: Optional[float]) -> float:
+    """
+    Speech threshold of the amplitude VAD given the background noise.
+
+    The fixed threshold applies in quiet rooms; louder noise raises it so
+    the noise itself isn't taken for speech.
+
+    Args:
+        sensitivity: VAD sensitivity, 1-5
+        noise_floor_dbfs: Estimated noise floor, or None if not known yet
+    """
+    threshold = vad_threshold_dbfs(sensitivity)
+    if noise_floor_dbfs is None:
+        return threshold
+    return max(threshold, noise_floor_dbfs + _NOISE_MARGIN_DB[_clamp_sensitivity(sensitivity)])
+
+
+class NoiseFloorEstimator:
+    """Tracks the background noise level as the quietest level over a sliding window."""
+
+    def __init__(
+        self,
+        window: float = NOISE_FLOOR_WINDOW_SECONDS,
+        calibration: float = NOISE_FLOOR_CALIBRATION_SECONDS,
+    ):
+        """
+        Initialize the estimator.
+
+        Args:
+            window: Seconds of audio the floor is taken over
+            calibration: Seconds to hear before the floor is reported
+        """
+        self.window = window
+        self.calibration = calibration
+        self.reset()
+
+    def update(self, dbfs: float, elapsed: float):
+        """
+        Feed the level of the latest block of audio.
+
+        Args:
+            dbfs: Level of the block
+            elapsed: Duration of the block in seconds
+        """
+        self._blocks.append((dbfs, elapsed))
+        self._heard += elapsed
+        self._span += elapsed
+        while len(self._blocks) > 1 and self._span - self._blocks[0][1] >= self.window:
+            self._span -= self._blocks.popleft()[1]
+
+    @property
+    def floor(self) -> Optional[float]:
+        """The estimated noise floor in dBFS, or None while still calibrating."""
+        if self._heard < self.calibration or not self._blocks:
+            return None
+        return min(level for level, _elapsed in self._blocks)
+
+    def reset(self):
+        """Forget the noise heard so far (call when a new recording starts)."""
+        self._blocks: deque = deque()
+        self._heard = 0.0
+        self._span = 0.0
+
+
+class LevelMeter:
+    """Smooths a stream of dBFS readings with separate attack and release times."""
+
+    def __init__(
+        self,
+        attack: float = DEFAULT_ATTACK_SECONDS,
+        release: float = DEFAULT_RELEASE_SECONDS,
+    ):
+        """
+        Initialize the meter.
+
+        Args:
+            attack: Time constant in seconds for rising levels
+            release: Time constant in seconds for falling levels
+        """
+        self.attack = attack
+        self.release = release
+        self.level = METER_FLOOR_DBFS
+
+    def update(self, dbfs: float, elapsed: float) -> float:
+        """
+        Feed a new reading and return the smoothed level in dBFS.
+
+        Args:
+            dbfs: Level of the latest block of audio
+            elapsed: Duration of that block in seconds
+        """
+        time_constant = self.attack if dbfs > self.level else self.release
+        if time_constant <= 0:
+            self.level = dbfs
+        else:
+            self.level += (dbfs - self.level) * (1.0 - math.exp(-elapsed / time_constant))
+        return self.level
+
+    def reset(self):
+        """Return the meter to silence."""
+        self.level = METER_FLOOR_DBFS
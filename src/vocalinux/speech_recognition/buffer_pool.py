@@ -0,0 +1,47 @@
+"""
+Reusable scratch arrays for the per-chunk audio processing in Vocalinux.
+
+The recording loop handles about sixteen chunks a second for as long as
+dictation runs, and converting each one (gain, mixing, level metering) used
+to allocate several temporary numpy arrays that were thrown away right
+after. A BufferPool hands out the same arrays chunk after chunk, growing one
+only when a larger chunk arrives, so the temporaries stop churning memory
+during long sessions. Only the finished chunk is copied out, since it is
+kept in the recording buffer.
+
+A pool isn't thread-safe; each thread that processes audio keeps its own.
+"""
+
+import numpy as np
+
+
+class BufferPool:
+    """Scratch numpy arrays, reused between chunks."""
+
+    def __init__(self):
+        self._arrays = {}
+
+    def take(self, key: str, length: int, dtype):
+        """
+        A scratch array of `length` elements for one use.
+
+        The contents are left over from the previous chunk, and the array is
+        overwritten by the next call with the same key.
+
+        Args:
+            key: Which of the pool's arrays to use
+            length: Number of elements needed
+            dtype: Element type
+
+        Returns:
+            A view of `length` elements of the pooled array
+        """
+        array = self._arrays.get(key)
+        if array is None or array.dtype != np.dtype(dtype) or len(array) < length:
+            array = np.empty(length, dtype=dtype)
+            self._arrays[key] = array
+        return array[:length]
+
+    def clear(self):
+        """Release the pooled arrays."""
+        self._arrays = {}
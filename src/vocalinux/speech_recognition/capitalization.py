@@ -0,0 +1,49 @@
+"""
+Capitalization preservation for Vocalinux.
+
+Engines often lowercase acronyms and brand names ("api", "gmbh",
+"postgresql"). This final formatting pass restores the exact spelling of
+terms from the user's always-capitalized list.
+"""
+
+import re
+from typing import Iterable, Optional
+
+
+class TermCapitalizer:
+    """Rewrites every occurrence of a listed term with its canonical capitalization."""
+
+    def __init__(self, terms: Iterable[str] = ()):
+        self._pattern: Optional[re.Pattern] = None
+        self._canonical: dict[str, str] = {}
+        self.terms = terms
+
+    @property
+    def terms(self) -> list[str]:
+        return list(self._canonical.values())
+
+    @terms.setter
+    def terms(self, terms: Iterable[str]):
+        self._canonical = {}
+        for term in terms:
+            term = term.strip()
+            if term:
+                # The last spelling of a duplicate wins
+                self._canonical[term.lower()] = term
+
+        if not self._canonical:
+            self._pattern = None
+            return
+
+        # Longest first so "PostgreSQL" is not split by a shorter "SQL" entry.
+        # Lookarounds instead of \b so terms like "C++" and ".NET" match.
+        alternation = "|".join(
+            re.escape(term) for term in sorted(self._canonical.values(), key=len, reverse=True)
+        )
+        self._pattern = re.compile(rf"(?<!\w)(?:{alternation})(?!\w)", re.IGNORECASE)
+
+    def apply(self, text: str) -> str:
+        """Return the text with listed terms restored to their canonical spelling."""
+        if not text or self._pattern is None:
+            return text
+        return self._pattern.sub(lambda match: self._canonical[match.group(0).lower()], text)
@@ -8,8 +8,91 @@ This module processes text commands from speech recognition, such as
 import logging
 import re
 
+from .number_formatter import spoken_to_digits
+
 logger = logging.getLogger(__name__)
 
+# NATO phonetic alphabet, accepted in letters mode
+_PHONETIC_LETTERS = {
+    "alpha": "a",
+    "alfa": "a",
+    "bravo": "b",
+    "charlie": "c",
+    "delta": "d",
+    "echo": "e",
+    "foxtrot": "f",
+    "golf": "g",
+    "hotel": "h",
+    "india": "i",
+    "juliet": "j",
+    "juliett": "j",
+    "kilo": "k",
+    "lima": "l",
+    "mike": "m",
+    "november": "n",
+    "oscar": "o",
+    "papa": "p",
+    "quebec": "q",
+    "romeo": "r",
+    "sierra": "s",
+    "tango": "t",
+    "uniform": "u",
+    "victor": "v",
+    "whiskey": "w",
+    "x-ray": "x",
+    "xray": "x",
+    "yankee": "y",
+    "zulu": "z",
+}
+
+
+def _spoken_to_letters(text: str) -> str:
+    """
+    Convert spelled-out letters to a string, dropping every other word.
+
+    Accepts single letters ("a b c"), runs Whisper has already joined ("ABC")
+    and NATO phonetic words ("alpha bravo"). "capital" uppercases the next letter.
+    """
+    output = []
+    capitalize_next = False
+    for word in text.split():
+        token = re.sub(r"[^A-Za-z\-]", "", word)
+        lowered = token.lower()
+        if lowered in ("capital", "cap", "uppercase"):
+            capitalize_next = True
+            continue
+
+        if lowered in _PHONETIC_LETTERS:
+            letters = _PHONETIC_LETTERS[lowered]
+        elif len(token) == 1 or (token.isalpha() and token.isupper()):
+            letters = lowered
+        else:
+            continue
+
+        if capitalize_next and letters:
+            letters = letters[0].upper() + letters[1:]
+            capitalize_next = False
+        output.append(letters)
+    return "".join(output)
+
+
+# Said before a command phrase to type its words instead, e.g. "literal period"
+LITERAL_PREFIXES = ("literal", "type the word")
+
+# Stands in for an escaped phrase while the other commands are processed
+_LITERAL_PLACEHOLDER = "\ue000{}\ue001"
+_LITERAL_PLACEHOLDER_REGEX = re.compile("\ue000(\\d+)\ue001")
+
+# Text commands whose punctuation attaches to the previous word
+_ATTACHED_PUNCTUATION = (".", ",", "?", "!", ";", ":")
+
+
+def _phrase_regex(phrases) -> re.Pattern:
+    """Match any of the phrases as whole words, or nothing if there are none."""
+    if not phrases:
+        return re.compile(r"(?!)")
+    return re.compile(r"\b(" + "|".join(re.escape(p) for p in phrases) + r")\b", re.IGNORECASE)
+
 
 class CommandProcessor:
     """
@@ -19,8 +102,14 @@ class CommandProcessor:
     "delete that", etc.
     """
 
-    def __init__(self):
-        """Initialize the command processor."""
+    def __init__(self, disabled_commands=None, renamed_commands=None):
+        """
+        Initialize the command processor.
+
+        Args:
+            disabled_commands: Built-in phrases to type as plain words instead
+            renamed_commands: Built-in phrase -> the phrase that triggers it instead
+        """
         # Map of command phrases to their actions
         self.text_commands = {
             # Line commands
@@ -61,6 +150,21 @@ class CommandProcessor:
             "cut": "cut",
             "copy": "copy",
             "paste": "paste",
+            "read that back": "read_back",
+            "increase sensitivity": "increase_sensitivity",
+            "raise sensitivity": "increase_sensitivity",
+            "decrease sensitivity": "decrease_sensitivity",
+            "lower sensitivity": "decrease_sensitivity",
+            "increase silence timeout": "increase_silence_timeout",
+            "decrease silence timeout": "decrease_silence_timeout",
+            "switch to tiny model": "switch_model_tiny",
+            "switch to base model": "switch_model_base",
+            "switch to small model": "switch_model_small",
+            "switch to medium model": "switch_model_medium",
+            "switch to large model": "switch_model_large",
+            "translate to english": "enable_translation",
+            "start translating": "enable_translation",
+            "stop translating": "disable_translation",
         }
 
         # Formatting commands that modify the next word
@@ -72,31 +176,153 @@ class CommandProcessor:
             "no spaces": "no_spaces_next",
         }
 
+        # Field modes that stay active across utterances until switched off
+        self.mode_commands = {
+            "numbers mode": "numbers_mode",
+            "number mode": "numbers_mode",
+            "letters mode": "letters_mode",
+            "letter mode": "letters_mode",
+            "spelling mode": "letters_mode",
+            "normal mode": "normal_mode",
+            "exit numbers mode": "normal_mode",
+            "exit letters mode": "normal_mode",
+        }
+
         # Active format modifiers
         self.active_formats = set()
 
+        # Current field mode: None, "numbers" or "letters"
+        self.field_mode = None
+
+        self._builtin_commands = {
+            name: dict(getattr(self, name))
+            for name in ("text_commands", "action_commands", "format_commands", "mode_commands")
+        }
+        self.customize(disabled_commands, renamed_commands)
+
+    def customize(self, disabled_commands=None, renamed_commands=None):
+        """
+        Disable or rename built-in commands, replacing any earlier customization.
+
+        Args:
+            disabled_commands: Built-in phrases to type as plain words instead,
+                e.g. ["period"] for prose that mentions periods
+            renamed_commands: Built-in phrase -> the phrase that triggers it
+                instead, e.g. {"period": "full stop now"}
+        """
+        disabled = {phrase.strip().lower() for phrase in disabled_commands or []}
+        renamed = {
+            old.strip().lower(): new.strip().lower()
+            for old, new in (renamed_commands or {}).items()
+            if new and new.strip()
+        }
+        builtin_phrases = {
+            phrase for commands in self._builtin_commands.values() for phrase in commands
+        }
+        for phrase in sorted((disabled | set(renamed)) - builtin_phrases):
+            logger.warning(f"Ignoring unknown voice command: {phrase!r}")
+
+        for name, builtin in self._builtin_commands.items():
+            commands = {}
+            for phrase, value in builtin.items():
+                if phrase not in disabled:
+                    commands[renamed.get(phrase, phrase)] = value
+            setattr(self, name, commands)
+
+        self.disabled_commands = sorted(disabled & builtin_phrases)
+        self.renamed_commands = {old: new for old, new in renamed.items() if old in builtin_phrases}
+        self._customized = bool(self.disabled_commands or self.renamed_commands)
+
         # Compile regex patterns for faster matching
         self._compile_patterns()
 
     def _compile_patterns(self):
         """Compile regex patterns for command matching."""
-        # Create regex pattern for text commands
-        text_cmd_pattern = (
-            r"\b(" + "|".join(re.escape(cmd) for cmd in self.text_commands.keys()) + r")\b"
+        self.text_cmd_regex = _phrase_regex(self.text_commands)
+        self.action_cmd_regex = _phrase_regex(self.action_commands)
+        self.format_cmd_regex = _phrase_regex(self.format_commands)
+        # Longest phrases first so "exit numbers mode" wins over "numbers mode"
+        self.mode_cmd_regex = _phrase_regex(sorted(self.mode_commands, key=len, reverse=True))
+
+        phrases = sorted(
+            {
+                phrase
+                for commands in (
+                    self.text_commands,
+                    self.action_commands,
+                    self.format_commands,
+                    self.mode_commands,
+                )
+                for phrase in commands
+            },
+            key=len,
+            reverse=True,
         )
-        self.text_cmd_regex = re.compile(text_cmd_pattern, re.IGNORECASE)
-
-        # Create regex pattern for action commands
-        action_cmd_pattern = (
-            r"\b(" + "|".join(re.escape(cmd) for cmd in self.action_commands.keys()) + r")\b"
+        self.literal_regex = re.compile(
+            r"\b(?:" + "|".join(re.escape(p) for p in LITERAL_PREFIXES) + r")\s+"
+            r"(" + "|".join(re.escape(p) for p in phrases) + r")\b",
+            re.IGNORECASE,
         )
-        self.action_cmd_regex = re.compile(action_cmd_pattern, re.IGNORECASE)
 
-        # Create regex pattern for format commands
-        format_cmd_pattern = (
-            r"\b(" + "|".join(re.escape(cmd) for cmd in self.format_commands.keys()) + r")\b"
-        )
-        self.format_cmd_regex = re.compile(format_cmd_pattern, re.IGNORECASE)
+    def set_field_mode(self, mode: str = None):
+        """
+        Switch the field mode used for form filling.
+
+        Args:
+            mode: "numbers" (digits only), "letters" (letters only), or None for
+                normal dictation
+        """
+        if mode not in (None, "numbers", "letters"):
+            raise ValueError(f"Unknown field mode: {mode}")
+        if mode != self.field_mode:
+            logger.info(f"Field mode: {mode or 'normal'}")
+        self.field_mode = mode
+
+    def _process_field_modes(self, text: str) -> tuple[str, list[str]]:
+        """Process text that switches field mode or is dictated while one is active."""
+        # split() with a capturing group alternates text and mode phrases
+        parts = self.mode_cmd_regex.split(text)
+        processed_parts = []
+        actions = []
+        for index, part in enumerate(parts):
+            if index % 2:
+                mode = self.mode_commands[part.lower()]
+                self.set_field_mode(None if mode == "normal_mode" else mode[: -len("_mode")])
+                continue
+            if not part.strip():
+                continue
+
+            if self.field_mode == "numbers":
+                processed = spoken_to_digits(part)
+            elif self.field_mode == "letters":
+                processed = _spoken_to_letters(part)
+            else:
+                processed, part_actions = self.process_text(part.strip())
+                actions.extend(part_actions)
+            if processed:
+                processed_parts.append(processed)
+
+        return " ".join(processed_parts), actions
+
+    def get_command_reference(self) -> list[tuple[str, str, str]]:
+        """
+        List every command this processor currently recognises.
+
+        Returns:
+            List of (category, phrase, value) tuples, where category is
+            "text", "action", "format" or "mode" and value is the inserted
+            text or the action name.
+        """
+        reference = []
+        for category, commands in (
+            ("text", self.text_commands),
+            ("action", self.action_commands),
+            ("format", self.format_commands),
+            ("mode", self.mode_commands),
+        ):
+            for phrase, value in commands.items():
+                reference.append((category, phrase, value))
+        return reference
 
     def process_text(self, text: str) -> tuple[str, list[str]]:
         """
@@ -115,6 +341,29 @@ class CommandProcessor:
 
         logger.debug(f"Processing commands in text: {text}")
 
+        if not self.field_mode:
+            # Hide "literal <command>" phrases from the command matching below
+            literals = []
+
+            def protect(match):
+                literals.append(match.group(1))
+                return _LITERAL_PLACEHOLDER.format(len(literals) - 1)
+
+            protected = self.literal_regex.sub(protect, text)
+            if literals:
+                processed_text, actions = self.process_text(protected)
+                processed_text = _LITERAL_PLACEHOLDER_REGEX.sub(
+                    lambda match: literals[int(match.group(1))], processed_text
+                )
+                return processed_text, actions
+
+        if self.field_mode or self.mode_cmd_regex.search(text):
+            return self._process_field_modes(text)
+
+        # The exact cases below are written for the built-in phrases
+        if self._customized:
+            return self._apply_commands(text)
+
         # Initialize output values to handle all test cases exactly
         processed_text = ""
         actions = []
@@ -221,86 +470,82 @@ class CommandProcessor:
 
         # If no exact match found, fallback to generic processing
         else:
-            processed_text = text.strip()
-
-            # Handle action commands
-            for cmd, action in self.action_commands.items():
-                cmd_pattern = r"\b" + re.escape(cmd) + r"\b"
-
-                if re.search(cmd_pattern, text, re.IGNORECASE):
-                    actions.append(action)
-
-                    # Check if there's text after the command
-                    match = re.search(r"\b" + re.escape(cmd) + r"\s+(.*)", text, re.IGNORECASE)
-                    if match:
-                        remaining_text = match.group(1).strip()
-                        # Only add space if there's text before the command
-                        cmd_match = re.search(
-                            r"^(.*?)\b" + re.escape(cmd) + r"\b", text, re.IGNORECASE
-                        )
-                        if cmd_match and cmd_match.group(1).strip():
-                            processed_text = " " + remaining_text
-                        else:
-                            processed_text = remaining_text
-                    else:
-                        processed_text = ""
-
-            # Handle text commands
-            for cmd, replacement in self.text_commands.items():
-                cmd_pattern = r"\b" + re.escape(cmd) + r"\b"
-                if re.search(cmd_pattern, processed_text, re.IGNORECASE):
-                    if cmd in [
-                        "period",
-                        "full stop",
-                        "comma",
-                        "question mark",
-                        "exclamation mark",
-                        "exclamation point",
-                        "semicolon",
-                        "colon",
-                    ]:
-                        # For punctuation, replace the command and remove the space before it
-                        processed_text = re.sub(
-                            r"\s*" + cmd_pattern + r"\s*",
-                            replacement,
-                            processed_text,
-                            flags=re.IGNORECASE,
-                        )
+            processed_text, actions = self._apply_commands(text)
+
+        return processed_text, actions
+
+    def _apply_commands(self, text: str) -> tuple[str, list[str]]:
+        """Replace every command phrase in the text; see process_text."""
+        actions = []
+        processed_text = text.strip()
+
+        # Handle action commands
+        for cmd, action in self.action_commands.items():
+            cmd_pattern = r"\b" + re.escape(cmd) + r"\b"
+
+            if re.search(cmd_pattern, text, re.IGNORECASE):
+                actions.append(action)
+
+                # Check if there's text after the command
+                match = re.search(r"\b" + re.escape(cmd) + r"\s+(.*)", text, re.IGNORECASE)
+                if match:
+                    remaining_text = match.group(1).strip()
+                    # Only add space if there's text before the command
+                    cmd_match = re.search(r"^(.*?)\b" + re.escape(cmd) + r"\b", text, re.IGNORECASE)
+                    if cmd_match and cmd_match.group(1).strip():
+                        processed_text = " " + remaining_text
                     else:
-                        processed_text = re.sub(
-                            cmd_pattern,
-                            replacement,
-                            processed_text,
-                            flags=re.IGNORECASE,
-                        )
-
-            # Handle format commands
-            for cmd, format_type in self.format_commands.items():
-                cmd_pattern = r"\b" + re.escape(cmd) + r"\b"
-
-                if re.search(cmd_pattern, text, re.IGNORECASE):
-                    # Handle format command that modifies next word
-                    match = re.search(r"\b" + re.escape(cmd) + r"\s+(\w+)", text, re.IGNORECASE)
-                    if match:
-                        word = match.group(1)
-                        if format_type == "capitalize_next":
-                            replacement = word.capitalize()
-                        elif format_type == "uppercase_next":
-                            replacement = word.upper()
-                        elif format_type == "lowercase_next":
-                            replacement = word.lower()
-                        else:
-                            replacement = word
-
-                        # Replace just that word
-                        processed_text = re.sub(
-                            r"\b" + re.escape(cmd) + r"\s+" + re.escape(word) + r"\b",
-                            replacement,
-                            text,
-                            flags=re.IGNORECASE,
-                        )
+                        processed_text = remaining_text
+                else:
+                    processed_text = ""
+
+        # Handle text commands
+        for cmd, replacement in self.text_commands.items():
+            cmd_pattern = r"\b" + re.escape(cmd) + r"\b"
+            if re.search(cmd_pattern, processed_text, re.IGNORECASE):
+                if replacement in _ATTACHED_PUNCTUATION:
+                    # For punctuation, replace the command and remove the space before it
+                    processed_text = re.sub(
+                        r"\s*" + cmd_pattern + r"\s*",
+                        replacement,
+                        processed_text,
+                        flags=re.IGNORECASE,
+                    )
+                else:
+                    processed_text = re.sub(
+                        cmd_pattern,
+                        replacement,
+                        processed_text,
+                        flags=re.IGNORECASE,
+                    )
+
+        # Handle format commands
+        for cmd, format_type in self.format_commands.items():
+            cmd_pattern = r"\b" + re.escape(cmd) + r"\b"
+
+            if re.search(cmd_pattern, text, re.IGNORECASE):
+                # Handle format command that modifies next word
+                match = re.search(r"\b" + re.escape(cmd) + r"\s+(\w+)", text, re.IGNORECASE)
+                if match:
+                    word = match.group(1)
+                    if format_type == "capitalize_next":
+                        replacement = word.capitalize()
+                    elif format_type == "uppercase_next":
+                        replacement = word.upper()
+                    elif format_type == "lowercase_next":
+                        replacement = word.lower()
                     else:
-                        # Format command with no target word
-                        processed_text = ""
+                        replacement = word
+
+                    # Replace just that word
+                    processed_text = re.sub(
+                        r"\b" + re.escape(cmd) + r"\s+" + re.escape(word) + r"\b",
+                        replacement,
+                        text,
+                        flags=re.IGNORECASE,
+                    )
+                else:
+                    # Format command with no target word
+                    processed_text = ""
 
         return processed_text, actions
@@ -0,0 +1,146 @@
+"""
+Noise suppression for Vocalinux.
+
+Laptop microphones pick up fans, keyboards and room noise, which Whisper
+tends to "transcribe" as made-up words. When noise suppression is on, each
+captured chunk is run through RNNoise (librnnoise, loaded with ctypes)
+after resampling and before the VAD and the recognizer see it.
+
+RNNoise works on 10 ms frames of 48 kHz audio, so the 16 kHz chunks are
+upsampled, denoised frame by frame with the remainder carried over to the
+next chunk, and downsampled again. That adds a constant 10 ms or less of
+latency.
+"""
+
+import ctypes
+import ctypes.util
+import logging
+from typing import Optional
+
+import numpy as np
+
+from .resample import TARGET_SAMPLE_RATE, StreamResampler
+
+logger = logging.getLogger(__name__)
+
+RNNOISE_SAMPLE_RATE = 48000
+RNNOISE_FRAME_SAMPLES = 480  # 10 ms at 48 kHz
+
+_FloatPointer = ctypes.POINTER(ctypes.c_float)
+
+_library = None
+_library_loaded = False
+
+
+def load_rnnoise():
+    """
+    Load librnnoise once.
+
+    Returns:
+        The library with its function signatures set, or None if it isn't installed
+    """
+    global _library, _library_loaded
+    if _library_loaded:
+        return _library
+    _library_loaded = True
+
+    path = ctypes.util.find_library("rnnoise")
+    if not path:
+        logger.debug("librnnoise not found")
+        return None
+    try:
+        library = ctypes.CDLL(path)
+        library.rnnoise_create.argtypes = [ctypes.c_void_p]
+        library.rnnoise_create.restype = ctypes.c_void_p
+        library.rnnoise_destroy.argtypes = [ctypes.c_void_p]
+        library.rnnoise_destroy.restype = None
+        library.rnnoise_process_frame.argtypes = [ctypes.c_void_p, _FloatPointer, _FloatPointer]
+        library.rnnoise_process_frame.restype = ctypes.c_float
+    except (OSError, AttributeError) as e:
+        logger.warning(f"Could not load {path}: {e}")
+        return None
+    _library = library
+    return _library
+
+
+def is_rnnoise_available() -> bool:
+    """Whether librnnoise is installed."""
+    return load_rnnoise() is not None
+
+
+class RNNoiseDenoiser:
+    """Denoises a stream of 16 kHz mono int16 chunks with RNNoise."""
+
+    def __init__(self, library=None):
+        """
+        Initialize the denoiser.
+
+        Args:
+            library: A loaded librnnoise, defaults to load_rnnoise()
+
+        Raises:
+            RuntimeError: If librnnoise isn't installed
+        """
+        self._library = library or load_rnnoise()
+        if self._library is None:
+            raise RuntimeError("librnnoise is not installed")
+        self._state = self._library.rnnoise_create(None)
+        self._upsampler = StreamResampler(TARGET_SAMPLE_RATE, RNNOISE_SAMPLE_RATE)
+        self._downsampler = StreamResampler(RNNOISE_SAMPLE_RATE, TARGET_SAMPLE_RATE)
+        self._pending = np.empty(0, dtype=np.float32)
+        self._output = np.empty(RNNOISE_FRAME_SAMPLES, dtype=np.float32)
+        self.speech_probability = 0.0  # RNNoise's own voice estimate for the last frame
+
+    def reset(self):
+        """Forget the previous chunks, e.g. when a new recording starts."""
+        self.close()
+        self._state = self._library.rnnoise_create(None)
+        self._upsampler.reset()
+        self._downsampler.reset()
+        self._pending = np.empty(0, dtype=np.float32)
+
+    def process(self, data: bytes) -> bytes:
+        """
+        Denoise one chunk.
+
+        Returns:
+            The denoised chunk; like the resampler's output, its length varies
+            slightly from chunk to chunk
+        """
+        if not data or self._state is None:
+            return data
+
+        upsampled = np.frombuffer(self._upsampler.process(data), dtype=np.int16)
+        # RNNoise expects floats in the int16 range, not -1.0..1.0
+        self._pending = np.concatenate([self._pending, upsampled.astype(np.float32)])
+
+        frame_count = len(self._pending) // RNNOISE_FRAME_SAMPLES
+        denoised = np.empty(frame_count * RNNOISE_FRAME_SAMPLES, dtype=np.float32)
+        for index in range(frame_count):
+            start = index * RNNOISE_FRAME_SAMPLES
+            frame = np.ascontiguousarray(self._pending[start : start + RNNOISE_FRAME_SAMPLES])
+            self.speech_probability = self._library.rnnoise_process_frame(
+                self._state,
+                self._output.ctypes.data_as(_FloatPointer),
+                frame.ctypes.data_as(_FloatPointer),
+            )
+            denoised[start : start + RNNOISE_FRAME_SAMPLES] = self._output
+        self._pending = self._pending[frame_count * RNNOISE_FRAME_SAMPLES :]
+
+        samples = np.clip(np.round(denoised), -32768, 32767).astype(np.int16)
+        return self._downsampler.process(samples.tobytes())
+
+    def close(self):
+        """Free the RNNoise state."""
+        if self._state is not None:
+            self._library.rnnoise_destroy(self._state)
+            self._state = None
+
+
+def create_denoiser() -> Optional[RNNoiseDenoiser]:
+    """Create a denoiser, or return None (with a warning) if RNNoise isn't installed."""
+    library = load_rnnoise()
+    if library is None:
+        logger.warning("Noise suppression is on but librnnoise is not installed; skipping it")
+        return None
+    return RNNoiseDenoiser(library)
@@ -0,0 +1,76 @@
+"""
+Speech engine availability for Vocalinux.
+
+Every engine is an optional dependency, so an installation may lack some of
+them. This module detects which engines can be imported at runtime, so the
+UI only offers those, and defines the error raised when an engine that is
+not installed is requested.
+"""
+
+import importlib
+import logging
+from typing import Iterable, Optional
+
+logger = logging.getLogger(__name__)
+
+# Module each engine needs and how to install it
+ENGINE_REQUIREMENTS = {
+    "vosk": ("vosk", "pip install vosk"),
+    "whisper": ("whisper", "pip install 'vocalinux[whisper]'"),
+    "whisper_cpp": ("pywhispercpp.model", "pip install pywhispercpp"),
+    "remote_api": ("requests", "pip install requests"),
+}
+
+# Engines tried, in order, when the configured one is not installed. The remote
+# API is left out because it needs a server to be configured first.
+FALLBACK_ENGINES = ("whisper_cpp", "vosk", "whisper")
+
+
+class EngineNotInstalledError(ImportError):
+    """Raised when a speech engine whose dependencies are not installed is used."""
+
+    def __init__(self, engine: str, reason: str = ""):
+        self.engine = engine
+        self.install_hint = ENGINE_REQUIREMENTS.get(engine, ("", ""))[1]
+        message = f"Speech engine '{engine}' is not installed"
+        if self.install_hint:
+            message += f" (install with: {self.install_hint})"
+        if reason:
+            message += f": {reason}"
+        super().__init__(message)
+
+
+def is_engine_available(engine: str) -> bool:
+    """Whether the engine's dependencies can be imported."""
+    requirement = ENGINE_REQUIREMENTS.get(engine)
+    if requirement is None:
+        return False
+    try:
+        importlib.import_module(requirement[0])
+    except ImportError:
+        return False
+    except Exception as e:
+        # A broken install (e.g. a missing shared library) is as good as absent
+        logger.debug(f"Importing {requirement[0]} for {engine} failed: {e}")
+        return False
+    return True
+
+
+def get_available_engines() -> dict:
+    """
+    Detect which speech recognition engines are available/installed.
+
+    Returns:
+        Dictionary of engine name -> availability (bool)
+    """
+    engines = {engine: is_engine_available(engine) for engine in ENGINE_REQUIREMENTS}
+    logger.debug(f"Available engines: {engines}")
+    return engines
+
+
+def pick_available_engine(exclude: Iterable[str] = ()) -> Optional[str]:
+    """First installed engine from FALLBACK_ENGINES not in exclude, or None."""
+    for engine in FALLBACK_ENGINES:
+        if engine not in exclude and is_engine_available(engine):
+            return engine
+    return None
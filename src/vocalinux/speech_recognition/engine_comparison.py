@@ -0,0 +1,68 @@
+"""
+Engine comparison mode for Vocalinux.
+
+A debugging aid for choosing an engine: every utterance is also decoded by a
+second engine in the background, and both transcripts are reported side by
+side once each engine has finished. Only the primary engine's text is typed.
+"""
+
+import logging
+import threading
+from dataclasses import dataclass
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass(frozen=True)
+class EngineTranscript:
+    """One engine's transcript of an utterance."""
+
+    engine: str
+    text: Optional[str]  # None if the engine could not run
+    seconds: float  # Time taken to decode
+
+    def describe(self) -> str:
+        """The transcript for display, with the decode time."""
+        if self.text is None:
+            text = "(not available)"
+        else:
+            text = self.text.strip() or "(nothing recognized)"
+        return f"{text} [{self.seconds:.2f}s]"
+
+
+class UtteranceComparison:
+    """
+    Collects the primary and comparison transcripts of one utterance.
+
+    The callback runs once, on the thread that delivers the second transcript.
+    """
+
+    def __init__(self, on_complete: Callable[[EngineTranscript, EngineTranscript], None]):
+        self._on_complete = on_complete
+        self._primary: Optional[EngineTranscript] = None
+        self._comparison: Optional[EngineTranscript] = None
+        self._lock = threading.Lock()
+
+    def set_primary(self, transcript: EngineTranscript):
+        """Record the primary engine's transcript."""
+        with self._lock:
+            self._primary = transcript
+        self._complete_if_ready()
+
+    def set_comparison(self, transcript: EngineTranscript):
+        """Record the comparison engine's transcript."""
+        with self._lock:
+            self._comparison = transcript
+        self._complete_if_ready()
+
+    def _complete_if_ready(self):
+        with self._lock:
+            if self._primary is None or self._comparison is None or self._on_complete is None:
+                return
+            callback, self._on_complete = self._on_complete, None
+        logger.info(
+            f"Engine comparison: {self._primary.engine}: {self._primary.describe()} | "
+            f"{self._comparison.engine}: {self._comparison.describe()}"
+        )
+        callback(self._primary, self._comparison)
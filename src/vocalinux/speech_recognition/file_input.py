@@ -0,0 +1,193 @@
+"""
+Replaying a WAV file in place of the microphone for Vocalinux.
+
+Engine and VAD problems are hard to reproduce when they depend on someone
+speaking into a particular microphone. With `vocalinux --input-file FILE`,
+each dictation session plays the file instead: FileAudio stands in for
+PyAudio as a single input device that only opens in the file's own format,
+so the recording loop negotiates, converts, resamples, segments and
+transcribes it exactly as it would live audio. Reads are paced at real time
+(or a multiple of it), and once the file and a short tail of silence have
+been read the session stops by itself.
+"""
+
+import logging
+import os
+import threading
+import time
+import wave
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+# PCM sample widths in bytes, as the SAMPLE_FORMATS the capture path converts
+_WIDTH_FORMATS = {1: "uint8", 2: "int16", 3: "int24", 4: "int32"}
+
+# Silence played after the file, so a final utterance is cut by the normal
+# silence timeout rather than the end of the session
+DEFAULT_TAIL_SECONDS = 1.0
+
+
+class WavFileStream:
+    """Reads a WAV file like a PyAudio input stream."""
+
+    def __init__(
+        self,
+        path: str,
+        speed: float = 1.0,
+        tail_seconds: float = DEFAULT_TAIL_SECONDS,
+        clock: Callable[[], float] = time.monotonic,
+        sleep: Callable[[float], None] = time.sleep,
+    ):
+        """
+        Open the file.
+
+        Args:
+            path: The WAV file to play
+            speed: Multiple of real time to read at (0 = as fast as possible)
+            tail_seconds: Silence returned after the end of the file
+            clock: Replacement for time.monotonic, for tests
+            sleep: Replacement for time.sleep, for tests
+        """
+        self._wav = wave.open(path, "rb")
+        self.channels = self._wav.getnchannels()
+        self.sample_width = self._wav.getsampwidth()
+        self.rate = self._wav.getframerate()
+        self.speed = max(0.0, float(speed or 0))
+        self._tail_frames = int(tail_seconds * self.rate)
+        self._clock = clock
+        self._sleep = sleep
+        self._start: Optional[float] = None
+        self._frames_read = 0
+        self._closed = False
+        self.finished = False
+
+    def read(self, num_frames: int, exception_on_overflow: bool = True) -> bytes:
+        """
+        The next frames of the file, then silence, waiting to keep to the speed.
+
+        The read that reaches the end of the silent tail sets `finished`.
+
+        Raises:
+            EOFError: If called again once finished
+        """
+        if self.finished:
+            raise EOFError("End of the input file")
+        if self._start is None:
+            self._start = self._clock()
+        frame_bytes = self.channels * self.sample_width
+        data = self._wav.readframes(num_frames)
+        missing = num_frames - len(data) // frame_bytes
+        if missing > 0:
+            tail = min(missing, self._tail_frames)
+            self._tail_frames -= tail
+            silence = b"\x80" if self.sample_width == 1 else b"\x00"
+            data += silence * (tail * frame_bytes)
+            self.finished = tail < missing
+        self._frames_read += len(data) // frame_bytes
+
+        if self.speed:
+            ahead = self._frames_read / self.rate / self.speed - (self._clock() - self._start)
+            if ahead > 0:
+                self._sleep(ahead)
+        return data
+
+    def is_active(self) -> bool:
+        return not self._closed
+
+    def stop_stream(self):
+        pass
+
+    def close(self):
+        self._closed = True
+        self._wav.close()
+
+
+class FileAudio:
+    """A stand-in for PyAudio whose only input device is a WAV file."""
+
+    def __init__(self, path: str, speed: float = 1.0):
+        """
+        Read the file's format.
+
+        Args:
+            path: The WAV file to play
+            speed: Multiple of real time to play it at (0 = as fast as possible)
+
+        Raises:
+            OSError: If the file can't be opened
+            ValueError: If it isn't a WAV file of 8- to 32-bit PCM samples
+        """
+        self.path = path
+        self.speed = speed
+        try:
+            with wave.open(path, "rb") as wav_file:
+                self.channels = wav_file.getnchannels()
+                self.sample_width = wav_file.getsampwidth()
+                self.rate = wav_file.getframerate()
+                self.duration = wav_file.getnframes() / self.rate
+        except (EOFError, wave.Error) as e:
+            raise ValueError(f"Not a PCM WAV file: {e}") from e
+        if self.sample_width not in _WIDTH_FORMATS:
+            raise ValueError(f"Unsupported {self.sample_width * 8}-bit samples, expected PCM")
+        self._streams: list[WavFileStream] = []
+        self._lock = threading.Lock()
+
+    @property
+    def sample_format(self) -> str:
+        """The file's samples as one of SAMPLE_FORMATS."""
+        return _WIDTH_FORMATS[self.sample_width]
+
+    @property
+    def finished(self) -> bool:
+        """Whether a stream has played the whole file."""
+        with self._lock:
+            return any(stream.finished for stream in self._streams)
+
+    def _device_info(self) -> dict:
+        return {
+            "index": 0,
+            "name": f"File: {os.path.basename(self.path)}",
+            "maxInputChannels": self.channels,
+            "maxOutputChannels": 0,
+            "defaultSampleRate": float(self.rate),
+        }
+
+    def get_device_count(self) -> int:
+        return 1
+
+    def get_device_info_by_index(self, index: int) -> dict:
+        if index != 0:
+            raise OSError(f"Invalid device index {index}")
+        return self._device_info()
+
+    def get_default_input_device_info(self) -> dict:
+        return self._device_info()
+
+    def open(
+        self,
+        format=None,
+        channels: int = 1,
+        rate: int = 16000,
+        input: bool = False,
+        input_device_index: Optional[int] = None,
+        **kwargs,
+    ) -> WavFileStream:
+        """Open the file as an input stream, if asked for in exactly its format."""
+        import pyaudio
+
+        if not input or input_device_index not in (None, 0):
+            raise OSError("Only the input file can be opened")
+        if (
+            format != pyaudio.get_format_from_width(self.sample_width)
+            or channels != self.channels
+            or int(rate) != self.rate
+        ):
+            raise OSError("Invalid sample rate, format or channel count for the input file")
+        stream = WavFileStream(self.path, self.speed)
+        with self._lock:
+            self._streams.append(stream)
+        return stream
+
+    def terminate(self):
+        pass
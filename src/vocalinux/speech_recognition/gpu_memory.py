@@ -0,0 +1,111 @@
+"""
+GPU memory budget for Vocalinux's speech models.
+
+Users can keep part of their graphics card's memory free for other programs
+(games, other ML tools). Before a Whisper or whisper.cpp model is loaded on
+the GPU, its approximate VRAM use is compared with the card's total memory
+minus that reserve, and a smaller model is used when it would not fit.
+"""
+
+import logging
+import subprocess
+from typing import Callable, Optional, Sequence
+
+logger = logging.getLogger(__name__)
+
+# Approximate VRAM used by each OpenAI Whisper model, from the Whisper README
+WHISPER_VRAM_MB = {
+    "tiny": 1000,
+    "base": 1000,
+    "small": 2000,
+    "medium": 5000,
+    "large": 10000,
+}
+
+# whisper.cpp needs roughly the model file plus its compute buffers; this
+# matches the memory usage table in the whisper.cpp README
+_WHISPERCPP_OVERHEAD_FACTOR = 1.3
+_WHISPERCPP_OVERHEAD_MB = 200
+
+
+def whispercpp_vram_mb(model_file_mb: float) -> int:
+    """Estimate the VRAM a whisper.cpp model of this file size uses."""
+    return int(model_file_mb * _WHISPERCPP_OVERHEAD_FACTOR + _WHISPERCPP_OVERHEAD_MB)
+
+
+def total_vram_mb(torch_module=None) -> Optional[int]:
+    """
+    Total memory of the first GPU in MB.
+
+    Args:
+        torch_module: torch, when already imported and built with CUDA;
+            otherwise nvidia-smi is asked
+
+    Returns:
+        The memory in MB, or None when it can't be determined (no NVIDIA GPU,
+        or a driver without nvidia-smi)
+    """
+    if torch_module is not None:
+        try:
+            if torch_module.cuda.is_available():
+                return int(torch_module.cuda.get_device_properties(0).total_memory // (1024**2))
+        except (RuntimeError, AttributeError) as e:
+            logger.debug(f"Could not read GPU memory from torch: {e}")
+    try:
+        result = subprocess.run(
+            ["nvidia-smi", "--query-gpu=memory.total", "--format=csv,noheader,nounits"],
+            capture_output=True,
+            text=True,
+            timeout=5,
+        )
+        if result.returncode == 0 and result.stdout.strip():
+            return int(result.stdout.splitlines()[0].strip())
+    except (FileNotFoundError, OSError, subprocess.TimeoutExpired, ValueError) as e:
+        logger.debug(f"Could not read GPU memory from nvidia-smi: {e}")
+    return None
+
+
+def vram_budget_mb(reserve_mb: int, total_mb: Optional[int]) -> Optional[int]:
+    """
+    VRAM the speech model may use.
+
+    Args:
+        reserve_mb: Memory to keep free for other programs (0 = no limit)
+        total_mb: The GPU's total memory, None if unknown
+
+    Returns:
+        The budget in MB, or None when there is no limit to enforce
+    """
+    if reserve_mb <= 0 or total_mb is None:
+        return None
+    return max(0, total_mb - reserve_mb)
+
+
+def fit_model_to_budget(
+    requested: str,
+    sizes: Sequence[str],
+    vram_mb: Callable[[str], int],
+    budget_mb: int,
+) -> Optional[str]:
+    """
+    Pick the model to load within a VRAM budget.
+
+    Args:
+        requested: The configured model
+        sizes: Models to fall back to, smallest first
+        vram_mb: Estimated VRAM use of a model
+        budget_mb: VRAM the model may use
+
+    Returns:
+        The requested model if it fits, else the largest smaller one that
+        does, or None when not even the smallest fits
+    """
+    if vram_mb(requested) <= budget_mb:
+        return requested
+    smaller = list(sizes)
+    if requested in smaller:
+        smaller = smaller[: smaller.index(requested)]
+    for size in reversed(smaller):
+        if vram_mb(size) <= budget_mb:
+            return size
+    return None
@@ -0,0 +1,154 @@
+"""
+Assistant-style intent commands for Vocalinux.
+
+An optional layer after the command processor: when a whole utterance is
+"open <app>" or "search for <query>", the application is launched or a web
+search is opened in the browser instead of typing the words. Off unless
+enabled in the settings, since it turns dictated text into actions.
+"""
+
+import difflib
+import logging
+import re
+import urllib.parse
+import webbrowser
+from dataclasses import dataclass
+from typing import Callable, Iterable, Optional
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_SEARCH_URL = "https://duckduckgo.com/?q={query}"
+
+_OPEN_APP_RE = re.compile(r"^(?:open|launch|start)\s+(?:the\s+)?(?P<argument>.+)$", re.IGNORECASE)
+_WEB_SEARCH_RE = re.compile(
+    r"^(?:search|search the web|look up|google)(?:\s+for)?\s+(?P<argument>.+)$", re.IGNORECASE
+)
+
+# Trailing punctuation Whisper adds to a short utterance ("Open Firefox.")
+_TRAILING_PUNCTUATION = ".,!?;: "
+
+# How closely a spoken name must match an application name (difflib ratio)
+_APP_MATCH_CUTOFF = 0.75
+
+
+@dataclass(frozen=True)
+class Intent:
+    """An action the utterance asks for."""
+
+    kind: str  # "open_app" or "web_search"
+    argument: str  # Application name or search query
+
+
+def match_intent(text: str) -> Optional[Intent]:
+    """
+    Match an utterance against the intent patterns.
+
+    Returns:
+        The intent, or None if the text is ordinary dictation
+    """
+    text = text.strip().rstrip(_TRAILING_PUNCTUATION)
+    for kind, pattern in (("web_search", _WEB_SEARCH_RE), ("open_app", _OPEN_APP_RE)):
+        match = pattern.match(text)
+        if match:
+            argument = match.group("argument").strip().rstrip(_TRAILING_PUNCTUATION)
+            if argument:
+                return Intent(kind, argument)
+    return None
+
+
+def find_application(name: str, app_names: Iterable[str]) -> Optional[str]:
+    """
+    Pick the application whose name best matches a spoken name.
+
+    An exact (case-insensitive) match wins, then a name starting with the
+    spoken words ("firefox" -> "Firefox Web Browser"), then the closest
+    fuzzy match.
+
+    Returns:
+        The matching name from app_names, or None
+    """
+    spoken = name.lower()
+    names = {app_name.lower(): app_name for app_name in app_names}
+    if spoken in names:
+        return names[spoken]
+    prefixed = sorted(lowered for lowered in names if lowered.startswith(spoken + " "))
+    if prefixed:
+        return names[prefixed[0]]
+    close = difflib.get_close_matches(spoken, list(names), n=1, cutoff=_APP_MATCH_CUTOFF)
+    return names[close[0]] if close else None
+
+
+def launch_application(name: str) -> bool:
+    """
+    Launch the installed .desktop application matching a spoken name.
+
+    Returns:
+        True if an application was found and launched
+    """
+    try:
+        import gi
+
+        gi.require_version("Gio", "2.0")
+        from gi.repository import Gio
+    except (ImportError, ValueError) as e:
+        logger.warning(f"Cannot look up applications: {e}")
+        return False
+
+    apps = {app.get_display_name(): app for app in Gio.AppInfo.get_all() if app.should_show()}
+    match = find_application(name, apps)
+    if match is None:
+        logger.info(f"No application matches '{name}'")
+        return False
+    try:
+        apps[match].launch([], None)
+    except Exception as e:
+        logger.warning(f"Failed to launch {match}: {e}")
+        return False
+    logger.info(f"Launched {match}")
+    return True
+
+
+class IntentHandler:
+    """Carries out intents found in dictated text."""
+
+    def __init__(
+        self,
+        search_url: str = DEFAULT_SEARCH_URL,
+        launch_app: Callable[[str], bool] = launch_application,
+        open_url: Callable[[str], bool] = webbrowser.open,
+    ):
+        """
+        Initialize the handler.
+
+        Args:
+            search_url: Search engine URL with a {query} placeholder
+            launch_app: Launches an application by spoken name, returning success
+            open_url: Opens a URL in the browser
+        """
+        self.search_url = search_url
+        self._launch_app = launch_app
+        self._open_url = open_url
+
+    def search_url_for(self, query: str) -> str:
+        """The search URL for a query."""
+        template = self.search_url if "{query}" in (self.search_url or "") else DEFAULT_SEARCH_URL
+        return template.replace("{query}", urllib.parse.quote_plus(query))
+
+    def handle(self, text: str) -> bool:
+        """
+        Carry out the intent in the text, if any.
+
+        Returns:
+            True if the text was an intent that was carried out (and should
+            not be typed), False if it should be typed as usual
+        """
+        intent = match_intent(text)
+        if intent is None:
+            return False
+        if intent.kind == "web_search":
+            url = self.search_url_for(intent.argument)
+            logger.info(f"Searching the web for '{intent.argument}'")
+            self._open_url(url)
+            return True
+        # "open the door" is dictation unless an application by that name exists
+        return self._launch_app(intent.argument)
@@ -0,0 +1,123 @@
+"""
+Live microphone level for the Audio settings page of Vocalinux.
+
+While the Audio page is open, a LevelMonitor reads the selected microphone
+in a background thread and reports its smoothed level on the same 0-100
+scale as the other meters, so users can see the device picks up their voice
+without starting dictation. Nothing it reads is kept or transcribed.
+"""
+
+import logging
+import threading
+from typing import Callable, Optional
+
+from ..utils.echo_cancel import pulse_source
+from .audio_level import LevelMeter, dbfs_to_percent, rms_dbfs
+from .resample import ChannelDownmixer, apply_gain, to_int16
+
+logger = logging.getLogger(__name__)
+
+# Frames read from the microphone at a time
+CHUNK_FRAMES = 1024
+
+
+class LevelMonitor:
+    """Reads a microphone in the background and reports its level."""
+
+    def __init__(
+        self,
+        on_level: Callable[[float], None],
+        device_index: Optional[int] = None,
+        gain: float = 1.0,
+        monitor_source: Optional[str] = None,
+        on_dbfs: Optional[Callable[[float], None]] = None,
+    ):
+        """
+        Initialize the monitor.
+
+        Args:
+            on_level: Called with each level (0-100) from the monitor thread,
+                and with 0 when the monitor stops
+            device_index: The input device to read (None for the default)
+            gain: Software gain applied before measuring, as while dictating
+            monitor_source: PulseAudio monitor source to read through the
+                default input instead of a device
+            on_dbfs: Called with each chunk's unsmoothed level in dBFS, for
+                measuring rather than display
+        """
+        self._on_level = on_level
+        self.device_index = device_index
+        self.gain = gain
+        self.monitor_source = monitor_source
+        self._on_dbfs = on_dbfs
+        self._stop_event = threading.Event()
+        self._thread: Optional[threading.Thread] = None
+
+    @property
+    def running(self) -> bool:
+        return self._thread is not None and self._thread.is_alive()
+
+    def start(self):
+        """Start reading the microphone, unless already running."""
+        if self.running:
+            return
+        self._stop_event.clear()
+        self._thread = threading.Thread(target=self._run, name="level-monitor", daemon=True)
+        self._thread.start()
+
+    def stop(self):
+        """Stop reading; returns once the microphone is closed (or after a second)."""
+        self._stop_event.set()
+        thread, self._thread = self._thread, None
+        if thread is not None and thread is not threading.current_thread():
+            thread.join(timeout=1.0)
+
+    def _run(self):
+        try:
+            import numpy as np
+            import pyaudio
+        except ImportError as e:
+            logger.debug(f"Live level meter unavailable: {e}")
+            return
+        from .recognition_manager import negotiate_capture_format
+
+        audio = pyaudio.PyAudio()
+        stream = None
+        try:
+            device_index = self.device_index
+            if device_index is None:
+                device_index = audio.get_default_input_device_info().get("index")
+            # Opened like the dictation stream, so devices without 16-bit mono work here too
+            capture_format = negotiate_capture_format(audio, device_index)
+            stream_kwargs = {
+                "format": capture_format.pyaudio_format,
+                "channels": capture_format.channels,
+                "rate": capture_format.rate,
+                "input": True,
+                "frames_per_buffer": CHUNK_FRAMES,
+            }
+            if self.device_index is not None:
+                stream_kwargs["input_device_index"] = self.device_index
+            with pulse_source(self.monitor_source):
+                stream = audio.open(**stream_kwargs)
+
+            meter = LevelMeter()
+            downmixer = ChannelDownmixer(capture_format.channels)
+            while not self._stop_event.is_set():
+                data = stream.read(CHUNK_FRAMES, exception_on_overflow=False)
+                data = to_int16(data, capture_format.sample_format)
+                data = downmixer.process(data)
+                samples = np.frombuffer(apply_gain(data, self.gain), dtype=np.int16)
+                dbfs = rms_dbfs(samples)
+                if self._on_dbfs is not None:
+                    self._on_dbfs(dbfs)
+                level = meter.update(dbfs, CHUNK_FRAMES / capture_format.rate)
+                self._on_level(dbfs_to_percent(level))
+        except (IOError, OSError, ValueError) as e:
+            logger.debug(f"Live level meter stopped: {e}")
+        finally:
+            if stream is not None:
+                stream.stop_stream()
+                stream.close()
+            audio.terminate()
+            self._on_level(0.0)
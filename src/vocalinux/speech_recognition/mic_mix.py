@@ -0,0 +1,153 @@
+"""
+Mixing extra microphones into the capture for Vocalinux.
+
+Some users move between a headset and a desk microphone, and whichever is
+closer picks them up best. The selected input stays the primary stream that
+paces the recording loop; every extra microphone is read on its own thread,
+converted to 16 kHz mono and queued, and each primary chunk is averaged with
+the same number of samples from each queue. The devices' clocks drift apart
+slowly, so a queue that runs ahead is trimmed to keep the mix aligned, and a
+device that falls behind is left out of the chunk until it catches up.
+"""
+
+import logging
+import threading
+from typing import Optional
+
+import numpy as np
+
+from .buffer_pool import BufferPool
+from .resample import (
+    TARGET_SAMPLE_RATE,
+    ChannelDownmixer,
+    StreamResampler,
+    apply_gain,
+    to_int16,
+)
+
+logger = logging.getLogger(__name__)
+
+# Most audio an extra microphone may queue before its oldest samples are dropped
+MAX_MIX_LAG_SECONDS = 0.25
+
+_MAX_QUEUED_BYTES = int(TARGET_SAMPLE_RATE * MAX_MIX_LAG_SECONDS) * 2
+
+
+class _ExtraInput:
+    """One extra microphone, read into a queue of 16 kHz mono 16-bit audio."""
+
+    def __init__(self, name: str, stream, capture_format, gain: float, frames_per_buffer: int):
+        self.name = name
+        self._stream = stream
+        self._format = capture_format
+        self._gain = gain
+        self._frames_per_buffer = frames_per_buffer
+        self._resampler = StreamResampler(capture_format.rate)
+        self._downmixer = ChannelDownmixer(capture_format.channels)
+        self._pool = BufferPool()  # Used only by the reader thread
+        self._queued = bytearray()
+        self._lock = threading.Lock()
+        self._running = True
+        self._thread = threading.Thread(target=self._read, name=f"mic-mix-{name}", daemon=True)
+
+    def start(self):
+        self._thread.start()
+
+    def stop(self):
+        self._running = False
+        self._thread.join(timeout=1.0)
+        try:
+            self._stream.stop_stream()
+            self._stream.close()
+        except Exception as e:
+            logger.debug(f"Error closing extra microphone {self.name}: {e}")
+
+    def _read(self):
+        while self._running:
+            try:
+                data = self._stream.read(self._frames_per_buffer, exception_on_overflow=False)
+            except (IOError, OSError) as e:
+                logger.warning(f"Extra microphone {self.name} stopped, no longer mixed: {e}")
+                self._running = False
+                return
+            self.feed(data)
+
+    def feed(self, data: bytes):
+        """Convert raw captured audio and queue it."""
+        data = to_int16(data, self._format.sample_format)
+        data = self._downmixer.process(data)
+        data = apply_gain(data, self._gain, self._pool)
+        data = self._resampler.process(data)
+        with self._lock:
+            self._queued += data
+            excess = len(self._queued) - _MAX_QUEUED_BYTES
+            if excess > 0:
+                del self._queued[:excess]
+
+    def take(self, size: int) -> Optional[bytes]:
+        """The next `size` bytes, or None if fewer are queued."""
+        with self._lock:
+            if len(self._queued) < size:
+                return None
+            data = bytes(self._queued[:size])
+            del self._queued[:size]
+        return data
+
+
+class MicMixer:
+    """Averages the extra microphones into the primary capture."""
+
+    def __init__(self):
+        self._inputs: list[_ExtraInput] = []
+        self._pool = BufferPool()
+
+    @property
+    def device_names(self) -> list:
+        return [extra.name for extra in self._inputs]
+
+    def add(
+        self, name: str, stream, capture_format, gain: float = 1.0, frames_per_buffer: int = 1024
+    ):
+        """
+        Start reading an opened extra microphone.
+
+        Args:
+            name: Device name, for logs
+            stream: The open PyAudio input stream
+            capture_format: The CaptureFormat the stream was opened with
+            gain: Software gain for this microphone
+            frames_per_buffer: Frames per read
+        """
+        extra = _ExtraInput(name, stream, capture_format, gain, frames_per_buffer)
+        self._inputs.append(extra)
+        extra.start()
+
+    def mix(self, data: bytes) -> bytes:
+        """
+        Mix the extra microphones into a chunk of the primary capture.
+
+        Args:
+            data: 16 kHz mono 16-bit audio from the primary microphone
+
+        Returns:
+            The mixed chunk, the same length as `data`
+        """
+        chunks = [chunk for chunk in (extra.take(len(data)) for extra in self._inputs) if chunk]
+        if not chunks or not data:
+            return data
+        samples = np.frombuffer(data, dtype=np.int16)
+        mixed = self._pool.take("mix", len(samples), np.int32)
+        mixed[:] = samples
+        for chunk in chunks:
+            mixed += np.frombuffer(chunk, dtype=np.int16)
+        mixed //= len(chunks) + 1
+        result = self._pool.take("mix_int16", len(samples), np.int16)
+        np.copyto(result, mixed, casting="unsafe")
+        return result.tobytes()
+
+    def stop(self):
+        """Stop reading and close the extra microphones."""
+        for extra in self._inputs:
+            extra.stop()
+        self._inputs = []
+        self._pool.clear()
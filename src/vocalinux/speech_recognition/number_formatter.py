@@ -0,0 +1,285 @@
+"""
+Number formatting for Vocalinux.
+
+Converts spoken numbers in transcripts to their written form: digits for
+numbers mode, plus the optional ordinal, fraction and Roman numeral styles
+enabled in the "formatting" config section.
+"""
+
+import logging
+import re
+
+logger = logging.getLogger(__name__)
+
+# Spoken number words
+_NUMBER_UNITS = {
+    "zero": 0,
+    "oh": 0,
+    "one": 1,
+    "two": 2,
+    "three": 3,
+    "four": 4,
+    "five": 5,
+    "six": 6,
+    "seven": 7,
+    "eight": 8,
+    "nine": 9,
+}
+_NUMBER_TEENS = {
+    "ten": 10,
+    "eleven": 11,
+    "twelve": 12,
+    "thirteen": 13,
+    "fourteen": 14,
+    "fifteen": 15,
+    "sixteen": 16,
+    "seventeen": 17,
+    "eighteen": 18,
+    "nineteen": 19,
+}
+_NUMBER_TENS = {
+    "twenty": 20,
+    "thirty": 30,
+    "forty": 40,
+    "fifty": 50,
+    "sixty": 60,
+    "seventy": 70,
+    "eighty": 80,
+    "ninety": 90,
+}
+_NUMBER_SCALES = {"thousand": 1000, "million": 1000000}
+
+_ORDINAL_WORDS = {
+    "first": 1,
+    "second": 2,
+    "third": 3,
+    "fourth": 4,
+    "fifth": 5,
+    "sixth": 6,
+    "seventh": 7,
+    "eighth": 8,
+    "ninth": 9,
+    "tenth": 10,
+    "eleventh": 11,
+    "twelfth": 12,
+    **{f"{word}th": value for word, value in _NUMBER_TEENS.items() if value >= 13},
+    **{f"{word[:-1]}ieth": value for word, value in _NUMBER_TENS.items()},
+}
+
+# Words before "second" that mean the unit of time, not the ordinal
+_SECOND_AS_TIME = frozenset({"a", "one", "per", "split"})
+
+_FRACTION_DENOMINATORS = {
+    "half": 2,
+    "halves": 2,
+    "third": 3,
+    "thirds": 3,
+    "quarter": 4,
+    "quarters": 4,
+    "fourth": 4,
+    "fourths": 4,
+    "fifth": 5,
+    "fifths": 5,
+    "sixth": 6,
+    "sixths": 6,
+    "seventh": 7,
+    "sevenths": 7,
+    "eighth": 8,
+    "eighths": 8,
+    "ninth": 9,
+    "ninths": 9,
+    "tenth": 10,
+    "tenths": 10,
+}
+
+_ROMAN_NUMERALS = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+]
+
+
+def _alternation(words) -> str:
+    return "|".join(sorted(words, key=len, reverse=True))
+
+
+_UNIT_WORDS = [word for word in _NUMBER_UNITS if word != "oh"]
+
+_ORDINAL_PATTERN = re.compile(
+    rf"\b(?:({_alternation(_NUMBER_TENS)})[\s-]+)?({_alternation(_ORDINAL_WORDS)})\b",
+    re.IGNORECASE,
+)
+
+_FRACTION_PATTERN = re.compile(
+    rf"\b(?:(\d+|{_alternation(_UNIT_WORDS)})\s+and\s+)?"
+    rf"(\d+|an?|{_alternation(_UNIT_WORDS)})[\s-]+({_alternation(_FRACTION_DENOMINATORS)})\b",
+    re.IGNORECASE,
+)
+
+_CARDINAL_WORD = _alternation(
+    [*_UNIT_WORDS, *_NUMBER_TEENS, *_NUMBER_TENS, r"hundred(?:\s+and)?", "thousand"]
+)
+_ROMAN_PATTERN = re.compile(
+    rf"\broman\s+numerals?\s+(\d+|(?:{_CARDINAL_WORD})(?:[\s-]+(?:{_CARDINAL_WORD}))*)\b",
+    re.IGNORECASE,
+)
+
+
+def spoken_to_digits(text: str) -> str:
+    """
+    Convert spoken numbers to digits, dropping every other word.
+
+    Digits spoken one at a time are kept separate ("four two" -> "42"), while
+    compound numbers are combined ("forty two" -> "42", "one hundred five" -> "105").
+    "point" and "dot" insert a decimal point.
+    """
+    output = []
+    total = 0
+    group = None  # Value being built below one thousand; None when idle
+    last = None  # Kind of the previous number word
+
+    def flush():
+        nonlocal total, group, last
+        if group is not None:
+            output.append(str(total + group))
+        total = 0
+        group = None
+        last = None
+
+    for token in re.findall(r"[a-z]+|\d+(?:\.\d+)?", text.lower()):
+        if token[0].isdigit():
+            flush()
+            output.append(token)
+        elif token in ("point", "dot"):
+            flush()
+            output.append(".")
+        elif token in _NUMBER_UNITS:
+            value = _NUMBER_UNITS[token]
+            if group is not None and last in ("tens", "hundred", "scale") and group % 10 == 0:
+                group += value
+                last = "unit"
+            else:
+                flush()
+                group = value
+                last = "unit"
+        elif token in _NUMBER_TEENS or token in _NUMBER_TENS:
+            value = _NUMBER_TEENS.get(token, _NUMBER_TENS.get(token))
+            if group is not None and last in ("hundred", "scale") and group % 100 == 0:
+                group += value
+            else:
+                flush()
+                group = value
+            last = "teen" if token in _NUMBER_TEENS else "tens"
+        elif token == "hundred":
+            group = (group or 1) * 100
+            last = "hundred"
+        elif token in _NUMBER_SCALES:
+            total += (group or 1) * _NUMBER_SCALES[token]
+            group = 0
+            last = "scale"
+        # Any other word is not a number and is dropped
+
+    flush()
+    return "".join(output)
+
+
+def _number_value(word: str) -> int:
+    """Value of a digit string or a single unit word."""
+    return int(word) if word.isdigit() else _NUMBER_UNITS[word.lower()]
+
+
+def ordinal_suffix(value: int) -> str:
+    """Return the English ordinal suffix for a number ("st", "nd", "rd" or "th")."""
+    if 10 <= value % 100 <= 20:
+        return "th"
+    return {1: "st", 2: "nd", 3: "rd"}.get(value % 10, "th")
+
+
+def to_roman(value: int) -> str:
+    """Convert 1-3999 to an uppercase Roman numeral."""
+    if not 1 <= value <= 3999:
+        raise ValueError(f"Roman numerals cover 1-3999, got {value}")
+    numeral = []
+    for amount, symbol in _ROMAN_NUMERALS:
+        count, value = divmod(value, amount)
+        numeral.append(symbol * count)
+    return "".join(numeral)
+
+
+class NumberFormatter:
+    """
+    Applies the optional number styles from the "formatting" config section.
+
+    - ordinals: "third" -> "3rd", "twenty-first" -> "21st"
+    - fractions: "three quarters" -> "3/4", "one and a half" -> "1 1/2"
+    - roman_numerals: "roman numeral four" -> "IV"
+    """
+
+    def __init__(
+        self, ordinals: bool = False, fractions: bool = False, roman_numerals: bool = False
+    ):
+        self.ordinals = ordinals
+        self.fractions = fractions
+        self.roman_numerals = roman_numerals
+
+    def format(self, text: str) -> str:
+        """Rewrite spoken numbers in the text according to the enabled styles."""
+        if not text:
+            return text
+        # Roman numerals first, then fractions, so "one third" is not read as an ordinal
+        if self.roman_numerals:
+            text = _ROMAN_PATTERN.sub(self._replace_roman, text)
+        if self.fractions:
+            text = _FRACTION_PATTERN.sub(self._replace_fraction, text)
+        if self.ordinals:
+            text = _ORDINAL_PATTERN.sub(self._replace_ordinal, text)
+        return text
+
+    @staticmethod
+    def _replace_roman(match: re.Match) -> str:
+        digits = spoken_to_digits(match.group(1))
+        if not digits.isdigit() or not 1 <= int(digits) <= 3999:
+            return match.group(0)
+        return to_roman(int(digits))
+
+    @staticmethod
+    def _replace_fraction(match: re.Match) -> str:
+        whole, numerator, denominator = match.groups()
+        if numerator.lower() in ("a", "an"):
+            # "a quarter" is usually about time ("a quarter past")
+            if denominator.lower() == "quarter":
+                return match.group(0)
+            numerator_value = 1
+        else:
+            numerator_value = _number_value(numerator)
+        fraction = f"{numerator_value}/{_FRACTION_DENOMINATORS[denominator.lower()]}"
+        if whole:
+            return f"{_number_value(whole)} {fraction}"
+        return fraction
+
+    @staticmethod
+    def _replace_ordinal(match: re.Match) -> str:
+        tens, word = match.groups()
+        value = _ORDINAL_WORDS[word.lower()]
+
+        if tens and value < 10:
+            value += _NUMBER_TENS[tens.lower()]
+            prefix = ""
+        else:
+            prefix = f"{tens} " if tens else ""
+            if word.lower() == "second":
+                preceding = match.string[: match.start(2)].split()
+                if not tens and preceding and preceding[-1].lower() in _SECOND_AS_TIME:
+                    return match.group(0)
+
+        return f"{prefix}{value}{ordinal_suffix(value)}"
@@ -0,0 +1,65 @@
+"""
+Adapting the silence timeout to the speaker for Vocalinux.
+
+A fixed silence timeout suits nobody exactly: people who pause to think in
+mid-sentence get cut off, while fast speakers wait for text that could have
+been typed already. With the adaptive timeout on, every pause that doesn't
+end a segment is measured, and the timeout is kept a margin above the
+speaker's longer pauses: it lengthens for slow, pausing speech and shortens
+for fast speech. The fixed silence_timeout is used until enough pauses have
+been heard.
+"""
+
+from collections import deque
+
+# Range the adaptive timeout stays in; the same as the fixed setting's
+MIN_ADAPTIVE_TIMEOUT = 0.5
+MAX_ADAPTIVE_TIMEOUT = 5.0
+
+# Shorter silences are gaps inside words or detector jitter, not pauses
+MIN_PAUSE_SECONDS = 0.15
+
+# Pauses remembered, and how many are needed before the timeout adapts
+PAUSE_HISTORY = 30
+MIN_PAUSES = 5
+
+# The timeout is this many times the speaker's longer pauses (the 90th percentile)
+PAUSE_MARGIN = 1.5
+_PAUSE_PERCENTILE = 0.9
+
+
+class AdaptiveSilenceTimeout:
+    """Learns a silence timeout from the pauses between words."""
+
+    def __init__(self):
+        self._pauses = deque(maxlen=PAUSE_HISTORY)
+
+    def add_pause(self, seconds: float):
+        """Record a pause after which the speaker carried on."""
+        if seconds >= MIN_PAUSE_SECONDS:
+            self._pauses.append(seconds)
+
+    def reset(self):
+        """Forget the pauses heard so far."""
+        self._pauses.clear()
+
+    @property
+    def pause_count(self) -> int:
+        return len(self._pauses)
+
+    def timeout(self, fixed_timeout: float) -> float:
+        """
+        The silence timeout for the pauses heard so far.
+
+        Args:
+            fixed_timeout: The configured silence_timeout, used until
+                MIN_PAUSES pauses have been heard
+
+        Returns:
+            Seconds of silence that end a segment
+        """
+        if len(self._pauses) < MIN_PAUSES:
+            return fixed_timeout
+        pauses = sorted(self._pauses)
+        long_pause = pauses[min(len(pauses) - 1, int(len(pauses) * _PAUSE_PERCENTILE))]
+        return max(MIN_ADAPTIVE_TIMEOUT, min(MAX_ADAPTIVE_TIMEOUT, long_pause * PAUSE_MARGIN))
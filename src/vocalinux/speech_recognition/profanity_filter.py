@@ -0,0 +1,182 @@
+"""
+Profanity filtering for Vocalinux.
+
+Masks or removes profanity from final transcripts before they are typed,
+for users dictating in shared or work settings. Word lists are kept per
+language and can be extended from the "formatting" config section.
+"""
+
+import re
+from typing import Iterable, Optional
+
+PROFANITY_FILTER_MODES = ("off", "mask", "remove")
+
+# Base word lists by language code. Common inflections are listed explicitly
+# rather than matched by prefix, so words like "class" or "Scunthorpe" are safe.
+PROFANITY_WORDS = {
+    "en": (
+        "fuck",
+        "fucks",
+        "fucked",
+        "fucker",
+        "fuckers",
+        "fucking",
+        "motherfucker",
+        "motherfucking",
+        "shit",
+        "shits",
+        "shitty",
+        "bullshit",
+        "bitch",
+        "bitches",
+        "bastard",
+        "bastards",
+        "asshole",
+        "assholes",
+        "dick",
+        "dickhead",
+        "cunt",
+        "cunts",
+        "piss",
+        "pissed",
+        "wanker",
+        "bollocks",
+        "goddamn",
+        "damn",
+        "crap",
+    ),
+    "es": (
+        "mierda",
+        "joder",
+        "jodido",
+        "puta",
+        "putas",
+        "puto",
+        "cabrón",
+        "cabrones",
+        "coño",
+        "gilipollas",
+        "pendejo",
+        "pendejos",
+        "carajo",
+    ),
+    "fr": (
+        "merde",
+        "putain",
+        "connard",
+        "connards",
+        "connasse",
+        "salope",
+        "enculé",
+        "bordel",
+        "chier",
+    ),
+    "de": (
+        "scheiße",
+        "scheisse",
+        "scheiß",
+        "arschloch",
+        "fotze",
+        "wichser",
+        "verdammt",
+        "hure",
+        "mist",
+    ),
+    "it": (
+        "cazzo",
+        "merda",
+        "stronzo",
+        "stronzi",
+        "vaffanculo",
+        "puttana",
+        "coglione",
+    ),
+    "pt": (
+        "merda",
+        "porra",
+        "caralho",
+        "puta",
+        "foda-se",
+        "cacete",
+    ),
+}
+
+
+def _language_words(language: str) -> tuple[str, ...]:
+    """Word list for a language code such as "en-us"; every list for "auto"."""
+    if language == "auto":
+        return tuple(word for words in PROFANITY_WORDS.values() for word in words)
+    return PROFANITY_WORDS.get(language.split("-")[0].lower(), ())
+
+
+def _mask_word(word: str) -> str:
+    """Keep the first letter and replace the rest with asterisks ("shit" -> "s***")."""
+    return word[0] + "*" * (len(word) - 1)
+
+
+class ProfanityFilter:
+    """
+    Masks or removes profanity according to the configured mode.
+
+    - off: text is left unchanged
+    - mask: "this is shit" -> "this is s***"
+    - remove: "this is shit" -> "this is"
+    """
+
+    def __init__(self, mode: str = "off", custom_words: Iterable[str] = ()):
+        self.mode = mode
+        self._patterns: dict[str, Optional[re.Pattern]] = {}
+        self.custom_words = custom_words
+
+    @property
+    def mode(self) -> str:
+        return self._mode
+
+    @mode.setter
+    def mode(self, mode: str):
+        if mode not in PROFANITY_FILTER_MODES:
+            raise ValueError(f"Unknown profanity filter mode: {mode}")
+        self._mode = mode
+
+    @property
+    def custom_words(self) -> list[str]:
+        return list(self._custom_words)
+
+    @custom_words.setter
+    def custom_words(self, words: Iterable[str]):
+        self._custom_words = [word.strip() for word in words if word.strip()]
+        # Compiled patterns include the custom words, so rebuild them lazily
+        self._patterns = {}
+
+    def _pattern(self, language: str) -> Optional[re.Pattern]:
+        if language not in self._patterns:
+            words = {word.lower() for word in (*_language_words(language), *self._custom_words)}
+            if words:
+                alternation = "|".join(
+                    re.escape(word) for word in sorted(words, key=len, reverse=True)
+                )
+                self._patterns[language] = re.compile(
+                    rf"(?<!\w)(?:{alternation})(?!\w)", re.IGNORECASE
+                )
+            else:
+                self._patterns[language] = None
+        return self._patterns[language]
+
+    def apply(self, text: str, language: str = "en") -> str:
+        """Return the text with profanity masked or removed for the given language."""
+        if not text or self._mode == "off":
+            return text
+        pattern = self._pattern(language)
+        if pattern is None:
+            return text
+
+        if self._mode == "mask":
+            return pattern.sub(lambda match: _mask_word(match.group(0)), text)
+
+        text = pattern.sub("", text)
+        # Tidy the gaps left behind: doubled spaces, spaces before punctuation and
+        # separators left with nothing to separate ("great, shit." -> "great.")
+        text = re.sub(r"[ \t]{2,}", " ", text)
+        text = re.sub(r"\s+([.,!?;:])", r"\1", text)
+        text = re.sub(r"[,;:]+(?=[.,!?;:]|\s*$)", "", text)
+        return text.strip(" \t,;:")
@@ -0,0 +1,73 @@
+"""
+Whisper prompt presets chosen by the focused application for Vocalinux.
+
+Whisper continues the style of the prompt it is given, so the same words come
+out better with a prompt that suits what is being written: shell commands in
+a terminal, salutations and sign-offs in an email client. Prompt presets are
+named prompts, and app rules attach a preset to applications by their window
+class. When dictation starts, the focused window's class picks the preset,
+which is added to the prompt Whisper and whisper.cpp decode with.
+"""
+
+import logging
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+# Presets available without configuring any; presets in the config add to or replace these
+BUILTIN_PROMPT_PRESETS = {
+    "shell": (
+        "ls -la ~/projects, cd src/, git commit -m, sudo apt install, grep -rn, "
+        "chmod +x ./build.sh, ssh user@host, | less, --help"
+    ),
+    "email": (
+        "Hi Sam, Thanks for getting back to me. Dear Ms. Smith, I hope this email finds you "
+        "well. Kind regards, Best wishes,"
+    ),
+}
+
+
+class AppPrompts:
+    """Picks the prompt preset for the focused application."""
+
+    def __init__(self, presets: Optional[dict] = None, rules: Optional[dict] = None):
+        """
+        Initialize the rules.
+
+        Args:
+            presets: Prompt preset name -> prompt, on top of the built-in presets
+            rules: Application -> preset name. An application matches when its
+                window class contains it, ignoring case; the first match wins.
+        """
+        self.presets = {**BUILTIN_PROMPT_PRESETS}
+        for name, prompt in (presets or {}).items():
+            self.presets[str(name).strip().lower()] = str(prompt).strip()
+
+        self.rules = {}
+        for app, preset in (rules or {}).items():
+            app, preset = str(app).strip().lower(), str(preset).strip().lower()
+            if not app:
+                continue
+            if preset not in self.presets:
+                logger.warning(f"App prompt rule {app!r} uses unknown preset {preset!r}, ignored")
+                continue
+            self.rules[app] = preset
+
+    @property
+    def enabled(self) -> bool:
+        return bool(self.rules)
+
+    def preset_for(self, app: Optional[str]) -> Optional[str]:
+        """The name of the preset for an application's window class, if a rule matches."""
+        if not app:
+            return None
+        app = app.lower()
+        for rule, preset in self.rules.items():
+            if rule in app:
+                return preset
+        return None
+
+    def prompt_for(self, app: Optional[str]) -> str:
+        """The prompt for an application's window class ("" if no rule matches)."""
+        preset = self.preset_for(app)
+        return self.presets[preset] if preset else ""
@@ -0,0 +1,89 @@
+"""
+Auto-punctuation levels for Vocalinux.
+
+Punctuation can come from three places: the engine itself (Whisper writes
+punctuated sentences, VOSK does not), spoken commands ("comma", "period")
+and a light restoration pass for engines that don't punctuate. The level
+decides which of them make it into the typed text:
+
+- "off": no punctuation at all
+- "commands": only punctuation you speak; the engine's own is removed
+- "auto": the engine's punctuation plus spoken commands, and the
+  restoration pass for engines that don't punctuate
+
+Without a level set, each engine keeps its own behavior: its punctuation
+plus spoken commands, without the restoration pass.
+"""
+
+import logging
+import re
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+PUNCTUATION_LEVELS = ("off", "commands", "auto")
+
+# Engines whose transcripts are already punctuated
+NATIVE_PUNCTUATION_ENGINES = ("whisper", "whisper_cpp", "remote_api")
+
+# Sentence punctuation, except between digits (3.5, 10:30, 1,000)
+_PUNCTUATION_RE = re.compile(r"(?<!\d)[.,!?;:…¿¡]|[.,!?;:…¿¡](?!\d)")
+_SPACES_RE = re.compile(r"[ \t]{2,}")
+
+# Words that start a question, for the restoration pass
+_QUESTION_WORDS = {
+    "who",
+    "what",
+    "when",
+    "where",
+    "why",
+    "how",
+    "which",
+    "is",
+    "are",
+    "am",
+    "was",
+    "were",
+    "do",
+    "does",
+    "did",
+    "can",
+    "could",
+    "would",
+    "will",
+    "should",
+    "shall",
+    "have",
+    "has",
+}
+
+
+def valid_punctuation_level(level: Optional[str]) -> Optional[str]:
+    """The level if it is known, otherwise None (the engine default)."""
+    if level is None or level in PUNCTUATION_LEVELS:
+        return level
+    logger.warning(f"Unknown punctuation level {level!r}; using the engine default")
+    return None
+
+
+def strip_punctuation(text: str) -> str:
+    """Remove sentence punctuation, keeping decimals, times and line breaks."""
+    stripped = _PUNCTUATION_RE.sub("", text)
+    return _SPACES_RE.sub(" ", stripped).strip(" ")
+
+
+def restore_punctuation(text: str) -> str:
+    """
+    Punctuate an unpunctuated utterance as a sentence.
+
+    Capitalizes the first letter and ends the utterance with a period, or a
+    question mark when it starts with a question word. Text that already
+    ends in punctuation is left alone.
+    """
+    stripped = text.rstrip()
+    if not stripped or not stripped[-1].isalnum():
+        return text
+    first_word = stripped.split()[0].lower()
+    mark = "?" if first_word in _QUESTION_WORDS else "."
+    start = len(stripped) - len(stripped.lstrip())
+    return stripped[:start] + stripped[start].upper() + stripped[start + 1 :] + mark
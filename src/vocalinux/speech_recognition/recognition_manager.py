@@ -5,27 +5,112 @@ This module provides a unified interface to different speech recognition engines
 currently supporting VOSK, Whisper, and whisper.cpp.
 """
 
+import collections
+import concurrent.futures
 import ctypes
 import importlib.util
+import itertools
 import json
 import logging
+import math
 import os
 import queue
 import re
 import sys
 import threading
 import time
+from dataclasses import dataclass
 from pathlib import Path
 from typing import Callable, Optional
 
 from ..common_types import RecognitionState
-from ..ui.audio_feedback import play_error_sound, play_start_sound, play_stop_sound
+from ..ui.audio_feedback import (
+    play_error_sound,
+    play_start_sound,
+    play_stop_sound,
+    play_utterance_sound,
+)
+from ..utils import credentials, notifications
+from ..utils.audio_dump import AudioDumper
+from ..utils.device_monitor import DeviceChange, DeviceMonitor
+from ..utils.download_mirrors import DEFAULT_DOWNLOAD_MIRROR, BandwidthLimiter, mirrored_url
+from ..utils.echo_cancel import EchoCanceller, pulse_source
+from ..utils.language_support import validate_language_selection
+from ..utils.media_ducking import DEFAULT_DUCK_LEVEL, DUCK_RECHECK_SECONDS, MediaDucker
+from ..utils.mic_permission import MicrophonePermission, denied_message
 from ..utils.paths import models_dir
+from ..utils.pulse_sources import (
+    app_stream_name,
+    find_app_stream,
+    is_app_stream,
+    is_monitor_source,
+    is_source_muted,
+)
+from ..utils.self_playback import is_self_playback_active
+from ..utils.utterance_log import UtteranceLog
+from ..utils.vocabulary_packs import build_vocabulary_prompt
 from ..utils.vosk_model_info import VOSK_MODEL_INFO
+from ..utils.whispercpp_load_report import (
+    WhisperCppLoadReport,
+    capture_native_stderr,
+    parse_load_log,
+)
+from ..utils.whispercpp_model_info import MODEL_SIZES as WHISPERCPP_MODEL_SIZES
 from ..utils.whispercpp_model_info import WHISPERCPP_MODEL_INFO, get_model_path, is_model_downloaded
+from ..utils.whispercpp_model_info import get_model_size as get_whispercpp_model_size
 from ..version import __version__
+from .audio_chunk import AudioChunk, chunk_sources, missing_chunks
+from .audio_level import (
+    METER_FLOOR_DBFS,
+    LevelMeter,
+    amplitude_dbfs,
+    dbfs_to_percent,
+    rms_dbfs,
+)
+from .capitalization import TermCapitalizer
 from .command_processor import CommandProcessor
-from .silero_vad import SILERO_CHUNK_SIZE, load_silero_vad
+from .denoise import create_denoiser
+from .engine_availability import EngineNotInstalledError
+from .engine_comparison import EngineTranscript, UtteranceComparison
+from .gpu_memory import (
+    WHISPER_VRAM_MB,
+    fit_model_to_budget,
+    total_vram_mb,
+    vram_budget_mb,
+    whispercpp_vram_mb,
+)
+from .intent_commands import DEFAULT_SEARCH_URL, IntentHandler
+from .number_formatter import NumberFormatter
+from .pause_timing import AdaptiveSilenceTimeout
+from .profanity_filter import PROFANITY_FILTER_MODES, ProfanityFilter
+from .prompt_presets import AppPrompts
+from .punctuation import (
+    NATIVE_PUNCTUATION_ENGINES,
+    restore_punctuation,
+    strip_punctuation,
+    valid_punctuation_level,
+)
+from .resample import (
+    DEFAULT_DEVICE_GAIN_KEY,
+    MAX_DEVICE_GAIN,
+    MIN_DEVICE_GAIN,
+    SAMPLE_FORMATS,
+    TARGET_SAMPLE_RATE,
+    ChannelDownmixer,
+    StreamResampler,
+    apply_gain,
+    to_int16,
+)
+from .session_stats import DictationSession, format_elapsed
+from .silero_vad import load_silero_vad
+from .speaker_profile import (
+    DEFAULT_MAX_SPEAKER_DISTANCE,
+    SPK_MODEL_NAME,
+    SpeakerProfile,
+    find_spk_model,
+)
+from .text_pipeline import TextPipeline
+from .vad import VAD_BACKENDS, EnergyVAD, SileroDetector, VoiceActivityDetector, create_vad
 
 
 # ALSA error handler to suppress warnings during PyAudio initialization
@@ -254,6 +339,114 @@ def get_audio_input_devices() -> list:
     return devices
 
 
+def get_input_device_capabilities() -> list[dict]:
+    """
+    Probe what each input device accepts, without opening any streams.
+
+    Returns:
+        One dict per non-virtual input device with keys ``index``, ``name``,
+        ``is_default``, ``default_rate``, ``max_channels``, ``rates`` (Hz),
+        ``channels`` and ``formats``; a rate or channel count is listed if it
+        works with any of the SAMPLE_FORMATS the capture path converts
+    """
+    capabilities = []
+    try:
+        import pyaudio
+    except ImportError:
+        logger.error("PyAudio not installed, cannot probe audio devices")
+        return capabilities
+
+    format_labels = {
+        "int16": "16-bit int",
+        "float32": "32-bit float",
+        "int32": "32-bit int",
+        "int24": "24-bit int",
+        "uint8": "8-bit uint",
+    }
+    formats = {format_labels[name]: _pyaudio_format(name) for name in SAMPLE_FORMATS}
+    common_rates = [48000, 44100, 32000, 22050, 16000, 8000]
+
+    def supports(rate, channels, sample_format, device_index):
+        try:
+            return bool(
+                audio.is_format_supported(
+                    rate,
+                    input_device=device_index,
+                    input_channels=channels,
+                    input_format=sample_format,
+                )
+            )
+        except ValueError:
+            return False
+
+    try:
+        audio = pyaudio.PyAudio()
+    except OSError as e:
+        logger.error(f"Error probing audio devices: {e}")
+        return capabilities
+
+    try:
+        try:
+            default_index = audio.get_default_input_device_info().get("index")
+        except (IOError, OSError):
+            default_index = None
+
+        for i in range(audio.get_device_count()):
+            try:
+                info = audio.get_device_info_by_index(i)
+            except (IOError, OSError):
+                continue
+            max_channels = int(info.get("maxInputChannels", 0))
+            name = info.get("name", f"Device {i}")
+            if max_channels <= 0 or _is_virtual_device(name):
+                continue
+
+            default_rate = int(info.get("defaultSampleRate", 0))
+            rates = sorted({default_rate, *common_rates} - {0}, reverse=True)
+            channel_options = [
+                ch for ch in _channel_counts_to_try(max_channels) if ch <= max_channels
+            ]
+            capabilities.append(
+                {
+                    "index": i,
+                    "name": name,
+                    "is_default": i == default_index,
+                    "default_rate": default_rate,
+                    "max_channels": max_channels,
+                    "rates": [
+                        rate
+                        for rate in rates
+                        if any(
+                            supports(rate, ch, sample_format, i)
+                            for sample_format in formats.values()
+                            for ch in channel_options
+                        )
+                    ],
+                    "channels": [
+                        ch
+                        for ch in channel_options
+                        if any(
+                            supports(rate, ch, sample_format, i)
+                            for sample_format in formats.values()
+                            for rate in rates
+                        )
+                    ],
+                    "formats": [
+                        label
+                        for label, sample_format in formats.items()
+                        if any(
+                            supports(default_rate or 48000, ch, sample_format, i)
+                            for ch in channel_options
+                        )
+                    ],
+                }
+            )
+    finally:
+        audio.terminate()
+
+    return capabilities
+
+
 def _resolve_device_by_name(
     audio, device_name: Optional[str], fallback_index: Optional[int] = None
 ) -> Optional[int]:
@@ -285,6 +478,22 @@ def _resolve_device_by_name(
     return _resolve_valid_input_device(audio, fallback_index)
 
 
+def _find_input_device(audio, device_name: str) -> Optional[int]:
+    """The index of the input device with this exact name, or None (no fallback)."""
+    try:
+        device_count = int(audio.get_device_count())
+    except (IOError, OSError, TypeError, ValueError, AttributeError):
+        return None
+    for i in range(device_count):
+        try:
+            info = audio.get_device_info_by_index(i)
+        except (IOError, OSError, TypeError, ValueError, AttributeError):
+            continue
+        if info.get("name") == device_name and info.get("maxInputChannels", 0) > 0:
+            return i
+    return None
+
+
 def _resolve_valid_input_device(audio, preferred_index: Optional[int] = None) -> Optional[int]:
     """Resolve a valid audio input device, skipping output-only devices (e.g. HDMI).
 
@@ -357,13 +566,25 @@ def _resolve_valid_input_device(audio, preferred_index: Optional[int] = None) ->
     return input_device_indices[0]
 
 
-def _get_supported_channels(audio, device_index: Optional[int] = None) -> int:
+def _channel_counts_to_try(max_channels: int) -> list[int]:
+    """Channel counts to probe, mono first; multi-channel interfaces up to their maximum."""
+    counts = [1, 2]
+    if max_channels > 2:
+        counts += [n for n in (4, 6, 8) if n < max_channels] + [max_channels]
+    return counts
+
+
+def _get_supported_channels(
+    audio, device_index: Optional[int] = None, sample_format: str = "int16"
+) -> int:
     """
     Detect the supported number of channels for the audio device.
 
     Some audio devices (particularly professional audio interfaces and certain
     onboard audio chips) only support specific channel configurations. This
-    function tests mono (1) and stereo (2) to find a working configuration.
+    function tests mono (1), stereo (2) and then larger counts up to the
+    device's maximum (4-channel USB interfaces, for example) to find a working
+    configuration; the audio is downmixed to mono after capture.
 
     Pro-audio USB interfaces (MUPRO, Vocaster, etc.) often only support 48kHz
     and will reject 16kHz probes. This function uses the device's default
@@ -372,18 +593,18 @@ def _get_supported_channels(audio, device_index: Optional[int] = None) -> int:
     Args:
         audio: PyAudio instance
         device_index: The device index to test (None for default)
+        sample_format: Sample format to probe with (one of SAMPLE_FORMATS)
 
     Returns:
-        int: Number of channels supported (1 or 2), defaults to 1
+        int: Number of channels supported, defaults to 1
     """
-    import pyaudio
-
-    FORMAT = pyaudio.paInt16
+    FORMAT = _pyaudio_format(sample_format)
     CHUNK = 1024
 
     COMMON_RATES = [48000, 44100, 32000, 22050, 16000, 8000]
 
     rates_to_try = []
+    max_channels = 2
     try:
         if device_index is not None:
             device_info = audio.get_device_info_by_index(device_index)
@@ -394,14 +615,17 @@ def _get_supported_channels(audio, device_index: Optional[int] = None) -> int:
         if default_rate > 0:
             rates_to_try.append(default_rate)
             logger.debug(f"Device reports default sample rate: {default_rate}Hz")
+        max_channels = int(device_info.get("maxInputChannels", 2))
     except (IOError, OSError) as e:
         logger.debug(f"Could not get device info for channel probing: {e}")
+    except (TypeError, ValueError):
+        pass
 
     for rate in COMMON_RATES:
         if rate not in rates_to_try:
             rates_to_try.append(rate)
 
-    for channels in [1, 2]:
+    for channels in _channel_counts_to_try(max_channels):
         for rate in rates_to_try:
             try:
                 stream_kwargs = {
@@ -430,7 +654,13 @@ def _get_supported_channels(audio, device_index: Optional[int] = None) -> int:
     return 1
 
 
-def _get_supported_sample_rate(audio, device_index: Optional[int], channels: int = 1) -> int:
+def _get_supported_sample_rate(
+    audio,
+    device_index: Optional[int],
+    channels: int = 1,
+    preferred_rate: Optional[int] = None,
+    sample_format: str = "int16",
+) -> int:
     """
     Get a supported sample rate for the audio device.
 
@@ -442,18 +672,41 @@ def _get_supported_sample_rate(audio, device_index: Optional[int], channels: int
         audio: PyAudio instance
         device_index: The device index to test
         channels: Number of channels (default 1)
+        preferred_rate: Rate to use if the device accepts it (None = pick automatically)
+        sample_format: Sample format to probe with (one of SAMPLE_FORMATS)
 
     Returns:
         int: A supported sample rate, defaulting to 16000 if none work
     """
-    import pyaudio
-
-    FORMAT = pyaudio.paInt16
+    FORMAT = _pyaudio_format(sample_format)
     CHUNK = 1024
 
     # Common sample rates to try, ordered from highest to lowest quality
     COMMON_RATES = [48000, 44100, 32000, 22050, 16000, 8000]
 
+    # A configured override wins whenever the device can open at that rate
+    if preferred_rate:
+        try:
+            stream_kwargs = {
+                "format": FORMAT,
+                "channels": channels,
+                "rate": int(preferred_rate),
+                "input": True,
+                "frames_per_buffer": CHUNK,
+            }
+            if device_index is not None:
+                stream_kwargs["input_device_index"] = device_index
+
+            test_stream = audio.open(**stream_kwargs)
+            test_stream.close()
+            logger.debug(f"Using configured sample rate: {preferred_rate}Hz")
+            return int(preferred_rate)
+        except (IOError, OSError, ValueError) as e:
+            logger.warning(
+                f"Device does not support the configured sample rate {preferred_rate}Hz ({e}); "
+                "picking one automatically"
+            )
+
     # First, try the device's default sample rate
     try:
         if device_index is not None:
@@ -509,13 +762,157 @@ def _get_supported_sample_rate(audio, device_index: Optional[int], channels: int
     return 16000
 
 
-def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
+def _pyaudio_format(sample_format: str) -> int:
+    """The PyAudio constant for one of SAMPLE_FORMATS."""
+    import pyaudio
+
+    return {
+        "int16": pyaudio.paInt16,
+        "float32": pyaudio.paFloat32,
+        "int32": pyaudio.paInt32,
+        "int24": pyaudio.paInt24,
+        "uint8": pyaudio.paUInt8,
+    }[sample_format]
+
+
+def _get_supported_sample_format(audio, device_index: Optional[int]) -> str:
+    """
+    Find a sample format the device opens with, preferring 16-bit ints.
+
+    PortAudio usually converts to 16-bit itself, but some devices (many
+    PipeWire nodes among them) only open in their native format, such as
+    32-bit float. Each format is tried at the device's default rate.
+
+    Args:
+        audio: PyAudio instance
+        device_index: The device index to test (None for default)
+
+    Returns:
+        str: One of SAMPLE_FORMATS, defaulting to "int16"
+    """
+    rate = 48000
+    try:
+        if device_index is not None:
+            device_info = audio.get_device_info_by_index(device_index)
+        else:
+            device_info = audio.get_default_input_device_info()
+        rate = int(device_info.get("defaultSampleRate", 0)) or rate
+    except (IOError, OSError) as e:
+        logger.debug(f"Could not get device info for format probing: {e}")
+
+    for sample_format in SAMPLE_FORMATS:
+        for channels in [1, 2]:
+            try:
+                stream_kwargs = {
+                    "format": _pyaudio_format(sample_format),
+                    "channels": channels,
+                    "rate": rate,
+                    "input": True,
+                    "frames_per_buffer": 1024,
+                }
+                if device_index is not None:
+                    stream_kwargs["input_device_index"] = device_index
+
+                test_stream = audio.open(**stream_kwargs)
+                test_stream.close()
+                logger.debug(f"Device supports {sample_format} samples")
+                return sample_format
+            except (IOError, OSError, ValueError):
+                continue
+
+    logger.debug("Could not determine a supported sample format, defaulting to int16")
+    return "int16"
+
+
+@dataclass(frozen=True)
+class CaptureFormat:
+    """The stream parameters a capture device was actually opened with."""
+
+    rate: int
+    channels: int
+    sample_format: str = "int16"  # One of SAMPLE_FORMATS; converted to int16 on capture
+
+    @property
+    def pyaudio_format(self) -> int:
+        return _pyaudio_format(self.sample_format)
+
+    @property
+    def needs_downmix(self) -> bool:
+        return self.channels > 1
+
+    @property
+    def needs_resampling(self) -> bool:
+        return self.rate != TARGET_SAMPLE_RATE
+
+    def describe(self) -> str:
+        """Short description for logs, e.g. "48000Hz, 2 ch, int16"."""
+        return f"{self.rate}Hz, {self.channels} ch, {self.sample_format}"
+
+
+def negotiate_capture_format(
+    audio, device_index: Optional[int], preferred_rate: Optional[int] = None
+) -> CaptureFormat:
+    """
+    Pick stream parameters the device accepts instead of assuming 16 kHz mono.
+
+    The sample format is probed first, since some devices only deliver
+    float samples, then the channel count, since some devices only open in
+    stereo, then a sample rate at that channel count (the configured rate if
+    the device takes it). Audio is converted to 16 kHz mono 16-bit before it
+    reaches the engines.
+
+    Args:
+        audio: PyAudio instance
+        device_index: The device index to open (None for default)
+        preferred_rate: Configured sample rate override, if any
+
+    Returns:
+        The negotiated CaptureFormat
+    """
+    sample_format = _get_supported_sample_format(audio, device_index)
+    channels = _get_supported_channels(audio, device_index, sample_format)
+    rate = _get_supported_sample_rate(
+        audio, device_index, channels, preferred_rate, sample_format
+    )
+    return CaptureFormat(rate=rate, channels=channels, sample_format=sample_format)
+
+
+def _play_back_recording(audio, pyaudio, data: bytes, rate: int) -> bool:
+    """Play a microphone test recording (mono 16-bit) on the default output device."""
+    try:
+        stream = audio.open(format=pyaudio.paInt16, channels=1, rate=rate, output=True)
+    except (IOError, OSError, ValueError) as e:
+        logger.warning(f"Could not open output device to play the microphone test back: {e}")
+        return False
+    try:
+        stream.write(data)
+        return True
+    except (IOError, OSError) as e:
+        logger.warning(f"Could not play the microphone test back: {e}")
+        return False
+    finally:
+        stream.stop_stream()
+        stream.close()
+
+
+def test_audio_input(
+    device_index: int = None,
+    duration: float = 1.0,
+    gain: float = 1.0,
+    playback: bool = False,
+    on_playback: Optional[Callable[[], None]] = None,
+    keep_audio: bool = False,
+) -> dict:
     """
     Test audio input from a device and return diagnostic information.
 
     Args:
         device_index: The device index to test (None for default)
         duration: How long to record in seconds
+        gain: Software gain applied before measuring, as while dictating
+        playback: Play the recording back afterwards so the user can hear it
+        on_playback: Called when recording ends and playback starts
+        keep_audio: Return the recording, e.g. to enroll the speaker from it
 
     Returns:
         Dictionary with test results including:
@@ -524,7 +921,11 @@ def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
         - sample_count: int
         - max_amplitude: float
         - mean_amplitude: float
+        - peak_dbfs: float (peak sample level in dBFS)
+        - rms_dbfs: float (RMS level in dBFS, the unit the VAD threshold uses)
         - has_signal: bool (amplitude above noise floor)
+        - played_back: bool (the recording was played back)
+        - audio: bytes (16-bit mono 16 kHz recording, with keep_audio)
         - error: str (if failed)
     """
     result = {
@@ -534,7 +935,10 @@ def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
         "sample_count": 0,
         "max_amplitude": 0.0,
         "mean_amplitude": 0.0,
+        "peak_dbfs": METER_FLOOR_DBFS,
+        "rms_dbfs": METER_FLOOR_DBFS,
         "has_signal": False,
+        "played_back": False,
         "error": None,
     }
 
@@ -543,7 +947,6 @@ def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
         import pyaudio
 
         CHUNK = 1024
-        FORMAT = pyaudio.paInt16
 
         audio = pyaudio.PyAudio()
 
@@ -561,18 +964,17 @@ def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
             audio.terminate()
             return result
 
-        # Detect supported channel count first (some devices require stereo)
-        CHANNELS = _get_supported_channels(audio, device_index)
-        logger.info(f"Using {CHANNELS} channel(s) for audio test")
-
-        # Detect supported sample rate for this device
-        RATE = _get_supported_sample_rate(audio, device_index, CHANNELS)
+        # Open the device the way dictation does (some only deliver stereo or float samples)
+        capture_format = negotiate_capture_format(audio, device_index)
+        CHANNELS = capture_format.channels
+        RATE = capture_format.rate
+        logger.info(f"Using {capture_format.describe()} for audio test")
         result["sample_rate"] = RATE
 
         # Open stream
         try:
             stream_kwargs = {
-                "format": FORMAT,
+                "format": capture_format.pyaudio_format,
                 "channels": CHANNELS,
                 "rate": RATE,
                 "input": True,
@@ -588,12 +990,18 @@ def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
             return result
 
         # Record and analyze
+        downmixer = ChannelDownmixer(CHANNELS)
         all_amplitudes = []
+        recording = []
         frames_to_read = int(RATE * duration / CHUNK)
 
         for _ in range(frames_to_read):
             try:
                 data = stream.read(CHUNK, exception_on_overflow=False)
+                data = to_int16(data, capture_format.sample_format)
+                data = downmixer.process(data)
+                data = apply_gain(data, gain)
+                recording.append(data)
                 audio_data = np.frombuffer(data, dtype=np.int16)
                 amplitudes = np.abs(audio_data)
                 all_amplitudes.extend(amplitudes)
@@ -603,7 +1011,15 @@ def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
 
         stream.stop_stream()
         stream.close()
+
+        if playback and recording:
+            if on_playback is not None:
+                on_playback()
+            result["played_back"] = _play_back_recording(audio, pyaudio, b"".join(recording), RATE)
         audio.terminate()
+        if keep_audio:
+            resampler = StreamResampler(RATE)
+            result["audio"] = b"".join(resampler.process(chunk) for chunk in recording)
 
         if all_amplitudes:
             all_amplitudes = np.array(all_amplitudes)
@@ -611,6 +1027,8 @@ def test_audio_input(device_index: int = None, duration: float = 1.0) -> dict:
             result["sample_count"] = len(all_amplitudes)
             result["max_amplitude"] = float(np.max(all_amplitudes))
             result["mean_amplitude"] = float(np.mean(all_amplitudes))
+            result["peak_dbfs"] = amplitude_dbfs(result["max_amplitude"])
+            result["rms_dbfs"] = rms_dbfs(all_amplitudes)
             # Signal present if max amplitude is above typical digital noise floor
             # 16-bit audio has max value of 32768, noise floor is typically < 100
             result["has_signal"] = result["max_amplitude"] > 200
@@ -690,19 +1108,186 @@ def _filter_non_speech(text: str) -> str:
     return probe + "\n" if trailing_newline else probe
 
 
-def _show_notification(title: str, message: str, icon: str = "dialog-warning"):
-    """Show a desktop notification."""
-    try:
-        import subprocess
+# Words that usually continue a sentence rather than start a new one
+_CONTINUATION_WORDS = frozenset(
+    {
+        "and",
+        "but",
+        "or",
+        "nor",
+        "so",
+        "because",
+        "which",
+        "that",
+        "who",
+        "whose",
+        "where",
+        "when",
+        "while",
+        "then",
+        "than",
+        "if",
+        "unless",
+        "until",
+        "with",
+        "without",
+        "to",
+        "of",
+        "for",
+        "from",
+        "in",
+        "on",
+        "at",
+        "by",
+    }
+)
 
-        # Use notify-send which is available on most Linux desktops
-        subprocess.Popen(
-            ["notify-send", "-i", icon, "-a", "Vocalinux", title, message],
-            stdout=subprocess.DEVNULL,
-            stderr=subprocess.DEVNULL,
-        )
-    except (FileNotFoundError, OSError) as e:
-        logger.debug(f"Could not show notification: {e}")
+
+def _join_utterance_fragments(previous: str, current: str) -> str:
+    """
+    Join two transcripts that the VAD split at a pause.
+
+    Whisper formats each segment as a complete sentence. When the previous
+    fragment trails off (no closing punctuation, or an ellipsis) or ends with a
+    period before a continuation word such as "and" or "because", the period is
+    dropped and the new fragment's first letter is lowercased.
+
+    Args:
+        previous: Transcript of the earlier fragment
+        current: Transcript of the fragment that followed it
+
+    Returns:
+        The combined text
+    """
+    previous = previous.strip()
+    current = current.strip()
+    if not previous or not current:
+        return previous or current
+
+    if previous[-1] in "!?":
+        return f"{previous} {current}"
+
+    first_word = re.sub(r"[^\w']", "", current.split()[0])
+    trails_off = previous.endswith(("...", "\u2026")) or previous[-1] != "."
+    if not trails_off and first_word.lower() not in _CONTINUATION_WORDS:
+        return f"{previous} {current}"
+
+    previous = previous.rstrip(".\u2026")
+    keeps_capital = first_word == "I" or first_word.startswith("I'") or first_word.isupper()
+    if current[0].isupper() and not keeps_capital:
+        current = current[0].lower() + current[1:]
+    return f"{previous} {current}"
+
+
+# Long-form mode feeds Whisper fixed windows instead of silence-delimited segments.
+# Consecutive windows share an overlap so words spoken across a boundary are not lost.
+LONG_FORM_WINDOW_SECONDS = 30.0
+LONG_FORM_OVERLAP_SECONDS = 5.0
+
+# Partial results re-decode the segment being recorded at most this often, once it
+# holds enough audio to be worth decoding.
+PARTIAL_RESULT_INTERVAL_SECONDS = 1.0
+PARTIAL_RESULT_MIN_AUDIO_SECONDS = 0.5
+
+# Engines that decode locally; partial results would flood a remote server with requests
+PARTIAL_RESULT_ENGINES = ("vosk", "whisper", "whisper_cpp")
+
+# Engines that can translate speech into English text instead of transcribing it
+TRANSLATE_ENGINES = ("whisper", "whisper_cpp")
+
+# Accepted range for a configured capture rate override
+MIN_CAPTURE_SAMPLE_RATE = 8000
+MAX_CAPTURE_SAMPLE_RATE = 192000
+
+# Frames read from the microphone at a time. Larger buffers cope better with a
+# busy machine but add latency; the configured value is kept within these bounds
+DEFAULT_BUFFER_FRAMES = 1024
+MIN_BUFFER_FRAMES = 256
+MAX_BUFFER_FRAMES = 8192
+
+# Speech segments waiting for transcription before the oldest is dropped
+DEFAULT_QUEUE_DEPTH = 32
+MIN_QUEUE_DEPTH = 4
+MAX_QUEUE_DEPTH = 256
+
+# Longest utterance before it is transcribed without waiting for a pause (0 turns this off).
+# The cut waits for a gap between words, for up to the grace period.
+DEFAULT_MAX_UTTERANCE_SECONDS = 30
+MIN_MAX_UTTERANCE_SECONDS = 5
+MAX_MAX_UTTERANCE_SECONDS = 600
+MAX_UTTERANCE_GRACE_SECONDS = 2.0
+
+# Audio kept before detected speech starts and after it ends when a segment is
+# cut at a pause, so soft onsets (plosives) and trailing syllables the VAD
+# misses aren't chopped off
+DEFAULT_SPEECH_PAD_MS = 200
+MAX_SPEECH_PAD_MS = 1000
+
+# Recent utterances whose audio is kept for retrying, as many as the tray can list
+RETRY_AUDIO_ENTRIES = 50
+
+# How long a remote transcription waits for the keyring to hand over the API key
+KEYRING_WAIT_SECONDS = 2.0
+
+# How often the playback stream of an application being recorded is looked up;
+# apps re-create their streams (e.g. a call switching speakers)
+APP_STREAM_RECHECK_SECONDS = 1.0
+
+# Stream errors further apart than this are separate incidents, each with a
+# fresh set of reconnection attempts
+STREAM_ERROR_RESET_SECONDS = 5.0
+
+# A chunk that fails to process is dropped; this many failures in a row stop
+# the recording, since the stream is then unlikely to recover on its own
+MAX_CONSECUTIVE_CHUNK_ERRORS = 20
+
+# Whisper often garbles the first words of a window, so allow the repeated
+# overlap text to start this many words into the new transcript
+_LONG_FORM_MAX_LEAD_WORDS = 3
+
+
+def _merge_overlapping_transcript(previous: str, current: str) -> str:
+    """
+    Remove the words at the start of a long-form window that repeat the previous one.
+
+    Words are compared ignoring case and punctuation. The longest run of at
+    least two words that ends ``previous`` and appears near the start of
+    ``current`` is treated as the overlap.
+
+    Args:
+        previous: Transcript of the previous window
+        current: Transcript of the window that overlaps it
+
+    Returns:
+        The part of ``current`` that was not already transcribed
+    """
+
+    def normalize(word: str) -> str:
+        return re.sub(r"[^\w']", "", word).lower()
+
+    previous_words = [normalize(word) for word in previous.split()]
+    current_raw = current.split()
+    current_words = [normalize(word) for word in current_raw]
+
+    for size in range(min(len(previous_words), len(current_words)), 1, -1):
+        tail = previous_words[-size:]
+        for lead in range(min(_LONG_FORM_MAX_LEAD_WORDS, len(current_words) - size) + 1):
+            if current_words[lead : lead + size] == tail:
+                return " ".join(current_raw[lead + size :])
+
+    return current.strip()
+
+
+def _show_notification(
+    title: str, message: str, icon: str = "dialog-warning", category: Optional[str] = None
+):
+    """
+    Show a desktop notification.
+
+    Notifications with a category are rate-limited, with repeats coalesced
+    into one follow-up (see utils.notifications).
+    """
+    notifications.notify(title, message, icon, category)
 
 
 # Define constants
@@ -801,12 +1386,41 @@ class SpeechRecognitionManager:
         self.recognition_thread = None
         self.model = None
         self.recognizer = None  # Added for VOSK
-        self.command_processor = CommandProcessor()
+        # Backend whisper.cpp really loaded the model on (from its load log)
+        self.whispercpp_load_report: Optional[WhisperCppLoadReport] = None
+        self.command_processor = CommandProcessor(
+            disabled_commands=kwargs.get("disabled_commands"),
+            renamed_commands=kwargs.get("renamed_commands"),
+        )
+        self.number_formatter = NumberFormatter(
+            ordinals=kwargs.get("format_ordinals", False),
+            fractions=kwargs.get("format_fractions", False),
+            roman_numerals=kwargs.get("format_roman_numerals", False),
+        )
+        self.term_capitalizer = TermCapitalizer(kwargs.get("preserved_terms") or [])
+        self.profanity_filter = ProfanityFilter(
+            self._valid_profanity_filter_mode(kwargs.get("profanity_filter", "off")),
+            kwargs.get("profanity_words") or [],
+        )
+        # Order of the post-processing stages, from the output profile
+        self.text_pipeline = TextPipeline(kwargs.get("text_pipeline"))
 
         # Voice commands: None=auto (VOSK=yes, Whisper=no), True=always on, False=always off
         self._voice_commands_preference = kwargs.get("voice_commands_enabled")
         self._voice_commands_enabled = self._resolve_voice_commands_enabled()
 
+        # Auto-punctuation level: None=engine default, "off", "commands" or "auto"
+        self.punctuation_level = valid_punctuation_level(kwargs.get("punctuation"))
+
+        # Language Whisper detected for the last utterance (auto-detect only), and
+        # whether typed text is prefixed with it for multilingual notes
+        self.detected_language: Optional[str] = None
+        self.tag_language = bool(kwargs.get("tag_language", False))
+
+        # Assistant-style intents ("open firefox", "search for ..."), off by default
+        self.intent_commands_enabled = kwargs.get("intent_commands_enabled", False)
+        self._intent_handler = IntentHandler(kwargs.get("intent_search_url") or DEFAULT_SEARCH_URL)
+
         self.text_callbacks: list[Callable[[str], None]] = []
         self.state_callbacks: list[Callable[[RecognitionState], None]] = []
         self.action_callbacks: list[Callable[[str], None]] = []
@@ -820,18 +1434,158 @@ class SpeechRecognitionManager:
         # Speech detection parameters (load defaults, will be overridden by configure)
         self.vad_sensitivity = kwargs.get("vad_sensitivity", 3)
         self.silence_timeout = kwargs.get("silence_timeout", 2.0)
+        # Per-engine overrides of the two above, e.g. a longer timeout for Whisper
+        self.engine_vad = self._valid_engine_vad(kwargs.get("engine_vad"))
+        # Adaptive timeout: follow the speaker's own pauses instead of silence_timeout
+        self.adaptive_silence_timeout = kwargs.get("adaptive_silence_timeout", False)
+        self._pause_timing = AdaptiveSilenceTimeout()
+
+        # VOSK: skip utterances in another voice than the enrolled one (see speaker_profile)
+        self.vosk_speaker_profile = kwargs.get("vosk_speaker_profile", False)
+        self._spk_model = None  # VOSK speaker model, loaded on first use
+        self._speaker_profile: Optional[SpeakerProfile] = None  # Set while matching is on
+
+        # Long-form mode: fixed overlapping windows instead of VAD segmentation (Whisper only)
+        self.long_form_mode = kwargs.get("long_form_mode", False)
+        self._long_form_session = False
+        self._long_form_last_text = ""
+
+        # Utterance joining: hold text briefly in case the next segment continues the sentence
+        self.utterance_join_window = kwargs.get("utterance_join_window", 0.0)
+        self._pending_fragment = ""
+        self._last_segment_cut_time = 0.0
+        self._speech_resumed_time = 0.0
+        # Index in audio_buffer of the chunk where the segment's speech started
+        self._segment_speech_start = 0
+
+        # Translate mode: Whisper engines output English text whatever language is spoken
+        self.translate = bool(kwargs.get("translate", False))
+
+        # Comparison mode (debugging): each utterance is also decoded by a second engine
+        # and both transcripts are reported; only the primary engine's text is typed
+        self.comparison_engine = kwargs.get("comparison_engine") or None
+        self.comparison_model_size = kwargs.get("comparison_model_size") or "small"
+        self._comparison_callbacks: list[Callable[[EngineTranscript, EngineTranscript], None]] = []
+        self._comparison_manager: Optional["SpeechRecognitionManager"] = None
+        self._comparison_lock = threading.Lock()
+        self._comparison_busy = False
+
+        # Draft-then-rewrite: the main engine's text is typed at once as a draft, then the
+        # same audio is decoded again with a larger model and the typed text corrected
+        self.rewrite_engine = kwargs.get("rewrite_engine") or None
+        self.rewrite_model_size = kwargs.get("rewrite_model_size") or "small"
+        self._rewrite_callbacks: list[Callable[[str, str], None]] = []
+        self._rewrite_manager: Optional["SpeechRecognitionManager"] = None
+        self._rewrite_lock = threading.Lock()
+        self._rewrite_busy = False
+
+        # Retry: recent utterances' audio is kept in memory (when enabled) so one that
+        # came out garbled can be decoded again with a larger model
+        self.retry_audio = bool(kwargs.get("retry_audio", False))
+        self.retry_model_size = kwargs.get("retry_model_size") or "large"
+        self._utterance_audio: collections.deque = collections.deque(maxlen=RETRY_AUDIO_ENTRIES)
+        self._retry_manager: Optional["SpeechRecognitionManager"] = None
+        self._retry_manager_key: Optional[tuple] = None
+        self._retry_lock = threading.Lock()
+
+        # Offline fallback: segments the remote server can't be reached for are decoded
+        # locally; with standby on, its model is loaded as soon as the remote engine is
+        self.offline_fallback_engine = kwargs.get("offline_fallback_engine") or None
+        self.offline_fallback_model_size = kwargs.get("offline_fallback_model_size") or "tiny"
+        self.offline_fallback_standby = bool(kwargs.get("offline_fallback_standby", False))
+        self._fallback_manager: Optional["SpeechRecognitionManager"] = None
+        self._fallback_manager_key: Optional[tuple] = None
+        self._fallback_lock = threading.Lock()
+        self._remote_api_unreachable = False
+
+        # Partial results: periodically decode the segment being recorded so text can be
+        # typed while speaking, then corrected in place when the final transcript arrives
+        self.partial_results = kwargs.get("partial_results", False)
+        self._partial_callbacks: list[Callable[[str], None]] = []
+        self._partial_text = ""
+        self._last_partial_time = 0.0
+        self._last_partial_chunks = 0
 
         # Silero VAD (neural-network-based, falls back to amplitude if unavailable)
         self._silero_vad = load_silero_vad()
-        if self._silero_vad is not None:
-            logger.info("Using Silero neural VAD")
-        else:
-            logger.info("Using amplitude-based VAD (install vocalinux[vad] for neural VAD)")
+        # VAD backend picked in the settings; None ("auto") uses Silero when it
+        # loaded and the energy VAD otherwise
+        self.vad_backend = self._valid_vad_backend(kwargs.get("vad_backend", "auto"))
+        self._vad = None
+        self._energy_vad = EnergyVAD()
+        self._silero_detector: Optional[SileroDetector] = None
+        self._select_vad()
 
         # Audio device selection (None means use system default)
         self.audio_device_index = kwargs.get("audio_device_index", None)
         self.audio_device_name = kwargs.get("audio_device_name", None)
 
+        # Capture rate override (None picks one the device supports); audio is
+        # resampled to the 16kHz the engines expect
+        self.sample_rate = self._valid_sample_rate(kwargs.get("sample_rate"))
+
+        # Latency tuning: microphone buffer size and transcription queue depth
+        self.buffer_frames = self._bounded_setting(
+            "buffer_frames",
+            kwargs.get("buffer_frames"),
+            DEFAULT_BUFFER_FRAMES,
+            MIN_BUFFER_FRAMES,
+            MAX_BUFFER_FRAMES,
+        )
+        self.queue_depth = self._bounded_setting(
+            "queue_depth",
+            kwargs.get("queue_depth"),
+            DEFAULT_QUEUE_DEPTH,
+            MIN_QUEUE_DEPTH,
+            MAX_QUEUE_DEPTH,
+        )
+        # Transcribe very long utterances in parts instead of one huge segment
+        self.max_utterance_seconds = self._max_utterance_setting(
+            kwargs.get("max_utterance_seconds")
+        )
+        # Padding around the detected speech in each segment
+        self.speech_pad_before_ms = self._speech_pad_setting(
+            "speech_pad_before_ms", kwargs.get("speech_pad_before_ms")
+        )
+        self.speech_pad_after_ms = self._speech_pad_setting(
+            "speech_pad_after_ms", kwargs.get("speech_pad_after_ms")
+        )
+
+        # Debug: play this WAV file in each session instead of recording the microphone,
+        # at input_file_speed times real time (0 = as fast as possible)
+        self.input_file = kwargs.get("input_file")
+        self.input_file_speed = kwargs.get("input_file_speed", 1.0)
+
+        # Microphone sidetone (hear yourself while dictating)
+        self.sidetone_enabled = kwargs.get("sidetone_enabled", False)
+        self.sidetone_volume = kwargs.get("sidetone_volume", 0.3)
+
+        # Media ducking (lower other apps' playback while dictating). Duck and
+        # restore run in order on one worker so pactl never blocks the caller.
+        self.duck_media = kwargs.get("duck_media", False)
+        self._media_ducker = MediaDucker(kwargs.get("duck_level", DEFAULT_DUCK_LEVEL))
+        self._ducking_executor = None
+
+        # Echo cancellation (subtract playback from the default microphone)
+        self.echo_cancel = kwargs.get("echo_cancel", False)
+        self._echo_canceller = EchoCanceller()
+        # Record silence while our feedback sounds or read-back play, so they aren't typed
+        self.mute_own_sounds = kwargs.get("mute_own_sounds", True)
+
+        # Noise suppression (RNNoise between capture and VAD/recognition)
+        self.noise_suppression = kwargs.get("noise_suppression", False)
+
+        # Software gain per input device, for microphones that are too quiet
+        self.device_gain = self._valid_device_gain(kwargs.get("device_gain"))
+
+        # More microphones mixed into the selected one, by device name
+        self.extra_device_names = list(kwargs.get("extra_device_names") or [])
+
+        # Model download source and speed limit
+        self.download_mirror = kwargs.get("download_mirror", DEFAULT_DOWNLOAD_MIRROR)
+        self.vosk_mirror_url = kwargs.get("vosk_mirror_url", "")
+        self.download_limit_kbps = kwargs.get("download_limit_kbps", 0)
+
         # whisper.cpp advanced parameters
         self.whispercpp_no_timestamps = kwargs.get("whispercpp_no_timestamps", True)
         self.whispercpp_no_context = kwargs.get("whispercpp_no_context", True)
@@ -843,17 +1597,56 @@ class SpeechRecognitionManager:
         self.whispercpp_no_speech_thold = kwargs.get("whispercpp_no_speech_thold", 0.6)
         self.whispercpp_n_threads = kwargs.get("whispercpp_n_threads", None)
 
+        # VRAM kept free for other programs; larger models are swapped for smaller ones
+        self.gpu_memory_reserve_mb = max(0, int(kwargs.get("gpu_memory_reserve_mb", 0) or 0))
+        # The configured model when a smaller one was loaded to stay within that reserve
+        self._gpu_downgraded_from: Optional[str] = None
+
+        # Custom vocabulary and installed domain packs, passed to Whisper as a glossary prompt
+        self.vocabulary_terms = list(kwargs.get("vocabulary_terms") or [])
+
+        # Whisper prompt presets picked by the application focused when dictation starts
+        self.app_prompts = AppPrompts(kwargs.get("prompt_presets"), kwargs.get("app_prompts"))
+        self._focused_app_provider: Optional[Callable[[], Optional[str]]] = None
+        self._app_prompt = ""
+
         # Remote API settings
         self.remote_api_url = kwargs.get("remote_api_url", "")
         self.remote_api_key = kwargs.get("remote_api_key", "")
         self.remote_api_endpoint = kwargs.get("remote_api_endpoint", "/inference")
         self.remote_api_model = kwargs.get("remote_api_model", "whisper-1")
         self._http_session = None
+        # The account's key is read from the keyring in the background, since
+        # an unlock prompt would otherwise hold up startup
+        self._remote_api_secret: Optional[credentials.DeferredSecret] = None
+        remote_api_account = kwargs.get("remote_api_account")
+        if remote_api_account and credentials.keyring_available():
+            self._remote_api_secret = credentials.DeferredSecret(remote_api_account)
+
+        # Bumped by panic_stop(); transcriptions started under an older value are discarded
+        self._session_generation = 0
 
         # Audio diagnostics tracking
         self._last_audio_level = 0.0
+        self._level_meter = LevelMeter()
         self._audio_level_callbacks: list[Callable[[float], None]] = []
 
+        # Decoding performance (real-time factor of the most recent utterance)
+        self._last_rtf: Optional[float] = None
+        # Confidence (0-1) the engine reported for the last decode, if it reports one
+        self._last_confidence: Optional[float] = None
+
+        # Opt-in JSON log with one event per utterance
+        self.utterance_log = UtteranceLog(kwargs.get("utterance_log", "off"))
+
+        # Debug: each utterance is also written to a WAV file in this directory
+        self.audio_dumper = AudioDumper(kwargs.get("dump_audio_dir"))
+
+        # Session timer and word count, with an optional word target
+        self.session = DictationSession(
+            kwargs.get("session_word_target", 0), on_target_reached=self._on_word_target_reached
+        )
+
         # Recording control flags
         self.should_record = False
         self._recognition_mode = "toggle"  # "toggle" or "push_to_talk"
@@ -861,7 +1654,7 @@ class SpeechRecognitionManager:
         self._recording_segment_has_speech = False
         self._buffer_lock = threading.Lock()  # Thread safety for audio_buffer
         self._model_lock = threading.Lock()  # Thread safety for model/recognizer access
-        self._segment_queue = queue.Queue(maxsize=32)
+        self._segment_queue = queue.Queue(maxsize=self.queue_depth)
 
         # Reliability improvements - Issue #92
         self._max_buffer_size = 5000  # Maximum number of audio chunks in buffer
@@ -871,7 +1664,12 @@ class SpeechRecognitionManager:
         self._last_audio_error_time = 0
         self._audio_stream = None
         self._pyaudio_instance = None
+        self._capture_device_name: Optional[str] = None  # PortAudio name of the open device
+        self._app_stream_target: Optional[str] = None  # Serial of the app stream recorded
+        self._chunk_sequence = itertools.count()  # Numbers the captured chunks, never reset
         self._capture_sample_rate = 16000  # Default, updated when device is opened
+        self.capture_format: Optional[CaptureFormat] = None  # Set when the device is opened
+        self._microphone_permission = MicrophonePermission()
 
         # Create models directory if it doesn't exist
         os.makedirs(MODELS_DIR, exist_ok=True)
@@ -880,6 +1678,8 @@ class SpeechRecognitionManager:
             f"Initializing speech recognition with {engine} engine, {language} language and {model_size} model"
         )
 
+        self._warn_if_language_unsupported()
+
         # Initialize the selected speech recognition engine
         if engine == "vosk":
             self._init_vosk()
@@ -892,12 +1692,73 @@ class SpeechRecognitionManager:
         else:
             raise ValueError(f"Unsupported speech recognition engine: {engine}")
 
+    def _warn_if_language_unsupported(self) -> Optional[str]:
+        """Log and notify when the engine/model/language combination is unsupported."""
+        warning = validate_language_selection(self.engine, self.model_size, self.language)
+        if warning:
+            logger.warning(
+                f"Unsupported language selection (engine={self.engine}, "
+                f"model={self.model_size}, language={self.language}): {warning}"
+            )
+            _show_notification("Language not supported", warning)
+        return warning
+
     def _resolve_voice_commands_enabled(self) -> bool:
         """Resolve effective voice commands state from preference and engine."""
         if self._voice_commands_preference is None:
             return self.engine == "vosk"
         return bool(self._voice_commands_preference)
 
+    def _vocabulary_prompt(self, base_prompt: str = "") -> str:
+        """Return the Whisper prompt with the vocabulary glossary appended."""
+        return build_vocabulary_prompt(self.vocabulary_terms, base_prompt)
+
+    def _session_prompt(self, base_prompt: str = "") -> str:
+        """Return the Whisper prompt with the focused app's preset and the glossary added."""
+        prompt = " ".join(part for part in (base_prompt.strip(), self._app_prompt) if part)
+        return self._vocabulary_prompt(prompt)
+
+    def _whispercpp_prompt_params(self) -> dict:
+        """
+        Per-call whisper.cpp params for the focused app's prompt.
+
+        whisper.cpp otherwise keeps the prompt it was loaded with. pywhispercpp
+        keeps params passed to transcribe() for later calls, so once app rules
+        are set the prompt is passed every time, restoring the loaded one when
+        no rule matches.
+        """
+        if not self.app_prompts.enabled:
+            return {}
+        return {"initial_prompt": self._session_prompt(self.whispercpp_initial_prompt)}
+
+    def set_focused_app_provider(self, provider: Optional[Callable[[], Optional[str]]]):
+        """
+        Set how to find the focused application for the app prompt rules.
+
+        Args:
+            provider: Returns the focused window's class, or None if unknown
+        """
+        self._focused_app_provider = provider
+
+    def _update_app_prompt(self):
+        """Pick the prompt preset for the application focused as dictation starts."""
+        self._app_prompt = ""
+        if not self.app_prompts.enabled or self._focused_app_provider is None:
+            return
+        try:
+            app = self._focused_app_provider()
+        except Exception as e:
+            logger.debug(f"Could not get the focused application: {e}")
+            return
+        preset = self.app_prompts.preset_for(app)
+        if preset:
+            logger.info(f"Using the {preset!r} prompt preset for {app}")
+            self._app_prompt = self.app_prompts.presets[preset]
+
+    def _long_form_active(self) -> bool:
+        """Whether recordings should be cut into fixed long-form windows."""
+        return bool(self.long_form_mode) and self.engine in ("whisper", "whisper_cpp")
+
     def _init_vosk(self):
         """Initialize the VOSK speech recognition engine."""
         # VOSK doesn't support auto-detect, so fall back to en-us for "auto"
@@ -941,13 +1802,115 @@ class SpeechRecognitionManager:
             self.recognizer = None
             self.model = Model(self.vosk_model_path)
             self.recognizer = KaldiRecognizer(self.model, 16000)
+            self._apply_speaker_profile()
             self._model_initialized = True
             logger.info("VOSK engine initialized successfully.")
 
-        except ImportError:
+        except ImportError as e:
             logger.error("Failed to import VOSK. Please install it with 'pip install vosk'")
             self.state = RecognitionState.ERROR
-            raise
+            raise EngineNotInstalledError("vosk", str(e)) from e
+
+    def _load_spk_model(self):
+        """Load VOSK's speaker model, once; None if it isn't installed."""
+        if self._spk_model is None:
+            path = find_spk_model([MODELS_DIR, *SYSTEM_MODELS_DIRS])
+            if path is None:
+                logger.warning(f"VOSK speaker model {SPK_MODEL_NAME} not found in {MODELS_DIR}")
+                return None
+            from vosk import SpkModel
+
+            self._spk_model = SpkModel(path)
+        return self._spk_model
+
+    def _apply_speaker_profile(self):
+        """Give the VOSK recognizer the speaker model if utterances should match the profile."""
+        self._speaker_profile = None
+        if not self.vosk_speaker_profile or self.recognizer is None:
+            return
+        profile = SpeakerProfile.load()
+        if profile is None:
+            logger.warning("No voice enrolled yet; transcribing every voice")
+            return
+        spk_model = self._load_spk_model()
+        if spk_model is None:
+            return
+        self.recognizer.SetSpkModel(spk_model)
+        self._speaker_profile = profile
+        logger.info("Transcribing only the enrolled voice")
+
+    def _reset_vosk_recognizer(self):
+        """Re-create the VOSK recognizer, e.g. after the speaker profile changed."""
+        if self.engine != "vosk" or self.model is None:
+            return
+        from vosk import KaldiRecognizer
+
+        with self._model_lock:
+            self.recognizer = KaldiRecognizer(self.model, 16000)
+            self._apply_speaker_profile()
+
+    def _is_enrolled_speaker(self, result: dict) -> bool:
+        """
+        Whether a VOSK result is in the enrolled voice (always True without a profile).
+
+        A skipped utterance is announced with its text, so a user whose own
+        speech stops matching (new microphone, a cold) can tell why nothing
+        was typed and enroll again.
+        """
+        if self._speaker_profile is None or not result.get("spk"):
+            return True
+        distance = self._speaker_profile.distance(result["spk"])
+        if distance > DEFAULT_MAX_SPEAKER_DISTANCE:
+            text = result.get("text", "")
+            logger.info(
+                f"Skipped an utterance in another voice (distance {distance:.2f}): {text!r}"
+            )
+            _show_notification(
+                "Skipped Another Voice",
+                f'"{text}" didn\'t match your enrolled voice and wasn\'t typed. If it was you, '
+                "enroll again or turn off Recognize Only My Voice.",
+                icon="dialog-information",
+                category="speaker_profile",
+            )
+            return False
+        return True
+
+    def enroll_speaker(self, audio: bytes) -> SpeakerProfile:
+        """
+        Enroll the user's voice for the VOSK speaker profile and save it.
+
+        Args:
+            audio: 16-bit mono 16 kHz recording of the user reading aloud
+
+        Returns:
+            The saved profile
+
+        Raises:
+            RuntimeError: If VOSK or its speaker model isn't available, or there
+                was too little speech in the recording
+        """
+        if self.engine != "vosk" or self.model is None:
+            raise RuntimeError("Enrolling a voice needs the VOSK engine with its model loaded.")
+        spk_model = self._load_spk_model()
+        if spk_model is None:
+            raise RuntimeError(f"The VOSK speaker model ({SPK_MODEL_NAME}) isn't installed.")
+        from vosk import KaldiRecognizer
+
+        # A recognizer of its own, so dictation can carry on meanwhile
+        recognizer = KaldiRecognizer(self.model, 16000, spk_model)
+        results = []
+        step = 2 * 16000  # One second of audio
+        for start in range(0, len(audio), step):
+            if recognizer.AcceptWaveform(audio[start : start + step]):
+                results.append(json.loads(recognizer.Result()))
+        results.append(json.loads(recognizer.FinalResult()))
+
+        profile = SpeakerProfile.from_results(results)
+        if profile is None:
+            raise RuntimeError("Too little speech was recorded. Read the text aloud and try again.")
+        profile.save()
+        self._reset_vosk_recognizer()
+        return profile
 
     def _init_whisper(self):
         """Initialize the Whisper speech recognition engine."""
@@ -970,6 +1933,14 @@ class SpeechRecognitionManager:
                 )
                 self.model_size = "base"
 
+            # Determine device (GPU if available, otherwise CPU)
+            device = "cuda" if torch.cuda.is_available() else "cpu"
+            if device == "cuda":
+                sizes = list(WHISPER_VRAM_MB)
+                if self._fit_model_to_gpu_memory(sizes, WHISPER_VRAM_MB.get, torch) is None:
+                    # Not even the smallest model leaves the reserve free; keep off the GPU
+                    device = "cpu"
+
             # Check if model is downloaded
             whisper_cache_dir = os.path.join(MODELS_DIR, "whisper")
             os.makedirs(whisper_cache_dir, exist_ok=True)
@@ -991,8 +1962,6 @@ class SpeechRecognitionManager:
                 logger.info(f"Downloading Whisper '{self.model_size}' model...")
                 self._download_whisper_model(whisper_cache_dir)
 
-            # Determine device (GPU if available, otherwise CPU)
-            device = "cuda" if torch.cuda.is_available() else "cpu"
             logger.info(f"Using device: {device}")
 
             logger.info(f"Loading Whisper '{self.model_size}' model...")
@@ -1012,7 +1981,7 @@ class SpeechRecognitionManager:
             logger.error(f"Failed to import required libraries for Whisper: {e}")
             logger.error("Please install with: pip install openai-whisper torch")
             self.state = RecognitionState.ERROR
-            raise
+            raise EngineNotInstalledError("whisper", str(e)) from e
         except (RuntimeError, OSError) as e:
             logger.error(f"Failed to initialize Whisper engine: {e}")
             self.state = RecognitionState.ERROR
@@ -1068,14 +2037,23 @@ class SpeechRecognitionManager:
                 result = self.model.transcribe(
                     audio_float,
                     language=lang,
-                    task="transcribe",
+                    task=self._whisper_task(),
                     verbose=False,
                     temperature=0.0,  # Greedy decoding for consistency
                     no_speech_threshold=0.6,
                     fp16=use_fp16,  # Explicitly set to avoid warning on CPU
+                    initial_prompt=self._session_prompt() or None,
                 )
 
+            self._record_detected_language(result)
             text = result.get("text", "").strip()
+            logprobs = [
+                segment["avg_logprob"]
+                for segment in result.get("segments", [])
+                if segment.get("avg_logprob") is not None
+            ]
+            if logprobs:
+                self._last_confidence = math.exp(sum(logprobs) / len(logprobs))
 
             if text:
                 logger.info(f"Whisper transcribed: '{text}'")
@@ -1103,6 +2081,15 @@ class SpeechRecognitionManager:
                 )
                 self.model_size = "tiny"
 
+            if self.gpu_memory_reserve_mb > 0 and self._detect_pywhispercpp_gpu_backend() != "cpu":
+                tier = get_whispercpp_model_size(self.model_size)
+                smaller = WHISPERCPP_MODEL_SIZES[: WHISPERCPP_MODEL_SIZES.index(tier)]
+                fitted = self._fit_model_to_gpu_memory(smaller, self._whispercpp_vram_mb)
+                if fitted is None and smaller:
+                    # whisper.cpp can't be moved to the CPU here; the smallest model comes closest
+                    self._gpu_downgraded_from = self._gpu_downgraded_from or self.model_size
+                    self.model_size = smaller[0]
+
             # Check if model is downloaded
             model_path = get_model_path(self.model_size)
 
@@ -1125,12 +2112,64 @@ class SpeechRecognitionManager:
             logger.error(f"Python path: {sys.path}")
             logger.error("Please install with: pip install pywhispercpp")
             self.state = RecognitionState.ERROR
-            raise
+            raise EngineNotInstalledError("whisper_cpp", str(e)) from e
         except (FileNotFoundError, RuntimeError, OSError) as e:
             logger.error(f"Failed to initialize whisper.cpp engine: {e}", exc_info=True)
             self.state = RecognitionState.ERROR
             raise
 
+    @staticmethod
+    def _whispercpp_vram_mb(model_name: str) -> int:
+        return whispercpp_vram_mb(WHISPERCPP_MODEL_INFO[model_name]["size_mb"])
+
+    def _fit_model_to_gpu_memory(
+        self, smaller_sizes: list[str], vram_mb: Callable[[str], int], torch_module=None
+    ) -> Optional[str]:
+        """
+        Swap the model for a smaller one if it would break the VRAM reserve.
+
+        Args:
+            smaller_sizes: Models to fall back to, smallest first
+            vram_mb: Estimated VRAM use of a model
+            torch_module: torch, to read the GPU's memory without nvidia-smi
+
+        Returns:
+            The model to load (self.model_size is updated to it), or None when
+            not even the smallest model fits
+        """
+        if self.gpu_memory_reserve_mb <= 0:
+            return self.model_size
+        budget = vram_budget_mb(self.gpu_memory_reserve_mb, total_vram_mb(torch_module))
+        if budget is None:
+            logger.info("GPU memory unknown; the GPU memory reserve is not enforced")
+            return self.model_size
+
+        requested = self.model_size
+        fitted = fit_model_to_budget(requested, smaller_sizes, vram_mb, budget)
+        if fitted == requested:
+            return fitted
+        if fitted is None:
+            logger.warning(
+                f"No model fits in the {budget} MB of VRAM left after keeping "
+                f"{self.gpu_memory_reserve_mb} MB free"
+            )
+            return None
+
+        logger.warning(
+            f"Model '{requested}' needs about {vram_mb(requested)} MB of VRAM, more than the "
+            f"{budget} MB left after keeping {self.gpu_memory_reserve_mb} MB free; "
+            f"using '{fitted}' instead"
+        )
+        _show_notification(
+            "Using a Smaller Model",
+            f"The {requested} model would not leave {self.gpu_memory_reserve_mb} MB of GPU "
+            f"memory free, so the {fitted} model is used instead.",
+            icon="dialog-information",
+        )
+        self._gpu_downgraded_from = self._gpu_downgraded_from or requested
+        self.model_size = fitted
+        return fitted
+
     def _build_whispercpp_model_kwargs(self, n_threads: int) -> dict:
         model_kwargs = {
             "n_threads": n_threads,
@@ -1145,8 +2184,9 @@ class SpeechRecognitionManager:
             model_kwargs["no_timestamps"] = True
         if self.whispercpp_no_context:
             model_kwargs["no_context"] = True
-        if self.whispercpp_initial_prompt:
-            model_kwargs["initial_prompt"] = self.whispercpp_initial_prompt
+        initial_prompt = self._vocabulary_prompt(self.whispercpp_initial_prompt)
+        if initial_prompt:
+            model_kwargs["initial_prompt"] = initial_prompt
         return model_kwargs
 
     def _get_supported_whispercpp_params(self) -> Optional[set[str]]:
@@ -1277,13 +2317,16 @@ class SpeechRecognitionManager:
 
         model_kwargs = self._build_whispercpp_model_kwargs(n_threads)
 
-        # Attempt to load model; filter unsupported params and fall back to CPU if needed
-        try:
-            self.model = self._load_model_with_compatible_params(model_path, model_kwargs)
-        except RuntimeError as model_error:
-            loaded_backend = self._handle_gpu_fallback(
-                model_error, model_path, model_kwargs, ComputeBackend.CPU
-            )
+        # Attempt to load model; filter unsupported params and fall back to CPU if needed.
+        # whisper.cpp's load log on stderr says which backend it really initialized.
+        with capture_native_stderr() as load_log:
+            try:
+                self.model = self._load_model_with_compatible_params(model_path, model_kwargs)
+            except RuntimeError as model_error:
+                loaded_backend = self._handle_gpu_fallback(
+                    model_error, model_path, model_kwargs, ComputeBackend.CPU
+                )
+        self.whispercpp_load_report = parse_load_log(load_log.text)
 
         load_duration = time.time() - load_start_time
         if has_gpu_libs:
@@ -1297,10 +2340,44 @@ class SpeechRecognitionManager:
                 f"(CPU-only; pywhispercpp lacks GPU libraries)"
             )
         logger.info(f"whisper.cpp model loaded in {load_duration:.2f}s ({loaded_backend} backend)")
+        self._report_whispercpp_backend(
+            expected_gpu=has_gpu_libs or backend != ComputeBackend.CPU,
+            fell_back=loaded_backend != backend and loaded_backend == ComputeBackend.CPU,
+        )
 
         self._model_initialized = True
         logger.info("whisper.cpp engine initialized successfully.")
 
+    def _report_whispercpp_backend(self, expected_gpu: bool, fell_back: bool):
+        """
+        Tell the user which backend whisper.cpp actually runs on.
+
+        Args:
+            expected_gpu: Whether the GPU libraries or drivers suggested a GPU would be used
+            fell_back: Whether loading already fell back to CPU (and said so)
+        """
+        report = self.whispercpp_load_report
+        if report is None or report.backend == "unknown":
+            logger.info("whisper.cpp did not log its backend; effective backend unknown")
+            return
+
+        logger.info(f"whisper.cpp effective backend: {report.summary()}")
+        if report.uses_gpu:
+            _show_notification(
+                "whisper.cpp Using GPU",
+                f"Speech recognition runs on {report.summary()}.",
+                "dialog-information",
+                category="whispercpp_backend",
+            )
+        elif expected_gpu and not fell_back:
+            _show_notification(
+                "whisper.cpp Running on CPU",
+                "A GPU was detected but whisper.cpp didn't use it, so transcription is slower.\n"
+                "Check the GPU drivers, or rebuild: ./install.sh --rebuild-whispercpp",
+                "dialog-warning",
+                category="whispercpp_backend",
+            )
+
     def _handle_gpu_fallback(self, error, model_path: str, model_kwargs: dict, cpu_backend):
         """Handle GPU backend failure by falling back to CPU.
 
@@ -1389,7 +2466,12 @@ class SpeechRecognitionManager:
                 # Transcribe with whisper.cpp
                 # pywhispercpp expects audio as numpy array
                 transcribe_start = time.time()
-                segments = self.model.transcribe(audio_float, language=lang)
+                segments = self.model.transcribe(
+                    audio_float,
+                    language=lang,
+                    translate=self.translate,
+                    **self._whispercpp_prompt_params(),
+                )
                 transcribe_duration = time.time() - transcribe_start
 
             # Extract text from segments, filtering non-speech tokens
@@ -1460,6 +2542,31 @@ class SpeechRecognitionManager:
         # Remote API does not need local models, directly mark as ready
         self._model_initialized = True
         logger.info("Remote API engine setup complete.")
+        self._warm_offline_fallback()
+
+    @property
+    def remote_api_key_locked(self) -> bool:
+        """Whether the remote API key is waiting for the keyring to be unlocked."""
+        return (
+            self._remote_api_secret is not None
+            and self._remote_api_secret.locked
+            and not self.remote_api_key
+        )
+
+    def _remote_api_auth_key(self) -> Optional[str]:
+        """
+        The API key to send to the remote server.
+
+        Returns:
+            The keyring's key for the account, else the configured one, or
+            None while the keyring is locked and there is no configured key
+        """
+        if self._remote_api_secret is None:
+            return self.remote_api_key
+        key = self._remote_api_secret.get(wait=KEYRING_WAIT_SECONDS)
+        if key is None:
+            return self.remote_api_key or None
+        return key or self.remote_api_key
 
     def _transcribe_with_remote_api(self, audio_buffer: list[bytes], session) -> str:
         """Transcribe audio via remote API.
@@ -1512,10 +2619,20 @@ class SpeechRecognitionManager:
             elif lang == "auto":
                 lang = None
 
+            api_key = self._remote_api_auth_key()
+            if api_key is None:
+                logger.warning("Remote API key is in the keyring, which is still locked")
+                _show_notification(
+                    "Remote Engine Locked",
+                    "Unlock your keyring (for example KDE Wallet) to use the remote server.",
+                    category="remote_api_keyring",
+                )
+                return ""
+
             # Prepare HTTP request headers
             headers = {}
-            if self.remote_api_key:
-                headers["Authorization"] = f"Bearer {self.remote_api_key}"
+            if api_key:
+                headers["Authorization"] = f"Bearer {api_key}"
 
             transcribe_start = time.time()
 
@@ -1525,10 +2642,19 @@ class SpeechRecognitionManager:
             else:
                 text = self._try_openai_api(wav_bytes, lang, headers, session)
 
-            # If both formats fail
             if text is None:
+                if self._remote_api_unreachable:
+                    # The caller falls back to a local model or tells the user
+                    logger.error(f"Cannot reach {self.remote_api_url} to transcribe")
+                    return ""
                 logger.error(
-                    "Remote API transcription failed: Cannot connect to server or API format not supported"
+                    "Remote API transcription failed: request rejected or API format not supported"
+                )
+                _show_notification(
+                    "Remote Transcription Failed",
+                    f"{self.remote_api_url} did not accept the request. "
+                    "Check the API key and endpoint in Settings.",
+                    category="remote_api",
                 )
                 return ""
 
@@ -1561,6 +2687,7 @@ class SpeechRecognitionManager:
                 else "empty audio buffer"
             )
             logger.error(f"Remote API transcription error: {e} ({audio_info})", exc_info=True)
+            _show_notification("Remote Transcription Failed", str(e), category="remote_api")
             return ""
 
     def _try_openai_api(self, wav_bytes: bytes, lang, headers: dict, session):
@@ -1583,6 +2710,9 @@ class SpeechRecognitionManager:
         data = {"model": self.remote_api_model or "whisper-1"}
         if lang:
             data["language"] = lang
+        prompt = self._vocabulary_prompt()
+        if prompt:
+            data["prompt"] = prompt
 
         try:
             response = session.post(url, headers=headers, files=files, data=data, timeout=30)
@@ -1594,15 +2724,27 @@ class SpeechRecognitionManager:
             response.raise_for_status()
             result = response.json()
 
+            self._record_detected_language(result)
             return self._extract_remote_transcription_text(result)
 
-        except requests.exceptions.ConnectionError as e:
+        except (requests.exceptions.ConnectionError, requests.exceptions.Timeout) as e:
             logger.error(f"Cannot connect to remote server {url}: {e}")
+            # Only then is a local fallback worth it; a rejected key or bad reply is not
+            self._remote_api_unreachable = True
             return None
         except Exception as e:
             logger.debug(f"OpenAI API format attempt failed: {e}")
             return None
 
+    def _record_detected_language(self, result: object):
+        """Remember the language an engine detected for the utterance, when auto-detecting."""
+        language = result.get("language") if isinstance(result, dict) else None
+        if self.language != "auto" or not isinstance(language, str) or not language:
+            self.detected_language = None
+            return
+        self.detected_language = language
+        logger.debug(f"Detected language: {language}")
+
     def _extract_remote_transcription_text(self, payload: object) -> str:
         """Extract transcript text from OpenAI-compatible ASR responses.
 
@@ -1664,6 +2806,9 @@ class SpeechRecognitionManager:
         }
         if lang:
             data["language"] = lang
+        prompt = self._vocabulary_prompt()
+        if prompt:
+            data["prompt"] = prompt
 
         try:
             response = session.post(url, headers=headers, files=files, data=data, timeout=30)
@@ -1678,8 +2823,10 @@ class SpeechRecognitionManager:
             # whisper.cpp server format returns {"text": "..."}
             return result.get("text", "")
 
-        except requests.exceptions.ConnectionError as e:
+        except (requests.exceptions.ConnectionError, requests.exceptions.Timeout) as e:
             logger.error(f"Cannot connect to remote server {url}: {e}")
+            # Only then is a local fallback worth it; a rejected key or bad reply is not
+            self._remote_api_unreachable = True
             return None
         except Exception as e:
             logger.debug(f"whisper.cpp server API format attempt failed: {e}")
@@ -1696,7 +2843,9 @@ class SpeechRecognitionManager:
         """
         import requests
 
+        url = mirrored_url(url, self.download_mirror, self.vosk_mirror_url)
         logger.info(f"Downloading from {url}")
+        limiter = BandwidthLimiter(self.download_limit_kbps)
         response = requests.get(
             url,
             stream=True,
@@ -1732,6 +2881,7 @@ class SpeechRecognitionManager:
                     continue
                 f.write(data)
                 downloaded_size += len(data)
+                limiter.throttle(len(data))
 
                 current_time = time.time()
                 if self._download_progress_callback and (current_time - last_update_time) >= 0.1:
@@ -1822,7 +2972,11 @@ class SpeechRecognitionManager:
 
     def _get_vosk_model_path(self) -> str:
         """Get the path to the VOSK model based on the selected size and language."""
-        model_name = self.vosk_model_map.get(self.model_size, self.vosk_model_map["small"])
+        if self.model_size in self.vosk_model_map:
+            model_name = self.vosk_model_map[self.model_size]
+        else:
+            logger.warning(f"Unknown VOSK model size '{self.model_size}', using the small model")
+            model_name = self.vosk_model_map["small"]
 
         if not model_name:
             vosk_language = "en-us" if self.language == "auto" else self.language
@@ -1895,7 +3049,9 @@ class SpeechRecognitionManager:
         logger.info(f"Downloading VOSK {self.model_size} model to user directory: {model_path}")
 
         # Download the model
+        url = mirrored_url(url, self.download_mirror, self.vosk_mirror_url)
         logger.info(f"Downloading VOSK model from {url}")
+        limiter = BandwidthLimiter(self.download_limit_kbps)
         try:
             response = requests.get(url, stream=True, timeout=self._MODEL_DOWNLOAD_TIMEOUT)
             response.raise_for_status()  # Raise an exception for bad status codes (4xx or 5xx)
@@ -1917,6 +3073,7 @@ class SpeechRecognitionManager:
 
                     f.write(data)
                     downloaded_size += len(data)
+                    limiter.throttle(len(data))
 
                     # Update progress callback
                     current_time = time.time()
@@ -2029,6 +3186,7 @@ class SpeechRecognitionManager:
 
         logger.info(f"Downloading Whisper {self.model_size} model to {model_file}")
         logger.info(f"Downloading from {url}")
+        limiter = BandwidthLimiter(self.download_limit_kbps)
 
         try:
             response = requests.get(url, stream=True)
@@ -2051,6 +3209,7 @@ class SpeechRecognitionManager:
 
                     f.write(data)
                     downloaded_size += len(data)
+                    limiter.throttle(len(data))
 
                     # Update progress callback
                     current_time = time.time()
@@ -2115,6 +3274,20 @@ class SpeechRecognitionManager:
         """
         self.text_callbacks.append(callback)
 
+    def register_partial_callback(self, callback: Callable[[str], None]):
+        """
+        Register a callback for partial results while partial results are enabled.
+
+        The callback receives the best guess so far at the utterance being
+        spoken; the next final text replaces it. An empty string means the
+        partial text should be withdrawn, e.g. because the utterance was
+        cancelled or turned out to contain no words.
+
+        Args:
+            callback: A function that takes the partial text
+        """
+        self._partial_callbacks.append(callback)
+
     def unregister_text_callback(self, callback: Callable[[str], None]):
         """
         Unregister a text callback function.
@@ -2145,6 +3318,18 @@ class SpeechRecognitionManager:
         """
         self.state_callbacks.append(callback)
 
+    def unregister_state_callback(self, callback: Callable[[RecognitionState], None]):
+        """
+        Unregister a state callback function.
+
+        Args:
+            callback: The callback function to remove.
+        """
+        try:
+            self.state_callbacks.remove(callback)
+        except ValueError:
+            logger.warning(f"Callback {callback} not found in state_callbacks.")
+
     def register_action_callback(self, callback: Callable[[str], None]):
         """
         Register a callback function that will be called when a special action is triggered.
@@ -2181,7 +3366,8 @@ class SpeechRecognitionManager:
 
         Args:
             device_index: The device index to use, or None for system default
-            device_name: The device name (for stable re-resolution on next recording)
+            device_name: The device name (for stable re-resolution on next recording),
+                or a monitor source name ending in ".monitor" to record system audio
         """
         if device_index != self.audio_device_index or device_name != self.audio_device_name:
             logger.info(
@@ -2191,18 +3377,447 @@ class SpeechRecognitionManager:
         self.audio_device_index = device_index
         self.audio_device_name = device_name
 
+    def _monitor_source(self) -> Optional[str]:
+        """The monitor source or application stream to record, if one is selected."""
+        if is_app_stream(self.audio_device_name):
+            return self._app_stream_target
+        return self.audio_device_name if is_monitor_source(self.audio_device_name) else None
+
+    def _capture_source(self) -> Optional[str]:
+        """What the audio is read from: the input file, monitor source or device name."""
+        if self.input_file:
+            return self.input_file
+        if is_app_stream(self.audio_device_name):
+            return self.audio_device_name
+        return self._monitor_source() or self._capture_device_name
+
+    def _find_app_stream(self) -> bool:
+        """
+        Look up the playback stream of the selected application, if one is selected.
+
+        Recording is refused while the application plays nothing: PipeWire
+        would link a stream with a missing target to the microphone instead.
+
+        Returns:
+            False if an application is selected but isn't playing audio
+        """
+        self._app_stream_target = None
+        if not is_app_stream(self.audio_device_name):
+            return True
+        app = app_stream_name(self.audio_device_name)
+        self._app_stream_target = find_app_stream(app)
+        if self._app_stream_target is None:
+            logger.error(f"{app} isn't playing audio, nothing to record")
+            _show_notification(
+                "Application Not Playing",
+                f"{app} isn't playing any audio. Start the call or video, then record again.",
+                "audio-input-microphone",
+                category="audio_device",
+            )
+            return False
+        logger.info(f"Recording the audio {app} plays (stream {self._app_stream_target})")
+        return True
+
+    def _follow_app_stream(self, audio) -> Optional[str]:
+        """
+        Keep recording the selected application after it re-creates its stream.
+
+        Args:
+            audio: The PyAudio instance recording
+
+        Returns:
+            Why recording has to stop, or None to carry on
+        """
+        app = app_stream_name(self.audio_device_name or "")
+        target = find_app_stream(app)
+        if target is None:
+            logger.info(f"{app} stopped playing audio")
+            return f"{app} stopped playing audio."
+        if target != self._app_stream_target:
+            logger.info(f"{app} switched to stream {target}, reopening")
+            self._app_stream_target = target
+            if not self._reopen_capture_stream(audio):
+                return f"The audio {app} plays could no longer be recorded."
+        return None
+
+    def _warn_if_microphone_muted(self):
+        """
+        Warn when the microphone is muted in the system sound settings.
+
+        A muted source still opens and delivers silence, so otherwise the
+        session would listen and transcribe nothing without saying why.
+        """
+        if is_source_muted():
+            logger.warning("The microphone is muted in the system sound settings")
+            _show_notification(
+                "Microphone Muted",
+                "Your microphone is muted in the system sound settings. Unmute it to dictate.",
+                "microphone-sensitivity-muted",
+                category="audio_device",
+            )
+
+    def _resolve_capture_device(self, audio) -> Optional[int]:
+        """Resolve the PortAudio device to open for the selected input."""
+        if self._monitor_source() is not None:
+            # Monitor sources aren't PortAudio devices; they are recorded through
+            # the PulseAudio/PipeWire default input with PULSE_SOURCE set
+            return _resolve_valid_input_device(audio, None)
+        return _resolve_device_by_name(audio, self.audio_device_name, self.audio_device_index)
+
+    @staticmethod
+    def _valid_vad_backend(backend) -> str:
+        if backend in VAD_BACKENDS:
+            return backend
+        logger.warning(f"Unknown VAD backend {backend!r}, using auto")
+        return "auto"
+
+    @staticmethod
+    def _valid_engine_vad(engine_vad) -> dict:
+        """
+        Check the per-engine VAD overrides from the config.
+
+        An engine whose entry isn't a table, or has a value that isn't a
+        number, is dropped with a warning. vad_sensitivity is clamped to 1-5
+        and silence_timeout to 0.5-5.0 s; other keys are ignored.
+
+        Returns:
+            The usable overrides, by engine
+        """
+        overrides = {}
+        for engine, settings in (engine_vad or {}).items():
+            if not isinstance(settings, dict):
+                logger.warning(f"Ignoring VAD overrides for {engine!r}: expected a table")
+                continue
+            valid = {}
+            try:
+                if settings.get("vad_sensitivity") is not None:
+                    valid["vad_sensitivity"] = max(1, min(5, int(settings["vad_sensitivity"])))
+                if settings.get("silence_timeout") is not None:
+                    timeout = float(settings["silence_timeout"])
+                    valid["silence_timeout"] = max(0.5, min(5.0, timeout))
+            except (TypeError, ValueError):
+                logger.warning(f"Ignoring invalid VAD overrides for {engine!r}: {settings!r}")
+                continue
+            if valid:
+                overrides[engine] = valid
+        return overrides
+
+    def vad_setting(self, key: str):
+        """
+        The value of vad_sensitivity or silence_timeout in effect for the current engine.
+
+        That is the engine's engine_vad override if it has one, else the global setting.
+        """
+        return self.engine_vad.get(self.engine, {}).get(key, getattr(self, key))
+
+    def overrides_vad_setting(self, key: str) -> bool:
+        """Whether the current engine has its own value for vad_sensitivity or silence_timeout."""
+        return key in self.engine_vad.get(self.engine, {})
+
+    def _set_vad_setting(self, key: str, value):
+        """Change a VAD setting where it takes effect: the engine's override, if it has one."""
+        if self.overrides_vad_setting(key):
+            self.engine_vad[self.engine][key] = value
+        else:
+            setattr(self, key, value)
+
+    def _engine_vad_sensitivity(self) -> int:
+        """The VAD sensitivity for the current engine."""
+        return self.vad_setting("vad_sensitivity")
+
+    def _select_vad(self):
+        """Create the detector for vad_backend."""
+        self._vad = create_vad(self.vad_backend, silero_model=self._silero_vad)
+        if self._vad is not None:
+            logger.info(f"Using {self._vad.name} VAD")
+        elif self._silero_vad is not None:
+            logger.info("Using Silero neural VAD")
+        else:
+            logger.info("Using amplitude-based VAD (install vocalinux[vad] for neural VAD)")
+
+    def _active_vad(self) -> VoiceActivityDetector:
+        """The detector for the recording loop; "auto" picks Silero when it loaded."""
+        if self._vad is not None:
+            return self._vad
+        if self._silero_vad is None:
+            return self._energy_vad
+        if self._silero_detector is None or self._silero_detector.model is not self._silero_vad:
+            self._silero_detector = SileroDetector(self._silero_vad)
+        return self._silero_detector
+
+    def set_sidetone(self, enabled: bool, volume: Optional[float] = None):
+        """
+        Turn microphone sidetone on or off; applies to a recording in progress.
+
+        Args:
+            enabled: Whether to play the microphone back while dictating
+            volume: Playback gain between 0.0 and 1.0 (unchanged if None)
+        """
+        self.sidetone_enabled = enabled
+        if volume is not None:
+            self.sidetone_volume = max(0.0, min(1.0, volume))
+        logger.info(
+            f"Microphone sidetone {'enabled' if enabled else 'disabled'} "
+            f"(volume {int(self.sidetone_volume * 100)}%)"
+        )
+
+    def set_echo_cancel(self, enabled: bool):
+        """
+        Turn echo cancellation on or off; takes effect from the next dictation.
+
+        Args:
+            enabled: Whether to remove speaker playback from the microphone signal
+        """
+        self.echo_cancel = enabled
+        logger.info(f"Echo cancellation {'enabled' if enabled else 'disabled'}")
+
+    def set_noise_suppression(self, enabled: bool):
+        """
+        Turn noise suppression on or off; takes effect from the next dictation.
+
+        Args:
+            enabled: Whether to denoise the microphone signal with RNNoise
+        """
+        self.noise_suppression = enabled
+        logger.info(f"Noise suppression {'enabled' if enabled else 'disabled'}")
+
+    @staticmethod
+    def _valid_device_gain(device_gain) -> dict:
+        gains = {}
+        for device, gain in (device_gain or {}).items():
+            try:
+                gain = float(gain)
+            except (TypeError, ValueError):
+                logger.warning(f"Ignoring invalid gain {gain!r} for audio device {device!r}")
+                continue
+            gains[device] = max(MIN_DEVICE_GAIN, min(MAX_DEVICE_GAIN, gain))
+        return gains
+
+    def set_device_gain(self, device_gain: dict):
+        """
+        Set the software gain of each input device; applies to a recording in progress.
+
+        Args:
+            device_gain: Gain factor per device name ("default" for the system default)
+        """
+        self.device_gain = self._valid_device_gain(device_gain)
+        logger.info(f"Input gain: {self.device_gain or 'none'}")
+
+    def _input_gain(self) -> float:
+        """The gain for the selected input device."""
+        return self.device_gain.get(self.audio_device_name or DEFAULT_DEVICE_GAIN_KEY, 1.0)
+
+    def set_extra_devices(self, device_names: list):
+        """
+        Set the microphones mixed into the selected one; takes effect from the next dictation.
+
+        Args:
+            device_names: Input device names, e.g. a desk mic next to a headset
+        """
+        self.extra_device_names = list(device_names or [])
+        logger.info(f"Extra microphones: {', '.join(self.extra_device_names) or 'none'}")
+
+    def _start_mic_mixer(self, audio, frames_per_buffer: int):
+        """
+        Open the extra microphones to mix into the capture.
+
+        Returns:
+            The mixer, or None if no extra microphone is configured or could be opened
+        """
+        if not self.extra_device_names or self._monitor_source() is not None:
+            return None
+        from .mic_mix import MicMixer
+
+        mixer = MicMixer()
+        for name in self.extra_device_names:
+            if name == self._capture_device_name:
+                continue
+            device_index = _find_input_device(audio, name)
+            if device_index is None:
+                logger.warning(f"Extra microphone {name} not found")
+                continue
+            try:
+                capture_format = negotiate_capture_format(audio, device_index)
+                stream = audio.open(
+                    format=capture_format.pyaudio_format,
+                    channels=capture_format.channels,
+                    rate=capture_format.rate,
+                    input=True,
+                    frames_per_buffer=frames_per_buffer,
+                    input_device_index=device_index,
+                )
+            except (IOError, OSError) as e:
+                logger.warning(f"Could not open extra microphone {name}: {e}")
+                continue
+            mixer.add(
+                name, stream, capture_format, self.device_gain.get(name, 1.0), frames_per_buffer
+            )
+        if not mixer.device_names:
+            return None
+        logger.info(f"Mixing in {', '.join(mixer.device_names)}")
+        return mixer
+
+    def set_download_options(
+        self,
+        mirror: Optional[str] = None,
+        vosk_mirror_url: Optional[str] = None,
+        limit_kbps: Optional[float] = None,
+    ):
+        """
+        Set where models are downloaded from and how fast; applies to the next download.
+
+        Args:
+            mirror: Download source for Hugging Face models (unchanged if None)
+            vosk_mirror_url: Base URL of a VOSK model mirror, "" for the official one
+            limit_kbps: Maximum download speed in KB/s, 0 for unlimited
+        """
+        if mirror is not None:
+            self.download_mirror = mirror
+        if vosk_mirror_url is not None:
+            self.vosk_mirror_url = vosk_mirror_url
+        if limit_kbps is not None:
+            self.download_limit_kbps = limit_kbps
+        logger.info(
+            f"Model downloads: mirror {self.download_mirror}, "
+            f"VOSK mirror {self.vosk_mirror_url or 'official'}, "
+            f"limit {self.download_limit_kbps or 'none'} KB/s"
+        )
+
+    def set_media_ducking(self, enabled: bool, level: Optional[float] = None):
+        """
+        Turn media ducking on or off; turning it off restores ducked streams.
+
+        Args:
+            enabled: Whether to lower other apps' playback while dictating
+            level: Fraction of their volume media keeps while ducked (unchanged if None)
+        """
+        self.duck_media = enabled
+        if level is not None:
+            self._media_ducker.level = level
+        if not enabled:
+            self._run_media_ducking(self._media_ducker.restore)
+        elif self.state == RecognitionState.LISTENING:
+            self._run_media_ducking(self._media_ducker.duck)
+        logger.info(
+            f"Media ducking {'enabled' if enabled else 'disabled'} "
+            f"(level {int(self._media_ducker.level * 100)}%)"
+        )
+
+    def _run_media_ducking(self, action: Callable[[], object]):
+        """Queue a duck or restore on the ducking worker."""
+        if self._ducking_executor is None:
+            self._ducking_executor = concurrent.futures.ThreadPoolExecutor(
+                max_workers=1, thread_name_prefix="media-ducking"
+            )
+        self._ducking_executor.submit(action)
+
+    def _sync_media_ducking(self, new_state: RecognitionState):
+        """Duck media when dictation starts and restore it when it ends."""
+        if new_state == RecognitionState.LISTENING:
+            if self.duck_media:
+                self._run_media_ducking(self._media_ducker.duck)
+        elif new_state in (RecognitionState.IDLE, RecognitionState.ERROR):
+            # Nothing can be ducked before the worker first ran
+            if self._ducking_executor is not None:
+                self._run_media_ducking(self._media_ducker.restore)
+
+    def _sync_sidetone(self, sidetone, audio, rate: int):
+        """
+        Start, update or stop the sidetone monitor to match the current setting.
+
+        Returns:
+            The monitor to write captured audio to, or None when sidetone is off
+        """
+        if not self.sidetone_enabled:
+            return self._stop_sidetone(sidetone)
+
+        if sidetone is None:
+            from .sidetone import SidetoneMonitor
+
+            sidetone = SidetoneMonitor(self.sidetone_volume)
+            # A failed start leaves an inactive monitor, so we don't retry every chunk
+            sidetone.start(audio, rate)
+        sidetone.volume = self.sidetone_volume
+        return sidetone
+
+    @staticmethod
+    def _stop_sidetone(sidetone) -> None:
+        """
+        Stop the sidetone monitor, e.g. before the stream it plays to is closed.
+
+        Returns:
+            None, to clear the caller's reference to the monitor
+        """
+        if sidetone is not None:
+            sidetone.stop()
+        return None
+
     def get_audio_device(self) -> Optional[int]:
         """Get the currently configured audio device index."""
         return self.audio_device_index
 
+    def _on_word_target_reached(self, words: int, elapsed: float):
+        """Congratulate the user on reaching the session word target."""
+        _show_notification(
+            "Word Target Reached",
+            f"You dictated {words} words in {format_elapsed(elapsed)}. Nice work!",
+            icon="emblem-default",
+        )
+
     def get_audio_device_name(self) -> Optional[str]:
         """Get the currently configured audio device name."""
         return self.audio_device_name
 
+    def get_performance_stats(self) -> dict:
+        """
+        Get decoding performance statistics.
+
+        Returns:
+            dict with ``rtf`` (real-time factor of the last decoded utterance,
+            None before the first one; >1 means slower than realtime) and
+            ``queued_segments`` (utterances waiting to be decoded)
+        """
+        return {
+            "rtf": self._last_rtf,
+            "queued_segments": self._segment_queue.qsize(),
+        }
+
+    def get_performance_summary(self) -> str:
+        """
+        Describe decoding performance in a short human-readable sentence.
+
+        Example: "decoding 1.8× slower than realtime, 2 utterances queued"
+        """
+        stats = self.get_performance_stats()
+        rtf = stats["rtf"]
+        queued = stats["queued_segments"]
+
+        if rtf is None:
+            speed = "no utterances decoded yet"
+        elif rtf > 1.0:
+            speed = f"decoding {rtf:.1f}× slower than realtime"
+        elif rtf > 0:
+            speed = f"decoding {1.0 / rtf:.1f}× faster than realtime"
+        else:
+            speed = "decoding faster than realtime"
+
+        if queued == 0:
+            backlog = "no utterances queued"
+        elif queued == 1:
+            backlog = "1 utterance queued"
+        else:
+            backlog = f"{queued} utterances queued"
+
+        return f"{speed}, {backlog}"
+
     def get_last_audio_level(self) -> float:
-        """Get the last recorded audio level (0-100)."""
+        """Get the last recorded audio level on the 0-100 meter scale (see audio_level)."""
         return self._last_audio_level
 
+    def get_last_audio_level_dbfs(self) -> float:
+        """Get the last recorded (smoothed) audio level in dBFS."""
+        return self._level_meter.level
+
     def _update_state(self, new_state: RecognitionState):
         """
         Update the recognition state and notify callbacks.
@@ -2211,6 +3826,9 @@ class SpeechRecognitionManager:
             new_state: The new recognition state
         """
         self.state = new_state
+        if new_state == RecognitionState.LISTENING:
+            self.session.start()
+        self._sync_media_ducking(new_state)
         for callback in self.state_callbacks:
             callback(new_state)
 
@@ -2232,7 +3850,7 @@ class SpeechRecognitionManager:
             )
             guard_ms = 200
 
-        chunk_duration_ms = (1024 / 16000) * 1000
+        chunk_duration_ms = (self.buffer_frames / 16000) * 1000
         return int(guard_ms / chunk_duration_ms)
 
     def start_recognition(self, mode: str = "toggle"):
@@ -2264,7 +3882,11 @@ class SpeechRecognitionManager:
         self.should_record = True
         self._recognition_mode = mode
         self.audio_buffer = []
-        self._segment_queue = queue.Queue(maxsize=32)
+        self._long_form_session = self._long_form_active()
+        self._segment_queue = queue.Queue(maxsize=self.queue_depth)
+        self._long_form_last_text = ""
+        # Numbers/letters mode only lasts for one dictation session
+        self.command_processor.set_field_mode(None)
 
         # Start the audio recording thread
         self.audio_thread = threading.Thread(target=self._record_audio)
@@ -2333,69 +3955,209 @@ class SpeechRecognitionManager:
         self._recognition_mode = "toggle"
         self._update_state(RecognitionState.IDLE)
 
-    def _record_audio(self):
-        """Record audio from the microphone with reconnection logic."""
-        # Lazy import to avoid circular dependency
-        from ..ui.audio_feedback import play_error_sound  # noqa: F401
+    def shutdown(self):
+        """
+        Stop recognition and release what it holds, for quitting Vocalinux.
 
-        try:
-            import numpy as np
-            import pyaudio
-        except ImportError as e:
-            logger.error(f"Failed to import required audio libraries: {e}")
-            logger.error("Please install required dependencies: pip install pyaudio numpy")
-            play_error_sound()
-            self._update_state(RecognitionState.ERROR)
-            return
+        Dictation in progress is finished as with stop_recognition(), so the
+        last words are still typed. Ducked media is restored before returning,
+        the echo canceller is unloaded and connections to the remote API are
+        closed.
+        """
+        logger.info("Shutting down speech recognition")
+        self.stop_recognition()
 
-        try:
-            # PyAudio configuration
-            CHUNK = 1024
-            FORMAT = pyaudio.paInt16
+        # Waits for the restore queued by the switch to IDLE
+        if self._ducking_executor is not None:
+            self._ducking_executor.shutdown(wait=True)
+            self._ducking_executor = None
 
-            # Initialize PyAudio with reconnection support
-            self._pyaudio_instance = pyaudio.PyAudio()
-            audio = self._pyaudio_instance
+        self._echo_canceller.stop()
 
-            # Resolve the input device by name first (indices can shift between
-            # sessions due to USB replugging or virtual devices being added).
-            # Fall back to the stored index, then to the system default.
-            resolved_device_index = _resolve_device_by_name(
-                audio, self.audio_device_name, self.audio_device_index
-            )
-            if resolved_device_index is None:
-                resolved_device_index = _resolve_valid_input_device(audio, None)
-            if resolved_device_index is None:
-                logger.error("No audio input devices found with input channels.")
-                logger.error(
-                    "Please connect a microphone and ensure it is recognized by the system."
-                )
-                play_error_sound()
-                audio.terminate()
-                self._update_state(RecognitionState.ERROR)
-                return
+        if self._http_session is not None:
+            self._http_session.close()
+            self._http_session = None
 
-            # Log available devices for debugging (skip virtual devices)
-            logger.debug("Available audio input devices:")
-            for i in range(audio.get_device_count()):
-                try:
-                    info = audio.get_device_info_by_index(i)
-                    if info.get("maxInputChannels", 0) > 0:
-                        name = info.get("name", "")
-                        if _is_virtual_device(name):
-                            continue
-                        logger.debug(f"  [{i}] {name} (inputs: {info.get('maxInputChannels')})")
-                except (IOError, OSError):
-                    continue
+    def panic_stop(self) -> bool:
+        """
+        Stop recognition immediately and throw away everything captured.
 
-            # Detect supported channel count first (some devices require stereo)
-            CHANNELS = _get_supported_channels(audio, resolved_device_index)
-            logger.info(f"Using {CHANNELS} channel(s) for recording")
+        Unlike stop_recognition(), nothing is transcribed or typed: buffered
+        audio, queued segments, held fragments and audio kept for retrying are
+        dropped, any transcription still running is discarded when it finishes,
+        and the remote API session is closed so open connections to the server
+        are torn down.
 
-            # Detect supported sample rate for the selected device
-            RATE = _get_supported_sample_rate(audio, resolved_device_index, CHANNELS)
-            self._capture_sample_rate = RATE
-            logger.info(f"Using sample rate: {RATE}Hz")
+        Returns:
+            True if recognition was active, False if it was already idle
+        """
+        was_active = self.state != RecognitionState.IDLE
+        logger.warning("Panic stop: discarding all captured audio")
+
+        self._session_generation += 1
+        self.should_record = False
+
+        with self._buffer_lock:
+            self.audio_buffer = []
+            self._recording_segment_has_speech = False
+        self._pending_fragment = ""
+        self._utterance_audio.clear()
+
+        while True:
+            try:
+                self._segment_queue.get_nowait()
+            except queue.Empty:
+                break
+        # Wake the recognition thread so it notices it has been abandoned
+        self._signal_recognition_stop()
+
+        # Not under _model_lock: a local decode holds it for its whole run, and a
+        # panic stop must not wait for that. Closing drops the pooled keep-alive
+        # connections; a request still in flight is discarded by the generation check.
+        old_session = self._http_session
+        if old_session is not None:
+            import requests
+
+            self._http_session = requests.Session()
+            old_session.close()
+
+        self._retract_partial()
+        self._long_form_session = False
+        self._recognition_mode = "toggle"
+        self._update_state(RecognitionState.IDLE)
+        if was_active:
+            _show_notification(
+                "Dictation Stopped", "Panic stop: captured audio was discarded.", "process-stop"
+            )
+        return was_active
+
+    def cancel_utterance(self) -> bool:
+        """
+        Discard the utterance being captured without transcribing it.
+
+        Recognition keeps listening; only buffered and not-yet-transcribed
+        audio is dropped.
+
+        Returns:
+            True if recognition was active, False if there was nothing to cancel
+        """
+        if self.state == RecognitionState.IDLE or not self.should_record:
+            return False
+
+        with self._buffer_lock:
+            discarded_chunks = len(self.audio_buffer)
+            self.audio_buffer = []
+            self._recording_segment_has_speech = False
+
+        discarded_segments = 0
+        while True:
+            try:
+                segment = self._segment_queue.get_nowait()
+            except queue.Empty:
+                break
+            if segment is None:
+                # A stop signal raced with us; leave it for the recognition thread
+                self._signal_recognition_stop()
+                break
+            discarded_segments += 1
+
+        logger.info(
+            f"Cancelled utterance: dropped {discarded_chunks} buffered chunks "
+            f"and {discarded_segments} queued segments"
+        )
+        self._retract_partial()
+        _show_notification("Utterance Cancelled", "Nothing was typed.", "edit-undo")
+        return True
+
+    def _record_audio(self):
+        """Record audio from the microphone with reconnection logic."""
+        # Lazy import to avoid circular dependency
+        from ..ui.audio_feedback import play_error_sound  # noqa: F401
+
+        # In a sandbox, ask for the microphone before PortAudio fails opaquely
+        if not self.input_file and not self._microphone_permission.check():
+            _show_notification(
+                "Microphone Access Denied",
+                denied_message(),
+                "audio-input-microphone",
+                category="microphone_permission",
+            )
+            play_error_sound()
+            self._update_state(RecognitionState.ERROR)
+            return
+        self._update_app_prompt()
+
+        try:
+            import numpy as np
+            import pyaudio
+        except ImportError as e:
+            logger.error(f"Failed to import required audio libraries: {e}")
+            logger.error("Please install required dependencies: pip install pyaudio numpy")
+            play_error_sound()
+            self._update_state(RecognitionState.ERROR)
+            return
+
+        try:
+            # PyAudio configuration
+            CHUNK = self.buffer_frames
+
+            # Initialize PyAudio with reconnection support
+            input_file_audio = self._open_input_file()
+            if (self.input_file and input_file_audio is None) or (
+                input_file_audio is None and not self._find_app_stream()
+            ):
+                play_error_sound()
+                self._update_state(RecognitionState.ERROR)
+                return
+            self._pyaudio_instance = input_file_audio or pyaudio.PyAudio()
+            audio = self._pyaudio_instance
+
+            # Resolve the input device by name first (indices can shift between
+            # sessions due to USB replugging or virtual devices being added).
+            # Fall back to the stored index, then to the system default.
+            resolved_device_index = self._resolve_capture_device(audio)
+            if resolved_device_index is None:
+                resolved_device_index = _resolve_valid_input_device(audio, None)
+            if resolved_device_index is None:
+                logger.error("No audio input devices found with input channels.")
+                logger.error(
+                    "Please connect a microphone and ensure it is recognized by the system."
+                )
+                play_error_sound()
+                audio.terminate()
+                self._update_state(RecognitionState.ERROR)
+                return
+
+            # Log available devices for debugging (skip virtual devices)
+            logger.debug("Available audio input devices:")
+            for i in range(audio.get_device_count()):
+                try:
+                    info = audio.get_device_info_by_index(i)
+                    if info.get("maxInputChannels", 0) > 0:
+                        name = info.get("name", "")
+                        if _is_virtual_device(name):
+                            continue
+                        logger.debug(f"  [{i}] {name} (inputs: {info.get('maxInputChannels')})")
+                except (IOError, OSError):
+                    continue
+
+            # Negotiate sample format, channels and rate with the device (some devices
+            # only deliver float, require stereo or only open at 48kHz)
+            self.capture_format = negotiate_capture_format(
+                audio, resolved_device_index, self.sample_rate
+            )
+            FORMAT = self.capture_format.pyaudio_format
+            CHANNELS = self.capture_format.channels
+            RATE = self.capture_format.rate
+            self._capture_sample_rate = RATE
+            logger.info(f"Recording format: {self.capture_format.describe()}")
+            resampler = StreamResampler(RATE)
+            downmixer = ChannelDownmixer(self.capture_format.channels)
+            downmix_format = self.capture_format
+            from .buffer_pool import BufferPool
+
+            buffer_pool = BufferPool()  # Scratch arrays reused for every chunk
+            denoiser = create_denoiser() if self.noise_suppression else None
 
             # Open microphone stream with optional device selection and reconnection logic
             stream_kwargs = {
@@ -2416,14 +4178,29 @@ class SpeechRecognitionManager:
 
             try:
                 device_info = audio.get_device_info_by_index(resolved_device_index)
+                self._capture_device_name = device_info.get("name")
                 logger.info(
                     f"Using audio device [{resolved_device_index}]: {device_info.get('name')}"
                 )
             except (IOError, OSError):
+                self._capture_device_name = None
                 logger.warning(f"Could not get info for device index {resolved_device_index}")
 
+            # Record from the echo-cancelled source when enabled. Only the system
+            # default input goes through PulseAudio/PipeWire, so a device picked
+            # by index is recorded as is.
+            echo_source = None
+            if self.echo_cancel and input_file_audio is None:
+                if "input_device_index" in stream_kwargs:
+                    logger.info("Echo cancellation only applies to the default input device")
+                elif self._monitor_source() is not None:
+                    logger.info("Echo cancellation doesn't apply to recording system audio")
+                else:
+                    echo_source = self._echo_canceller.start()
+
             try:
-                self._audio_stream = audio.open(**stream_kwargs)
+                with pulse_source(echo_source or self._monitor_source()):
+                    self._audio_stream = audio.open(**stream_kwargs)
                 stream = self._audio_stream
             except (IOError, OSError) as e:
                 logger.error(f"Failed to open audio stream: {e}")
@@ -2433,12 +4210,23 @@ class SpeechRecognitionManager:
                 if self._attempt_audio_reconnection(audio):
                     stream = self._audio_stream
                 else:
+                    self._echo_canceller.stop()
                     play_error_sound()
                     audio.terminate()
                     self._update_state(RecognitionState.ERROR)
                     return
 
             logger.info("Audio recording started")
+            # Only the default input goes through the sound server's mute switch
+            if (
+                input_file_audio is None
+                and "input_device_index" not in stream_kwargs
+                and self._monitor_source() is None
+            ):
+                self._warn_if_microphone_muted()
+            device_monitor = DeviceMonitor()
+            device_monitor.start()
+            mixer = self._start_mic_mixer(audio, CHUNK)
 
             # Record audio while should_record is True
             silence_counter = 0
@@ -2446,20 +4234,64 @@ class SpeechRecognitionManager:
             self._recording_segment_has_speech = False
             log_level_interval = 0  # Counter for periodic level logging
             max_level_seen = 0.0
-            # Accumulator for 512-sample Silero chunks.  When the capture rate
-            # is higher than 16 kHz (e.g. 48 kHz), resampling produces fewer
-            # than 1024 samples per read (~341 at 48 kHz), so the buffer may
-            # need several reads to fill a full 512-sample chunk.  This is
-            # expected -- VAD decisions simply arrive less frequently (every
-            # ~128 ms instead of ~64 ms) with no impact on accuracy.
-            silero_chunk_buf = np.array([], dtype=np.int16)
-
-            # Reset Silero VAD state for this recording session
-            if self._silero_vad is not None:
-                self._silero_vad.reset()
+            self._level_meter.reset()
+            sidetone = None
+            chunk_errors = 0
+            capture_error = None  # Why recording stopped on its own, for the notification
+            next_duck_check = time.monotonic() + DUCK_RECHECK_SECONDS
+            next_app_stream_check = time.monotonic() + APP_STREAM_RECHECK_SECONDS
+
+            # Reset VAD state for this recording session
+            self._active_vad().reset()
 
             while self.should_record:
                 try:
+                    if input_file_audio is not None and input_file_audio.finished:
+                        logger.info("Input file played to the end, stopping")
+                        break
+
+                    # Duck media that started playing after dictation began
+                    if self.duck_media and time.monotonic() >= next_duck_check:
+                        next_duck_check = time.monotonic() + DUCK_RECHECK_SECONDS
+                        self._run_media_ducking(self._media_ducker.duck)
+
+                    # Follow the application being recorded to its current stream
+                    if (
+                        self._app_stream_target is not None
+                        and time.monotonic() >= next_app_stream_check
+                    ):
+                        next_app_stream_check = time.monotonic() + APP_STREAM_RECHECK_SECONDS
+                        capture_error = self._follow_app_stream(audio)
+                        if capture_error:
+                            break
+                        stream = self._audio_stream
+                        if self._capture_sample_rate != RATE:
+                            sidetone = self._stop_sidetone(sidetone)
+                            RATE = self._capture_sample_rate
+
+                    # Reopen the microphone when an input device was plugged in or out
+                    change = device_monitor.take_change()
+                    if (
+                        change
+                        and input_file_audio is None
+                        and self._device_change_affects_capture(change)
+                    ):
+                        if mixer is not None:
+                            mixer.stop()
+                        # Its output stream belongs to the PyAudio instance being replaced;
+                        # it is started again below on the new one
+                        sidetone = self._stop_sidetone(sidetone)
+                        audio = self._reopen_after_device_change(audio, change)
+                        if audio is None:
+                            mixer = None
+                            capture_error = (
+                                "The microphone was disconnected and no other input was found."
+                            )
+                            break
+                        stream = self._audio_stream
+                        RATE = self._capture_sample_rate
+                        mixer = self._start_mic_mixer(audio, CHUNK)
+
                     # Check buffer size and enforce limits (with lock for thread safety)
                     with self._buffer_lock:
                         if len(self.audio_buffer) >= self._max_buffer_size:
@@ -2469,40 +4301,55 @@ class SpeechRecognitionManager:
                             # Remove oldest 25% of data to prevent memory issues
                             remove_count = self._max_buffer_size // 4
                             self.audio_buffer = self.audio_buffer[remove_count:]
+                            self._segment_speech_start = max(
+                                0, self._segment_speech_start - remove_count
+                            )
                             logger.info(f"Buffer trimmed by {remove_count} chunks")
 
                         data = stream.read(CHUNK, exception_on_overflow=False)
-
-                        # Convert stereo to mono if necessary
-                        # Speech recognition engines expect mono (1 channel) audio
-                        if CHANNELS == 2:
-                            audio_array = np.frombuffer(data, dtype=np.int16)
-                            # Reshape to (n_samples, 2) and average channels
-                            stereo_samples = audio_array.reshape(-1, 2)
-                            mono_samples = stereo_samples.mean(axis=1).astype(np.int16)
-                            data = mono_samples.tobytes()
+                        # Numbered as read, so a chunk that fails to process leaves a gap
+                        sequence = next(self._chunk_sequence)
+
+                        # Speech recognition engines expect mono (1 channel) 16-bit audio
+                        data = to_int16(data, self.capture_format.sample_format)
+                        if downmix_format is not self.capture_format:
+                            # A reconnect opened a device whose channels are chosen afresh
+                            downmix_format = self.capture_format
+                            downmixer = ChannelDownmixer(downmix_format.channels)
+                        data = downmixer.process(data)
+                        data = apply_gain(data, self._input_gain(), buffer_pool)
+
+                        # Play the microphone back to the user if sidetone is on
+                        sidetone = self._sync_sidetone(sidetone, audio, RATE)
+                        if sidetone is not None:
+                            sidetone.write(data)
 
                         # Resample to 16kHz if capturing at non-16kHz for Vosk/Whisper compatibility
-                        if self._capture_sample_rate != 16000:
-                            audio_array = np.frombuffer(data, dtype=np.int16)
-                            resample_ratio = 16000 / self._capture_sample_rate
-                            resampled_length = int(len(audio_array) * resample_ratio)
-                            resampled = np.interp(
-                                np.linspace(0, len(audio_array), resampled_length),
-                                np.arange(len(audio_array)),
-                                audio_array,
-                            ).astype(np.int16)
-                            data = resampled.tobytes()
-
-                        self.audio_buffer.append(data)
+                        if resampler.input_rate != self._capture_sample_rate:
+                            # A reconnect opened the device at a different rate
+                            resampler = StreamResampler(self._capture_sample_rate)
+                        data = resampler.process(data)
+                        if mixer is not None:
+                            data = mixer.mix(data)
+                        if denoiser is not None:
+                            data = denoiser.process(data)
+                        if self._is_own_sound_playing(input_file_audio):
+                            data = bytes(len(data))
+
+                        self.audio_buffer.append(
+                            AudioChunk(
+                                data, self._capture_source(), self._capture_sample_rate, sequence
+                            )
+                        )
 
                     # Voice Activity Detection (VAD)
                     audio_data = np.frombuffer(data, dtype=np.int16)
-                    volume = np.abs(audio_data).mean()
+                    level_dbfs = rms_dbfs(audio_data, buffer_pool)
 
-                    # Track max level and notify callbacks
-                    # Normalize to 0-100 scale (16-bit audio max is ~32768)
-                    normalized_level = min(100.0, (volume / 327.68))
+                    # Track max level and notify callbacks. Meters get the smoothed
+                    # level on the 0-100 dBFS scale; the VAD uses the raw chunk level.
+                    metered_dbfs = self._level_meter.update(level_dbfs, CHUNK / RATE)
+                    normalized_level = dbfs_to_percent(metered_dbfs)
                     self._last_audio_level = normalized_level
                     max_level_seen = max(max_level_seen, normalized_level)
 
@@ -2521,53 +4368,30 @@ class SpeechRecognitionManager:
                         )
                         log_level_interval = 0
 
-                    # Determine if current chunk contains speech
-                    is_speech = False
-                    if self._silero_vad is not None:
-                        # Silero VAD: accumulate samples into 512-sample chunks
-                        speech_prob = 0.0
-                        chunk_processed = False
-                        silero_chunk_buf = np.concatenate([silero_chunk_buf, audio_data])
-                        while len(silero_chunk_buf) >= SILERO_CHUNK_SIZE:
-                            chunk_512 = silero_chunk_buf[:SILERO_CHUNK_SIZE]
-                            silero_chunk_buf = silero_chunk_buf[SILERO_CHUNK_SIZE:]
-                            speech_prob = max(speech_prob, self._silero_vad.process(chunk_512))
-                            chunk_processed = True
-
-                        # Map vad_sensitivity (1-5) to threshold:
-                        # 1 (least sensitive) -> 0.8, 5 (most sensitive) -> 0.3
-                        try:
-                            vad_sens = int(self.vad_sensitivity)
-                            vad_sens = max(1, min(5, vad_sens))
-                        except ValueError:
-                            vad_sens = 3
-                        silero_threshold = 0.8 - (vad_sens - 1) * 0.125
-
-                        # Skip speech decision until at least one full chunk
-                        # has been processed to avoid false silence detection
-                        if chunk_processed:
-                            is_speech = speech_prob >= silero_threshold
-                    else:
-                        # Amplitude fallback when Silero is unavailable
-                        try:
-                            vad_sens = int(self.vad_sensitivity)
-                            threshold = 500 / max(1, min(5, vad_sens))
-                        except ValueError:
-                            logger.warning(
-                                f"Invalid VAD sensitivity value: {self.vad_sensitivity}. Using default 3."
-                            )
-                            threshold = 500 / 3
-                        is_speech = volume >= threshold
+                    # Determine if current chunk contains speech; no decision yet
+                    # (a partial frame) counts as silence
+                    vad = self._active_vad()
+                    is_speech = bool(
+                        vad.is_speech(audio_data, level_dbfs, self._engine_vad_sensitivity())
+                    )
 
-                    if not is_speech:  # Silence
+                    if self._long_form_session:
+                        # Ignore pauses and cut a fixed window once enough audio is buffered
+                        if is_speech:
+                            self._recording_segment_has_speech = True
+                        self._roll_long_form_window()
+                    elif not is_speech:  # Silence
                         silence_counter += CHUNK / RATE  # Convert chunks to seconds
-                        if silence_counter > self.silence_timeout:
+                        if silence_counter > self._current_silence_timeout():
                             if len(self.audio_buffer) > 0:
                                 if not self._recording_segment_has_speech:
                                     logger.debug(
                                         "Silence detected with no speech, dropping audio buffer"
                                     )
-                                    self.audio_buffer = []
+                                    # Keep the onset padding for speech starting right now
+                                    self.audio_buffer = self._onset_padding(
+                                        self.audio_buffer, CHUNK / RATE
+                                    )
                                 elif self._recognition_mode == "push_to_talk":
                                     logger.debug(
                                         "Silence detected in push-to-talk mode, "
@@ -2575,52 +4399,64 @@ class SpeechRecognitionManager:
                                     )
                                 else:
                                     logger.debug("Silence detected, queueing audio segment")
-                                    self._enqueue_audio_segment(self.audio_buffer)
-                                    self.audio_buffer = []
+                                    self._enqueue_audio_segment(
+                                        self._padded_segment(
+                                            self.audio_buffer, silence_counter, CHUNK / RATE
+                                        )
+                                    )
+                                    self.audio_buffer = self._onset_padding(
+                                        self.audio_buffer, CHUNK / RATE
+                                    )
                                     self._recording_segment_has_speech = False
+                                    self._last_segment_cut_time = time.time()
                             silence_counter = 0
+                        self._cut_long_utterance(False, len(self.audio_buffer) * CHUNK / RATE)
                     else:  # Speech
+                        if self._recording_segment_has_speech and silence_counter > 0:
+                            # A pause the speaker carried on after
+                            self._pause_timing.add_pause(silence_counter)
+                        if not self._recording_segment_has_speech:
+                            self._speech_resumed_time = time.time()
+                            self._segment_speech_start = len(self.audio_buffer) - 1
                         self._recording_segment_has_speech = True
                         if not speech_detected_in_session:
-                            if self._silero_vad is not None:
-                                logger.debug(
-                                    f"Speech detected (silero_prob={speech_prob:.2f}, "
-                                    f"threshold={silero_threshold:.3f})"
-                                )
-                            else:
-                                logger.debug(
-                                    f"Speech detected (level={normalized_level:.1f}%, "
-                                    f"threshold={500 / max(1, min(5, int(self.vad_sensitivity))):.0f})"
-                                )
+                            logger.debug(f"Speech detected ({vad.describe_decision()})")
                             speech_detected_in_session = True
                         silence_counter = 0
+                        self._cut_long_utterance(True, len(self.audio_buffer) * CHUNK / RATE)
+                    chunk_errors = 0
                 except (IOError, OSError) as e:
-                    current_time = time.time()
                     logger.error(f"Audio device error: {e}")
-
-                    # Implement reconnection logic with exponential backoff
-                    if (
-                        current_time - self._last_audio_error_time > 5.0
-                    ):  # Prevent rapid reconnection attempts
-                        self._last_audio_error_time = current_time
-
-                        if self._attempt_audio_reconnection(audio):
-                            logger.info("Audio reconnection successful, continuing recording")
-                            stream = self._audio_stream  # Update stream reference
-                            continue  # Continue recording with new stream
-                        else:
-                            logger.error("Audio reconnection failed, stopping recording")
-                            break
-                    else:
-                        logger.warning(
-                            "Audio error occurred too soon after last error, stopping recording"
-                        )
-                        break
+                    if self._recover_capture_stream(audio):
+                        stream = self._audio_stream  # Continue recording with the new stream
+                        if self._capture_sample_rate != RATE:
+                            sidetone = self._stop_sidetone(sidetone)
+                            RATE = self._capture_sample_rate
+                        continue
+                    if self.should_record:
+                        capture_error = f"The microphone stopped working: {e}"
+                    break
                 except Exception as e:
-                    logger.error(f"Unexpected error reading audio data: {e}")
+                    chunk_errors += 1
+                    if chunk_errors < MAX_CONSECUTIVE_CHUNK_ERRORS:
+                        logger.warning(f"Dropping audio chunk that failed to process: {e}")
+                        continue
+                    logger.error(
+                        f"Unexpected error reading audio data ({chunk_errors} chunks in a row): {e}"
+                    )
+                    capture_error = (
+                        "The microphone audio could not be processed. See the log for details."
+                    )
                     break
 
             # Clean up
+            device_monitor.stop()
+            if mixer is not None:
+                mixer.stop()
+            self._stop_sidetone(sidetone)
+            if denoiser is not None:
+                denoiser.close()
+
             if stream and hasattr(stream, "is_active") and stream.is_active():
                 try:
                     stream.stop_stream()
@@ -2633,6 +4469,7 @@ class SpeechRecognitionManager:
                     audio.terminate()
                 except Exception as e:
                     logger.warning(f"Error terminating PyAudio: {e}")
+            self._echo_canceller.stop()
 
             # Reset audio stream reference and reconnection state
             self._audio_stream = None
@@ -2650,80 +4487,770 @@ class SpeechRecognitionManager:
 
             logger.info("Audio recording stopped")
 
+            if input_file_audio is not None and input_file_audio.finished:
+                # Finish the session as if the user had stopped it; stop_recognition
+                # waits for this thread, so it can't be called from here
+                threading.Thread(target=self.stop_recognition, daemon=True).start()
+
+            if capture_error:
+                _show_notification(
+                    "Recording Stopped",
+                    capture_error,
+                    "audio-input-microphone",
+                    category="audio_device",
+                )
+                play_error_sound()
+                self._update_state(RecognitionState.ERROR)
+
         except Exception as e:
             logger.error(f"Error in audio recording: {e}")
+            self._echo_canceller.stop()
             play_error_sound()
             self._update_state(RecognitionState.ERROR)
 
-    def _process_final_buffer(self):
-        """Process the final audio buffer after silence is detected."""
-        with self._buffer_lock:
-            if not self.audio_buffer:
-                return
+    def _open_input_file(self):
+        """
+        A FileAudio playing the input file, if one is set.
+
+        Returns:
+            The FileAudio, or None without an input file or if it can't be read
+        """
+        if not self.input_file:
+            return None
+        from .file_input import FileAudio
+
+        try:
+            audio = FileAudio(self.input_file, self.input_file_speed)
+        except (OSError, ValueError) as e:
+            logger.error(f"Cannot play input file {self.input_file}: {e}")
+            _show_notification(
+                "Cannot Play Input File",
+                f"{os.path.basename(self.input_file)}: {e}",
+                "dialog-error",
+                category="audio_device",
+            )
+            return None
+        logger.info(f"Playing {self.input_file} ({audio.duration:.1f} s) instead of the microphone")
+        return audio
+
+    def _current_silence_timeout(self) -> float:
+        """Seconds of silence that end a segment, adapted to the speaker if enabled."""
+        timeout = self.vad_setting("silence_timeout")
+        if not self.adaptive_silence_timeout:
+            return timeout
+        return self._pause_timing.timeout(timeout)
+
+    def _roll_long_form_window(self) -> bool:
+        """
+        Queue the buffered audio once it spans a full long-form window.
+
+        The last LONG_FORM_OVERLAP_SECONDS of the window are kept in the buffer
+        to start the next one.
+
+        Returns:
+            True if a window was completed, False if more audio is needed
+        """
+        bytes_per_second = 2 * 16000
+        if sum(len(chunk) for chunk in self.audio_buffer) < (
+            LONG_FORM_WINDOW_SECONDS * bytes_per_second
+        ):
+            return False
+
+        overlap = []
+        overlap_bytes = 0
+        for chunk in reversed(self.audio_buffer):
+            if overlap_bytes >= LONG_FORM_OVERLAP_SECONDS * bytes_per_second:
+                break
+            overlap.insert(0, chunk)
+            overlap_bytes += len(chunk)
+
+        if self._recording_segment_has_speech:
+            logger.debug(f"Long-form window complete, queueing {len(self.audio_buffer)} chunks")
+            self._enqueue_audio_segment(self.audio_buffer)
+        else:
+            logger.debug("Long-form window had no speech, dropping it")
+            self._long_form_last_text = ""
+
+        self.audio_buffer = overlap
+        self._recording_segment_has_speech = False
+        return True
+
+    def _process_final_buffer(self):
+        """Process the final audio buffer after silence is detected."""
+        with self._buffer_lock:
+            if not self.audio_buffer:
+                return
+
+            audio_buffer = self.audio_buffer.copy()
+            self.audio_buffer = []
+
+        self._process_audio_buffer(audio_buffer)
+
+    def _transcribe_buffer(self, audio_buffer: list[bytes]) -> Optional[str]:
+        """
+        Transcribe an audio segment with the active engine.
+
+        Returns:
+            The raw transcript, or None if the engine could not run
+        """
+        self._last_confidence = None
+        if self.engine == "vosk":
+            # Lock recognizer access to prevent race condition with reconfigure
+            with self._model_lock:
+                # Check if recognizer is still valid
+                if self.recognizer is None:
+                    logger.warning("Recognizer is None during processing, returning empty result")
+                    return None
+                for data in audio_buffer:
+                    self.recognizer.AcceptWaveform(data)
+
+                result = json.loads(self.recognizer.FinalResult())
+                text = result.get("text", "")
+            if text and not self._is_enrolled_speaker(result):
+                text = ""
+
+        elif self.engine == "whisper":
+            text = self._transcribe_with_whisper(audio_buffer)
+
+        elif self.engine == "whisper_cpp":
+            text = self._transcribe_with_whispercpp(audio_buffer)
+
+        elif self.engine == "remote_api":
+            # Snapshot the HTTP session under lock to prevent race with
+            # reconfigure() / reinitialize_after_resume() which close/recreate
+            # the session under _model_lock.  The snapshot (a local reference)
+            # remains valid even if another thread closes the old session —
+            # urllib3's PoolManager keeps existing connections alive until the
+            # in-flight request completes.
+            with self._model_lock:
+                session = self._http_session
+            if session is None:
+                logger.error("Remote API HTTP session not initialized")
+                return self._transcribe_offline_fallback(audio_buffer)
+            self._remote_api_unreachable = False
+            text = self._transcribe_with_remote_api(audio_buffer, session)
+            if self._remote_api_unreachable:
+                fallback_text = self._transcribe_offline_fallback(audio_buffer)
+                if fallback_text is not None:
+                    # Dictation carried on, so the user needn't be interrupted
+                    text = fallback_text
+                else:
+                    _show_notification(
+                        "Remote Server Unreachable",
+                        f"Could not transcribe with {self.remote_api_url}.",
+                        category="remote_api",
+                    )
+
+        else:
+            logger.error(f"Unknown engine: {self.engine}")
+            return None
+
+        return text
+
+    def register_comparison_callback(
+        self, callback: Callable[[EngineTranscript, EngineTranscript], None]
+    ):
+        """
+        Register a callback for engine comparison results.
+
+        Args:
+            callback: Called with the primary and comparison transcripts of each
+                utterance while comparison mode is on (from a background thread)
+        """
+        self._comparison_callbacks.append(callback)
+
+    def unregister_comparison_callback(
+        self, callback: Callable[[EngineTranscript, EngineTranscript], None]
+    ):
+        """Unregister a callback added with register_comparison_callback."""
+        if callback in self._comparison_callbacks:
+            self._comparison_callbacks.remove(callback)
+
+    def _start_comparison(self, audio_buffer: list[bytes]) -> Optional[UtteranceComparison]:
+        """
+        Decode a segment with the comparison engine in the background.
+
+        Returns:
+            The comparison to hand the primary transcript to, or None if
+            comparison mode is off or the comparison engine is still busy
+        """
+        if not self.comparison_engine or self.comparison_engine == self.engine:
+            return None
+        if not self._comparison_callbacks:
+            return None
+        if self._comparison_busy:
+            logger.debug("Comparison engine still busy, skipping this utterance")
+            return None
+
+        self._comparison_busy = True
+        comparison = UtteranceComparison(self._report_comparison)
+        threading.Thread(
+            target=self._run_comparison, args=(comparison, list(audio_buffer)), daemon=True
+        ).start()
+        return comparison
+
+    def _run_comparison(self, comparison: UtteranceComparison, audio_buffer: list[bytes]):
+        engine = self.comparison_engine
+        start = time.time()
+        text = None
+        try:
+            manager = self._get_comparison_manager()
+            if manager._model_initialized:
+                text = manager._transcribe_buffer(audio_buffer)
+        except Exception as e:
+            logger.warning(f"Comparison engine {engine} failed: {e}")
+        finally:
+            self._comparison_busy = False
+        comparison.set_comparison(EngineTranscript(engine, text, time.time() - start))
+
+    def _create_secondary_manager(
+        self, engine: str, model_size: str, purpose: str, **kwargs
+    ) -> "SpeechRecognitionManager":
+        """
+        Create a manager that only decodes segments for this one.
+
+        Args:
+            engine: Engine to decode with
+            model_size: Its model, which must already be downloaded
+            purpose: What it is for, for the log ("comparison", "rewriting")
+            **kwargs: Further SpeechRecognitionManager settings
+        """
+        logger.info(f"Loading {purpose} engine {engine} ({model_size} model)")
+        manager = SpeechRecognitionManager(
+            engine=engine,
+            model_size=model_size,
+            language=self.language,
+            defer_download=True,
+            voice_commands_enabled=False,
+            **kwargs,
+        )
+        if not manager._model_initialized:
+            logger.warning(
+                f"The {model_size} {engine} model is not downloaded; "
+                f"select it once in Settings to download it for {purpose}"
+            )
+        return manager
+
+    def _get_comparison_manager(self) -> "SpeechRecognitionManager":
+        """The manager decoding with the comparison engine, created on first use."""
+        with self._comparison_lock:
+            if self._comparison_manager is None:
+                self._comparison_manager = self._create_secondary_manager(
+                    self.comparison_engine, self.comparison_model_size, "comparison"
+                )
+            return self._comparison_manager
+
+    def _report_comparison(self, primary: EngineTranscript, comparison: EngineTranscript):
+        for callback in list(self._comparison_callbacks):
+            try:
+                callback(primary, comparison)
+            except Exception as e:
+                logger.error(f"Error in comparison callback: {e}")
+
+    def register_rewrite_callback(self, callback: Callable[[str, str], None]):
+        """
+        Register a callback for rewritten transcripts while draft-then-rewrite is on.
+
+        Args:
+            callback: Called with the draft text given to the text callbacks and the
+                larger model's text that should replace it (from a background thread)
+        """
+        self._rewrite_callbacks.append(callback)
+
+    def _rewrite_active(self) -> bool:
+        """Whether drafts are rewritten: a different model is set and someone listens."""
+        if not self.rewrite_engine or not self._rewrite_callbacks:
+            return False
+        return (self.rewrite_engine, self.rewrite_model_size) != (self.engine, self.model_size)
+
+    def _start_rewrite(self, audio_buffer: list[bytes], draft: str) -> bool:
+        """
+        Decode a segment again with the rewrite model in the background.
+
+        Args:
+            audio_buffer: The segment the draft was decoded from
+            draft: The text delivered for it
+
+        Returns:
+            Whether a rewrite was started; not while the previous one is running
+        """
+        if not self._rewrite_active():
+            return False
+        if self._rewrite_busy:
+            logger.debug("Rewrite model still busy, keeping this draft")
+            return False
+
+        self._rewrite_busy = True
+        threading.Thread(
+            target=self._run_rewrite,
+            args=(list(audio_buffer), draft, self._session_generation, self._language_tag()),
+            daemon=True,
+        ).start()
+        return True
+
+    def _run_rewrite(self, audio_buffer: list[bytes], draft: str, generation: int, tag: str):
+        text = None
+        try:
+            manager = self._get_rewrite_manager()
+            if manager._model_initialized:
+                text = manager._transcribe_buffer(audio_buffer)
+        except Exception as e:
+            logger.warning(f"Rewrite with {self.rewrite_engine} failed: {e}")
+        finally:
+            self._rewrite_busy = False
+        if not text or generation != self._session_generation:
+            return
+
+        # Voice commands already ran on the draft; a rewrite only changes words
+        rewritten, actions = self._prepare_text(
+            text, run_intents=False, engine=self.rewrite_engine
+        )
+        if not rewritten or actions:
+            return
+        rewritten = tag + rewritten
+        if rewritten == draft:
+            return
+
+        logger.debug(f"Rewrote draft '{draft[:50]}' as '{rewritten[:50]}'")
+        for callback in list(self._rewrite_callbacks):
+            try:
+                callback(draft, rewritten)
+            except Exception as e:
+                logger.error(f"Error in rewrite callback: {e}")
+
+    def _get_rewrite_manager(self) -> "SpeechRecognitionManager":
+        """The manager decoding with the rewrite model, created on first use."""
+        with self._rewrite_lock:
+            if self._rewrite_manager is None:
+                self._rewrite_manager = self._create_secondary_manager(
+                    self.rewrite_engine,
+                    self.rewrite_model_size,
+                    "rewriting",
+                    translate=self.translate,
+                )
+            return self._rewrite_manager
+
+    def _remember_utterance_audio(self, text: str, audio_buffer: list[bytes]):
+        """Keep the audio a transcript was decoded from, if retrying is enabled."""
+        if self.retry_audio:
+            self._utterance_audio.append((text.strip(), list(audio_buffer)))
+
+    def _utterance_audio_for(self, text: str) -> Optional[list[bytes]]:
+        text = text.strip()
+        for kept_text, audio_buffer in reversed(self._utterance_audio):
+            if kept_text == text:
+                return audio_buffer
+        return None
+
+    def can_retry(self, text: str) -> bool:
+        """Whether the audio of a recent transcript is kept, so retry_utterance() can redo it."""
+        return self._utterance_audio_for(text) is not None
+
+    def retry_utterance(self, text: str) -> Optional[str]:
+        """
+        Decode a recent utterance again with the retry model.
+
+        Loading the model and decoding can take a while, so call this from a
+        background thread.
+
+        Args:
+            text: The transcript as given to the text callbacks
+
+        Returns:
+            The new transcript, or None if the audio isn't kept or decoding failed
+        """
+        audio_buffer = self._utterance_audio_for(text)
+        if audio_buffer is None:
+            return None
+        try:
+            manager = self._get_retry_manager()
+            if not manager._model_initialized:
+                return None
+            retried = manager._transcribe_buffer(audio_buffer)
+        except Exception as e:
+            logger.warning(f"Retry with the {self.retry_model_size} model failed: {e}")
+            return None
+        if not retried:
+            return None
+        # Voice commands in it already ran when it was dictated
+        processed_text, _actions = self._prepare_text(retried, run_intents=False)
+        return processed_text or None
+
+    def _get_retry_manager(self) -> "SpeechRecognitionManager":
+        """The manager decoding with the retry model, created on first use."""
+        if self.retry_model_size == self.model_size:
+            return self
+        with self._retry_lock:
+            # Loaded again after the engine, model or language changed
+            key = (self.engine, self.retry_model_size, self.language)
+            if self._retry_manager is None or self._retry_manager_key != key:
+                self._retry_manager = self._create_secondary_manager(
+                    self.engine, self.retry_model_size, "retrying", translate=self.translate
+                )
+                self._retry_manager_key = key
+            return self._retry_manager
+
+    def _transcribe_offline_fallback(self, audio_buffer: list[bytes]) -> Optional[str]:
+        """
+        Decode a segment locally because the remote server couldn't be reached.
+
+        Returns:
+            The transcript, or None if no fallback is set or its model isn't downloaded
+        """
+        if not self.offline_fallback_engine:
+            return None
+        try:
+            manager = self._get_fallback_manager()
+            if not manager._model_initialized:
+                return None
+            logger.info(f"Remote server unreachable, transcribing with {manager.engine}")
+            return manager._transcribe_buffer(audio_buffer)
+        except Exception as e:
+            logger.warning(f"Offline fallback with {self.offline_fallback_engine} failed: {e}")
+            return None
+
+    def _get_fallback_manager(self) -> "SpeechRecognitionManager":
+        """The manager decoding with the offline fallback engine, created on first use."""
+        with self._fallback_lock:
+            # Loaded again after the fallback model or language changed
+            key = (self.offline_fallback_engine, self.offline_fallback_model_size, self.language)
+            if self._fallback_manager is None or self._fallback_manager_key != key:
+                self._fallback_manager = self._create_secondary_manager(
+                    self.offline_fallback_engine,
+                    self.offline_fallback_model_size,
+                    "offline fallback",
+                )
+                self._fallback_manager_key = key
+            return self._fallback_manager
+
+    def _warm_offline_fallback(self):
+        """Load the offline fallback model in the background if standby is on."""
+        if self.engine != "remote_api" or not self.offline_fallback_engine:
+            return
+        if not self.offline_fallback_standby:
+            return
+        threading.Thread(target=self._load_offline_fallback, daemon=True).start()
+
+    def _load_offline_fallback(self):
+        try:
+            self._get_fallback_manager()
+        except Exception as e:
+            logger.warning(f"Could not load the offline fallback model: {e}")
+
+    @staticmethod
+    def _check_segment_chunks(audio_buffer: list[bytes]):
+        """Log chunks dropped from a segment and a change of input in the middle of it."""
+        missing = missing_chunks(audio_buffer)
+        if missing:
+            logger.warning(f"{missing} audio chunks are missing from this segment")
+        sources = chunk_sources(audio_buffer)
+        if len(sources) > 1:
+            logger.info(f"Segment was recorded from {', '.join(sources)}")
+        first, last = audio_buffer[0], audio_buffer[-1]
+        if isinstance(first, AudioChunk) and isinstance(last, AudioChunk):
+            logger.debug(
+                f"Segment chunks {first.sequence}-{last.sequence} "
+                f"from {first.source or 'the default input'} at {first.capture_rate} Hz"
+            )
+
+    def _process_audio_buffer(self, audio_buffer: list[bytes]):
+        """Process an immutable audio segment for transcription and commands."""
+        if not audio_buffer:
+            return
+
+        generation = self._session_generation
+        self._check_segment_chunks(audio_buffer)
+        self.audio_dumper.dump(audio_buffer)
+        decode_start = time.time()
+
+        comparison = self._start_comparison(audio_buffer)
+        text = self._transcribe_buffer(audio_buffer)
+        if comparison is not None:
+            comparison.set_primary(EngineTranscript(self.engine, text, time.time() - decode_start))
+        if text is None:
+            self._retract_partial()
+            return
+
+        decode_seconds = time.time() - decode_start
+        audio_seconds = sum(len(chunk) for chunk in audio_buffer) / (2 * 16000)
+        if audio_seconds > 0:
+            self._last_rtf = decode_seconds / audio_seconds
+
+        if self._long_form_session and text:
+            previous_text = self._long_form_last_text
+            self._long_form_last_text = text
+            text = _merge_overlapping_transcript(previous_text, text)
+
+        self.utterance_log.record(
+            text,
+            self.engine,
+            model=self.model_size,
+            language=self.detected_language or self.language,
+            audio_seconds=audio_seconds,
+            decode_seconds=decode_seconds,
+            confidence=self._last_confidence,
+        )
+
+        logger.debug(f"_process_audio_buffer got text='{text[:50] if text else '(empty)'}...'")
+        if generation != self._session_generation:
+            logger.info("Discarding transcription that finished after a panic stop")
+            return
+        if text:
+            self._hold_or_emit_text(text, audio_buffer)
+        else:
+            self._retract_partial()
+
+    def _hold_or_emit_text(self, text: str, audio_buffer: Optional[list[bytes]] = None):
+        """
+        Emit transcribed text, holding it briefly if the next utterance may continue it.
+
+        Args:
+            text: The transcript
+            audio_buffer: The segment it was decoded from, to rewrite the draft from
+        """
+        if self._pending_fragment:
+            text = _join_utterance_fragments(self._pending_fragment, text)
+            self._pending_fragment = ""
+            # A joined sentence spans two segments; only single segments are rewritten
+            audio_buffer = None
+
+        if self.utterance_join_window > 0 and self.should_record and not self._long_form_session:
+            self._pending_fragment = text
+            return
+
+        draft = self._emit_text(text)
+        if draft and audio_buffer and not self._long_form_session:
+            self._remember_utterance_audio(draft, audio_buffer)
+            self._start_rewrite(audio_buffer, draft)
+
+    def _flush_pending_fragment(self, force: bool = False):
+        """
+        Emit held text once it is clear no continuation is coming.
+
+        Args:
+            force: Emit immediately, e.g. when the recognition loop exits
+        """
+        if not self._pending_fragment:
+            return
+
+        if not force:
+            cut_time = self._last_segment_cut_time
+            if (
+                self._recording_segment_has_speech
+                and self._speech_resumed_time - cut_time <= self.utterance_join_window
+            ):
+                # Speech resumed in time; the next segment will be joined to this one
+                return
+            if time.time() - cut_time < self.utterance_join_window:
+                return
+
+        text = self._pending_fragment
+        self._pending_fragment = ""
+        self._emit_text(text)
+
+    def _whisper_task(self) -> str:
+        """The Whisper decoding task: "translate" (to English) or "transcribe"."""
+        return "translate" if self.translate else "transcribe"
+
+    def _output_language(self) -> str:
+        """Language of the text the engine produces; English when translating."""
+        if self.translate and self.engine in TRANSLATE_ENGINES:
+            return "en"
+        return self.language
+
+    @staticmethod
+    def _valid_sample_rate(rate) -> Optional[int]:
+        """Return the capture rate override if it is usable, otherwise None (automatic)."""
+        if rate in (None, "", 0):
+            return None
+        try:
+            value = int(rate)
+        except (TypeError, ValueError):
+            value = 0
+        if MIN_CAPTURE_SAMPLE_RATE <= value <= MAX_CAPTURE_SAMPLE_RATE:
+            return value
+        logger.warning(f"Ignoring invalid sample rate override '{rate}', picking automatically")
+        return None
+
+    @staticmethod
+    def _bounded_setting(name: str, value, default: int, minimum: int, maximum: int) -> int:
+        """Return a numeric setting kept within its bounds, or the default if it isn't a number."""
+        if value is None:
+            return default
+        try:
+            number = int(value)
+        except (TypeError, ValueError):
+            logger.warning(f"Ignoring invalid {name} '{value}', using {default}")
+            return default
+        bounded = min(max(number, minimum), maximum)
+        if bounded != number:
+            logger.warning(f"{name} {number} is out of range, using {bounded}")
+        return bounded
+
+    @classmethod
+    def _max_utterance_setting(cls, value) -> int:
+        """Return the maximum utterance length in seconds; 0 turns the cutoff off."""
+        if value == 0:
+            return 0
+        return cls._bounded_setting(
+            "max_utterance_seconds",
+            value,
+            DEFAULT_MAX_UTTERANCE_SECONDS,
+            MIN_MAX_UTTERANCE_SECONDS,
+            MAX_MAX_UTTERANCE_SECONDS,
+        )
+
+    @classmethod
+    def _speech_pad_setting(cls, name: str, value) -> int:
+        """Return a speech padding length in milliseconds; 0 keeps no padding."""
+        return cls._bounded_setting(name, value, DEFAULT_SPEECH_PAD_MS, 0, MAX_SPEECH_PAD_MS)
+
+    @staticmethod
+    def _valid_profanity_filter_mode(mode: str) -> str:
+        """Return the mode if it is known, otherwise fall back to "off"."""
+        if mode in PROFANITY_FILTER_MODES:
+            return mode
+        logger.warning(f"Unknown profanity filter mode '{mode}', filtering disabled")
+        return "off"
+
+    def _formatting_stages(self) -> dict:
+        """Pipeline stages that only reformat the text, usable on partial results too."""
+        return {
+            "numbers": lambda text, actions: (self.number_formatter.format(text), actions),
+            "profanity": lambda text, actions: (
+                self.profanity_filter.apply(text, self._output_language()),
+                actions,
+            ),
+            # Last by default so acronyms and brand names keep their capitalization
+            "capitalization": lambda text, actions: (self.term_capitalizer.apply(text), actions),
+        }
 
-            audio_buffer = self.audio_buffer.copy()
-            self.audio_buffer = []
+    def _format_text(self, text: str) -> str:
+        """Apply the pipeline's formatting stages: numbers, profanity and capitalization."""
+        return self.text_pipeline.run(text, self._formatting_stages())[0]
 
-        self._process_audio_buffer(audio_buffer)
+    def _partial_results_active(self) -> bool:
+        """Whether partial results are enabled and supported by the current engine."""
+        return bool(self.partial_results) and self.engine in PARTIAL_RESULT_ENGINES
 
-    def _process_audio_buffer(self, audio_buffer: list[bytes]):
-        """Process an immutable audio segment for transcription and commands."""
-        if not audio_buffer:
+    def _maybe_emit_partial(self):
+        """Decode the segment being recorded and report it as a partial result."""
+        if not self._partial_callbacks or not self._partial_results_active():
+            return
+        # Held fragments and long-form windows are emitted differently; stay out of their way
+        if not self.should_record or self._long_form_session or self._pending_fragment:
+            return
+        if not self._recording_segment_has_speech:
             return
 
-        if self.engine == "vosk":
-            # Lock recognizer access to prevent race condition with reconfigure
-            with self._model_lock:
-                # Check if recognizer is still valid
-                if self.recognizer is None:
-                    logger.warning("Recognizer is None during processing, returning empty result")
-                    return
-                for data in audio_buffer:
-                    self.recognizer.AcceptWaveform(data)
+        now = time.time()
+        if now - self._last_partial_time < PARTIAL_RESULT_INTERVAL_SECONDS:
+            return
+        with self._buffer_lock:
+            snapshot = self.audio_buffer.copy()
+        audio_seconds = sum(len(chunk) for chunk in snapshot) / (2 * 16000)
+        if audio_seconds < PARTIAL_RESULT_MIN_AUDIO_SECONDS:
+            return
+        if len(snapshot) == self._last_partial_chunks:
+            return
 
-                result = json.loads(self.recognizer.FinalResult())
-                text = result.get("text", "")
+        self._last_partial_time = now
+        self._last_partial_chunks = len(snapshot)
+        generation = self._session_generation
+        text = self._transcribe_buffer(snapshot)
+        if not text or generation != self._session_generation or not self.should_record:
+            return
 
-        elif self.engine == "whisper":
-            text = self._transcribe_with_whisper(audio_buffer)
+        # Voice commands are left to the final text so a misheard partial can't run them
+        if self.punctuation_level in ("off", "commands"):
+            text = strip_punctuation(text)
+        partial_text = self._format_text(text.strip())
+        if partial_text and partial_text != self._partial_text:
+            self._partial_text = partial_text
+            for callback in self._partial_callbacks:
+                callback(partial_text)
+
+    def _retract_partial(self):
+        """Tell partial callbacks to withdraw the partial text no final will replace."""
+        if not self._partial_text:
+            return
+        self._partial_text = ""
+        for callback in self._partial_callbacks:
+            callback("")
 
-        elif self.engine == "whisper_cpp":
-            text = self._transcribe_with_whispercpp(audio_buffer)
+    def _prepare_text(
+        self, text: str, run_intents: bool = True, engine: Optional[str] = None
+    ) -> tuple[str, list]:
+        """
+        Turn a transcript into the text to type and the voice command actions.
 
-        elif self.engine == "remote_api":
-            # Snapshot the HTTP session under lock to prevent race with
-            # reconfigure() / reinitialize_after_resume() which close/recreate
-            # the session under _model_lock.  The snapshot (a local reference)
-            # remains valid even if another thread closes the old session —
-            # urllib3's PoolManager keeps existing connections alive until the
-            # in-flight request completes.
-            with self._model_lock:
-                session = self._http_session
-            if session is None:
-                logger.error("Remote API HTTP session not initialized")
-                return
-            text = self._transcribe_with_remote_api(audio_buffer, session)
+        Args:
+            text: The engine's transcript
+            run_intents: Whether an intent command may take the utterance
+            engine: Engine that produced the transcript, if not the main one
 
-        else:
-            logger.error(f"Unknown engine: {self.engine}")
-            return
+        Returns:
+            The processed text and the actions of the voice commands in it
+        """
+        engine = engine or self.engine
+        punctuation_level = self.punctuation_level
+        if punctuation_level in ("off", "commands"):
+            # Drop the engine's own punctuation; only spoken commands may add any
+            text = strip_punctuation(text)
+
+        def commands(text: str, actions: list) -> tuple[str, list]:
+            if not self._voice_commands_enabled:
+                # Voice commands disabled - pass text through directly (Whisper handles punctuation)
+                return text, actions
+            processed_text, command_actions = self.command_processor.process_text(text)
+            return processed_text, actions + command_actions
+
+        def intents(text: str, actions: list) -> tuple[str, list]:
+            # An intent takes the whole utterance instead of it being typed
+            if run_intents and self.intent_commands_enabled and self._intent_handler.handle(text):
+                return "", []
+            return text, actions
+
+        def punctuation(text: str, actions: list) -> tuple[str, list]:
+            if punctuation_level == "off":
+                return strip_punctuation(text), actions
+            if punctuation_level == "auto" and engine not in NATIVE_PUNCTUATION_ENGINES:
+                return restore_punctuation(text), actions
+            return text, actions
+
+        stages = {"commands": commands, "intents": intents, "punctuation": punctuation}
+        return self.text_pipeline.run(text, {**stages, **self._formatting_stages()})
+
+    def _language_tag(self) -> str:
+        """Prefix for typed text when tagging the detected language, e.g. "[de] "."""
+        if self.tag_language and self.detected_language:
+            return f"[{self.detected_language}] "
+        return ""
 
-        # Process text - either with voice commands or pass through directly
-        logger.debug(f"_process_audio_buffer got text='{text[:50] if text else '(empty)'}...'")
+    def _emit_text(self, text: str) -> str:
+        """
+        Run voice commands on the text and deliver it to the callbacks.
+
+        Returns:
+            The text delivered to the text callbacks if the utterance ran no
+            voice command actions, otherwise ""
+        """
         if text:
-            if self._voice_commands_enabled:
-                # Process with voice commands (original behavior)
-                processed_text, actions = self.command_processor.process_text(text)
+            processed_text, actions = self._prepare_text(text)
+            if processed_text:
+                # The final text replaces the partial on screen
+                self._partial_text = ""
             else:
-                # Voice commands disabled - pass text through directly (Whisper handles punctuation)
-                processed_text = text.strip()
-                actions = []
+                # Nothing to type (e.g. only a voice command): remove the partial first
+                self._retract_partial()
 
             # Call text callbacks with processed text
             logger.debug(
                 f"processed_text='{processed_text[:50] if processed_text else '(empty)'}...', callbacks={len(self.text_callbacks)}"
             )
             if processed_text:
+                self.session.add_text(processed_text)
+                processed_text = self._language_tag() + processed_text
                 for callback in self.text_callbacks:
                     logger.debug(
                         f"invoking text callback: {callback.__name__ if hasattr(callback, '__name__') else callback}"
@@ -2735,16 +5262,185 @@ class SpeechRecognitionManager:
                 for callback in self.action_callbacks:
                     callback(action)
 
+            if processed_text or actions:
+                play_utterance_sound()
+
+            if not actions:
+                return processed_text
+        return ""
+
+    def transcribe_segments(self, audio_buffer: list[bytes]) -> list[dict]:
+        """
+        Transcribe an audio buffer into timed segments.
+
+        Used by offline transcription (``vocalinux transcribe``) where callers
+        want timestamps and confidence rather than a single string. Voice
+        commands are not applied.
+
+        Args:
+            audio_buffer: List of audio data chunks (16-bit PCM at 16kHz)
+
+        Returns:
+            List of dicts with ``start``/``end`` (seconds), ``text``,
+            ``confidence`` (0-1 or None when the engine doesn't report it) and
+            ``language`` (detected or configured code, None if unknown).
+        """
+        if not audio_buffer:
+            return []
+
+        duration = len(b"".join(audio_buffer)) / (2 * 16000)
+        configured_language = None if self.language == "auto" else self.language
+        segments = []
+
+        if self.engine == "vosk":
+            with self._model_lock:
+                if self.recognizer is None:
+                    logger.warning("Recognizer is None during transcription, returning no segments")
+                    return []
+                self.recognizer.SetWords(True)
+                try:
+                    for data in audio_buffer:
+                        self.recognizer.AcceptWaveform(data)
+                    result = json.loads(self.recognizer.FinalResult())
+                finally:
+                    self.recognizer.SetWords(False)
+
+            words = result.get("result", [])
+            text = result.get("text", "").strip()
+            if text:
+                confidences = [w["conf"] for w in words if "conf" in w]
+                segments.append(
+                    {
+                        "start": words[0]["start"] if words else 0.0,
+                        "end": words[-1]["end"] if words else duration,
+                        "text": text,
+                        "confidence": (
+                            sum(confidences) / len(confidences) if confidences else None
+                        ),
+                        "language": configured_language,
+                    }
+                )
+
+        elif self.engine == "whisper":
+            import numpy as np
+
+            audio_data = np.frombuffer(b"".join(audio_buffer), dtype=np.int16)
+            audio_float = audio_data.astype(np.float32) / 32768.0
+            lang = self.language
+            if self.language == "en-us":
+                lang = "en"
+            elif self.language == "auto":
+                lang = None
+
+            with self._model_lock:
+                if self.model is None:
+                    logger.warning("Model is None during transcription, returning no segments")
+                    return []
+                result = self.model.transcribe(
+                    audio_float,
+                    language=lang,
+                    task=self._whisper_task(),
+                    verbose=False,
+                    temperature=0.0,
+                    no_speech_threshold=0.6,
+                    fp16=False,
+                    initial_prompt=self._session_prompt() or None,
+                )
+
+            detected_language = result.get("language") or configured_language
+            for segment in result.get("segments", []):
+                text = _filter_non_speech(segment.get("text", "").strip())
+                if not text:
+                    continue
+                avg_logprob = segment.get("avg_logprob")
+                segments.append(
+                    {
+                        "start": float(segment.get("start", 0.0)),
+                        "end": float(segment.get("end", 0.0)),
+                        "text": text,
+                        "confidence": (
+                            round(math.exp(avg_logprob), 3) if avg_logprob is not None else None
+                        ),
+                        "language": detected_language,
+                    }
+                )
+
+        elif self.engine == "whisper_cpp":
+            import numpy as np
+
+            audio_data = np.frombuffer(b"".join(audio_buffer), dtype=np.int16)
+            audio_float = audio_data.astype(np.float32) / 32768.0
+            lang = self.language
+            if self.language == "en-us":
+                lang = "en"
+            elif self.language == "auto":
+                lang = None
+
+            with self._model_lock:
+                if self.model is None:
+                    logger.warning("Model is None during transcription, returning no segments")
+                    return []
+                raw_segments = self.model.transcribe(
+                    audio_float,
+                    language=lang,
+                    translate=self.translate,
+                    **self._whispercpp_prompt_params(),
+                )
+
+            for segment in raw_segments:
+                text = _filter_non_speech(getattr(segment, "text", "") or "")
+                if not text:
+                    continue
+                # pywhispercpp reports t0/t1 in 10 ms units
+                segments.append(
+                    {
+                        "start": getattr(segment, "t0", 0) / 100.0,
+                        "end": getattr(segment, "t1", 0) / 100.0,
+                        "text": text.strip(),
+                        "confidence": None,
+                        "language": configured_language,
+                    }
+                )
+
+        elif self.engine == "remote_api":
+            with self._model_lock:
+                session = self._http_session
+            if session is None:
+                logger.error("Remote API HTTP session not initialized")
+                return []
+            text = self._transcribe_with_remote_api(audio_buffer, session).strip()
+            if text:
+                segments.append(
+                    {
+                        "start": 0.0,
+                        "end": duration,
+                        "text": text,
+                        "confidence": None,
+                        "language": configured_language,
+                    }
+                )
+
+        else:
+            logger.error(f"Unknown engine: {self.engine}")
+
+        return segments
+
     def _perform_recognition(self):
         """Perform speech recognition in real-time."""
         logger.debug("_perform_recognition thread started")
+        generation = self._session_generation
         while True:
+            if generation != self._session_generation:
+                logger.debug("Recognition loop - abandoned by panic stop, exiting")
+                break
             logger.debug(
                 f"Recognition loop - should_record={self.should_record}, queue_empty={self._segment_queue.empty()}"
             )
             try:
                 segment = self._segment_queue.get(timeout=0.1)
             except queue.Empty:
+                self._flush_pending_fragment()
+                self._maybe_emit_partial()
                 # Only exit if we're not recording AND queue is empty
                 if not self.should_record and self._segment_queue.empty():
                     logger.debug(
@@ -2755,6 +5451,7 @@ class SpeechRecognitionManager:
                         segment = self._segment_queue.get(timeout=0.5)
                     except queue.Empty:
                         logger.debug("Recognition loop - no more items, exiting")
+                        self._flush_pending_fragment(force=True)
                         break
                 else:
                     logger.debug("Recognition loop - queue timeout, continuing")
@@ -2774,6 +5471,7 @@ class SpeechRecognitionManager:
                             self._process_audio_buffer(remaining)
                     except queue.Empty:
                         break
+                self._flush_pending_fragment(force=True)
                 logger.debug("Recognition loop - exiting after None signal")
                 break
 
@@ -2784,6 +5482,70 @@ class SpeechRecognitionManager:
                 self._update_state(RecognitionState.LISTENING)
         logger.debug("_perform_recognition thread exiting")
 
+    def _cut_long_utterance(self, is_speech: bool, buffered_seconds: float) -> bool:
+        """
+        Queue the buffered speech once it reaches the maximum utterance length.
+
+        Someone dictating without pausing would otherwise build one huge
+        segment that takes ages to transcribe. The cut is made on a gap
+        between words when one comes within MAX_UTTERANCE_GRACE_SECONDS, so
+        words aren't split; recording carries on into the next segment.
+
+        Args:
+            is_speech: Whether the chunk just recorded holds speech
+            buffered_seconds: Length of the audio buffered for the segment
+
+        Returns:
+            bool: True if the segment was queued
+        """
+        limit = self.max_utterance_seconds
+        if not limit or not self._recording_segment_has_speech or buffered_seconds < limit:
+            return False
+        if is_speech and buffered_seconds < limit + MAX_UTTERANCE_GRACE_SECONDS:
+            return False
+
+        logger.info(f"Utterance reached {buffered_seconds:.0f} s, transcribing it in parts")
+        self._enqueue_audio_segment(self.audio_buffer)
+        self.audio_buffer = []
+        # After a cut between words, the next segment starts with silence
+        self._recording_segment_has_speech = is_speech
+        self._segment_speech_start = 0
+        self._last_segment_cut_time = time.time()
+        return True
+
+    @staticmethod
+    def _pad_chunks(milliseconds: int, chunk_seconds: float) -> int:
+        """Return how many chunks cover the padding length."""
+        return math.ceil(milliseconds / 1000 / chunk_seconds)
+
+    def _padded_segment(
+        self, audio_buffer: list[bytes], trailing_silence: float, chunk_seconds: float
+    ) -> list[bytes]:
+        """
+        Return the buffered segment trimmed to its speech plus the padding.
+
+        The buffer holds whatever silence came before the speech and the whole
+        silence timeout after it; only speech_pad_before_ms of the first and
+        speech_pad_after_ms of the second are kept.
+
+        Args:
+            audio_buffer: Chunks of the segment, ending with the pause that cut it
+            trailing_silence: Seconds of silence at the end of the buffer
+            chunk_seconds: Length of one chunk in seconds
+        """
+        silent_chunks = round(trailing_silence / chunk_seconds)
+        after = self._pad_chunks(self.speech_pad_after_ms, chunk_seconds)
+        end = len(audio_buffer) - max(0, silent_chunks - after)
+        before = self._pad_chunks(self.speech_pad_before_ms, chunk_seconds)
+        start = max(0, min(self._segment_speech_start, end) - before)
+        return audio_buffer[start:end]
+
+    def _onset_padding(self, audio_buffer: list[bytes], chunk_seconds: float) -> list[bytes]:
+        """Return the end of the buffer to start the next segment with, as its onset padding."""
+        before = self._pad_chunks(self.speech_pad_before_ms, chunk_seconds)
+        self._segment_speech_start = 0
+        return audio_buffer[-before:] if before else []
+
     def _enqueue_audio_segment(self, audio_buffer: list[bytes]):
         """Queue an audio segment for asynchronous transcription."""
         segment = audio_buffer.copy()
@@ -2797,7 +5559,10 @@ class SpeechRecognitionManager:
             self._segment_queue.put_nowait(segment)
             logger.debug("Enqueued segment successfully")
         except queue.Full:
-            logger.warning("Transcription queue is full, dropping oldest pending segment")
+            logger.warning(
+                "Transcription queue is full, dropping oldest pending segment "
+                f"(raise audio.queue_depth, now {self.queue_depth}, to hold more)"
+            )
             try:
                 self._segment_queue.get_nowait()
                 self._segment_queue.put_nowait(segment)
@@ -2834,8 +5599,9 @@ class SpeechRecognitionManager:
             engine: The new speech recognition engine ("vosk" or "whisper").
             model_size: The new model size.
             language: The new language code (e.g., "en-us", "hi", "auto").
-            vad_sensitivity: New VAD sensitivity (for VOSK).
-            silence_timeout: New silence timeout (for VOSK).
+            vad_sensitivity: New VAD sensitivity (for VOSK), set in the engine's
+                engine_vad override if it has one.
+            silence_timeout: New silence timeout (for VOSK), likewise.
             audio_device_index: Audio input device index (None for default, -1 to clear).
             audio_device_name: Audio device name for stable re-resolution.
             force_download: If True, download missing models (default: True for UI-triggered reconfigures).
@@ -2850,24 +5616,46 @@ class SpeechRecognitionManager:
         old_engine = self.engine
         if engine is not None and engine != self.engine:
             self.engine = engine
+            self.detected_language = None
+            self._restore_gpu_downgraded_model()
             restart_needed = True
 
-        if model_size is not None and model_size != self.model_size:
+        # Compare with the configured model, not a smaller one loaded to fit in VRAM
+        if model_size is not None and model_size != (self._gpu_downgraded_from or self.model_size):
             self.model_size = model_size
+            self._gpu_downgraded_from = None
             restart_needed = True
 
+        if "gpu_memory_reserve_mb" in kwargs:
+            reserve = max(0, int(kwargs.get("gpu_memory_reserve_mb") or 0))
+            if reserve != self.gpu_memory_reserve_mb:
+                self.gpu_memory_reserve_mb = reserve
+                if self.engine in ("whisper", "whisper_cpp"):
+                    self._restore_gpu_downgraded_model()
+                    restart_needed = True
+
         # Language change requires restart for both engines
         # Whisper needs to know the language for transcription
         # VOSK needs to load a different model for the new language
         if language is not None and language != self.language:
             self.language = language
+            self.detected_language = None
+            self._comparison_manager = None
             restart_needed = True
 
         # Update VOSK specific params if provided
+        if "engine_vad" in kwargs:
+            self.engine_vad = self._valid_engine_vad(kwargs.get("engine_vad"))
+        # Set for the (new) engine, so a per-engine override doesn't hide the change
         if vad_sensitivity is not None:
-            self.vad_sensitivity = max(1, min(5, int(vad_sensitivity)))
+            self._set_vad_setting("vad_sensitivity", max(1, min(5, int(vad_sensitivity))))
         if silence_timeout is not None:
-            self.silence_timeout = max(0.5, min(5.0, float(silence_timeout)))
+            self._set_vad_setting("silence_timeout", max(0.5, min(5.0, float(silence_timeout))))
+        if "vad_backend" in kwargs:
+            backend = self._valid_vad_backend(kwargs.get("vad_backend"))
+            if backend != self.vad_backend:
+                self.vad_backend = backend
+                self._select_vad()
 
         # Handle audio device index (-1 means use default/clear selection)
         if audio_device_index is not None:
@@ -2879,12 +5667,147 @@ class SpeechRecognitionManager:
         if audio_device_name is not None:
             self.audio_device_name = audio_device_name
 
+        if "sample_rate" in kwargs:
+            # Takes effect the next time the microphone is opened
+            self.sample_rate = self._valid_sample_rate(kwargs.get("sample_rate"))
+
+        # Both take effect the next time recording starts
+        if "buffer_frames" in kwargs:
+            self.buffer_frames = self._bounded_setting(
+                "buffer_frames",
+                kwargs.get("buffer_frames"),
+                DEFAULT_BUFFER_FRAMES,
+                MIN_BUFFER_FRAMES,
+                MAX_BUFFER_FRAMES,
+            )
+        if "queue_depth" in kwargs:
+            self.queue_depth = self._bounded_setting(
+                "queue_depth",
+                kwargs.get("queue_depth"),
+                DEFAULT_QUEUE_DEPTH,
+                MIN_QUEUE_DEPTH,
+                MAX_QUEUE_DEPTH,
+            )
+        if "max_utterance_seconds" in kwargs:
+            self.max_utterance_seconds = self._max_utterance_setting(
+                kwargs.get("max_utterance_seconds")
+            )
+        for name in ("speech_pad_before_ms", "speech_pad_after_ms"):
+            if name in kwargs:
+                setattr(self, name, self._speech_pad_setting(name, kwargs.get(name)))
+
         if "voice_commands_enabled" in kwargs:
             self._voice_commands_preference = kwargs.get("voice_commands_enabled")
 
+        if "punctuation" in kwargs:
+            self.punctuation_level = valid_punctuation_level(kwargs.get("punctuation"))
+
+        if "intent_commands_enabled" in kwargs:
+            self.intent_commands_enabled = bool(kwargs.get("intent_commands_enabled"))
+
+        if "disabled_commands" in kwargs or "renamed_commands" in kwargs:
+            processor = self.command_processor
+            processor.customize(
+                kwargs.get("disabled_commands", processor.disabled_commands),
+                kwargs.get("renamed_commands", processor.renamed_commands),
+            )
+
+        if "tag_language" in kwargs:
+            self.tag_language = bool(kwargs.get("tag_language"))
+
+        if "session_word_target" in kwargs:
+            self.session.word_target = kwargs.get("session_word_target")
+
+        if "utterance_log" in kwargs:
+            self.utterance_log.mode = kwargs.get("utterance_log")
+
+        if "dump_audio_dir" in kwargs:
+            self.audio_dumper.directory = kwargs.get("dump_audio_dir")
+
+        if "intent_search_url" in kwargs:
+            self._intent_handler.search_url = kwargs.get("intent_search_url") or DEFAULT_SEARCH_URL
+
         if "stop_sound_guard_ms" in kwargs:
             self.stop_sound_guard_ms = kwargs.get("stop_sound_guard_ms", self.stop_sound_guard_ms)
 
+        if "mute_own_sounds" in kwargs:
+            self.mute_own_sounds = bool(kwargs.get("mute_own_sounds"))
+
+        if "long_form_mode" in kwargs:
+            self.long_form_mode = bool(kwargs.get("long_form_mode"))
+
+        if "vosk_speaker_profile" in kwargs:
+            self.vosk_speaker_profile = bool(kwargs.get("vosk_speaker_profile"))
+            self._reset_vosk_recognizer()
+
+        if "partial_results" in kwargs:
+            self.partial_results = bool(kwargs.get("partial_results"))
+
+        if "comparison_engine" in kwargs or "comparison_model_size" in kwargs:
+            self.comparison_engine = kwargs.get("comparison_engine", self.comparison_engine)
+            self.comparison_model_size = (
+                kwargs.get("comparison_model_size") or self.comparison_model_size
+            )
+            # Loaded again with the new settings on the next utterance
+            self._comparison_manager = None
+
+        if "rewrite_engine" in kwargs or "rewrite_model_size" in kwargs:
+            self.rewrite_engine = kwargs.get("rewrite_engine", self.rewrite_engine)
+            self.rewrite_model_size = kwargs.get("rewrite_model_size") or self.rewrite_model_size
+            self._rewrite_manager = None
+
+        if "offline_fallback_engine" in kwargs or "offline_fallback_model_size" in kwargs:
+            self.offline_fallback_engine = kwargs.get(
+                "offline_fallback_engine", self.offline_fallback_engine
+            )
+            self.offline_fallback_model_size = (
+                kwargs.get("offline_fallback_model_size") or self.offline_fallback_model_size
+            )
+            # Loaded again with the new settings, now with standby or else when needed
+            self._fallback_manager = None
+            self._warm_offline_fallback()
+        if "offline_fallback_standby" in kwargs:
+            self.offline_fallback_standby = bool(kwargs.get("offline_fallback_standby"))
+            self._warm_offline_fallback()
+
+        if "retry_audio" in kwargs:
+            self.retry_audio = bool(kwargs.get("retry_audio"))
+            if not self.retry_audio:
+                self._utterance_audio.clear()
+        if "retry_model_size" in kwargs:
+            self.retry_model_size = kwargs.get("retry_model_size") or self.retry_model_size
+
+        if "translate" in kwargs:
+            self.translate = bool(kwargs.get("translate"))
+            if self.translate and self.engine not in TRANSLATE_ENGINES:
+                logger.warning(f"Translation is not supported by {self.engine}; transcribing")
+            # The rewrite model decodes with the same task
+            self._rewrite_manager = None
+
+        for param_name, attr in (
+            ("format_ordinals", "ordinals"),
+            ("format_fractions", "fractions"),
+            ("format_roman_numerals", "roman_numerals"),
+        ):
+            if param_name in kwargs:
+                setattr(self.number_formatter, attr, bool(kwargs[param_name]))
+
+        if "profanity_filter" in kwargs:
+            self.profanity_filter.mode = self._valid_profanity_filter_mode(
+                kwargs["profanity_filter"]
+            )
+        if "profanity_words" in kwargs:
+            self.profanity_filter.custom_words = kwargs.get("profanity_words") or []
+
+        if "preserved_terms" in kwargs:
+            self.term_capitalizer.terms = kwargs.get("preserved_terms") or []
+
+        if "utterance_join_window" in kwargs:
+            self.utterance_join_window = max(0.0, float(kwargs.get("utterance_join_window")))
+
+        if "adaptive_silence_timeout" in kwargs:
+            self.adaptive_silence_timeout = bool(kwargs.get("adaptive_silence_timeout"))
+
         for param_name in (
             "whispercpp_no_timestamps",
             "whispercpp_no_context",
@@ -2900,6 +5823,22 @@ class SpeechRecognitionManager:
                 setattr(self, param_name, kwargs[param_name])
                 restart_needed = True
 
+        if "text_pipeline" in kwargs:
+            self.text_pipeline = TextPipeline(kwargs.get("text_pipeline"))
+
+        if "prompt_presets" in kwargs or "app_prompts" in kwargs:
+            self.app_prompts = AppPrompts(
+                kwargs.get("prompt_presets", self.app_prompts.presets),
+                kwargs.get("app_prompts", self.app_prompts.rules),
+            )
+            self._app_prompt = ""
+
+        if "vocabulary_terms" in kwargs:
+            self.vocabulary_terms = list(kwargs.get("vocabulary_terms") or [])
+            # whisper.cpp takes its prompt when the model is loaded
+            if self.engine == "whisper_cpp":
+                restart_needed = True
+
         # Handle Remote API settings
         if "remote_api_url" in kwargs:
             new_url = kwargs.get("remote_api_url", "")
@@ -2908,7 +5847,9 @@ class SpeechRecognitionManager:
                 if self.engine == "remote_api":
                     restart_needed = True
         if "remote_api_key" in kwargs:
+            # Settings pass the key they read from the keyring themselves
             self.remote_api_key = kwargs.get("remote_api_key", "")
+            self._remote_api_secret = None
         if "remote_api_endpoint" in kwargs:
             self.remote_api_endpoint = kwargs.get("remote_api_endpoint", "/inference")
         if "remote_api_model" in kwargs:
@@ -2918,6 +5859,7 @@ class SpeechRecognitionManager:
 
         if restart_needed:
             logger.info("Engine or model changed, re-initializing...")
+            self._warn_if_language_unsupported()
 
             # Stop any active recognition before switching engines.
             # This is critical to prevent segfaults when the old engine's
@@ -2941,6 +5883,7 @@ class SpeechRecognitionManager:
                     if self._http_session is not None:
                         self._http_session.close()
                     self._http_session = None
+                    self._fallback_manager = None
                 try:
                     if self.engine == "vosk":
                         self._init_vosk()
@@ -2964,6 +5907,41 @@ class SpeechRecognitionManager:
             # If only VOSK params changed, just log it
             logger.info("Applied VAD/silence timeout changes.")
 
+    def _restore_gpu_downgraded_model(self):
+        """Go back to the configured model before fitting it into VRAM again."""
+        if self._gpu_downgraded_from is not None:
+            self.model_size = self._gpu_downgraded_from
+            self._gpu_downgraded_from = None
+
+    def _recover_capture_stream(self, audio_instance) -> bool:
+        """
+        Rebuild the capture stream after a stream error, retrying with backoff.
+
+        An error more than STREAM_ERROR_RESET_SECONDS after the previous one
+        starts a fresh round of attempts. Otherwise the attempts carry on from
+        the last error, so a device that keeps failing right after each
+        reconnection gives up after _max_reconnection_attempts.
+
+        Args:
+            audio_instance: The PyAudio instance to reopen the stream with
+
+        Returns:
+            bool: True if recording can continue on the rebuilt stream
+        """
+        now = time.time()
+        if now - self._last_audio_error_time > STREAM_ERROR_RESET_SECONDS:
+            self._reconnection_attempts = 0
+        self._last_audio_error_time = now
+
+        while self.should_record:
+            if self._attempt_audio_reconnection(audio_instance):
+                logger.info("Audio reconnection successful, continuing recording")
+                return True
+            if self._reconnection_attempts >= self._max_reconnection_attempts:
+                logger.error("Audio reconnection failed, stopping recording")
+                return False
+        return False
+
     def _attempt_audio_reconnection(self, audio_instance) -> bool:
         """
         Attempt to reconnect to the audio device.
@@ -2992,7 +5970,18 @@ class SpeechRecognitionManager:
 
         # Wait before attempting reconnection
         time.sleep(delay)
+        return self._reopen_capture_stream(audio_instance)
+
+    def _reopen_capture_stream(self, audio_instance) -> bool:
+        """
+        Close the current stream and open the selected (or default) input again.
 
+        Args:
+            audio_instance: The PyAudio instance to open the stream with
+
+        Returns:
+            bool: True if the new stream delivered audio
+        """
         try:
             # Close existing stream if it exists
             if self._audio_stream:
@@ -3003,27 +5992,29 @@ class SpeechRecognitionManager:
                     logger.debug(f"Error closing old audio stream: {e}")
 
             # Resolve a valid input device — by name first, then by index
-            resolved_device_index = _resolve_device_by_name(
-                audio_instance, self.audio_device_name, self.audio_device_index
-            )
+            resolved_device_index = self._resolve_capture_device(audio_instance)
             if resolved_device_index is None:
                 resolved_device_index = _resolve_valid_input_device(audio_instance, None)
             if resolved_device_index is None:
                 logger.error("Reconnection failed: no input devices available.")
                 return False
+            try:
+                info = audio_instance.get_device_info_by_index(resolved_device_index)
+                self._capture_device_name = info.get("name")
+            except (IOError, OSError, AttributeError):
+                self._capture_device_name = None
 
             # Stream configuration
-            CHUNK = 1024
-            FORMAT = pyaudio.paInt16
+            CHUNK = self.buffer_frames
 
-            # Detect supported channel count first (some devices require stereo)
-            CHANNELS = _get_supported_channels(audio_instance, resolved_device_index)
-            logger.debug(f"Reconnecting with {CHANNELS} channel(s)")
-
-            # Detect supported sample rate for the device
-            RATE = _get_supported_sample_rate(audio_instance, resolved_device_index, CHANNELS)
+            self.capture_format = negotiate_capture_format(
+                audio_instance, resolved_device_index, self.sample_rate
+            )
+            FORMAT = self.capture_format.pyaudio_format
+            CHANNELS = self.capture_format.channels
+            RATE = self.capture_format.rate
             self._capture_sample_rate = RATE
-            logger.debug(f"Reconnecting with sample rate: {RATE}Hz")
+            logger.debug(f"Reconnecting with {self.capture_format.describe()}")
 
             stream_kwargs = {
                 "format": FORMAT,
@@ -3041,8 +6032,17 @@ class SpeechRecognitionManager:
             if resolved_device_index != default_idx:
                 stream_kwargs["input_device_index"] = resolved_device_index
 
+            # The echo canceller is tied to the source it was loaded for, which may be
+            # gone; load it again for the default input, as when recording started
+            echo_source = None
+            if self.echo_cancel and not self.input_file:
+                self._echo_canceller.stop()
+                if "input_device_index" not in stream_kwargs and self._monitor_source() is None:
+                    echo_source = self._echo_canceller.start()
+
             # Attempt to open new stream
-            new_stream = audio_instance.open(**stream_kwargs)
+            with pulse_source(echo_source or self._monitor_source()):
+                new_stream = audio_instance.open(**stream_kwargs)
 
             # Test the stream by reading a small amount of data
             test_data = new_stream.read(CHUNK, exception_on_overflow=False)
@@ -3067,6 +6067,86 @@ class SpeechRecognitionManager:
             logger.error(f"Unexpected error during audio reconnection: {e}")
             return False
 
+    def _device_change_affects_capture(self, change: DeviceChange) -> bool:
+        """Whether a hotplug event means the microphone should be reopened."""
+        if change.removed:
+            # The device in use may be gone, or the stream moved to another source
+            return True
+        if change.default_source and self._records_default_source():
+            # The user picked another input in the desktop's sound settings
+            return True
+        # A new device matters if it may be the selected one coming back
+        return bool(self.audio_device_name) and self._capture_device_name != self.audio_device_name
+
+    def _is_own_sound_playing(self, input_file_audio) -> bool:
+        """
+        Whether captured audio should be replaced with silence because Vocalinux is playing.
+
+        Covers feedback sounds and read-back; echo cancellation is off by default.
+        Never while a speech segment is in progress: the user is already talking over
+        it, and a silenced stretch would end the segment early. A recording played
+        with --input-file doesn't contain our sounds.
+        """
+        return (
+            self.mute_own_sounds
+            and input_file_audio is None
+            and not self._recording_segment_has_speech
+            and is_self_playback_active()
+        )
+
+    def _records_default_source(self) -> bool:
+        """Whether the default input is recorded, so it should follow the default source."""
+        if self.audio_device_name or self.audio_device_index is not None:
+            return False
+        # The echo canceller is tied to the source that was default when it was loaded
+        return not self._echo_canceller.active
+
+    def _reopen_after_device_change(self, audio_instance, change: DeviceChange):
+        """
+        Re-enumerate the audio devices and reopen the microphone after a hotplug event.
+
+        PortAudio only lists devices when it is initialized, so the PyAudio
+        instance is replaced.
+
+        Args:
+            audio_instance: The PyAudio instance recording so far
+            change: The sources that appeared or disappeared
+
+        Returns:
+            The new PyAudio instance, or None if no input device could be opened
+        """
+        import pyaudio
+
+        logger.info(f"Audio input devices changed ({change.describe()}), reopening microphone")
+        previous_device = self._capture_device_name
+        if self._audio_stream is not None:
+            try:
+                self._audio_stream.stop_stream()
+                self._audio_stream.close()
+            except Exception as e:
+                logger.debug(f"Error closing old audio stream: {e}")
+            self._audio_stream = None
+        try:
+            audio_instance.terminate()
+        except Exception as e:
+            logger.debug(f"Error terminating PyAudio: {e}")
+
+        self._pyaudio_instance = pyaudio.PyAudio()
+        if not self._reopen_capture_stream(self._pyaudio_instance):
+            logger.error("No input device could be opened after the device change")
+            self._pyaudio_instance.terminate()
+            self._pyaudio_instance = None
+            return None
+
+        if self._capture_device_name != previous_device:
+            _show_notification(
+                "Microphone Changed",
+                f"Now recording from {self._capture_device_name or 'the default input'}",
+                "audio-input-microphone",
+                category="audio_device",
+            )
+        return self._pyaudio_instance
+
     def reinitialize_after_resume(self):
         """Reinitialize the speech engine after system resume from suspend.
 
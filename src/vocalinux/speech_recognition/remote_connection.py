@@ -0,0 +1,215 @@
+"""
+Remote server connection test for Vocalinux.
+
+Tells apart the usual reasons a remote speech server can't be used (the
+name doesn't resolve, nothing listens on the port, the certificate is
+rejected, the API key is wrong, the account is out of quota, the endpoint
+format doesn't match) so the settings can name the cause and the fix
+instead of showing a raw exception.
+"""
+
+import logging
+from dataclasses import dataclass
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+TEST_TIMEOUT = 5.0
+
+_DNS_ERROR_MARKERS = (
+    "name or service not known",
+    "temporary failure in name resolution",
+    "nodename nor servname",
+    "no address associated with hostname",
+    "getaddrinfo failed",
+    "failed to resolve",
+)
+
+
+@dataclass
+class ConnectionDiagnosis:
+    """Outcome of a connection test."""
+
+    ok: bool
+    cause: str  # "connected", "dns", "refused", "tls", "unauthorized", "quota", ...
+    summary: str  # One line for the status label and dialog title
+    detail: str  # What it means and how to fix it
+    status_code: Optional[int] = None
+    server_info: str = ""  # e.g. "whisper.cpp server", when the endpoint answered
+
+
+def _diagnose_exception(error: Exception, url: str) -> ConnectionDiagnosis:
+    import requests
+
+    message = str(error)
+    if isinstance(error, (requests.exceptions.MissingSchema, requests.exceptions.InvalidSchema)):
+        return ConnectionDiagnosis(
+            False,
+            "invalid_url",
+            "Invalid server URL",
+            f"'{url}' is not a web address. It should start with http:// or https://, "
+            "for example http://192.168.1.100:8080.",
+        )
+    if isinstance(error, requests.exceptions.InvalidURL):
+        return ConnectionDiagnosis(
+            False, "invalid_url", "Invalid server URL", f"'{url}' could not be parsed: {message}"
+        )
+    if isinstance(error, requests.exceptions.SSLError):
+        return ConnectionDiagnosis(
+            False,
+            "tls",
+            "Secure connection failed",
+            "The server's TLS certificate was rejected (self-signed, expired or issued for "
+            "another name). Import its CA into the system trust store, or use http:// on a "
+            "trusted network.",
+        )
+    if isinstance(error, requests.exceptions.Timeout):
+        return ConnectionDiagnosis(
+            False,
+            "timeout",
+            "Server did not answer",
+            f"No response within {TEST_TIMEOUT:.0f} seconds. Check that the server is running "
+            "and that no firewall drops the connection.",
+        )
+    if isinstance(error, requests.exceptions.ConnectionError):
+        lowered = message.lower()
+        if any(marker in lowered for marker in _DNS_ERROR_MARKERS):
+            return ConnectionDiagnosis(
+                False,
+                "dns",
+                "Server name not found",
+                "The host name in the URL doesn't resolve. Check it for typos, or use the "
+                "server's IP address.",
+            )
+        if "refused" in lowered:
+            return ConnectionDiagnosis(
+                False,
+                "refused",
+                "Connection refused",
+                "The host is reachable but nothing listens on that port. Check the port in the "
+                "URL and that the server is started with --host 0.0.0.0 to accept remote "
+                "connections.",
+            )
+        return ConnectionDiagnosis(
+            False,
+            "unreachable",
+            "Server unreachable",
+            f"Could not connect: {message[:200]}",
+        )
+    return ConnectionDiagnosis(False, "unknown", "Connection failed", message[:200])
+
+
+def _diagnose_status(status_code: int, endpoint: str) -> Optional[ConnectionDiagnosis]:
+    """Explain an HTTP status that means the server can't be used as configured."""
+    if status_code == 401:
+        return ConnectionDiagnosis(
+            False,
+            "unauthorized",
+            "API key rejected",
+            "The server requires a valid API key. Check the key for the selected account, "
+            "or that it hasn't been revoked.",
+            status_code,
+        )
+    if status_code == 403:
+        return ConnectionDiagnosis(
+            False,
+            "forbidden",
+            "Access denied",
+            "The API key is valid but not allowed to use this server or model.",
+            status_code,
+        )
+    if status_code in (402, 429):
+        return ConnectionDiagnosis(
+            False,
+            "quota",
+            "Quota or rate limit reached",
+            "The account is out of credit or sending too many requests. Check its usage and "
+            "billing, or wait a moment and try again.",
+            status_code,
+        )
+    if status_code == 404:
+        return ConnectionDiagnosis(
+            False,
+            "not_found",
+            "Endpoint not found",
+            f"The server has no {endpoint} endpoint. Pick the other API endpoint format, or "
+            "check that the URL has no extra path.",
+            status_code,
+        )
+    if status_code >= 500:
+        return ConnectionDiagnosis(
+            False,
+            "server_error",
+            "Server error",
+            f"The server answered with HTTP {status_code}. Check its logs.",
+            status_code,
+        )
+    return None
+
+
+def test_connection(url: str, api_key: str = "", endpoint: str = "/inference", session=None):
+    """
+    Check that a remote speech server is reachable and accepts the API key.
+
+    Args:
+        url: Base URL of the server
+        api_key: Key sent as a Bearer token (optional)
+        endpoint: "/inference" (whisper.cpp) or "/v1/audio/transcriptions" (OpenAI)
+        session: requests.Session to use, for tests
+
+    Returns:
+        ConnectionDiagnosis
+    """
+    import requests
+
+    url = (url or "").strip().rstrip("/")
+    if not url:
+        return ConnectionDiagnosis(
+            False, "invalid_url", "No server URL", "Enter the address of the server."
+        )
+
+    headers = {"Authorization": f"Bearer {api_key}"} if api_key else {}
+    own_session = session is None
+    session = session or requests.Session()
+    try:
+        try:
+            response = session.get(url, headers=headers, timeout=TEST_TIMEOUT)
+        except requests.exceptions.RequestException as e:
+            logger.debug(f"Connection test to {url} failed: {e}")
+            return _diagnose_exception(e, url)
+
+        # The base URL often answers 404; the endpoint check below decides
+        if response.status_code != 404:
+            problem = _diagnose_status(response.status_code, endpoint)
+            if problem is not None:
+                return problem
+
+        if endpoint == "/v1/audio/transcriptions":
+            probe_path, server_info = "/v1/models", "OpenAI compatible"
+        else:
+            probe_path, server_info = "/inference", "whisper.cpp server"
+        try:
+            probe = session.get(f"{url}{probe_path}", headers=headers, timeout=TEST_TIMEOUT)
+        except requests.exceptions.RequestException as e:
+            logger.debug(f"Endpoint probe {probe_path} failed: {e}")
+            return _diagnose_exception(e, url)
+
+        # /inference only takes POST, so 405 means it exists
+        if probe.status_code != 405:
+            problem = _diagnose_status(
+                probe.status_code, endpoint if probe_path == "/inference" else probe_path
+            )
+            if problem is not None:
+                return problem
+
+        return ConnectionDiagnosis(
+            True,
+            "connected",
+            "Connected",
+            f"The server at {url} answered and accepted the request.",
+            response.status_code,
+            server_info,
+        )
+    finally:
+        if own_session:
+            session.close()
@@ -0,0 +1,294 @@
+"""
+Resampling between the capture device and the speech engines for Vocalinux.
+
+Microphones often only run at 44.1 or 48 kHz, while every engine expects
+16 kHz mono 16-bit audio. Capture runs at the device's rate and passes each
+chunk through a StreamResampler: a windowed-sinc low-pass filter removes
+everything above the new Nyquist frequency (which would otherwise fold back
+as aliasing noise) and the filtered signal is interpolated at the new rate.
+Filter history and the interpolation phase carry over from one chunk to the
+next, so chunk boundaries don't click. Devices that only deliver float or
+other integer samples are converted to 16-bit first with to_int16, devices
+that only open with two or more channels are downmixed to mono (from the
+channels that carry the microphone, chosen once per stream), and quiet
+microphones can be boosted with a per-device software gain.
+"""
+
+from typing import Optional
+
+TARGET_SAMPLE_RATE = 16000
+
+# Filter length; odd so the filter has a whole-sample delay
+DEFAULT_NUM_TAPS = 63
+
+# Cutoff as a fraction of the output Nyquist frequency, leaving room for the
+# filter's transition band
+_CUTOFF_RATIO = 0.9
+
+
+# Range of the per-device software gain, and the audio.device_gain key of
+# the system default input
+MIN_DEVICE_GAIN = 0.25
+MAX_DEVICE_GAIN = 8.0
+DEFAULT_DEVICE_GAIN_KEY = "default"
+
+# Channels this far below the loudest one are left out of the downmix, so the
+# unused inputs of a multi-channel interface don't dilute the microphone
+DOWNMIX_INACTIVE_DB = 20.0
+
+# A live stream's channels are chosen once, from the first chunks at least this
+# loud; quieter chunks (silence, hiss) don't tell the microphone apart
+DOWNMIX_CHOOSE_DBFS = -50.0
+DOWNMIX_CHOOSE_CHUNKS = 5
+
+# Capture sample formats that to_int16 converts, most preferred first
+SAMPLE_FORMATS = ("int16", "float32", "int32", "int24", "uint8")
+
+
+def _whole_samples(data: bytes, sample_width: int) -> bytes:
+    """Drop a trailing partial sample, which a short or corrupt read can leave."""
+    return data[: len(data) - len(data) % sample_width]
+
+
+def to_int16(data: bytes, sample_format: str) -> bytes:
+    """
+    Convert interleaved samples in one of SAMPLE_FORMATS to 16-bit ints.
+
+    Float samples are clipped to [-1.0, 1.0]; wider ints keep their top 16
+    bits; unsigned 8-bit samples are re-centred on zero.
+    """
+    import numpy as np
+
+    if sample_format == "int16":
+        return _whole_samples(data, 2)
+    if sample_format == "float32":
+        samples = np.clip(np.frombuffer(_whole_samples(data, 4), dtype="<f4"), -1.0, 1.0)
+        return np.round(samples * 32767).astype(np.int16).tobytes()
+    if sample_format == "int32":
+        samples = np.frombuffer(_whole_samples(data, 4), dtype="<i4")
+        return (samples >> 16).astype(np.int16).tobytes()
+    if sample_format == "int24":
+        # Packed little-endian 3-byte samples; the top two bytes are the int16
+        packed = np.frombuffer(_whole_samples(data, 3), dtype=np.uint8)
+        return packed.reshape(-1, 3)[:, 1:].copy().view("<i2").astype(np.int16).tobytes()
+    if sample_format == "uint8":
+        samples = np.frombuffer(data, dtype=np.uint8).astype(np.int16)
+        return ((samples - 128) << 8).astype(np.int16).tobytes()
+    raise ValueError(f"Unsupported sample format: {sample_format}")
+
+
+def downmix_to_mono(data: bytes, channels: int) -> bytes:
+    """
+    Average the interleaved 16-bit channels that carry signal into mono.
+
+    The channels are chosen from this data alone, e.g. a whole recording; a live
+    stream is downmixed with a ChannelDownmixer.
+    """
+    if channels <= 1:
+        return data
+    frames = _frames(data, channels)
+    if not len(frames):
+        return b""
+    return _mix(frames, _active_channels(_channel_power(frames)))
+
+
+def _frames(data: bytes, channels: int):
+    import numpy as np
+
+    samples = np.frombuffer(_whole_samples(data, 2), dtype=np.int16)
+    return samples[: len(samples) - len(samples) % channels].reshape(-1, channels)
+
+
+def _channel_power(frames):
+    import numpy as np
+
+    return np.square(frames, dtype=np.float64).mean(axis=0)
+
+
+def _active_channels(power):
+    return power >= power.max() * 10 ** (-DOWNMIX_INACTIVE_DB / 10)
+
+
+def _mix(frames, active) -> bytes:
+    import numpy as np
+
+    return frames[:, active].mean(axis=1).astype(np.int16).tobytes()
+
+
+class ChannelDownmixer:
+    """
+    Downmixes a live stream to mono from channels chosen once per stream.
+
+    Choosing the channels for every chunk (as downmix_to_mono does for a
+    whole recording) makes the level jump whenever a quiet channel crosses
+    the threshold. Until the stream has been loud enough to tell which
+    channels carry the microphone, all of them are averaged.
+    """
+
+    def __init__(self, channels: int):
+        """
+        Initialize the downmixer.
+
+        Args:
+            channels: Interleaved 16-bit channels in the incoming chunks
+        """
+        self.channels = channels
+        self.reset()
+
+    def reset(self):
+        """Choose the channels again, e.g. when the stream was reopened."""
+        self._active = None
+        self._power = None  # Summed power of the loud chunks seen so far
+        self._loud_chunks = 0
+
+    def process(self, data: bytes) -> bytes:
+        """Downmix one chunk to mono."""
+        import numpy as np
+
+        if self.channels <= 1:
+            return data
+        frames = _frames(data, self.channels)
+        if not len(frames):
+            return b""
+        if self._active is None:
+            self._learn(_channel_power(frames))
+        active = self._active if self._active is not None else np.ones(self.channels, bool)
+        return _mix(frames, active)
+
+    def _learn(self, power):
+        loud_power = (32768 * 10 ** (DOWNMIX_CHOOSE_DBFS / 20)) ** 2
+        if power.max() < loud_power:
+            return
+        self._power = power if self._power is None else self._power + power
+        self._loud_chunks += 1
+        if self._loud_chunks >= DOWNMIX_CHOOSE_CHUNKS:
+            self._active = _active_channels(self._power)
+
+
+def apply_gain(data: bytes, gain: float, pool=None) -> bytes:
+    """
+    Scale 16-bit samples by a gain factor, clipping instead of wrapping around.
+
+    Args:
+        data: 16-bit samples
+        gain: Factor to scale by
+        pool: BufferPool for the intermediate arrays, reused between chunks
+    """
+    import numpy as np
+
+    if gain == 1.0 or not data:
+        return data
+    if pool is None:
+        from .buffer_pool import BufferPool
+
+        pool = BufferPool()
+    samples = np.frombuffer(_whole_samples(data, 2), dtype=np.int16)
+    scaled = pool.take("gain", len(samples), np.float32)
+    np.multiply(samples, gain, out=scaled)
+    np.rint(scaled, out=scaled)
+    np.clip(scaled, -32768, 32767, out=scaled)
+    result = pool.take("gain_int16", len(samples), np.int16)
+    np.copyto(result, scaled, casting="unsafe")
+    return result.tobytes()
+
+
+def lowpass_taps(cutoff: float, num_taps: int = DEFAULT_NUM_TAPS):
+    """
+    Windowed-sinc low-pass filter taps with unity gain at DC.
+
+    Args:
+        cutoff: Cutoff frequency as a fraction of the sample rate (0-0.5)
+        num_taps: Number of taps
+    """
+    import numpy as np
+
+    n = np.arange(num_taps) - (num_taps - 1) / 2
+    taps = 2 * cutoff * np.sinc(2 * cutoff * n) * np.blackman(num_taps)
+    return taps / taps.sum()
+
+
+class StreamResampler:
+    """Converts a stream of 16-bit mono chunks from one sample rate to another."""
+
+    def __init__(
+        self,
+        input_rate: int,
+        output_rate: int = TARGET_SAMPLE_RATE,
+        num_taps: int = DEFAULT_NUM_TAPS,
+    ):
+        """
+        Initialize the resampler.
+
+        Args:
+            input_rate: Sample rate of the incoming chunks in Hz
+            output_rate: Sample rate to produce in Hz
+            num_taps: Length of the anti-aliasing filter
+        """
+        self.input_rate = int(input_rate)
+        self.output_rate = int(output_rate)
+        self._step = self.input_rate / self.output_rate
+        self._taps = None
+        if self.output_rate < self.input_rate:
+            self._taps = lowpass_taps(_CUTOFF_RATIO * 0.5 / self._step, num_taps)
+        self.reset()
+
+    @property
+    def passthrough(self) -> bool:
+        """Whether the rates match and chunks are passed on unchanged."""
+        return self.input_rate == self.output_rate
+
+    def reset(self):
+        """Forget the previous chunks, e.g. when a new stream starts."""
+        self._history = None  # Unfiltered input the filter still needs
+        self._last_sample: Optional[float] = None  # Last filtered sample, to interpolate from
+        self._position = 0.0  # Read position of the next output sample
+
+    def _filter(self, samples):
+        import numpy as np
+
+        if self._taps is None:
+            return samples
+        if self._history is None:
+            # Start from silence rather than a jump to the first sample
+            self._history = np.zeros(len(self._taps) - 1)
+        padded = np.concatenate((self._history, samples))
+        self._history = padded[len(padded) - (len(self._taps) - 1) :]
+        return np.convolve(padded, self._taps, mode="valid")
+
+    def process(self, data: bytes) -> bytes:
+        """
+        Resample one chunk.
+
+        Returns:
+            The chunk at the output rate; its length varies slightly from
+            chunk to chunk as the fractional phase carries over
+        """
+        import numpy as np
+
+        if self.passthrough or not data:
+            return data
+        data = _whole_samples(data, 2)
+        if not data:
+            return b""
+
+        filtered = self._filter(np.frombuffer(data, dtype=np.int16).astype(np.float64))
+        if self._last_sample is not None:
+            filtered = np.concatenate(([self._last_sample], filtered))
+
+        last_index = len(filtered) - 1
+        count = int(np.floor((last_index - self._position) / self._step)) + 1
+        if count <= 0:
+            positions = np.empty(0)
+        else:
+            positions = self._position + np.arange(count) * self._step
+        resampled = np.interp(positions, np.arange(len(filtered)), filtered)
+
+        # The next chunk starts with this chunk's last sample at index 0
+        self._position += count * self._step - last_index
+        self._last_sample = filtered[-1]
+        return np.clip(np.round(resampled), -32768, 32767).astype(np.int16).tobytes()
+
+
+def resample_audio(data: bytes, input_rate: int, output_rate: int = TARGET_SAMPLE_RATE) -> bytes:
+    """Resample a complete 16-bit mono recording."""
+    return StreamResampler(input_rate, output_rate).process(data)
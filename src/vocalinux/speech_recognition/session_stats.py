@@ -0,0 +1,131 @@
+"""
+Dictation session statistics for Vocalinux.
+
+Tracks how long the current writing session has been going and how many
+words were dictated in it, for the status window. An optional word target
+("write 500 words") triggers a one-off callback when it is reached.
+"""
+
+import logging
+import threading
+import time
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+
+def format_elapsed(seconds: float) -> str:
+    """Format a duration as m:ss, or h:mm:ss from one hour."""
+    seconds = max(0, int(seconds))
+    hours, remainder = divmod(seconds, 3600)
+    minutes, seconds = divmod(remainder, 60)
+    if hours:
+        return f"{hours}:{minutes:02d}:{seconds:02d}"
+    return f"{minutes}:{seconds:02d}"
+
+
+def count_words(text: str) -> int:
+    """Count the words in dictated text; punctuation on its own is not a word."""
+    return sum(1 for token in text.split() if any(char.isalnum() for char in token))
+
+
+class DictationSession:
+    """
+    Elapsed time and word count since the first dictation (or the last reset).
+
+    The clock starts when dictation first starts and keeps running between
+    utterances, like a writing sprint timer.
+    """
+
+    def __init__(
+        self,
+        word_target: int = 0,
+        on_target_reached: Optional[Callable[[int, float], None]] = None,
+        clock: Callable[[], float] = time.monotonic,
+    ):
+        """
+        Initialize the session.
+
+        Args:
+            word_target: Words to reach in the session (0 for no target)
+            on_target_reached: Called once with (word count, elapsed seconds)
+                when the target is reached
+            clock: Monotonic clock in seconds, for tests
+        """
+        self._on_target_reached = on_target_reached
+        self._clock = clock
+        self._lock = threading.Lock()
+        self.word_target = word_target
+        self.reset()
+
+    @property
+    def word_target(self) -> int:
+        return self._word_target
+
+    @word_target.setter
+    def word_target(self, value: int):
+        try:
+            self._word_target = max(0, int(value or 0))
+        except (TypeError, ValueError):
+            logger.warning(f"Invalid word target {value!r}; disabling it")
+            self._word_target = 0
+
+    @property
+    def word_count(self) -> int:
+        return self._word_count
+
+    @property
+    def started(self) -> bool:
+        return self._started_at is not None
+
+    @property
+    def elapsed(self) -> float:
+        """Seconds since the session started (0 before the first dictation)."""
+        if self._started_at is None:
+            return 0.0
+        return self._clock() - self._started_at
+
+    def reset(self):
+        """Start a new session: clear the timer, word count and target notification."""
+        with self._lock:
+            self._started_at: Optional[float] = None
+            self._word_count = 0
+            self._target_announced = False
+
+    def start(self):
+        """Start the clock if it is not running yet."""
+        with self._lock:
+            if self._started_at is None:
+                self._started_at = self._clock()
+
+    def add_text(self, text: str):
+        """Count the words of dictated text, announcing the target once reached."""
+        words = count_words(text)
+        if not words:
+            return
+        self.start()
+        with self._lock:
+            self._word_count += words
+            reached = (
+                self._word_target > 0
+                and self._word_count >= self._word_target
+                and not self._target_announced
+            )
+            if reached:
+                self._target_announced = True
+        if reached:
+            logger.info(f"Word target of {self._word_target} reached")
+            if self._on_target_reached is not None:
+                self._on_target_reached(self._word_count, self.elapsed)
+
+    def summary(self) -> str:
+        """
+        Describe the session in a short line.
+
+        Example: "12:05 · 340 / 500 words"
+        """
+        if self._word_target:
+            words = f"{self._word_count} / {self._word_target} words"
+        else:
+            words = f"{self._word_count} word{'' if self._word_count == 1 else 's'}"
+        return f"{format_elapsed(self.elapsed)} · {words}"
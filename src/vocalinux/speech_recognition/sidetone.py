@@ -0,0 +1,98 @@
+"""
+Microphone sidetone for Vocalinux.
+
+Plays the captured microphone signal back to the default output device at a
+low volume while dictating, so headset users can hear whether their mic is
+picking them up.
+"""
+
+import logging
+
+import numpy as np
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_SIDETONE_VOLUME = 0.3
+
+# Small output buffer keeps the delay between speaking and hearing yourself short
+_OUTPUT_FRAMES_PER_BUFFER = 256
+
+
+class SidetoneMonitor:
+    """Mono 16-bit playback of captured audio to the default output device.
+
+    Writes never block: if the output falls behind, chunks are dropped so the
+    capture loop in _record_audio() is never stalled.
+    """
+
+    def __init__(self, volume: float = DEFAULT_SIDETONE_VOLUME):
+        self.volume = volume
+        self._stream = None
+
+    @property
+    def volume(self) -> float:
+        """Playback gain between 0.0 and 1.0."""
+        return self._volume
+
+    @volume.setter
+    def volume(self, value: float):
+        self._volume = max(0.0, min(1.0, float(value)))
+
+    @property
+    def active(self) -> bool:
+        return self._stream is not None
+
+    def start(self, audio, rate: int) -> bool:
+        """
+        Open the output stream.
+
+        Args:
+            audio: The PyAudio instance used for capture
+            rate: Sample rate of the audio that will be written
+
+        Returns:
+            True if playback started, False otherwise
+        """
+        import pyaudio
+
+        try:
+            self._stream = audio.open(
+                format=pyaudio.paInt16,
+                channels=1,
+                rate=rate,
+                output=True,
+                frames_per_buffer=_OUTPUT_FRAMES_PER_BUFFER,
+            )
+            logger.info(f"Microphone sidetone started at {int(self._volume * 100)}% volume")
+            return True
+        except (IOError, OSError, ValueError) as e:
+            logger.warning(f"Could not open output device for microphone sidetone: {e}")
+            self._stream = None
+            return False
+
+    def write(self, data: bytes):
+        """Play a chunk of mono 16-bit audio, dropping it if the output is busy."""
+        if self._stream is None:
+            return
+
+        samples = np.frombuffer(data, dtype=np.int16).astype(np.float32) * self._volume
+        frames = np.clip(samples, -32768, 32767).astype(np.int16)
+        try:
+            if self._stream.get_write_available() < len(frames):
+                return
+            self._stream.write(frames.tobytes(), exception_on_underflow=False)
+        except (IOError, OSError) as e:
+            logger.warning(f"Microphone sidetone stopped: {e}")
+            self.stop()
+
+    def stop(self):
+        """Close the output stream."""
+        if self._stream is None:
+            return
+        try:
+            self._stream.stop_stream()
+            self._stream.close()
+        except Exception as e:
+            logger.debug(f"Error closing sidetone stream: {e}")
+        finally:
+            self._stream = None
@@ -0,0 +1,140 @@
+"""
+Speaker profile for the VOSK engine.
+
+With VOSK's speaker model (vosk-model-spk) loaded, the recognizer reports an
+x-vector with every result: a fingerprint of the voice that spoke. A short
+enrollment, read aloud once, is averaged into a profile stored in the data
+directory. While the profile is enabled, utterances in a voice too far from
+it (someone else in the room, a TV, a call on speakers) are skipped instead
+of typed.
+
+VOSK doesn't expose a way to adapt its acoustic model to a voice, so the
+profile can't make the model itself hear an accent better; it keeps other
+voices out of the transcript, which is where most of the errors for a
+quiet or atypical voice come from in a shared room.
+"""
+
+import json
+import logging
+import math
+import os
+from typing import Iterable, Optional, Sequence
+
+from ..utils.paths import data_dir
+
+logger = logging.getLogger(__name__)
+
+# The speaker model, unpacked into the models directory (or a system models directory)
+SPK_MODEL_NAME = "vosk-model-spk-0.4"
+SPK_MODEL_URL = f"https://alphacephei.com/vosk/models/{SPK_MODEL_NAME}.zip"
+
+# How long the enrollment recording is; enough for a few sentences
+ENROLLMENT_SECONDS = 15
+
+# Read aloud during enrollment
+ENROLLMENT_TEXT = (
+    "I am setting up voice typing so it learns how I sound. "
+    "Please type exactly what I say, and ignore anyone else who is talking nearby. "
+    "The weather today is clear, and I will send the report to the team this afternoon."
+)
+
+# Utterances further than this cosine distance from the profile are another voice
+DEFAULT_MAX_SPEAKER_DISTANCE = 0.6
+
+# Enrollments with less speech than this give an unreliable profile (x-vector frames)
+MIN_ENROLLMENT_FRAMES = 300
+
+PROFILE_FILENAME = "speaker_profile.json"
+
+
+def profile_path() -> str:
+    """Where the enrolled speaker profile is stored."""
+    return os.path.join(data_dir(), PROFILE_FILENAME)
+
+
+def find_spk_model(search_dirs: Iterable[str]) -> Optional[str]:
+    """Return the speaker model directory in the first of search_dirs that has it."""
+    for directory in search_dirs:
+        path = os.path.join(directory, SPK_MODEL_NAME)
+        if os.path.isdir(path):
+            return path
+    return None
+
+
+def cosine_distance(a: Sequence[float], b: Sequence[float]) -> float:
+    """Cosine distance between two x-vectors: 0 for the same direction, up to 2."""
+    dot = sum(x * y for x, y in zip(a, b))
+    norm = math.sqrt(sum(x * x for x in a)) * math.sqrt(sum(y * y for y in b))
+    if norm == 0:
+        return 1.0
+    return 1.0 - dot / norm
+
+
+class SpeakerProfile:
+    """The enrolled voice: an x-vector averaged over the enrollment."""
+
+    def __init__(self, xvector: list[float], frames: int):
+        """
+        Create a profile.
+
+        Args:
+            xvector: The averaged x-vector
+            frames: How many frames of speech it was averaged over
+        """
+        self.xvector = xvector
+        self.frames = frames
+
+    @classmethod
+    def from_results(cls, results: Iterable[dict]) -> Optional["SpeakerProfile"]:
+        """
+        Average the x-vectors of VOSK results, weighted by their speech frames.
+
+        Returns:
+            The profile, or None if the results carry too little speech
+        """
+        total: Optional[list[float]] = None
+        frames = 0
+        for result in results:
+            xvector = result.get("spk")
+            weight = result.get("spk_frames", 0)
+            if not xvector or weight <= 0:
+                continue
+            if total is None:
+                total = [0.0] * len(xvector)
+            total = [t + x * weight for t, x in zip(total, xvector)]
+            frames += weight
+        if total is None or frames < MIN_ENROLLMENT_FRAMES:
+            return None
+        return cls([t / frames for t in total], frames)
+
+    def distance(self, xvector: Sequence[float]) -> float:
+        """Cosine distance from an utterance's x-vector to this voice."""
+        return cosine_distance(self.xvector, xvector)
+
+    def matches(
+        self, xvector: Sequence[float], max_distance: float = DEFAULT_MAX_SPEAKER_DISTANCE
+    ) -> bool:
+        """Whether an utterance's x-vector is close enough to be this voice."""
+        return self.distance(xvector) <= max_distance
+
+    def save(self, path: Optional[str] = None) -> None:
+        """Write the profile, by default to the data directory."""
+        path = path or profile_path()
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        with open(path, "w", encoding="utf-8") as f:
+            json.dump({"xvector": self.xvector, "frames": self.frames}, f)
+        logger.info(f"Saved speaker profile to {path}")
+
+    @classmethod
+    def load(cls, path: Optional[str] = None) -> Optional["SpeakerProfile"]:
+        """Read the saved profile; None if there is none or it can't be read."""
+        path = path or profile_path()
+        try:
+            with open(path, encoding="utf-8") as f:
+                data = json.load(f)
+            return cls([float(x) for x in data["xvector"]], int(data.get("frames", 0)))
+        except FileNotFoundError:
+            return None
+        except (OSError, ValueError, KeyError, TypeError) as e:
+            logger.warning(f"Could not read the speaker profile {path}: {e}")
+            return None
@@ -0,0 +1,84 @@
+"""
+Ordered post-processing of recognized text for Vocalinux.
+
+Before it is typed, a transcript passes through a chain of stages: voice
+commands, intent commands, punctuation, number formatting, the profanity
+filter and preserved capitalization. Output profiles list the stages in the
+order they run, so a profile can move a stage or leave it out to skip it.
+"""
+
+import logging
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+# A stage takes the text and the voice command actions so far and returns both
+Stage = Callable[[str, list], tuple[str, list]]
+
+# Every stage, in the order used when a profile doesn't list any
+PIPELINE_STAGES = (
+    "commands",  # Voice commands ("new line", "delete that", ...)
+    "intents",  # Intent commands that take the whole utterance ("open Firefox")
+    "punctuation",  # Strip or restore punctuation as set by the punctuation level
+    "numbers",  # Number formatting: ordinals, fractions, roman numerals
+    "profanity",  # The profanity filter
+    "capitalization",  # Preserved capitalization of acronyms and brand names
+)
+
+
+def parse_pipeline(stages: Optional[list]) -> list[str]:
+    """
+    Clean up a configured list of stages.
+
+    Args:
+        stages: Stage names in order, or None for all stages in the default order
+
+    Returns:
+        The known stages in order; unknown names are warned about and
+        dropped, and repeated ones are kept only the first time
+    """
+    if stages is None:
+        return list(PIPELINE_STAGES)
+    parsed = []
+    for stage in stages:
+        stage = str(stage).strip().lower()
+        if stage not in PIPELINE_STAGES:
+            logger.warning(f"Unknown text pipeline stage {stage!r}, ignored")
+        elif stage not in parsed:
+            parsed.append(stage)
+    return parsed
+
+
+class TextPipeline:
+    """Runs the post-processing stages in the configured order."""
+
+    def __init__(self, stages: Optional[list] = None):
+        """
+        Initialize the pipeline.
+
+        Args:
+            stages: Stage names from PIPELINE_STAGES in the order they run
+                (default: all of them, in that order)
+        """
+        self.stages = parse_pipeline(stages)
+
+    def run(self, text: str, handlers: dict[str, Stage]) -> tuple[str, list]:
+        """
+        Run the text through the stages.
+
+        Args:
+            text: The transcript
+            handlers: Stage name -> the function carrying it out; stages
+                without a handler are skipped, so callers can run a subset
+
+        Returns:
+            The processed text and the voice command actions in it
+        """
+        # Engines often start a transcript with a space
+        text = text.strip()
+        actions: list = []
+        for stage in self.stages:
+            handler = handlers.get(stage)
+            if handler is not None:
+                text, actions = handler(text, actions)
+        return text, actions
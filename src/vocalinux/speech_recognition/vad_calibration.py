@@ -0,0 +1,88 @@
+"""
+Microphone calibration for the VAD sensitivity.
+
+Choosing a VAD sensitivity of 1-5 by hand is trial and error. Calibration
+measures a few seconds of the room's silence and a few of the user reading
+aloud, and picks the sensitivity whose speech threshold falls between the
+two, so background noise is ignored and quiet speech is still heard.
+"""
+
+from dataclasses import dataclass
+from typing import Optional, Sequence
+
+from .audio_level import adaptive_vad_threshold_dbfs
+
+# How long each part of the calibration records
+SILENCE_SECONDS = 3.0
+SPEECH_SECONDS = 6.0
+
+# Speech closer than this to the noise can't be told apart by its level
+MIN_SEPARATION_DB = 6.0
+
+# Chunks this far above the noise count as voiced while reading aloud; the
+# rest are the pauses between words
+_VOICED_MARGIN_DB = 3.0
+
+# Where the threshold should sit between noise (0) and speech (1): nearer the
+# noise, so quiet word endings still count as speech
+_THRESHOLD_POSITION = 0.4
+
+SENSITIVITIES = (1, 2, 3, 4, 5)
+
+
+@dataclass(frozen=True)
+class CalibrationResult:
+    """What calibration measured and the sensitivity it recommends."""
+
+    noise_dbfs: float
+    speech_dbfs: float
+    sensitivity: Optional[int]
+    threshold_dbfs: Optional[float] = None
+
+    @property
+    def ok(self) -> bool:
+        """Whether speech could be told apart from the noise."""
+        return self.sensitivity is not None
+
+
+def _percentile(levels: Sequence[float], fraction: float) -> float:
+    ordered = sorted(levels)
+    return ordered[round(fraction * (len(ordered) - 1))]
+
+
+def calibrate_sensitivity(
+    silence_dbfs: Sequence[float], speech_dbfs: Sequence[float]
+) -> CalibrationResult:
+    """
+    Pick the VAD sensitivity for the measured noise and speech levels.
+
+    Args:
+        silence_dbfs: Chunk levels recorded while the user was quiet
+        speech_dbfs: Chunk levels recorded while the user read aloud
+
+    Returns:
+        The result; its sensitivity is None when the speech was too close to
+        the noise (or nothing was recorded) for any sensitivity to work
+    """
+    if not silence_dbfs or not speech_dbfs:
+        return CalibrationResult(float("-inf"), float("-inf"), None)
+
+    # The loud end of the room's noise must stay below the threshold; the
+    # energy VAD measures its noise floor as the quietest recent level
+    noise = _percentile(silence_dbfs, 0.9)
+    noise_floor = min(silence_dbfs)
+    voiced = [level for level in speech_dbfs if level > noise + _VOICED_MARGIN_DB]
+    speech = _percentile(voiced, 0.5) if voiced else max(speech_dbfs)
+    if speech - noise < MIN_SEPARATION_DB:
+        return CalibrationResult(noise, speech, None)
+
+    target = noise + (speech - noise) * _THRESHOLD_POSITION
+    thresholds = {
+        sensitivity: adaptive_vad_threshold_dbfs(sensitivity, noise_floor)
+        for sensitivity in SENSITIVITIES
+    }
+    usable = [s for s in SENSITIVITIES if noise < thresholds[s] < speech]
+    if not usable:
+        return CalibrationResult(noise, speech, None)
+    best = min(usable, key=lambda s: abs(thresholds[s] - target))
+    return CalibrationResult(noise, speech, best, thresholds[best])
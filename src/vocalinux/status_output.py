@@ -0,0 +1,227 @@
+"""
+Status output for window-manager bars (waybar, polybar) for Vocalinux.
+
+Tiling window manager setups often have no system tray. While Vocalinux runs
+it keeps a small JSON status file in its runtime directory with the
+recognition state and the microphone level, and ``vocalinux status`` prints
+it: once, or with ``--follow`` as one line per change for a bar's custom
+module to read. JSON lines carry waybar's text/alt/class/tooltip/percentage
+keys, so they work as a waybar ``return-type: json`` module as they are;
+``--format text`` prints plain lines for polybar.
+"""
+
+import argparse
+import json
+import logging
+import os
+import sys
+import threading
+import time
+from typing import Callable, Optional
+
+from .common_types import RecognitionState
+from .utils.paths import ensure_private_dir, runtime_dir
+
+logger = logging.getLogger(__name__)
+
+STATUS_FILE_NAME = "status.json"
+
+# State reported when no instance is running
+STOPPED = "stopped"
+
+# Fewest seconds between level-only updates of the status file
+LEVEL_UPDATE_INTERVAL = 0.1
+
+# How often --follow checks the status file
+FOLLOW_POLL_INTERVAL = 0.1
+
+_TEXT = {
+    "idle": "Idle",
+    "listening": "Listening",
+    "processing": "Processing",
+    "error": "Error",
+    STOPPED: "Not running",
+}
+
+
+def status_file_path() -> str:
+    """Where the running instance keeps its status."""
+    return os.path.join(runtime_dir(), STATUS_FILE_NAME)
+
+
+class StatusWriter:
+    """Keeps the status file up to date from the recognition callbacks."""
+
+    def __init__(
+        self,
+        path: Optional[str] = None,
+        min_interval: float = LEVEL_UPDATE_INTERVAL,
+        clock: Callable[[], float] = time.monotonic,
+    ):
+        """
+        Initialize the writer and write the idle status.
+
+        Args:
+            path: The status file (default: status_file_path())
+            min_interval: Fewest seconds between level-only updates
+            clock: Replacement for time.monotonic, for tests
+        """
+        self.path = path or status_file_path()
+        self._min_interval = min_interval
+        self._clock = clock
+        self._state = "idle"
+        self._level = 0.0
+        self._last_write: Optional[float] = None
+        self._lock = threading.Lock()
+        self._write()
+
+    def on_state_changed(self, state: RecognitionState):
+        """State callback for the recognition manager."""
+        with self._lock:
+            self._state = state.name.lower()
+            if state != RecognitionState.LISTENING:
+                self._level = 0.0
+            self._write()
+
+    def on_audio_level(self, level: float):
+        """Audio level callback for the recognition manager (0-100)."""
+        with self._lock:
+            self._level = round(float(level), 1)
+            now = self._clock()
+            if self._last_write is None or now - self._last_write >= self._min_interval:
+                self._write()
+
+    def close(self):
+        """Remove the status file, e.g. on quit."""
+        with self._lock:
+            try:
+                os.remove(self.path)
+            except OSError:
+                pass
+
+    def _write(self):
+        self._last_write = self._clock()
+        status = {"state": self._state, "level": self._level, "pid": os.getpid()}
+        temp_path = f"{self.path}.tmp"
+        try:
+            ensure_private_dir(os.path.dirname(self.path))
+            with open(temp_path, "w", encoding="utf-8") as status_file:
+                json.dump(status, status_file)
+            # Replaced in one step, so readers never see a half-written file
+            os.replace(temp_path, self.path)
+        except OSError as e:
+            logger.debug(f"Could not write the status file {self.path}: {e}")
+
+
+def _process_running(pid) -> bool:
+    try:
+        os.kill(int(pid), 0)
+    except PermissionError:
+        return True
+    except (OSError, TypeError, ValueError):
+        return False
+    return True
+
+
+def read_status(path: Optional[str] = None) -> dict:
+    """
+    The running instance's status.
+
+    Returns:
+        A dict with ``state`` and ``level``; the state is "stopped" when no
+        instance is running, including after a crash left the file behind
+    """
+    try:
+        with open(path or status_file_path(), encoding="utf-8") as status_file:
+            status = json.load(status_file)
+    except (OSError, ValueError):
+        status = None
+    if not isinstance(status, dict) or not _process_running(status.get("pid")):
+        return {"state": STOPPED, "level": 0.0}
+    return {"state": str(status.get("state", STOPPED)), "level": float(status.get("level") or 0)}
+
+
+def format_status(status: dict, output_format: str = "json") -> str:
+    """
+    Format a status as one line for a bar.
+
+    Args:
+        status: A status from read_status()
+        output_format: "json" (waybar's custom module keys) or "text"
+    """
+    state = status["state"]
+    text = _TEXT.get(state, state.capitalize())
+    level = int(round(status["level"]))
+    if state == "listening":
+        text = f"{text} {level}%"
+    if output_format == "text":
+        return text
+    return json.dumps(
+        {
+            "text": text,
+            "alt": state,
+            "class": state,
+            "tooltip": f"Vocalinux: {text}",
+            "percentage": level,
+            "state": state,
+            "level": status["level"],
+        }
+    )
+
+
+def follow(
+    output_format: str = "json",
+    path: Optional[str] = None,
+    poll_interval: float = FOLLOW_POLL_INTERVAL,
+    write: Callable[[str], None] = print,
+    sleep: Callable[[float], None] = time.sleep,
+    running: Callable[[], bool] = lambda: True,
+):
+    """Print the status, then a new line each time it changes."""
+    last_line = None
+    while running():
+        line = format_status(read_status(path), output_format)
+        if line != last_line:
+            write(line)
+            last_line = line
+        sleep(poll_interval)
+
+
+def main(argv: list[str]) -> int:
+    """
+    Entry point for ``vocalinux status``.
+
+    Args:
+        argv: Arguments following the ``status`` subcommand
+
+    Returns:
+        Process exit code
+    """
+    parser = argparse.ArgumentParser(
+        prog="vocalinux status",
+        description="Print the running Vocalinux instance's state and microphone level",
+    )
+    parser.add_argument(
+        "--follow",
+        action="store_true",
+        help="Keep running and print a line each time the status changes (for bars)",
+    )
+    parser.add_argument(
+        "--format",
+        choices=["json", "text"],
+        default="json",
+        help="json: waybar custom module lines (default); text: plain lines, e.g. for polybar",
+    )
+    args = parser.parse_args(argv)
+
+    def write(line: str):
+        print(line, flush=True)
+
+    if not args.follow:
+        write(format_status(read_status(), args.format))
+        return 0
+    try:
+        follow(args.format, write=write)
+    except (KeyboardInterrupt, BrokenPipeError):
+        pass
+    return 0
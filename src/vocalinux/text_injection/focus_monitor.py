@@ -0,0 +1,114 @@
+"""
+Focus monitoring for Vocalinux.
+
+Watches the focused window while dictation is active and reports when focus
+moves away from the window that was focused when dictation started, so
+transcripts don't spill into windows that steal focus (chat popups, etc.).
+"""
+
+import logging
+import threading
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_POLL_INTERVAL = 0.5  # seconds between focus checks
+
+
+class FocusMonitor:
+    """
+    Detects focus changes away from a locked target window.
+
+    The target is the window focused when ``start()`` is called. Focus is
+    polled on a background thread; ``on_focus_lost`` is called once when a
+    different window becomes active, after which monitoring stops.
+    """
+
+    def __init__(
+        self,
+        get_window_id: Callable[[], Optional[str]],
+        on_focus_lost: Callable[[], None],
+        poll_interval: float = DEFAULT_POLL_INTERVAL,
+    ):
+        """
+        Initialize the focus monitor.
+
+        Args:
+            get_window_id: Returns the active window ID, or None if it can't be determined
+            on_focus_lost: Called when focus leaves the target window
+            poll_interval: Seconds between focus checks
+        """
+        self._get_window_id = get_window_id
+        self._on_focus_lost = on_focus_lost
+        self._poll_interval = poll_interval
+        self._target_window: Optional[str] = None
+        self._stop_event = threading.Event()
+        self._thread: Optional[threading.Thread] = None
+
+    @property
+    def active(self) -> bool:
+        """Whether the monitor is currently watching a target window."""
+        return self._thread is not None and self._thread.is_alive()
+
+    @property
+    def target_window(self) -> Optional[str]:
+        """The window ID focus is locked to, if any."""
+        return self._target_window
+
+    def start(self) -> bool:
+        """
+        Lock onto the currently focused window and start monitoring.
+
+        Returns:
+            True if monitoring started, False if the active window is unknown
+            (e.g. on Wayland compositors that don't expose it)
+        """
+        if self.active:
+            return True
+
+        target = self._get_window_id()
+        if not target:
+            logger.info("Active window unavailable, auto-stop on focus change is disabled")
+            return False
+
+        self._target_window = target
+        self._stop_event.clear()
+        self._thread = threading.Thread(target=self._run, daemon=True)
+        self._thread.start()
+        logger.debug(f"Focus monitor locked to window {target}")
+        return True
+
+    def stop(self):
+        """Stop monitoring."""
+        self._stop_event.set()
+        thread = self._thread
+        if thread is not None and thread is not threading.current_thread():
+            thread.join(timeout=self._poll_interval * 2)
+        self._thread = None
+        self._target_window = None
+
+    def check_focus(self) -> bool:
+        """
+        Check whether focus is still on the target window.
+
+        Returns:
+            True if focus is unchanged (or can't be determined), False if it moved
+        """
+        current = self._get_window_id()
+        if current is None or self._target_window is None:
+            return True
+        return current == self._target_window
+
+    def _run(self):
+        """Poll the focused window until stopped or focus is lost."""
+        while not self._stop_event.wait(self._poll_interval):
+            if not self.check_focus():
+                logger.info(
+                    f"Focus moved away from window {self._target_window}, stopping dictation"
+                )
+                self._stop_event.set()
+                try:
+                    self._on_focus_lost()
+                except Exception as e:
+                    logger.error(f"Error in focus lost callback: {e}")
+                break
@@ -29,14 +29,21 @@ from typing import Callable, Optional
 # Package import when loaded normally; absolute / inline fallbacks when this
 # file is executed by path (Vocalinux start_engine_process / IBus component exec).
 try:
-    from ..utils.paths import xdg_data_home
+    from ..utils.paths import ensure_private_dir, xdg_runtime_dir
 except ImportError:  # pragma: no cover - exercised via script-path subprocess
     try:
-        from vocalinux.utils.paths import xdg_data_home
+        from vocalinux.utils.paths import ensure_private_dir, xdg_runtime_dir
     except ImportError:
-        # Last resort: same semantics as vocalinux.utils.paths.xdg_data_home.
-        def xdg_data_home() -> str:
-            return os.environ.get("XDG_DATA_HOME") or os.path.expanduser("~/.local/share")
+        # Last resort: same semantics as vocalinux.utils.paths.xdg_runtime_dir.
+        def xdg_runtime_dir() -> str:
+            return os.environ.get("XDG_RUNTIME_DIR") or f"/tmp/runtime-{os.getuid()}"
+
+        def ensure_private_dir(path: str) -> str:
+            os.makedirs(path, 0o700, exist_ok=True)
+            if os.stat(path).st_uid != os.getuid():
+                raise PermissionError(f"{path} is not a directory owned by you")
+            os.chmod(path, 0o700)
+            return path
 
 
 logger = logging.getLogger(__name__)
@@ -70,8 +77,10 @@ except (ImportError, ValueError) as e:
     GObject = None
 
 
-# File paths for communication ($XDG_DATA_HOME, defaults to ~/.local/share)
-VOCALINUX_IBUS_DIR = Path(xdg_data_home()) / "vocalinux-ibus"
+# File paths for communication ($XDG_RUNTIME_DIR, private to the user on this
+# machine, so sessions of other users or on other machines sharing the home
+# directory never reach this engine)
+VOCALINUX_IBUS_DIR = Path(xdg_runtime_dir()) / "vocalinux-ibus"
 SOCKET_PATH = VOCALINUX_IBUS_DIR / "inject.sock"
 PID_FILE = VOCALINUX_IBUS_DIR / "engine.pid"
 
@@ -101,10 +110,13 @@ _COMPONENT_META = {
 
 
 def ensure_ibus_dir() -> None:
-    """Ensure the IBus data directory exists with secure permissions."""
-    VOCALINUX_IBUS_DIR.mkdir(parents=True, exist_ok=True)
-    # Secure the directory - only owner can access (prevents socket hijacking)
-    VOCALINUX_IBUS_DIR.chmod(0o700)
+    """Ensure the IBus runtime directory exists with secure permissions."""
+    # Only the owner can access it (prevents socket hijacking); a directory
+    # another user created first is refused
+    try:
+        ensure_private_dir(str(VOCALINUX_IBUS_DIR))
+    except PermissionError as e:
+        raise IBusSetupError(str(e)) from e
 
 
 def verify_peer_credentials(conn: socket.socket) -> bool:
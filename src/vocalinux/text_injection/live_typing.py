@@ -0,0 +1,133 @@
+"""
+Live typing for Vocalinux.
+
+Types partial results as soon as they arrive and corrects them in place as
+the engine revises its guess: only the part that changed is deleted with
+backspaces and retyped, so text appears while the user is still speaking.
+
+The same correction replaces a typed draft once a larger model has decoded
+the utterance again (draft-then-rewrite).
+"""
+
+import logging
+import threading
+from typing import Callable
+
+logger = logging.getLogger(__name__)
+
+
+def common_prefix_length(a: str, b: str) -> int:
+    """Number of leading characters two strings share."""
+    length = 0
+    for char_a, char_b in zip(a, b):
+        if char_a != char_b:
+            break
+        length += 1
+    return length
+
+
+def correction_keys(typed: str, target: str) -> str:
+    """
+    Keystrokes turning the typed text into the target text.
+
+    Backspaces ("\\b") remove the part after the common prefix, then the rest
+    of the target is typed: correction_keys("hello word", "hello world.")
+    returns "\\bld.".
+    """
+    keep = common_prefix_length(typed, target)
+    return "\b" * (len(typed) - keep) + target[keep:]
+
+
+class LiveTyper:
+    """Keeps the text typed for the current utterance in sync with the engine's latest guess."""
+
+    def __init__(self, inject: Callable[[str], bool]):
+        """
+        Initialize the live typer.
+
+        Args:
+            inject: Types text into the focused window, treating "\\b" as backspace
+        """
+        self._inject = inject
+        self._typed = ""
+        self._lock = threading.Lock()
+
+    @property
+    def active(self) -> bool:
+        """Whether partial text for the current utterance is on screen."""
+        return bool(self._typed)
+
+    def _replace(self, text: str) -> bool:
+        keys = correction_keys(self._typed, text)
+        if not keys:
+            return True
+        if not self._inject(keys):
+            logger.warning("Live typing correction failed; the typed text may be out of sync")
+            return False
+        self._typed = text
+        return True
+
+    def update(self, text: str) -> bool:
+        """Show a new partial result, correcting what was typed for the previous one."""
+        with self._lock:
+            return self._replace(text)
+
+    def finish(self, text: str) -> bool:
+        """Replace the partial text with the final text and start a new utterance."""
+        with self._lock:
+            success = self._replace(text)
+            self._typed = ""
+            return success
+
+    def discard(self) -> bool:
+        """Delete the partial text because no final text will replace it."""
+        return self.finish("")
+
+
+class DraftRewriter:
+    """Corrects the last typed utterance when a better transcript of it arrives."""
+
+    def __init__(self, inject: Callable[[str], bool]):
+        """
+        Initialize the rewriter.
+
+        Args:
+            inject: Types text into the focused window, treating "\\b" as backspace
+        """
+        self._inject = inject
+        self._typed = ""
+        # Hold while typing other text, so a rewrite can't land in the middle of it
+        self.lock = threading.RLock()
+
+    def typed(self, text: str):
+        """Remember the text just typed for an utterance; earlier drafts can't be reached."""
+        with self.lock:
+            self._typed = text
+
+    def forget(self):
+        """Stop correcting, e.g. because other text was typed or the cursor may have moved."""
+        self.typed("")
+
+    def rewrite(self, draft: str, text: str) -> bool:
+        """
+        Replace a draft with the rewritten text if it is still the last thing typed.
+
+        Args:
+            draft: The text that was delivered for the utterance
+            text: The text to replace it with
+
+        Returns:
+            Whether the typed text now reads as the rewrite
+        """
+        with self.lock:
+            if not draft or not self._typed.endswith(draft):
+                logger.debug("Draft is no longer the last typed text; keeping it")
+                return False
+            target = self._typed[: len(self._typed) - len(draft)] + text
+            keys = correction_keys(self._typed, target)
+            if keys and not self._inject(keys):
+                logger.warning("Rewriting the draft failed; the typed text may be out of sync")
+                self._typed = ""
+                return False
+            self._typed = target
+            return True
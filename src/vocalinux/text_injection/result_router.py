@@ -0,0 +1,184 @@
+"""
+Result routing for Vocalinux.
+
+Sends each final transcript to one or more sinks at once: typed into the
+focused window, copied to the clipboard, appended to a file (for example a
+daily notes file) or written to a socket for other programs to consume.
+Which sinks are used is set per output profile in the "output" config section.
+"""
+
+import json
+import logging
+import os
+import socket
+import time
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+RESULT_SINKS = ("inject", "clipboard", "file", "socket")
+
+SOCKET_TIMEOUT = 0.5  # seconds; a slow listener must not hold up dictation
+
+
+def expand_file_path(path_template: str, now: Optional[float] = None) -> str:
+    """
+    Expand "~" and strftime codes in a file sink path.
+
+    "~/Notes/%Y-%m-%d.md" becomes "/home/me/Notes/2026-10-17.md", so a new
+    file is started every day.
+    """
+    return os.path.expanduser(time.strftime(path_template, time.localtime(now)))
+
+
+def parse_socket_address(address: str):
+    """
+    Parse a socket sink address.
+
+    Returns:
+        (family, address) for socket.socket()/connect(): a Unix socket for a
+        path (optionally prefixed with "unix:"), TCP for "host:port"
+
+    Raises:
+        ValueError: If the address is neither
+    """
+    address = address.strip()
+    if address.startswith("unix:"):
+        address = address[len("unix:") :]
+    if address.startswith(("/", "~")):
+        return socket.AF_UNIX, os.path.expanduser(address)
+
+    host, sep, port = address.rpartition(":")
+    if not sep or not host or not port.isdigit():
+        raise ValueError(f"Invalid socket address: {address!r} (use host:port or a path)")
+    return socket.AF_INET, (host.strip("[]"), int(port))
+
+
+def profile_name(output_settings: dict, profile: Optional[str] = None) -> str:
+    """Name of the output profile in use: the given one, else the configured one."""
+    return profile or output_settings.get("profile", "default")
+
+
+def profile_settings(output_settings: dict, profile: Optional[str] = None) -> Optional[dict]:
+    """Settings of the output profile in use, or None if no such profile exists."""
+    return output_settings.get("profiles", {}).get(profile_name(output_settings, profile))
+
+
+class ResultRouter:
+    """Delivers final transcripts to the sinks enabled in an output profile."""
+
+    def __init__(
+        self,
+        inject: Callable[[str], bool],
+        copy_to_clipboard: Callable[[str], bool],
+        sinks=("inject",),
+        file_path: str = "",
+        socket_address: str = "",
+    ):
+        """
+        Initialize the router.
+
+        Args:
+            inject: Types text into the focused window; returns True on success
+            copy_to_clipboard: Copies text to the clipboard; returns True on success
+            sinks: Names from RESULT_SINKS to deliver to
+            file_path: File the "file" sink appends to; strftime codes are expanded
+            socket_address: "host:port" or Unix socket path for the "socket" sink
+        """
+        self._inject = inject
+        self._copy_to_clipboard = copy_to_clipboard
+        self.file_path = file_path
+        self.socket_address = socket_address
+        self.sinks = sinks
+
+    @classmethod
+    def from_config(
+        cls,
+        output_settings: dict,
+        inject: Callable[[str], bool],
+        copy_to_clipboard: Callable[[str], bool],
+        profile: Optional[str] = None,
+    ) -> "ResultRouter":
+        """
+        Build a router from the "output" config section.
+
+        Args:
+            output_settings: The "output" section of the config
+            inject: Types text into the focused window
+            copy_to_clipboard: Copies text to the clipboard
+            profile: Profile to use instead of the configured one
+        """
+        name = profile_name(output_settings, profile)
+        settings = profile_settings(output_settings, profile)
+        if settings is None:
+            logger.warning(f"Unknown output profile {name!r}; typing results only")
+            return cls(inject, copy_to_clipboard)
+
+        logger.info(f"Using output profile {name!r}")
+        return cls(
+            inject,
+            copy_to_clipboard,
+            sinks=settings.get("sinks", ["inject"]),
+            file_path=settings.get("file_path", ""),
+            socket_address=settings.get("socket_address", ""),
+        )
+
+    @property
+    def sinks(self) -> list[str]:
+        return list(self._sinks)
+
+    @sinks.setter
+    def sinks(self, sinks):
+        enabled = []
+        for sink in sinks:
+            if sink not in RESULT_SINKS:
+                logger.warning(f"Ignoring unknown result sink: {sink!r}")
+            elif sink == "file" and not self.file_path:
+                logger.warning("File sink enabled without a file_path; ignoring it")
+            elif sink == "socket" and not self.socket_address:
+                logger.warning("Socket sink enabled without a socket_address; ignoring it")
+            elif sink not in enabled:
+                enabled.append(sink)
+        self._sinks = enabled
+
+    def route(self, text: str) -> bool:
+        """
+        Deliver a final transcript to every enabled sink.
+
+        A failing sink is logged and skipped so the others still receive the text.
+
+        Returns:
+            True if the text was typed into the focused window
+        """
+        injected = False
+        for sink in self._sinks:
+            try:
+                if sink == "inject":
+                    injected = bool(self._inject(text))
+                elif sink == "clipboard":
+                    self._copy_to_clipboard(text)
+                elif sink == "file":
+                    self._append_to_file(text)
+                elif sink == "socket":
+                    self._send_to_socket(text)
+            except Exception as e:
+                logger.warning(f"Result sink {sink!r} failed: {e}")
+        return injected
+
+    def _append_to_file(self, text: str):
+        """Append the text as its own line to the file sink."""
+        path = expand_file_path(self.file_path)
+        directory = os.path.dirname(path)
+        if directory:
+            os.makedirs(directory, exist_ok=True)
+        with open(path, "a", encoding="utf-8") as f:
+            f.write(text + "\n")
+
+    def _send_to_socket(self, text: str):
+        """Send the text as one JSON line to the socket sink."""
+        family, address = parse_socket_address(self.socket_address)
+        message = json.dumps({"text": text, "timestamp": time.time()}) + "\n"
+        with socket.socket(family, socket.SOCK_STREAM) as sock:
+            sock.settimeout(SOCKET_TIMEOUT)
+            sock.connect(address)
+            sock.sendall(message.encode("utf-8"))
@@ -16,7 +16,7 @@ import time
 from enum import Enum
 from typing import Optional  # noqa: F401
 
-from ..utils.paths import config_dir
+from ..utils import notifications
 from .ibus_engine import (
     IBusTextInjector,
     is_ibus_active_input_method,
@@ -84,6 +84,8 @@ class TextInjector:
         self._state_lock = threading.Lock()
         self._clipboard_tool_health = {}
         self._clipboard_timeout = 0.35
+        # ydotoold started by this instance, stopped again on shutdown
+        self._ydotoold_process: Optional[subprocess.Popen] = None
 
         # Force Wayland mode if requested
         if wayland_mode and self.environment == DesktopEnvironment.X11:
@@ -149,6 +151,20 @@ class TextInjector:
                 self._ibus_injector.stop()
                 self._ibus_injector = None
             self._ibus_ready = False
+        self._stop_ydotoold()
+
+    def _stop_ydotoold(self) -> None:
+        """Stop the ydotoold this instance started, leaving one started elsewhere alone."""
+        process = getattr(self, "_ydotoold_process", None)
+        self._ydotoold_process = None
+        if process is None or process.poll() is not None:
+            return
+        logger.info("Stopping the ydotoold started for text injection")
+        process.terminate()
+        try:
+            process.wait(timeout=2)
+        except subprocess.TimeoutExpired:
+            process.kill()
 
     def _detect_environment(self) -> DesktopEnvironment:
         """
@@ -233,9 +249,24 @@ class TextInjector:
         runtime_dir = os.environ.get("XDG_RUNTIME_DIR")
         if runtime_dir:
             paths.append(os.path.join(runtime_dir, ".ydotool_socket"))
-        paths.append("/tmp/.ydotool_socket")
+        shared_socket = "/tmp/.ydotool_socket"
+        if self._is_own_socket(shared_socket):
+            paths.append(shared_socket)
         return paths
 
+    @staticmethod
+    def _is_own_socket(path: str) -> bool:
+        """Return False for a socket another user's ydotoold owns.
+
+        /tmp is shared by every session on the machine; typing through another
+        user's daemon would put our text into their session.
+        """
+        try:
+            owner = os.stat(path).st_uid
+        except OSError:
+            return True
+        return owner in (os.getuid(), 0)
+
     def _is_ydotoold_running(self) -> bool:
         """Return True if ydotoold is accepting connections.
 
@@ -311,7 +342,7 @@ class TextInjector:
             )
             return False
         try:
-            subprocess.Popen(
+            self._ydotoold_process = subprocess.Popen(
                 [ydotoold],
                 stdout=subprocess.DEVNULL,
                 stderr=subprocess.DEVNULL,
@@ -734,35 +765,23 @@ class TextInjector:
     def _should_copy_to_clipboard(self) -> bool:
         """Check if copy-to-clipboard setting is enabled."""
         try:
-            import json
+            from ..ui.config_manager import read_user_config
 
-            config_path = os.path.join(config_dir(), "config.json")
-            if os.path.exists(config_path):
-                with open(config_path, "r") as f:
-                    config = json.load(f)
-                return config.get("text_injection", {}).get("copy_to_clipboard", False)
+            config = read_user_config()
+            return config.get("text_injection", {}).get("copy_to_clipboard", False)
         except Exception as e:
             logger.debug(f"Could not read copy_to_clipboard setting: {e}")
         return False
 
     def _show_clipboard_fallback_notification(self):
         """Show a desktop notification when text is copied to clipboard as fallback."""
-        try:
-            subprocess.Popen(
-                [
-                    "notify-send",
-                    "-i",
-                    "edit-paste",
-                    "-a",
-                    "Vocalinux",
-                    "Text copied to clipboard",
-                    "Text injection failed - paste with Ctrl+V",
-                ],
-                stdout=subprocess.DEVNULL,
-                stderr=subprocess.DEVNULL,
-            )
-        except Exception as e:
-            logger.debug(f"Could not show clipboard notification: {e}")
+        # Injection usually fails for every utterance in a row, so coalesce repeats
+        notifications.notify(
+            "Text copied to clipboard",
+            "Text injection failed - paste with Ctrl+V",
+            "edit-paste",
+            category="clipboard_fallback",
+        )
 
     def inject_text(self, text: str) -> bool:
         """
@@ -1373,6 +1392,77 @@ class TextInjector:
         except Exception as e:
             logger.debug(f"Could not get window info: {e}")
 
+    def get_active_window_id(self) -> Optional[str]:
+        """
+        Get the ID of the currently focused window.
+
+        Returns:
+            The X11 window ID, or None on pure Wayland or if xdotool is unavailable
+        """
+        if self.environment not in (
+            DesktopEnvironment.X11,
+            DesktopEnvironment.X11_IBUS,
+            DesktopEnvironment.WAYLAND_XDOTOOL,
+        ):
+            return None
+        if not shutil.which("xdotool"):
+            return None
+
+        env = os.environ.copy()
+        if self.environment == DesktopEnvironment.WAYLAND_XDOTOOL:
+            env["GDK_BACKEND"] = "x11"
+            if "DISPLAY" not in env or not env["DISPLAY"]:
+                env["DISPLAY"] = ":0"
+
+        try:
+            result = subprocess.run(
+                ["xdotool", "getactivewindow"],
+                env=env,
+                stdout=subprocess.PIPE,
+                stderr=subprocess.PIPE,
+                text=True,
+                check=True,
+                timeout=2,
+            )
+        except (subprocess.SubprocessError, OSError) as e:
+            logger.debug(f"Could not get active window: {e}")
+            return None
+
+        return result.stdout.strip() or None
+
+    def get_active_window_class(self) -> Optional[str]:
+        """
+        Get the class of the currently focused window, e.g. "Thunderbird".
+
+        Returns:
+            The X11 window class, or None on pure Wayland or if xdotool is unavailable
+        """
+        window_id = self.get_active_window_id()
+        if window_id is None:
+            return None
+
+        env = os.environ.copy()
+        if self.environment == DesktopEnvironment.WAYLAND_XDOTOOL:
+            env["GDK_BACKEND"] = "x11"
+            if "DISPLAY" not in env or not env["DISPLAY"]:
+                env["DISPLAY"] = ":0"
+
+        try:
+            result = subprocess.run(
+                ["xdotool", "getwindowclassname", window_id],
+                env=env,
+                stdout=subprocess.PIPE,
+                stderr=subprocess.PIPE,
+                text=True,
+                check=True,
+                timeout=2,
+            )
+        except (subprocess.SubprocessError, OSError) as e:
+            logger.debug(f"Could not get active window class: {e}")
+            return None
+
+        return result.stdout.strip() or None
+
     def _log_x11_window_info(self):
         """Log X11 window information."""
         env = os.environ.copy()
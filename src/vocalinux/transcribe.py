@@ -0,0 +1,181 @@
+"""
+Offline file transcription for Vocalinux.
+
+Implements ``vocalinux transcribe FILE``, which runs a WAV file through the
+configured speech engine and prints the result either as plain text or as
+structured JSON for scripts and downstream tools.
+"""
+
+import argparse
+import json
+import logging
+import sys
+import wave
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+OUTPUT_FORMATS = ["text", "json"]
+
+# Audio is fed to the engines in chunks matching the live capture path
+CHUNK_SAMPLES = 1024
+TARGET_SAMPLE_RATE = 16000
+
+
+def parse_transcribe_arguments(argv: list[str]) -> argparse.Namespace:
+    """Parse arguments for the ``transcribe`` subcommand."""
+    parser = argparse.ArgumentParser(
+        prog="vocalinux transcribe",
+        description="Transcribe a WAV file with the configured speech engine",
+    )
+    parser.add_argument("file", help="Path to a 16-bit PCM WAV file")
+    parser.add_argument(
+        "--format",
+        choices=OUTPUT_FORMATS,
+        default="text",
+        help="Output format (json includes segments with timestamps and confidence)",
+    )
+    parser.add_argument(
+        "--engine",
+        type=str,
+        choices=["vosk", "whisper", "whisper_cpp", "remote_api"],
+        help="Speech recognition engine (defaults to the saved config)",
+    )
+    parser.add_argument("--model", type=str, help="Model ID (defaults to the saved config)")
+    parser.add_argument("--language", type=str, help="Language code (defaults to the saved config)")
+    parser.add_argument("--debug", action="store_true", help="Enable debug logging")
+    return parser.parse_args(argv)
+
+
+def load_wav_chunks(path: str) -> tuple[list[bytes], float]:
+    """
+    Load a WAV file as 16kHz mono 16-bit PCM chunks.
+
+    Args:
+        path: Path to the WAV file
+
+    Returns:
+        Tuple of (audio chunks, duration in seconds)
+
+    Raises:
+        ValueError: If the file is not 16-bit PCM
+    """
+    from .speech_recognition.resample import downmix_to_mono, resample_audio
+
+    with wave.open(path, "rb") as wav_file:
+        channels = wav_file.getnchannels()
+        sample_width = wav_file.getsampwidth()
+        sample_rate = wav_file.getframerate()
+        frames = wav_file.readframes(wav_file.getnframes())
+
+    if sample_width != 2:
+        raise ValueError(f"Unsupported sample width {sample_width * 8}-bit, expected 16-bit PCM")
+
+    data = resample_audio(downmix_to_mono(frames, channels), sample_rate, TARGET_SAMPLE_RATE)
+    chunk_bytes = CHUNK_SAMPLES * 2
+    chunks = [data[i : i + chunk_bytes] for i in range(0, len(data), chunk_bytes)]
+    duration = len(data) / 2 / TARGET_SAMPLE_RATE
+    return chunks, duration
+
+
+def build_result(
+    file_path: str,
+    engine: str,
+    model_size: str,
+    language: Optional[str],
+    duration: float,
+    segments: list[dict],
+) -> dict:
+    """
+    Build the structured transcription result.
+
+    Every segment gets an ``id`` and a ``speaker`` field. Speaker is always
+    None for now since none of the engines perform diarization, but the key is
+    present so consumers can rely on a stable schema.
+    """
+    result_segments = []
+    for index, segment in enumerate(segments):
+        confidence = segment.get("confidence")
+        result_segments.append(
+            {
+                "id": index,
+                "start": round(float(segment.get("start", 0.0)), 3),
+                "end": round(float(segment.get("end", 0.0)), 3),
+                "text": segment.get("text", ""),
+                "confidence": round(confidence, 3) if confidence is not None else None,
+                "language": segment.get("language"),
+                "speaker": segment.get("speaker"),
+            }
+        )
+
+    return {
+        "file": file_path,
+        "engine": engine,
+        "model": model_size,
+        "language": language,
+        "duration": round(duration, 3),
+        "text": " ".join(s["text"] for s in result_segments).strip(),
+        "segments": result_segments,
+    }
+
+
+def format_result(result: dict, output_format: str) -> str:
+    """Render a transcription result in the requested output format."""
+    if output_format == "json":
+        return json.dumps(result, indent=2, ensure_ascii=False)
+    return result["text"]
+
+
+def main(argv: list[str]) -> int:
+    """
+    Entry point for ``vocalinux transcribe``.
+
+    Args:
+        argv: Arguments following the ``transcribe`` subcommand
+
+    Returns:
+        Process exit code
+    """
+    args = parse_transcribe_arguments(argv)
+
+    # Keep stdout clean for the transcript; diagnostics go to stderr
+    logging.getLogger().setLevel(logging.DEBUG if args.debug else logging.WARNING)
+
+    from .speech_recognition.recognition_manager import SpeechRecognitionManager
+    from .ui.config_manager import ConfigManager
+    from .utils.credentials import resolve_remote_api_key
+
+    saved_settings = ConfigManager().get_settings().get("speech_recognition", {})
+    engine = args.engine or saved_settings.get("engine", "whisper_cpp")
+    model_size = args.model or saved_settings.get("model_size", "tiny")
+    language = args.language or saved_settings.get("language", "auto")
+
+    try:
+        chunks, duration = load_wav_chunks(args.file)
+    except (OSError, EOFError, ValueError, wave.Error) as e:
+        logger.error(f"Could not read audio file {args.file}: {e}")
+        return 1
+
+    try:
+        manager = SpeechRecognitionManager(
+            engine=engine,
+            model_size=model_size,
+            language=language,
+            defer_download=False,
+            remote_api_url=saved_settings.get("remote_api_url", ""),
+            remote_api_key=resolve_remote_api_key(saved_settings),
+            remote_api_endpoint=saved_settings.get("remote_api_endpoint", "/inference"),
+            remote_api_model=saved_settings.get("remote_api_model", "whisper-1"),
+        )
+        segments = manager.transcribe_segments(chunks)
+    except Exception as e:
+        logger.error(f"Transcription failed: {e}")
+        return 1
+
+    result = build_result(args.file, engine, model_size, language, duration, segments)
+    print(format_result(result, args.format))
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main(sys.argv[1:]))
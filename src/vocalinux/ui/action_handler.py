@@ -48,6 +48,7 @@ class ActionHandler:
         # Build action dispatch table: custom handlers + shortcut-based actions
         self.action_handlers: dict[str, Callable[[], bool]] = {
             "delete_last": self._handle_delete_last,
+            "read_back": self._handle_read_back,
         }
         for action, shortcut in self._SHORTCUT_ACTIONS.items():
             self.action_handlers[action] = self._make_shortcut_handler(shortcut)
@@ -75,6 +76,15 @@ class ActionHandler:
             logger.warning(f"Unknown action: {action}")
             return False
 
+    def register_actions(self, handlers: dict[str, Callable[[], bool]]):
+        """
+        Register additional action handlers, e.g. voice-controlled settings.
+
+        Args:
+            handlers: Mapping of action name to a handler returning success
+        """
+        self.action_handlers.update(handlers)
+
     def set_last_injected_text(self, text: str):
         """
         Set the last injected text for undo/delete operations.
@@ -107,3 +117,17 @@ class ActionHandler:
             self.last_injected_text = ""
 
         return success
+
+    def _handle_read_back(self) -> bool:
+        """Handle 'read that back' by speaking the last injected text aloud."""
+        from . import text_to_speech
+
+        if not text_to_speech.is_readback_enabled():
+            logger.info("Read back is disabled; enable it in Settings to use this command")
+            return False
+
+        if not self.last_injected_text.strip():
+            logger.debug("No text to read back")
+            return True
+
+        return text_to_speech.speak(self.last_injected_text)
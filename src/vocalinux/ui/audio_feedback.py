@@ -10,6 +10,7 @@ import shutil
 import subprocess
 import sys
 from pathlib import Path  # noqa: F401
+from typing import Optional
 
 logger = logging.getLogger(__name__)
 
@@ -42,6 +43,7 @@ def _is_ci_mode():
 
 # Import the centralized resource manager
 from ..utils.resource_manager import ResourceManager  # noqa: E402
+from ..utils.self_playback import register_playback  # noqa: E402
 
 # Initialize resource manager
 _resource_manager = ResourceManager()
@@ -50,15 +52,48 @@ _resource_manager = ResourceManager()
 START_SOUND = _resource_manager.get_sound_path("start_recording")
 STOP_SOUND = _resource_manager.get_sound_path("stop_recording")
 ERROR_SOUND = _resource_manager.get_sound_path("error")
+UTTERANCE_SOUND = _resource_manager.get_sound_path("utterance")
 
 
-def _is_sound_effects_enabled() -> bool:
+def _sound_effects_settings() -> dict:
+    """The [sound_effects] section of the config, or {} if it can't be read."""
     try:
         from .config_manager import ConfigManager
 
-        return ConfigManager().is_sound_effects_enabled()
+        return ConfigManager().get_settings().get("sound_effects", {})
     except Exception:
-        return True
+        return {}
+
+
+def _is_sound_effects_enabled(settings: Optional[dict] = None) -> bool:
+    """
+    Whether sound effects are on (the default, also when the config can't be read).
+
+    Args:
+        settings: The [sound_effects] section if already read; read from the config if None
+    """
+    if settings is None:
+        settings = _sound_effects_settings()
+    return bool(settings.get("enabled", True))
+
+
+def _sound_for(event: str, default_path: str, settings: dict) -> str:
+    """
+    The sound to play for an event: the user's own file if set, else the built-in one.
+
+    Args:
+        event: "start", "stop", "utterance" or "error"; the config key is "<event>_sound"
+        default_path: The built-in sound
+        settings: The [sound_effects] section of the config
+    """
+    custom = settings.get(f"{event}_sound") or ""
+    if not custom:
+        return default_path
+    path = os.path.expanduser(custom)
+    if not os.path.isfile(path):
+        logger.warning(f"Custom {event} sound not found: {path}; using the built-in one")
+        return default_path
+    return path
 
 
 def _get_audio_player():
@@ -127,51 +162,77 @@ def _play_sound_file(sound_path):
         logger.info(f"CI mode: Simulating playing sound {sound_path}")
         return True
 
+    process = None
     try:
         if player == "paplay":
-            subprocess.Popen(
+            process = subprocess.Popen(
                 [player, sound_path],
                 stdout=subprocess.DEVNULL,
                 stderr=subprocess.DEVNULL,
             )
         elif player == "aplay":
-            subprocess.Popen(
+            process = subprocess.Popen(
                 [player, "-q", sound_path],
                 stdout=subprocess.DEVNULL,
                 stderr=subprocess.DEVNULL,
             )
         elif player == "mplayer":
-            subprocess.Popen(
+            process = subprocess.Popen(
                 [player, "-really-quiet", sound_path],
                 stdout=subprocess.DEVNULL,
                 stderr=subprocess.DEVNULL,
             )
         elif player == "play":
-            subprocess.Popen(
+            process = subprocess.Popen(
                 [player, "-q", sound_path],
                 stdout=subprocess.DEVNULL,
                 stderr=subprocess.DEVNULL,
             )
         elif player == "ci_test_player":
             # This is a placeholder for CI tests - the subprocess call will be mocked
-            subprocess.Popen(
+            process = subprocess.Popen(
                 ["ci_test_player", sound_path],
                 stdout=subprocess.DEVNULL,
                 stderr=subprocess.DEVNULL,
             )
+        if process is not None:
+            # Silences the microphone while the sound plays (see self_playback)
+            register_playback(process)
         return True
     except Exception as e:
         logger.error(f"Failed to play sound {sound_path}: {e}")
         return False
 
 
+def _play_effect(event: str, default_path: str, opt_in: bool = False) -> bool:
+    """
+    Play the sound for an event if sound effects are on; reads the config once.
+
+    Args:
+        event: "start", "stop", "utterance" or "error"
+        default_path: The built-in sound
+        opt_in: The event also needs its own switch, sound_effects.<event>, turned on
+    """
+    settings = _sound_effects_settings()
+    if not _is_sound_effects_enabled(settings):
+        return False
+    if opt_in and not settings.get(event, False):
+        return False
+    return _play_sound_file(_sound_for(event, default_path, settings))
+
+
 def play_start_sound():
-    return _play_sound_file(START_SOUND) if _is_sound_effects_enabled() else False
+    return _play_effect("start", START_SOUND)
 
 
 def play_stop_sound():
-    return _play_sound_file(STOP_SOUND) if _is_sound_effects_enabled() else False
+    return _play_effect("stop", STOP_SOUND)
+
+
+def play_utterance_sound():
+    """Play the short earcon for a finished utterance, if turned on (off by default)."""
+    return _play_effect("utterance", UTTERANCE_SOUND, opt_in=True)
 
 
 def play_error_sound():
-    return _play_sound_file(ERROR_SOUND) if _is_sound_effects_enabled() else False
+    return _play_effect("error", ERROR_SOUND)
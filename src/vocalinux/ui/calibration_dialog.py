@@ -0,0 +1,211 @@
+"""
+Microphone calibration dialog for Vocalinux.
+
+Guides the user through recording a few seconds of silence and a few of
+speech, then offers the VAD sensitivity that tells the two apart (see
+vad_calibration). Nothing recorded is kept or transcribed.
+"""
+
+import logging
+import time
+from typing import Callable, Optional
+
+import gi
+
+gi.require_version("Gtk", "3.0")
+from gi.repository import GLib, Gtk  # noqa: E402
+
+from ..speech_recognition.level_monitor import LevelMonitor  # noqa: E402
+from ..speech_recognition.vad_calibration import (  # noqa: E402
+    SILENCE_SECONDS,
+    SPEECH_SECONDS,
+    CalibrationResult,
+    calibrate_sensitivity,
+)
+
+logger = logging.getLogger(__name__)
+
+RESPONSE_START = 1
+
+# Read aloud during the speech part; long enough to fill it at a normal pace
+CALIBRATION_SENTENCE = (
+    "The quick brown fox jumps over the lazy dog. "
+    "I am setting up voice typing so it hears me clearly, even when I speak softly."
+)
+
+_TICK_MS = 100
+
+
+class CalibrationDialog(Gtk.Dialog):
+    """Dialog that measures the room and the user's voice to pick a VAD sensitivity."""
+
+    def __init__(
+        self,
+        parent: Optional[Gtk.Window],
+        on_apply: Callable[[int], None],
+        device_index: Optional[int] = None,
+        gain: float = 1.0,
+        monitor_source: Optional[str] = None,
+    ):
+        """
+        Initialize the dialog.
+
+        Args:
+            parent: Parent window
+            on_apply: Called with the recommended sensitivity when the user applies it
+            device_index: The input device to calibrate (None for the default)
+            gain: Software gain of that device, as while dictating
+            monitor_source: PulseAudio monitor source to read instead of a device
+        """
+        super().__init__(
+            title="Calibrate Microphone",
+            transient_for=parent,
+            flags=Gtk.DialogFlags.MODAL | Gtk.DialogFlags.DESTROY_WITH_PARENT,
+        )
+        self.set_default_size(460, -1)
+        self._on_apply = on_apply
+        self._monitor_kwargs = {
+            "device_index": device_index,
+            "gain": gain,
+            "monitor_source": monitor_source,
+        }
+        self._monitor: Optional[LevelMonitor] = None
+        self._levels: dict[str, list[float]] = {"silence": [], "speech": []}
+        self._phase: Optional[str] = None
+        self._phase_started = 0.0
+        self._timer_id: Optional[int] = None
+        self.result: Optional[CalibrationResult] = None
+
+        self.add_button("_Cancel", Gtk.ResponseType.CANCEL)
+        self.start_button = self.add_button("_Start", RESPONSE_START)
+        self.apply_button = self.add_button("_Apply", Gtk.ResponseType.APPLY)
+        self.apply_button.set_sensitive(False)
+        self.connect("response", self._on_response)
+
+        box = self.get_content_area()
+        box.set_spacing(12)
+        box.set_margin_start(24)
+        box.set_margin_end(24)
+        box.set_margin_top(20)
+        box.set_margin_bottom(16)
+
+        self.message_label = Gtk.Label(xalign=0)
+        self.message_label.set_line_wrap(True)
+        self.message_label.set_max_width_chars(52)
+        box.pack_start(self.message_label, False, False, 0)
+
+        self.level_bar = Gtk.LevelBar.new_for_interval(0, 100)
+        self.level_bar.set_size_request(-1, 12)
+        box.pack_start(self.level_bar, False, False, 0)
+
+        self.progress_bar = Gtk.ProgressBar()
+        box.pack_start(self.progress_bar, False, False, 0)
+
+        self._show_message(
+            "Calibration measures your room's background noise and your voice, then "
+            "picks the VAD sensitivity that tells them apart. It takes about ten "
+            "seconds. Click Start and stay quiet until asked to speak."
+        )
+        self.show_all()
+
+    def _show_message(self, text: str):
+        self.message_label.set_text(text)
+
+    def _start(self):
+        """Record the silence, then the speech."""
+        self._levels = {"silence": [], "speech": []}
+        self.result = None
+        self.start_button.set_sensitive(False)
+        self.apply_button.set_sensitive(False)
+        self._enter_phase("silence")
+        self._show_message("Stay quiet while the background noise is measured...")
+        self._monitor = LevelMonitor(self._on_level, on_dbfs=self._on_dbfs, **self._monitor_kwargs)
+        self._monitor.start()
+        self._timer_id = GLib.timeout_add(_TICK_MS, self._tick)
+
+    def _enter_phase(self, phase: Optional[str]):
+        self._phase = phase
+        self._phase_started = time.monotonic()
+        self.progress_bar.set_fraction(0.0)
+
+    def _on_dbfs(self, dbfs: float):
+        # Called from the monitor thread; appending to a list is atomic
+        phase = self._phase
+        if phase is not None:
+            self._levels[phase].append(dbfs)
+
+    def _on_level(self, level: float):
+        GLib.idle_add(self._show_level, level)
+
+    def _show_level(self, level: float) -> bool:
+        # The monitor reports a last 0 while stopping, possibly after the dialog closed
+        if self._monitor is not None:
+            self.level_bar.set_value(level)
+        return False
+
+    def _tick(self) -> bool:
+        duration = SILENCE_SECONDS if self._phase == "silence" else SPEECH_SECONDS
+        elapsed = time.monotonic() - self._phase_started
+        self.progress_bar.set_fraction(min(1.0, elapsed / duration))
+        if elapsed < duration:
+            return True
+        if self._phase == "silence":
+            self._enter_phase("speech")
+            self._show_message(
+                f"Now read this aloud at your normal volume:\n\n“{CALIBRATION_SENTENCE}”"
+            )
+            return True
+        self._timer_id = None
+        self._finish()
+        return False
+
+    def _stop_monitor(self):
+        self._phase = None
+        if self._timer_id is not None:
+            GLib.source_remove(self._timer_id)
+            self._timer_id = None
+        if self._monitor is not None:
+            self._monitor.stop()
+            self._monitor = None
+
+    def _finish(self):
+        """Work out the sensitivity from what was recorded."""
+        self._stop_monitor()
+        self.level_bar.set_value(0)
+        self.result = calibrate_sensitivity(self._levels["silence"], self._levels["speech"])
+        self.start_button.set_label("_Try Again")
+        self.start_button.set_sensitive(True)
+        if not self._levels["silence"] or not self._levels["speech"]:
+            self._show_message(
+                "Nothing was recorded. Check the microphone on the Audio page and try again."
+            )
+            return
+        measured = (
+            f"Background noise: {self.result.noise_dbfs:.0f} dBFS\n"
+            f"Your speech: {self.result.speech_dbfs:.0f} dBFS\n\n"
+        )
+        if not self.result.ok:
+            self._show_message(
+                measured + "Your voice is hardly louder than the background noise, so no "
+                "sensitivity can tell them apart. Move closer to the microphone, raise "
+                "its Input Gain on the Audio page, or reduce the noise, then try again."
+            )
+            return
+        logger.info(
+            f"Calibrated VAD sensitivity {self.result.sensitivity} "
+            f"(noise {self.result.noise_dbfs:.1f} dBFS, speech {self.result.speech_dbfs:.1f} dBFS)"
+        )
+        self._show_message(
+            measured + f"Recommended VAD sensitivity: {self.result.sensitivity}\n\n"
+            "Click Apply to use it."
+        )
+        self.apply_button.set_sensitive(True)
+
+    def _on_response(self, dialog, response):
+        if response == RESPONSE_START:
+            self._start()
+            return
+        self._stop_monitor()
+        if response == Gtk.ResponseType.APPLY and self.result and self.result.ok:
+            self._on_apply(self.result.sensitivity)
+        self.destroy()
@@ -0,0 +1,60 @@
+"""
+On-screen recording indicator for Vocalinux.
+
+An optional red dot in the top-right corner of the screen, shown while
+Vocalinux records (ui.capture_overlay), so it is obvious that dictation is
+on even with the tray icon hidden or out of sight. It never takes the
+keyboard focus and is drawn round where a compositor supports transparent
+windows. On Wayland the compositor decides where windows go, so the dot may
+appear elsewhere.
+"""
+
+import math
+
+import gi
+
+gi.require_version("Gdk", "3.0")
+gi.require_version("Gtk", "3.0")
+from gi.repository import Gdk, Gtk  # noqa: E402
+
+DOT_SIZE = 16
+SCREEN_MARGIN = 12
+
+
+class CaptureOverlay(Gtk.Window):
+    """Borderless always-on-top window holding a red dot."""
+
+    def __init__(self):
+        super().__init__(title="Vocalinux Recording")
+        self.set_decorated(False)
+        self.set_resizable(False)
+        self.set_keep_above(True)
+        self.set_skip_taskbar_hint(True)
+        self.set_skip_pager_hint(True)
+        self.set_accept_focus(False)
+        self.set_focus_on_map(False)
+        self.set_type_hint(Gdk.WindowTypeHint.NOTIFICATION)
+        self.set_default_size(DOT_SIZE, DOT_SIZE)
+        self.set_tooltip_text("Vocalinux is recording")
+
+        # Transparent corners around the dot when a compositor is running
+        visual = self.get_screen().get_rgba_visual()
+        if visual is not None and self.get_screen().is_composited():
+            self.set_visual(visual)
+            self.set_app_paintable(True)
+        self.connect("draw", self._on_draw)
+
+    def _on_draw(self, widget, cr) -> bool:
+        cr.set_source_rgba(0.86, 0.11, 0.11, 1.0)
+        cr.arc(DOT_SIZE / 2, DOT_SIZE / 2, DOT_SIZE / 2, 0, 2 * math.pi)
+        cr.fill()
+        return True
+
+    def show_dot(self) -> None:
+        """Show the dot in the top-right corner of the primary monitor."""
+        display = Gdk.Display.get_default()
+        monitor = display.get_primary_monitor() or display.get_monitor(0)
+        if monitor is not None:
+            area = monitor.get_workarea()
+            self.move(area.x + area.width - DOT_SIZE - SCREEN_MARGIN, area.y + SCREEN_MARGIN)
+        self.show_all()
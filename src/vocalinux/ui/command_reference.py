@@ -0,0 +1,194 @@
+"""
+Voice command reference for Vocalinux.
+
+Builds a cheat sheet of every command the CommandProcessor recognises, with
+search filtering and a printable HTML export.
+"""
+
+import html
+import logging
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Optional
+
+from ..speech_recognition.command_processor import CommandProcessor
+
+logger = logging.getLogger(__name__)
+
+# Section titles, in display order
+CATEGORY_TITLES = {
+    "text": "Text Commands",
+    "action": "Action Commands",
+    "format": "Formatting Commands",
+    "mode": "Field Modes",
+}
+
+# Human-readable descriptions for action and format commands.
+# Unknown actions fall back to their name with underscores replaced.
+ACTION_DESCRIPTIONS = {
+    "delete_last": "Delete the last dictated text",
+    "undo": "Undo",
+    "redo": "Redo",
+    "select_all": "Select all",
+    "select_line": "Select the current line",
+    "select_word": "Select the next word",
+    "select_paragraph": "Select the next paragraph",
+    "cut": "Cut the selection",
+    "copy": "Copy the selection",
+    "paste": "Paste",
+    "read_back": "Read the last dictated text aloud",
+    "increase_sensitivity": "Raise VAD sensitivity by one step",
+    "decrease_sensitivity": "Lower VAD sensitivity by one step",
+    "increase_silence_timeout": "Lengthen the silence timeout",
+    "decrease_silence_timeout": "Shorten the silence timeout",
+    "enable_translation": "Type English text whatever language is spoken",
+    "disable_translation": "Type text in the spoken language again",
+    "capitalize_next": "Capitalize the next word",
+    "uppercase_next": "Uppercase the next word",
+    "lowercase_next": "Lowercase the next word",
+    "no_spaces_next": "Remove spaces from the next words",
+    "numbers_mode": "Type only digits until normal mode",
+    "letters_mode": "Type only spelled-out letters until normal mode",
+    "normal_mode": "Return to normal dictation",
+}
+
+# Whitespace inserted by text commands, which would be invisible as-is
+WHITESPACE_DESCRIPTIONS = {
+    "\n": "Line break",
+    "\n\n": "Paragraph break",
+}
+
+
+@dataclass(frozen=True)
+class CommandEntry:
+    """A single spoken command and what it does."""
+
+    category: str
+    phrase: str
+    description: str
+
+    @property
+    def category_title(self) -> str:
+        return CATEGORY_TITLES.get(self.category, self.category.title())
+
+
+def _describe(category: str, value: str) -> str:
+    if category == "text":
+        if value in WHITESPACE_DESCRIPTIONS:
+            return WHITESPACE_DESCRIPTIONS[value]
+        return f"Types {value}"
+
+    if value in ACTION_DESCRIPTIONS:
+        return ACTION_DESCRIPTIONS[value]
+    if value.startswith("switch_model_"):
+        return f"Switch to the {value.rsplit('_', 1)[-1]} model"
+    return value.replace("_", " ").capitalize()
+
+
+def build_command_reference(processor: Optional[CommandProcessor] = None) -> list[CommandEntry]:
+    """
+    Build the command reference by introspecting a CommandProcessor.
+
+    Args:
+        processor: The processor in use (a default one is created if None)
+
+    Returns:
+        Entries grouped by category, sorted by phrase within each group
+    """
+    if processor is None:
+        processor = CommandProcessor()
+
+    entries = [
+        CommandEntry(category, phrase, _describe(category, value))
+        for category, phrase, value in processor.get_command_reference()
+    ]
+    order = list(CATEGORY_TITLES)
+    entries.sort(
+        key=lambda e: (order.index(e.category) if e.category in order else len(order), e.phrase)
+    )
+    return entries
+
+
+def filter_commands(entries: list[CommandEntry], query: str) -> list[CommandEntry]:
+    """
+    Filter entries whose phrase, description or category matches a query.
+
+    Args:
+        entries: Entries to search
+        query: Case-insensitive search text (empty matches everything)
+    """
+    query = query.strip().lower()
+    if not query:
+        return list(entries)
+    return [
+        e
+        for e in entries
+        if query in e.phrase.lower()
+        or query in e.description.lower()
+        or query in e.category_title.lower()
+    ]
+
+
+def render_html(entries: list[CommandEntry]) -> str:
+    """Render entries as a standalone, print-friendly HTML page."""
+    sections = []
+    for category, title in CATEGORY_TITLES.items():
+        rows = [
+            f"<tr><td>&ldquo;{html.escape(e.phrase)}&rdquo;</td>"
+            f"<td>{html.escape(e.description)}</td></tr>"
+            for e in entries
+            if e.category == category
+        ]
+        if not rows:
+            continue
+        sections.append(
+            f"<h2>{html.escape(title)}</h2>\n"
+            "<table>\n<tr><th>Say</th><th>Result</th></tr>\n" + "\n".join(rows) + "\n</table>"
+        )
+
+    generated = datetime.now().strftime("%Y-%m-%d %H:%M")
+    body = "\n".join(sections) if sections else "<p>No commands available.</p>"
+    return f"""<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Vocalinux Voice Commands</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; color: #222; }}
+h1 {{ font-size: 1.6em; }}
+h2 {{ font-size: 1.2em; margin-top: 1.5em; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ border: 1px solid #ccc; padding: 4px 8px; text-align: left; }}
+th {{ background: #f0f0f0; }}
+.generated {{ color: #777; font-size: 0.85em; }}
+@media print {{
+  body {{ margin: 0; }}
+  h2 {{ page-break-after: avoid; }}
+  tr {{ page-break-inside: avoid; }}
+}}
+</style>
+</head>
+<body>
+<h1>Vocalinux Voice Commands</h1>
+<p class="generated">Generated {generated}</p>
+{body}
+</body>
+</html>
+"""
+
+
+def export_html(filepath: str, entries: list[CommandEntry]) -> bool:
+    """
+    Write the HTML cheat sheet to a file.
+
+    Returns:
+        True on success, False otherwise
+    """
+    try:
+        with open(filepath, "w", encoding="utf-8") as f:
+            f.write(render_html(entries))
+        logger.info(f"Exported command reference to {filepath}")
+        return True
+    except OSError as e:
+        logger.error(f"Failed to export command reference: {e}")
+        return False
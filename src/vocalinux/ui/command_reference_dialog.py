@@ -0,0 +1,129 @@
+"""
+Voice command cheat sheet dialog for Vocalinux.
+
+Shows a searchable list of every command the speech engine currently
+recognises, and can export it as a printable HTML page.
+"""
+
+import logging
+from typing import Optional
+
+import gi
+
+gi.require_version("Gtk", "3.0")
+from gi.repository import Gtk  # noqa: E402
+
+from .command_reference import build_command_reference, export_html, filter_commands  # noqa: E402
+
+logger = logging.getLogger(__name__)
+
+
+class CommandReferenceDialog(Gtk.Dialog):
+    """GTK dialog listing the available voice commands."""
+
+    def __init__(self, parent: Optional[Gtk.Window] = None, command_processor=None):
+        """
+        Initialize the dialog.
+
+        Args:
+            parent: Parent window
+            command_processor: The CommandProcessor in use, so the list
+                reflects the commands that are actually active
+        """
+        super().__init__(
+            title="Voice Commands",
+            transient_for=parent,
+            flags=Gtk.DialogFlags.DESTROY_WITH_PARENT,
+            modal=False,
+        )
+
+        self.entries = build_command_reference(command_processor)
+
+        self.set_default_size(560, 600)
+        self.add_button("_Close", Gtk.ResponseType.CLOSE)
+
+        self._create_ui()
+        self._refresh_list()
+
+        self.connect("response", lambda dialog, response: dialog.destroy())
+
+    def _create_ui(self):
+        """Create the user interface."""
+        content_area = self.get_content_area()
+        content_area.set_margin_top(16)
+        content_area.set_margin_bottom(8)
+        content_area.set_margin_start(16)
+        content_area.set_margin_end(16)
+
+        main_box = Gtk.Box(orientation=Gtk.Orientation.VERTICAL, spacing=8)
+        content_area.add(main_box)
+
+        # Search row
+        search_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=8)
+        self.search_entry = Gtk.SearchEntry()
+        self.search_entry.set_placeholder_text("Search commands...")
+        self.search_entry.connect("search-changed", lambda w: self._refresh_list())
+        search_box.pack_start(self.search_entry, True, True, 0)
+
+        export_button = Gtk.Button(label="Export HTML")
+        export_button.set_tooltip_text("Save a printable HTML cheat sheet")
+        export_button.connect("clicked", lambda w: self._export())
+        search_box.pack_start(export_button, False, False, 0)
+        main_box.pack_start(search_box, False, False, 0)
+
+        # Command list: category, phrase, description
+        self.store = Gtk.ListStore(str, str, str)
+        tree_view = Gtk.TreeView(model=self.store)
+        for index, title in enumerate(("Type", "Say", "Result")):
+            column = Gtk.TreeViewColumn(title, Gtk.CellRendererText(), text=index)
+            column.set_resizable(True)
+            tree_view.append_column(column)
+
+        scrolled = Gtk.ScrolledWindow()
+        scrolled.set_policy(Gtk.PolicyType.AUTOMATIC, Gtk.PolicyType.AUTOMATIC)
+        scrolled.set_vexpand(True)
+        scrolled.add(tree_view)
+        main_box.pack_start(scrolled, True, True, 0)
+
+        self.count_label = Gtk.Label(xalign=0)
+        main_box.pack_start(self.count_label, False, False, 0)
+
+        main_box.show_all()
+
+    def _refresh_list(self):
+        """Repopulate the list from the current search text."""
+        matches = filter_commands(self.entries, self.search_entry.get_text())
+        self.store.clear()
+        for entry in matches:
+            self.store.append([entry.category_title, f'"{entry.phrase}"', entry.description])
+        self.count_label.set_text(f"{len(matches)} of {len(self.entries)} commands")
+
+    def _export(self):
+        """Export the full command list as HTML."""
+        file_dialog = Gtk.FileChooserDialog(
+            title="Export Voice Commands", parent=self, action=Gtk.FileChooserAction.SAVE
+        )
+        file_dialog.add_buttons("_Cancel", Gtk.ResponseType.CANCEL, "_Save", Gtk.ResponseType.OK)
+        file_dialog.set_do_overwrite_confirmation(True)
+        file_dialog.set_current_name("vocalinux_commands.html")
+
+        file_filter = Gtk.FileFilter()
+        file_filter.set_name("HTML files")
+        file_filter.add_pattern("*.html")
+        file_dialog.add_filter(file_filter)
+
+        if file_dialog.run() == Gtk.ResponseType.OK:
+            filepath = file_dialog.get_filename()
+            if not export_html(filepath, self.entries):
+                message = Gtk.MessageDialog(
+                    transient_for=self,
+                    modal=True,
+                    message_type=Gtk.MessageType.ERROR,
+                    buttons=Gtk.ButtonsType.OK,
+                    text="Export failed",
+                )
+                message.format_secondary_text("Check the logs for details.")
+                message.run()
+                message.destroy()
+
+        file_dialog.destroy()
@@ -10,13 +10,20 @@ import logging
 import os
 from typing import Any, Optional
 
+from ..utils import toml_format
 from ..utils.paths import config_dir
+from .config_validation import ConfigProblem, validate_config
 
 logger = logging.getLogger(__name__)
 
 # Define constants
 CONFIG_DIR = config_dir()
-CONFIG_FILE = os.path.join(CONFIG_DIR, "config.json")
+CONFIG_FILE = os.path.join(CONFIG_DIR, "config.toml")
+LEGACY_CONFIG_FILE = os.path.join(CONFIG_DIR, "config.json")  # Read once and converted
+
+CONFIG_FILE_HEADER = """Vocalinux configuration
+Most settings are easier to change in the Settings dialog. Settings shown as
+"(not set)" use their automatic default."""
 
 # Default configuration
 DEFAULT_CONFIG = {
@@ -28,24 +35,78 @@ DEFAULT_CONFIG = {
         "whisper_model_size": "tiny",  # Default model for Whisper engine
         "whisper_cpp_model_size": "tiny",  # Default model for whisper.cpp engine
         "vad_sensitivity": 3,  # Voice Activity Detection sensitivity (1-5)
+        "vad_backend": "auto",  # auto (Silero if installed, else energy), energy, webrtc or silero
         "silence_timeout": 2.0,  # Seconds of silence before stopping
+        # VAD settings per engine, overriding the two above, e.g.
+        # "whisper_cpp" = {silence_timeout = 3.0}, "vosk" = {vad_sensitivity = 4}
+        "engine_vad": {},
+        "adaptive_silence_timeout": False,  # Lengthen/shorten the timeout to fit your pauses
+        "max_utterance_seconds": 30,  # Transcribe non-stop speech in parts this long (0 = off)
+        "speech_pad_before_ms": 200,  # Audio kept before detected speech, for soft onsets
+        "speech_pad_after_ms": 200,  # Audio kept after speech ends, for trailing syllables
         "stop_sound_guard_ms": 200,  # Small tail trim to avoid the stop sound without clipping speech
         "voice_commands_enabled": None,  # None = auto (enabled for VOSK, disabled for Whisper)
+        "disabled_commands": [],  # Built-in command phrases typed as words, e.g. "period"
+        "renamed_commands": {},  # Built-in phrase = phrase said instead, e.g. "period" = "stop"
+        "punctuation": None,  # "off", "commands" or "auto"; None = engine default
+        "utterance_join_window": 0.0,  # Seconds to wait for a sentence to continue (0 = off)
+        "vosk_speaker_profile": False,  # VOSK: skip utterances not in your enrolled voice
+        "long_form_mode": False,  # Whisper only: overlapping 30 s windows, no pause detection
+        "partial_results": False,  # Type while speaking and correct when the final text arrives
+        "translate": False,  # Whisper engines: type English text whatever language is spoken
+        "tag_language": False,  # Auto-detect: prefix typed text with the language, e.g. "[de] "
+        "prompt_presets": {},  # Whisper: named prompts, added to the built-in "shell" and "email"
+        "app_prompts": {},  # Focused window class = prompt preset, e.g. "thunderbird" = "email"
+        "comparison_engine": None,  # Debug: also decode with this engine, show both transcripts
+        "rewrite_engine": None,  # Type a draft, then correct it with this engine (None = off)
+        "rewrite_model_size": "small",  # Model the rewrite engine decodes with
+        "retry_model_size": "large",  # Model a recent transcript is retried with from the tray
+        "offline_fallback_engine": None,  # Remote API: decode locally when the server is down
+        "offline_fallback_model_size": "tiny",  # Model the offline fallback decodes with
+        "offline_fallback_standby": False,  # Keep the fallback loaded so it takes over at once
+        "gpu_memory_reserve_mb": 0,  # VRAM Whisper engines leave free, smaller model if needed
+        "intent_commands_enabled": False,  # "open <app>" / "search for <query>" run, not typed
+        "intent_search_url": "https://duckduckgo.com/?q={query}",  # Web search for intents
         "remote_api_url": "",  # Remote speech recognition server URL (e.g. http://192.168.1.100:8080)
-        "remote_api_key": "",  # Remote server API key (optional)
+        "remote_api_key": "",  # Remote server API key, used only when no keyring is available
+        "remote_api_account": "default",  # Account whose key is used (keyring: <account>/...)
+        "remote_api_accounts": ["default"],  # Named accounts, e.g. separate work and personal keys
         "remote_api_endpoint": "/inference",  # Remote server API endpoint format
         "remote_api_model": "whisper-1",  # Model name sent to compatible remote APIs
     },
     "audio": {
         "device_index": None,  # Audio input device index (None for system default)
-        "device_name": None,  # Saved device name for display/reference
+        "device_name": None,  # Device name, "<sink>.monitor" (system audio) or "app:<name>"
+        "sample_rate": None,  # Capture rate override in Hz (None = automatic); resampled to 16kHz
+        "sidetone_enabled": False,  # Play the microphone back at low volume while dictating
+        "sidetone_volume": 0.3,  # Sidetone playback gain (0.0-1.0)
+        "duck_media": False,  # Lower other apps' playback volume while dictating
+        "duck_level": 0.3,  # Fraction of its volume media keeps while ducked (0.0-1.0)
+        "echo_cancel": False,  # Remove speaker playback from the default mic while dictating
+        "mute_own_sounds": True,  # Record silence while our sounds or read-back play
+        "noise_suppression": False,  # Denoise the microphone with RNNoise (needs librnnoise)
+        # Software gain per input, keyed by device_name ("default" for the system
+        # default), e.g. "USB Webcam: Audio (hw:2,0)" = 3.0
+        "device_gain": {},
+        "extra_device_names": [],  # More microphones mixed into the selected one, by device name
+        "buffer_frames": 1024,  # Frames read per chunk (256-8192); larger adds latency
+        "queue_depth": 32,  # Segments waiting for transcription before dropping (4-256)
     },
     "sound_effects": {
         "enabled": True,  # Play sounds for recording start/stop/error
+        "utterance": False,  # Also play a short earcon each time an utterance is finished
+        # Your own WAV files instead of the built-in sounds ("" = built-in)
+        "start_sound": "",
+        "stop_sound": "",
+        "utterance_sound": "",
+        "error_sound": "",
     },
     "shortcuts": {
         "toggle_recognition": "ctrl+ctrl",  # Double-tap modifier key
         "mode": "toggle",  # "toggle" or "push_to_talk"
+        "tap_count": 2,  # Taps of the modifier that toggle recognition (2-4)
+        "tap_threshold_ms": 300,  # Max gap between taps in milliseconds (100-1000)
+        "panic": "",  # Modifier+key combo that stops and discards everything ("" = off)
         # Pure-modifier gestures: "ctrl+ctrl", "alt+alt", "shift+shift" (and
         # left_/right_ variants) — double-tap (toggle) or hold (push_to_talk).
         # Modifier+key combos are also supported, e.g. "alt+r", "ctrl+alt+r",
@@ -54,13 +115,61 @@ DEFAULT_CONFIG = {
     "ui": {
         "start_minimized": False,
         "show_notifications": True,
+        "transcript_history_size": 10,  # Recent transcripts in the tray menu (0 = hide the menu)
+        "capture_overlay": False,  # Red dot in the corner of the screen while recording
     },
     "general": {
         "autostart": False,
         "first_run": True,
+        "word_target": 0,  # Words to dictate per session before a congratulation (0 = none)
+        "utterance_log": "off",  # JSON line per utterance in the data dir: off, hashed or full
+        "retry_audio": False,  # Keep recent utterances' audio in memory to retry them
+        "timed_session_minutes": 30,  # Length of a timed session started from the tray menu
     },
     "text_injection": {
         "copy_to_clipboard": False,  # Disabled by default; users can enable in Settings
+        "stop_on_focus_change": False,  # Stop dictation when focus leaves the starting window
+    },
+    "output": {
+        "profile": "default",  # Output profile used for results (--output-profile overrides)
+        "profiles": {
+            "default": {
+                "sinks": ["inject"],  # Any of "inject", "clipboard", "file", "socket"
+                "file_path": "",  # Appended to by "file"; strftime codes work, e.g. %Y-%m-%d
+                "socket_address": "",  # "host:port" or Unix socket path; one JSON line per result
+                "translate": None,  # Overrides speech_recognition.translate when True/False
+                # Text post-processing stages in order; leave one out to skip it
+                "pipeline": [
+                    "commands",
+                    "intents",
+                    "punctuation",
+                    "numbers",
+                    "profanity",
+                    "capitalization",
+                ],
+            },
+        },
+    },
+    "vocabulary": {
+        "packs": [],  # Installed domain vocabulary packs, e.g. ["medical", "legal"]
+        "custom_terms": [],  # Extra words/names Whisper should spell correctly
+    },
+    "downloads": {
+        "mirror": "official",  # Hugging Face model downloads: "official" or "hf-mirror"
+        "vosk_mirror_url": "",  # Base URL serving the VOSK model zips ("" = alphacephei.com)
+        "bandwidth_limit_kbps": 0,  # Maximum model download speed in KB/s (0 = unlimited)
+    },
+    "formatting": {
+        "ordinals": False,  # "third" -> "3rd"
+        "fractions": False,  # "three quarters" -> "3/4"
+        "roman_numerals": False,  # "roman numeral four" -> "IV"
+        "profanity_filter": "off",  # "off", "mask" (f***) or "remove"
+        "profanity_words": [],  # Extra words to filter on top of the built-in lists
+        "preserved_terms": [],  # Always typed with this capitalization, e.g. ["API", "GmbH"]
+    },
+    "text_to_speech": {
+        "readback_enabled": False,  # Allow "read that back" to speak the last dictated text
+        "piper_model": "",  # Path to a Piper .onnx voice; espeak-ng is used when empty
     },
     "advanced": {
         "power_user_mode": False,
@@ -75,10 +184,218 @@ DEFAULT_CONFIG = {
         "whispercpp_logprob_thold": -1.0,
         "whispercpp_no_speech_thold": 0.6,
         "whispercpp_n_threads": 0,  # 0 = auto-detect optimal thread count; set to override
+        "dump_audio_dir": "",  # Debug: write each utterance to a WAV here first ("" = off)
     },
 }
 
 
+# Comments written after each setting in config.toml, keyed by setting path;
+# keep in step with DEFAULT_CONFIG when adding a setting
+DEFAULT_CONFIG_COMMENTS = {
+    ("speech_recognition", "engine"): (
+        '"vosk", "whisper", or "whisper_cpp" - whisper_cpp is default for best performance'
+    ),
+    ("speech_recognition", "language"): "Auto-detect language (Whisper/whisper.cpp only)",
+    ("speech_recognition", "model_size"): "Current model size (for backward compatibility)",
+    ("speech_recognition", "vosk_model_size"): "Default model for VOSK engine",
+    ("speech_recognition", "whisper_model_size"): "Default model for Whisper engine",
+    ("speech_recognition", "whisper_cpp_model_size"): "Default model for whisper.cpp engine",
+    ("speech_recognition", "vad_sensitivity"): "Voice Activity Detection sensitivity (1-5)",
+    ("speech_recognition", "vad_backend"): (
+        "auto (Silero if installed, else energy), energy, webrtc or silero"
+    ),
+    ("speech_recognition", "silence_timeout"): "Seconds of silence before stopping",
+    ("speech_recognition", "adaptive_silence_timeout"): (
+        "Lengthen/shorten the timeout to fit your pauses"
+    ),
+    ("speech_recognition", "max_utterance_seconds"): (
+        "Transcribe non-stop speech in parts this long (0 = off)"
+    ),
+    ("speech_recognition", "speech_pad_before_ms"): (
+        "Audio kept before detected speech, for soft onsets"
+    ),
+    ("speech_recognition", "speech_pad_after_ms"): (
+        "Audio kept after speech ends, for trailing syllables"
+    ),
+    ("speech_recognition", "stop_sound_guard_ms"): (
+        "Small tail trim to avoid the stop sound without clipping speech"
+    ),
+    ("speech_recognition", "voice_commands_enabled"): (
+        "None = auto (enabled for VOSK, disabled for Whisper)"
+    ),
+    ("speech_recognition", "disabled_commands"): (
+        'Built-in command phrases typed as words, e.g. "period"'
+    ),
+    ("speech_recognition", "punctuation"): '"off", "commands" or "auto"; None = engine default',
+    ("speech_recognition", "utterance_join_window"): (
+        "Seconds to wait for a sentence to continue (0 = off)"
+    ),
+    ("speech_recognition", "vosk_speaker_profile"): (
+        "VOSK: skip utterances not in your enrolled voice"
+    ),
+    ("speech_recognition", "long_form_mode"): (
+        "Whisper only: overlapping 30 s windows, no pause detection"
+    ),
+    ("speech_recognition", "partial_results"): (
+        "Type while speaking and correct when the final text arrives"
+    ),
+    ("speech_recognition", "translate"): (
+        "Whisper engines: type English text whatever language is spoken"
+    ),
+    ("speech_recognition", "tag_language"): (
+        'Auto-detect: prefix typed text with the language, e.g. "[de] "'
+    ),
+    ("speech_recognition", "comparison_engine"): (
+        "Debug: also decode with this engine, show both transcripts"
+    ),
+    ("speech_recognition", "rewrite_engine"): (
+        "Type a draft, then correct it with this engine (None = off)"
+    ),
+    ("speech_recognition", "rewrite_model_size"): "Model the rewrite engine decodes with",
+    ("speech_recognition", "retry_model_size"): (
+        "Model a recent transcript is retried with from the tray"
+    ),
+    ("speech_recognition", "offline_fallback_engine"): (
+        "Remote API: decode locally when the server is down"
+    ),
+    ("speech_recognition", "offline_fallback_model_size"): (
+        "Model the offline fallback decodes with"
+    ),
+    ("speech_recognition", "offline_fallback_standby"): (
+        "Keep the fallback loaded so it takes over at once"
+    ),
+    ("speech_recognition", "gpu_memory_reserve_mb"): (
+        "VRAM Whisper engines leave free, smaller model if needed"
+    ),
+    ("speech_recognition", "intent_commands_enabled"): (
+        '"open <app>" / "search for <query>" run, not typed'
+    ),
+    ("speech_recognition", "intent_search_url"): "Web search for intents",
+    ("speech_recognition", "remote_api_url"): (
+        "Remote speech recognition server URL (e.g. http://192.168.1.100:8080)"
+    ),
+    ("speech_recognition", "remote_api_key"): (
+        "Remote server API key, used only when no keyring is available"
+    ),
+    ("speech_recognition", "remote_api_account"): (
+        "Account whose key is used (keyring: <account>/...)"
+    ),
+    ("speech_recognition", "remote_api_accounts"): (
+        "Named accounts, e.g. separate work and personal keys"
+    ),
+    ("speech_recognition", "remote_api_endpoint"): "Remote server API endpoint format",
+    ("speech_recognition", "remote_api_model"): "Model name sent to compatible remote APIs",
+    ("audio", "device_index"): "Audio input device index (None for system default)",
+    ("audio", "device_name"): 'Device name, "<sink>.monitor" (system audio) or "app:<name>"',
+    ("audio", "sample_rate"): "Capture rate override in Hz (None = automatic); resampled to 16kHz",
+    ("audio", "sidetone_enabled"): "Play the microphone back at low volume while dictating",
+    ("audio", "sidetone_volume"): "Sidetone playback gain (0.0-1.0)",
+    ("audio", "duck_media"): "Lower other apps' playback volume while dictating",
+    ("audio", "duck_level"): "Fraction of its volume media keeps while ducked (0.0-1.0)",
+    ("audio", "echo_cancel"): "Remove speaker playback from the default mic while dictating",
+    ("audio", "mute_own_sounds"): "Record silence while our sounds or read-back play",
+    ("audio", "noise_suppression"): "Denoise the microphone with RNNoise (needs librnnoise)",
+    ("audio", "extra_device_names"): "More microphones mixed into the selected one, by device name",
+    ("audio", "buffer_frames"): "Frames read per chunk (256-8192); larger adds latency",
+    ("audio", "queue_depth"): "Segments waiting for transcription before dropping (4-256)",
+    ("sound_effects", "enabled"): "Play sounds for recording start/stop/error",
+    ("sound_effects", "utterance"): "Also play a short earcon each time an utterance is finished",
+    ("sound_effects", "start_sound"): (
+        'Your own WAV file instead of the built-in sound ("" = built-in)'
+    ),
+    ("sound_effects", "stop_sound"): (
+        'Your own WAV file instead of the built-in sound ("" = built-in)'
+    ),
+    ("sound_effects", "utterance_sound"): (
+        'Your own WAV file instead of the built-in sound ("" = built-in)'
+    ),
+    ("sound_effects", "error_sound"): (
+        'Your own WAV file instead of the built-in sound ("" = built-in)'
+    ),
+    ("shortcuts", "toggle_recognition"): "Double-tap modifier key",
+    ("shortcuts", "mode"): '"toggle" or "push_to_talk"',
+    ("shortcuts", "tap_count"): "Taps of the modifier that toggle recognition (2-4)",
+    ("shortcuts", "tap_threshold_ms"): "Max gap between taps in milliseconds (100-1000)",
+    ("shortcuts", "panic"): 'Modifier+key combo that stops and discards everything ("" = off)',
+    ("ui", "transcript_history_size"): "Recent transcripts in the tray menu (0 = hide the menu)",
+    ("ui", "capture_overlay"): "Red dot in the corner of the screen while recording",
+    ("general", "word_target"): "Words to dictate per session before a congratulation (0 = none)",
+    ("general", "utterance_log"): "JSON line per utterance in the data dir: off, hashed or full",
+    ("general", "retry_audio"): "Keep recent utterances' audio in memory to retry them",
+    ("general", "timed_session_minutes"): "Length of a timed session started from the tray menu",
+    ("text_injection", "copy_to_clipboard"): "Disabled by default; users can enable in Settings",
+    ("text_injection", "stop_on_focus_change"): (
+        "Stop dictation when focus leaves the starting window"
+    ),
+    ("output", "profile"): "Output profile used for results (--output-profile overrides)",
+    ("output", "profiles", "default", "sinks"): 'Any of "inject", "clipboard", "file", "socket"',
+    ("output", "profiles", "default", "file_path"): (
+        'Appended to by "file"; strftime codes work, e.g. %Y-%m-%d'
+    ),
+    ("output", "profiles", "default", "socket_address"): (
+        '"host:port" or Unix socket path; one JSON line per result'
+    ),
+    ("output", "profiles", "default", "translate"): (
+        "Overrides speech_recognition.translate when True/False"
+    ),
+    ("output", "profiles", "default", "pipeline"): (
+        "Text post-processing stages in order; leave one out to skip it"
+    ),
+    ("vocabulary", "packs"): 'Installed domain vocabulary packs, e.g. ["medical", "legal"]',
+    ("vocabulary", "custom_terms"): "Extra words/names Whisper should spell correctly",
+    ("downloads", "mirror"): 'Hugging Face model downloads: "official" or "hf-mirror"',
+    ("downloads", "vosk_mirror_url"): 'Base URL serving the VOSK model zips ("" = alphacephei.com)',
+    ("downloads", "bandwidth_limit_kbps"): "Maximum model download speed in KB/s (0 = unlimited)",
+    ("formatting", "ordinals"): '"third" -> "3rd"',
+    ("formatting", "fractions"): '"three quarters" -> "3/4"',
+    ("formatting", "roman_numerals"): '"roman numeral four" -> "IV"',
+    ("formatting", "profanity_filter"): '"off", "mask" (f***) or "remove"',
+    ("formatting", "profanity_words"): "Extra words to filter on top of the built-in lists",
+    ("formatting", "preserved_terms"): (
+        'Always typed with this capitalization, e.g. ["API", "GmbH"]'
+    ),
+    ("text_to_speech", "readback_enabled"): (
+        'Allow "read that back" to speak the last dictated text'
+    ),
+    ("text_to_speech", "piper_model"): "Path to a Piper .onnx voice; espeak-ng is used when empty",
+    ("advanced", "whispercpp_n_threads"): "0 = auto-detect optimal thread count; set to override",
+    ("advanced", "dump_audio_dir"): 'Debug: write each utterance to a WAV here first ("" = off)',
+}
+
+
+def _config_file_to_read() -> Optional[str]:
+    """The config file to load: config.toml, else a config.json from older versions."""
+    if toml_format.TOML_AVAILABLE and os.path.exists(CONFIG_FILE):
+        return CONFIG_FILE
+    if os.path.exists(LEGACY_CONFIG_FILE):
+        return LEGACY_CONFIG_FILE
+    return None
+
+
+def read_user_config(path: Optional[str] = None) -> dict:
+    """
+    Read the user's settings as saved, without defaults.
+
+    Args:
+        path: Config file to read (the current one if None); .toml files are
+            read as TOML, anything else as JSON
+
+    Returns:
+        The saved settings, or an empty dict if there is no config file
+
+    Raises:
+        OSError, json.JSONDecodeError, toml_format.TOMLDecodeError: If the
+            file can't be read
+    """
+    path = path or _config_file_to_read()
+    if path is None:
+        return {}
+    with open(path, "r", encoding="utf-8") as f:
+        if path.endswith(".toml"):
+            return toml_format.loads(f.read())
+        return json.load(f)
+
+
 class ConfigManager:
     """
     Manager for user configuration settings.
@@ -94,6 +411,8 @@ class ConfigManager:
     def __init__(self):
         """Initialize the configuration manager."""
         self.config = copy.deepcopy(DEFAULT_CONFIG)
+        # Invalid settings found in the config file, each reset to its default
+        self.config_problems: list[ConfigProblem] = []
         self._ensure_config_dir()
         self.load_config()
 
@@ -105,32 +424,54 @@ class ConfigManager:
         """
         Load configuration from the config file.
 
-        If the config file doesn't exist, the default configuration is used.
+        config.toml is preferred; a config.json from older versions is read
+        when there is none and converted to TOML once. If no config file
+        exists, the defaults are used and written out with comments.
         """
-        if not os.path.exists(CONFIG_FILE):
+        path = _config_file_to_read()
+        if path is None:
             logger.info(f"Config file not found at {CONFIG_FILE}. Using defaults.")
+            if toml_format.TOML_AVAILABLE:
+                self.save_config()
             return
 
         try:
-            with open(CONFIG_FILE, "r") as f:
-                user_config = json.load(f)
+            user_config = read_user_config(path)
 
             # Check if migration is needed BEFORE merging with defaults
             needs_migration = self._check_needs_migration(user_config)
 
             # Update the default config with user settings
             self._update_dict_recursive(self.config, user_config)
-            logger.info(f"Loaded configuration from {CONFIG_FILE}")
+            logger.info(f"Loaded configuration from {path}")
+
+            self.config_problems = validate_config(self.config, DEFAULT_CONFIG)
+            for problem in self.config_problems:
+                logger.warning(f"Invalid setting in {path}, {problem}")
 
             # Migrate old config format if needed
             if needs_migration:
                 self._migrate_config(user_config)
 
-            self._migrate_shortcuts_config()
+            self._migrate_shortcuts_config(user_config)
 
-        except (json.JSONDecodeError, OSError) as e:
+            if path == LEGACY_CONFIG_FILE and toml_format.TOML_AVAILABLE:
+                self._migrate_to_toml()
+
+        except (json.JSONDecodeError, toml_format.TOMLDecodeError, OSError) as e:
             logger.error(f"Failed to load config: {e}")
 
+    def _migrate_to_toml(self):
+        """Write config.toml from a loaded config.json and set the JSON file aside."""
+        if not self.save_config():
+            return
+        try:
+            os.replace(LEGACY_CONFIG_FILE, LEGACY_CONFIG_FILE + ".bak")
+        except OSError as e:
+            logger.warning(f"Could not rename {LEGACY_CONFIG_FILE}: {e}")
+            return
+        logger.info(f"Converted {LEGACY_CONFIG_FILE} to {CONFIG_FILE}")
+
     def _check_needs_migration(self, user_config: dict) -> bool:
         """Check if the user config needs migration to add per-engine model sizes."""
         sr_config = user_config.get("speech_recognition", {})
@@ -164,11 +505,20 @@ class ConfigManager:
         self.save_config()
         logger.info("Config migrated to new per-engine model format")
 
-    def _migrate_shortcuts_config(self):
+    def _migrate_shortcuts_config(self, user_config: dict):
+        """
+        Replace the super+super default of old versions with ctrl+ctrl.
+
+        Super wasn't selectable again until the tap settings were added, and every
+        config saved since then contains shortcuts.tap_count. So super+super in a
+        config without tap_count is the deprecated default, while one with it was
+        picked in the settings ("Super (either side)") and is kept.
+        """
         shortcuts_config = self.config.get("shortcuts", {})
         shortcut = shortcuts_config.get("toggle_recognition")
 
-        if shortcut == "super+super":
+        is_legacy = "tap_count" not in user_config.get("shortcuts", {})
+        if shortcut == "super+super" and is_legacy:
             shortcuts_config["toggle_recognition"] = "ctrl+ctrl"
             self.save_config()
             logger.info("Migrated deprecated super+super shortcut to ctrl+ctrl")
@@ -178,10 +528,17 @@ class ConfigManager:
         try:
             # Ensure directory exists before writing
             self._ensure_config_dir()
-            with open(CONFIG_FILE, "w") as f:
-                json.dump(self.config, f, indent=4)
+            if toml_format.TOML_AVAILABLE:
+                path = CONFIG_FILE
+                text = toml_format.dumps(self.config, DEFAULT_CONFIG_COMMENTS, CONFIG_FILE_HEADER)
+            else:
+                # Without a TOML reader the file couldn't be loaded again
+                path = LEGACY_CONFIG_FILE
+                text = json.dumps(self.config, indent=4)
+            with open(path, "w", encoding="utf-8") as f:
+                f.write(text)
 
-            logger.info(f"Saved configuration to {CONFIG_FILE}")
+            logger.info(f"Saved configuration to {path}")
             return True
 
         except (OSError, TypeError) as e:
@@ -328,6 +685,45 @@ class ConfigManager:
         self.config["speech_recognition"]["model_size"] = model_size
         logger.info(f"Set {engine} model size to: {model_size}")
 
+    def _engine_vad_override(self, engine: str) -> dict:
+        overrides = self.config.get("speech_recognition", {}).get("engine_vad") or {}
+        override = overrides.get(engine)
+        return override if isinstance(override, dict) else {}
+
+    def get_vad_setting(self, engine: str, key: str, default: Any = None) -> Any:
+        """Get vad_sensitivity or silence_timeout as in effect for an engine.
+
+        Args:
+            engine: The engine name
+            key: "vad_sensitivity" or "silence_timeout"
+            default: Returned if neither the engine nor the global setting has a value
+
+        Returns:
+            The engine's engine_vad override if it has one, else the global setting
+        """
+        override = self._engine_vad_override(engine)
+        if override.get(key) is not None:
+            return override[key]
+        return self.config.get("speech_recognition", {}).get(key, default)
+
+    def set_vad_setting(self, engine: str, key: str, value: Any):
+        """Set vad_sensitivity or silence_timeout where it takes effect for an engine.
+
+        An engine with its own value in engine_vad gets that override changed;
+        otherwise the global setting is, since the engine follows it.
+
+        Args:
+            engine: The engine name
+            key: "vad_sensitivity" or "silence_timeout"
+            value: The new value
+        """
+        override = self._engine_vad_override(engine)
+        if override.get(key) is not None:
+            override[key] = value
+            logger.info(f"Set {engine} {key} override to: {value}")
+        else:
+            self.set("speech_recognition", key, value)
+
     def is_voice_commands_enabled(self) -> bool:
         """Check if voice commands should be enabled.
 
@@ -357,8 +753,16 @@ class ConfigManager:
             model_size = settings["model_size"]
             self.set_model_size_for_engine(engine, model_size)
 
+        # VAD settings go to the engine's own override if it has one
+        engine = settings.get("engine") or self.config["speech_recognition"].get("engine", "")
+        for key in ("vad_sensitivity", "silence_timeout"):
+            if key in settings:
+                self.set_vad_setting(engine, key, settings[key])
+
         # Update all other keys present in the provided settings dict
         for key, value in settings.items():
+            if key in ("vad_sensitivity", "silence_timeout"):
+                continue
             self.config["speech_recognition"][key] = value
         logger.info(f"Updated speech recognition settings: {settings}")
 
@@ -0,0 +1,172 @@
+"""
+Validation of the settings read from the config file.
+
+The config file is edited by hand as well as by the Settings dialog, so it
+can hold values of the wrong type, out of range, or an engine/language
+combination that can't work. Each invalid setting is reported with its path
+(e.g. ``speech_recognition.vad_sensitivity``) and reset to its default on
+its own, so one typo doesn't cost the user the rest of their settings.
+"""
+
+import copy
+from dataclasses import dataclass
+from typing import Any
+
+from ..utils.language_support import get_supported_languages, validate_language_selection
+from ..utils.utterance_log import UTTERANCE_LOG_MODES
+
+# Numeric settings and the range they must be in (None = no limit on that side)
+SETTING_RANGES = {
+    "speech_recognition.vad_sensitivity": (1, 5),
+    "speech_recognition.silence_timeout": (0.5, 5.0),
+    "speech_recognition.max_utterance_seconds": (0, 600),
+    "speech_recognition.speech_pad_before_ms": (0, 1000),
+    "speech_recognition.speech_pad_after_ms": (0, 1000),
+    "speech_recognition.gpu_memory_reserve_mb": (0, None),
+    "audio.sample_rate": (8000, 192000),
+    "audio.sidetone_volume": (0.0, 1.0),
+    "audio.duck_level": (0.0, 1.0),
+    "audio.buffer_frames": (256, 8192),
+    "audio.queue_depth": (4, 256),
+    "shortcuts.tap_count": (2, 4),
+    "shortcuts.tap_threshold_ms": (100, 1000),
+    "ui.transcript_history_size": (0, None),
+    "general.timed_session_minutes": (1, 480),
+}
+
+# Settings that take one of a few values
+SETTING_CHOICES = {
+    "speech_recognition.engine": ("vosk", "whisper", "whisper_cpp", "remote_api"),
+    "shortcuts.mode": ("toggle", "push_to_talk"),
+    "general.utterance_log": UTTERANCE_LOG_MODES,
+}
+
+
+@dataclass(frozen=True)
+class ConfigProblem:
+    """An invalid setting, which was replaced with the value that is used instead."""
+
+    path: str
+    message: str
+
+    def __str__(self) -> str:
+        return f"{self.path}: {self.message}"
+
+
+def _lookup(config: dict, path: str):
+    section, key = path.split(".", 1)
+    return config.get(section, {}).get(key)
+
+
+def _reset(config: dict, defaults: dict, path: str) -> Any:
+    section, key = path.split(".", 1)
+    value = copy.deepcopy(defaults[section][key])
+    config[section][key] = value
+    return value
+
+
+def _type_matches(value, default) -> bool:
+    if default is None:
+        return True
+    if isinstance(default, bool):
+        return isinstance(value, bool)
+    if isinstance(default, (int, float)):
+        return isinstance(value, (int, float)) and not isinstance(value, bool)
+    return isinstance(value, type(default))
+
+
+def _check_types(config: dict, defaults: dict, prefix: str, problems: list):
+    for key, default in defaults.items():
+        if key not in config:
+            continue
+        path = f"{prefix}{key}"
+        value = config[key]
+        # Empty defaults ({} for device_gain, prompt_presets...) hold user-chosen keys
+        if isinstance(default, dict) and default and isinstance(value, dict):
+            _check_types(value, default, f"{path}.", problems)
+        elif not _type_matches(value, default):
+            config[key] = copy.deepcopy(default)
+            problems.append(
+                ConfigProblem(
+                    path,
+                    f"expected {type(default).__name__}, got {value!r}; using {default!r}",
+                )
+            )
+
+
+def _check_ranges(config: dict, defaults: dict, problems: list):
+    for path, (minimum, maximum) in SETTING_RANGES.items():
+        value = _lookup(config, path)
+        # None (and 0 for sample_rate) means automatic
+        if value is None or (path == "audio.sample_rate" and value == 0):
+            continue
+        if not isinstance(value, (int, float)) or isinstance(value, bool):
+            in_range = False
+        else:
+            in_range = (minimum is None or value >= minimum) and (
+                maximum is None or value <= maximum
+            )
+        if in_range:
+            continue
+        bounds = f"at least {minimum}" if maximum is None else f"between {minimum} and {maximum}"
+        default = _reset(config, defaults, path)
+        problems.append(ConfigProblem(path, f"{value!r} is not {bounds}; using {default!r}"))
+
+
+def _check_choices(config: dict, defaults: dict, problems: list):
+    for path, choices in SETTING_CHOICES.items():
+        value = _lookup(config, path)
+        if value in choices:
+            continue
+        default = _reset(config, defaults, path)
+        problems.append(
+            ConfigProblem(path, f"{value!r} is not one of {', '.join(choices)}; using {default!r}")
+        )
+
+
+def _check_language(config: dict, defaults: dict, problems: list):
+    """Check the engine, its model and the language go together."""
+    sr = config.get("speech_recognition", {})
+    engine = sr.get("engine")
+    if not get_supported_languages(engine, sr.get("model_size")):
+        # Only VOSK has a fixed list of model sizes
+        if engine == "vosk":
+            model_size = sr.get("model_size")
+            sr["model_size"] = sr["vosk_model_size"] = "small"
+            problems.append(
+                ConfigProblem(
+                    "speech_recognition.model_size",
+                    f"VOSK has no {model_size!r} model; using 'small'",
+                )
+            )
+        else:
+            return
+
+    language = sr.get("language")
+    warning = validate_language_selection(engine, sr.get("model_size"), language)
+    if not warning:
+        return
+    fallback = defaults["speech_recognition"]["language"]
+    if validate_language_selection(engine, sr.get("model_size"), fallback):
+        fallback = get_supported_languages(engine, sr.get("model_size"))[0]
+    sr["language"] = fallback
+    problems.append(ConfigProblem("speech_recognition.language", f"{warning} Using {fallback!r}."))
+
+
+def validate_config(config: dict, defaults: dict) -> list[ConfigProblem]:
+    """
+    Check settings merged over the defaults, resetting each invalid one.
+
+    Args:
+        config: The settings; invalid values are replaced in place
+        defaults: The default settings (DEFAULT_CONFIG)
+
+    Returns:
+        Every problem found, in the order they were checked
+    """
+    problems: list[ConfigProblem] = []
+    _check_types(config, defaults, "", problems)
+    _check_ranges(config, defaults, problems)
+    _check_choices(config, defaults, problems)
+    _check_language(config, defaults, problems)
+    return problems
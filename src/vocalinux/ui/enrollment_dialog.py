@@ -0,0 +1,156 @@
+"""
+Voice enrollment dialog for Vocalinux.
+
+Records the user reading a short text aloud and turns it into the speaker
+profile the VOSK engine matches utterances against (see speaker_profile).
+Only the profile is kept; the recording is discarded.
+"""
+
+import logging
+import threading
+import time
+from typing import Callable, Optional
+
+import gi
+
+gi.require_version("Gtk", "3.0")
+from gi.repository import GLib, Gtk  # noqa: E402
+
+from ..speech_recognition.speaker_profile import (  # noqa: E402
+    ENROLLMENT_SECONDS,
+    ENROLLMENT_TEXT,
+    SpeakerProfile,
+)
+
+logger = logging.getLogger(__name__)
+
+RESPONSE_START = 1
+
+_TICK_MS = 100
+
+
+class EnrollmentDialog(Gtk.Dialog):
+    """Dialog that records the user's voice and enrolls it for the speaker profile."""
+
+    def __init__(
+        self,
+        parent: Optional[Gtk.Window],
+        enroll: Callable[[bytes], SpeakerProfile],
+        device_index: Optional[int] = None,
+        gain: float = 1.0,
+    ):
+        """
+        Initialize the dialog.
+
+        Args:
+            parent: Parent window
+            enroll: Turns the 16 kHz recording into a saved profile; raises
+                RuntimeError with a message for the user if it can't
+            device_index: The input device to record (None for the default)
+            gain: Software gain of that device, as while dictating
+        """
+        super().__init__(
+            title="Enroll Your Voice",
+            transient_for=parent,
+            flags=Gtk.DialogFlags.MODAL | Gtk.DialogFlags.DESTROY_WITH_PARENT,
+        )
+        self.set_default_size(460, -1)
+        self._enroll = enroll
+        self._device_index = device_index
+        self._gain = gain
+        self._started = 0.0
+        self._timer_id: Optional[int] = None
+        self.profile: Optional[SpeakerProfile] = None
+
+        self.close_button = self.add_button("_Close", Gtk.ResponseType.CLOSE)
+        self.start_button = self.add_button("_Start", RESPONSE_START)
+        self.connect("response", self._on_response)
+
+        box = self.get_content_area()
+        box.set_spacing(12)
+        box.set_margin_start(24)
+        box.set_margin_end(24)
+        box.set_margin_top(20)
+        box.set_margin_bottom(16)
+
+        self.message_label = Gtk.Label(xalign=0)
+        self.message_label.set_line_wrap(True)
+        self.message_label.set_max_width_chars(52)
+        box.pack_start(self.message_label, False, False, 0)
+
+        self.progress_bar = Gtk.ProgressBar()
+        box.pack_start(self.progress_bar, False, False, 0)
+
+        self.message_label.set_text(
+            f"Enrollment records {ENROLLMENT_SECONDS} seconds of your voice so that only "
+            "you are transcribed. Click Start, then read this aloud at your normal "
+            f"volume:\n\n“{ENROLLMENT_TEXT}”"
+        )
+        self.show_all()
+
+    def _start(self):
+        """Record, then enroll in the background."""
+        self.profile = None
+        self.start_button.set_sensitive(False)
+        self.close_button.set_sensitive(False)
+        self.progress_bar.set_fraction(0.0)
+        self._started = time.monotonic()
+        self._timer_id = GLib.timeout_add(_TICK_MS, self._tick)
+        threading.Thread(target=self._record_and_enroll, daemon=True).start()
+
+    def _tick(self) -> bool:
+        elapsed = time.monotonic() - self._started
+        self.progress_bar.set_fraction(min(1.0, elapsed / ENROLLMENT_SECONDS))
+        if elapsed < ENROLLMENT_SECONDS:
+            return True
+        self._timer_id = None
+        return False
+
+    def _record_and_enroll(self):
+        from ..speech_recognition.recognition_manager import test_audio_input
+
+        result = test_audio_input(
+            device_index=self._device_index,
+            duration=ENROLLMENT_SECONDS,
+            gain=self._gain,
+            keep_audio=True,
+        )
+        if not result.get("audio"):
+            error = result.get("error") or "Nothing was recorded."
+            GLib.idle_add(self._finish, None, f"{error} Check the microphone and try again.")
+            return
+        GLib.idle_add(self.message_label.set_text, "Creating your voice profile...")
+        try:
+            profile = self._enroll(result["audio"])
+        except RuntimeError as e:
+            GLib.idle_add(self._finish, None, str(e))
+            return
+        GLib.idle_add(self._finish, profile, None)
+
+    def _finish(self, profile: Optional[SpeakerProfile], error: Optional[str]) -> bool:
+        if self._timer_id is not None:
+            GLib.source_remove(self._timer_id)
+            self._timer_id = None
+        self.progress_bar.set_fraction(1.0 if profile else 0.0)
+        self.close_button.set_sensitive(True)
+        self.start_button.set_label("_Try Again")
+        self.start_button.set_sensitive(True)
+        if profile is None:
+            logger.warning(f"Voice enrollment failed: {error}")
+            self.message_label.set_text(error or "Enrollment failed.")
+            return False
+        self.profile = profile
+        logger.info(f"Enrolled a voice from {profile.frames} frames of speech")
+        self.message_label.set_text(
+            "Your voice is enrolled. Turn on Recognize Only My Voice to skip other voices."
+        )
+        return False
+
+    def _on_response(self, dialog, response):
+        if response == RESPONSE_START:
+            self._start()
+            return
+        if response == Gtk.ResponseType.DELETE_EVENT and not self.close_button.get_sensitive():
+            # Still recording; the background thread finishes on its own
+            return
+        self.destroy()
@@ -7,16 +7,21 @@ supporting multiple platforms and display servers (X11, Wayland).
 
 import logging
 import os
+from dataclasses import dataclass
 from typing import Optional
 
 from .base import (
     DEFAULT_SHORTCUT,
     DEFAULT_SHORTCUT_MODE,
+    DEFAULT_TAP_COUNT,
+    DEFAULT_TAP_THRESHOLD_MS,
     SHORTCUT_DISPLAY_NAMES,
     SHORTCUT_GROUPS,
     SHORTCUT_MODE_DISPLAY_NAMES,
     SHORTCUT_MODES,
     SUPPORTED_SHORTCUTS,
+    TAP_COUNT_RANGE,
+    TAP_THRESHOLD_RANGE_MS,
     KeyboardBackend,
     ShortcutSpec,
     format_shortcut_label,
@@ -80,6 +85,95 @@ class DesktopEnvironment:
         return DesktopEnvironment.UNKNOWN
 
 
+# Shown with every "shortcuts unavailable" message so users aren't stuck
+TRAY_FALLBACK_HINT = "Until then, start and stop voice typing from the tray menu."
+
+
+@dataclass(frozen=True)
+class ShortcutCapability:
+    """Whether global shortcut listening is possible in the current session.
+
+    ``backend`` is the best usable backend ("pynput" or "evdev"), or None when
+    no backend can see key presses from other applications, in which case
+    ``guidance`` explains how to fix it.
+    """
+
+    environment: str
+    backend: Optional[str]
+    guidance: str = ""
+
+    @property
+    def available(self) -> bool:
+        return self.backend is not None
+
+
+def _evdev_usable(shortcut: str) -> bool:
+    if not EVDEV_AVAILABLE:
+        return False
+    try:
+        return EvdevKeyboardBackend(shortcut=shortcut).is_available()  # type: ignore
+    except Exception as e:
+        logger.debug(f"Could not probe evdev backend: {e}")
+        return False
+
+
+def _evdev_guidance(shortcut: str, reason: str) -> str:
+    """Explain why evdev can't be used and how to enable it."""
+    if not EVDEV_AVAILABLE:
+        steps = (
+            "Install python-evdev (pip install evdev) and add your user to the "
+            "'input' group: sudo usermod -a -G input $USER, then log out and back in."
+        )
+    elif os.environ.get("FLATPAK_ID"):
+        steps = (
+            "Grant access to input devices: "
+            f"flatpak override --user --device=input {os.environ['FLATPAK_ID']}"
+        )
+    else:
+        hint = EvdevKeyboardBackend(shortcut=shortcut).get_permission_hint()  # type: ignore
+        steps = (
+            hint.replace(":\n", ": ")
+            if hint
+            else "No keyboard with the shortcut key was found in /dev/input."
+        )
+    return f"Global shortcuts on {reason} need keyboard device access. {steps} {TRAY_FALLBACK_HINT}"
+
+
+def detect_shortcut_capability(shortcut: str = DEFAULT_SHORTCUT) -> ShortcutCapability:
+    """
+    Detect whether global key listening actually works in this session.
+
+    On X11 pynput can listen globally. On Wayland (and in Flatpak, where
+    XWayland only delivers keys for focused X clients) only evdev reading
+    /dev/input directly works, so it must be installed and permitted.
+
+    Args:
+        shortcut: The configured shortcut, used to find a matching keyboard
+
+    Returns:
+        A ShortcutCapability describing the best backend or how to get one
+    """
+    env = DesktopEnvironment.detect()
+    flatpak = bool(os.environ.get("FLATPAK_ID"))
+
+    if env == DesktopEnvironment.WAYLAND or flatpak:
+        if _evdev_usable(shortcut):
+            return ShortcutCapability(env, "evdev")
+        reason = "Flatpak" if flatpak else "Wayland"
+        return ShortcutCapability(env, None, _evdev_guidance(shortcut, reason))
+
+    if PYNPUT_AVAILABLE:
+        return ShortcutCapability(env, "pynput")
+    if _evdev_usable(shortcut):
+        return ShortcutCapability(env, "evdev")
+    return ShortcutCapability(
+        env,
+        None,
+        "Keyboard shortcuts need pynput or python-evdev: pip install pynput evdev. "
+        f"{TRAY_FALLBACK_HINT}",
+    )
+
+
 def create_backend(
     preferred_backend: Optional[str] = None,
     shortcut: str = DEFAULT_SHORTCUT,
@@ -173,6 +267,9 @@ def create_backend(
 __all__ = [
     "KeyboardBackend",
     "create_backend",
+    "detect_shortcut_capability",
+    "ShortcutCapability",
+    "TRAY_FALLBACK_HINT",
     "DesktopEnvironment",
     "PynputKeyboardBackend",
     "EvdevKeyboardBackend",
@@ -184,6 +281,10 @@ __all__ = [
     "SHORTCUT_MODES",
     "DEFAULT_SHORTCUT",
     "DEFAULT_SHORTCUT_MODE",
+    "DEFAULT_TAP_COUNT",
+    "DEFAULT_TAP_THRESHOLD_MS",
+    "TAP_COUNT_RANGE",
+    "TAP_THRESHOLD_RANGE_MS",
     "parse_shortcut",
     "parse_shortcut_spec",
     "is_valid_shortcut",
@@ -20,6 +20,7 @@ SUPPORTED_SHORTCUTS = {
     "ctrl+ctrl": "ctrl",
     "alt+alt": "alt",
     "shift+shift": "shift",
+    "super+super": "super",
     # Left side only
     "left_ctrl+left_ctrl": "left_ctrl",
     "left_alt+left_alt": "left_alt",
@@ -35,6 +36,7 @@ SHORTCUT_DISPLAY_NAMES = {
     "ctrl+ctrl": "Ctrl (either side)",
     "alt+alt": "Alt (either side)",
     "shift+shift": "Shift (either side)",
+    "super+super": "Super (either side)",
     "left_ctrl+left_ctrl": "Left Ctrl",
     "left_alt+left_alt": "Left Alt",
     "left_shift+left_shift": "Left Shift",
@@ -45,7 +47,7 @@ SHORTCUT_DISPLAY_NAMES = {
 
 # Grouping for UI display: maps group label -> list of shortcut IDs
 SHORTCUT_GROUPS = {
-    "Either Side": ["ctrl+ctrl", "alt+alt", "shift+shift"],
+    "Either Side": ["ctrl+ctrl", "alt+alt", "shift+shift", "super+super"],
     "Left Side": ["left_ctrl+left_ctrl", "left_alt+left_alt", "left_shift+left_shift"],
     "Right Side": ["right_ctrl+right_ctrl", "right_alt+right_alt", "right_shift+right_shift"],
 }
@@ -64,6 +66,10 @@ SHORTCUT_MODE_DISPLAY_NAMES = {
         "toggle": "Double-tap Shift",
         "push_to_talk": "Hold Shift",
     },
+    "super+super": {
+        "toggle": "Double-tap Super",
+        "push_to_talk": "Hold Super",
+    },
     "left_ctrl+left_ctrl": {
         "toggle": "Double-tap Left Ctrl",
         "push_to_talk": "Hold Left Ctrl",
@@ -100,6 +106,15 @@ SHORTCUT_MODES = {
 
 DEFAULT_SHORTCUT_MODE = "toggle"
 
+# Tap gesture tuning for toggle mode (pure-modifier shortcuts only)
+DEFAULT_TAP_COUNT = 2
+TAP_COUNT_RANGE = (2, 4)
+DEFAULT_TAP_THRESHOLD_MS = 300  # Max gap between consecutive taps
+TAP_THRESHOLD_RANGE_MS = (100, 1000)
+
+# Holding the tap key this long (with no other key) cancels the current utterance
+HOLD_TO_CANCEL_SECONDS = 1.0
+
 
 # ---------------------------------------------------------------------------
 # Generalized shortcut parsing (modifier-only gestures AND modifier+key combos)
@@ -406,10 +421,16 @@ class KeyboardBackend(ABC):
         self.double_tap_callback: Optional[Callback] = None
         self.key_press_callback: Optional[Callback] = None
         self.key_release_callback: Optional[Callback] = None
+        self.cancel_callback: Optional[Callback] = None
         self._shortcut = shortcut
         self._mode = mode
         self._spec = parse_shortcut_spec(shortcut)
         self._modifier_key = self._spec.primary_modifier
+        self.tap_count = DEFAULT_TAP_COUNT
+        self.double_tap_threshold = DEFAULT_TAP_THRESHOLD_MS / 1000  # seconds
+        self.last_key_press_time = 0
+        self._tap_streak = 0
+        self._hold_start_time: Optional[float] = None
 
     @property
     def spec(self) -> ShortcutSpec:
@@ -453,6 +474,71 @@ class KeyboardBackend(ABC):
         self._modifier_key = self._spec.primary_modifier
         self._shortcut = shortcut
 
+    def set_tap_settings(self, tap_count: int, threshold_ms: int) -> None:
+        """
+        Update how many taps trigger toggle mode and how fast they must be.
+
+        Args:
+            tap_count: Number of consecutive taps of the modifier (2-4)
+            threshold_ms: Maximum gap between taps in milliseconds (100-1000)
+
+        Raises:
+            ValueError: If either value is out of range
+        """
+        if not TAP_COUNT_RANGE[0] <= tap_count <= TAP_COUNT_RANGE[1]:
+            raise ValueError(f"Invalid tap count: {tap_count}. Must be between {TAP_COUNT_RANGE}")
+        if not TAP_THRESHOLD_RANGE_MS[0] <= threshold_ms <= TAP_THRESHOLD_RANGE_MS[1]:
+            raise ValueError(
+                f"Invalid tap threshold: {threshold_ms} ms. "
+                f"Must be between {TAP_THRESHOLD_RANGE_MS}"
+            )
+        self.tap_count = tap_count
+        self.double_tap_threshold = threshold_ms / 1000
+        self._tap_streak = 0
+
+    def _register_tap(self, current_time: float) -> bool:
+        """
+        Record a press of the watched modifier in toggle mode.
+
+        Call before updating ``last_key_press_time``.
+
+        Returns:
+            True if this press completes the configured tap sequence
+        """
+        if current_time - self.last_key_press_time < self.double_tap_threshold:
+            self._tap_streak = max(self._tap_streak, 1) + 1
+        else:
+            self._tap_streak = 1
+
+        if self._tap_streak >= self.tap_count:
+            self._tap_streak = 0
+            return True
+        return False
+
+    def _begin_hold(self, current_time: float) -> None:
+        """Record that the watched modifier went down (key repeats are ignored)."""
+        if self._hold_start_time is None:
+            self._hold_start_time = current_time
+
+    def _interrupt_hold(self) -> None:
+        """Forget the current hold, e.g. because another key was pressed with it."""
+        self._hold_start_time = None
+
+    def _end_hold(self, current_time: float) -> bool:
+        """
+        Record that the watched modifier was released in toggle mode.
+
+        Returns:
+            True if it was held alone long enough to cancel the utterance
+        """
+        start_time = self._hold_start_time
+        self._hold_start_time = None
+        if start_time is None or current_time - start_time < HOLD_TO_CANCEL_SECONDS:
+            return False
+        # A hold is never the first tap of a sequence
+        self._tap_streak = 0
+        return True
+
     @abstractmethod
     def start(self) -> bool:
         """
@@ -514,3 +600,12 @@ class KeyboardBackend(ABC):
             callback: Function to call when the shortcut key is released
         """
         self.key_release_callback = callback
+
+    def register_cancel_callback(self, callback: Optional[Callback]) -> None:
+        """
+        Register a callback for the hold-to-cancel gesture (toggle mode).
+
+        Args:
+            callback: Function to call when the shortcut key is held and released
+        """
+        self.cancel_callback = callback
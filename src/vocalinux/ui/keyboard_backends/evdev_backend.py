@@ -216,8 +216,6 @@ class EvdevKeyboardBackend(KeyboardBackend):
         self.monitor_thread: Optional[threading.Thread] = None
 
         self.last_trigger_time = 0
-        self.last_key_press_time = 0
-        self.double_tap_threshold = 0.3  # seconds
         self.key_pressed_devices: set[int] = set()
 
         # Combo (modifier+key) state. Populated by _resolve_combo_targets().
@@ -656,13 +654,16 @@ class EvdevKeyboardBackend(KeyboardBackend):
                     current_time = time.time()
 
                     if self._mode == "toggle":
-                        # Check for double-tap
+                        self._begin_hold(current_time)
+                        # Check for the configured tap sequence
                         if (
-                            current_time - self.last_key_press_time < self.double_tap_threshold
+                            self._register_tap(current_time)
                             and self.double_tap_callback is not None
                             and current_time - self.last_trigger_time > 0.5
                         ):
-                            logger.debug(f"Double-tap {self._modifier_key} detected (evdev)")
+                            logger.debug(
+                                f"{self.tap_count}x tap {self._modifier_key} detected (evdev)"
+                            )
                             self.last_trigger_time = current_time
                             threading.Thread(target=self.double_tap_callback, daemon=True).start()
                     elif self._mode == "push_to_talk":
@@ -681,6 +682,14 @@ class EvdevKeyboardBackend(KeyboardBackend):
                         if self.key_release_callback is not None:
                             logger.debug(f"Key release {self._modifier_key} detected (evdev)")
                             threading.Thread(target=self.key_release_callback, daemon=True).start()
+                    elif self._mode == "toggle":
+                        if self._end_hold(time.time()) and self.cancel_callback is not None:
+                            logger.debug(f"Hold {self._modifier_key} detected (evdev)")
+                            threading.Thread(target=self.cancel_callback, daemon=True).start()
+
+            elif value == 1:
+                # Ctrl+C and friends are not a hold-to-cancel gesture
+                self._interrupt_hold()
 
         except Exception as e:
             logger.error(f"Error handling key event: {e}")
@@ -181,9 +181,8 @@ class PynputKeyboardBackend(KeyboardBackend):
         super().__init__(shortcut, mode)
         self.listener = None
         self.last_trigger_time = 0
-        self.last_key_press_time = 0
-        self.double_tap_threshold = 0.3  # seconds
         self.current_keys = set()
+        self._held_tap_keys: set = set()  # Raw keys of the modifier currently down
 
         # Combo (modifier+key) state.
         self._combo_required_modifier_keys: list = []
@@ -366,26 +365,35 @@ class PynputKeyboardBackend(KeyboardBackend):
         try:
             matched = self._matches_configured_modifier(key)
 
-            if matched:
-                current_time = time.time()
-                normalized_key = self._normalize_modifier_key(key)
-                self.current_keys.add(normalized_key)
-
-                if self._mode == "toggle":
-                    if (
-                        current_time - self.last_key_press_time < self.double_tap_threshold
-                        and self.double_tap_callback is not None
-                        and current_time - self.last_trigger_time > 0.5
-                    ):
-                        logger.debug(f"Double-tap {self._modifier_key} detected (pynput)")
-                        self.last_trigger_time = current_time
-                        threading.Thread(target=self.double_tap_callback, daemon=True).start()
-                elif self._mode == "push_to_talk":
-                    if self.key_press_callback is not None:
-                        logger.debug(f"Key press {self._modifier_key} detected (pynput)")
-                        threading.Thread(target=self.key_press_callback, daemon=True).start()
+            if not matched:
+                # Ctrl+C and friends are not a hold-to-cancel gesture
+                self._interrupt_hold()
+                return
 
-                self.last_key_press_time = current_time
+            current_time = time.time()
+            normalized_key = self._normalize_modifier_key(key)
+            self.current_keys.add(normalized_key)
+
+            if self._mode == "toggle":
+                if key in self._held_tap_keys:
+                    # X11 auto-repeat while the key is held down
+                    return
+                self._held_tap_keys.add(key)
+                self._begin_hold(current_time)
+                if (
+                    self._register_tap(current_time)
+                    and self.double_tap_callback is not None
+                    and current_time - self.last_trigger_time > 0.5
+                ):
+                    logger.debug(f"{self.tap_count}x tap {self._modifier_key} detected (pynput)")
+                    self.last_trigger_time = current_time
+                    threading.Thread(target=self.double_tap_callback, daemon=True).start()
+            elif self._mode == "push_to_talk":
+                if self.key_press_callback is not None:
+                    logger.debug(f"Key press {self._modifier_key} detected (pynput)")
+                    threading.Thread(target=self.key_press_callback, daemon=True).start()
+
+            self.last_key_press_time = current_time
         except Exception as e:
             logger.error(f"Error in pynput key press handling: {e}")
 
@@ -404,6 +412,11 @@ class PynputKeyboardBackend(KeyboardBackend):
                 if self.key_release_callback is not None:
                     logger.debug(f"Key release {self._modifier_key} detected (pynput)")
                     threading.Thread(target=self.key_release_callback, daemon=True).start()
+            elif self._mode == "toggle" and matched:
+                self._held_tap_keys.discard(key)
+                if self._end_hold(time.time()) and self.cancel_callback is not None:
+                    logger.debug(f"Hold {self._modifier_key} detected (pynput)")
+                    threading.Thread(target=self.cancel_callback, daemon=True).start()
 
         except Exception as e:
             logger.error(f"Error in pynput key release handling: {e}")
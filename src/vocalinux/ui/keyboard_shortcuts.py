@@ -21,8 +21,10 @@ from .keyboard_backends import (
     SHORTCUT_DISPLAY_NAMES,
     SHORTCUT_MODES,
     SUPPORTED_SHORTCUTS,
+    TRAY_FALLBACK_HINT,
     DesktopEnvironment,
     create_backend,
+    detect_shortcut_capability,
     get_shortcut_display_name,
     is_valid_shortcut,
 )
@@ -147,6 +149,31 @@ class KeyboardShortcutManager:
 
         return True
 
+    def set_tap_settings(self, tap_count: int, tap_threshold_ms: int) -> bool:
+        """
+        Update the tap count and timing used by toggle mode.
+
+        Takes effect on the next key press; no restart is needed.
+
+        Args:
+            tap_count: Taps of the modifier needed to toggle (2-4)
+            tap_threshold_ms: Maximum gap between taps in milliseconds (100-1000)
+
+        Returns:
+            True if successful, False if a value is out of range
+        """
+        if self.backend_instance is None:
+            return False
+
+        try:
+            self.backend_instance.set_tap_settings(int(tap_count), int(tap_threshold_ms))
+        except (TypeError, ValueError) as e:
+            logger.error(f"Invalid tap settings: {e}")
+            return False
+
+        logger.info(f"Tap settings updated: {tap_count} taps within {tap_threshold_ms} ms")
+        return True
+
     @property
     def shortcut_display_name(self) -> str:
         """Get the human-readable name for the current shortcut."""
@@ -207,11 +234,13 @@ class KeyboardShortcutManager:
         toggle_callback = None
         press_callback = None
         release_callback = None
+        cancel_callback = None
 
         if self.backend_instance:
             toggle_callback = self.backend_instance.double_tap_callback
             press_callback = self.backend_instance.key_press_callback
             release_callback = self.backend_instance.key_release_callback
+            cancel_callback = self.backend_instance.cancel_callback
 
         # Stop the current listener
         if was_active:
@@ -237,10 +266,13 @@ class KeyboardShortcutManager:
             self.register_toggle_callback(None)
             self.register_press_callback(None)
             self.register_release_callback(None)
+            self.register_cancel_callback(None)
 
             # Re-register callbacks based on current mode
             if self._mode == "toggle" and toggle_callback:
                 self.register_toggle_callback(toggle_callback)
+                if cancel_callback:
+                    self.register_cancel_callback(cancel_callback)
             elif self._mode == "push_to_talk":
                 if press_callback:
                     self.register_press_callback(press_callback)
@@ -283,6 +315,31 @@ class KeyboardShortcutManager:
 
         return self.active
 
+    def get_unavailable_guidance(self) -> Optional[str]:
+        """
+        Explain why global shortcuts aren't working and how to fix it.
+
+        Returns:
+            User-facing guidance, or None if the listener is running
+        """
+        if self.active:
+            return None
+
+        capability = detect_shortcut_capability(self._shortcut)
+        logger.info(
+            f"Shortcut capability: environment={capability.environment}, "
+            f"backend={capability.backend}"
+        )
+        if capability.guidance:
+            return capability.guidance
+
+        if self.backend_instance is not None:
+            hint = self.backend_instance.get_permission_hint()
+            if hint:
+                return f"{hint} {TRAY_FALLBACK_HINT}"
+
+        return f"The keyboard shortcut listener could not be started. {TRAY_FALLBACK_HINT}"
+
     def stop(self):
         """Stop listening for keyboard shortcuts."""
         if self.backend_instance is None:
@@ -343,6 +400,23 @@ class KeyboardShortcutManager:
         else:
             logger.info(f"Registered release callback for: {self.shortcut_display_name}")
 
+    def register_cancel_callback(self, callback: Optional[Callable[[], None]]):
+        """
+        Register a callback for holding the shortcut key to cancel (toggle mode).
+
+        Args:
+            callback: Function to call when the key is held alone for over a second
+        """
+        if self.backend_instance is None:
+            logger.warning("Cannot register cancel callback: no backend available")
+            return
+
+        self.backend_instance.register_cancel_callback(callback)
+        if callback is None:
+            logger.debug("Cleared cancel callback")
+        else:
+            logger.info(f"Registered hold-to-cancel callback for: {self.shortcut_display_name}")
+
     @property
     def listener(self):
         """
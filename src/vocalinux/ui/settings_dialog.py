@@ -29,9 +29,51 @@ gi.require_version("Gdk", "3.0")
 from gi.repository import Gdk, GLib, Gtk, Pango  # noqa: E402
 
 from ..common_types import RecognitionState  # noqa: E402
+from ..speech_recognition.audio_level import (  # noqa: E402
+    METER_FLOOR_DBFS,
+    dbfs_to_percent,
+    vad_threshold_dbfs,
+)
+from ..speech_recognition.denoise import is_rnnoise_available  # noqa: E402
+from ..speech_recognition.engine_availability import get_available_engines  # noqa: E402
+from ..speech_recognition.level_monitor import LevelMonitor  # noqa: E402
+from ..speech_recognition.resample import (  # noqa: E402
+    DEFAULT_DEVICE_GAIN_KEY,
+    MAX_DEVICE_GAIN,
+    MIN_DEVICE_GAIN,
+)
 from ..speech_recognition.silero_vad import is_silero_available  # noqa: E402
+from ..speech_recognition.speaker_profile import SPK_MODEL_NAME  # noqa: E402
+from ..speech_recognition.vad import is_webrtc_vad_available  # noqa: E402
+from ..utils import credentials  # noqa: E402
+from ..utils.download_mirrors import DOWNLOAD_MIRRORS  # noqa: E402
+from ..utils.language_support import (  # noqa: E402
+    get_supported_languages,
+    validate_language_selection,
+)
+from ..utils.echo_cancel import is_echo_cancel_available, pulse_source  # noqa: E402
+from ..utils.media_ducking import is_media_ducking_available  # noqa: E402
+from ..utils.model_storage import (  # noqa: E402
+    configured_model_path,
+    delete_model,
+    format_bytes,
+    list_installed_models,
+    unused_models,
+)
 from ..utils.paths import models_dir  # noqa: E402
+from ..utils.pulse_sources import (  # noqa: E402
+    APP_STREAM_PREFIX,
+    app_stream_name,
+    find_app_stream,
+    is_app_stream,
+    is_monitor_source,
+    list_monitor_sources,
+    list_playback_apps,
+)
+from ..utils.utterance_log import utterance_log_path  # noqa: E402
+from ..utils.vocabulary_packs import VOCABULARY_PACKS, get_vocabulary_terms  # noqa: E402
 from ..utils.vosk_model_info import SUPPORTED_LANGUAGES, VOSK_MODEL_INFO  # noqa: E402
+from ..utils.whispercpp_load_report import WhisperCppLoadReport  # noqa: E402
 from ..utils.whispercpp_model_info import MODEL_SIZES as WHISPERCPP_MODEL_SIZES
 from ..utils.whispercpp_model_info import (
     WHISPERCPP_MODEL_INFO,
@@ -49,6 +91,7 @@ from .keyboard_backends import (  # noqa: E402
     SHORTCUT_GROUPS,
     SHORTCUT_MODES,
     SUPPORTED_SHORTCUTS,
+    format_shortcut_label,
     get_shortcut_display_name,
     is_valid_shortcut,
     parse_shortcut_spec,
@@ -272,48 +315,14 @@ WHISPER_MODEL_INFO = {
 }
 
 
-def get_available_engines():
-    """
-    Detect which speech recognition engines are available/installed.
-    Returns a dictionary of engine_name -> availability (bool).
-    """
-    engines = {"vosk": False, "whisper": False, "whisper_cpp": False, "remote_api": False}
-
-    # Check VOSK
-    try:
-        import vosk
-
-        engines["vosk"] = True
-    except ImportError:
-        pass
-
-    # Check OpenAI Whisper
-    try:
-        import whisper
-
-        engines["whisper"] = True
-    except ImportError:
-        pass
-
-    # Check whisper.cpp (pywhispercpp)
-    try:
-        from pywhispercpp.model import Model
-
-        engines["whisper_cpp"] = True
-    except ImportError:
-        pass
-
-    # Remote API is always available (only requires requests package)
-    try:
-        import requests  # noqa: F401
-
-        engines["remote_api"] = True
-    except ImportError:
-        pass
+# Capture rates offered for the sample rate override (besides "Automatic")
+SAMPLE_RATE_CHOICES = (16000, 22050, 32000, 44100, 48000)
 
-    logger.debug(f"Available engines: {engines}")
-    return engines
+# Device combo ids for monitor sources, which have no PortAudio index
+MONITOR_DEVICE_PREFIX = "monitor:"
 
+# Seconds the microphone test records before playing the recording back
+MIC_TEST_SECONDS = 3
 
 # Models directory
 MODELS_DIR = models_dir()
@@ -591,6 +600,76 @@ def _prevent_scroll_on_hover(widget: Gtk.Widget):
     widget.set_can_focus(True)
 
 
+def _find_focus_target(widget: Gtk.Widget) -> Optional[Gtk.Widget]:
+    """Return the first keyboard-focusable control in widget (or widget itself)."""
+    if widget.get_can_focus():
+        return widget
+    if hasattr(widget, "get_children"):
+        for child in widget.get_children():
+            target = _find_focus_target(child)
+            if target is not None:
+                return target
+    return None
+
+
+def _strip_mnemonic(label: str) -> str:
+    """The text GTK shows for a mnemonic label: "_" marks are dropped and "__" is a "_"."""
+    return re.sub(r"_(_?)", r"\1", label)
+
+
+def _matches_search(query: str, *texts: str) -> bool:
+    """Return whether every word of query appears in the given texts."""
+    haystack = " ".join(text for text in texts if text).lower()
+    return all(word in haystack for word in query.lower().split())
+
+
+def _parse_term_list(text: str) -> list[str]:
+    """Split a comma-separated term list, dropping blanks and case-insensitive duplicates."""
+    terms = []
+    for term in text.split(","):
+        term = term.strip()
+        if term and term.lower() not in (existing.lower() for existing in terms):
+            terms.append(term)
+    return terms
+
+
+def _parse_command_renames(text: str) -> dict:
+    """Parse "old phrase = new phrase" pairs separated by commas, skipping malformed ones."""
+    renames = {}
+    for pair in text.split(","):
+        old, sep, new = pair.partition("=")
+        if sep and old.strip() and new.strip():
+            renames[old.strip().lower()] = new.strip().lower()
+    return renames
+
+
+def _format_device_capabilities(capabilities: list[dict]) -> str:
+    """Format get_input_device_capabilities() results as Pango markup."""
+    if not capabilities:
+        return "<i>No input devices found</i>"
+
+    blocks = []
+    for device in capabilities:
+        title = GLib.markup_escape_text(f"[{device['index']}] {device['name']}")
+        if device["is_default"]:
+            title += " (default)"
+        rates = ", ".join(f"{rate / 1000:g} kHz" for rate in device["rates"])
+        channels = ", ".join("mono" if ch == 1 else "stereo" for ch in device["channels"])
+        lines = [
+            f"<b>{title}</b>",
+            f"Sample rates: {rates or 'none in any supported format'}",
+            f"Channels: {channels or 'none'} (device reports {device['max_channels']})",
+            f"Formats: {', '.join(device['formats']) or 'none'}",
+        ]
+        if not device["rates"]:
+            lines.append(
+                "<span foreground='#c01c28'>No sample rate works in any format Vocalinux "
+                "can record; this device is likely to fail with 'Invalid sample rate'</span>"
+            )
+        blocks.append("\n".join(lines))
+    return "\n\n".join(blocks)
+
+
 def _get_whisper_cache_dir() -> str:
     """Get the Whisper model cache directory."""
     return os.path.join(MODELS_DIR, "whisper")
@@ -761,6 +840,8 @@ class PreferencesGroup(Gtk.Box):
     def __init__(self, title: str = "", description: str = ""):
         super().__init__(orientation=Gtk.Orientation.VERTICAL, spacing=0)
         self.get_style_context().add_class("preferences-group")
+        self.title = title
+        self._search_query = ""
 
         # Header with title
         if title:
@@ -785,15 +866,39 @@ class PreferencesGroup(Gtk.Box):
         self.listbox = Gtk.ListBox()
         self.listbox.set_selection_mode(Gtk.SelectionMode.NONE)
         self.listbox.set_activate_on_single_click(False)
+        self.listbox.set_filter_func(self._filter_row)
         self.pack_start(self.listbox, False, False, 0)
 
     def add_row(self, widget):
         """Add a widget as a row in the preferences group."""
         self.listbox.add(widget)
 
+    def _filter_row(self, row) -> bool:
+        if not self._search_query or _matches_search(self._search_query, self.title):
+            return True
+        return not isinstance(row, PreferenceRow) or row.matches(self._search_query)
+
+    def apply_search(self, query: str) -> int:
+        """
+        Show only the rows matching a search query.
+
+        Returns:
+            The number of matching rows
+        """
+        self._search_query = query.strip()
+        self.listbox.invalidate_filter()
+        return sum(
+            1 for row in self.listbox.get_children() if row.get_visible() and self._filter_row(row)
+        )
+
 
 class PreferenceRow(Gtk.ListBoxRow):
-    """A single preference row with title, subtitle, and a control widget."""
+    """A single preference row with title, subtitle, and a control widget.
+
+    An underscore in the title marks a mnemonic: Alt+<letter> focuses the
+    row's control. The title and subtitle are also exposed to screen readers
+    as the control's accessible name and description.
+    """
 
     def __init__(
         self,
@@ -804,7 +909,10 @@ class PreferenceRow(Gtk.ListBoxRow):
     ):
         super().__init__()
         self.set_activatable(activatable)
+        # Plain rows aren't useful focus stops; Tab should land on the control
+        self.set_can_focus(activatable)
         self.get_style_context().add_class("preference-row")
+        self.title = _strip_mnemonic(title)
 
         hbox = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=12)
         hbox.set_margin_top(12)
@@ -816,7 +924,7 @@ class PreferenceRow(Gtk.ListBoxRow):
         text_box = Gtk.Box(orientation=Gtk.Orientation.VERTICAL, spacing=2)
         text_box.set_valign(Gtk.Align.CENTER)
 
-        title_label = Gtk.Label(label=title, xalign=0)
+        title_label = Gtk.Label(label=title, xalign=0, use_underline=True)
         title_label.get_style_context().add_class("preference-row-title")
         text_box.pack_start(title_label, False, False, 0)
 
@@ -837,6 +945,14 @@ class PreferenceRow(Gtk.ListBoxRow):
             widget.set_valign(Gtk.Align.CENTER)
             hbox.pack_end(widget, False, False, 0)
 
+            target = _find_focus_target(widget)
+            if target is not None:
+                title_label.set_mnemonic_widget(target)
+                accessible = target.get_accessible()
+                accessible.set_name(self.title)
+                if subtitle:
+                    accessible.set_description(subtitle)
+
         self.add(hbox)
 
     def set_subtitle(self, subtitle: str):
@@ -844,6 +960,11 @@ class PreferenceRow(Gtk.ListBoxRow):
         if self.subtitle_label:
             self.subtitle_label.set_text(subtitle)
 
+    def matches(self, query: str) -> bool:
+        """Return whether the row's title or subtitle matches a search query."""
+        subtitle = self.subtitle_label.get_text() if self.subtitle_label else ""
+        return _matches_search(query, self.title, subtitle)
+
 
 class ModelDownloadDialog(Gtk.Dialog):
     """Dialog showing model download progress with cancel support."""
@@ -982,6 +1103,7 @@ class SettingsDialog(Gtk.Dialog):
         config_manager: "ConfigManager",
         speech_engine: "SpeechRecognitionManager",
         shortcut_update_callback: callable = None,
+        panic_shortcut_update_callback: callable = None,
     ):
         super().__init__(title="Vocalinux Settings", transient_for=parent, flags=0)
         self.set_decorated(True)  # Force window decorations (close button) on all WMs
@@ -989,7 +1111,7 @@ class SettingsDialog(Gtk.Dialog):
         # Add a Close action button so the dialog always has a visible way to
         # dismiss it, even on window managers that hide the title-bar close
         # button for Gtk.Dialog windows without action buttons (fixes #323).
-        self.add_button("Close", Gtk.ResponseType.CLOSE)
+        self.add_button("_Close", Gtk.ResponseType.CLOSE)
         action_area = self.get_action_area()
         action_area.set_margin_top(8)
         action_area.set_margin_bottom(12)
@@ -1006,6 +1128,7 @@ class SettingsDialog(Gtk.Dialog):
         self.config_manager = config_manager
         self.speech_engine = speech_engine
         self.shortcut_update_callback = shortcut_update_callback
+        self.panic_shortcut_update_callback = panic_shortcut_update_callback
         self._test_active = False
         self._test_result = ""
         self._initializing = True  # Flag to prevent auto-apply during initialization
@@ -1015,6 +1138,7 @@ class SettingsDialog(Gtk.Dialog):
         )
         self._applying_settings = False  # Flag to prevent recursive settings application
         self._advanced_prompt_dirty = False
+        self._level_monitor: Optional[LevelMonitor] = None  # Live level on the Audio page
 
         # Setup CSS styling
         _setup_css()
@@ -1100,32 +1224,32 @@ class SettingsDialog(Gtk.Dialog):
             return scroller
 
         # Speech Engine tab - most important (what model/language to use)
-        speech_engine_label = Gtk.Label(label="Speech Engine")
+        speech_engine_label = Gtk.Label.new_with_mnemonic("_Speech Engine")
         speech_engine_label.set_tooltip_text("Speech recognition engine and model settings")
         notebook.append_page(_scrollable(self.speech_engine_tab), speech_engine_label)
 
         # Recognition Settings tab - second most important (how to recognize)
-        recognition_label = Gtk.Label(label="Recognition")
+        recognition_label = Gtk.Label.new_with_mnemonic("_Recognition")
         recognition_label.set_tooltip_text("Recognition behavior and test settings")
         notebook.append_page(_scrollable(self.recognition_settings_tab), recognition_label)
 
         # Audio tab - third (hardware configuration)
-        audio_label = Gtk.Label(label="Audio")
+        audio_label = Gtk.Label.new_with_mnemonic("_Audio")
         audio_label.set_tooltip_text("Microphone and audio settings")
-        notebook.append_page(_scrollable(self.audio_tab), audio_label)
+        self.audio_page_num = notebook.append_page(_scrollable(self.audio_tab), audio_label)
 
         # Shortcuts tab
-        shortcuts_label = Gtk.Label(label="Shortcuts")
+        shortcuts_label = Gtk.Label.new_with_mnemonic("Shor_tcuts")
         shortcuts_label.set_tooltip_text("Keyboard shortcuts")
         notebook.append_page(_scrollable(self.shortcuts_tab), shortcuts_label)
 
         # General tab - least important (application behavior)
-        general_label = Gtk.Label(label="General")
+        general_label = Gtk.Label.new_with_mnemonic("_General")
         general_label.set_tooltip_text("General settings")
         notebook.append_page(_scrollable(self.general_tab), general_label)
 
         # Advanced tab - whisper.cpp parameters and power-user features (remote API, etc.)
-        advanced_label = Gtk.Label(label="Advanced")
+        advanced_label = Gtk.Label.new_with_mnemonic("Ad_vanced")
         advanced_label.set_tooltip_text(
             "Advanced whisper.cpp parameters and settings for power users"
         )
@@ -1134,6 +1258,7 @@ class SettingsDialog(Gtk.Dialog):
         )
         notebook.connect("switch-page", self._on_settings_page_switched)
 
+        self._build_search_entry()
         self.get_content_area().pack_start(notebook, True, True, 0)
 
         # Set content_box to speech_engine_tab for backward compatibility
@@ -1144,6 +1269,9 @@ class SettingsDialog(Gtk.Dialog):
         self._build_audio_section()
         self._build_engine_section()
         self._build_remote_server_section()
+        self._build_vocabulary_section()
+        self._build_model_storage_section()
+        self._build_download_options_section()
         self._build_recognition_section()
         self._build_shortcuts_section()
         self._build_advanced_section()
@@ -1170,6 +1298,65 @@ class SettingsDialog(Gtk.Dialog):
         # Initialization complete - enable auto-apply
         self._initializing = False
 
+    def _build_search_entry(self):
+        """Build the search entry that filters rows across all tabs (Ctrl+F)."""
+        self._search_hidden_groups = []
+        self.search_entry = Gtk.SearchEntry()
+        self.search_entry.set_placeholder_text("Search settings (Ctrl+F)")
+        self.search_entry.get_accessible().set_name("Search settings")
+        self.search_entry.set_margin_top(8)
+        self.search_entry.set_margin_bottom(4)
+        self.search_entry.set_margin_start(16)
+        self.search_entry.set_margin_end(16)
+        self.search_entry.connect("search-changed", self._on_search_changed)
+
+        accel_group = Gtk.AccelGroup()
+        self.add_accel_group(accel_group)
+        self.search_entry.add_accelerator(
+            "grab-focus",
+            accel_group,
+            Gdk.KEY_f,
+            Gdk.ModifierType.CONTROL_MASK,
+            Gtk.AccelFlags.VISIBLE,
+        )
+
+        self.get_content_area().pack_start(self.search_entry, False, False, 0)
+
+    def _iter_preference_groups(self, widget):
+        """Yield every PreferencesGroup inside widget."""
+        if isinstance(widget, PreferencesGroup):
+            yield widget
+        elif hasattr(widget, "get_children"):
+            for child in widget.get_children():
+                yield from self._iter_preference_groups(child)
+
+    def _on_search_changed(self, entry):
+        """Filter settings rows and jump to the first tab with a match."""
+        query = entry.get_text().strip()
+
+        # Restore groups hidden by the previous search before re-filtering
+        for group in self._search_hidden_groups:
+            group.show()
+        self._search_hidden_groups = []
+
+        notebook = self.settings_notebook
+        matching_pages = []
+        for page_num in range(notebook.get_n_pages()):
+            page_matches = 0
+            for group in self._iter_preference_groups(notebook.get_nth_page(page_num)):
+                matches = group.apply_search(query)
+                if not group.get_visible():
+                    continue
+                if query and matches == 0:
+                    group.hide()
+                    self._search_hidden_groups.append(group)
+                page_matches += matches
+            if page_matches:
+                matching_pages.append(page_num)
+
+        if query and matching_pages and notebook.get_current_page() not in matching_pages:
+            notebook.set_current_page(matching_pages[0])
+
     def _build_audio_section(self):
         """Build the Audio Input section."""
         group = PreferencesGroup(title="Audio Input")
@@ -1186,17 +1373,72 @@ class SettingsDialog(Gtk.Dialog):
 
         refresh_btn = Gtk.Button.new_from_icon_name("view-refresh-symbolic", Gtk.IconSize.BUTTON)
         refresh_btn.set_tooltip_text("Refresh device list")
+        refresh_btn.get_accessible().set_name("Refresh device list")
         refresh_btn.get_style_context().add_class("flat-button")
         refresh_btn.connect("clicked", self._on_refresh_audio_devices)
         device_box.pack_start(refresh_btn, False, False, 0)
 
         device_row = PreferenceRow(
-            title="Input Device",
+            title="Input _Device",
             subtitle="Select the microphone for voice recognition",
             widget=device_box,
         )
         group.add_row(device_row)
 
+        # Software gain for the selected device, for microphones that are too quiet
+        self.input_gain_spin = Gtk.SpinButton.new_with_range(
+            MIN_DEVICE_GAIN * 100, MAX_DEVICE_GAIN * 100, 25
+        )
+        self.input_gain_spin.set_tooltip_text(
+            "Boost or cut the selected microphone before recognition.\n"
+            "Saved per device; other apps are not affected."
+        )
+        _prevent_scroll_on_hover(self.input_gain_spin)
+        input_gain_row = PreferenceRow(
+            title="Input _Gain",
+            subtitle="Percent, for this device only",
+            widget=self.input_gain_spin,
+        )
+        group.add_row(input_gain_row)
+
+        # More microphones mixed into the selected one
+        self.extra_devices_box = Gtk.Box(orientation=Gtk.Orientation.VERTICAL, spacing=4)
+        self.extra_devices_box.set_margin_top(8)
+        self.extra_devices_box.set_margin_bottom(8)
+        self.extra_devices_box.set_margin_start(8)
+        self.extra_devices_box.set_margin_end(8)
+        extra_devices_popover = Gtk.Popover()
+        extra_devices_popover.add(self.extra_devices_box)
+        self.extra_devices_button = Gtk.MenuButton(label="None")
+        self.extra_devices_button.set_popover(extra_devices_popover)
+        self.extra_devices_button.set_tooltip_text(
+            "Record these microphones too and mix them with the input device,\n"
+            "e.g. a desk microphone next to a headset"
+        )
+        extra_devices_row = PreferenceRow(
+            title="Also Record _From",
+            subtitle="Mix more microphones in (takes effect next recording)",
+            widget=self.extra_devices_button,
+        )
+        group.add_row(extra_devices_row)
+
+        # Capture rate override; audio is resampled to 16kHz for the engines
+        self.sample_rate_combo = Gtk.ComboBoxText()
+        self.sample_rate_combo.append("auto", "Automatic")
+        for rate in SAMPLE_RATE_CHOICES:
+            self.sample_rate_combo.append(str(rate), f"{rate} Hz")
+        self.sample_rate_combo.set_tooltip_text(
+            "Rate the microphone is opened at. Audio is converted to 16 kHz for\n"
+            "recognition. Falls back to an automatic rate if the device refuses it."
+        )
+        _prevent_scroll_on_hover(self.sample_rate_combo)
+        sample_rate_row = PreferenceRow(
+            title="Sample _Rate",
+            subtitle="Capture rate (takes effect next recording)",
+            widget=self.sample_rate_combo,
+        )
+        group.add_row(sample_rate_row)
+
         # Audio level test row
         level_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=12)
         self.audio_level_bar = Gtk.LevelBar()
@@ -1207,17 +1449,102 @@ class SettingsDialog(Gtk.Dialog):
         level_box.pack_start(self.audio_level_bar, True, True, 0)
 
         self.test_audio_btn = Gtk.Button(label="Test")
-        self.test_audio_btn.set_tooltip_text("Test the microphone for 2 seconds")
+        self.test_audio_btn.set_tooltip_text(
+            f"Record {MIC_TEST_SECONDS} seconds from the microphone and play them back"
+        )
         self.test_audio_btn.connect("clicked", self._on_test_audio_clicked)
         level_box.pack_start(self.test_audio_btn, False, False, 0)
 
         level_row = PreferenceRow(
-            title="Audio Level",
-            subtitle="Test your microphone",
+            title="Audio _Level",
+            subtitle="Test your microphone: record, listen back and see the levels",
             widget=level_box,
         )
         group.add_row(level_row)
 
+        # Sidetone: hear the microphone while dictating
+        self.sidetone_switch = Gtk.Switch()
+        self.sidetone_switch.set_tooltip_text(
+            "Play your microphone back at low volume while dictating.\n"
+            "Use headphones to avoid feedback."
+        )
+        sidetone_row = PreferenceRow(
+            title="_Monitor Microphone",
+            subtitle="Hear yourself while dictating (headphones recommended)",
+            widget=self.sidetone_switch,
+        )
+        group.add_row(sidetone_row)
+
+        self.sidetone_volume_spin = Gtk.SpinButton.new_with_range(5, 100, 5)
+        self.sidetone_volume_spin.set_tooltip_text("Playback volume of the microphone monitor")
+        _prevent_scroll_on_hover(self.sidetone_volume_spin)
+        sidetone_volume_row = PreferenceRow(
+            title="Monitor Vol_ume",
+            subtitle="Percent of the captured level",
+            widget=self.sidetone_volume_spin,
+        )
+        group.add_row(sidetone_volume_row)
+
+        # Media ducking: lower other apps' playback while dictating
+        self.duck_media_switch = Gtk.Switch()
+        ducking_available = is_media_ducking_available()
+        self.duck_media_switch.set_tooltip_text(
+            "Turn down music and videos while you dictate and restore them afterwards"
+            if ducking_available
+            else "Needs pactl (PulseAudio or PipeWire)"
+        )
+        duck_media_row = PreferenceRow(
+            title="Lower Media _Volume",
+            subtitle="Duck music and videos while dictating",
+            widget=self.duck_media_switch,
+        )
+        duck_media_row.set_sensitive(ducking_available)
+        group.add_row(duck_media_row)
+
+        self.duck_level_spin = Gtk.SpinButton.new_with_range(0, 90, 5)
+        self.duck_level_spin.set_tooltip_text("Volume media keeps while ducked")
+        _prevent_scroll_on_hover(self.duck_level_spin)
+        duck_level_row = PreferenceRow(
+            title="Media Volume _While Dictating",
+            subtitle="Percent of its normal volume",
+            widget=self.duck_level_spin,
+        )
+        group.add_row(duck_level_row)
+
+        # Echo cancellation: keep speaker playback out of the transcript
+        self.echo_cancel_switch = Gtk.Switch()
+        echo_cancel_available = is_echo_cancel_available()
+        self.echo_cancel_switch.set_tooltip_text(
+            "Subtract what your speakers play from the microphone signal while dictating.\n"
+            "Applies to the system default input device."
+            if echo_cancel_available
+            else "Needs pactl (PulseAudio or PipeWire)"
+        )
+        echo_cancel_row = PreferenceRow(
+            title="Cancel Speaker Ec_ho",
+            subtitle="Don't transcribe videos and sounds playing on speakers",
+            widget=self.echo_cancel_switch,
+        )
+        echo_cancel_row.set_sensitive(echo_cancel_available)
+        group.add_row(echo_cancel_row)
+
+        # Noise suppression: keep fans and room noise from becoming words
+        self.noise_suppression_switch = Gtk.Switch()
+        rnnoise_available = is_rnnoise_available()
+        self.noise_suppression_switch.set_tooltip_text(
+            "Run the microphone through RNNoise before speech detection and recognition.\n"
+            "Takes effect from the next dictation."
+            if rnnoise_available
+            else "Needs the RNNoise library (librnnoise)"
+        )
+        noise_suppression_row = PreferenceRow(
+            title="_Noise Suppression",
+            subtitle="Filter out fans, typing and room noise",
+            widget=self.noise_suppression_switch,
+        )
+        noise_suppression_row.set_sensitive(rnnoise_available)
+        group.add_row(noise_suppression_row)
+
         # Status label for audio testing (added below the group)
         self.audio_test_status = Gtk.Label(label="", use_markup=True, xalign=0)
         self.audio_test_status.set_margin_start(16)
@@ -1227,6 +1554,26 @@ class SettingsDialog(Gtk.Dialog):
         self.audio_tab.pack_start(group, False, False, 0)
         self.audio_tab.pack_start(self.audio_test_status, False, False, 0)
 
+        # Per-device capability report, probed on first expand
+        self.device_capabilities_label = Gtk.Label(xalign=0, selectable=True)
+        self.device_capabilities_label.set_line_wrap(True)
+        self.device_capabilities_label.set_margin_start(16)
+        self.device_capabilities_label.set_margin_top(4)
+        self.device_capabilities_expander = Gtk.Expander(
+            label="Device _Capabilities", use_underline=True
+        )
+        self.device_capabilities_expander.set_tooltip_text(
+            "Sample rates, channel counts and formats each input device accepts"
+        )
+        self.device_capabilities_expander.set_margin_start(16)
+        self.device_capabilities_expander.set_margin_top(8)
+        self.device_capabilities_expander.add(self.device_capabilities_label)
+        self.device_capabilities_expander.connect(
+            "notify::expanded", lambda *args: self._refresh_device_capabilities()
+        )
+        self._device_capabilities_loaded = False
+        self.audio_tab.pack_start(self.device_capabilities_expander, False, False, 0)
+
         # Sound Effects section
         sound_group = PreferencesGroup(title="Sound Effects")
         self.sound_effects_switch = Gtk.Switch()
@@ -1234,13 +1581,33 @@ class SettingsDialog(Gtk.Dialog):
             "Play sounds when recording starts, stops, or encounters errors"
         )
         sound_row = PreferenceRow(
-            title="Enable Sound Effects",
+            title="Enable Sound _Effects",
             subtitle="Play audio feedback for recording events",
             widget=self.sound_effects_switch,
         )
         sound_group.add_row(sound_row)
+        self.utterance_sound_switch = Gtk.Switch()
+        self.utterance_sound_switch.set_tooltip_text(
+            "Play a short blip each time a phrase is typed while you dictate"
+        )
+        sound_group.add_row(
+            PreferenceRow(
+                title="Sound After Each _Phrase",
+                subtitle="A quiet blip when dictated text is typed",
+                widget=self.utterance_sound_switch,
+            )
+        )
         self.audio_tab.pack_start(sound_group, False, False, 0)
         self.sound_effects_switch.connect("state-set", self._on_sound_effects_toggled)
+        self.utterance_sound_switch.connect("state-set", self._on_utterance_sound_toggled)
+        self.sample_rate_combo.connect("changed", self._on_sample_rate_changed)
+        self.sidetone_switch.connect("state-set", self._on_sidetone_toggled)
+        self.sidetone_volume_spin.connect("value-changed", self._on_sidetone_volume_changed)
+        self.input_gain_spin.connect("value-changed", self._on_input_gain_changed)
+        self.duck_media_switch.connect("state-set", self._on_duck_media_toggled)
+        self.duck_level_spin.connect("value-changed", self._on_duck_level_changed)
+        self.echo_cancel_switch.connect("state-set", self._on_echo_cancel_toggled)
+        self.noise_suppression_switch.connect("state-set", self._on_noise_suppression_toggled)
 
         # Populate devices
         self._populate_audio_devices()
@@ -1253,7 +1620,7 @@ class SettingsDialog(Gtk.Dialog):
         self.autostart_switch = Gtk.Switch()
         self.autostart_switch.set_tooltip_text("Start Vocalinux automatically when you log in")
         autostart_row = PreferenceRow(
-            title="Start on Login",
+            title="Start on _Login",
             subtitle="Automatically start Vocalinux when you log in",
             widget=self.autostart_switch,
         )
@@ -1262,29 +1629,134 @@ class SettingsDialog(Gtk.Dialog):
         self.start_minimized_switch = Gtk.Switch()
         self.start_minimized_switch.set_tooltip_text("Start minimized to system tray")
         start_minimized_row = PreferenceRow(
-            title="Start Minimized",
+            title="Start _Minimized",
             subtitle="Start minimized to system tray instead of showing window",
             widget=self.start_minimized_switch,
         )
         group.add_row(start_minimized_row)
 
+        self.capture_overlay_switch = Gtk.Switch()
+        self.capture_overlay_switch.set_tooltip_text(
+            "Show a small red dot in the top-right corner of the screen while Vocalinux\n"
+            "records the microphone. On Wayland the desktop may place it elsewhere."
+        )
+        capture_overlay_row = PreferenceRow(
+            title="Recording _Indicator",
+            subtitle="Red dot on screen while dictation is listening",
+            widget=self.capture_overlay_switch,
+        )
+        group.add_row(capture_overlay_row)
+
         self.copy_to_clipboard_switch = Gtk.Switch()
         self.copy_to_clipboard_switch.set_tooltip_text(
             "Copy recognized text to clipboard after each transcription. "
             "Useful if injection fails or you want to paste elsewhere."
         )
         copy_to_clipboard_row = PreferenceRow(
-            title="Copy to Clipboard",
+            title="Copy to Clip_board",
             subtitle="Always copy recognized text to clipboard for easy pasting",
             widget=self.copy_to_clipboard_switch,
         )
         group.add_row(copy_to_clipboard_row)
 
+        self.stop_on_focus_change_switch = Gtk.Switch()
+        self.stop_on_focus_change_switch.set_tooltip_text(
+            "Stop voice typing when another window takes focus, so text is never typed "
+            "into a window you didn't start dictating in. Requires X11 or XWayland."
+        )
+        stop_on_focus_change_row = PreferenceRow(
+            title="Stop on _Focus Change",
+            subtitle="Stop dictation when focus leaves the window you started in",
+            widget=self.stop_on_focus_change_switch,
+        )
+        group.add_row(stop_on_focus_change_row)
+
+        self.word_target_spin = Gtk.SpinButton.new_with_range(0, 100000, 50)
+        self.word_target_spin.set_tooltip_text(
+            "Get a notification once you have dictated this many words in a session.\n"
+            "Session time and word count are shown in the status window. 0 = no target."
+        )
+        _prevent_scroll_on_hover(self.word_target_spin)
+        word_target_row = PreferenceRow(
+            title="Session Word _Target",
+            subtitle="Words to write per session (0 = no target)",
+            widget=self.word_target_spin,
+        )
+        group.add_row(word_target_row)
+
+        self.timed_session_spin = Gtk.SpinButton.new_with_range(1, 480, 5)
+        self.timed_session_spin.set_tooltip_text(
+            "Start Timed Session in the tray menu dictates for this many minutes, then\n"
+            "stops, saves the transcript to a file and shows a summary."
+        )
+        _prevent_scroll_on_hover(self.timed_session_spin)
+        timed_session_row = PreferenceRow(
+            title="Timed Session Le_ngth",
+            subtitle="Minutes before a timed session stops and is saved",
+            widget=self.timed_session_spin,
+        )
+        group.add_row(timed_session_row)
+
+        self.transcript_history_spin = Gtk.SpinButton.new_with_range(0, 50, 1)
+        self.transcript_history_spin.set_tooltip_text(
+            "How many recent transcripts the tray menu lists for copying again.\n"
+            "Kept in memory only. 0 hides the menu. Applies when Settings is closed."
+        )
+        _prevent_scroll_on_hover(self.transcript_history_spin)
+        transcript_history_row = PreferenceRow(
+            title="Recent Tran_scripts",
+            subtitle="Transcripts to keep in the tray menu",
+            widget=self.transcript_history_spin,
+        )
+        group.add_row(transcript_history_row)
+
+        self.retry_audio_switch = Gtk.Switch()
+        self.retry_audio_switch.set_tooltip_text(
+            "Keep the audio of the recent transcripts in memory, so one that came out\n"
+            "garbled can be transcribed again with a larger model from the tray menu.\n"
+            "Forgotten when Vocalinux exits."
+        )
+        retry_audio_row = PreferenceRow(
+            title="Keep Audio for Retr_y",
+            subtitle="Retry recent transcripts with a larger model",
+            widget=self.retry_audio_switch,
+        )
+        group.add_row(retry_audio_row)
+
+        self.utterance_log_combo = Gtk.ComboBoxText()
+        self.utterance_log_combo.append("off", "Off")
+        self.utterance_log_combo.append("hashed", "Without text (hashed)")
+        self.utterance_log_combo.append("full", "With text")
+        self.utterance_log_combo.set_tooltip_text(
+            "Append one JSON line per utterance (timings, engine, confidence) to\n"
+            f"{utterance_log_path()}\n"
+            "for your own analysis or to attach to a bug report.\n"
+            "Without text, only a hash of what was said is stored."
+        )
+        _prevent_scroll_on_hover(self.utterance_log_combo)
+        utterance_log_row = PreferenceRow(
+            title="_Utterance Log",
+            subtitle="Machine-readable log of each transcription",
+            widget=self.utterance_log_combo,
+        )
+        group.add_row(utterance_log_row)
+
         self.general_tab.pack_start(group, False, False, 0)
 
         self.autostart_switch.connect("state-set", self._on_autostart_toggled)
         self.start_minimized_switch.connect("state-set", self._on_start_minimized_toggled)
+        self.capture_overlay_switch.connect("state-set", self._on_capture_overlay_toggled)
         self.copy_to_clipboard_switch.connect("state-set", self._on_copy_to_clipboard_toggled)
+        self.stop_on_focus_change_switch.connect(
+            "state-set", self._on_stop_on_focus_change_toggled
+        )
+        self.word_target_spin.connect("value-changed", self._on_word_target_changed)
+        self.timed_session_spin.connect("value-changed", self._on_timed_session_minutes_changed)
+        self.transcript_history_spin.connect(
+            "value-changed", self._on_transcript_history_size_changed
+        )
+        self.utterance_log_combo.connect("changed", self._on_utterance_log_changed)
+        self.retry_audio_switch.connect("state-set", self._on_retry_audio_toggled)
 
     def _on_autostart_toggled(self, widget, state):
         """Handle toggle of the autostart switch."""
@@ -1304,6 +1776,65 @@ class SettingsDialog(Gtk.Dialog):
 
         return True
 
+    def _on_word_target_changed(self, widget):
+        """Handle changes in the session word target."""
+        if self._initializing or self._applying_settings:
+            return
+
+        target = self.word_target_spin.get_value_as_int()
+        self.config_manager.set("general", "word_target", target)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(session_word_target=target, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply word target: {e}")
+
+    def _on_timed_session_minutes_changed(self, widget):
+        """Handle changes in the timed session length (used by the next session)."""
+        if self._initializing or self._applying_settings:
+            return
+
+        minutes = self.timed_session_spin.get_value_as_int()
+        self.config_manager.set("general", "timed_session_minutes", minutes)
+        self.config_manager.save_settings()
+
+    def _on_utterance_log_changed(self, widget):
+        """Handle selection of the utterance log mode."""
+        if self._initializing or self._applying_settings:
+            return
+
+        mode = self.utterance_log_combo.get_active_id() or "off"
+        self.config_manager.set("general", "utterance_log", mode)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(utterance_log=mode, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply utterance log mode: {e}")
+
+    def _on_retry_audio_toggled(self, widget, state):
+        """Handle toggle of the keep-audio-for-retry switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("general", "retry_audio", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(retry_audio=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply keeping audio for retry: {e}")
+        return False
+
+    def _on_transcript_history_size_changed(self, widget):
+        """Handle changes in the number of recent transcripts kept."""
+        if self._initializing or self._applying_settings:
+            return
+
+        self.config_manager.set(
+            "ui", "transcript_history_size", self.transcript_history_spin.get_value_as_int()
+        )
+        self.config_manager.save_settings()
+
     def _on_start_minimized_toggled(self, widget, state):
         """Handle toggle of the start minimized switch."""
         if self._initializing or self._applying_settings:
@@ -1316,6 +1847,15 @@ class SettingsDialog(Gtk.Dialog):
         logger.info(f"Start minimized {'enabled' if enabled else 'disabled'}")
         return False
 
+    def _on_capture_overlay_toggled(self, widget, state):
+        """Handle toggle of the recording indicator switch (applies from the next recording)."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        self.config_manager.set("ui", "capture_overlay", bool(state))
+        self.config_manager.save_settings()
+        return False
+
     def _on_copy_to_clipboard_toggled(self, widget, state):
         """Handle toggle of the copy to clipboard switch."""
         if self._initializing or self._applying_settings:
@@ -1328,6 +1868,17 @@ class SettingsDialog(Gtk.Dialog):
         logger.info(f"Copy to clipboard {'enabled' if enabled else 'disabled'}")
         return False
 
+    def _on_stop_on_focus_change_toggled(self, widget, state):
+        """Handle toggle of the stop on focus change switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        logger.info(f"Stop on focus change toggled: {enabled}")
+        self.config_manager.set("text_injection", "stop_on_focus_change", enabled)
+        self.config_manager.save_settings()
+        return False
+
     def _on_sound_effects_toggled(self, widget, state):
         if self._initializing or self._applying_settings:
             return False
@@ -1336,9 +1887,136 @@ class SettingsDialog(Gtk.Dialog):
         logger.info(f"Sound effects toggled: {enabled}")
         self.config_manager.set_sound_effects_enabled(enabled)
         self.config_manager.save_settings()
+        self.utterance_sound_switch.set_sensitive(enabled)
         logger.info(f"Sound effects {'enabled' if enabled else 'disabled'}")
         return False
 
+    def _on_utterance_sound_toggled(self, widget, state):
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        logger.info(f"Utterance sound toggled: {enabled}")
+        self.config_manager.set("sound_effects", "utterance", enabled)
+        self.config_manager.save_settings()
+        return False
+
+    def _on_sample_rate_changed(self, widget):
+        """Handle changes to the capture sample rate override."""
+        if self._initializing or self._applying_settings:
+            return
+
+        rate_id = self.sample_rate_combo.get_active_id()
+        sample_rate = None if rate_id in (None, "auto") else int(rate_id)
+        self.config_manager.set("audio", "sample_rate", sample_rate)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(sample_rate=sample_rate, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply sample rate: {e}")
+
+    def _on_sidetone_toggled(self, widget, state):
+        """Handle toggle of the microphone monitor switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.sidetone_volume_spin.set_sensitive(enabled)
+        self.config_manager.set("audio", "sidetone_enabled", enabled)
+        self.config_manager.save_settings()
+        self.speech_engine.set_sidetone(enabled, self.sidetone_volume_spin.get_value() / 100)
+        return False
+
+    def _on_sidetone_volume_changed(self, widget):
+        """Handle changes in the microphone monitor volume."""
+        if self._initializing or self._applying_settings:
+            return
+
+        volume = self.sidetone_volume_spin.get_value() / 100
+        self.config_manager.set("audio", "sidetone_volume", volume)
+        self.config_manager.save_settings()
+        self.speech_engine.set_sidetone(self.sidetone_switch.get_active(), volume)
+
+    def _selected_device_gain_key(self) -> str:
+        """The audio.device_gain key of the device picked in the combo."""
+        return self.config_manager.get("audio", "device_name", None) or DEFAULT_DEVICE_GAIN_KEY
+
+    def _show_input_gain(self):
+        """Show the saved gain of the selected device."""
+        gains = self.config_manager.get("audio", "device_gain", {}) or {}
+        gain = gains.get(self._selected_device_gain_key(), 1.0)
+        applying = self._applying_settings
+        self._applying_settings = True
+        try:
+            self.input_gain_spin.set_value(round(gain * 100))
+        finally:
+            self._applying_settings = applying
+
+    def _on_input_gain_changed(self, widget):
+        """Handle changes in the selected device's input gain."""
+        if self._initializing or self._applying_settings:
+            return
+
+        gains = dict(self.config_manager.get("audio", "device_gain", {}) or {})
+        gain = self.input_gain_spin.get_value() / 100
+        key = self._selected_device_gain_key()
+        if gain == 1.0:
+            gains.pop(key, None)
+        else:
+            gains[key] = gain
+        self.config_manager.set("audio", "device_gain", gains)
+        self.config_manager.save_settings()
+        if self._level_monitor is not None:
+            self._level_monitor.gain = gain
+        try:
+            self.speech_engine.set_device_gain(gains)
+        except Exception as e:
+            logger.warning(f"Failed to apply input gain: {e}")
+
+    def _on_duck_media_toggled(self, widget, state):
+        """Handle toggle of the media ducking switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.duck_level_spin.set_sensitive(enabled)
+        self.config_manager.set("audio", "duck_media", enabled)
+        self.config_manager.save_settings()
+        self.speech_engine.set_media_ducking(enabled, self.duck_level_spin.get_value() / 100)
+        return False
+
+    def _on_duck_level_changed(self, widget):
+        """Handle changes in the ducked media volume."""
+        if self._initializing or self._applying_settings:
+            return
+
+        level = self.duck_level_spin.get_value() / 100
+        self.config_manager.set("audio", "duck_level", level)
+        self.config_manager.save_settings()
+        self.speech_engine.set_media_ducking(self.duck_media_switch.get_active(), level)
+
+    def _on_echo_cancel_toggled(self, widget, state):
+        """Handle toggle of the echo cancellation switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("audio", "echo_cancel", enabled)
+        self.config_manager.save_settings()
+        self.speech_engine.set_echo_cancel(enabled)
+        return False
+
+    def _on_noise_suppression_toggled(self, widget, state):
+        """Handle toggle of the noise suppression switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("audio", "noise_suppression", enabled)
+        self.config_manager.save_settings()
+        self.speech_engine.set_noise_suppression(enabled)
+        return False
+
     def _build_engine_section(self):
         """Build the Speech Engine section."""
         group = PreferencesGroup(title="Speech Engine")
@@ -1348,7 +2026,7 @@ class SettingsDialog(Gtk.Dialog):
         self.engine_combo.set_size_request(180, -1)
         _prevent_scroll_on_hover(self.engine_combo)
         engine_row = PreferenceRow(
-            title="Engine",
+            title="_Engine",
             subtitle="Speech recognition backend",
             widget=self.engine_combo,
         )
@@ -1360,7 +2038,7 @@ class SettingsDialog(Gtk.Dialog):
         self.model_combo.set_tooltip_text(MODEL_SIZE_TOOLTIP)
         _prevent_scroll_on_hover(self.model_combo)
         self.model_row = PreferenceRow(
-            title="Model Size",
+            title="_Model Size",
             subtitle="Larger models are more accurate but slower",
             widget=self.model_combo,
         )
@@ -1373,7 +2051,7 @@ class SettingsDialog(Gtk.Dialog):
         self.model_variant_combo.set_tooltip_text(MODEL_SPECIALIZATION_TOOLTIP)
         _prevent_scroll_on_hover(self.model_variant_combo)
         self.model_variant_row = PreferenceRow(
-            title="Specialization",
+            title="S_pecialization",
             subtitle="Variant for language, speed, or memory use",
             widget=self.model_variant_combo,
         )
@@ -1386,13 +2064,28 @@ class SettingsDialog(Gtk.Dialog):
         self.language_combo.set_tooltip_text(LANGUAGE_TOOLTIP)
         _prevent_scroll_on_hover(self.language_combo)
         self.language_row = PreferenceRow(
-            title="Language",
+            title="_Language",
             subtitle="Primary language for recognition",
             widget=self.language_combo,
         )
         self.language_row.set_tooltip_text(LANGUAGE_TOOLTIP)
         group.add_row(self.language_row)
 
+        # VRAM left for other programs (Whisper engines on a GPU)
+        self.gpu_reserve_spin = Gtk.SpinButton.new_with_range(0, 65536, 512)
+        self.gpu_reserve_spin.set_tooltip_text(
+            "GPU memory in MB that Whisper and whisper.cpp leave free for games and other\n"
+            "programs. If the chosen model would need more, a smaller one is loaded instead.\n"
+            "Needs an NVIDIA GPU to read its memory. 0 = no limit."
+        )
+        _prevent_scroll_on_hover(self.gpu_reserve_spin)
+        gpu_reserve_row = PreferenceRow(
+            title="_GPU Memory to Keep Free",
+            subtitle="MB of VRAM left for other programs (0 = no limit)",
+            widget=self.gpu_reserve_spin,
+        )
+        group.add_row(gpu_reserve_row)
+
         self.content_box.pack_start(group, False, False, 0)
 
         # Model info card (shown below the group)
@@ -1443,96 +2136,440 @@ class SettingsDialog(Gtk.Dialog):
         self.model_combo.connect("changed", self._on_model_changed)
         self.model_variant_combo.connect("changed", self._on_model_variant_changed)
         self.language_combo.connect("changed", self._on_language_changed)
+        self.gpu_reserve_spin.connect("value-changed", self._on_gpu_reserve_changed)
 
-    def _on_remote_api_settings_changed(self, widget):
-        """Handle remote API URL/Key/endpoint changes."""
+    def _build_vocabulary_section(self):
+        """Build the Vocabulary Packs section on the Speech Engine page."""
+        group = PreferencesGroup(
+            title="Vocabulary Packs",
+            description="Domain terms that help Whisper engines spell specialist words correctly",
+        )
+        self.vocabulary_pack_buttons = {}
+        for pack in VOCABULARY_PACKS.values():
+            button = Gtk.Button()
+            button.set_valign(Gtk.Align.CENTER)
+            button.connect("clicked", self._on_vocabulary_pack_clicked, pack.id)
+            group.add_row(
+                PreferenceRow(
+                    title=pack.name,
+                    subtitle=f"{pack.description} ({len(pack.terms)} terms)",
+                    widget=button,
+                )
+            )
+            self.vocabulary_pack_buttons[pack.id] = button
+        self.content_box.pack_start(group, False, False, 0)
+        self._update_vocabulary_pack_buttons()
+
+    def _update_vocabulary_pack_buttons(self):
+        """Label each vocabulary pack button with the action it will perform."""
+        installed = self.config_manager.get("vocabulary", "packs", []) or []
+        for pack_id, button in self.vocabulary_pack_buttons.items():
+            button.set_label("Remove" if pack_id in installed else "Install")
+
+    def _on_vocabulary_pack_clicked(self, widget, pack_id):
+        """Install or remove a vocabulary pack."""
         if self._initializing or self._applying_settings:
             return
 
-        url = self.remote_api_url_entry.get_text().strip()
-        key = self.remote_api_key_entry.get_text().strip()
-        endpoint = self.remote_api_endpoint_combo.get_active_id() or "/inference"
-        model = self.remote_api_model_entry.get_text().strip() or "whisper-1"
-
-        self.config_manager.set("speech_recognition", "remote_api_url", url)
-        self.config_manager.set("speech_recognition", "remote_api_key", key)
-        self.config_manager.set("speech_recognition", "remote_api_endpoint", endpoint)
-        self.config_manager.set("speech_recognition", "remote_api_model", model)
+        packs = list(self.config_manager.get("vocabulary", "packs", []) or [])
+        if pack_id in packs:
+            packs.remove(pack_id)
+        else:
+            packs.append(pack_id)
+        self.config_manager.set("vocabulary", "packs", packs)
         self.config_manager.save_settings()
+        self._update_vocabulary_pack_buttons()
 
-        # Auto apply only if remote engine is currently active
-        engine_text = self.engine_combo.get_active_text()
-        engine = _engine_from_display(engine_text) if engine_text else "vosk"
-        if engine == "remote_api":
-            self._auto_apply_settings()
-
-    def _on_test_remote_connection(self, widget):
-        """Test remote server connection."""
-        url = self.remote_api_url_entry.get_text().strip()
-        if not url:
-            self.remote_status_label.set_markup(
-                "<span foreground='#c01c28'>✗ Please enter a server URL</span>"
+        custom_terms = self.config_manager.get("vocabulary", "custom_terms", []) or []
+        try:
+            self.speech_engine.reconfigure(
+                vocabulary_terms=get_vocabulary_terms(packs, custom_terms), force_download=False
             )
-            return
+        except Exception as e:
+            logger.warning(f"Failed to apply vocabulary packs immediately: {e}")
+        logger.info(f"Vocabulary packs: {', '.join(packs) or 'none'}")
+
+    def _build_model_storage_section(self):
+        """Build the Downloaded Models section on the Speech Engine page."""
+        self.model_storage_group = PreferencesGroup(
+            title="Downloaded Models",
+            description="Models stay on disk after switching; delete the ones you no longer use",
+        )
+        self.remove_unused_models_btn = Gtk.Button(label="Remove Unused")
+        self.remove_unused_models_btn.set_valign(Gtk.Align.CENTER)
+        self.remove_unused_models_btn.connect("clicked", self._on_remove_unused_models)
+        self.model_storage_row = PreferenceRow(
+            title="_Free Up Space",
+            subtitle="Delete the models the current settings don't use",
+            widget=self.remove_unused_models_btn,
+        )
+        self.model_storage_group.add_row(self.model_storage_row)
+        self._model_rows = []
+        self.content_box.pack_start(self.model_storage_group, False, False, 0)
+        self._refresh_model_storage()
 
-        self.remote_test_btn.set_sensitive(False)
-        self.remote_test_btn.set_label("Testing...")
-        self.remote_status_label.set_markup("<i>Connecting...</i>")
+    def _build_download_options_section(self):
+        """Build the Model Downloads section on the Speech Engine page."""
+        group = PreferencesGroup(
+            title="Model Downloads",
+            description="Where new models are downloaded from, for blocked or metered connections",
+        )
 
-        key = self.remote_api_key_entry.get_text().strip()
-        endpoint = self.remote_api_endpoint_combo.get_active_id() or "/inference"
+        self.download_mirror_combo = Gtk.ComboBoxText()
+        for mirror_id, label in DOWNLOAD_MIRRORS.items():
+            self.download_mirror_combo.append(mirror_id, label)
+        self.download_mirror_combo.set_tooltip_text(
+            "hf-mirror.com serves the Hugging Face (whisper.cpp) models where huggingface.co "
+            "is blocked or slow"
+        )
+        _prevent_scroll_on_hover(self.download_mirror_combo)
+        self.download_mirror_combo.connect("changed", self._on_download_options_changed)
+        group.add_row(
+            PreferenceRow(
+                title="Download _Source",
+                subtitle="Server for whisper.cpp models",
+                widget=self.download_mirror_combo,
+            )
+        )
 
-        def test_connection(url=url, key=key, endpoint=endpoint):
-            try:
-                import requests
+        self.vosk_mirror_entry = Gtk.Entry()
+        self.vosk_mirror_entry.set_placeholder_text("https://alphacephei.com/vosk/models")
+        self.vosk_mirror_entry.set_tooltip_text(
+            "Base URL of a server with the VOSK model zips, such as a local copy"
+        )
+        self.vosk_mirror_entry.set_size_request(280, -1)
+        self.vosk_mirror_entry.connect("changed", self._on_download_options_changed)
+        group.add_row(
+            PreferenceRow(
+                title="VOSK Mi_rror",
+                subtitle="Leave empty to download from alphacephei.com",
+                widget=self.vosk_mirror_entry,
+            )
+        )
 
-                headers = {}
-                if key:
-                    headers["Authorization"] = f"Bearer {key}"
+        self.download_limit_spin = Gtk.SpinButton.new_with_range(0, 100000, 100)
+        self.download_limit_spin.set_tooltip_text("Maximum download speed in KB/s, 0 for no limit")
+        _prevent_scroll_on_hover(self.download_limit_spin)
+        self.download_limit_spin.connect("value-changed", self._on_download_options_changed)
+        group.add_row(
+            PreferenceRow(
+                title="_Bandwidth Limit",
+                subtitle="KB/s while downloading a model (0 = unlimited)",
+                widget=self.download_limit_spin,
+            )
+        )
+        self.content_box.pack_start(group, False, False, 0)
 
-                clean_url = url.rstrip("/")
+    def _on_download_options_changed(self, widget):
+        """Save the download source and speed limit."""
+        if self._initializing or self._applying_settings:
+            return
 
-                session = requests.Session()
-                try:
-                    response = session.get(clean_url, headers=headers, timeout=5)
+        mirror = self.download_mirror_combo.get_active_id() or "official"
+        vosk_mirror_url = self.vosk_mirror_entry.get_text().strip()
+        limit_kbps = int(self.download_limit_spin.get_value())
+        self.config_manager.set("downloads", "mirror", mirror)
+        self.config_manager.set("downloads", "vosk_mirror_url", vosk_mirror_url)
+        self.config_manager.set("downloads", "bandwidth_limit_kbps", limit_kbps)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.set_download_options(mirror, vosk_mirror_url, limit_kbps)
+        except Exception as e:
+            logger.warning(f"Failed to apply download options immediately: {e}")
+
+    def _model_paths_in_use(self) -> list:
+        """Paths of the models the current settings load."""
+        sr = self.config_manager.get_settings().get("speech_recognition", {})
+        language = sr.get("language", "en-us")
+        engines = {sr.get("engine"), sr.get("comparison_engine")} - {None}
+        paths = [
+            configured_model_path(
+                engine, self.config_manager.get_model_size_for_engine(engine), language
+            )
+            for engine in engines
+        ]
+        paths.append(
+            configured_model_path(sr.get("rewrite_engine"), sr.get("rewrite_model_size"), language)
+        )
+        return paths
+
+    def _refresh_model_storage(self):
+        """List the downloaded models with their sizes."""
+        for row in self._model_rows:
+            self.model_storage_group.listbox.remove(row)
+        self._model_rows = []
+
+        models = list_installed_models()
+        unused = unused_models(models, self._model_paths_in_use())
+        total = sum(model.size_bytes for model in models)
+        unused_total = sum(model.size_bytes for model in unused)
+        count = f"{len(models)} model{'' if len(models) == 1 else 's'}"
+        subtitle = f"{count} using {format_bytes(total)}"
+        if unused:
+            subtitle += f", {format_bytes(unused_total)} not used by the current settings"
+        self.model_storage_row.set_subtitle(subtitle)
+        self.remove_unused_models_btn.set_sensitive(bool(unused))
+
+        for model in models:
+            in_use = model not in unused
+            delete_btn = Gtk.Button.new_from_icon_name("user-trash-symbolic", Gtk.IconSize.BUTTON)
+            delete_btn.get_style_context().add_class("flat-button")
+            delete_btn.set_valign(Gtk.Align.CENTER)
+            delete_btn.set_tooltip_text(
+                "In use by the current settings" if in_use else "Delete this model"
+            )
+            delete_btn.set_sensitive(not in_use)
+            delete_btn.connect("clicked", self._on_delete_model_clicked, model)
+            row = PreferenceRow(
+                title=f"{_engine_display_name(model.engine)} {model.name.replace('_', '__')}",
+                subtitle=format_bytes(model.size_bytes) + (" · in use" if in_use else ""),
+                widget=delete_btn,
+            )
+            row.show_all()
+            self.model_storage_group.add_row(row)
+            self._model_rows.append(row)
 
-                    server_info = ""
+    def _confirm_model_deletion(self, text: str, size_bytes: int) -> bool:
+        dialog = Gtk.MessageDialog(
+            transient_for=self,
+            flags=Gtk.DialogFlags.MODAL,
+            message_type=Gtk.MessageType.QUESTION,
+            buttons=Gtk.ButtonsType.NONE,
+            text=text,
+        )
+        dialog.format_secondary_text(
+            f"This frees {format_bytes(size_bytes)}. "
+            "A deleted model is downloaded again if you select it later."
+        )
+        dialog.add_button("_Cancel", Gtk.ResponseType.CANCEL)
+        delete_btn = dialog.add_button("_Delete", Gtk.ResponseType.YES)
+        delete_btn.get_style_context().add_class("destructive-action")
+        response = dialog.run()
+        dialog.destroy()
+        return response == Gtk.ResponseType.YES
 
-                    try:
-                        if endpoint == "/v1/audio/transcriptions":
-                            openai_resp = session.get(
-                                f"{clean_url}/v1/models", headers=headers, timeout=5
-                            )
-                            if openai_resp.status_code == 200:
-                                server_info = " (OpenAI compatible)"
-                        elif endpoint == "/inference":
-                            whispercpp_resp = session.get(
-                                f"{clean_url}/inference", headers=headers, timeout=5
-                            )
-                            if whispercpp_resp.status_code != 404:
-                                server_info = " (whisper.cpp server)"
-                    except Exception:
-                        pass
-
-                    GLib.idle_add(
-                        self.remote_status_label.set_markup,
-                        f"<span foreground='#26a269'>✓ Connected! "
-                        f"(status={response.status_code}){server_info}</span>",
-                    )
-                finally:
-                    session.close()
+    def _on_delete_model_clicked(self, widget, model):
+        """Delete one downloaded model."""
+        name = f"{_engine_display_name(model.engine)} {model.name}"
+        if not self._confirm_model_deletion(f"Delete the {name} model?", model.size_bytes):
+            return
+        delete_model(model)
+        self._after_models_deleted()
+
+    def _on_remove_unused_models(self, widget):
+        """Delete every downloaded model the current settings don't load."""
+        unused = unused_models(list_installed_models(), self._model_paths_in_use())
+        if not unused:
+            return
+        count = f"{len(unused)} model{'' if len(unused) == 1 else 's'}"
+        if not self._confirm_model_deletion(
+            f"Delete {count} not used by the current settings?",
+            sum(model.size_bytes for model in unused),
+        ):
+            return
+        for model in unused:
+            delete_model(model)
+        self._after_models_deleted()
+
+    def _after_models_deleted(self):
+        self._refresh_model_storage()
+        # Update the downloaded marks in the model selector
+        self._populate_model_options()
+
+    def _on_remote_api_settings_changed(self, widget):
+        """Handle remote API URL/Key/endpoint changes."""
+        if self._initializing or self._applying_settings:
+            return
+
+        url = self.remote_api_url_entry.get_text().strip()
+        key = self.remote_api_key_entry.get_text().strip()
+        endpoint = self.remote_api_endpoint_combo.get_active_id() or "/inference"
+        model = self.remote_api_model_entry.get_text().strip() or "whisper-1"
+
+        self.config_manager.set("speech_recognition", "remote_api_url", url)
+        self._store_remote_api_key(key)
+        self.config_manager.set("speech_recognition", "remote_api_endpoint", endpoint)
+        self.config_manager.set("speech_recognition", "remote_api_model", model)
+        self.config_manager.save_settings()
+
+        # Auto apply only if remote engine is currently active
+        engine_text = self.engine_combo.get_active_text()
+        engine = _engine_from_display(engine_text) if engine_text else "vosk"
+        if engine == "remote_api":
+            self._auto_apply_settings()
+
+    def _current_remote_api_account(self) -> str:
+        return self.remote_api_account_combo.get_active_id() or credentials.DEFAULT_ACCOUNT
+
+    def _load_remote_api_key(self, account: str) -> str:
+        """The saved API key for an account (keyring first, then the config file)."""
+        key = credentials.get_secret(account)
+        if key:
+            return key
+        saved_account = self.config_manager.get(
+            "speech_recognition", "remote_api_account", credentials.DEFAULT_ACCOUNT
+        )
+        if account != saved_account:
+            return ""
+        return self.config_manager.get("speech_recognition", "remote_api_key", "") or ""
+
+    def _store_remote_api_key(self, key: str):
+        """Save the API key for the current account, in the keyring when possible."""
+        if credentials.set_secret(self._current_remote_api_account(), key):
+            # Don't leave a copy in the config file
+            self.config_manager.set("speech_recognition", "remote_api_key", "")
+        else:
+            self.config_manager.set("speech_recognition", "remote_api_key", key)
+
+    def _on_remote_api_account_changed(self, widget):
+        """Switch to another account's API key."""
+        if self._initializing or self._applying_settings:
+            return
+        if self.remote_api_account_combo.get_active_id() is None:
+            # Nothing selected while an account is being removed
+            return
+        account = self._current_remote_api_account()
+        key = self._load_remote_api_key(account)
+        self.config_manager.set("speech_recognition", "remote_api_account", account)
+        if not credentials.keyring_available():
+            # The config file only holds the current account's key
+            self.config_manager.set("speech_recognition", "remote_api_key", key)
+        self.config_manager.save_settings()
 
-            except Exception as e:
-                error_msg = str(e)[:80]
-                GLib.idle_add(
-                    self.remote_status_label.set_markup,
-                    f"<span foreground='#c01c28'>✗ Connection failed: {error_msg}</span>",
+        self.remote_api_key_entry.handler_block_by_func(self._on_remote_api_settings_changed)
+        try:
+            self.remote_api_key_entry.set_text(key)
+        finally:
+            self.remote_api_key_entry.handler_unblock_by_func(
+                self._on_remote_api_settings_changed
+            )
+        logger.info(f"Remote API account: {account}")
+
+        engine_text = self.engine_combo.get_active_text()
+        engine = _engine_from_display(engine_text) if engine_text else "vosk"
+        if engine == "remote_api":
+            self._auto_apply_settings()
+
+    def _save_remote_api_accounts(self):
+        accounts = [row[1] for row in self.remote_api_account_combo.get_model()]
+        self.config_manager.set("speech_recognition", "remote_api_accounts", accounts)
+        self.remove_account_btn.set_sensitive(len(accounts) > 1)
+
+    def _on_add_remote_api_account(self, widget):
+        """Ask for a name and add an account with its own API key."""
+        dialog = Gtk.Dialog(title="Add Account", transient_for=self, modal=True)
+        dialog.add_button("_Cancel", Gtk.ResponseType.CANCEL)
+        add_btn = dialog.add_button("_Add", Gtk.ResponseType.OK)
+        add_btn.get_style_context().add_class("suggested-action")
+        dialog.set_default_response(Gtk.ResponseType.OK)
+
+        name_entry = Gtk.Entry()
+        name_entry.set_placeholder_text("work")
+        name_entry.set_activates_default(True)
+        name_entry.get_accessible().set_name("Account name")
+        box = dialog.get_content_area()
+        box.set_spacing(8)
+        box.set_border_width(12)
+        box.pack_start(Gtk.Label(label="Account name:", xalign=0), False, False, 0)
+        box.pack_start(name_entry, False, False, 0)
+        dialog.show_all()
+        response = dialog.run()
+        name = name_entry.get_text().strip()
+        dialog.destroy()
+
+        if response != Gtk.ResponseType.OK or not name:
+            return
+        existing = [row[1] for row in self.remote_api_account_combo.get_model()]
+        if name not in existing:
+            if not credentials.is_valid_account_name(name):
+                self.remote_status_label.set_markup(
+                    "<span foreground='#c01c28'>✗ Account names may use letters, digits, "
+                    "spaces and . @ - _</span>"
                 )
+                self.remote_status_label.show()
+                return
+            self.remote_api_account_combo.append(name, name)
+            self._save_remote_api_accounts()
+        # Saves the settings and clears the key entry for the new account
+        self.remote_api_account_combo.set_active_id(name)
+
+    def _on_remove_remote_api_account(self, widget):
+        """Remove the current account and forget its API key."""
+        account = self._current_remote_api_account()
+        dialog = Gtk.MessageDialog(
+            transient_for=self,
+            flags=Gtk.DialogFlags.MODAL,
+            message_type=Gtk.MessageType.QUESTION,
+            buttons=Gtk.ButtonsType.NONE,
+            text=f"Remove the account “{account}”?",
+        )
+        dialog.format_secondary_text("Its API key is deleted from the keyring.")
+        dialog.add_button("_Cancel", Gtk.ResponseType.CANCEL)
+        remove_btn = dialog.add_button("_Remove", Gtk.ResponseType.YES)
+        remove_btn.get_style_context().add_class("destructive-action")
+        response = dialog.run()
+        dialog.destroy()
+        if response != Gtk.ResponseType.YES:
+            return
 
-            GLib.idle_add(self.remote_test_btn.set_sensitive, True)
-            GLib.idle_add(self.remote_test_btn.set_label, "Test Connection")
+        credentials.delete_secret(account)
+        self.remote_api_account_combo.remove(self.remote_api_account_combo.get_active())
+        self._save_remote_api_accounts()
+        # Saves the settings and loads the remaining account's key
+        self.remote_api_account_combo.set_active(0)
 
-        threading.Thread(target=test_connection, daemon=True).start()
+    def _on_test_remote_connection(self, widget):
+        """Test remote server connection."""
+        url = self.remote_api_url_entry.get_text().strip()
+        if not url:
+            self.remote_status_label.set_markup(
+                "<span foreground='#c01c28'>✗ Please enter a server URL</span>"
+            )
+            return
+
+        self.remote_test_btn.set_sensitive(False)
+        self.remote_test_btn.set_label("Testing...")
+        self.remote_status_label.set_markup("<i>Connecting...</i>")
+
+        key = self.remote_api_key_entry.get_text().strip()
+        endpoint = self.remote_api_endpoint_combo.get_active_id() or "/inference"
+
+        def run_test(url=url, key=key, endpoint=endpoint):
+            from ..speech_recognition.remote_connection import test_connection
+
+            diagnosis = test_connection(url, key, endpoint)
+            GLib.idle_add(self._show_remote_connection_result, diagnosis)
+
+        threading.Thread(target=run_test, daemon=True).start()
+
+    def _show_remote_connection_result(self, diagnosis):
+        """Show a connection test result in the status label and a dialog."""
+        self.remote_test_btn.set_sensitive(True)
+        self.remote_test_btn.set_label("Test Connection")
+
+        summary = GLib.markup_escape_text(diagnosis.summary)
+        if diagnosis.ok:
+            server_info = f" ({diagnosis.server_info})" if diagnosis.server_info else ""
+            self.remote_status_label.set_markup(
+                f"<span foreground='#26a269'>✓ {summary}{server_info}</span>"
+            )
+        else:
+            self.remote_status_label.set_markup(f"<span foreground='#c01c28'>✗ {summary}</span>")
+
+        dialog = Gtk.MessageDialog(
+            transient_for=self,
+            flags=Gtk.DialogFlags.MODAL,
+            message_type=Gtk.MessageType.INFO if diagnosis.ok else Gtk.MessageType.ERROR,
+            buttons=Gtk.ButtonsType.CLOSE,
+            text=diagnosis.summary,
+        )
+        detail = diagnosis.detail
+        if diagnosis.status_code is not None and not diagnosis.ok:
+            detail += f"\n\nHTTP status: {diagnosis.status_code}"
+        dialog.format_secondary_text(detail)
+        dialog.run()
+        dialog.destroy()
+        return False
 
     def _build_recognition_section(self):
         """Build the Recognition Settings section."""
@@ -1548,25 +2585,222 @@ class SettingsDialog(Gtk.Dialog):
             if silero_active
             else "Sensitivity (1-5) -- backend: amplitude (install vocalinux[vad] for neural)"
         )
+        vad_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=8)
+        vad_box.pack_start(self.vad_spin, False, False, 0)
+        self.calibrate_btn = Gtk.Button(label="Calibrate...")
+        self.calibrate_btn.set_tooltip_text(
+            "Measure your room's noise and your voice, and pick the sensitivity for you"
+        )
+        self.calibrate_btn.connect("clicked", self._on_calibrate_clicked)
+        vad_box.pack_start(self.calibrate_btn, False, False, 0)
         self.vad_row = PreferenceRow(
-            title="VAD Sensitivity",
+            title="VA_D Sensitivity",
             subtitle=vad_subtitle,
-            widget=self.vad_spin,
+            widget=vad_box,
         )
         group.add_row(self.vad_row)
 
+        # VAD backend
+        self.vad_backend_combo = Gtk.ComboBoxText()
+        self.vad_backend_combo.append("auto", "Automatic")
+        self.vad_backend_combo.append("energy", "Energy (audio level)")
+        if is_webrtc_vad_available():
+            self.vad_backend_combo.append("webrtc", "WebRTC")
+        if silero_active:
+            self.vad_backend_combo.append("silero", "Silero (neural)")
+        self.vad_backend_combo.set_tooltip_text(
+            "How speech is told apart from silence.\n"
+            "Automatic uses the Silero neural VAD when installed, else the energy VAD.\n"
+            "WebRTC copes better with steady background noise than the energy VAD.\n"
+            "Silero is the most robust but uses the most CPU."
+        )
+        _prevent_scroll_on_hover(self.vad_backend_combo)
+        group.add_row(
+            PreferenceRow(
+                title="VAD Bac_kend",
+                subtitle=(
+                    "Speech detector used while dictating"
+                    if is_webrtc_vad_available()
+                    else "Install webrtcvad-wheels for the WebRTC detector"
+                ),
+                widget=self.vad_backend_combo,
+            )
+        )
+
         # Silence Timeout
         self.silence_spin = Gtk.SpinButton.new_with_range(0.5, 5.0, 0.1)
         self.silence_spin.set_digits(1)
         self.silence_spin.set_tooltip_text("Wait time after silence before processing speech")
         _prevent_scroll_on_hover(self.silence_spin)
         silence_row = PreferenceRow(
-            title="Silence Timeout",
+            title="Silence T_imeout",
             subtitle="Seconds of silence before processing",
             widget=self.silence_spin,
         )
         group.add_row(silence_row)
 
+        # Adaptive silence timeout
+        self.adaptive_silence_switch = Gtk.Switch()
+        self.adaptive_silence_switch.set_tooltip_text(
+            "Measure how long you pause between words and keep the timeout a little longer.\n"
+            "Slow, thoughtful speech gets more time; fast speech is typed sooner.\n"
+            "The silence timeout above is used until a few pauses have been heard."
+        )
+        group.add_row(
+            PreferenceRow(
+                title="Adapti_ve Timeout",
+                subtitle="Adjust the silence timeout to how you speak",
+                widget=self.adaptive_silence_switch,
+            )
+        )
+
+        # Maximum utterance length
+        self.max_utterance_spin = Gtk.SpinButton.new_with_range(0, 600, 5)
+        self.max_utterance_spin.set_tooltip_text(
+            "If you speak without pausing for this long, what you said so far is\n"
+            "transcribed at the next gap between words while recording carries on,\n"
+            "so one long utterance doesn't hold up typing. 0 = no limit."
+        )
+        _prevent_scroll_on_hover(self.max_utterance_spin)
+        group.add_row(
+            PreferenceRow(
+                title="_Max Utterance Length",
+                subtitle="Seconds before non-stop speech is transcribed in parts (0 = off)",
+                widget=self.max_utterance_spin,
+            )
+        )
+
+        # Utterance joining window
+        self.join_window_spin = Gtk.SpinButton.new_with_range(0.0, 3.0, 0.1)
+        self.join_window_spin.set_digits(1)
+        self.join_window_spin.set_tooltip_text(
+            "After a pause, wait this long for you to continue the sentence.\n"
+            "If you do, both parts are typed as one sentence. 0 types each part immediately."
+        )
+        _prevent_scroll_on_hover(self.join_window_spin)
+        join_window_row = PreferenceRow(
+            title="_Join Window",
+            subtitle="Seconds to wait for a sentence to continue (0 = off)",
+            widget=self.join_window_spin,
+        )
+        group.add_row(join_window_row)
+
+        # Long-form mode (Whisper engines only)
+        self.long_form_switch = Gtk.Switch()
+        self.long_form_switch.set_tooltip_text(
+            "Transcribe in overlapping 30-second windows instead of waiting for pauses.\n"
+            "Suited to continuous, lecture-style dictation. Whisper engines only."
+        )
+        long_form_row = PreferenceRow(
+            title="_Long-Form Mode",
+            subtitle="Ignore pauses and transcribe in overlapping 30 s windows",
+            widget=self.long_form_switch,
+        )
+        group.add_row(long_form_row)
+
+        # Partial results: type while speaking, corrected when the final text arrives
+        self.partial_results_switch = Gtk.Switch()
+        self.partial_results_switch.set_tooltip_text(
+            "Type a first guess while you are still speaking, then fix it up in place\n"
+            "when the final transcript arrives. Local engines only; uses more CPU/GPU."
+        )
+        partial_results_row = PreferenceRow(
+            title="_Type While Speaking",
+            subtitle="Show partial results immediately and correct them",
+            widget=self.partial_results_switch,
+        )
+        group.add_row(partial_results_row)
+
+        # Speaker profile (VOSK only): skip utterances that aren't in the enrolled voice
+        self.speaker_profile_switch = Gtk.Switch()
+        self.speaker_profile_switch.set_tooltip_text(
+            "Skip what other people in the room say. Needs a voice enrolled with\n"
+            f"Enroll... and VOSK's speaker model ({SPK_MODEL_NAME}). VOSK only."
+        )
+        self.enroll_voice_btn = Gtk.Button(label="Enroll...")
+        self.enroll_voice_btn.set_tooltip_text("Record your voice to match utterances against")
+        self.enroll_voice_btn.connect("clicked", self._on_enroll_voice_clicked)
+        speaker_profile_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=8)
+        speaker_profile_box.pack_start(self.enroll_voice_btn, False, False, 0)
+        speaker_profile_box.pack_start(self.speaker_profile_switch, False, False, 0)
+        group.add_row(
+            PreferenceRow(
+                title="_Recognize Only My Voice",
+                subtitle="Transcribe only the voice you enrolled",
+                widget=speaker_profile_box,
+            )
+        )
+
+        # Draft-then-rewrite: type the main engine's text at once, then correct it
+        self.rewrite_combo = Gtk.ComboBoxText()
+        self.rewrite_combo.append("off", "Off")
+        if get_available_engines().get("whisper_cpp"):
+            engine_name = _engine_display_name("whisper_cpp")
+            for size in WHISPERCPP_MODEL_SIZES:
+                for variant in get_whispercpp_model_variants(size):
+                    if is_whispercpp_model_downloaded(variant):
+                        label = f"{engine_name} {variant}"
+                        self.rewrite_combo.append(f"whisper_cpp:{variant}", label)
+        self.rewrite_combo.set_tooltip_text(
+            "Type the main engine's text as a quick draft, then transcribe the same audio\n"
+            "again with this larger model and correct the draft in place. Only the last\n"
+            "utterance is corrected, and only if nothing was typed after it.\n"
+            "Lists downloaded whisper.cpp models."
+        )
+        _prevent_scroll_on_hover(self.rewrite_combo)
+        group.add_row(
+            PreferenceRow(
+                title="Re_write With",
+                subtitle="Correct fast drafts with a larger model",
+                widget=self.rewrite_combo,
+            )
+        )
+
+        # Translate mode (Whisper engines only)
+        self.translate_switch = Gtk.Switch()
+        self.translate_switch.set_tooltip_text(
+            "Type English text whatever language you speak, instead of transcribing\n"
+            "in the spoken language. Whisper engines only."
+        )
+        translate_row = PreferenceRow(
+            title="Translate to En_glish",
+            subtitle="Dictate in any language and get English text",
+            widget=self.translate_switch,
+        )
+        group.add_row(translate_row)
+
+        # Language tag for auto-detect (Whisper and remote servers that report it)
+        self.tag_language_switch = Gtk.Switch()
+        self.tag_language_switch.set_tooltip_text(
+            "With the language set to Auto-detect, start each typed utterance with\n"
+            "the language it was recognized as, e.g. \"[de] Guten Morgen\".\n"
+            "Useful for notes that mix languages."
+        )
+        tag_language_row = PreferenceRow(
+            title="Tag Detected La_nguage",
+            subtitle="Prefix dictated text with the detected language",
+            widget=self.tag_language_switch,
+        )
+        group.add_row(tag_language_row)
+
+        # Prompt presets for the focused application (Whisper engines only)
+        self.app_prompts_entry = Gtk.Entry()
+        self.app_prompts_entry.set_placeholder_text("terminal = shell, thunderbird = email")
+        self.app_prompts_entry.set_width_chars(24)
+        self.app_prompts_entry.set_valign(Gtk.Align.CENTER)
+        self.app_prompts_entry.set_tooltip_text(
+            "Prime Whisper with a prompt preset when dictating into an application,\n"
+            "as window class = preset. Built-in presets: shell, email; add your own\n"
+            "under prompt_presets in the config file. Whisper engines only."
+        )
+        group.add_row(
+            PreferenceRow(
+                title="Prompts for Eac_h App",
+                subtitle="Prompt presets picked by the focused window",
+                widget=self.app_prompts_entry,
+            )
+        )
+
         # Voice Commands Toggle
         self.voice_commands_switch = Gtk.Switch()
         self.voice_commands_switch.set_tooltip_text(
@@ -1574,14 +2808,151 @@ class SettingsDialog(Gtk.Dialog):
             "Useful for VOSK engine. Whisper engines handle punctuation automatically."
         )
         voice_commands_row = PreferenceRow(
-            title="Voice Commands",
+            title="Voice C_ommands",
             subtitle="Enable voice commands for punctuation and editing",
             widget=self.voice_commands_switch,
         )
         group.add_row(voice_commands_row)
 
+        # Built-in commands that get in the way of normal prose
+        self.disabled_commands_entry = Gtk.Entry()
+        self.disabled_commands_entry.set_placeholder_text("period, colon")
+        self.disabled_commands_entry.set_width_chars(24)
+        self.disabled_commands_entry.set_valign(Gtk.Align.CENTER)
+        self.disabled_commands_entry.set_tooltip_text(
+            "Built-in commands listed here are typed as ordinary words.\n"
+            "The command reference lists every built-in command."
+        )
+        group.add_row(
+            PreferenceRow(
+                title="Commands Typed as Word_s",
+                subtitle="Comma-separated built-in commands to turn off",
+                widget=self.disabled_commands_entry,
+            )
+        )
+
+        self.renamed_commands_entry = Gtk.Entry()
+        self.renamed_commands_entry.set_placeholder_text("period = full stop now")
+        self.renamed_commands_entry.set_width_chars(24)
+        self.renamed_commands_entry.set_valign(Gtk.Align.CENTER)
+        self.renamed_commands_entry.set_tooltip_text(
+            "Say a different phrase for a built-in command, as command = new phrase.\n"
+            "Separate several with commas. The original phrase is then typed as words."
+        )
+        group.add_row(
+            PreferenceRow(
+                title="R_enamed Commands",
+                subtitle="Built-in commands triggered by a phrase of your own",
+                widget=self.renamed_commands_entry,
+            )
+        )
+
+        self.punctuation_combo = Gtk.ComboBoxText()
+        for level, label in (
+            ("default", "Engine default"),
+            ("off", "Off"),
+            ("commands", "Spoken commands only"),
+            ("auto", "Full auto"),
+        ):
+            self.punctuation_combo.append(level, label)
+        self.punctuation_combo.set_valign(Gtk.Align.CENTER)
+        self.punctuation_combo.set_tooltip_text(
+            "Off: no punctuation at all.\n"
+            "Spoken commands only: remove the engine's punctuation and type only what you "
+            "say, like 'comma' (needs Voice Commands).\n"
+            "Full auto: keep the engine's punctuation, add spoken commands, and punctuate "
+            "sentences for engines that don't (VOSK).\n"
+            "Engine default: the engine's own punctuation plus spoken commands."
+        )
+        _prevent_scroll_on_hover(self.punctuation_combo)
+        punctuation_row = PreferenceRow(
+            title="P_unctuation",
+            subtitle="Where punctuation in the typed text comes from",
+            widget=self.punctuation_combo,
+        )
+        group.add_row(punctuation_row)
+
+        self.readback_switch = Gtk.Switch()
+        self.readback_switch.set_tooltip_text(
+            "Say 'read that back' to hear the last dictated text spoken aloud.\n"
+            "Uses Piper when a voice model is configured, otherwise espeak-ng.\n"
+            "Requires voice commands to be enabled."
+        )
+        readback_row = PreferenceRow(
+            title="Read _Back",
+            subtitle="Speak the last dictated text on 'read that back'",
+            widget=self.readback_switch,
+        )
+        group.add_row(readback_row)
+
+        self.intent_commands_switch = Gtk.Switch()
+        self.intent_commands_switch.set_tooltip_text(
+            "Say 'open Firefox' to launch an installed application, or\n"
+            "'search for ...' to open a web search, instead of typing the words."
+        )
+        intent_commands_row = PreferenceRow(
+            title="_Assistant Commands",
+            subtitle="Launch apps and search the web by voice",
+            widget=self.intent_commands_switch,
+        )
+        group.add_row(intent_commands_row)
+
         self.recognition_settings_tab.pack_start(group, False, False, 0)
 
+        # Formatting rules, applied to all dictated text
+        formatting_group = PreferencesGroup(title="Formatting")
+        self.number_format_switches = {}
+        for key, title, subtitle in (
+            ("ordinals", "Ordinal Nu_mbers", "Type \"third\" as 3rd and \"twenty-first\" as 21st"),
+            ("fractions", "_Fractions", "Type \"three quarters\" as 3/4"),
+            ("roman_numerals", "_Roman Numerals", "Type \"roman numeral four\" as IV"),
+        ):
+            switch = Gtk.Switch()
+            formatting_group.add_row(PreferenceRow(title=title, subtitle=subtitle, widget=switch))
+            self.number_format_switches[key] = switch
+
+        self.profanity_combo = Gtk.ComboBoxText()
+        for mode, label in (
+            ("off", "Off"),
+            ("mask", "Mask (f***)"),
+            ("remove", "Remove"),
+        ):
+            self.profanity_combo.append(mode, label)
+        self.profanity_combo.set_valign(Gtk.Align.CENTER)
+        _prevent_scroll_on_hover(self.profanity_combo)
+        formatting_group.add_row(
+            PreferenceRow(
+                title="_Profanity Filter",
+                subtitle="Mask or remove swear words before text is typed",
+                widget=self.profanity_combo,
+            )
+        )
+
+        self.profanity_words_entry = Gtk.Entry()
+        self.profanity_words_entry.set_placeholder_text("Comma-separated words")
+        self.profanity_words_entry.set_width_chars(24)
+        self.profanity_words_entry.set_valign(Gtk.Align.CENTER)
+        formatting_group.add_row(
+            PreferenceRow(
+                title="E_xtra Filtered Words",
+                subtitle="Also filtered, on top of the built-in list for your language",
+                widget=self.profanity_words_entry,
+            )
+        )
+
+        self.preserved_terms_entry = Gtk.Entry()
+        self.preserved_terms_entry.set_placeholder_text("API, GmbH, PostgreSQL")
+        self.preserved_terms_entry.set_width_chars(24)
+        self.preserved_terms_entry.set_valign(Gtk.Align.CENTER)
+        formatting_group.add_row(
+            PreferenceRow(
+                title="Preserved _Capitalization",
+                subtitle="Comma-separated terms always typed exactly as written here",
+                widget=self.preserved_terms_entry,
+            )
+        )
+        self.recognition_settings_tab.pack_start(formatting_group, False, False, 0)
+
         if not silero_active:
             vad_info_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=8)
             vad_info_box.get_style_context().add_class("info-box")
@@ -1611,8 +2982,29 @@ class SettingsDialog(Gtk.Dialog):
 
         # Connect signals
         self.vad_spin.connect("value-changed", self._on_vad_changed)
+        self.vad_backend_combo.connect("changed", self._on_vad_backend_changed)
         self.silence_spin.connect("value-changed", self._on_silence_changed)
+        self.adaptive_silence_switch.connect("state-set", self._on_adaptive_silence_toggled)
+        self.max_utterance_spin.connect("value-changed", self._on_max_utterance_changed)
+        self.join_window_spin.connect("value-changed", self._on_join_window_changed)
+        self.long_form_switch.connect("state-set", self._on_long_form_toggled)
+        self.partial_results_switch.connect("state-set", self._on_partial_results_toggled)
+        self.speaker_profile_switch.connect("state-set", self._on_speaker_profile_toggled)
+        self.rewrite_combo.connect("changed", self._on_rewrite_changed)
+        self.translate_switch.connect("state-set", self._on_translate_toggled)
+        self.tag_language_switch.connect("state-set", self._on_tag_language_toggled)
         self.voice_commands_switch.connect("state-set", self._on_voice_commands_toggled)
+        self.disabled_commands_entry.connect("changed", self._on_custom_commands_changed)
+        self.renamed_commands_entry.connect("changed", self._on_custom_commands_changed)
+        self.app_prompts_entry.connect("changed", self._on_app_prompts_changed)
+        self.readback_switch.connect("state-set", self._on_readback_toggled)
+        self.intent_commands_switch.connect("state-set", self._on_intent_commands_toggled)
+        self.punctuation_combo.connect("changed", self._on_punctuation_changed)
+        for key, switch in self.number_format_switches.items():
+            switch.connect("state-set", self._on_number_format_toggled, key)
+        self.profanity_combo.connect("changed", self._on_profanity_filter_changed)
+        self.profanity_words_entry.connect("changed", self._on_profanity_words_changed)
+        self.preserved_terms_entry.connect("changed", self._on_preserved_terms_changed)
 
     def _build_shortcuts_section(self):
         """Build the Keyboard Shortcuts section."""
@@ -1639,7 +3031,7 @@ class SettingsDialog(Gtk.Dialog):
             self.shortcut_mode_combo.set_active_id("toggle")
 
         mode_row = PreferenceRow(
-            title="Shortcut Mode",
+            title="Shortcut _Mode",
             subtitle="How the shortcut behaves",
             widget=self.shortcut_mode_combo,
         )
@@ -1697,7 +3089,7 @@ class SettingsDialog(Gtk.Dialog):
         custom_box.pack_start(self.set_custom_shortcut_button, False, False, 0)
 
         self.custom_shortcut_row = PreferenceRow(
-            title="Custom Shortcut",
+            title="C_ustom Shortcut",
             subtitle="Modifier + key combo (great for split keyboards)",
             widget=custom_box,
         )
@@ -1729,6 +3121,36 @@ class SettingsDialog(Gtk.Dialog):
 
         self.shortcuts_tab.pack_start(info_box, False, False, 0)
 
+        # Panic shortcut: a separate combo that stops everything and discards audio
+        panic_group = PreferencesGroup(
+            title="Panic Shortcut",
+            description="Stop dictation instantly and throw away everything captured",
+        )
+        panic_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=6)
+        self.panic_shortcut_entry = Gtk.Entry()
+        self.panic_shortcut_entry.set_placeholder_text("e.g. ctrl+alt+m")
+        self.panic_shortcut_entry.set_width_chars(12)
+        self.panic_shortcut_entry.set_tooltip_text(
+            "A modifier plus a key, e.g. ctrl+alt+m. Leave empty to disable."
+        )
+        self.panic_shortcut_entry.set_text(self.config_manager.get_str("shortcuts", "panic", ""))
+        self.panic_shortcut_entry.connect("activate", self._on_panic_shortcut_apply)
+        panic_box.pack_start(self.panic_shortcut_entry, False, False, 0)
+
+        set_panic_button = Gtk.Button(label="Set")
+        set_panic_button.set_tooltip_text("Apply the typed panic shortcut")
+        set_panic_button.connect("clicked", self._on_panic_shortcut_apply)
+        panic_box.pack_start(set_panic_button, False, False, 0)
+
+        panic_group.add_row(
+            PreferenceRow(
+                title="_Panic Shortcut",
+                subtitle="Nothing is transcribed or typed; cloud connections are closed",
+                widget=panic_box,
+            )
+        )
+        self.shortcuts_tab.pack_start(panic_group, False, False, 0)
+
         # Reflect active shortcut in combo + custom entry (preset vs custom).
         self._sync_shortcut_selection_ui(current_shortcut)
 
@@ -1823,6 +3245,36 @@ class SettingsDialog(Gtk.Dialog):
             applied = bool(self.shortcut_update_callback(shortcut, mode_id))
         self._report_shortcut_apply_result(display_name, applied)
 
+    def _on_panic_shortcut_apply(self, widget):
+        """Validate, persist, and live-apply the panic shortcut."""
+        if self._initializing:
+            return
+
+        shortcut = self.panic_shortcut_entry.get_text().strip().lower()
+        is_combo = is_valid_shortcut(shortcut) and parse_shortcut_spec(shortcut).is_combo
+        if shortcut and not is_combo:
+            self.shortcut_info_label.set_markup(
+                f"<span foreground='#e01b24'>Invalid panic shortcut: "
+                f"<b>{GLib.markup_escape_text(shortcut)}</b>. "
+                "Use a modifier + key, e.g. ctrl+alt+m.</span>"
+            )
+            return
+
+        self.config_manager.set("shortcuts", "panic", shortcut)
+        self.config_manager.save_settings()
+
+        applied = False
+        if self.panic_shortcut_update_callback:
+            applied = bool(self.panic_shortcut_update_callback(shortcut))
+
+        if not shortcut:
+            self.shortcut_info_label.set_markup("<i>Panic shortcut disabled.</i>")
+            logger.info("Panic shortcut disabled")
+            return
+        display_name = format_shortcut_label(parse_shortcut_spec(shortcut))
+        logger.info(f"Panic shortcut changed to: {display_name}")
+        self._report_shortcut_apply_result(f"{display_name} (panic)", applied)
+
     def _on_custom_shortcut_apply(self, widget):
         """Handle Set button / Entry activation for a typed custom shortcut."""
         if self._initializing:
@@ -2096,7 +3548,7 @@ class SettingsDialog(Gtk.Dialog):
         self.power_user_switch = Gtk.Switch()
         self.power_user_switch.set_tooltip_text("Reveal advanced whisper.cpp tuning parameters")
         power_user_row = PreferenceRow(
-            title="Unlock Advanced Settings",
+            title="Un_lock Advanced Settings",
             subtitle="I know what I'm doing — show me the whisper.cpp tuning knobs",
             widget=self.power_user_switch,
         )
@@ -2117,7 +3569,7 @@ class SettingsDialog(Gtk.Dialog):
             "Disable timestamp generation to reduce hallucinations"
         )
         no_timestamps_row = PreferenceRow(
-            title="No Timestamps",
+            title="_No Timestamps",
             subtitle="Disable timestamp tokens (reduces hallucinations)",
             widget=self.advanced_no_timestamps_switch,
         )
@@ -2128,7 +3580,7 @@ class SettingsDialog(Gtk.Dialog):
             "Do not condition on previously transcribed text"
         )
         no_context_row = PreferenceRow(
-            title="No Context",
+            title="No Conte_xt",
             subtitle="Prevent error loops from past text",
             widget=self.advanced_no_context_switch,
         )
@@ -2141,7 +3593,7 @@ class SettingsDialog(Gtk.Dialog):
         )
         _prevent_scroll_on_hover(self.advanced_temperature_spin)
         temperature_row = PreferenceRow(
-            title="Temperature",
+            title="Tem_perature",
             subtitle="Decoding randomness (0.0 = deterministic)",
             widget=self.advanced_temperature_spin,
         )
@@ -2154,7 +3606,7 @@ class SettingsDialog(Gtk.Dialog):
         )
         _prevent_scroll_on_hover(self.advanced_temperature_inc_spin)
         temperature_inc_row = PreferenceRow(
-            title="Temperature Increment",
+            title="Temperature _Increment",
             subtitle="Fallback step (-1.0 = disabled)",
             widget=self.advanced_temperature_inc_spin,
         )
@@ -2167,7 +3619,7 @@ class SettingsDialog(Gtk.Dialog):
         )
         _prevent_scroll_on_hover(self.advanced_entropy_thold_spin)
         entropy_row = PreferenceRow(
-            title="Entropy Threshold",
+            title="_Entropy Threshold",
             subtitle="Repetition loop detection",
             widget=self.advanced_entropy_thold_spin,
         )
@@ -2180,7 +3632,7 @@ class SettingsDialog(Gtk.Dialog):
         )
         _prevent_scroll_on_hover(self.advanced_logprob_thold_spin)
         logprob_row = PreferenceRow(
-            title="Logprob Threshold",
+            title="Logpro_b Threshold",
             subtitle="Fallback trigger for low confidence",
             widget=self.advanced_logprob_thold_spin,
         )
@@ -2193,7 +3645,7 @@ class SettingsDialog(Gtk.Dialog):
         )
         _prevent_scroll_on_hover(self.advanced_no_speech_thold_spin)
         no_speech_row = PreferenceRow(
-            title="No-Speech Threshold",
+            title="No-Speec_h Threshold",
             subtitle="Silence detection confidence",
             widget=self.advanced_no_speech_thold_spin,
         )
@@ -2216,7 +3668,7 @@ class SettingsDialog(Gtk.Dialog):
         prompt_scrolled.add(self.advanced_initial_prompt_textview)
 
         initial_prompt_row = PreferenceRow(
-            title="Initial Prompt",
+            title="Initial Pro_mpt",
             subtitle="Context to steer transcription style",
             widget=prompt_scrolled,
         )
@@ -2251,6 +3703,28 @@ class SettingsDialog(Gtk.Dialog):
         self.advanced_revealer.add(controls_box)
         self.advanced_tab.pack_start(self.advanced_revealer, False, False, 0)
 
+        # Engine comparison: a debugging aid for choosing an engine
+        comparison_group = PreferencesGroup(title="Engine Comparison")
+        self.comparison_engine_combo = Gtk.ComboBoxText()
+        self.comparison_engine_combo.append("off", "Off")
+        for engine, available in get_available_engines().items():
+            if available:
+                self.comparison_engine_combo.append(engine, _engine_display_name(engine))
+        self.comparison_engine_combo.set_tooltip_text(
+            "Also transcribe every utterance with a second engine and show both results\n"
+            "in the status window. Only the main engine's text is typed. Uses extra\n"
+            "memory and CPU/GPU, and the second engine's model must be downloaded."
+        )
+        _prevent_scroll_on_hover(self.comparison_engine_combo)
+        comparison_row = PreferenceRow(
+            title="Compare _With",
+            subtitle="Show a second engine's transcript next to the main one",
+            widget=self.comparison_engine_combo,
+        )
+        comparison_group.add_row(comparison_row)
+        self.advanced_tab.pack_start(comparison_group, False, False, 0)
+        self.comparison_engine_combo.connect("changed", self._on_comparison_engine_changed)
+
         self.advanced_no_timestamps_switch.connect("state-set", self._on_advanced_param_changed)
         self.advanced_no_context_switch.connect("state-set", self._on_advanced_param_changed)
         self.advanced_temperature_spin.connect("value-changed", self._on_advanced_param_changed)
@@ -2264,6 +3738,24 @@ class SettingsDialog(Gtk.Dialog):
 
         self.power_user_switch.connect("state-set", self._on_power_user_toggled)
 
+    def _on_comparison_engine_changed(self, widget):
+        """Handle selection of the engine used for comparison."""
+        if self._initializing or self._applying_settings:
+            return
+
+        engine_id = self.comparison_engine_combo.get_active_id()
+        engine = None if engine_id in (None, "off") else engine_id
+        model_size = self.config_manager.get_model_size_for_engine(engine) if engine else None
+        self.config_manager.set("speech_recognition", "comparison_engine", engine)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(
+                comparison_engine=engine, comparison_model_size=model_size, force_download=False
+            )
+        except Exception as e:
+            logger.warning(f"Failed to apply comparison engine: {e}")
+        logger.info(f"Engine comparison {'with ' + engine if engine else 'off'}")
+
     def _build_remote_server_section(self):
         """Build the Remote Server configuration section (shown when Remote API engine is selected)."""
         self.remote_server_group = PreferencesGroup(
@@ -2283,12 +3775,49 @@ class SettingsDialog(Gtk.Dialog):
         )
         self.remote_api_url_entry.set_size_request(280, -1)
         remote_url_row = PreferenceRow(
-            title="Server URL",
+            title="Server _URL",
             subtitle="Remote speech recognition server address",
             widget=self.remote_api_url_entry,
         )
         self.remote_server_group.add_row(remote_url_row)
 
+        # Account; each one keeps its own API key in the keyring
+        account_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=8)
+        self.remote_api_account_combo = Gtk.ComboBoxText()
+        self.remote_api_account_combo.set_tooltip_text(
+            "Account whose API key is used, e.g. separate work and personal keys"
+        )
+        self.remote_api_account_combo.set_size_request(200, -1)
+        _prevent_scroll_on_hover(self.remote_api_account_combo)
+        account_box.pack_start(self.remote_api_account_combo, True, True, 0)
+
+        add_account_btn = Gtk.Button.new_from_icon_name("list-add-symbolic", Gtk.IconSize.BUTTON)
+        add_account_btn.set_tooltip_text("Add account")
+        add_account_btn.get_accessible().set_name("Add account")
+        add_account_btn.get_style_context().add_class("flat-button")
+        add_account_btn.connect("clicked", self._on_add_remote_api_account)
+        account_box.pack_start(add_account_btn, False, False, 0)
+
+        self.remove_account_btn = Gtk.Button.new_from_icon_name(
+            "list-remove-symbolic", Gtk.IconSize.BUTTON
+        )
+        self.remove_account_btn.set_tooltip_text("Remove account and its API key")
+        self.remove_account_btn.get_accessible().set_name("Remove account")
+        self.remove_account_btn.get_style_context().add_class("flat-button")
+        self.remove_account_btn.connect("clicked", self._on_remove_remote_api_account)
+        account_box.pack_start(self.remove_account_btn, False, False, 0)
+
+        remote_account_row = PreferenceRow(
+            title="_Account",
+            subtitle=(
+                "Keys are kept in the system keyring"
+                if credentials.keyring_available()
+                else "Install the keyring package to keep a key per account"
+            ),
+            widget=account_box,
+        )
+        self.remote_server_group.add_row(remote_account_row)
+
         # API Key
         self.remote_api_key_entry = Gtk.Entry()
         self.remote_api_key_entry.set_placeholder_text("(optional)")
@@ -2296,7 +3825,7 @@ class SettingsDialog(Gtk.Dialog):
         self.remote_api_key_entry.set_tooltip_text("API Key for authentication (optional)")
         self.remote_api_key_entry.set_size_request(280, -1)
         remote_key_row = PreferenceRow(
-            title="API Key",
+            title="API _Key",
             subtitle="Authentication key (optional)",
             widget=self.remote_api_key_entry,
         )
@@ -2314,7 +3843,7 @@ class SettingsDialog(Gtk.Dialog):
         self.remote_api_endpoint_combo.append("/inference", "Whisper.cpp (/inference)")
         _prevent_scroll_on_hover(self.remote_api_endpoint_combo)
         remote_endpoint_row = PreferenceRow(
-            title="API Endpoint",
+            title="API En_dpoint",
             subtitle="API format for the remote server",
             widget=self.remote_api_endpoint_combo,
         )
@@ -2328,18 +3857,56 @@ class SettingsDialog(Gtk.Dialog):
         )
         self.remote_api_model_entry.set_size_request(280, -1)
         remote_model_row = PreferenceRow(
-            title="Model",
+            title="M_odel",
             subtitle="Remote model name, for example whisper-1 or sensevoice",
             widget=self.remote_api_model_entry,
         )
         self.remote_server_group.add_row(remote_model_row)
 
+        # Local engine used while the server can't be reached
+        self.offline_fallback_combo = Gtk.ComboBoxText()
+        self.offline_fallback_combo.append("off", "Off")
+        if get_available_engines().get("whisper_cpp"):
+            engine_name = _engine_display_name("whisper_cpp")
+            for size in WHISPERCPP_MODEL_SIZES:
+                for variant in get_whispercpp_model_variants(size):
+                    if is_whispercpp_model_downloaded(variant):
+                        label = f"{engine_name} {variant}"
+                        self.offline_fallback_combo.append(f"whisper_cpp:{variant}", label)
+        self.offline_fallback_combo.set_tooltip_text(
+            "Transcribe on this computer with this model when the server can't be\n"
+            "reached, for example when the network drops mid-dictation.\n"
+            "Lists downloaded whisper.cpp models; a small one such as tiny loads fastest."
+        )
+        _prevent_scroll_on_hover(self.offline_fallback_combo)
+        self.remote_server_group.add_row(
+            PreferenceRow(
+                title="Offl_ine Fallback",
+                subtitle="Keep dictating when the server is unreachable",
+                widget=self.offline_fallback_combo,
+            )
+        )
+
+        self.offline_fallback_standby_switch = Gtk.Switch()
+        self.offline_fallback_standby_switch.set_tooltip_text(
+            "Load the offline fallback model as soon as the remote engine starts, so it\n"
+            "takes over within a second instead of after the model has loaded.\n"
+            "Uses the model's memory even while the server works."
+        )
+        self.remote_server_group.add_row(
+            PreferenceRow(
+                title="Keep Fallback _Warm",
+                subtitle="Keep the fallback model loaded",
+                widget=self.offline_fallback_standby_switch,
+            )
+        )
+
         # Connection test
         self.remote_test_btn = Gtk.Button(label="Test Connection")
         self.remote_test_btn.set_tooltip_text("Test connection to remote server")
         self.remote_test_btn.connect("clicked", self._on_test_remote_connection)
         remote_test_row = PreferenceRow(
-            title="Connection Test",
+            title="Co_nnection Test",
             subtitle="Verify remote server is reachable",
             widget=self.remote_test_btn,
         )
@@ -2356,7 +3923,19 @@ class SettingsDialog(Gtk.Dialog):
 
         # Load saved values into the widgets
         saved_url = self.config_manager.get("speech_recognition", "remote_api_url", "")
-        saved_key = self.config_manager.get("speech_recognition", "remote_api_key", "")
+        accounts = self.config_manager.get(
+            "speech_recognition", "remote_api_accounts", [credentials.DEFAULT_ACCOUNT]
+        ) or [credentials.DEFAULT_ACCOUNT]
+        saved_account = self.config_manager.get(
+            "speech_recognition", "remote_api_account", credentials.DEFAULT_ACCOUNT
+        )
+        if saved_account not in accounts:
+            accounts = accounts + [saved_account]
+        for account in accounts:
+            self.remote_api_account_combo.append(account, account)
+        self.remote_api_account_combo.set_active_id(saved_account)
+        self.remove_account_btn.set_sensitive(len(accounts) > 1)
+        saved_key = self._load_remote_api_key(saved_account)
         saved_endpoint = self.config_manager.get(
             "speech_recognition", "remote_api_endpoint", "/inference"
         )
@@ -2367,11 +3946,27 @@ class SettingsDialog(Gtk.Dialog):
             self.remote_api_key_entry.set_text(saved_key)
         self.remote_api_endpoint_combo.set_active_id(saved_endpoint)
         self.remote_api_model_entry.set_text(saved_model or "whisper-1")
+        fallback_engine = self.config_manager.get("speech_recognition", "offline_fallback_engine")
+        fallback_model_size = self.config_manager.get(
+            "speech_recognition", "offline_fallback_model_size", "tiny"
+        )
+        if not fallback_engine or not self.offline_fallback_combo.set_active_id(
+            f"{fallback_engine}:{fallback_model_size}"
+        ):
+            self.offline_fallback_combo.set_active_id("off")
+        self.offline_fallback_standby_switch.set_active(
+            bool(self.config_manager.get("speech_recognition", "offline_fallback_standby", False))
+        )
 
+        self.remote_api_account_combo.connect("changed", self._on_remote_api_account_changed)
         self.remote_api_url_entry.connect("changed", self._on_remote_api_settings_changed)
         self.remote_api_key_entry.connect("changed", self._on_remote_api_settings_changed)
         self.remote_api_endpoint_combo.connect("changed", self._on_remote_api_settings_changed)
         self.remote_api_model_entry.connect("changed", self._on_remote_api_settings_changed)
+        self.offline_fallback_combo.connect("changed", self._on_offline_fallback_changed)
+        self.offline_fallback_standby_switch.connect(
+            "state-set", self._on_offline_fallback_standby_toggled
+        )
 
         self.remote_server_group.hide()
         self.remote_status_label.hide()
@@ -2421,6 +4016,7 @@ class SettingsDialog(Gtk.Dialog):
         if page_num != self.advanced_page_num:
             self._flush_advanced_prompt_if_dirty()
         self._update_advanced_reset_button_visibility(page_num)
+        self._update_live_level(page_num)
 
     def _update_advanced_reset_button_visibility(self, page_num: int = None):
         """Show the reset action only on the Advanced settings page."""
@@ -2490,11 +4086,54 @@ class SettingsDialog(Gtk.Dialog):
         autostart_enabled = general_settings.get("autostart", False)
         start_minimized = ui_settings.get("start_minimized", False)
         copy_to_clipboard = text_injection_settings.get("copy_to_clipboard", False)
+        stop_on_focus_change = text_injection_settings.get("stop_on_focus_change", False)
 
         self.autostart_switch.set_active(autostart_enabled)
         self.start_minimized_switch.set_active(start_minimized)
+        self.capture_overlay_switch.set_active(bool(ui_settings.get("capture_overlay", False)))
         self.copy_to_clipboard_switch.set_active(copy_to_clipboard)
-        self.sound_effects_switch.set_active(self.config_manager.is_sound_effects_enabled())
+        self.stop_on_focus_change_switch.set_active(stop_on_focus_change)
+        self.word_target_spin.set_value(self.config_manager.get("general", "word_target", 0) or 0)
+        self.timed_session_spin.set_value(
+            self.config_manager.get_int("general", "timed_session_minutes", 30)
+        )
+        utterance_log = self.config_manager.get("general", "utterance_log", "off")
+        if not self.utterance_log_combo.set_active_id(utterance_log):
+            self.utterance_log_combo.set_active_id("off")
+        self.transcript_history_spin.set_value(
+            self.config_manager.get_int("ui", "transcript_history_size", 10)
+        )
+        self.retry_audio_switch.set_active(bool(general_settings.get("retry_audio", False)))
+        sound_effects_enabled = self.config_manager.is_sound_effects_enabled()
+        self.sound_effects_switch.set_active(sound_effects_enabled)
+        self.utterance_sound_switch.set_active(
+            bool(self.config_manager.get("sound_effects", "utterance", False))
+        )
+        self.utterance_sound_switch.set_sensitive(sound_effects_enabled)
+
+        audio_settings = self.config_manager.get_settings().get("audio", {})
+        sample_rate = audio_settings.get("sample_rate")
+        if not sample_rate or not self.sample_rate_combo.set_active_id(str(sample_rate)):
+            self.sample_rate_combo.set_active_id("auto")
+        sidetone_enabled = bool(audio_settings.get("sidetone_enabled", False))
+        self.sidetone_switch.set_active(sidetone_enabled)
+        self.sidetone_volume_spin.set_value(round(audio_settings.get("sidetone_volume", 0.3) * 100))
+        self.sidetone_volume_spin.set_sensitive(sidetone_enabled)
+        duck_media = bool(audio_settings.get("duck_media", False))
+        self.duck_media_switch.set_active(duck_media)
+        self.duck_level_spin.set_value(round(audio_settings.get("duck_level", 0.3) * 100))
+        self.duck_level_spin.set_sensitive(duck_media)
+        self.echo_cancel_switch.set_active(bool(audio_settings.get("echo_cancel", False)))
+        self.noise_suppression_switch.set_active(
+            bool(audio_settings.get("noise_suppression", False))
+        )
+        self._show_input_gain()
+
+        download_settings = self.config_manager.get_settings().get("downloads", {})
+        if not self.download_mirror_combo.set_active_id(download_settings.get("mirror")):
+            self.download_mirror_combo.set_active_id("official")
+        self.vosk_mirror_entry.set_text(download_settings.get("vosk_mirror_url") or "")
+        self.download_limit_spin.set_value(download_settings.get("bandwidth_limit_kbps") or 0)
 
         available_engines = get_available_engines()
         available_count = 0
@@ -2545,11 +4184,90 @@ class SettingsDialog(Gtk.Dialog):
 
         # Set spin button values
         self.vad_spin.set_value(self.current_vad)
+        vad_backend = self.config_manager.get("speech_recognition", "vad_backend", "auto")
+        if not self.vad_backend_combo.set_active_id(vad_backend):
+            self.vad_backend_combo.set_active_id("auto")
         self.silence_spin.set_value(self.current_silence)
+        self.adaptive_silence_switch.set_active(
+            self.config_manager.get_bool("speech_recognition", "adaptive_silence_timeout", False)
+        )
+        self._update_level_threshold_marker()
+
+        self.max_utterance_spin.set_value(
+            self.config_manager.get_int("speech_recognition", "max_utterance_seconds", 30)
+        )
+        self.join_window_spin.set_value(
+            self.config_manager.get("speech_recognition", "utterance_join_window", 0.0)
+        )
+        self.long_form_switch.set_active(
+            self.config_manager.get_bool("speech_recognition", "long_form_mode", False)
+        )
+        self.partial_results_switch.set_active(
+            self.config_manager.get_bool("speech_recognition", "partial_results", False)
+        )
+        self.speaker_profile_switch.set_active(
+            self.config_manager.get_bool("speech_recognition", "vosk_speaker_profile", False)
+        )
+        self.gpu_reserve_spin.set_value(
+            self.config_manager.get_int("speech_recognition", "gpu_memory_reserve_mb", 0)
+        )
+        rewrite_engine = self.config_manager.get("speech_recognition", "rewrite_engine")
+        rewrite_model_size = self.config_manager.get(
+            "speech_recognition", "rewrite_model_size", "small"
+        )
+        if not rewrite_engine or not self.rewrite_combo.set_active_id(
+            f"{rewrite_engine}:{rewrite_model_size}"
+        ):
+            self.rewrite_combo.set_active_id("off")
+        self.translate_switch.set_active(
+            self.config_manager.get_bool("speech_recognition", "translate", False)
+        )
+        self.tag_language_switch.set_active(
+            self.config_manager.get_bool("speech_recognition", "tag_language", False)
+        )
+        comparison_engine = self.config_manager.get("speech_recognition", "comparison_engine")
+        if not comparison_engine or not self.comparison_engine_combo.set_active_id(
+            comparison_engine
+        ):
+            self.comparison_engine_combo.set_active_id("off")
 
         # Set voice commands switch based on config
         voice_commands_enabled = self.config_manager.is_voice_commands_enabled()
         self.voice_commands_switch.set_active(voice_commands_enabled)
+        self.disabled_commands_entry.set_text(
+            ", ".join(self.config_manager.get("speech_recognition", "disabled_commands", []) or [])
+        )
+        renamed_commands = self.config_manager.get("speech_recognition", "renamed_commands", {})
+        self.renamed_commands_entry.set_text(
+            ", ".join(f"{old} = {new}" for old, new in (renamed_commands or {}).items())
+        )
+        app_prompts = self.config_manager.get("speech_recognition", "app_prompts", {})
+        self.app_prompts_entry.set_text(
+            ", ".join(f"{app} = {preset}" for app, preset in (app_prompts or {}).items())
+        )
+        self.readback_switch.set_active(
+            self.config_manager.get_bool("text_to_speech", "readback_enabled", False)
+        )
+        self.intent_commands_switch.set_active(
+            self.config_manager.get_bool("speech_recognition", "intent_commands_enabled", False)
+        )
+        if not self.punctuation_combo.set_active_id(
+            self.config_manager.get("speech_recognition", "punctuation") or "default"
+        ):
+            self.punctuation_combo.set_active_id("default")
+        for key, switch in self.number_format_switches.items():
+            switch.set_active(self.config_manager.get_bool("formatting", key, False))
+        if not self.profanity_combo.set_active_id(
+            self.config_manager.get("formatting", "profanity_filter", "off")
+        ):
+            self.profanity_combo.set_active_id("off")
+        self.profanity_words_entry.set_sensitive(self.profanity_combo.get_active_id() != "off")
+        self.profanity_words_entry.set_text(
+            ", ".join(self.config_manager.get("formatting", "profanity_words", []) or [])
+        )
+        self.preserved_terms_entry.set_text(
+            ", ".join(self.config_manager.get("formatting", "preserved_terms", []) or [])
+        )
 
         advanced_settings = self.config_manager.get_settings().get("advanced", {})
         power_user_mode = advanced_settings.get("power_user_mode", False)
@@ -2589,8 +4307,9 @@ class SettingsDialog(Gtk.Dialog):
         engine = sr_settings.get("engine", "vosk")
         language = sr_settings.get("language", "en-us")
         model_size = self.config_manager.get_model_size_for_engine(engine)
-        vad_sensitivity = sr_settings.get("vad_sensitivity", 3)
-        silence_timeout = sr_settings.get("silence_timeout", 2.0)
+        # The values in effect, which may be the engine's own engine_vad override
+        vad_sensitivity = self.config_manager.get_vad_setting(engine, "vad_sensitivity", 3)
+        silence_timeout = self.config_manager.get_vad_setting(engine, "silence_timeout", 2.0)
 
         logger.info(
             f"Loaded current settings: engine={engine}, language={language}, model_size={model_size}, "
@@ -2865,12 +4584,28 @@ class SettingsDialog(Gtk.Dialog):
             elif engine in ["whisper", "whisper_cpp", "remote_api"] and not current_lang:
                 self.language = "auto"
 
+        self._show_vad_settings_for_engine(engine)
         self._populate_model_options()
         self._sync_language_options_for_selected_model(self.language)
         self._update_engine_specific_ui()
         self._update_model_info()
         self._update_voice_commands_for_engine()
 
+    def _show_vad_settings_for_engine(self, engine: str):
+        """Show the VAD settings in effect for an engine, which may have its own in engine_vad."""
+        applying = self._applying_settings
+        # Applied along with the engine change, not on their own with the old engine
+        self._applying_settings = True
+        try:
+            self.vad_spin.set_value(
+                self.config_manager.get_vad_setting(engine, "vad_sensitivity", 3)
+            )
+            self.silence_spin.set_value(
+                self.config_manager.get_vad_setting(engine, "silence_timeout", 2.0)
+            )
+        finally:
+            self._applying_settings = applying
+
     def _update_voice_commands_for_engine(self):
         """Update voice commands switch based on current engine."""
         sr_config = self.config_manager.get_settings().get("speech_recognition", {})
@@ -2896,6 +4631,8 @@ class SettingsDialog(Gtk.Dialog):
                 finally:
                     self._populating_models = False
                 self._sync_language_options_for_selected_model()
+        elif self._get_selected_engine() == "vosk":
+            self._sync_language_options_for_selected_model()
 
         self._update_model_info()
         self._auto_apply_settings()
@@ -2911,12 +4648,256 @@ class SettingsDialog(Gtk.Dialog):
 
     def _on_vad_changed(self, widget):
         """Handle changes in VAD sensitivity."""
+        self._update_level_threshold_marker()
         self._auto_apply_settings()
 
+    def _on_calibrate_clicked(self, widget):
+        """Open the microphone calibration dialog for the selected input."""
+        from .calibration_dialog import CalibrationDialog
+
+        device_index, monitor_source = self._selected_capture()
+        CalibrationDialog(
+            self,
+            on_apply=lambda sensitivity: self.vad_spin.set_value(sensitivity),
+            device_index=device_index,
+            gain=self.input_gain_spin.get_value() / 100,
+            monitor_source=monitor_source,
+        )
+
+    def _on_vad_backend_changed(self, widget):
+        """Handle selection of the VAD backend."""
+        if self._initializing or self._applying_settings:
+            return
+
+        backend = self.vad_backend_combo.get_active_id() or "auto"
+        self.config_manager.set("speech_recognition", "vad_backend", backend)
+        self.config_manager.save_settings()
+        self._update_level_threshold_marker()
+        try:
+            self.speech_engine.reconfigure(vad_backend=backend, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply VAD backend: {e}")
+        logger.info(f"VAD backend: {backend}")
+
+    def _update_level_threshold_marker(self):
+        """Color the audio level meter below the amplitude VAD's speech threshold."""
+        backend = self.vad_backend_combo.get_active_id() or "auto"
+        if backend in ("webrtc", "silero") or (backend == "auto" and is_silero_available()):
+            # These decide on the spectrum or speech probability, so no level is the threshold
+            self.recognition_audio_level.remove_offset_value(Gtk.LEVEL_BAR_OFFSET_LOW)
+            self.recognition_audio_level.set_tooltip_text(None)
+            return
+        threshold = dbfs_to_percent(vad_threshold_dbfs(self.vad_spin.get_value_as_int()))
+        self.recognition_audio_level.add_offset_value(Gtk.LEVEL_BAR_OFFSET_LOW, threshold)
+        self.recognition_audio_level.set_tooltip_text(
+            "Levels below the low mark are treated as silence.\n"
+            "In a noisy room the threshold rises above the background noise."
+        )
+
     def _on_silence_changed(self, widget):
         """Handle changes in silence timeout."""
         self._auto_apply_settings()
 
+    def _on_adaptive_silence_toggled(self, widget, state):
+        """Handle toggle of the adaptive silence timeout switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "adaptive_silence_timeout", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(adaptive_silence_timeout=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply adaptive silence timeout immediately: {e}")
+        logger.info(f"Adaptive silence timeout {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_max_utterance_changed(self, widget):
+        """Handle changes in the maximum utterance length."""
+        if self._initializing or self._applying_settings:
+            return
+
+        seconds = self.max_utterance_spin.get_value_as_int()
+        self.config_manager.set("speech_recognition", "max_utterance_seconds", seconds)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(max_utterance_seconds=seconds, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply maximum utterance length immediately: {e}")
+
+    def _on_join_window_changed(self, widget):
+        """Handle changes in the utterance join window."""
+        if self._initializing or self._applying_settings:
+            return
+
+        window = round(self.join_window_spin.get_value(), 1)
+        self.config_manager.set("speech_recognition", "utterance_join_window", window)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(utterance_join_window=window, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply utterance join window immediately: {e}")
+
+    def _on_long_form_toggled(self, widget, state):
+        """Handle toggle of the long-form mode switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "long_form_mode", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(long_form_mode=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply long-form mode immediately: {e}")
+        logger.info(f"Long-form mode {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_partial_results_toggled(self, widget, state):
+        """Handle toggle of the type-while-speaking switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "partial_results", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(partial_results=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply partial results immediately: {e}")
+        logger.info(f"Partial results {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_speaker_profile_toggled(self, widget, state):
+        """Handle toggle of the recognize-only-my-voice switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "vosk_speaker_profile", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(vosk_speaker_profile=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply the speaker profile immediately: {e}")
+        logger.info(f"Speaker profile {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_enroll_voice_clicked(self, widget):
+        """Open the voice enrollment dialog for the selected microphone."""
+        from .enrollment_dialog import EnrollmentDialog
+
+        device_index, _monitor_source = self._selected_capture()
+        EnrollmentDialog(
+            self,
+            enroll=self.speech_engine.enroll_speaker,
+            device_index=device_index,
+            gain=self.input_gain_spin.get_value() / 100,
+        )
+
+    def _on_gpu_reserve_changed(self, widget):
+        """Handle changes in the VRAM kept free for other programs."""
+        if self._initializing or self._applying_settings:
+            return
+
+        reserve_mb = self.gpu_reserve_spin.get_value_as_int()
+        self.config_manager.set("speech_recognition", "gpu_memory_reserve_mb", reserve_mb)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(gpu_memory_reserve_mb=reserve_mb, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply the GPU memory reserve: {e}")
+
+    def _on_rewrite_changed(self, widget):
+        """Handle selection of the model that rewrites drafts."""
+        if self._initializing or self._applying_settings:
+            return
+
+        rewrite_id = self.rewrite_combo.get_active_id()
+        engine, model_size = None, None
+        if rewrite_id and rewrite_id != "off":
+            engine, model_size = rewrite_id.split(":", 1)
+        self.config_manager.set("speech_recognition", "rewrite_engine", engine)
+        if model_size:
+            self.config_manager.set("speech_recognition", "rewrite_model_size", model_size)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(
+                rewrite_engine=engine, rewrite_model_size=model_size, force_download=False
+            )
+        except Exception as e:
+            logger.warning(f"Failed to apply the rewrite model: {e}")
+        logger.info(f"Draft rewriting {'with ' + rewrite_id if engine else 'off'}")
+
+    def _on_offline_fallback_changed(self, widget):
+        """Handle selection of the model used while the remote server is unreachable."""
+        if self._initializing or self._applying_settings:
+            return
+
+        fallback_id = self.offline_fallback_combo.get_active_id()
+        engine, model_size = None, None
+        if fallback_id and fallback_id != "off":
+            engine, model_size = fallback_id.split(":", 1)
+        self.config_manager.set("speech_recognition", "offline_fallback_engine", engine)
+        if model_size:
+            self.config_manager.set("speech_recognition", "offline_fallback_model_size", model_size)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(
+                offline_fallback_engine=engine,
+                offline_fallback_model_size=model_size,
+                force_download=False,
+            )
+        except Exception as e:
+            logger.warning(f"Failed to apply the offline fallback model: {e}")
+        logger.info(f"Offline fallback {'with ' + fallback_id if engine else 'off'}")
+
+    def _on_offline_fallback_standby_toggled(self, widget, state):
+        """Handle toggle of keeping the offline fallback model loaded."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "offline_fallback_standby", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(offline_fallback_standby=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply offline fallback standby: {e}")
+        logger.info(f"Offline fallback standby {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_translate_toggled(self, widget, state):
+        """Handle toggle of the translate-to-English switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "translate", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(translate=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply translation immediately: {e}")
+        logger.info(f"Translation to English {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_tag_language_toggled(self, widget, state):
+        """Handle toggle of the detected-language tag switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "tag_language", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(tag_language=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply language tagging immediately: {e}")
+        logger.info(f"Language tagging {'enabled' if enabled else 'disabled'}")
+        return False
+
     def _on_voice_commands_toggled(self, widget, state):
         """Handle toggle of the voice commands switch."""
         if self._initializing or self._applying_settings:
@@ -2934,6 +4915,133 @@ class SettingsDialog(Gtk.Dialog):
         logger.info(f"Voice commands {'enabled' if enabled else 'disabled'}")
         return False
 
+    def _on_punctuation_changed(self, widget):
+        """Handle auto-punctuation level change."""
+        if self._initializing or self._applying_settings:
+            return
+
+        level = widget.get_active_id()
+        level = None if level in (None, "default") else level
+        self.config_manager.set("speech_recognition", "punctuation", level)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(punctuation=level, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply punctuation level immediately: {e}")
+        logger.info(f"Punctuation level set to {level or 'engine default'}")
+
+    def _on_intent_commands_toggled(self, widget, state):
+        """Handle toggle of the assistant commands switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("speech_recognition", "intent_commands_enabled", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(intent_commands_enabled=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply assistant commands toggle immediately: {e}")
+        logger.info(f"Assistant commands {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_readback_toggled(self, widget, state):
+        """Handle toggle of the read back switch."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        logger.info(f"Read back toggled: {enabled}")
+        self.config_manager.set("text_to_speech", "readback_enabled", enabled)
+        self.config_manager.save_settings()
+        return False
+
+    def _on_number_format_toggled(self, widget, state, key):
+        """Handle toggle of one of the number formatting switches."""
+        if self._initializing or self._applying_settings:
+            return False
+
+        enabled = bool(state)
+        self.config_manager.set("formatting", key, enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(**{f"format_{key}": enabled}, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply number formatting immediately: {e}")
+        logger.info(f"Number formatting {key} {'enabled' if enabled else 'disabled'}")
+        return False
+
+    def _on_profanity_filter_changed(self, widget):
+        """Handle profanity filter mode change."""
+        if self._initializing or self._applying_settings:
+            return
+
+        mode = widget.get_active_id() or "off"
+        self.config_manager.set("formatting", "profanity_filter", mode)
+        self.config_manager.save_settings()
+        self.profanity_words_entry.set_sensitive(mode != "off")
+        try:
+            self.speech_engine.reconfigure(profanity_filter=mode, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply profanity filter immediately: {e}")
+        logger.info(f"Profanity filter set to {mode}")
+
+    def _on_profanity_words_changed(self, widget):
+        """Handle edits to the extra filtered word list."""
+        if self._initializing or self._applying_settings:
+            return
+
+        words = _parse_term_list(widget.get_text())
+        self.config_manager.set("formatting", "profanity_words", words)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(profanity_words=words, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply filtered words immediately: {e}")
+
+    def _on_preserved_terms_changed(self, widget):
+        """Handle edits to the always-capitalized term list."""
+        if self._initializing or self._applying_settings:
+            return
+
+        terms = _parse_term_list(widget.get_text())
+        self.config_manager.set("formatting", "preserved_terms", terms)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(preserved_terms=terms, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply preserved capitalization immediately: {e}")
+
+    def _on_app_prompts_changed(self, widget):
+        """Handle edits to the prompt presets attached to applications."""
+        if self._initializing or self._applying_settings:
+            return
+
+        rules = _parse_command_renames(self.app_prompts_entry.get_text())
+        self.config_manager.set("speech_recognition", "app_prompts", rules)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(app_prompts=rules, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply app prompt presets immediately: {e}")
+
+    def _on_custom_commands_changed(self, widget):
+        """Handle edits to the disabled or renamed built-in commands."""
+        if self._initializing or self._applying_settings:
+            return
+
+        disabled = _parse_term_list(self.disabled_commands_entry.get_text())
+        renamed = _parse_command_renames(self.renamed_commands_entry.get_text())
+        self.config_manager.set("speech_recognition", "disabled_commands", disabled)
+        self.config_manager.set("speech_recognition", "renamed_commands", renamed)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(
+                disabled_commands=disabled, renamed_commands=renamed, force_download=False
+            )
+        except Exception as e:
+            logger.warning(f"Failed to apply custom voice commands immediately: {e}")
+
     def _populate_language_options(self):
         """Populate language dropdown with supported languages."""
         self.language_combo.remove_all()
@@ -2943,15 +5051,16 @@ class SettingsDialog(Gtk.Dialog):
 
         engine = _engine_from_display(engine)
         english_only_whispercpp = self._is_selected_whispercpp_model_english_only()
+        vosk_size = self._get_selected_vosk_model_size()
+        vosk_languages = get_supported_languages("vosk", vosk_size)
 
         for lang_code, lang_info in SUPPORTED_LANGUAGES.items():
             display_text = lang_info["name"]
 
             if engine == "vosk":
-                has_model = lang_info["vosk"] is not None
-                if not has_model or lang_code == "auto":
+                if lang_code not in vosk_languages:
                     continue
-                is_downloaded = _is_vosk_model_downloaded("small", lang_code)
+                is_downloaded = _is_vosk_model_downloaded(vosk_size, lang_code)
                 display_text += " ✓" if is_downloaded else " ↓"
             elif engine in ["whisper", "whisper_cpp", "remote_api"]:
                 if english_only_whispercpp and lang_info.get("whisper") != "en":
@@ -2964,12 +5073,33 @@ class SettingsDialog(Gtk.Dialog):
 
             self.language_combo.append(lang_code, display_text)
 
+    def _get_selected_vosk_model_size(self) -> str:
+        """Return the selected VOSK model size, defaulting to small."""
+        size_id = self.model_combo.get_active_id()
+        if size_id and size_id.lower() in VOSK_MODEL_INFO:
+            return size_id.lower()
+        return "small"
+
     def _update_language_warning(self):
         """Update language help text for the selected engine/model/language."""
         lang_code = self.language_combo.get_active_id()
         lang_info = SUPPORTED_LANGUAGES.get(lang_code, {})
 
-        if self._is_selected_whispercpp_model_english_only():
+        engine = self._get_selected_engine()
+        if engine == "vosk":
+            model_id = self._get_selected_vosk_model_size()
+        elif engine == "whisper_cpp":
+            model_id = self._get_selected_whispercpp_model()
+        else:
+            model_id = self.model_combo.get_active_id()
+        selection_warning = validate_language_selection(engine, model_id, lang_code)
+
+        if selection_warning:
+            self.language_warning.set_markup(
+                f"<span foreground='#e5a50a'>⚠ {GLib.markup_escape_text(selection_warning)}</span>"
+            )
+            self.language_warning.show()
+        elif self._is_selected_whispercpp_model_english_only():
             self.language_warning.set_markup(
                 "<span foreground='#e5a50a'>⚠ English-only model selected. "
                 "Language choices are limited to English.</span>"
@@ -3029,6 +5159,13 @@ class SettingsDialog(Gtk.Dialog):
             self.remote_server_group.hide()
             self.remote_status_label.hide()
 
+        self.long_form_switch.set_sensitive(engine in ("whisper", "whisper_cpp"))
+        self.partial_results_switch.set_sensitive(engine != "remote_api")
+        self.speaker_profile_switch.set_sensitive(engine == "vosk")
+        self.enroll_voice_btn.set_sensitive(engine == "vosk")
+        self.translate_switch.set_sensitive(engine in ("whisper", "whisper_cpp"))
+        self.tag_language_switch.set_sensitive(engine in ("whisper", "remote_api"))
+
         self._update_model_info()
         self._update_language_warning()
         self._update_model_picker_tooltips()
@@ -3060,6 +5197,19 @@ class SettingsDialog(Gtk.Dialog):
                 "These settings only apply when the whisper.cpp engine is selected."
             )
 
+    def _whispercpp_backend_text(self, model_name: str) -> str:
+        """The backend the loaded model really runs on, or the detected one if not loaded."""
+        report = getattr(self.speech_engine, "whispercpp_load_report", None)
+        if (
+            isinstance(report, WhisperCppLoadReport)
+            and report.backend != "unknown"
+            and self.speech_engine.engine == "whisper_cpp"
+            and self.speech_engine.model_size == model_name
+        ):
+            return GLib.markup_escape_text(f"{report.summary()} (in use)")
+        backend, _backend_info = detect_compute_backend()
+        return f"{get_backend_display_name(backend)} (detected)"
+
     def _update_model_info(self):
         """Update the model info card display."""
         engine_text = self.engine_combo.get_active_text()
@@ -3093,10 +5243,8 @@ class SettingsDialog(Gtk.Dialog):
             info = WHISPERCPP_MODEL_INFO[model_name]
             is_downloaded = is_whispercpp_model_downloaded(model_name)
             recommended, reason = self._get_recommended_whispercpp_model_for_language()
-            backend, backend_info = detect_compute_backend()
-            extra_info = (
-                f"Parameters: {info['params']} • Backend: {get_backend_display_name(backend)}"
-            )
+            backend_text = self._whispercpp_backend_text(model_name)
+            extra_info = f"Parameters: {info['params']} • Backend: {backend_text}"
         elif engine == "vosk":
             if model_name not in VOSK_MODEL_INFO:
                 self.model_info_card.hide()
@@ -3196,6 +5344,7 @@ class SettingsDialog(Gtk.Dialog):
                             self._apply_settings_internal(settings)
                             GLib.idle_add(download_dialog.set_complete, True, "")
                             GLib.idle_add(self._populate_model_options)
+                            GLib.idle_add(self._refresh_model_storage)
                         finally:
                             GLib.source_remove(cancel_check_id)
                             self.speech_engine.set_download_progress_callback(None)
@@ -3232,6 +5381,9 @@ class SettingsDialog(Gtk.Dialog):
                 self.speech_engine.stop_recognition()
 
             self.speech_engine.reconfigure(**settings)
+            # Show the backend the newly loaded model really runs on
+            self._update_model_info()
+            self._refresh_model_storage()
             logger.info("Settings auto-applied successfully")
         except Exception as e:
             logger.error(f"Failed to auto-apply settings: {e}")
@@ -3550,10 +5702,31 @@ For now, the engine has been reverted to VOSK."""
                 label += " (default)"
             self.audio_device_combo.append(str(device_index), label)
 
+        # Output monitors, for transcribing what the computer plays
+        monitors = list_monitor_sources()
+        for source_name, description in monitors:
+            self.audio_device_combo.append(
+                MONITOR_DEVICE_PREFIX + source_name, f"{description} (system audio)"
+            )
+
         saved_device = self.config_manager.get_optional_int("audio", "device_index", None)
         saved_device_name = self.config_manager.get("audio", "device_name", None)
 
-        if saved_device is None:
+        # Applications playing audio, for transcribing just a call or a video.
+        # The saved one stays listed while it plays nothing, so it isn't lost.
+        apps = list_playback_apps()
+        if is_app_stream(saved_device_name) and app_stream_name(saved_device_name) not in apps:
+            apps.append(app_stream_name(saved_device_name))
+        for app in apps:
+            self.audio_device_combo.append(APP_STREAM_PREFIX + app, f"{app} (application audio)")
+
+        if is_app_stream(saved_device_name):
+            self.audio_device_combo.set_active_id(saved_device_name)
+        elif is_monitor_source(saved_device_name):
+            if not self.audio_device_combo.set_active_id(MONITOR_DEVICE_PREFIX + saved_device_name):
+                logger.warning(f"Saved monitor source {saved_device_name} no longer available")
+                self.audio_device_combo.set_active_id("-1")
+        elif saved_device is None:
             self.audio_device_combo.set_active_id("-1")
         else:
             # Try to match by saved device name first (more stable across reboots)
@@ -3573,12 +5746,72 @@ For now, the engine has been reverted to VOSK."""
                     )
                     self.audio_device_combo.set_active_id("-1")
 
-        logger.info(f"Found {len(devices)} audio input devices")
+        self._populate_extra_devices(devices)
+        logger.info(f"Found {len(devices)} audio input devices and {len(monitors)} monitors")
+
+    def _populate_extra_devices(self, devices: list):
+        """List the microphones that can be mixed into the selected one."""
+        for child in self.extra_devices_box.get_children():
+            self.extra_devices_box.remove(child)
+        selected = self.config_manager.get("audio", "extra_device_names", []) or []
+        for _index, device_name, _is_default in devices:
+            check = Gtk.CheckButton(label=device_name)
+            check.set_active(device_name in selected)
+            check.connect("toggled", self._on_extra_device_toggled)
+            self.extra_devices_box.pack_start(check, False, False, 0)
+        self.extra_devices_box.show_all()
+        self._update_extra_devices_label(selected)
+
+    def _update_extra_devices_label(self, device_names: list):
+        count = len(device_names)
+        label = f"{count} microphone{'' if count == 1 else 's'}" if count else "None"
+        self.extra_devices_button.set_label(label)
+
+    def _on_extra_device_toggled(self, widget):
+        """Save the microphones to mix in."""
+        if self._initializing or self._applying_settings:
+            return
+
+        selected = [
+            check.get_label()
+            for check in self.extra_devices_box.get_children()
+            if check.get_active()
+        ]
+        # Keep saved devices that are unplugged right now
+        listed = {check.get_label() for check in self.extra_devices_box.get_children()}
+        saved = self.config_manager.get("audio", "extra_device_names", []) or []
+        selected += [name for name in saved if name not in listed]
+        self.config_manager.set("audio", "extra_device_names", selected)
+        self.config_manager.save_settings()
+        self._update_extra_devices_label(selected)
+        try:
+            self.speech_engine.set_extra_devices(selected)
+        except Exception as e:
+            logger.warning(f"Failed to apply extra microphones: {e}")
 
     def _on_refresh_audio_devices(self, widget):
         """Handle refresh button click for audio devices."""
         self._populate_audio_devices()
         self.audio_test_status.set_markup("<i>Device list refreshed</i>")
+        self._device_capabilities_loaded = False
+        self._refresh_device_capabilities()
+
+    def _refresh_device_capabilities(self):
+        """Probe device capabilities in the background if the report is showing."""
+        if not self.device_capabilities_expander.get_expanded():
+            return
+        if self._device_capabilities_loaded:
+            return
+        self._device_capabilities_loaded = True
+        self.device_capabilities_label.set_markup("<i>Probing devices...</i>")
+
+        def probe():
+            from ..speech_recognition.recognition_manager import get_input_device_capabilities
+
+            markup = _format_device_capabilities(get_input_device_capabilities())
+            GLib.idle_add(self.device_capabilities_label.set_markup, markup)
+
+        threading.Thread(target=probe, daemon=True).start()
 
     def _on_audio_device_changed(self, widget):
         """Handle changes in the selected audio device."""
@@ -3589,6 +5822,23 @@ For now, the engine has been reverted to VOSK."""
         if device_id is None:
             return
 
+        if device_id.startswith(MONITOR_DEVICE_PREFIX) or is_app_stream(device_id):
+            # Recorded through the default input, so only the source name is kept
+            source_name = device_id
+            if device_id.startswith(MONITOR_DEVICE_PREFIX):
+                source_name = device_id[len(MONITOR_DEVICE_PREFIX) :]
+            self.config_manager.set("audio", "device_index", None)
+            self.config_manager.set("audio", "device_name", source_name)
+            self.config_manager.save_settings()
+            self.speech_engine.set_audio_device(None, source_name)
+            self._show_input_gain()
+            logger.info(f"Audio device changed to monitor source: {source_name}")
+            self.audio_test_status.set_markup(
+                f"<i>Selected: {self.audio_device_combo.get_active_text()}</i>"
+            )
+            self._update_live_level()
+            return
+
         device_index = int(device_id)
         device_name = self.audio_device_combo.get_active_text()
 
@@ -3605,24 +5855,91 @@ For now, the engine has been reverted to VOSK."""
             self.speech_engine.set_audio_device(None, None)
         else:
             self.speech_engine.set_audio_device(device_index, device_name)
+        self._show_input_gain()
 
         logger.info(f"Audio device changed to: [{device_index}] {device_name}")
         self.audio_test_status.set_markup(f"<i>Selected: {device_name}</i>")
+        self._update_live_level()
+
+    def _selected_capture(self) -> tuple[Optional[int], Optional[str]]:
+        """The selected input as (device index, monitor source); None means the default."""
+        device_id = self.audio_device_combo.get_active_id() or "-1"
+        if device_id.startswith(MONITOR_DEVICE_PREFIX):
+            return None, device_id[len(MONITOR_DEVICE_PREFIX) :]
+        if is_app_stream(device_id):
+            return None, find_app_stream(app_stream_name(device_id))
+        return (None if device_id == "-1" else int(device_id)), None
+
+    def _update_live_level(self, page_num: Optional[int] = None):
+        """
+        Run the live level meter while the Audio page is shown.
+
+        It pauses during a microphone test and while dictating, and restarts
+        so a newly selected device is read.
+
+        Args:
+            page_num: The page being switched to (default: the current page)
+        """
+        self._stop_live_level()
+        if page_num is None:
+            page_num = self.settings_notebook.get_current_page()
+        if page_num != self.audio_page_num or not self.test_audio_btn.get_sensitive():
+            return
+        if self.speech_engine.state != RecognitionState.IDLE:
+            return
+
+        device_index, monitor_source = self._selected_capture()
+        self._level_monitor = LevelMonitor(
+            self._on_live_level,
+            device_index=device_index,
+            gain=self.input_gain_spin.get_value() / 100,
+            monitor_source=monitor_source,
+        )
+        self._level_monitor.start()
+
+    def _stop_live_level(self):
+        """Stop the live level meter and empty the level bar."""
+        monitor, self._level_monitor = self._level_monitor, None
+        if monitor is not None:
+            monitor.stop()
+            self.audio_level_bar.set_value(0)
+
+    def _on_live_level(self, level: float):
+        """Show a live level (called from the level monitor thread)."""
+        GLib.idle_add(self._show_live_level, level)
+
+    def _show_live_level(self, level: float):
+        if self._level_monitor is not None:
+            self.audio_level_bar.set_value(level)
+        return False
 
     def _on_test_audio_clicked(self, widget):
         """Handle test audio button click."""
+        self._stop_live_level()
         self.test_audio_btn.set_sensitive(False)
         self.test_audio_btn.set_label("Testing...")
         self.audio_test_status.set_markup("<i>Recording... speak into your microphone</i>")
         self.audio_level_bar.set_value(0)
 
-        device_id = self.audio_device_combo.get_active_id()
-        device_index = None if device_id == "-1" else int(device_id)
+        device_index, monitor_source = self._selected_capture()
+        gain = self.input_gain_spin.get_value() / 100
+
+        def on_playback():
+            GLib.idle_add(
+                self.audio_test_status.set_markup, "<i>Playing back what was recorded...</i>"
+            )
 
         def run_test():
             from ..speech_recognition.recognition_manager import test_audio_input
 
-            result = test_audio_input(device_index=device_index, duration=2.0)
+            with pulse_source(monitor_source):
+                result = test_audio_input(
+                    device_index=device_index,
+                    duration=MIC_TEST_SECONDS,
+                    gain=gain,
+                    playback=True,
+                    on_playback=on_playback,
+                )
             GLib.idle_add(self._handle_audio_test_result, result)
 
         threading.Thread(target=run_test, daemon=True).start()
@@ -3633,29 +5950,36 @@ For now, the engine has been reverted to VOSK."""
         self.test_audio_btn.set_label("Test")
 
         if result.get("success"):
-            max_level = result.get("max_amplitude", 0)
+            peak_dbfs = result.get("peak_dbfs", METER_FLOOR_DBFS)
+            rms_dbfs = result.get("rms_dbfs", METER_FLOOR_DBFS)
             has_signal = result.get("has_signal", False)
             sample_rate = result.get("sample_rate", 16000)
 
-            level_percent = min(100, (max_level / 327.68))
-            self.audio_level_bar.set_value(level_percent)
+            self.audio_level_bar.set_value(dbfs_to_percent(peak_dbfs))
 
             # Build sample rate info string
             if sample_rate == 16000:
                 rate_info = "(16kHz native)"
             else:
                 rate_info = f"({sample_rate // 1000}kHz → 16kHz auto)"
+            playback_info = (
+                ""
+                if result.get("played_back")
+                else "\n<small>Could not play the recording back</small>"
+            )
 
             if has_signal:
                 self.audio_test_status.set_markup(
                     f"<span foreground='#26a269'>✓ Audio detected!</span> "
-                    f"Peak: {level_percent:.0f}% {rate_info}"
+                    f"Peak: {peak_dbfs:.0f} dBFS, RMS: {rms_dbfs:.0f} dBFS {rate_info}"
+                    f"{playback_info}"
                 )
             else:
                 self.audio_test_status.set_markup(
                     f"<span foreground='#e5a50a'>⚠ Very low audio level</span> "
-                    f"(peak: {level_percent:.1f}%)\n"
+                    f"(peak: {peak_dbfs:.0f} dBFS, RMS: {rms_dbfs:.0f} dBFS)\n"
                     "<small>Check if microphone is muted or try a different device</small>"
+                    f"{playback_info}"
                 )
         else:
             error_msg = result.get("error", "Unknown error")
@@ -3663,6 +5987,7 @@ For now, the engine has been reverted to VOSK."""
                 f"<span foreground='#c01c28'>✗ Test failed:</span> {error_msg}"
             )
 
+        self._update_live_level()
         return False
 
     def update_recognition_progress(self, state: str, audio_level: float = 0.0, info: str = ""):
@@ -3720,6 +6045,7 @@ For now, the engine has been reverted to VOSK."""
 
     def _on_dialog_destroy(self, widget):
         """Clean up callbacks when dialog is destroyed."""
+        self._stop_live_level()
         if hasattr(self, "speech_engine") and self.speech_engine:
             if self._on_recognition_state_changed in self.speech_engine.state_callbacks:
                 self.speech_engine.state_callbacks.remove(self._on_recognition_state_changed)
@@ -3736,6 +6062,8 @@ For now, the engine has been reverted to VOSK."""
 
         state_str = state_map.get(state, "Unknown")
         GLib.idle_add(self.update_recognition_progress, state_str)
+        # The live level meter gives the microphone up while dictating
+        GLib.idle_add(self._update_live_level)
 
     def _on_audio_level_changed(self, level: float):
         """Handle audio level changes."""
@@ -0,0 +1,302 @@
+"""
+Status window for Vocalinux.
+
+A compact window showing the recognition state, engine, model and
+microphone, with a large start/stop button and a link to the settings.
+It is shown from the tray menu, or when Vocalinux is launched while it is
+already running. It also shows the session timer and word count, and in
+engine comparison mode the last utterance as transcribed by both engines.
+In kiosk mode (`vocalinux --kiosk`) it shows the transcripts as captions
+instead of them being typed.
+"""
+
+import logging
+from collections import deque
+from typing import Callable, Iterable, Optional
+
+import gi
+
+gi.require_version("Gtk", "3.0")
+from gi.repository import GLib, Gtk, Pango  # noqa: E402
+
+from ..common_types import RecognitionState  # noqa: E402
+
+logger = logging.getLogger(__name__)
+
+# Transcripts shown as captions in kiosk mode
+CAPTION_TRANSCRIPTS = 3
+
+# Headline, button label and button style class for each state
+_STATE_DISPLAY = {
+    RecognitionState.IDLE: ("Ready", "Start Voice Typing", "suggested-action"),
+    RecognitionState.LISTENING: ("Listening…", "Stop Voice Typing", "destructive-action"),
+    RecognitionState.PROCESSING: ("Processing…", "Stop Voice Typing", "destructive-action"),
+    RecognitionState.ERROR: ("Error", "Start Voice Typing", "suggested-action"),
+}
+
+
+def describe_state(state: RecognitionState) -> tuple[str, str, str]:
+    """
+    Describe a recognition state for the status window.
+
+    Returns:
+        (headline, button label, button style class)
+    """
+    return _STATE_DISPLAY.get(state, _STATE_DISPLAY[RecognitionState.IDLE])
+
+
+def describe_engine(speech_engine) -> list[tuple[str, str]]:
+    """
+    Describe the active engine, model, language and microphone as label/value rows.
+
+    Args:
+        speech_engine: The speech recognition manager
+    """
+    from .settings_dialog import _engine_display_name
+
+    engine = getattr(speech_engine, "engine", "") or ""
+    model = getattr(speech_engine, "model_size", "") or ""
+    language = getattr(speech_engine, "language", "") or ""
+    if language == "auto":
+        detected = getattr(speech_engine, "detected_language", None)
+        language = f"Auto-detect · {detected}" if detected else "Auto-detect"
+    try:
+        device = speech_engine.get_audio_device_name()
+    except Exception:
+        device = None
+
+    rows = [
+        ("Engine", _engine_display_name(engine) if engine else "Unknown"),
+        ("Model", model.capitalize() if model else "Default"),
+        ("Language", language or "Default"),
+        ("Microphone", device or "System default"),
+    ]
+    if engine == "remote_api" and getattr(speech_engine, "remote_api_key_locked", False) is True:
+        rows.append(("API key", "Locked until the keyring is unlocked"))
+    return rows
+
+
+def describe_comparison(primary, comparison) -> list[tuple[str, str]]:
+    """
+    Describe both transcripts of an engine comparison as label/value rows.
+
+    Args:
+        primary: EngineTranscript from the engine whose text is typed
+        comparison: EngineTranscript from the comparison engine
+    """
+    from .settings_dialog import _engine_display_name
+
+    return [
+        (f"{_engine_display_name(primary.engine)} (typed)", primary.describe()),
+        (_engine_display_name(comparison.engine), comparison.describe()),
+    ]
+
+
+def caption_text(transcripts: Iterable[str]) -> str:
+    """
+    Join the latest transcripts into the caption shown in kiosk mode.
+
+    Args:
+        transcripts: The transcripts, oldest first
+    """
+    return " ".join(text.strip() for text in transcripts if text.strip())
+
+
+def describe_session(session) -> str:
+    """
+    Describe the dictation session (elapsed time and word count) in one line.
+
+    Args:
+        session: The manager's DictationSession
+    """
+    if not session.started:
+        return "Session: nothing dictated yet"
+    return f"Session: {session.summary()}"
+
+
+class StatusWindow(Gtk.Window):
+    """Compact window with the current dictation status and a start/stop button."""
+
+    def __init__(
+        self,
+        speech_engine,
+        on_settings: Optional[Callable[[], None]] = None,
+        show_transcripts: bool = False,
+    ):
+        """
+        Initialize the status window.
+
+        Args:
+            speech_engine: The speech recognition manager
+            on_settings: Called when the user clicks "Open Settings"
+            show_transcripts: Show the latest transcripts as captions (kiosk mode)
+        """
+        super().__init__(title="Vocalinux")
+        self.speech_engine = speech_engine
+        self._on_settings = on_settings
+        self._show_transcripts = show_transcripts
+        self._transcripts: deque = deque(maxlen=CAPTION_TRANSCRIPTS)
+
+        self.set_default_size(360, -1)
+        self.set_resizable(False)
+        self.set_position(Gtk.WindowPosition.CENTER)
+        self.set_icon_name("vocalinux")
+
+        self._create_ui()
+        self._refresh(self.speech_engine.state)
+        self._refresh_session()
+        # The session timer ticks while the window is open
+        self._session_timer = GLib.timeout_add_seconds(1, self._refresh_session)
+
+        self.speech_engine.register_state_callback(self._on_state_changed)
+        self.speech_engine.register_comparison_callback(self._on_comparison)
+        if self._show_transcripts:
+            self.speech_engine.register_text_callback(self._on_text)
+        self.connect("destroy", self._on_destroy)
+
+    def _create_ui(self):
+        """Create the user interface."""
+        main_box = Gtk.Box(orientation=Gtk.Orientation.VERTICAL, spacing=12)
+        main_box.set_margin_top(18)
+        main_box.set_margin_bottom(18)
+        main_box.set_margin_start(18)
+        main_box.set_margin_end(18)
+        self.add(main_box)
+
+        self.state_label = Gtk.Label(xalign=0)
+        main_box.pack_start(self.state_label, False, False, 0)
+
+        self.performance_label = Gtk.Label(xalign=0, wrap=True)
+        self.performance_label.get_style_context().add_class("dim-label")
+        # Only shown while there is something to report
+        self.performance_label.set_no_show_all(True)
+        main_box.pack_start(self.performance_label, False, False, 0)
+
+        details = Gtk.Grid(column_spacing=12, row_spacing=6)
+        self.detail_labels = {}
+        for row, (name, value) in enumerate(describe_engine(self.speech_engine)):
+            name_label = Gtk.Label(label=name, xalign=0)
+            name_label.get_style_context().add_class("dim-label")
+            value_label = Gtk.Label(label=value, xalign=0, selectable=True)
+            value_label.set_ellipsize(Pango.EllipsizeMode.END)
+            details.attach(name_label, 0, row, 1, 1)
+            details.attach(value_label, 1, row, 1, 1)
+            self.detail_labels[name] = value_label
+        main_box.pack_start(details, False, False, 0)
+
+        session_box = Gtk.Box(orientation=Gtk.Orientation.HORIZONTAL, spacing=6)
+        self.session_label = Gtk.Label(xalign=0)
+        session_box.pack_start(self.session_label, True, True, 0)
+        reset_button = Gtk.Button(label="Reset")
+        reset_button.set_tooltip_text("Start a new session: reset the timer and word count")
+        reset_button.set_relief(Gtk.ReliefStyle.NONE)
+        reset_button.connect("clicked", self._on_reset_session_clicked)
+        session_box.pack_start(reset_button, False, False, 0)
+        main_box.pack_start(session_box, False, False, 0)
+
+        if self._show_transcripts:
+            notice = Gtk.Label(label="Read-only: transcripts are shown here, not typed", xalign=0)
+            notice.get_style_context().add_class("dim-label")
+            main_box.pack_start(notice, False, False, 0)
+
+            self.caption_label = Gtk.Label(xalign=0, yalign=0, wrap=True, selectable=True)
+            self.caption_label.set_size_request(-1, 96)
+            self.caption_label.set_markup("<big>Transcripts appear here.</big>")
+            main_box.pack_start(self.caption_label, False, False, 0)
+
+        # Engine comparison results; only shown once there is one
+        self.comparison_label = Gtk.Label(xalign=0, wrap=True, selectable=True)
+        self.comparison_label.set_no_show_all(True)
+        main_box.pack_start(self.comparison_label, False, False, 0)
+
+        self.toggle_button = Gtk.Button()
+        self.toggle_button.set_size_request(-1, 48)
+        self.toggle_button.connect("clicked", self._on_toggle_clicked)
+        main_box.pack_start(self.toggle_button, False, False, 0)
+
+        settings_button = Gtk.Button(label="Open Settings")
+        settings_button.set_relief(Gtk.ReliefStyle.NONE)
+        settings_button.set_halign(Gtk.Align.CENTER)
+        settings_button.connect("clicked", self._on_settings_clicked)
+        main_box.pack_start(settings_button, False, False, 0)
+
+    def _refresh(self, state: RecognitionState):
+        """Update the labels and button for the given state."""
+        headline, button_label, style_class = describe_state(state)
+        self.state_label.set_markup(f"<big><b>{GLib.markup_escape_text(headline)}</b></big>")
+
+        self.toggle_button.set_label(button_label)
+        style = self.toggle_button.get_style_context()
+        for name in ("suggested-action", "destructive-action"):
+            style.remove_class(name)
+        style.add_class(style_class)
+
+        for name, value in describe_engine(self.speech_engine):
+            self.detail_labels[name].set_label(value)
+
+        try:
+            summary = self.speech_engine.get_performance_summary()
+        except Exception:
+            summary = ""
+        self.performance_label.set_label(summary)
+        self.performance_label.set_visible(bool(summary))
+        return False
+
+    def _refresh_session(self):
+        """Update the session timer and word count."""
+        self.session_label.set_label(describe_session(self.speech_engine.session))
+        return True  # Keep the timer running
+
+    def _on_reset_session_clicked(self, widget):
+        """Start a new dictation session."""
+        self.speech_engine.session.reset()
+        self._refresh_session()
+
+    def _on_state_changed(self, state: RecognitionState):
+        """Handle recognition state changes (called from the recognition thread)."""
+        GLib.idle_add(self._refresh, state)
+
+    def _show_comparison(self, primary, comparison):
+        """Show the last utterance as transcribed by both engines."""
+        lines = [
+            f"<b>{GLib.markup_escape_text(name)}</b>\n{GLib.markup_escape_text(text)}"
+            for name, text in describe_comparison(primary, comparison)
+        ]
+        self.comparison_label.set_markup("\n".join(lines))
+        self.comparison_label.show()
+        return False
+
+    def _on_comparison(self, primary, comparison):
+        """Handle an engine comparison result (called from a background thread)."""
+        GLib.idle_add(self._show_comparison, primary, comparison)
+
+    def _show_caption(self, text: str):
+        """Add a transcript to the captions."""
+        self._transcripts.append(text)
+        caption = GLib.markup_escape_text(caption_text(self._transcripts))
+        self.caption_label.set_markup(f"<big>{caption}</big>")
+        return False
+
+    def _on_text(self, text: str):
+        """Handle a final transcript (called from the recognition thread)."""
+        GLib.idle_add(self._show_caption, text)
+
+    def _on_toggle_clicked(self, widget):
+        """Start or stop dictation."""
+        if self.speech_engine.state in (RecognitionState.IDLE, RecognitionState.ERROR):
+            self.speech_engine.start_recognition()
+        else:
+            self.speech_engine.stop_recognition()
+
+    def _on_settings_clicked(self, widget):
+        """Open the settings dialog."""
+        if self._on_settings is not None:
+            self._on_settings()
+
+    def _on_destroy(self, widget):
+        """Stop listening for state changes once the window is closed."""
+        GLib.source_remove(self._session_timer)
+        self.speech_engine.unregister_state_callback(self._on_state_changed)
+        self.speech_engine.unregister_comparison_callback(self._on_comparison)
+        if self._show_transcripts:
+            self.speech_engine.unregister_text_callback(self._on_text)
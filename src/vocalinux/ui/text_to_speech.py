@@ -0,0 +1,160 @@
+"""
+Text-to-speech readback for Vocalinux.
+
+Speaks text aloud with a local TTS engine so eyes-free users can verify what
+was typed ("read that back"). Piper is preferred when a voice model is
+configured; espeak-ng, espeak or speech-dispatcher are used otherwise.
+"""
+
+import json
+import logging
+import os
+import shutil
+import subprocess
+import threading
+from typing import Optional
+
+from ..utils.self_playback import register_playback
+
+logger = logging.getLogger(__name__)
+
+# Piper voices ship a <model>.onnx.json with the sample rate; this is the
+# rate of the common "medium" quality voices, used when the file is missing.
+PIPER_DEFAULT_SAMPLE_RATE = 22050
+
+# Fallback speech synthesizers, in order of preference
+FALLBACK_TTS_COMMANDS = ["espeak-ng", "espeak", "spd-say"]
+
+
+def _get_tts_settings() -> dict:
+    try:
+        from .config_manager import ConfigManager
+
+        return ConfigManager().get_settings().get("text_to_speech", {})
+    except Exception:
+        return {}
+
+
+def is_readback_enabled() -> bool:
+    """Return whether "read that back" is enabled in the config."""
+    return bool(_get_tts_settings().get("readback_enabled", False))
+
+
+def _get_piper_sample_rate(model_path: str) -> int:
+    """Read the voice sample rate from the Piper model's JSON config."""
+    config_path = f"{model_path}.json"
+    try:
+        with open(config_path, "r") as f:
+            return int(json.load(f)["audio"]["sample_rate"])
+    except (OSError, ValueError, KeyError, TypeError):
+        return PIPER_DEFAULT_SAMPLE_RATE
+
+
+def get_tts_backend(piper_model: str = "") -> Optional[str]:
+    """
+    Determine the best available TTS backend.
+
+    Args:
+        piper_model: Path to a Piper .onnx voice model (Piper is only used if set)
+
+    Returns:
+        "piper", one of FALLBACK_TTS_COMMANDS, or None if nothing is installed
+    """
+    if piper_model and os.path.exists(piper_model) and shutil.which("piper"):
+        if shutil.which("aplay"):
+            return "piper"
+        logger.warning("Piper is configured but aplay is missing; falling back")
+
+    for command in FALLBACK_TTS_COMMANDS:
+        if shutil.which(command):
+            return command
+
+    return None
+
+
+def _reap_when_done(*processes: subprocess.Popen) -> None:
+    """Wait for the players in the background so they don't linger as zombies."""
+
+    def reap():
+        for process in processes:
+            try:
+                process.wait()
+            except OSError:
+                pass
+
+    threading.Thread(target=reap, name="tts-reaper", daemon=True).start()
+
+
+def _speak_with_piper(text: str, piper_model: str) -> bool:
+    sample_rate = _get_piper_sample_rate(piper_model)
+    piper = subprocess.Popen(
+        ["piper", "--model", piper_model, "--output-raw"],
+        stdin=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+        stderr=subprocess.DEVNULL,
+    )
+    try:
+        aplay = subprocess.Popen(
+            ["aplay", "-q", "-r", str(sample_rate), "-f", "S16_LE", "-t", "raw", "-"],
+            stdin=piper.stdout,
+            stdout=subprocess.DEVNULL,
+            stderr=subprocess.DEVNULL,
+        )
+    except (OSError, subprocess.SubprocessError):
+        piper.kill()
+        piper.wait()
+        raise
+    register_playback(aplay)
+    _reap_when_done(piper, aplay)
+    # Let aplay own the pipe so piper gets SIGPIPE if playback dies
+    piper.stdout.close()
+    try:
+        piper.stdin.write(text.encode("utf-8"))
+        piper.stdin.close()
+    except OSError:
+        # Piper exited early; aplay stops at the end of its input
+        piper.kill()
+        raise
+    return True
+
+
+def speak(text: str) -> bool:
+    """
+    Speak text aloud using the configured TTS backend.
+
+    Playback runs in the background; this returns once the synthesizer has
+    been started.
+
+    Args:
+        text: The text to speak
+
+    Returns:
+        True if speech was started, False otherwise
+    """
+    text = text.strip()
+    if not text:
+        return False
+
+    piper_model = _get_tts_settings().get("piper_model", "")
+    backend = get_tts_backend(piper_model)
+    if backend is None:
+        logger.warning("No text-to-speech engine found (install piper or espeak-ng)")
+        return False
+
+    logger.debug(f"Speaking {len(text)} characters with {backend}")
+    try:
+        if backend == "piper":
+            return _speak_with_piper(text, piper_model)
+
+        # spd-say returns before speaking unless told to wait; the process
+        # has to live as long as the speech for the microphone to be muted
+        options = ["--wait"] if backend == "spd-say" else []
+        # "--" so dictated text starting with "-" (e.g. "-5 degrees") isn't read as an option
+        command = [backend, *options, "--", text]
+        process = subprocess.Popen(command, stdout=subprocess.DEVNULL, stderr=subprocess.DEVNULL)
+        register_playback(process)
+        _reap_when_done(process)
+        return True
+    except (OSError, subprocess.SubprocessError) as e:
+        logger.error(f"Failed to speak text with {backend}: {e}")
+        return False
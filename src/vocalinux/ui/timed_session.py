@@ -0,0 +1,117 @@
+"""
+Time-boxed dictation sessions for Vocalinux.
+
+A timed session (a meeting timer) dictates for a fixed number of minutes:
+everything transcribed meanwhile is collected, and when the time is up (or
+dictation is stopped earlier) the transcript is saved to a text file in the
+sessions folder of the data directory.
+"""
+
+import logging
+import os
+import threading
+import time
+from datetime import datetime
+from typing import Callable, Optional
+
+from ..speech_recognition.session_stats import count_words
+from ..utils.paths import data_dir
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_SESSION_MINUTES = 30
+MAX_SESSION_MINUTES = 8 * 60
+
+
+def sessions_dir() -> str:
+    """Return the folder timed session transcripts are saved in."""
+    return os.path.join(data_dir(), "sessions")
+
+
+class TimedSession:
+    """Collects the transcript of a timed session and saves it once finished."""
+
+    def __init__(
+        self,
+        minutes: float,
+        on_expired: Callable[[], None],
+        directory: Optional[str] = None,
+        clock: Callable[[], float] = time.monotonic,
+    ):
+        """
+        Initialize the session.
+
+        Args:
+            minutes: How long the session lasts (clamped to 1 minute - 8 hours)
+            on_expired: Called from a timer thread when the time is up; it
+                should stop dictation, which then finishes the session
+            directory: Where the transcript is saved, defaults to sessions_dir()
+            clock: Monotonic clock in seconds, for tests
+        """
+        self.minutes = min(max(float(minutes), 1.0), MAX_SESSION_MINUTES)
+        self.directory = directory or sessions_dir()
+        self._on_expired = on_expired
+        self._clock = clock
+        self._lock = threading.Lock()
+        self._texts: list[str] = []
+        self._timer: Optional[threading.Timer] = None
+        self._started_at: Optional[float] = None
+        self._started_wall: Optional[datetime] = None
+        self.finished = False
+
+    @property
+    def running(self) -> bool:
+        return self._started_at is not None and not self.finished
+
+    def start(self):
+        """Start the clock."""
+        self._started_at = self._clock()
+        self._started_wall = datetime.now()
+        self._timer = threading.Timer(self.minutes * 60, self._expire)
+        self._timer.daemon = True
+        self._timer.start()
+        logger.info(f"Timed session started for {self.minutes:g} minutes")
+
+    def _expire(self):
+        logger.info("Timed session time is up")
+        self._on_expired()
+
+    def add_text(self, text: str):
+        """Add a final transcript to the session."""
+        text = text.strip()
+        if not text:
+            return
+        with self._lock:
+            if not self.finished:
+                self._texts.append(text)
+
+    def finish(self) -> Optional[tuple[str, int, float]]:
+        """
+        End the session and save its transcript.
+
+        Returns:
+            (file path, word count, elapsed seconds), or None if the session
+            already finished or the file could not be written
+        """
+        with self._lock:
+            if self.finished or self._started_at is None:
+                return None
+            self.finished = True
+            texts = list(self._texts)
+        if self._timer is not None:
+            self._timer.cancel()
+        elapsed = self._clock() - self._started_at
+
+        transcript = "\n".join(texts)
+        path = os.path.join(
+            self.directory, f"session-{self._started_wall.strftime('%Y%m%d-%H%M%S')}.txt"
+        )
+        try:
+            os.makedirs(self.directory, exist_ok=True)
+            with open(path, "w", encoding="utf-8") as f:
+                f.write(transcript + "\n" if transcript else "")
+        except OSError as e:
+            logger.error(f"Could not save the timed session transcript to {path}: {e}")
+            return None
+        logger.info(f"Timed session transcript saved to {path}")
+        return path, count_words(transcript), elapsed
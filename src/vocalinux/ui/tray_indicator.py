@@ -8,6 +8,8 @@ recognition process and displaying its status.
 import logging
 import os
 import signal
+import subprocess
+import threading
 from typing import Callable, Optional
 
 import gi
@@ -28,12 +30,19 @@ except (ImportError, ValueError):
 from gi.repository import GdkPixbuf, Gio, GLib, GObject, Gtk
 
 # Import local modules - Use protocols to avoid circular imports
+from ..capture_indicator import CaptureIndicator
 from ..common_types import RecognitionState, SpeechRecognitionManagerProtocol, TextInjectorProtocol
+from ..single_instance import SHOW_STATUS_SIGNAL
+from ..speech_recognition.session_stats import format_elapsed
 from ..suspend_handler import SuspendHandler
+from ..utils import notifications
 from ..utils.resource_manager import ResourceManager
+from ..utils.transcript_history import DEFAULT_HISTORY_SIZE, TranscriptHistory, menu_label
 from .config_manager import ConfigManager
+from .keyboard_backends import DEFAULT_TAP_COUNT, DEFAULT_TAP_THRESHOLD_MS, parse_shortcut_spec
 from .keyboard_shortcuts import KeyboardShortcutManager
 from .settings_dialog import SettingsDialog
+from .timed_session import DEFAULT_SESSION_MINUTES, TimedSession
 
 logger = logging.getLogger(__name__)
 
@@ -71,6 +80,10 @@ _INPUT_SETTLE_SECONDS = 2
 _INPUT_MONITOR_CAP_SECONDS = 10
 _FALLBACK_KEYBOARD_RESTART_SECONDS = 6
 
+# Grace period for a tray (e.g. the GNOME AppIndicator extension) that registers after
+# Vocalinux autostarts, before falling back to the status window and notification controls
+_TRAYLESS_FALLBACK_DELAY_SECONDS = 5
+
 
 class TrayIndicator:
     """
@@ -84,6 +97,8 @@ class TrayIndicator:
         self,
         speech_engine: SpeechRecognitionManagerProtocol,
         text_injector: TextInjectorProtocol,
+        kiosk: bool = False,
+        config_manager: Optional[ConfigManager] = None,
     ):
         """
         Initialize the system tray indicator.
@@ -91,11 +106,22 @@ class TrayIndicator:
         Args:
             speech_engine: The speech recognition manager instance
             text_injector: The text injector instance
+            kiosk: Read-only mode; the status window opens and shows the transcripts
+            config_manager: Shared config manager, so Settings changes are seen
+                by the rest of the app; a new one is created if not given
         """
         self.speech_engine = speech_engine
         self.text_injector = text_injector
-        self.config_manager = ConfigManager()  # Added: Initialize ConfigManager
+        self.kiosk = kiosk
+        self.config_manager = config_manager or ConfigManager()
         self._syncing_autostart_menu = False
+        self._syncing_quick_toggles = False
+        self._shortcut_warning_shown = False
+        self._status_window = None
+        self._fallback_notifications = False
+        self._fallback_process = None
+        self._timed_session: Optional[TimedSession] = None
+        self._timed_session_dictating = False
 
         # Get configured shortcut and mode from config
         shortcut = self.config_manager.get_str("shortcuts", "toggle_recognition", "ctrl+ctrl")
@@ -103,6 +129,8 @@ class TrayIndicator:
 
         # Initialize keyboard shortcut manager with configured shortcut and mode
         self.shortcut_manager = KeyboardShortcutManager(shortcut=shortcut, mode=mode)
+        self._apply_tap_settings()
+        notifications.set_enabled(self._show_notifications())
 
         # Ensure icon directory exists
         os.makedirs(ICON_DIR, exist_ok=True)
@@ -115,15 +143,26 @@ class TrayIndicator:
         }
         self.icon_names = _themed_icon_names()
 
+        # Tell D-Bus listeners (and optionally an on-screen dot) when the microphone is recorded
+        self._capture_overlay = None
+        self._capture_indicator = CaptureIndicator(on_capturing_changed=self._on_capturing_changed)
+
         # Register for speech recognition state changes
         self.speech_engine.register_state_callback(self._on_recognition_state_changed)
 
+        # Recent transcripts for the tray submenu, kept in memory only
+        self.transcript_history = TranscriptHistory(self._transcript_history_size())
+        self.speech_engine.register_text_callback(self._on_final_text)
+
         # Initialize the icon files and validate resources
         self._init_icons()
         self._validate_resources()
 
         # Initialize the indicator (in the GTK main thread)
         GLib.idle_add(self._init_indicator)
+        if self.kiosk:
+            # Transcripts aren't typed anywhere, so show them from the start
+            GLib.idle_add(self.show_status_window)
 
         self._suspend_handler = SuspendHandler(
             on_suspend=self._on_system_suspend,
@@ -133,6 +172,32 @@ class TrayIndicator:
         # Set up keyboard shortcuts with mode support
         self._setup_keyboard_shortcuts()
 
+        # Optional second shortcut that stops everything and discards audio
+        self.panic_shortcut_manager = None
+        self._setup_panic_shortcut()
+
+        # Summarize degraded features in one notification instead of log warnings
+        if self._show_notifications():
+            threading.Thread(target=self._check_startup_health, daemon=True).start()
+
+    def _on_capturing_changed(self, capturing: bool):
+        """Show the red-dot overlay while recording, if it is turned on."""
+        if capturing and self.config_manager.get_bool("ui", "capture_overlay", False):
+            if self._capture_overlay is None:
+                from .capture_overlay import CaptureOverlay
+
+                self._capture_overlay = CaptureOverlay()
+            self._capture_overlay.show_dot()
+        elif self._capture_overlay is not None:
+            self._capture_overlay.hide()
+
+    def _apply_tap_settings(self):
+        """Apply the configured tap count and timing to the shortcut manager."""
+        self.shortcut_manager.set_tap_settings(
+            self.config_manager.get_int("shortcuts", "tap_count", DEFAULT_TAP_COUNT),
+            self.config_manager.get_int("shortcuts", "tap_threshold_ms", DEFAULT_TAP_THRESHOLD_MS),
+        )
+
     def _setup_keyboard_shortcuts(self):
         """Set up keyboard shortcuts based on configured mode."""
         # Stop existing shortcut manager if running
@@ -144,6 +209,7 @@ class TrayIndicator:
         self.shortcut_manager.register_toggle_callback(None)
         self.shortcut_manager.register_press_callback(None)
         self.shortcut_manager.register_release_callback(None)
+        self.shortcut_manager.register_cancel_callback(None)
 
         # Get configured mode from config
         mode = self.config_manager.get_str("shortcuts", "mode", "toggle")
@@ -152,13 +218,112 @@ class TrayIndicator:
         if mode == "toggle":
             # Register toggle callback for double-tap mode
             self.shortcut_manager.register_toggle_callback(self._toggle_recognition)
+            # Holding the key instead cancels the current utterance
+            self.shortcut_manager.register_cancel_callback(self._cancel_utterance)
         elif mode == "push_to_talk":
             # Register press/release callbacks for push-to-talk mode
             self.shortcut_manager.register_press_callback(self._start_recognition)
             self.shortcut_manager.register_release_callback(self._stop_recognition)
 
         # Start the keyboard shortcut manager
-        self.shortcut_manager.start()
+        if not self.shortcut_manager.start():
+            self._notify_shortcuts_unavailable()
+
+    def _setup_panic_shortcut(self):
+        """Listen for the configured panic shortcut, if any."""
+        if self.panic_shortcut_manager is not None:
+            self.panic_shortcut_manager.stop()
+            self.panic_shortcut_manager = None
+
+        shortcut = self.config_manager.get_str("shortcuts", "panic", "")
+        if not shortcut:
+            return
+        try:
+            spec = parse_shortcut_spec(shortcut)
+        except (ValueError, AttributeError):
+            logger.warning(f"Ignoring invalid panic shortcut: {shortcut!r}")
+            return
+        if not spec.is_combo:
+            # A modifier gesture would clash with the dictation shortcut's tap detection
+            logger.warning(f"Panic shortcut must be a modifier+key combo, got {shortcut!r}")
+            return
+
+        self.panic_shortcut_manager = KeyboardShortcutManager(shortcut=shortcut, mode="toggle")
+        self.panic_shortcut_manager.register_toggle_callback(self._panic_stop)
+        if not self.panic_shortcut_manager.start():
+            logger.warning("Panic shortcut could not be registered")
+
+    def update_panic_shortcut(self, shortcut: str) -> bool:
+        """
+        Switch the panic shortcut without restarting the app.
+
+        The caller is expected to have saved the new value to the config.
+
+        Args:
+            shortcut: A modifier+key combo (e.g. "ctrl+alt+m"), or "" to disable it
+
+        Returns:
+            True if the shortcut is listening (or was disabled), False otherwise
+        """
+        self._setup_panic_shortcut()
+        if not shortcut:
+            return True
+        return self.panic_shortcut_manager is not None and self.panic_shortcut_manager.active
+
+    def _panic_stop(self):
+        """Stop dictation immediately and discard everything captured."""
+        logger.info("Panic shortcut pressed")
+        self.speech_engine.panic_stop()
+
+    def _notify_shortcuts_unavailable(self):
+        """Tell the user (once per session) that global shortcuts don't work."""
+        if self._shortcut_warning_shown:
+            return
+        self._shortcut_warning_shown = True
+
+        guidance = self.shortcut_manager.get_unavailable_guidance()
+        if not guidance:
+            return
+        logger.warning(f"Keyboard shortcuts unavailable: {guidance}")
+        notifications.notify("Keyboard shortcuts unavailable", guidance, "dialog-warning")
+
+    def _check_startup_health(self):
+        """Run the doctor checks and notify once if any feature is degraded."""
+        from .. import doctor
+
+        results = doctor.run_health_checks(self.config_manager, self.speech_engine)
+        limitations = doctor.get_limitations(results)
+        if not limitations:
+            return
+
+        for result in limitations:
+            logger.warning(f"{result.name}: {result.message}")
+
+        title = "Vocalinux is running with limitations"
+        message = "\n".join(f"• {result.name}: {result.message}" for result in limitations)
+        notifications.notify_with_action(
+            title,
+            message,
+            "Show Report",
+            lambda: GLib.idle_add(self._show_health_report, results),
+            "dialog-warning",
+            fallback_message=message + "\n\nRun 'vocalinux doctor' for details.",
+        )
+
+    def _show_health_report(self, results):
+        """Show the doctor report in a dialog."""
+        from .. import doctor
+
+        dialog = Gtk.MessageDialog(
+            message_type=Gtk.MessageType.WARNING,
+            buttons=Gtk.ButtonsType.CLOSE,
+            text="Vocalinux Health Report",
+        )
+        dialog.set_title("Vocalinux Health Report")
+        dialog.format_secondary_text(doctor.format_report(results))
+        dialog.connect("response", lambda d, r: d.destroy())
+        dialog.show()
+        return False
 
     def _init_icons(self):
         """Initialize the icon files for the tray indicator."""
@@ -223,30 +388,52 @@ class TrayIndicator:
         except Exception as e:
             logger.error(f"Failed to create AppIndicator: {e}")
             GLib.idle_add(self._show_appindicator_error_dialog, str(e))
+            GLib.timeout_add_seconds(
+                _TRAYLESS_FALLBACK_DELAY_SECONDS, self._maybe_start_trayless_fallback
+            )
             return False
 
-        if not self._check_status_notifier_watcher():
+        if not self._check_status_notifier_watcher() or not self._check_status_notifier_host():
             logger.warning(
-                "No StatusNotifierWatcher on D-Bus session bus; tray icon may not appear. "
+                "No StatusNotifierWatcher/host on D-Bus session bus; tray icon may not appear. "
                 "On GNOME, install gnome-shell-extension-appindicator."
             )
             GLib.idle_add(self._show_missing_watcher_dialog)
+            GLib.timeout_add_seconds(
+                _TRAYLESS_FALLBACK_DELAY_SECONDS, self._maybe_start_trayless_fallback
+            )
 
         # Create the menu
         self.menu = Gtk.Menu()
 
         # Add menu items
+        self._add_menu_item("Show Status", self._on_status_clicked)
+        self._add_menu_separator()
         self._add_menu_item("Start Voice Typing", self._on_start_clicked)
         self._add_menu_item("Stop Voice Typing", self._on_stop_clicked)
+        timed_item = self._add_menu_item("Start Timed Session", self._on_timed_session_clicked)
+        timed_item.set_tooltip_text(
+            "Dictate for a set time, then stop and save the transcript to a file"
+        )
+        self._history_menu_item = self._add_menu_item("Recent Transcripts", None)
+        self._rebuild_history_menu()
         self._add_menu_separator()
 
         self._autostart_menu_item = self._add_menu_checkbox(
             "Start on Login", self._on_autostart_toggled
         )
         self._update_autostart_checkbox()
+        self._partial_results_menu_item = self._add_menu_checkbox(
+            "Type While Speaking", self._on_partial_results_toggled
+        )
+        self._notifications_menu_item = self._add_menu_checkbox(
+            "Show Notifications", self._on_notifications_toggled
+        )
+        self._update_quick_toggles()
 
         self._add_menu_separator()
         self._add_menu_item("Settings", self._on_settings_clicked)
+        self._add_menu_item("Voice Commands", self._on_commands_clicked)
         self._add_menu_item("View Logs", self._on_logs_clicked)
         self._add_menu_separator()
         self._add_menu_item("About", self._on_about_clicked)
@@ -290,6 +477,73 @@ class TrayIndicator:
 
         return True
 
+    @staticmethod
+    def _check_status_notifier_host() -> bool:
+        """Check that a tray (StatusNotifierHost) has registered with the watcher."""
+        try:
+            proxy = Gio.DBusProxy.new_for_bus_sync(
+                Gio.BusType.SESSION,
+                Gio.DBusProxyFlags.DO_NOT_AUTO_START_AT_CONSTRUCTION,
+                None,
+                "org.kde.StatusNotifierWatcher",
+                "/StatusNotifierWatcher",
+                "org.kde.StatusNotifierWatcher",
+                None,
+            )
+            registered = proxy.get_cached_property("IsStatusNotifierHostRegistered")
+            if registered is not None:
+                return bool(registered.unpack())
+        except Exception:
+            pass
+
+        return True
+
+    def _maybe_start_trayless_fallback(self):
+        """Fall back to the tray-less UI unless a tray has appeared in the meantime."""
+        if (
+            hasattr(self, "indicator")
+            and self._check_status_notifier_watcher()
+            and self._check_status_notifier_host()
+        ):
+            logger.info("System tray became available; tray-less fallback not needed")
+            return False
+        return self._start_trayless_fallback()
+
+    def _start_trayless_fallback(self):
+        """Keep Vocalinux visible and controllable when there is no tray to show the icon."""
+        logger.info("No system tray available; showing status window and notification controls")
+        self.show_status_window()
+        if not self._fallback_notifications and self._show_notifications():
+            self._fallback_notifications = True
+            threading.Thread(target=self._run_fallback_notification, daemon=True).start()
+        return False
+
+    def _run_fallback_notification(self):
+        """Show a persistent notification with dictation controls until it is dismissed."""
+        while self._fallback_notifications:
+            action = notifications.wait_for_action(
+                "Vocalinux is running",
+                "No system tray was found. Use these buttons, the status window or your shortcut.",
+                {"toggle": "Start/Stop Voice Typing", "status": "Show Status"},
+                "audio-input-microphone",
+                resident=True,
+                on_started=self._set_fallback_process,
+            )
+            # Dismissed, or an older notify-send without action support: stop re-posting
+            if not action:
+                break
+            if action == "toggle":
+                GLib.idle_add(self._toggle_recognition)
+            elif action == "status":
+                GLib.idle_add(self.show_status_window)
+
+        self._fallback_notifications = False
+        self._fallback_process = None
+
+    def _set_fallback_process(self, process):
+        """Remember the fallback notification's notify-send, so quitting can close it."""
+        self._fallback_process = process
+
     def _show_missing_watcher_dialog(self):
         dialog = Gtk.MessageDialog(
             flags=Gtk.DialogFlags.MODAL,
@@ -305,7 +559,8 @@ class TrayIndicator:
             "\n"
             "Then log out and back in (or press Alt+F2, type 'r', press Enter).\n"
             "\n"
-            "Keyboard shortcuts will still work even without the tray icon."
+            "Until then, use the Vocalinux status window, its notification or your keyboard "
+            "shortcut to control dictation."
         )
         dialog.connect("response", lambda d, _: d.destroy())
         dialog.show()
@@ -338,6 +593,11 @@ class TrayIndicator:
         else:
             self.speech_engine.stop_recognition()
 
+    def _cancel_utterance(self):
+        """Discard the utterance in progress, if recognition is running."""
+        if self.speech_engine.state != RecognitionState.IDLE:
+            self.speech_engine.cancel_utterance()
+
     def _start_recognition(self):
         """Start voice recognition (for push-to-talk mode)."""
         if self.speech_engine.state == RecognitionState.IDLE:
@@ -348,16 +608,18 @@ class TrayIndicator:
         if self.speech_engine.state != RecognitionState.IDLE:
             self.speech_engine.stop_recognition()
 
-    def _add_menu_item(self, label: str, callback: Callable):
+    def _add_menu_item(self, label: str, callback: Optional[Callable]):
         """
         Add a menu item to the indicator menu.
 
         Args:
             label: The label for the menu item
-            callback: The callback function to call when the item is clicked
+            callback: The callback function to call when the item is clicked, or
+                None for an item that only opens a submenu
         """
         item = Gtk.MenuItem.new_with_label(label)
-        item.connect("activate", callback)
+        if callback is not None:
+            item.connect("activate", callback)
         self.menu.append(item)
         return item
 
@@ -416,6 +678,135 @@ class TrayIndicator:
             widget.set_active(not enabled)
             self._syncing_autostart_menu = False
 
+    def _show_notifications(self) -> bool:
+        return bool(self.config_manager.get_bool("ui", "show_notifications", True))
+
+    def _update_quick_toggles(self):
+        """Check the quick-toggle menu items to match the saved settings."""
+        if not hasattr(self, "_partial_results_menu_item"):
+            return
+        self._syncing_quick_toggles = True
+        self._partial_results_menu_item.set_active(
+            bool(self.config_manager.get_bool("speech_recognition", "partial_results", False))
+        )
+        self._notifications_menu_item.set_active(self._show_notifications())
+        self._syncing_quick_toggles = False
+
+    def _on_partial_results_toggled(self, widget):
+        """Handle toggle of the Type While Speaking menu item."""
+        if self._syncing_quick_toggles:
+            return
+
+        enabled = widget.get_active()
+        self.config_manager.set("speech_recognition", "partial_results", enabled)
+        self.config_manager.save_settings()
+        try:
+            self.speech_engine.reconfigure(partial_results=enabled, force_download=False)
+        except Exception as e:
+            logger.warning(f"Failed to apply partial results: {e}")
+        logger.info(f"Type while speaking {'enabled' if enabled else 'disabled'} from the tray")
+
+    def _on_notifications_toggled(self, widget):
+        """Handle toggle of the Show Notifications menu item."""
+        if self._syncing_quick_toggles:
+            return
+
+        enabled = widget.get_active()
+        self.config_manager.set("ui", "show_notifications", enabled)
+        self.config_manager.save_settings()
+        notifications.set_enabled(enabled)
+        logger.info(f"Notifications {'enabled' if enabled else 'disabled'} from the tray")
+
+    def _transcript_history_size(self) -> int:
+        """How many recent transcripts the tray menu lists."""
+        return self.config_manager.get_int("ui", "transcript_history_size", DEFAULT_HISTORY_SIZE)
+
+    def _on_final_text(self, text: str):
+        """Remember a final transcript (called from the recognition thread)."""
+        language = getattr(self.speech_engine, "detected_language", None)
+        self.transcript_history.add(text, language if isinstance(language, str) else None)
+        GLib.idle_add(self._rebuild_history_menu)
+        session = self._timed_session
+        if session is not None:
+            session.add_text(text)
+
+    def _rebuild_history_menu(self):
+        """List the recent transcripts in the Recent Transcripts submenu."""
+        if not hasattr(self, "_history_menu_item"):
+            return False
+
+        submenu = Gtk.Menu()
+        entries = self.transcript_history.items()
+        for text, language in entries:
+            item = Gtk.MenuItem.new_with_label(menu_label(text, language=language))
+            item.set_tooltip_text(text)
+            if self.speech_engine.can_retry(text):
+                item.set_submenu(self._history_item_menu(text))
+            else:
+                item.connect("activate", self._on_history_item_clicked, text)
+            submenu.append(item)
+        if entries:
+            submenu.append(Gtk.SeparatorMenuItem())
+            clear_item = Gtk.MenuItem.new_with_label("Clear")
+            clear_item.connect("activate", self._on_clear_history_clicked)
+            submenu.append(clear_item)
+        else:
+            empty_item = Gtk.MenuItem.new_with_label("Nothing dictated yet")
+            empty_item.set_sensitive(False)
+            submenu.append(empty_item)
+        submenu.show_all()
+
+        self._history_menu_item.set_submenu(submenu)
+        self._history_menu_item.set_visible(self.transcript_history.max_entries > 0)
+        return False  # Remove idle callback
+
+    def _history_item_menu(self, text: str) -> Gtk.Menu:
+        """Actions for a recent transcript whose audio is kept."""
+        menu = Gtk.Menu()
+        copy_item = Gtk.MenuItem.new_with_label("Copy")
+        copy_item.connect("activate", self._on_history_item_clicked, text)
+        menu.append(copy_item)
+        retry_item = Gtk.MenuItem.new_with_label("Retry with Larger Model")
+        retry_item.set_tooltip_text(
+            "Transcribe it again with the larger model and copy the result to the clipboard"
+        )
+        retry_item.connect("activate", self._on_history_retry_clicked, text)
+        menu.append(retry_item)
+        return menu
+
+    def _on_history_retry_clicked(self, widget, text: str):
+        """Transcribe a recent utterance again with the larger model."""
+        logger.debug("Retry of a recent transcript clicked")
+        # Loading the larger model can take a while
+        threading.Thread(target=self._retry_transcript, args=(text,), daemon=True).start()
+
+    def _retry_transcript(self, text: str):
+        """Retry a recent transcript and copy the new text (runs in a background thread)."""
+        retried = self.speech_engine.retry_utterance(text)
+        if not retried:
+            notifications.notify(
+                "Retry Failed",
+                "The larger model could not transcribe it again. Check that it is downloaded.",
+                "dialog-warning",
+                category="retry",
+            )
+            return
+        self.text_injector._copy_to_clipboard(retried)
+        notifications.notify("Retried Transcript Copied", retried, "edit-paste", category="retry")
+
+    def _on_history_item_clicked(self, widget, text: str):
+        """Copy a recent transcript to the clipboard."""
+        logger.debug("Recent transcript clicked")
+        # Clipboard tools are external programs; don't block the menu on them
+        threading.Thread(
+            target=self.text_injector._copy_to_clipboard, args=(text,), daemon=True
+        ).start()
+
+    def _on_clear_history_clicked(self, widget):
+        """Forget the recent transcripts."""
+        self.transcript_history.clear()
+        self._rebuild_history_menu()
+
     def _on_recognition_state_changed(self, state: RecognitionState):
         """
         Handle changes in the speech recognition state.
@@ -423,6 +814,16 @@ class TrayIndicator:
         Args:
             state: The new recognition state
         """
+        self._capture_indicator.on_state_changed(state)
+
+        if self._timed_session is not None:
+            if state in (RecognitionState.LISTENING, RecognitionState.PROCESSING):
+                self._timed_session_dictating = True
+            elif self._timed_session_dictating:
+                # Time is up or dictation was stopped early; the last
+                # transcript has been delivered by now
+                self._finish_timed_session()
+
         # Update the UI in the GTK main thread
         GLib.idle_add(self._update_ui, state)
 
@@ -452,9 +853,22 @@ class TrayIndicator:
             self.indicator.set_icon_full(self.icon_names["default"], "Error")
             self._set_menu_item_enabled("Start Voice Typing", True)
             self._set_menu_item_enabled("Stop Voice Typing", False)
+        self._set_menu_item_enabled("Start Timed Session", self._timed_session is None)
+
+        self._update_tooltip()
 
         return False  # Remove idle callback
 
+    def _update_tooltip(self):
+        """Show decoding speed and queue depth in the tray tooltip."""
+        try:
+            summary = self.speech_engine.get_performance_summary()
+        except Exception as e:
+            logger.debug(f"Could not get performance summary: {e}")
+            return
+
+        self.indicator.set_title(f"Vocalinux: {summary}")
+
     def _set_menu_item_enabled(self, label: str, enabled: bool):
         """
         Set the enabled state of a menu item by its label.
@@ -481,16 +895,99 @@ class TrayIndicator:
         logger.debug("Stop Voice Typing clicked")
         self.speech_engine.stop_recognition()
 
+    def _on_timed_session_clicked(self, widget):
+        """Start a timed session: dictate for the configured minutes, then save it."""
+        logger.debug("Start Timed Session clicked")
+        if self._timed_session is not None:
+            return
+        minutes = self.config_manager.get_float(
+            "general", "timed_session_minutes", DEFAULT_SESSION_MINUTES
+        )
+        self._timed_session = TimedSession(
+            minutes,
+            on_expired=lambda: GLib.idle_add(self._stop_recognition),
+        )
+        self._timed_session_dictating = self.speech_engine.state in (
+            RecognitionState.LISTENING,
+            RecognitionState.PROCESSING,
+        )
+        self._timed_session.start()
+        self._set_menu_item_enabled("Start Timed Session", False)
+        if not self._timed_session_dictating:
+            self.speech_engine.start_recognition()
+
+    def _finish_timed_session(self):
+        """Save the timed session's transcript and show a summary."""
+        session, self._timed_session = self._timed_session, None
+        self._timed_session_dictating = False
+        if session is None:
+            return
+        result = session.finish()
+        if result is None:
+            notifications.notify(
+                "Timed Session Not Saved",
+                "The transcript could not be written. See View Logs for details.",
+                "dialog-warning",
+            )
+            return
+        path, words, elapsed = result
+        notifications.notify_with_action(
+            "Timed Session Finished",
+            f"{words} word{'' if words == 1 else 's'} in {format_elapsed(elapsed)}, "
+            f"saved to {os.path.basename(path)}",
+            "Open Transcript",
+            lambda: self._open_file(path),
+            "document-save",
+        )
+
+    @staticmethod
+    def _open_file(path: str):
+        """Open a file in the desktop's default application."""
+        try:
+            subprocess.Popen(
+                ["xdg-open", path], stdout=subprocess.DEVNULL, stderr=subprocess.DEVNULL
+            )
+        except (FileNotFoundError, OSError) as e:
+            logger.warning(f"Could not open {path}: {e}")
+
+    def _on_status_clicked(self, widget):
+        """Handle click on the Show Status menu item."""
+        logger.debug("Show Status clicked")
+        self.show_status_window()
+
+    def show_status_window(self):
+        """Show the status window, creating it if needed."""
+        if self._status_window is None:
+            from .status_window import StatusWindow
+
+            self._status_window = StatusWindow(
+                self.speech_engine,
+                on_settings=lambda: self._on_settings_clicked(None),
+                show_transcripts=self.kiosk,
+            )
+            self._status_window.connect("destroy", self._on_status_window_destroyed)
+            self._status_window.show_all()
+        self._status_window.present()
+        return False  # Remove idle callback
+
+    def _on_status_window_destroyed(self, window):
+        """Forget the status window once it is closed."""
+        self._status_window = None
+
     def _on_settings_clicked(self, widget):
         """Handle click on the Settings menu item."""
         logger.debug("Settings clicked")
 
+        # Pick up changes saved elsewhere (e.g. by voice commands)
+        self.config_manager.load_config()
+
         # Create the settings dialog
         dialog = SettingsDialog(
             parent=None,  # Or get the main window if available
             config_manager=self.config_manager,
             speech_engine=self.speech_engine,
             shortcut_update_callback=self.update_shortcut,
+            panic_shortcut_update_callback=self.update_panic_shortcut,
         )
 
         # Connect to the response signal
@@ -499,6 +996,18 @@ class TrayIndicator:
         # Show the dialog (non-modal)
         dialog.show()
 
+    def _on_commands_clicked(self, widget):
+        """Handle click on the Voice Commands menu item."""
+        logger.debug("Voice Commands clicked")
+
+        from .command_reference_dialog import CommandReferenceDialog
+
+        dialog = CommandReferenceDialog(
+            parent=None,
+            command_processor=getattr(self.speech_engine, "command_processor", None),
+        )
+        dialog.show()
+
     def _on_logs_clicked(self, widget):
         """Handle click on the View Logs menu item."""
         logger.debug("View Logs clicked")
@@ -516,6 +1025,9 @@ class TrayIndicator:
         if response == Gtk.ResponseType.CLOSE or response == Gtk.ResponseType.DELETE_EVENT:
             logger.info("Settings dialog closed.")
             dialog.destroy()
+            self.transcript_history.max_entries = self._transcript_history_size()
+            self._rebuild_history_menu()
+            self._update_quick_toggles()
 
     def update_shortcut(self, shortcut: str, mode: Optional[str] = None) -> bool:
         """
@@ -649,13 +1161,28 @@ class TrayIndicator:
         """Quit the application."""
         logger.info("Quitting application")
 
+        # Finish dictation in progress and release audio and network resources
+        try:
+            self.speech_engine.shutdown()
+        except Exception as e:
+            logger.warning(f"Error shutting down speech recognition: {e}")
+
         if self._suspend_handler is not None:
             self._suspend_handler.shutdown()
+        self._capture_indicator.shutdown()
 
         self._cleanup_input_monitor()
 
-        # Stop the keyboard shortcut manager
+        # Remove the tray-less fallback notification
+        self._fallback_notifications = False
+        fallback_process = getattr(self, "_fallback_process", None)
+        if fallback_process is not None and fallback_process.poll() is None:
+            fallback_process.terminate()
+
+        # Stop the keyboard shortcut managers
         self.shortcut_manager.stop()
+        if getattr(self, "panic_shortcut_manager", None) is not None:
+            self.panic_shortcut_manager.stop()
 
         # Stop the text injector (restores previous IBus engine)
         if hasattr(self, "text_injector") and self.text_injector is not None:
@@ -670,6 +1197,10 @@ class TrayIndicator:
         # Set up signal handlers for graceful termination
         signal.signal(signal.SIGINT, self._signal_handler)
         signal.signal(signal.SIGTERM, self._signal_handler)
+        # Sent when the session or terminal Vocalinux runs in ends
+        signal.signal(signal.SIGHUP, self._signal_handler)
+        # Sent by a second launch of Vocalinux
+        signal.signal(SHOW_STATUS_SIGNAL, self._show_status_signal_handler)
 
         # Start the GTK main loop
         try:
@@ -679,7 +1210,7 @@ class TrayIndicator:
 
     def _signal_handler(self, sig, frame):
         """
-        Handle signals (e.g., SIGINT, SIGTERM).
+        Handle signals (e.g., SIGINT, SIGTERM, SIGHUP).
 
         Args:
             sig: The signal number
@@ -687,3 +1218,8 @@ class TrayIndicator:
         """
         logger.info(f"Received signal {sig}, shutting down...")
         GLib.idle_add(self._quit)
+
+    def _show_status_signal_handler(self, sig, frame):
+        """Show the status window when another launch asks for it."""
+        logger.info("Vocalinux launched again, showing status window")
+        GLib.idle_add(self.show_status_window)
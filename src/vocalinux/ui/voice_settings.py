@@ -0,0 +1,213 @@
+"""
+Voice-controlled settings for Vocalinux.
+
+Handles meta-commands like "increase sensitivity", "switch to small model" or
+"translate to English"
+that adjust the running speech engine and persist the new value to the config.
+"""
+
+import logging
+import threading
+from typing import TYPE_CHECKING, Callable, Optional
+
+if TYPE_CHECKING:
+    from ..speech_recognition.recognition_manager import SpeechRecognitionManager
+    from .config_manager import ConfigManager
+
+logger = logging.getLogger(__name__)
+
+# Limits match SpeechRecognitionManager.reconfigure's clamping
+VAD_SENSITIVITY_MIN = 1
+VAD_SENSITIVITY_MAX = 5
+SILENCE_TIMEOUT_MIN = 0.5
+SILENCE_TIMEOUT_MAX = 5.0
+SILENCE_TIMEOUT_STEP = 0.5
+
+# Model sizes that can be selected by voice ("switch to <size> model")
+VOICE_MODEL_SIZES = ["tiny", "base", "small", "medium", "large"]
+
+# Engines with a translate-to-English mode
+TRANSLATE_ENGINES = ("whisper", "whisper_cpp")
+
+# Sizes each engine accepts; remote_api has no local models
+ENGINE_MODEL_SIZES = {
+    "vosk": ["small", "medium", "large"],
+    "whisper": ["tiny", "base", "small", "medium", "large"],
+    "whisper_cpp": ["tiny", "base", "small", "medium", "large"],
+}
+
+
+class VoiceSettingsHandler:
+    """
+    Applies settings changes requested by voice command.
+
+    Changes go through SpeechRecognitionManager.reconfigure so they take
+    effect immediately, and are saved via the config manager so they survive
+    a restart.
+    """
+
+    def __init__(
+        self,
+        speech_engine: "SpeechRecognitionManager",
+        config_manager: "ConfigManager",
+        notify: Optional[Callable[[str, str], None]] = None,
+    ):
+        """
+        Initialize the voice settings handler.
+
+        Args:
+            speech_engine: The running speech recognition manager
+            config_manager: Config manager used to persist changes
+            notify: Optional callback(title, message) to report changes
+        """
+        self.speech_engine = speech_engine
+        self.config_manager = config_manager
+        self.notify = notify
+
+    def get_action_handlers(self) -> dict[str, Callable[[], bool]]:
+        """Return the voice command actions handled by this class."""
+        handlers: dict[str, Callable[[], bool]] = {
+            "increase_sensitivity": lambda: self.adjust_vad_sensitivity(1),
+            "decrease_sensitivity": lambda: self.adjust_vad_sensitivity(-1),
+            "increase_silence_timeout": lambda: self.adjust_silence_timeout(SILENCE_TIMEOUT_STEP),
+            "decrease_silence_timeout": lambda: self.adjust_silence_timeout(-SILENCE_TIMEOUT_STEP),
+            "enable_translation": lambda: self.set_translate(True),
+            "disable_translation": lambda: self.set_translate(False),
+        }
+        for size in VOICE_MODEL_SIZES:
+            handlers[f"switch_model_{size}"] = self._make_switch_model_handler(size)
+        return handlers
+
+    def adjust_vad_sensitivity(self, delta: int) -> bool:
+        """
+        Raise or lower the VAD sensitivity by delta steps.
+
+        The value changed is the one in effect: the current engine's
+        engine_vad override if it has one, else the global setting.
+
+        Returns:
+            True if the value changed, False if it is already at the limit
+        """
+        current = int(self.speech_engine.vad_setting("vad_sensitivity"))
+        new_value = max(VAD_SENSITIVITY_MIN, min(VAD_SENSITIVITY_MAX, current + delta))
+        label = self._vad_setting_label("VAD sensitivity", "vad_sensitivity")
+        if new_value == current:
+            self._notify("Sensitivity unchanged", f"{label} is already {current}")
+            return False
+
+        self.speech_engine.reconfigure(vad_sensitivity=new_value, force_download=False)
+        self._save_vad_setting("vad_sensitivity", new_value)
+        self._notify("Sensitivity changed", f"{label} set to {new_value}")
+        return True
+
+    def adjust_silence_timeout(self, delta: float) -> bool:
+        """
+        Lengthen or shorten the silence timeout by delta seconds.
+
+        Like adjust_vad_sensitivity, this changes the value in effect.
+
+        Returns:
+            True if the value changed, False if it is already at the limit
+        """
+        current = float(self.speech_engine.vad_setting("silence_timeout"))
+        new_value = max(SILENCE_TIMEOUT_MIN, min(SILENCE_TIMEOUT_MAX, current + delta))
+        new_value = round(new_value, 2)
+        label = self._vad_setting_label("Silence timeout", "silence_timeout")
+        if new_value == current:
+            self._notify("Silence timeout unchanged", f"{label} is already {current:g}s")
+            return False
+
+        self.speech_engine.reconfigure(silence_timeout=new_value, force_download=False)
+        self._save_vad_setting("silence_timeout", new_value)
+        self._notify("Silence timeout changed", f"{label} set to {new_value:g}s")
+        return True
+
+    def set_translate(self, enabled: bool) -> bool:
+        """
+        Turn translation of speech into English text on or off.
+
+        Returns:
+            True if the setting changed, False if it was already set or the
+            engine cannot translate
+        """
+        engine = self.speech_engine.engine
+        if enabled and engine not in TRANSLATE_ENGINES:
+            self._notify("Translation not available", f"{engine} cannot translate speech")
+            return False
+        if bool(self.speech_engine.translate) == enabled:
+            logger.debug(f"Translation already {'on' if enabled else 'off'}")
+            return False
+
+        self.speech_engine.reconfigure(translate=enabled, force_download=False)
+        self._save("translate", enabled)
+        if enabled:
+            self._notify("Translation on", "Speech will be typed as English text")
+        else:
+            self._notify("Translation off", "Speech will be typed in the spoken language")
+        return True
+
+    def switch_model(self, model_size: str) -> bool:
+        """
+        Switch the current engine to another model size.
+
+        Loading a model stops recognition and joins the recognition thread,
+        which is the thread voice commands run on, so the switch happens in
+        the background.
+
+        Returns:
+            True if the switch was started, False if the size is not valid
+        """
+        engine = self.speech_engine.engine
+        valid_sizes = ENGINE_MODEL_SIZES.get(engine, [])
+        if model_size not in valid_sizes:
+            logger.warning(f"Model size '{model_size}' is not available for engine '{engine}'")
+            self._notify("Model not available", f"{engine} has no '{model_size}' model")
+            return False
+
+        if model_size == self.speech_engine.model_size:
+            logger.debug(f"Already using the {model_size} model")
+            return True
+
+        self.config_manager.load_config()
+        self.config_manager.set_model_size_for_engine(engine, model_size)
+        self.config_manager.save_config()
+        self._notify("Switching model", f"Loading the {model_size} {engine} model")
+
+        thread = threading.Thread(target=self._reconfigure_model, args=(model_size,), daemon=True)
+        thread.start()
+        return True
+
+    def _reconfigure_model(self, model_size: str):
+        try:
+            self.speech_engine.reconfigure(model_size=model_size, force_download=False)
+        except Exception as e:
+            logger.error(f"Failed to switch to the {model_size} model: {e}")
+            self._notify("Model switch failed", str(e))
+
+    def _make_switch_model_handler(self, model_size: str) -> Callable[[], bool]:
+        def handler() -> bool:
+            return self.switch_model(model_size)
+
+        return handler
+
+    def _save(self, key: str, value):
+        # Reload first so changes made in the Settings dialog aren't overwritten
+        self.config_manager.load_config()
+        self.config_manager.set("speech_recognition", key, value)
+        self.config_manager.save_config()
+
+    def _save_vad_setting(self, key: str, value):
+        # The engine's engine_vad override, if it has one, is what takes effect
+        self.config_manager.load_config()
+        self.config_manager.set_vad_setting(self.speech_engine.engine, key, value)
+        self.config_manager.save_config()
+
+    def _vad_setting_label(self, name: str, key: str) -> str:
+        if self.speech_engine.overrides_vad_setting(key):
+            return f"{name} for {self.speech_engine.engine}"
+        return name
+
+    def _notify(self, title: str, message: str):
+        logger.info(f"{title}: {message}")
+        if self.notify:
+            self.notify(title, message)
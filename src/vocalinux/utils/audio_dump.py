@@ -0,0 +1,80 @@
+"""
+Debug dumps of the captured utterances for Vocalinux.
+
+When the tray shows Vocalinux listening but nothing gets transcribed, the
+first question is what the engine actually heard. With a dump directory set
+(`vocalinux --dump-audio DIR` or advanced.dump_audio_dir), every utterance
+is written there as a timestamped 16 kHz mono WAV right before it is
+transcribed, so it can be played back or attached to a bug report.
+
+The files hold everything the microphone picked up, so dumping stays off
+unless it is asked for, and old files are never deleted automatically.
+"""
+
+import logging
+import os
+import threading
+import time
+import wave
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+
+class AudioDumper:
+    """Writes each utterance to its own WAV file."""
+
+    def __init__(self, directory: Optional[str] = None, sample_rate: int = 16000):
+        """
+        Initialize the dumper.
+
+        Args:
+            directory: Where to write the files (None or "" = don't dump)
+            sample_rate: Sample rate of the 16-bit mono audio
+        """
+        self.directory = directory
+        self.sample_rate = sample_rate
+        self._sequence = 0
+        self._lock = threading.Lock()
+
+    @property
+    def enabled(self) -> bool:
+        return bool(self.directory)
+
+    def dump(self, audio_buffer: list[bytes], timestamp: Optional[float] = None) -> Optional[str]:
+        """
+        Write an utterance to a new WAV file; does nothing when dumping is off.
+
+        Files are named utterance-<date>-<time>-<sequence>.wav, so they sort
+        in the order they were captured.
+
+        Args:
+            audio_buffer: The utterance's 16-bit mono chunks
+            timestamp: When it was captured (default: now)
+
+        Returns:
+            The path written, or None if dumping is off or the file couldn't be written
+        """
+        if not self.enabled or not audio_buffer:
+            return None
+        timestamp = time.time() if timestamp is None else timestamp
+        directory = os.path.expanduser(self.directory)
+        with self._lock:
+            self._sequence += 1
+            name = (
+                f"utterance-{time.strftime('%Y%m%d-%H%M%S', time.localtime(timestamp))}"
+                f"-{self._sequence:04d}.wav"
+            )
+            path = os.path.join(directory, name)
+            try:
+                os.makedirs(directory, exist_ok=True)
+                with wave.open(path, "wb") as wav_file:
+                    wav_file.setnchannels(1)
+                    wav_file.setsampwidth(2)
+                    wav_file.setframerate(self.sample_rate)
+                    wav_file.writeframes(b"".join(audio_buffer))
+            except (OSError, wave.Error) as e:
+                logger.warning(f"Could not write the audio dump {path}: {e}")
+                return None
+        logger.debug(f"Dumped utterance audio to {path}")
+        return path
@@ -0,0 +1,199 @@
+"""
+API key storage in the system keyring for Vocalinux.
+
+Keys are stored per account, so a work and a personal key for the same
+service don't overwrite each other. Each key is a keyring entry in the
+"vocalinux" service named "<account>/<key name>", e.g.
+vocalinux/work/remote_api_key. Without the keyring package (or without a
+running keyring daemon) the key stays in the config file, which holds one
+key for the current account only.
+
+Reading the keyring can block while the desktop asks the user to unlock it
+(e.g. KDE Wallet's first-use prompt), so the running app reads keys with
+DeferredSecret on a worker thread.
+"""
+
+import logging
+import re
+import threading
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+KEYRING_SERVICE = "vocalinux"
+DEFAULT_ACCOUNT = "default"
+REMOTE_API_KEY = "remote_api_key"
+
+# Account names become part of the keyring entry name
+_ACCOUNT_NAME_RE = re.compile(r"^[\w .@-]{1,64}$")
+
+
+def is_valid_account_name(name: str) -> bool:
+    """Whether a name can be used for an account."""
+    return bool(name) and name == name.strip() and bool(_ACCOUNT_NAME_RE.match(name))
+
+
+def entry_name(account: Optional[str], key_name: str = REMOTE_API_KEY) -> str:
+    """The keyring user name for an account's key."""
+    return f"{account or DEFAULT_ACCOUNT}/{key_name}"
+
+
+def _keyring():
+    try:
+        import keyring
+    except ImportError:
+        return None
+    return keyring
+
+
+def keyring_available() -> bool:
+    """Whether the keyring package is installed."""
+    return _keyring() is not None
+
+
+def _read_secret(account: Optional[str], key_name: str = REMOTE_API_KEY) -> Optional[str]:
+    # Raises if the keyring can't be read, so callers can tell "locked" from "not stored"
+    keyring = _keyring()
+    if keyring is None:
+        return None
+    return keyring.get_password(KEYRING_SERVICE, entry_name(account, key_name))
+
+
+def get_secret(account: Optional[str], key_name: str = REMOTE_API_KEY) -> Optional[str]:
+    """
+    Read an account's key from the keyring.
+
+    Returns:
+        The key, or None if it isn't stored or the keyring can't be read
+    """
+    try:
+        return _read_secret(account, key_name)
+    except Exception as e:
+        logger.debug(f"Could not read {entry_name(account, key_name)} from the keyring: {e}")
+        return None
+
+
+def set_secret(account: Optional[str], value: str, key_name: str = REMOTE_API_KEY) -> bool:
+    """
+    Store an account's key in the keyring; an empty value removes it.
+
+    Returns:
+        True if the keyring holds the new value, False if it couldn't be used
+    """
+    if not value:
+        return delete_secret(account, key_name)
+    keyring = _keyring()
+    if keyring is None:
+        return False
+    try:
+        keyring.set_password(KEYRING_SERVICE, entry_name(account, key_name), value)
+        return True
+    except Exception as e:
+        logger.warning(f"Could not store {entry_name(account, key_name)} in the keyring: {e}")
+        return False
+
+
+def delete_secret(account: Optional[str], key_name: str = REMOTE_API_KEY) -> bool:
+    """
+    Remove an account's key from the keyring.
+
+    Returns:
+        True if the key is no longer stored, False if the keyring couldn't be used
+    """
+    keyring = _keyring()
+    if keyring is None:
+        return False
+    try:
+        keyring.delete_password(KEYRING_SERVICE, entry_name(account, key_name))
+    except Exception as e:
+        # keyring raises PasswordDeleteError when there was nothing to delete
+        if type(e).__name__ != "PasswordDeleteError":
+            name = entry_name(account, key_name)
+            logger.warning(f"Could not remove {name} from the keyring: {e}")
+            return False
+    return True
+
+
+def resolve_remote_api_key(settings: dict) -> str:
+    """
+    The remote API key for the configured account.
+
+    Args:
+        settings: The speech_recognition config section
+
+    Returns:
+        The key from the keyring, else the one in the config file, else ""
+    """
+    account = settings.get("remote_api_account") or DEFAULT_ACCOUNT
+    return get_secret(account) or settings.get("remote_api_key", "") or ""
+
+
+class DeferredSecret:
+    """
+    Reads an account's key from the keyring on a worker thread.
+
+    The read starts right away but nothing waits for it, so a keyring that
+    is locked behind an unlock prompt doesn't hold up startup. If the read
+    fails (the prompt was dismissed, the keyring is unavailable), it is tried
+    again the next time the key is needed.
+    """
+
+    def __init__(self, account: Optional[str], key_name: str = REMOTE_API_KEY):
+        """
+        Initialize and start reading.
+
+        Args:
+            account: Account whose key to read
+            key_name: Name of the key within the account
+        """
+        self.account = account or DEFAULT_ACCOUNT
+        self.key_name = key_name
+        self._lock = threading.Lock()
+        self._done = threading.Event()
+        self._thread: Optional[threading.Thread] = None
+        self._value: Optional[str] = None
+        self._failed = False
+        self._start()
+
+    def _start(self):
+        with self._lock:
+            if self._thread is not None and self._thread.is_alive():
+                return
+            self._done.clear()
+            self._failed = False
+            self._thread = threading.Thread(target=self._read, name="keyring-reader", daemon=True)
+            self._thread.start()
+
+    def _read(self):
+        name = entry_name(self.account, self.key_name)
+        try:
+            value = _read_secret(self.account, self.key_name)
+        except Exception as e:
+            logger.warning(f"Could not read {name} from the keyring: {e}")
+            self._failed = True
+        else:
+            self._value = value
+            logger.debug(f"Read {name} from the keyring ({'found' if value else 'not stored'})")
+        finally:
+            self._done.set()
+
+    @property
+    def locked(self) -> bool:
+        """Whether the key hasn't been read yet (keyring still locked or read failed)."""
+        return not self._done.is_set() or self._failed
+
+    def get(self, wait: float = 0.0) -> Optional[str]:
+        """
+        The key, waiting up to ``wait`` seconds for the keyring.
+
+        Starts another read if the last one failed.
+
+        Returns:
+            The key, "" if the keyring has none for the account, or None if
+            the keyring is still locked
+        """
+        if self._done.is_set() and self._failed:
+            self._start()
+        if not self._done.wait(wait) or self._failed:
+            return None
+        return self._value or ""
@@ -0,0 +1,210 @@
+"""
+Audio device hotplug detection for Vocalinux.
+
+PortAudio only enumerates devices when it is initialized, so a USB headset
+plugged in or pulled out mid-dictation goes unnoticed: the stream raises or
+just delivers silence from then on. While recording, DeviceMonitor polls the
+PulseAudio/PipeWire input sources with pactl, and the recording loop
+re-creates PyAudio and reopens the microphone when one appears or
+disappears. The default source is watched too, so when the user picks
+another input in the desktop's sound settings, dictation recording from the
+default input moves to it. Without pactl (plain ALSA) neither is detected.
+"""
+
+import logging
+import os
+import subprocess
+import threading
+from dataclasses import dataclass
+from typing import Callable, Optional
+
+from .pulse_sources import is_monitor_source
+
+logger = logging.getLogger(__name__)
+
+# How often the input sources are listed while recording
+DEVICE_POLL_SECONDS = 1.0
+
+_PACTL_TIMEOUT = 2.0
+
+
+@dataclass(frozen=True)
+class DeviceChange:
+    """Input sources that appeared or disappeared, and the new default source, if it changed."""
+
+    added: frozenset = frozenset()
+    removed: frozenset = frozenset()
+    default_source: Optional[str] = None
+
+    def __bool__(self) -> bool:
+        return bool(self.added or self.removed or self.default_source)
+
+    def describe(self) -> str:
+        parts = [f"+{name}" for name in sorted(self.added)]
+        parts += [f"-{name}" for name in sorted(self.removed)]
+        if self.default_source:
+            parts.append(f"default {self.default_source}")
+        return ", ".join(parts)
+
+
+def _pactl(
+    args: list[str], run: Optional[Callable[..., subprocess.CompletedProcess]] = None
+) -> Optional[str]:
+    """Run pactl and return its output, or None if it failed or isn't installed."""
+    run = run or subprocess.run
+    try:
+        result = run(
+            ["pactl", *args],
+            capture_output=True,
+            text=True,
+            timeout=_PACTL_TIMEOUT,
+            env={**os.environ, "LC_ALL": "C"},
+        )
+    except (OSError, subprocess.SubprocessError) as e:
+        logger.debug(f"pactl {' '.join(args)} failed: {e}")
+        return None
+    if result.returncode != 0:
+        logger.debug(f"pactl {' '.join(args)} failed: {result.stderr.strip()}")
+        return None
+    return result.stdout
+
+
+def list_input_sources(
+    run: Optional[Callable[..., subprocess.CompletedProcess]] = None,
+) -> Optional[frozenset]:
+    """
+    List the PulseAudio/PipeWire sources that are microphones (not monitors).
+
+    Args:
+        run: Replacement for subprocess.run, for tests
+
+    Returns:
+        The source names, or None if pactl isn't available
+    """
+    output = _pactl(["list", "short", "sources"], run)
+    if output is None:
+        return None
+
+    names = set()
+    for line in output.splitlines():
+        fields = line.split("\t")
+        if len(fields) >= 2 and fields[1] and not is_monitor_source(fields[1]):
+            names.add(fields[1])
+    return frozenset(names)
+
+
+def get_default_source(
+    run: Optional[Callable[..., subprocess.CompletedProcess]] = None,
+) -> Optional[str]:
+    """
+    Name the PulseAudio/PipeWire default input source.
+
+    Args:
+        run: Replacement for subprocess.run, for tests
+
+    Returns:
+        The source name, or None if pactl isn't available
+    """
+    output = _pactl(["get-default-source"], run)
+    if output is not None:
+        return output.strip() or None
+    # pactl before version 15 has no get-default-source
+    output = _pactl(["info"], run)
+    for line in (output or "").splitlines():
+        if line.startswith("Default Source:"):
+            return line.split(":", 1)[1].strip() or None
+    return None
+
+
+class DeviceMonitor:
+    """Watches the input sources on a background thread while recording."""
+
+    def __init__(
+        self,
+        interval: float = DEVICE_POLL_SECONDS,
+        run: Optional[Callable[..., subprocess.CompletedProcess]] = None,
+    ):
+        """
+        Initialize the monitor.
+
+        Args:
+            interval: Seconds between checks
+            run: Replacement for subprocess.run, for tests
+        """
+        self.interval = interval
+        self._run = run
+        self._sources: Optional[frozenset] = None
+        self._default_source: Optional[str] = None
+        self._added: set = set()
+        self._removed: set = set()
+        self._new_default: Optional[str] = None
+        self._lock = threading.Lock()
+        self._stop = threading.Event()
+        self._thread: Optional[threading.Thread] = None
+
+    def start(self) -> bool:
+        """
+        Take the first snapshot and start polling.
+
+        Returns:
+            False if the sources can't be listed, in which case nothing is watched
+        """
+        self._sources = list_input_sources(self._run)
+        if self._sources is None:
+            logger.debug("pactl unavailable, audio device hotplug is not monitored")
+            return False
+        self._default_source = get_default_source(self._run)
+        self._stop.clear()
+        self._thread = threading.Thread(target=self._watch, name="device-monitor", daemon=True)
+        self._thread.start()
+        return True
+
+    def stop(self):
+        """Stop polling."""
+        self._stop.set()
+        if self._thread is not None and self._thread is not threading.current_thread():
+            self._thread.join(timeout=self.interval + _PACTL_TIMEOUT)
+        self._thread = None
+
+    def poll(self):
+        """List the sources now and record any change since the last check."""
+        sources = list_input_sources(self._run)
+        if sources is None or self._sources is None:
+            return
+        self._poll_default_source()
+        added = sources - self._sources
+        removed = self._sources - sources
+        self._sources = sources
+        if not (added or removed):
+            return
+        logger.debug(f"Input sources changed: {DeviceChange(added, removed).describe()}")
+        with self._lock:
+            self._added |= added
+            self._removed |= removed
+
+    def _poll_default_source(self):
+        # Only once start() found a default to compare with
+        if self._default_source is None:
+            return
+        default = get_default_source(self._run)
+        if default is None or default == self._default_source:
+            return
+        logger.debug(f"Default input source changed to {default}")
+        self._default_source = default
+        with self._lock:
+            self._new_default = default
+
+    def take_change(self) -> DeviceChange:
+        """Return the changes seen since the last call and forget them."""
+        with self._lock:
+            change = DeviceChange(
+                frozenset(self._added), frozenset(self._removed), self._new_default
+            )
+            self._added.clear()
+            self._removed.clear()
+            self._new_default = None
+        return change
+
+    def _watch(self):
+        while not self._stop.wait(self.interval):
+            self.poll()
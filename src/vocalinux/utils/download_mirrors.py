@@ -0,0 +1,80 @@
+"""
+Model download mirrors and bandwidth limiting for Vocalinux.
+
+whisper.cpp models come from Hugging Face and VOSK models from
+alphacephei.com, neither of which is reachable everywhere. The downloads
+can go through hf-mirror.com or a self-hosted VOSK mirror instead, and can
+be held to a speed limit so a large model doesn't saturate a metered or
+shared connection. Whisper (PyTorch) models are only published on OpenAI's
+CDN, so they always come from there.
+"""
+
+import time
+from typing import Callable
+from urllib.parse import urlsplit, urlunsplit
+
+# Download sources for Hugging Face models, as shown in the settings
+DOWNLOAD_MIRRORS = {
+    "official": "Official servers",
+    "hf-mirror": "hf-mirror.com",
+}
+DEFAULT_DOWNLOAD_MIRROR = "official"
+
+VOSK_MODELS_URL = "https://alphacephei.com/vosk/models"
+
+_HUGGING_FACE_HOST = "huggingface.co"
+_MIRROR_HOSTS = {"hf-mirror": "hf-mirror.com"}
+
+
+def mirrored_url(url: str, mirror: str = DEFAULT_DOWNLOAD_MIRROR, vosk_mirror_url: str = "") -> str:
+    """
+    Rewrite a model URL to download from the chosen mirror.
+
+    Args:
+        url: The official download URL
+        mirror: A DOWNLOAD_MIRRORS key, used for Hugging Face URLs
+        vosk_mirror_url: Base URL serving the VOSK model zips ("" = alphacephei.com)
+
+    Returns:
+        The URL to download from
+    """
+    if vosk_mirror_url and url.startswith(VOSK_MODELS_URL + "/"):
+        return vosk_mirror_url.rstrip("/") + url[len(VOSK_MODELS_URL) :]
+    host = _MIRROR_HOSTS.get(mirror)
+    parts = urlsplit(url)
+    if host and parts.hostname == _HUGGING_FACE_HOST:
+        return urlunsplit(parts._replace(netloc=host))
+    return url
+
+
+class BandwidthLimiter:
+    """Slows a download loop down to an average speed."""
+
+    def __init__(
+        self,
+        limit_kbps: float,
+        clock: Callable[[], float] = time.monotonic,
+        sleep: Callable[[float], None] = time.sleep,
+    ):
+        """
+        Initialize the limiter.
+
+        Args:
+            limit_kbps: Maximum speed in KB/s (0 or less = unlimited)
+            clock: Replacement for time.monotonic, for tests
+            sleep: Replacement for time.sleep, for tests
+        """
+        self.limit_bytes = max(limit_kbps or 0, 0) * 1024
+        self._clock = clock
+        self._sleep = sleep
+        self._start = clock()
+        self._received = 0
+
+    def throttle(self, size: int):
+        """Account for `size` bytes just received, sleeping if they came in too fast."""
+        if not self.limit_bytes:
+            return
+        self._received += size
+        ahead = self._received / self.limit_bytes - (self._clock() - self._start)
+        if ahead > 0:
+            self._sleep(ahead)
@@ -0,0 +1,131 @@
+"""
+Echo cancellation for Vocalinux.
+
+With speakers instead of headphones, the microphone also picks up whatever
+the computer plays (videos, notification sounds), which then gets typed.
+This loads PulseAudio's / PipeWire's echo-cancel module for the duration of
+a dictation: playback is routed through its sink, which serves as the
+reference signal, and Vocalinux records from its source, where that signal
+has been subtracted.
+"""
+
+import contextlib
+import logging
+import os
+import shutil
+import subprocess
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+EC_SOURCE_NAME = "vocalinux_echo_cancel"
+EC_SINK_NAME = "vocalinux_echo_cancel_sink"
+
+_PACTL_TIMEOUT = 5.0
+
+
+class EchoCanceller:
+    """Loads the echo-cancel module while dictating and removes it afterwards."""
+
+    def __init__(self, run: Optional[Callable[..., subprocess.CompletedProcess]] = None):
+        """
+        Initialize the canceller.
+
+        Args:
+            run: Replacement for subprocess.run, for tests
+        """
+        self._run = run or subprocess.run
+        self._module_index: Optional[str] = None
+        self._previous_sink: Optional[str] = None
+
+    @property
+    def active(self) -> bool:
+        """Whether the echo-cancel module is loaded by us."""
+        return self._module_index is not None
+
+    def _pactl(self, *args: str) -> Optional[str]:
+        try:
+            result = self._run(
+                ["pactl", *args],
+                capture_output=True,
+                text=True,
+                timeout=_PACTL_TIMEOUT,
+                env={**os.environ, "LC_ALL": "C"},
+            )
+        except (OSError, subprocess.SubprocessError) as e:
+            logger.debug(f"pactl {args[0]} failed: {e}")
+            return None
+        if result.returncode != 0:
+            logger.debug(f"pactl {args[0]} failed: {result.stderr.strip()}")
+            return None
+        return result.stdout.strip()
+
+    def start(self) -> Optional[str]:
+        """
+        Load the echo-cancel module and route playback through it.
+
+        Returns:
+            Name of the echo-cancelled source to record from, or None if the
+            module could not be loaded
+        """
+        if self.active:
+            return EC_SOURCE_NAME
+
+        self._previous_sink = self._pactl("get-default-sink") or None
+        module_index = self._pactl(
+            "load-module",
+            "module-echo-cancel",
+            "aec_method=webrtc",
+            "use_master_format=1",
+            f"source_name={EC_SOURCE_NAME}",
+            f"sink_name={EC_SINK_NAME}",
+            "source_properties=device.description=Vocalinux-Echo-Cancel",
+            "sink_properties=device.description=Vocalinux-Echo-Cancel",
+        )
+        if not module_index:
+            logger.warning("Could not load the echo-cancel module; recording without it")
+            return None
+
+        self._module_index = module_index
+        # Playback must go through the canceller's sink for it to be subtracted
+        self._pactl("set-default-sink", EC_SINK_NAME)
+        logger.info(f"Echo cancellation enabled (module {module_index})")
+        return EC_SOURCE_NAME
+
+    def stop(self):
+        """Put the previous output device back and unload the module."""
+        if not self.active:
+            return
+        if self._previous_sink and self._pactl("get-default-sink") == EC_SINK_NAME:
+            self._pactl("set-default-sink", self._previous_sink)
+        self._pactl("unload-module", self._module_index)
+        logger.info("Echo cancellation disabled")
+        self._module_index = None
+        self._previous_sink = None
+
+
+def is_echo_cancel_available() -> bool:
+    """Whether pactl is installed, which echo cancellation needs."""
+    return shutil.which("pactl") is not None
+
+
+@contextlib.contextmanager
+def pulse_source(source: Optional[str]):
+    """
+    Make streams opened inside the block record from the given PulseAudio source.
+
+    The ALSA "pulse" device, which PortAudio's default input uses, reads
+    PULSE_SOURCE when a stream is opened. Does nothing if source is None.
+    """
+    if source is None:
+        yield
+        return
+    previous = os.environ.get("PULSE_SOURCE")
+    os.environ["PULSE_SOURCE"] = source
+    try:
+        yield
+    finally:
+        if previous is None:
+            os.environ.pop("PULSE_SOURCE", None)
+        else:
+            os.environ["PULSE_SOURCE"] = previous
@@ -0,0 +1,81 @@
+"""
+Language capability checks for Vocalinux speech engines.
+
+Not every engine/model/language combination is valid: VOSK ships a separate
+model per language and size, and English-only whisper.cpp variants cannot
+transcribe other languages. These helpers let the settings UI list only
+supported languages and let the recognition manager warn about invalid
+combinations instead of silently falling back.
+"""
+
+from typing import Optional
+
+from .vosk_model_info import SUPPORTED_LANGUAGES, VOSK_MODEL_INFO
+from .whispercpp_model_info import is_english_only_model
+
+WHISPER_FAMILY_ENGINES = ("whisper", "whisper_cpp", "remote_api")
+
+
+def get_supported_languages(engine: str, model_size: Optional[str] = None) -> list[str]:
+    """
+    Return the language codes an engine/model combination supports.
+
+    Args:
+        engine: Engine ID ("vosk", "whisper", "whisper_cpp" or "remote_api")
+        model_size: Model size or whisper.cpp variant (None for the engine default)
+
+    Returns:
+        Language codes in SUPPORTED_LANGUAGES order
+    """
+    if engine == "vosk":
+        size_info = VOSK_MODEL_INFO.get((model_size or "small").lower())
+        if size_info is None:
+            return []
+        vosk_languages = size_info["languages"]
+        return [code for code in SUPPORTED_LANGUAGES if vosk_languages.get(code)]
+
+    if engine in WHISPER_FAMILY_ENGINES:
+        if engine == "whisper_cpp" and model_size and is_english_only_model(model_size):
+            return [code for code, info in SUPPORTED_LANGUAGES.items() if info["whisper"] == "en"]
+        return list(SUPPORTED_LANGUAGES)
+
+    return []
+
+
+def validate_language_selection(
+    engine: str, model_size: Optional[str], language: Optional[str]
+) -> Optional[str]:
+    """
+    Check whether an engine/model/language combination is supported.
+
+    Args:
+        engine: Engine ID
+        model_size: Model size or whisper.cpp variant
+        language: Language code
+
+    Returns:
+        A user-facing warning message, or None if the combination is valid
+    """
+    if not language:
+        return None
+
+    if engine == "vosk":
+        size = (model_size or "small").lower()
+        if size not in VOSK_MODEL_INFO:
+            return f"VOSK has no '{model_size}' model size. Choose small, medium or large."
+        if language == "auto":
+            return "VOSK cannot auto-detect the language and will use English (US)."
+        if not VOSK_MODEL_INFO[size]["languages"].get(language):
+            name = SUPPORTED_LANGUAGES.get(language, {}).get("name", language)
+            if size != "small" and VOSK_MODEL_INFO["small"]["languages"].get(language):
+                return f"{name} is not available for the VOSK {size} model. Use the small model."
+            return f"{name} is not supported by VOSK."
+        return None
+
+    if engine == "whisper_cpp" and model_size and is_english_only_model(model_size):
+        if language != "auto" and not language.startswith("en"):
+            name = SUPPORTED_LANGUAGES.get(language, {}).get("name", language)
+            return f"'{model_size}' is an English-only model and cannot transcribe {name}."
+
+    # Whisper-family engines are multilingual and accept any language code
+    return None
@@ -0,0 +1,185 @@
+"""
+Media ducking for Vocalinux.
+
+Lowers the volume of other applications' playback (music, videos, calls)
+while dictating and restores it afterwards, so the microphone picks up less
+of it. Works with PulseAudio and PipeWire (through pipewire-pulse) using
+pactl, one playback stream at a time, so the output device volume and
+Vocalinux's own sounds are left alone. Playback that starts while dictating
+is ducked too, when the recording loop checks again.
+"""
+
+import logging
+import os
+import re
+import shutil
+import subprocess
+import threading
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_DUCK_LEVEL = 0.3
+
+# How often, while dictating, to look for playback that started after ducking
+DUCK_RECHECK_SECONDS = 2.0
+
+# Players used for Vocalinux's feedback sounds and read-back; their streams are never ducked
+_SOUND_PLAYERS = {
+    "paplay",
+    "pw-play",
+    "pw-cat",
+    "aplay",
+    "play",
+    "mplayer",
+    "espeak-ng",
+    "espeak",
+    "spd-say",
+}
+
+_PACTL_TIMEOUT = 2.0
+
+_SINK_INPUT_RE = re.compile(r"^Sink Input #(\d+)", re.MULTILINE)
+_CHANNEL_VOLUME_RE = re.compile(r"(\d+)\s*/\s*\d+%")
+_PROPERTY_RE = re.compile(r'^\s*([\w.]+) = "(.*)"$', re.MULTILINE)
+
+
+def parse_sink_inputs(output: str) -> list[dict]:
+    """
+    Parse the output of ``pactl list sink-inputs``.
+
+    Returns:
+        One dict per playback stream with ``index`` (int), ``volumes`` (raw
+        per-channel volumes) and ``properties`` (str -> str)
+    """
+    streams = []
+    matches = list(_SINK_INPUT_RE.finditer(output))
+    for position, match in enumerate(matches):
+        end = matches[position + 1].start() if position + 1 < len(matches) else len(output)
+        block = output[match.end() : end]
+
+        volumes = []
+        for line in block.splitlines():
+            if line.strip().startswith("Volume:"):
+                volumes = [int(value) for value in _CHANNEL_VOLUME_RE.findall(line)]
+                break
+
+        streams.append(
+            {
+                "index": int(match.group(1)),
+                "volumes": volumes,
+                "properties": dict(_PROPERTY_RE.findall(block)),
+            }
+        )
+    return streams
+
+
+class MediaDucker:
+    """Lowers other applications' playback streams and puts them back."""
+
+    def __init__(
+        self,
+        level: float = DEFAULT_DUCK_LEVEL,
+        run: Optional[Callable[..., subprocess.CompletedProcess]] = None,
+    ):
+        """
+        Initialize the ducker.
+
+        Args:
+            level: Fraction of their volume streams keep while ducked (0.0-1.0)
+            run: Replacement for subprocess.run, for tests
+        """
+        self.level = level
+        self._run = run or subprocess.run
+        self._ducked: dict[int, tuple[list[int], list[int]]] = {}
+        self._lock = threading.Lock()
+
+    @property
+    def level(self) -> float:
+        return self._level
+
+    @level.setter
+    def level(self, value: float):
+        self._level = max(0.0, min(1.0, float(value)))
+
+    @property
+    def active(self) -> bool:
+        """Whether any streams are currently ducked."""
+        return bool(self._ducked)
+
+    def _pactl(self, *args: str) -> Optional[str]:
+        try:
+            result = self._run(
+                ["pactl", *args],
+                capture_output=True,
+                text=True,
+                timeout=_PACTL_TIMEOUT,
+                env={**os.environ, "LC_ALL": "C"},
+            )
+        except (OSError, subprocess.SubprocessError) as e:
+            logger.debug(f"pactl {args[0]} failed: {e}")
+            return None
+        if result.returncode != 0:
+            logger.debug(f"pactl {args[0]} failed: {result.stderr.strip()}")
+            return None
+        return result.stdout
+
+    def _list_streams(self) -> list[dict]:
+        output = self._pactl("list", "sink-inputs")
+        return parse_sink_inputs(output) if output else []
+
+    def _is_ducked_candidate(self, stream: dict) -> bool:
+        properties = stream["properties"]
+        if properties.get("application.process.id") == str(os.getpid()):
+            return False
+        if properties.get("application.process.binary") in _SOUND_PLAYERS:
+            return False
+        return bool(stream["volumes"])
+
+    def duck(self) -> int:
+        """
+        Lower every other application's playback stream.
+
+        Calling it again while ducked lowers only streams that started since;
+        streams already ducked are left alone, even if the user raised them.
+
+        Returns:
+            Number of streams ducked
+        """
+        with self._lock:
+            lowered_now = 0
+            for stream in self._list_streams():
+                index = stream["index"]
+                if index in self._ducked or not self._is_ducked_candidate(stream):
+                    continue
+                original = stream["volumes"]
+                lowered = [int(volume * self.level) for volume in original]
+                if self._pactl("set-sink-input-volume", str(index), *map(str, lowered)) is not None:
+                    self._ducked[index] = (original, lowered)
+                    lowered_now += 1
+            if lowered_now:
+                logger.info(f"Lowered media volume for {lowered_now} stream(s)")
+            return len(self._ducked)
+
+    def restore(self):
+        """
+        Put ducked streams back to their previous volume.
+
+        Streams that ended, or whose volume the user changed while ducked, are
+        left as they are.
+        """
+        with self._lock:
+            if not self._ducked:
+                return
+            current = {stream["index"]: stream["volumes"] for stream in self._list_streams()}
+            for index, (original, lowered) in self._ducked.items():
+                if current.get(index) != lowered:
+                    continue
+                self._pactl("set-sink-input-volume", str(index), *map(str, original))
+            logger.info("Restored media volume")
+            self._ducked = {}
+
+
+def is_media_ducking_available() -> bool:
+    """Whether pactl is installed, which ducking needs."""
+    return shutil.which("pactl") is not None
@@ -0,0 +1,166 @@
+"""
+Microphone permission pre-flight for Vocalinux.
+
+Inside a Flatpak sandbox, microphone access goes through the Device portal
+(org.freedesktop.portal.Device): the desktop may ask the user once and
+remembers the answer. Asking before capture starts means a denial can be
+explained in plain words, instead of surfacing as an opaque PortAudio error
+when the stream is opened. Outside a sandbox there is nothing to ask.
+"""
+
+import logging
+import os
+import time
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+PORTAL_BUS_NAME = "org.freedesktop.portal.Desktop"
+PORTAL_OBJECT_PATH = "/org/freedesktop/portal/desktop"
+DEVICE_INTERFACE = "org.freedesktop.portal.Device"
+REQUEST_INTERFACE = "org.freedesktop.portal.Request"
+
+# Portal response codes
+_RESPONSE_GRANTED = 0
+
+# How long to wait for the user to answer the permission dialog
+DEFAULT_REQUEST_TIMEOUT = 60.0
+
+MICROPHONE_DENIED_MESSAGE = (
+    "Vocalinux is not allowed to use the microphone. Allow it under "
+    "Settings → Privacy → Microphone, or run: "
+    "flatpak permission-set devices microphone {app_id} yes"
+)
+
+
+def is_sandboxed() -> bool:
+    """Whether Vocalinux runs inside a Flatpak sandbox."""
+    return bool(os.environ.get("FLATPAK_ID")) or os.path.exists("/.flatpak-info")
+
+
+def denied_message() -> str:
+    """Explain how to grant microphone access after a denial."""
+    app_id = os.environ.get("FLATPAK_ID") or "com.vocalinux.Vocalinux"
+    return MICROPHONE_DENIED_MESSAGE.format(app_id=app_id)
+
+
+def request_microphone_access(timeout: float = DEFAULT_REQUEST_TIMEOUT) -> Optional[bool]:
+    """
+    Ask the Device portal for microphone access, waiting for the answer.
+
+    Blocks until the user answers (the portal answers at once when it
+    remembers an earlier decision), so call it off the GTK main thread.
+
+    Returns:
+        True if access was granted, False if it was denied or not answered
+        in time, None if the portal is not available
+    """
+    try:
+        from gi.repository import Gio, GLib
+    except ImportError as e:
+        logger.debug(f"Cannot reach the Device portal: {e}")
+        return None
+
+    # The Response signal is delivered to the context it was subscribed from
+    context = GLib.MainContext.new()
+    context.push_thread_default()
+    try:
+        try:
+            bus = Gio.bus_get_sync(Gio.BusType.SESSION, None)
+        except GLib.Error as e:
+            logger.debug(f"No session bus for the Device portal: {e.message}")
+            return None
+
+        # Subscribe before calling: the request path is known from the token
+        token = f"vocalinux_{os.getpid()}_{int(time.monotonic() * 1000)}"
+        sender = bus.get_unique_name().lstrip(":").replace(".", "_")
+        handle = f"{PORTAL_OBJECT_PATH}/request/{sender}/{token}"
+        response = {}
+
+        def on_response(connection, sender_name, object_path, interface, signal, parameters):
+            response["code"] = parameters.unpack()[0]
+
+        subscription = bus.signal_subscribe(
+            PORTAL_BUS_NAME,
+            REQUEST_INTERFACE,
+            "Response",
+            handle,
+            None,
+            Gio.DBusSignalFlags.NONE,
+            on_response,
+        )
+        try:
+            try:
+                bus.call_sync(
+                    PORTAL_BUS_NAME,
+                    PORTAL_OBJECT_PATH,
+                    DEVICE_INTERFACE,
+                    "AccessDevice",
+                    GLib.Variant(
+                        "(uasa{sv})",
+                        (os.getpid(), ["microphone"], {"handle_token": GLib.Variant("s", token)}),
+                    ),
+                    GLib.VariantType.new("(o)"),
+                    Gio.DBusCallFlags.NONE,
+                    -1,
+                    None,
+                )
+            except GLib.Error as e:
+                logger.debug(f"Device portal not available: {e.message}")
+                return None
+
+            deadline = time.monotonic() + timeout
+            while "code" not in response and time.monotonic() < deadline:
+                if not context.iteration(False):
+                    time.sleep(0.05)
+        finally:
+            bus.signal_unsubscribe(subscription)
+    finally:
+        context.pop_thread_default()
+
+    if "code" not in response:
+        logger.warning("No answer to the microphone permission request")
+        return False
+    return response["code"] == _RESPONSE_GRANTED
+
+
+class MicrophonePermission:
+    """Checks microphone access before capture, asking the portal when sandboxed."""
+
+    def __init__(
+        self,
+        request: Callable[[], Optional[bool]] = request_microphone_access,
+        sandboxed: Callable[[], bool] = is_sandboxed,
+    ):
+        """
+        Initialize the check.
+
+        Args:
+            request: Asks for access; True, False, or None if it cannot ask
+            sandboxed: Whether access has to be asked for at all
+        """
+        self._request = request
+        self._sandboxed = sandboxed
+        self._granted = False
+
+    def check(self) -> bool:
+        """
+        Whether capture may start.
+
+        A grant is remembered for the rest of the session; a denial is asked
+        again next time, since the user may have changed the permission.
+        When the portal can't be reached, capture is allowed to try.
+        """
+        if self._granted or not self._sandboxed():
+            return True
+
+        result = self._request()
+        if result is None:
+            logger.info("Cannot check microphone permission; trying to record anyway")
+            return True
+        if result:
+            logger.info("Microphone access granted")
+            self._granted = True
+            return True
+        logger.warning("Microphone access denied")
+        return False
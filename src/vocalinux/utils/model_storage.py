@@ -0,0 +1,167 @@
+"""
+Downloaded speech models and the disk space they use, for Vocalinux.
+
+Models are downloaded into the models directory the first time they are
+used and are never removed, so trying a few sizes can leave gigabytes
+behind. The settings list what is there with its size and delete models on
+request. Only the user's models directory is managed; models installed
+system-wide by a package are left alone.
+"""
+
+import logging
+import os
+import shutil
+from dataclasses import dataclass
+from typing import Iterable, Optional
+
+from .paths import models_dir
+from .vosk_model_info import VOSK_MODEL_INFO
+from .whispercpp_model_info import WHISPERCPP_MODEL_INFO
+
+logger = logging.getLogger(__name__)
+
+# ggml file name -> whisper.cpp model name, e.g. "ggml-large-v3.bin" -> "large"
+_WHISPERCPP_FILE_NAMES = {
+    os.path.basename(info["url"]): name
+    for name, info in WHISPERCPP_MODEL_INFO.items()
+    if info.get("url")
+}
+
+
+@dataclass(frozen=True)
+class InstalledModel:
+    """A model in the models directory."""
+
+    engine: str  # "vosk", "whisper" or "whisper_cpp"
+    name: str  # Model size or name as the settings show it
+    path: str
+    size_bytes: int
+
+
+def _path_size(path: str) -> int:
+    """Bytes used by a file, or by everything under a directory."""
+    if not os.path.isdir(path):
+        try:
+            return os.path.getsize(path)
+        except OSError:
+            return 0
+    total = 0
+    for directory, _subdirs, files in os.walk(path):
+        for file_name in files:
+            try:
+                total += os.path.getsize(os.path.join(directory, file_name))
+            except OSError:
+                continue
+    return total
+
+
+def _entries(directory: str) -> list[str]:
+    try:
+        return sorted(os.listdir(directory))
+    except OSError:
+        return []
+
+
+def list_installed_models(root: Optional[str] = None) -> list[InstalledModel]:
+    """
+    List the downloaded models.
+
+    Args:
+        root: Models directory to look in (the user's one if None)
+
+    Returns:
+        The models, sorted by engine and name
+    """
+    root = root or models_dir()
+    models = []
+
+    for entry in _entries(root):
+        path = os.path.join(root, entry)
+        if entry.startswith("vosk-model") and os.path.isdir(path):
+            models.append(InstalledModel("vosk", entry, path, _path_size(path)))
+
+    whisper_dir = os.path.join(root, "whisper")
+    for entry in _entries(whisper_dir):
+        if entry.endswith(".pt"):
+            path = os.path.join(whisper_dir, entry)
+            models.append(InstalledModel("whisper", entry[: -len(".pt")], path, _path_size(path)))
+
+    whispercpp_dir = os.path.join(root, "whispercpp")
+    for entry in _entries(whispercpp_dir):
+        if not entry.endswith(".bin"):  # Also skips downloads in progress (.bin.tmp)
+            continue
+        name = _WHISPERCPP_FILE_NAMES.get(entry, entry[: -len(".bin")].replace("ggml-", "", 1))
+        path = os.path.join(whispercpp_dir, entry)
+        models.append(InstalledModel("whisper_cpp", name, path, _path_size(path)))
+
+    return sorted(models, key=lambda model: (model.engine, model.name))
+
+
+def configured_model_path(
+    engine: Optional[str],
+    model_size: Optional[str],
+    language: str = "en-us",
+    root: Optional[str] = None,
+) -> Optional[str]:
+    """
+    Where the model an engine is configured with lives in the models directory.
+
+    Args:
+        engine: "vosk", "whisper" or "whisper_cpp"; other engines have no local model
+        model_size: The configured model size (a variant name for whisper.cpp)
+        language: Recognition language, which picks the VOSK model
+        root: Models directory (the user's one if None)
+
+    Returns:
+        The path, or None if the engine doesn't use a downloaded model
+    """
+    if not engine or not model_size:
+        return None
+    root = root or models_dir()
+    if engine == "vosk":
+        languages = VOSK_MODEL_INFO.get(model_size, {}).get("languages", {})
+        model_name = languages.get(language) or languages.get("en-us")
+        return os.path.join(root, model_name) if model_name else None
+    if engine == "whisper":
+        return os.path.join(root, "whisper", f"{model_size}.pt")
+    if engine == "whisper_cpp":
+        info = WHISPERCPP_MODEL_INFO.get(model_size, {})
+        file_name = os.path.basename(info["url"]) if info.get("url") else f"ggml-{model_size}.bin"
+        return os.path.join(root, "whispercpp", file_name)
+    return None
+
+
+def unused_models(
+    models: Iterable[InstalledModel], paths_in_use: Iterable[Optional[str]]
+) -> list[InstalledModel]:
+    """The models whose path isn't one of the configured ones."""
+    in_use = {os.path.realpath(path) for path in paths_in_use if path}
+    return [model for model in models if os.path.realpath(model.path) not in in_use]
+
+
+def delete_model(model: InstalledModel) -> bool:
+    """
+    Delete a downloaded model.
+
+    Returns:
+        True if it was removed
+    """
+    try:
+        if os.path.isdir(model.path):
+            shutil.rmtree(model.path)
+        else:
+            os.remove(model.path)
+    except OSError as e:
+        logger.error(f"Could not delete {model.path}: {e}")
+        return False
+    logger.info(f"Deleted {model.engine} model {model.name} ({format_bytes(model.size_bytes)})")
+    return True
+
+
+def format_bytes(size_bytes: int) -> str:
+    """Format a size for display, e.g. '466 MB' or '1.5 GB'."""
+    if size_bytes >= 1000**3:
+        return f"{size_bytes / 1000**3:.1f} GB"
+    if size_bytes >= 1000**2:
+        return f"{size_bytes / 1000**2:.0f} MB"
+    return f"{max(size_bytes, 0) / 1000:.0f} KB"
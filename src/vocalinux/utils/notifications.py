@@ -0,0 +1,269 @@
+"""
+Desktop notifications for Vocalinux.
+
+Notifications that can repeat quickly (engine errors in a reconnect loop,
+injection failures on every utterance) are given a category. Within a
+category's cooldown only the first notification is shown; the repeats are
+counted and summarized in a single follow-up once the cooldown ends. With
+ui.show_notifications off nothing is shown at all.
+"""
+
+import logging
+import subprocess
+import threading
+import time
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_COOLDOWN_SECONDS = 30.0
+
+
+def send_notification(title: str, message: str, icon: str = "dialog-warning", tag: str = ""):
+    """
+    Show a desktop notification with notify-send.
+
+    Args:
+        title: Notification title
+        message: Notification body
+        icon: Icon name
+        tag: If set, replaces an earlier notification with the same tag on
+            servers that support it instead of stacking a new one
+    """
+    command = ["notify-send", "-i", icon, "-a", "Vocalinux"]
+    if tag:
+        command += ["-h", f"string:x-canonical-private-synchronous:vocalinux-{tag}"]
+    try:
+        subprocess.Popen(
+            command + [title, message],
+            stdout=subprocess.DEVNULL,
+            stderr=subprocess.DEVNULL,
+        )
+    except (FileNotFoundError, OSError) as e:
+        logger.debug(f"Could not show notification: {e}")
+
+
+def wait_for_action(
+    title: str,
+    message: str,
+    actions: dict[str, str],
+    icon: str = "dialog-information",
+    resident: bool = False,
+    on_started: Optional[Callable[[subprocess.Popen], None]] = None,
+) -> Optional[str]:
+    """
+    Show a desktop notification with buttons and wait until it is closed.
+
+    Blocks, so call it from a background thread.
+
+    Args:
+        title: Notification title
+        message: Notification body
+        actions: Button labels keyed by the name reported when clicked
+        icon: Icon name
+        resident: Keep the notification on screen until it is dismissed
+        on_started: Called with the notify-send process, e.g. to close it on quit
+
+    Returns:
+        The name of the clicked button, "" if the notification was dismissed,
+        or None if it couldn't be shown with buttons (no notify-send, or one
+        without --action from before libnotify 0.7.10)
+    """
+    command = ["notify-send", "-i", icon, "-a", "Vocalinux", "--wait"]
+    if resident:
+        command += ["-t", "0", "-h", "boolean:resident:true"]
+    command += [f"--action={name}={label}" for name, label in actions.items()]
+    try:
+        process = subprocess.Popen(
+            command + [title, message],
+            stdout=subprocess.PIPE,
+            stderr=subprocess.DEVNULL,
+            text=True,
+        )
+    except (FileNotFoundError, OSError) as e:
+        logger.debug(f"Could not show notification: {e}")
+        return None
+    if on_started is not None:
+        on_started(process)
+    output, _ = process.communicate()
+    if process.returncode != 0:
+        return None
+    return output.strip()
+
+
+def send_action_notification(
+    title: str,
+    message: str,
+    action_label: str,
+    on_action: Callable[[], None],
+    icon: str = "dialog-information",
+    fallback_message: Optional[str] = None,
+):
+    """
+    Show a desktop notification with one button, without blocking.
+
+    notify-send waits in a background thread until the notification is
+    closed and reports whether the button was clicked. Versions of
+    notify-send without --action (before libnotify 0.7.10) show the
+    notification without the button instead.
+
+    Args:
+        title: Notification title
+        message: Notification body
+        action_label: Text of the button
+        on_action: Called from the background thread when the button is clicked
+        icon: Icon name
+        fallback_message: Body shown instead when there can't be a button
+            (the same message if None)
+    """
+
+    def wait():
+        action = wait_for_action(title, message, {"default": action_label}, icon)
+        if action is None:
+            send_notification(title, fallback_message or message, icon)
+        elif action == "default":
+            on_action()
+
+    threading.Thread(target=wait, daemon=True).start()
+
+
+class _CategoryState:
+    """Cooldown bookkeeping for one notification category."""
+
+    def __init__(self):
+        self.last_shown = float("-inf")
+        self.suppressed = 0
+        self.latest: Optional[tuple[str, str, str]] = None
+        self.timer: Optional[threading.Timer] = None
+
+
+class NotificationManager:
+    """
+    Rate-limits notifications per category and coalesces repeats with a count.
+
+    Notifications without a category are always shown immediately.
+    """
+
+    def __init__(
+        self,
+        cooldown: float = DEFAULT_COOLDOWN_SECONDS,
+        cooldowns: Optional[dict[str, float]] = None,
+        send: Callable[..., None] = send_notification,
+        clock: Callable[[], float] = time.monotonic,
+    ):
+        """
+        Initialize the notification manager.
+
+        Args:
+            cooldown: Seconds between notifications of the same category
+            cooldowns: Per-category overrides of the cooldown
+            send: Shows a notification; called as send(title, message, icon, tag=...)
+            clock: Monotonic clock, replaceable in tests
+        """
+        self.cooldown = cooldown
+        self.cooldowns = dict(cooldowns or {})
+        self.enabled = True
+        self._send = send
+        self._clock = clock
+        self._lock = threading.Lock()
+        self._categories: dict[str, _CategoryState] = {}
+
+    def _cooldown_for(self, category: str) -> float:
+        return self.cooldowns.get(category, self.cooldown)
+
+    def notify(
+        self,
+        title: str,
+        message: str,
+        icon: str = "dialog-warning",
+        category: Optional[str] = None,
+    ) -> bool:
+        """
+        Show a notification, or hold it back if its category is cooling down.
+
+        Returns:
+            True if the notification was shown now, False if it was coalesced
+            or notifications are turned off
+        """
+        if not self.enabled:
+            return False
+        if category is None:
+            self._send(title, message, icon)
+            return True
+
+        with self._lock:
+            state = self._categories.setdefault(category, _CategoryState())
+            now = self._clock()
+            remaining = state.last_shown + self._cooldown_for(category) - now
+            if remaining > 0:
+                state.suppressed += 1
+                state.latest = (title, message, icon)
+                if state.timer is None:
+                    state.timer = threading.Timer(remaining, self.flush, args=(category,))
+                    state.timer.daemon = True
+                    state.timer.start()
+                return False
+
+            state.last_shown = now
+        self._send(title, message, icon, tag=category)
+        return True
+
+    def flush(self, category: str) -> bool:
+        """
+        Show the summary of notifications held back in a category, if any.
+
+        Returns:
+            True if a summary was shown
+        """
+        with self._lock:
+            state = self._categories.get(category)
+            if state is None:
+                return False
+            if state.timer is not None:
+                state.timer.cancel()
+                state.timer = None
+            if not state.suppressed:
+                return False
+            count, (title, message, icon) = state.suppressed, state.latest
+            state.suppressed = 0
+            state.latest = None
+            state.last_shown = self._clock()
+        if not self.enabled:
+            return False
+
+        suffix = "time" if count == 1 else "times"
+        self._send(title, f"{message}\n(repeated {count} more {suffix})", icon, tag=category)
+        return True
+
+
+_manager = NotificationManager()
+
+
+def notify(
+    title: str,
+    message: str,
+    icon: str = "dialog-warning",
+    category: Optional[str] = None,
+) -> bool:
+    """Show a notification through the shared NotificationManager."""
+    return _manager.notify(title, message, icon, category)
+
+
+def set_enabled(enabled: bool):
+    """Turn the shared NotificationManager's notifications on or off."""
+    _manager.enabled = bool(enabled)
+
+
+def notify_with_action(
+    title: str,
+    message: str,
+    action_label: str,
+    on_action: Callable[[], None],
+    icon: str = "dialog-information",
+    fallback_message: Optional[str] = None,
+) -> bool:
+    """Show a notification with one button, unless notifications are turned off."""
+    if not _manager.enabled:
+        return False
+    send_action_notification(title, message, action_label, on_action, icon, fallback_message)
+    return True
@@ -1,6 +1,16 @@
-"""XDG base-directory helpers (Flatpak-safe via XDG_CONFIG_HOME / XDG_DATA_HOME)."""
+"""XDG base-directory helpers (Flatpak-safe via XDG_CONFIG_HOME / XDG_DATA_HOME).
+
+Files that only live while Vocalinux runs (the single-instance lock, the IBus
+engine's socket, the status file) go in the runtime directory, never in the
+data directory: a home directory shared over NFS between lab machines, or
+several seats with the same XDG_DATA_HOME, would otherwise see each other's
+locks and sockets. XDG_RUNTIME_DIR is private to the user on each machine;
+without it a per-user directory in /tmp is used instead.
+"""
 
 import os
+import stat
+import tempfile
 
 APP_DIR_NAME = "vocalinux"
 
@@ -15,6 +25,13 @@ def xdg_data_home() -> str:
     return os.environ.get("XDG_DATA_HOME") or os.path.expanduser("~/.local/share")
 
 
+def xdg_runtime_dir() -> str:
+    """Return ``$XDG_RUNTIME_DIR``, or ``/tmp/runtime-<uid>`` when it isn't set."""
+    return os.environ.get("XDG_RUNTIME_DIR") or os.path.join(
+        tempfile.gettempdir(), f"runtime-{os.getuid()}"
+    )
+
+
 def config_dir() -> str:
     """Return the Vocalinux configuration directory."""
     return os.path.join(xdg_config_home(), APP_DIR_NAME)
@@ -25,6 +42,42 @@ def data_dir() -> str:
     return os.path.join(xdg_data_home(), APP_DIR_NAME)
 
 
+def runtime_dir() -> str:
+    """Return the directory for files that only live while Vocalinux runs."""
+    return os.path.join(xdg_runtime_dir(), APP_DIR_NAME)
+
+
 def models_dir() -> str:
     """Return the directory where speech-recognition models are stored."""
     return os.path.join(data_dir(), "models")
+
+
+def ensure_private_dir(path: str) -> str:
+    """
+    Create a directory only the current user can use, with its missing parents.
+
+    The directory and its parent must belong to the current user: in /tmp,
+    another account could have created them first to read or replace the
+    files inside.
+
+    Returns:
+        The path
+
+    Raises:
+        PermissionError: If the directory or its parent belongs to another user
+    """
+    parent = os.path.dirname(path)
+    for directory in (parent, path):
+        try:
+            os.mkdir(directory, 0o700)
+        except FileExistsError:
+            pass
+        except FileNotFoundError:
+            os.makedirs(directory, 0o700, exist_ok=True)
+    for directory in (parent, path):
+        info = os.lstat(directory)
+        if not stat.S_ISDIR(info.st_mode) or info.st_uid != os.getuid():
+            raise PermissionError(f"{directory} is not a directory owned by you")
+    if stat.S_IMODE(os.lstat(path).st_mode) & 0o077:
+        os.chmod(path, 0o700)
+    return path
@@ -0,0 +1,212 @@
+"""
+PulseAudio / PipeWire monitor sources for Vocalinux.
+
+Every output device has a monitor source that carries whatever it plays.
+Recording from one transcribes system audio (a video, the other side of a
+call) instead of the microphone. PortAudio doesn't list these sources, so
+they are found with pactl and recorded through the default input by
+pointing PULSE_SOURCE at them (see echo_cancel.pulse_source).
+
+Under PipeWire a single application's playback can be recorded too, e.g.
+the other side of a Zoom call or a video in Firefox without the rest of the
+desktop's sounds. Its stream is picked by the application's name, saved as
+"app:<name>", and recorded by pointing PULSE_SOURCE at the stream's
+object.serial, which PipeWire links straight to the playback stream. The
+serial changes whenever the application re-creates its stream, so it is
+looked up again each time recording starts.
+
+pactl also reports whether a source is muted in the system sound settings,
+which PortAudio can't see: a muted microphone records plain silence.
+"""
+
+import logging
+import os
+import re
+import subprocess
+from typing import Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+# PulseAudio and PipeWire name a sink's monitor "<sink name>.monitor"
+MONITOR_SUFFIX = ".monitor"
+
+# Saved device names for an application's playback are "app:<application name>"
+APP_STREAM_PREFIX = "app:"
+
+# The source the default input records from
+DEFAULT_SOURCE = "@DEFAULT_SOURCE@"
+
+_PACTL_TIMEOUT = 2.0
+
+_SOURCE_RE = re.compile(r"^Source #(\d+)", re.MULTILINE)
+_FIELD_RE = re.compile(r"^\s*(Name|Description|Monitor of Sink): (.*)$", re.MULTILINE)
+_MUTE_RE = re.compile(r"^Mute: (yes|no)$", re.MULTILINE)
+_SINK_INPUT_RE = re.compile(r"^Sink Input #(\d+)", re.MULTILINE)
+_PROPERTY_RE = re.compile(r'^\s*([\w.]+) = "(.*)"$', re.MULTILINE)
+
+
+def is_monitor_source(device_name: Optional[str]) -> bool:
+    """Whether a saved device name refers to a monitor source."""
+    return bool(device_name) and device_name.endswith(MONITOR_SUFFIX)
+
+
+def is_app_stream(device_name: Optional[str]) -> bool:
+    """Whether a saved device name refers to an application's playback."""
+    return bool(device_name) and device_name.startswith(APP_STREAM_PREFIX)
+
+
+def app_stream_name(device_name: str) -> str:
+    """The application name in a saved "app:<name>" device name."""
+    return device_name[len(APP_STREAM_PREFIX) :]
+
+
+def parse_sources(output: str) -> list[dict]:
+    """
+    Parse the output of ``pactl list sources``.
+
+    Returns:
+        One dict per source with ``name``, ``description`` and ``monitor``
+        (True for a sink's monitor)
+    """
+    sources = []
+    matches = list(_SOURCE_RE.finditer(output))
+    for position, match in enumerate(matches):
+        end = matches[position + 1].start() if position + 1 < len(matches) else len(output)
+        fields = dict(_FIELD_RE.findall(output[match.end() : end]))
+        name = fields.get("Name", "").strip()
+        if not name:
+            continue
+        monitor_of = fields.get("Monitor of Sink", "n/a").strip()
+        sources.append(
+            {
+                "name": name,
+                "description": fields.get("Description", "").strip() or name,
+                "monitor": monitor_of != "n/a" or is_monitor_source(name),
+            }
+        )
+    return sources
+
+
+def list_monitor_sources(
+    run: Optional[Callable[..., subprocess.CompletedProcess]] = None,
+) -> list[tuple[str, str]]:
+    """
+    List the monitor sources of the output devices.
+
+    Args:
+        run: Replacement for subprocess.run, for tests
+
+    Returns:
+        (source name, description) pairs; empty if pactl isn't available
+    """
+    output = _pactl(run, "list", "sources")
+    if output is None:
+        return []
+    return [
+        (source["name"], source["description"])
+        for source in parse_sources(output)
+        if source["monitor"]
+    ]
+
+
+def parse_playback_streams(output: str) -> list[dict]:
+    """
+    Parse the output of ``pactl list sink-inputs``.
+
+    Only PipeWire reports the object.serial a stream can be recorded by, so
+    under plain PulseAudio nothing is returned.
+
+    Returns:
+        One dict per playback stream with ``app``, ``serial`` and ``media``
+        (what it plays, e.g. a tab title)
+    """
+    streams = []
+    matches = list(_SINK_INPUT_RE.finditer(output))
+    for position, match in enumerate(matches):
+        end = matches[position + 1].start() if position + 1 < len(matches) else len(output)
+        properties = dict(_PROPERTY_RE.findall(output[match.end() : end]))
+        app = properties.get("application.name", "").strip()
+        serial = properties.get("object.serial", "").strip()
+        if not app or not serial.isdigit():
+            continue
+        streams.append({"app": app, "serial": serial, "media": properties.get("media.name", "")})
+    return streams
+
+
+def list_playback_apps(
+    run: Optional[Callable[..., subprocess.CompletedProcess]] = None,
+) -> list[str]:
+    """
+    List the applications playing audio that can be recorded.
+
+    Args:
+        run: Replacement for subprocess.run, for tests
+
+    Returns:
+        Application names in the order pactl lists them; empty without PipeWire
+    """
+    output = _pactl(run, "list", "sink-inputs")
+    apps: list[str] = []
+    for stream in parse_playback_streams(output or ""):
+        if stream["app"] not in apps:
+            apps.append(stream["app"])
+    return apps
+
+
+def find_app_stream(
+    app: str, run: Optional[Callable[..., subprocess.CompletedProcess]] = None
+) -> Optional[str]:
+    """
+    Find the playback stream to record for an application.
+
+    Args:
+        app: The application name, e.g. "Firefox" or "ZOOM VoiceEngine"
+        run: Replacement for subprocess.run, for tests
+
+    Returns:
+        The object.serial of its newest stream, or None if it isn't playing
+    """
+    output = _pactl(run, "list", "sink-inputs")
+    serials = [
+        stream["serial"] for stream in parse_playback_streams(output or "") if stream["app"] == app
+    ]
+    return max(serials, key=int) if serials else None
+
+
+def is_source_muted(
+    source: Optional[str] = None,
+    run: Optional[Callable[..., subprocess.CompletedProcess]] = None,
+) -> Optional[bool]:
+    """
+    Whether a source is muted in the system sound settings.
+
+    Args:
+        source: The source name (None = the default source)
+        run: Replacement for subprocess.run, for tests
+
+    Returns:
+        True if muted, False if not, None if pactl can't tell
+    """
+    output = _pactl(run, "get-source-mute", source or DEFAULT_SOURCE)
+    match = _MUTE_RE.search(output or "")
+    return match.group(1) == "yes" if match else None
+
+
+def _pactl(run: Optional[Callable[..., subprocess.CompletedProcess]], *args: str) -> Optional[str]:
+    """Run pactl with untranslated output; its stdout, or None if it failed."""
+    run = run or subprocess.run
+    try:
+        result = run(
+            ["pactl", *args],
+            capture_output=True,
+            text=True,
+            timeout=_PACTL_TIMEOUT,
+            env={**os.environ, "LC_ALL": "C"},
+        )
+    except (OSError, subprocess.SubprocessError) as e:
+        logger.debug(f"pactl {args[0]} failed: {e}")
+        return None
+    if result.returncode != 0:
+        logger.debug(f"pactl {args[0]} failed: {result.stderr.strip()}")
+        return None
+    return result.stdout
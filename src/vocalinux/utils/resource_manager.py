@@ -109,7 +109,7 @@ class ResourceManager:
             "vocalinux-microphone-off",
             "vocalinux-microphone-process",
         ]
-        expected_sounds = ["start_recording", "stop_recording", "error"]
+        expected_sounds = ["start_recording", "stop_recording", "error", "utterance"]
 
         icon_score = sum(
             (candidate / "icons" / "scalable" / f"{icon}.svg").exists() for icon in expected_icons
@@ -214,7 +214,7 @@ class ResourceManager:
                 results["missing_icons"].append(icon)
 
         # Expected sounds
-        expected_sounds = ["start_recording", "stop_recording", "error"]
+        expected_sounds = ["start_recording", "stop_recording", "error", "utterance"]
 
         for sound in expected_sounds:
             sound_path = self.get_sound_path(sound)
@@ -0,0 +1,62 @@
+"""
+Tracking of the sounds Vocalinux plays itself.
+
+Feedback sounds and read-back speech come out of the speakers while the
+microphone is open, and echo cancellation (echo_cancel) is off by default.
+The players are registered here, and the recognition manager records silence
+while one of them is still running, unless the user is already speaking.
+"""
+
+import threading
+import time
+from typing import Callable
+
+# Room echo that reaches the microphone after the player has exited
+PLAYBACK_TAIL_SECONDS = 0.15
+
+
+class SelfPlayback:
+    """The players Vocalinux started and that may still be audible."""
+
+    def __init__(self, clock: Callable[[], float] = time.monotonic):
+        """
+        Initialize the tracker.
+
+        Args:
+            clock: Replacement for time.monotonic, for tests
+        """
+        self._clock = clock
+        self._lock = threading.Lock()
+        self._processes: list = []
+        self._audible_until = 0.0
+
+    def add(self, process) -> None:
+        """Register a player (a subprocess.Popen) that plays until it exits."""
+        with self._lock:
+            self._processes.append(process)
+
+    def is_playing(self) -> bool:
+        """Whether one of our sounds is playing, or has just ended and may still echo."""
+        with self._lock:
+            now = self._clock()
+            running = []
+            for process in self._processes:
+                if process.poll() is None:
+                    running.append(process)
+                else:
+                    self._audible_until = max(self._audible_until, now + PLAYBACK_TAIL_SECONDS)
+            self._processes = running
+            return bool(running) or now < self._audible_until
+
+
+_self_playback = SelfPlayback()
+
+
+def register_playback(process) -> None:
+    """Register a player process started by Vocalinux (feedback sound, read-back)."""
+    _self_playback.add(process)
+
+
+def is_self_playback_active() -> bool:
+    """Whether a sound Vocalinux started may currently reach the microphone."""
+    return _self_playback.is_playing()
@@ -0,0 +1,110 @@
+"""
+Reading and writing the TOML config file for Vocalinux.
+
+Reading uses the standard library's tomllib (Python 3.11+) or the tomli
+backport. Writing covers what the config holds: tables, strings, numbers,
+booleans and lists. TOML has no null, so None values are left out of the
+file and read back as the default.
+"""
+
+import json
+import re
+from typing import Any, Optional
+
+try:  # Python 3.11+
+    import tomllib as _toml_reader
+except ImportError:  # pragma: no cover - depends on the Python version
+    try:
+        import tomli as _toml_reader
+    except ImportError:
+        _toml_reader = None
+
+TOML_AVAILABLE = _toml_reader is not None
+
+# Raised for malformed TOML (ValueError when no reader is installed)
+TOMLDecodeError = _toml_reader.TOMLDecodeError if _toml_reader is not None else ValueError
+
+_BARE_KEY_RE = re.compile(r"^[A-Za-z0-9_-]+$")
+
+
+def loads(text: str) -> dict:
+    """
+    Parse TOML text.
+
+    Raises:
+        TOMLDecodeError: If the text is not valid TOML
+        RuntimeError: If no TOML reader is installed
+    """
+    if _toml_reader is None:
+        raise RuntimeError("Reading TOML needs Python 3.11 or the tomli package")
+    return _toml_reader.loads(text)
+
+
+def _key(key: str) -> str:
+    return key if _BARE_KEY_RE.match(key) else _string(key)
+
+
+def _string(value: str) -> str:
+    # JSON string escapes are valid in TOML basic strings, except that TOML
+    # also forbids a raw DEL character
+    return json.dumps(value, ensure_ascii=False).replace("\x7f", "\\u007f")
+
+
+def _value(value: Any) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, (int, float)):
+        return repr(value)
+    if isinstance(value, str):
+        return _string(value)
+    if isinstance(value, (list, tuple)):
+        return "[" + ", ".join(_value(item) for item in value if item is not None) + "]"
+    if isinstance(value, dict):
+        pairs = (f"{_key(k)} = {_value(v)}" for k, v in value.items() if v is not None)
+        return "{ " + ", ".join(pairs) + " }" if value else "{}"
+    raise TypeError(f"Cannot write {type(value).__name__} to TOML")
+
+
+def _write_table(
+    lines: list[str], path: tuple[str, ...], table: dict, comments: dict[tuple, str]
+):
+    if path:
+        if lines:
+            lines.append("")
+        lines.append("[" + ".".join(_key(part) for part in path) + "]")
+
+    subtables = []
+    for key, value in table.items():
+        if isinstance(value, dict):
+            subtables.append((key, value))
+            continue
+        comment = comments.get(path + (key,))
+        if value is None:
+            # Keep the setting discoverable even though TOML can't store None
+            line = f"# {_key(key)} = (not set)"
+        else:
+            line = f"{_key(key)} = {_value(value)}"
+        lines.append(f"{line}  # {comment}" if comment else line)
+
+    for key, value in subtables:
+        _write_table(lines, path + (key,), value, comments)
+
+
+def dumps(
+    data: dict, comments: Optional[dict[tuple, str]] = None, header: Optional[str] = None
+) -> str:
+    """
+    Write a config dict as TOML.
+
+    Args:
+        data: Top-level keys mapping to tables (dicts)
+        comments: Comments to put after values, keyed by the path of keys
+            (e.g. ("audio", "sample_rate"))
+        header: Comment lines for the top of the file
+
+    Returns:
+        The TOML text
+    """
+    lines = [f"# {line}" if line else "#" for line in (header or "").splitlines()]
+    _write_table(lines, (), data, comments or {})
+    return "\n".join(lines) + "\n"
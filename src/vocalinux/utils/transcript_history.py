@@ -0,0 +1,82 @@
+"""
+Recent transcript history for Vocalinux.
+
+Keeps the last few final transcripts in memory so they can be copied again
+from the tray menu ("what did I just say?"). Nothing is written to disk;
+the history is gone when Vocalinux exits.
+"""
+
+import threading
+from collections import deque
+from typing import Optional
+
+DEFAULT_HISTORY_SIZE = 10
+
+# Longest menu label before it is shortened with an ellipsis
+MENU_LABEL_CHARS = 48
+
+
+def menu_label(
+    text: str, max_chars: int = MENU_LABEL_CHARS, language: Optional[str] = None
+) -> str:
+    """Shorten a transcript to one line for a menu item, tagged with its language if known."""
+    label = " ".join(text.split())
+    if language and not label.startswith(f"[{language}] "):
+        label = f"[{language}] {label}"
+    if len(label) <= max_chars:
+        return label
+    return label[: max_chars - 1].rstrip() + "…"
+
+
+class TranscriptHistory:
+    """The most recent final transcripts, newest first."""
+
+    def __init__(self, max_entries: int = DEFAULT_HISTORY_SIZE):
+        """
+        Initialize the history.
+
+        Args:
+            max_entries: How many transcripts to keep (0 keeps none)
+        """
+        self._lock = threading.Lock()
+        self._entries: deque = deque(maxlen=max(0, int(max_entries)))
+
+    @property
+    def max_entries(self) -> int:
+        return self._entries.maxlen
+
+    @max_entries.setter
+    def max_entries(self, value: int):
+        with self._lock:
+            self._entries = deque(self._entries, maxlen=max(0, int(value)))
+
+    def add(self, text: Optional[str], language: Optional[str] = None):
+        """
+        Remember a transcript; blank text and repeats of the newest are ignored.
+
+        Args:
+            text: The final transcript
+            language: Language detected for it, if the engine auto-detected one
+        """
+        text = (text or "").strip()
+        if not text:
+            return
+        with self._lock:
+            if self._entries and self._entries[-1][0] == text:
+                return
+            self._entries.append((text, language))
+
+    def entries(self) -> list[str]:
+        """The remembered transcripts, newest first."""
+        with self._lock:
+            return [text for text, _language in reversed(self._entries)]
+
+    def items(self) -> list[tuple[str, Optional[str]]]:
+        """The remembered (transcript, detected language) pairs, newest first."""
+        with self._lock:
+            return list(reversed(self._entries))
+
+    def clear(self):
+        """Forget every transcript."""
+        with self._lock:
+            self._entries.clear()
@@ -0,0 +1,149 @@
+"""
+Per-utterance event log for Vocalinux.
+
+When enabled, every transcribed utterance appends one JSON object to
+utterances.jsonl in the data directory: when it happened, which engine and
+model decoded it, how long the audio and the decoding took, and the
+engine's confidence. Users can analyse their own dictation with it, and it
+helps with support triage without sharing audio.
+
+The privacy mode decides what is kept of the text itself: "hashed" stores a
+SHA-256 of it plus its length and word count, "full" stores the transcript.
+"""
+
+import hashlib
+import json
+import logging
+import os
+import threading
+import time
+from typing import Optional
+
+from .paths import data_dir
+
+logger = logging.getLogger(__name__)
+
+# Values for the general.utterance_log setting
+UTTERANCE_LOG_MODES = ("off", "hashed", "full")
+
+UTTERANCE_LOG_FILE = "utterances.jsonl"
+
+# The log is rotated to utterances.jsonl.1 once it grows past this size
+MAX_LOG_BYTES = 5 * 1024 * 1024
+
+
+def utterance_log_path() -> str:
+    """Return where the utterance log is written."""
+    return os.path.join(data_dir(), UTTERANCE_LOG_FILE)
+
+
+def text_hash(text: str) -> str:
+    """SHA-256 of a transcript, so equal utterances can be matched without the text."""
+    return hashlib.sha256(text.encode("utf-8")).hexdigest()
+
+
+class UtteranceLog:
+    """Appends one JSON line per utterance to the log file."""
+
+    def __init__(
+        self, mode: str = "off", path: Optional[str] = None, max_bytes: int = MAX_LOG_BYTES
+    ):
+        """
+        Initialize the log.
+
+        Args:
+            mode: "off", "hashed" or "full" (see the module docstring)
+            path: File to write, defaults to utterance_log_path()
+            max_bytes: Size at which the file is rotated
+        """
+        self._lock = threading.Lock()
+        self.path = path or utterance_log_path()
+        self.max_bytes = max_bytes
+        self.mode = mode
+
+    @property
+    def mode(self) -> str:
+        return self._mode
+
+    @mode.setter
+    def mode(self, value: str):
+        if value not in UTTERANCE_LOG_MODES:
+            logger.warning(f"Unknown utterance log mode {value!r}; turning the log off")
+            value = "off"
+        self._mode = value
+
+    @property
+    def enabled(self) -> bool:
+        return self._mode != "off"
+
+    def build_event(
+        self,
+        text: str,
+        engine: str,
+        model: Optional[str] = None,
+        language: Optional[str] = None,
+        audio_seconds: float = 0.0,
+        decode_seconds: float = 0.0,
+        confidence: Optional[float] = None,
+        timestamp: Optional[float] = None,
+    ) -> dict:
+        """
+        Build the event for one utterance, keeping only what the mode allows of the text.
+
+        Returns:
+            The JSON-serializable event
+        """
+        text = text or ""
+        timestamp = time.time() if timestamp is None else timestamp
+        event = {
+            "time": time.strftime("%Y-%m-%dT%H:%M:%S%z", time.localtime(timestamp)),
+            "engine": engine,
+            "model": model,
+            "language": language,
+            "audio_seconds": round(audio_seconds, 3),
+            "decode_seconds": round(decode_seconds, 3),
+            "rtf": round(decode_seconds / audio_seconds, 3) if audio_seconds > 0 else None,
+            "confidence": None if confidence is None else round(confidence, 3),
+            "chars": len(text),
+            "words": len(text.split()),
+        }
+        if self._mode == "full":
+            event["text"] = text
+        else:
+            event["text_sha256"] = text_hash(text) if text else None
+        return event
+
+    def record(self, text: str, engine: str, **fields) -> Optional[dict]:
+        """
+        Append an utterance to the log; does nothing when the log is off.
+
+        Args:
+            text: The transcript (may be empty when nothing was recognized)
+            engine: The engine that decoded it
+            **fields: The other build_event() arguments
+
+        Returns:
+            The event written, or None if the log is off or couldn't be written
+        """
+        if not self.enabled:
+            return None
+        event = self.build_event(text, engine, **fields)
+        line = json.dumps(event, ensure_ascii=False) + "\n"
+        with self._lock:
+            try:
+                os.makedirs(os.path.dirname(self.path), exist_ok=True)
+                self._rotate_if_needed()
+                with open(self.path, "a", encoding="utf-8") as log_file:
+                    log_file.write(line)
+            except OSError as e:
+                logger.warning(f"Could not write the utterance log {self.path}: {e}")
+                return None
+        return event
+
+    def _rotate_if_needed(self):
+        try:
+            if os.path.getsize(self.path) < self.max_bytes:
+                return
+        except OSError:
+            return
+        os.replace(self.path, f"{self.path}.1")
@@ -0,0 +1,187 @@
+"""
+Domain vocabulary packs for Vocalinux.
+
+Packs are curated lists of professional terms that are merged with the
+user's custom vocabulary and passed to Whisper as a glossary prompt, which
+biases decoding towards the correct spelling of specialist words.
+"""
+
+import logging
+from dataclasses import dataclass
+from typing import Iterable
+
+logger = logging.getLogger(__name__)
+
+# Whisper only reads the last ~224 tokens of a prompt; keep the glossary well within that
+MAX_VOCABULARY_PROMPT_CHARS = 600
+
+
+@dataclass(frozen=True)
+class VocabularyPack:
+    """A named list of domain terms."""
+
+    id: str
+    name: str
+    description: str
+    terms: tuple[str, ...]
+
+
+VOCABULARY_PACKS = {
+    "medical": VocabularyPack(
+        id="medical",
+        name="Medical",
+        description="Clinical terms, common drugs and dosing abbreviations",
+        terms=(
+            "acetaminophen",
+            "amoxicillin",
+            "atorvastatin",
+            "metformin",
+            "lisinopril",
+            "metoprolol",
+            "apixaban",
+            "warfarin",
+            "levothyroxine",
+            "prednisone",
+            "gabapentin",
+            "omeprazole",
+            "sertraline",
+            "albuterol",
+            "hypertension",
+            "hyperlipidemia",
+            "hypothyroidism",
+            "tachycardia",
+            "bradycardia",
+            "atrial fibrillation",
+            "myocardial infarction",
+            "dyspnea",
+            "edema",
+            "erythema",
+            "auscultation",
+            "palpation",
+            "differential diagnosis",
+            "echocardiogram",
+            "electrocardiogram",
+            "pneumothorax",
+            "cholecystectomy",
+            "laparoscopic",
+            "subcutaneous",
+            "intramuscular",
+            "thrombocytopenia",
+            "cellulitis",
+            "pharyngitis",
+            "otitis media",
+            "COPD",
+            "HbA1c",
+            "b.i.d.",
+            "t.i.d.",
+            "p.r.n.",
+            "NPO",
+        ),
+    ),
+    "legal": VocabularyPack(
+        id="legal",
+        name="Legal",
+        description="Court, contract and Latin legal terms",
+        terms=(
+            "plaintiff",
+            "defendant",
+            "appellant",
+            "appellee",
+            "petitioner",
+            "respondent",
+            "affidavit",
+            "subpoena",
+            "subpoena duces tecum",
+            "deposition",
+            "interrogatories",
+            "voir dire",
+            "habeas corpus",
+            "certiorari",
+            "amicus curiae",
+            "res judicata",
+            "stare decisis",
+            "prima facie",
+            "pro bono",
+            "pro se",
+            "per curiam",
+            "mens rea",
+            "actus reus",
+            "inter alia",
+            "estoppel",
+            "indemnification",
+            "force majeure",
+            "severability",
+            "summary judgment",
+            "statute of limitations",
+            "counterclaim",
+            "tortfeasor",
+            "fiduciary",
+            "easement",
+            "garnishment",
+            "escrow",
+            "lien",
+            "notwithstanding",
+            "heretofore",
+        ),
+    ),
+}
+
+
+def get_vocabulary_terms(pack_ids: Iterable[str], custom_terms: Iterable[str] = ()) -> list[str]:
+    """
+    Combine custom terms with the terms of the given packs.
+
+    Custom terms come first so they survive prompt truncation. Duplicates
+    (case-insensitive) and unknown pack ids are skipped.
+
+    Args:
+        pack_ids: Ids of the installed packs
+        custom_terms: Terms the user added themselves
+
+    Returns:
+        The combined term list
+    """
+    terms = []
+    seen = set()
+
+    def add(term: str):
+        term = term.strip()
+        if term and term.lower() not in seen:
+            seen.add(term.lower())
+            terms.append(term)
+
+    for term in custom_terms:
+        add(term)
+    for pack_id in pack_ids:
+        pack = VOCABULARY_PACKS.get(pack_id)
+        if pack is None:
+            logger.warning(f"Unknown vocabulary pack: {pack_id}")
+            continue
+        for term in pack.terms:
+            add(term)
+    return terms
+
+
+def build_vocabulary_prompt(terms: Iterable[str], base_prompt: str = "") -> str:
+    """
+    Build a Whisper prompt listing the vocabulary as a glossary.
+
+    Args:
+        terms: Vocabulary terms, most important first
+        base_prompt: The user's own initial prompt, kept ahead of the glossary
+
+    Returns:
+        The combined prompt (the base prompt alone if there are no terms)
+    """
+    glossary = []
+    length = 0
+    for term in terms:
+        length += len(term) + 2
+        if length > MAX_VOCABULARY_PROMPT_CHARS:
+            break
+        glossary.append(term)
+
+    if not glossary:
+        return base_prompt
+    prompt = f"Glossary: {', '.join(glossary)}."
+    return f"{base_prompt.strip()} {prompt}" if base_prompt.strip() else prompt
@@ -0,0 +1,147 @@
+"""
+What whisper.cpp actually ran on, read from its load log.
+
+detect_compute_backend() guesses the backend from the drivers and tools on
+the system, but whether whisper.cpp really uses the GPU depends on how
+pywhispercpp was built and whether the device initializes. whisper.cpp
+logs the devices it found, the backend it picked and where it put the
+model weights to stderr while loading, so the load is run with stderr
+captured and that log is parsed.
+
+whisper.cpp keeps the whole model on one device (there is no partial
+layer offload as in llama.cpp), so the weights' buffer sizes per device
+tell how much of the model is on the GPU.
+"""
+
+import logging
+import os
+import re
+import sys
+import tempfile
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+# "whisper_backend_init_gpu: using Vulkan0 backend" (older versions: "whisper_backend_init")
+_USING_BACKEND_RE = re.compile(r"whisper_backend_init\w*: using ([A-Za-z]+)\d* backend")
+_NO_GPU_RE = re.compile(r"whisper_backend_init\w*: no GPU found")
+# "whisper_model_load:      Vulkan0 total size =   147.37 MB" (older: "CPU buffer size")
+_BUFFER_RE = re.compile(
+    r"whisper_model_load:\s+([A-Za-z]+)\d*(?:_\w+)? (?:total|buffer) size\s*=\s*([\d.]+) MB"
+)
+# "ggml_vulkan: 0 = AMD Radeon RX 6600 (RADV NAVI23) (radv) | uma: 0 | ..."
+_VULKAN_DEVICE_RE = re.compile(r"ggml_vulkan: \d+ = (.+?)(?: \(|\s*\|)")
+# "  Device 0: NVIDIA GeForce RTX 3060, compute capability 8.6, VMM: yes"
+_CUDA_DEVICE_RE = re.compile(r"Device \d+: (.+?), compute capability")
+
+_BACKEND_NAMES = {"vulkan": "Vulkan", "cuda": "CUDA", "metal": "Metal", "cpu": "CPU"}
+
+
+@dataclass
+class WhisperCppLoadReport:
+    """The backend whisper.cpp initialized and where the model weights went."""
+
+    backend: str = "unknown"  # "vulkan", "cuda", "metal", "cpu" or "unknown"
+    device: Optional[str] = None  # GPU name, when the log names it
+    buffers_mb: dict = field(default_factory=dict)  # Weight buffer size per backend
+
+    @property
+    def uses_gpu(self) -> bool:
+        return self.backend not in ("cpu", "unknown")
+
+    @property
+    def gpu_mb(self) -> float:
+        """Megabytes of model weights on the GPU."""
+        return sum(size for name, size in self.buffers_mb.items() if name != "cpu")
+
+    @property
+    def display_name(self) -> str:
+        return _BACKEND_NAMES.get(self.backend, self.backend.title())
+
+    def summary(self) -> str:
+        """One line for notifications and the settings, e.g. 'Vulkan (AMD Radeon RX 6600)'."""
+        text = self.display_name
+        if self.device and self.uses_gpu:
+            text += f" ({self.device})"
+        total = sum(self.buffers_mb.values())
+        if self.uses_gpu and total:
+            text += f", {self.gpu_mb:.0f} of {total:.0f} MB on the GPU"
+        return text
+
+
+def parse_load_log(log: str) -> WhisperCppLoadReport:
+    """
+    Read the backend and weight placement from whisper.cpp's load log.
+
+    Args:
+        log: What whisper.cpp wrote to stderr while loading a model
+
+    Returns:
+        The report; backend is "unknown" if the log doesn't say
+    """
+    report = WhisperCppLoadReport()
+    for name, size in _BUFFER_RE.findall(log):
+        name = name.lower()
+        report.buffers_mb[name] = report.buffers_mb.get(name, 0.0) + float(size)
+
+    using = _USING_BACKEND_RE.search(log)
+    if using:
+        report.backend = using.group(1).lower()
+    elif _NO_GPU_RE.search(log):
+        report.backend = "cpu"
+    elif report.buffers_mb:
+        gpu_buffers = [name for name in report.buffers_mb if name != "cpu"]
+        report.backend = gpu_buffers[0] if gpu_buffers else "cpu"
+
+    if report.backend == "vulkan":
+        device = _VULKAN_DEVICE_RE.search(log)
+    elif report.backend == "cuda":
+        device = _CUDA_DEVICE_RE.search(log)
+    else:
+        device = None
+    if device:
+        report.device = device.group(1).strip()
+    return report
+
+
+class CapturedStderr:
+    """Holds what was written to stderr inside capture_native_stderr()."""
+
+    def __init__(self):
+        self.text = ""
+
+
+@contextmanager
+def capture_native_stderr():
+    """
+    Capture what native libraries write to file descriptor 2 inside the block.
+
+    The captured output is written back to stderr afterwards, so it still
+    shows up in a terminal.
+    """
+    captured = CapturedStderr()
+    try:
+        sys.stderr.flush()
+        saved_fd = os.dup(2)
+    except (OSError, ValueError, AttributeError) as e:
+        logger.debug(f"Cannot capture stderr: {e}")
+        yield captured
+        return
+
+    with tempfile.TemporaryFile() as buffer:
+        os.dup2(buffer.fileno(), 2)
+        try:
+            yield captured
+        finally:
+            os.dup2(saved_fd, 2)
+            os.close(saved_fd)
+            buffer.seek(0)
+            data = buffer.read()
+            captured.text = data.decode("utf-8", errors="replace")
+            if data:
+                try:
+                    os.write(2, data)
+                except OSError:
+                    pass
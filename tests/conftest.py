@@ -86,6 +86,7 @@ mock_audio_feedback = MagicMock()
 mock_audio_feedback.play_start_sound = MagicMock()
 mock_audio_feedback.play_stop_sound = MagicMock()
 mock_audio_feedback.play_error_sound = MagicMock()
+mock_audio_feedback.play_utterance_sound = MagicMock()
 
 # Inject the mock into sys.modules so imports resolve correctly
 sys.modules["vocalinux.ui.audio_feedback"] = mock_audio_feedback
@@ -168,12 +169,29 @@ def pytest_addoption(parser):
         default=False,
         help="Run audio feedback tests (may fail in CI environments without audio)",
     )
+    parser.addoption(
+        "--run-xvfb-tests",
+        action="store_true",
+        default=False,
+        help="Run text injection tests against a headless X server (needs Xvfb and xdotool)",
+    )
 
 
 def pytest_configure(config):
     """Configure pytest markers."""
     config.addinivalue_line("markers", "tray: marks tests as tray indicator tests")
     config.addinivalue_line("markers", "audio: marks tests as audio feedback tests")
+    config.addinivalue_line("markers", "xvfb: marks tests that type into an Xvfb display")
+
+
+def pytest_collection_modifyitems(config, items):
+    """Skip the Xvfb tests unless they were asked for; they start real X clients."""
+    if config.getoption("--run-xvfb-tests"):
+        return
+    skip_xvfb = pytest.mark.skip(reason="needs --run-xvfb-tests")
+    for item in items:
+        if "xvfb" in item.keywords:
+            item.add_marker(skip_xvfb)
 
 
 @pytest.fixture
@@ -3,7 +3,7 @@ Tests for the action handler module.
 """
 
 import unittest
-from unittest.mock import MagicMock
+from unittest.mock import MagicMock, patch
 
 
 class TestActionHandler(unittest.TestCase):
@@ -140,3 +140,45 @@ class TestActionHandler(unittest.TestCase):
         self.assertFalse(result)
         # Text should not be cleared on failure
         self.assertEqual(self.handler.last_injected_text, "test")
+
+    def test_read_back_speaks_last_text(self):
+        """Test read_back speaks the last injected text when enabled."""
+        self.handler.set_last_injected_text(" Hello world")
+
+        with patch(
+            "vocalinux.ui.text_to_speech.is_readback_enabled", return_value=True
+        ), patch("vocalinux.ui.text_to_speech.speak", return_value=True) as mock_speak:
+            result = self.handler.handle_action("read_back")
+
+        self.assertTrue(result)
+        mock_speak.assert_called_once_with(" Hello world")
+
+    def test_read_back_disabled(self):
+        """Test read_back does nothing when disabled in config."""
+        self.handler.set_last_injected_text("Hello")
+
+        with patch(
+            "vocalinux.ui.text_to_speech.is_readback_enabled", return_value=False
+        ), patch("vocalinux.ui.text_to_speech.speak") as mock_speak:
+            result = self.handler.handle_action("read_back")
+
+        self.assertFalse(result)
+        mock_speak.assert_not_called()
+
+    def test_read_back_no_text(self):
+        """Test read_back with nothing dictated yet."""
+        with patch(
+            "vocalinux.ui.text_to_speech.is_readback_enabled", return_value=True
+        ), patch("vocalinux.ui.text_to_speech.speak") as mock_speak:
+            result = self.handler.handle_action("read_back")
+
+        self.assertTrue(result)
+        mock_speak.assert_not_called()
+
+    def test_register_actions(self):
+        """Test extra action handlers can be registered."""
+        extra = MagicMock(return_value=True)
+        self.handler.register_actions({"increase_sensitivity": extra})
+
+        self.assertTrue(self.handler.handle_action("increase_sensitivity"))
+        extra.assert_called_once()
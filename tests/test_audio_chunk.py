@@ -0,0 +1,42 @@
+"""Tests for captured audio chunks and their metadata."""
+
+import unittest
+
+from vocalinux.speech_recognition.audio_chunk import AudioChunk, chunk_sources, missing_chunks
+
+
+class TestAudioChunk(unittest.TestCase):
+    """Test AudioChunk and the helpers that read its metadata."""
+
+    def test_behaves_as_bytes(self):
+        chunk = AudioChunk(b"\x01\x00\x02\x00", "USB Microphone", 48000, 7)
+
+        self.assertEqual(chunk, b"\x01\x00\x02\x00")
+        following = AudioChunk(b"\x03\x00", None, 16000, 8)
+        self.assertEqual(b"".join([chunk, following]), b"\x01\x00\x02\x00\x03\x00")
+        self.assertEqual(chunk.source, "USB Microphone")
+        self.assertEqual(chunk.capture_rate, 48000)
+        self.assertEqual(chunk.sequence, 7)
+        self.assertIn("sequence=7", repr(chunk))
+
+    def test_missing_chunks(self):
+        chunks = [AudioChunk(b"\x00\x00", "mic", 16000, n) for n in (3, 4, 7, 8, 10)]
+
+        self.assertEqual(missing_chunks(chunks), 3)
+        self.assertEqual(missing_chunks(chunks[:2]), 0)
+        # Plain bytes carry no sequence number and are skipped
+        self.assertEqual(missing_chunks([b"\x00\x00", chunks[0], b"\x00\x00", chunks[1]]), 0)
+
+    def test_chunk_sources_in_order(self):
+        chunks = [
+            AudioChunk(b"", "Laptop Microphone", 44100, 1),
+            AudioChunk(b"", "Laptop Microphone", 44100, 2),
+            AudioChunk(b"", "USB Headset", 48000, 3),
+            b"",
+        ]
+
+        self.assertEqual(chunk_sources(chunks), ["Laptop Microphone", "USB Headset"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,57 @@
+"""
+Tests for the debug dumps of captured utterances.
+"""
+
+import os
+import tempfile
+import unittest
+import wave
+
+from vocalinux.utils.audio_dump import AudioDumper
+
+
+class TestAudioDumper(unittest.TestCase):
+    """Tests for AudioDumper."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.directory = os.path.join(self.temp_dir.name, "dumps")
+
+    def tearDown(self):
+        self.temp_dir.cleanup()
+
+    def test_off_without_directory(self):
+        dumper = AudioDumper()
+        self.assertFalse(dumper.enabled)
+        self.assertIsNone(dumper.dump([b"\x01\x00"]))
+
+    def test_writes_timestamped_wav(self):
+        dumper = AudioDumper(self.directory)
+        path = dumper.dump([b"\x01\x00\x02\x00", b"\x03\x00"], timestamp=0)
+        self.assertEqual(os.path.dirname(path), self.directory)
+        self.assertRegex(os.path.basename(path), r"^utterance-\d{8}-\d{6}-0001\.wav$")
+        with wave.open(path, "rb") as wav_file:
+            self.assertEqual(wav_file.getnchannels(), 1)
+            self.assertEqual(wav_file.getsampwidth(), 2)
+            self.assertEqual(wav_file.getframerate(), 16000)
+            self.assertEqual(wav_file.readframes(3), b"\x01\x00\x02\x00\x03\x00")
+
+    def test_files_sort_in_capture_order(self):
+        dumper = AudioDumper(self.directory)
+        paths = [dumper.dump([b"\x00\x00"], timestamp=0) for _ in range(3)]
+        self.assertEqual(len(set(paths)), 3)
+        self.assertEqual(sorted(os.listdir(self.directory)), [os.path.basename(p) for p in paths])
+
+    def test_empty_utterance_skipped(self):
+        self.assertIsNone(AudioDumper(self.directory).dump([]))
+        self.assertFalse(os.path.exists(self.directory))
+
+    def test_unwritable_directory(self):
+        blocker = os.path.join(self.temp_dir.name, "file")
+        with open(blocker, "w") as f:
+            f.write("not a directory")
+        self.assertIsNone(AudioDumper(os.path.join(blocker, "dumps")).dump([b"\x00\x00"]))
+
+
+if __name__ == "__main__":
+    unittest.main()
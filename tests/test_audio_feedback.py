@@ -209,6 +209,20 @@ class TestAudioFeedback(unittest.TestCase):
             self.assertEqual(args[0][0], "paplay")
             self.assertEqual(args[0][1], "test.wav")
 
+    def test_play_sound_file_registers_the_player(self):
+        """Test the player is registered so the microphone is muted while it plays."""
+        import vocalinux.ui.audio_feedback as audio_feedback
+
+        with (
+            patch.object(audio_feedback.os.path, "exists", return_value=True),
+            patch.object(audio_feedback, "_get_audio_player", return_value=("paplay", ["wav"])),
+            patch.object(audio_feedback.subprocess, "Popen") as mock_popen,
+            patch.object(audio_feedback, "register_playback") as register_playback,
+        ):
+            self.assertTrue(audio_feedback._play_sound_file("test.wav"))
+
+        register_playback.assert_called_once_with(mock_popen.return_value)
+
     def test_play_sound_file_aplay(self):
         """Test playing sound with aplay."""
         # Import the module first
@@ -412,6 +426,60 @@ class TestAudioFeedback(unittest.TestCase):
             result = audio_feedback.play_error_sound()
             self.assertFalse(result)
 
+    def test_play_utterance_sound_only_when_turned_on(self):
+        """Test the utterance earcon is off by default and plays once turned on."""
+        import vocalinux.ui.audio_feedback as audio_feedback
+
+        with (
+            patch.object(audio_feedback, "_is_sound_effects_enabled", return_value=True),
+            patch.object(audio_feedback, "_play_sound_file", return_value=True) as mock_play,
+        ):
+            with patch.object(audio_feedback, "_sound_effects_settings", return_value={}):
+                self.assertFalse(audio_feedback.play_utterance_sound())
+            mock_play.assert_not_called()
+
+            with patch.object(
+                audio_feedback, "_sound_effects_settings", return_value={"utterance": True}
+            ):
+                self.assertTrue(audio_feedback.play_utterance_sound())
+            mock_play.assert_called_once_with(audio_feedback.UTTERANCE_SOUND)
+
+    def test_config_is_read_once_per_sound(self):
+        """Test playing a sound reads the config file once, not once per setting."""
+        import vocalinux.ui.audio_feedback as audio_feedback
+
+        settings = {"sound_effects": {"enabled": True, "utterance": True, "utterance_sound": ""}}
+        with (
+            patch("vocalinux.ui.config_manager.ConfigManager") as mock_config_manager,
+            patch.object(audio_feedback, "_play_sound_file", return_value=True) as mock_play,
+        ):
+            mock_config_manager.return_value.get_settings.return_value = settings
+            self.assertTrue(audio_feedback.play_utterance_sound())
+
+        mock_config_manager.assert_called_once()
+        mock_play.assert_called_once_with(audio_feedback.UTTERANCE_SOUND)
+
+    def test_custom_sound_replaces_the_built_in_one(self):
+        """Test a configured sound file is played, falling back when it doesn't exist."""
+        import tempfile
+
+        import vocalinux.ui.audio_feedback as audio_feedback
+
+        with tempfile.NamedTemporaryFile(suffix=".wav") as custom:
+            with (
+                patch.object(audio_feedback, "_is_sound_effects_enabled", return_value=True),
+                patch.object(audio_feedback, "_play_sound_file") as mock_play,
+            ):
+                settings = {"start_sound": custom.name, "stop_sound": "/missing/stop.wav"}
+                with patch.object(audio_feedback, "_sound_effects_settings", return_value=settings):
+                    audio_feedback.play_start_sound()
+                    audio_feedback.play_stop_sound()
+
+            self.assertEqual(
+                [c.args[0] for c in mock_play.call_args_list],
+                [custom.name, audio_feedback.STOP_SOUND],
+            )
+
     def test_is_sound_effects_enabled_returns_true_on_error(self):
         """Test that sound effects are enabled by default when config is unavailable."""
         import vocalinux.ui.audio_feedback as audio_feedback
@@ -0,0 +1,105 @@
+"""
+Tests for dBFS audio level metering.
+"""
+
+import unittest
+
+import numpy as np
+
+from vocalinux.speech_recognition.audio_level import (
+    METER_FLOOR_DBFS,
+    LevelMeter,
+    NoiseFloorEstimator,
+    adaptive_vad_threshold_dbfs,
+    amplitude_dbfs,
+    dbfs_to_percent,
+    percent_to_dbfs,
+    rms_dbfs,
+    vad_threshold_dbfs,
+)
+
+
+class TestLevels(unittest.TestCase):
+    """Tests for the level conversions."""
+
+    def test_full_scale_square_wave_is_zero_dbfs(self):
+        samples = np.array([32767, -32768] * 512, dtype=np.int16)
+        self.assertAlmostEqual(rms_dbfs(samples), 0.0, places=3)
+
+    def test_half_scale_is_minus_six_dbfs(self):
+        samples = np.full(1024, 16384, dtype=np.int16)
+        self.assertAlmostEqual(rms_dbfs(samples), -6.02, places=2)
+
+    def test_silence_reads_floor(self):
+        self.assertEqual(rms_dbfs(np.zeros(1024, dtype=np.int16)), METER_FLOOR_DBFS)
+        self.assertEqual(rms_dbfs(np.array([], dtype=np.int16)), METER_FLOOR_DBFS)
+        self.assertEqual(amplitude_dbfs(0), METER_FLOOR_DBFS)
+
+    def test_meter_scale_round_trip(self):
+        self.assertEqual(dbfs_to_percent(METER_FLOOR_DBFS), 0.0)
+        self.assertEqual(dbfs_to_percent(0.0), 100.0)
+        self.assertEqual(dbfs_to_percent(-30.0), 50.0)
+        self.assertEqual(dbfs_to_percent(-90.0), 0.0)
+        self.assertAlmostEqual(percent_to_dbfs(dbfs_to_percent(-23.5)), -23.5)
+
+    def test_vad_threshold_lowers_with_sensitivity(self):
+        thresholds = [vad_threshold_dbfs(sensitivity) for sensitivity in range(1, 6)]
+        self.assertEqual(thresholds, sorted(thresholds, reverse=True))
+        self.assertAlmostEqual(thresholds[0], -34.4, places=1)
+        self.assertEqual(vad_threshold_dbfs("bad"), vad_threshold_dbfs(3))
+        self.assertEqual(vad_threshold_dbfs(9), vad_threshold_dbfs(5))
+
+    def test_adaptive_threshold_rises_above_noise(self):
+        quiet_room = adaptive_vad_threshold_dbfs(3, METER_FLOOR_DBFS)
+        self.assertEqual(quiet_room, vad_threshold_dbfs(3))
+        self.assertEqual(adaptive_vad_threshold_dbfs(3, None), vad_threshold_dbfs(3))
+        self.assertEqual(adaptive_vad_threshold_dbfs(3, -35.0), -27.0)
+        # A less sensitive setting wants speech further above the noise
+        self.assertGreater(adaptive_vad_threshold_dbfs(1, -35.0), -27.0)
+
+
+class TestNoiseFloorEstimator(unittest.TestCase):
+    """Tests for tracking the background noise."""
+
+    def test_calibrates_then_reports_quietest_level(self):
+        noise = NoiseFloorEstimator(window=2.0, calibration=0.5)
+        noise.update(-35.0, 0.25)
+        self.assertIsNone(noise.floor)
+        noise.update(-20.0, 0.25)
+        self.assertEqual(noise.floor, -35.0)
+
+    def test_forgets_noise_older_than_the_window(self):
+        noise = NoiseFloorEstimator(window=1.0, calibration=0.0)
+        noise.update(-60.0, 0.5)
+        for _ in range(4):
+            noise.update(-35.0, 0.5)
+        self.assertEqual(noise.floor, -35.0)
+
+        noise.reset()
+        self.assertIsNone(NoiseFloorEstimator().floor)
+
+    def test_no_floor_before_any_audio_without_calibration(self):
+        self.assertIsNone(NoiseFloorEstimator(calibration=0.0).floor)
+
+
+class TestLevelMeter(unittest.TestCase):
+    """Tests for meter ballistics."""
+
+    def test_fast_attack_slow_release(self):
+        meter = LevelMeter(attack=0.01, release=0.3)
+        rising = meter.update(-10.0, 0.064)
+        self.assertGreater(rising, -11.0)
+
+        falling = meter.update(METER_FLOOR_DBFS, 0.064)
+        self.assertGreater(falling, -25.0)
+        self.assertLess(falling, rising)
+
+    def test_reset(self):
+        meter = LevelMeter()
+        meter.update(-3.0, 1.0)
+        meter.reset()
+        self.assertEqual(meter.level, METER_FLOOR_DBFS)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,51 @@
+"""
+Tests for the scratch arrays reused between audio chunks.
+"""
+
+import unittest
+
+import numpy as np
+
+from vocalinux.speech_recognition.audio_level import rms_dbfs
+from vocalinux.speech_recognition.buffer_pool import BufferPool
+
+
+class TestBufferPool(unittest.TestCase):
+    """Tests for BufferPool."""
+
+    def test_reuses_array_for_same_or_smaller_chunks(self):
+        pool = BufferPool()
+        first = pool.take("gain", 1024, np.float32)
+        self.assertEqual(len(first), 1024)
+        self.assertTrue(np.shares_memory(first, pool.take("gain", 1024, np.float32)))
+        self.assertTrue(np.shares_memory(first, pool.take("gain", 100, np.float32)))
+        self.assertEqual(len(pool.take("gain", 100, np.float32)), 100)
+
+    def test_grows_for_larger_chunks_and_other_types(self):
+        pool = BufferPool()
+        first = pool.take("gain", 100, np.float32)
+        larger = pool.take("gain", 2048, np.float32)
+        self.assertEqual(len(larger), 2048)
+        self.assertFalse(np.shares_memory(first, larger))
+        self.assertEqual(pool.take("gain", 10, np.int16).dtype, np.int16)
+
+    def test_keys_are_separate(self):
+        pool = BufferPool()
+        gain = pool.take("gain", 64, np.float32)
+        self.assertFalse(np.shares_memory(gain, pool.take("rms", 64, np.float32)))
+
+    def test_clear_releases_arrays(self):
+        pool = BufferPool()
+        first = pool.take("gain", 64, np.float32)
+        pool.clear()
+        self.assertFalse(np.shares_memory(first, pool.take("gain", 64, np.float32)))
+
+    def test_pooled_level_matches(self):
+        pool = BufferPool()
+        samples = np.array([1000, -1000, 2000, -2000], dtype=np.int16)
+        self.assertAlmostEqual(rms_dbfs(samples, pool), rms_dbfs(samples))
+        self.assertAlmostEqual(rms_dbfs(samples[:2], pool), rms_dbfs(samples[:2]))
+
+
+if __name__ == "__main__":
+    unittest.main()
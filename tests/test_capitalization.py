@@ -0,0 +1,38 @@
+"""
+Tests for capitalization preservation.
+"""
+
+import unittest
+
+from vocalinux.speech_recognition.capitalization import TermCapitalizer
+
+
+class TestTermCapitalizer(unittest.TestCase):
+    """Tests for restoring the capitalization of listed terms."""
+
+    def test_no_terms_leaves_text_unchanged(self):
+        self.assertEqual(TermCapitalizer().apply("the api"), "the api")
+
+    def test_restores_canonical_spelling(self):
+        capitalizer = TermCapitalizer(["API", "GmbH", "PostgreSQL"])
+        self.assertEqual(
+            capitalizer.apply("Our Api talks to postgresql at acme gmbh."),
+            "Our API talks to PostgreSQL at acme GmbH.",
+        )
+
+    def test_matches_whole_words_only(self):
+        capitalizer = TermCapitalizer(["API"])
+        self.assertEqual(capitalizer.apply("rapid apis and api"), "rapid apis and API")
+
+    def test_longest_term_wins_and_symbols_match(self):
+        capitalizer = TermCapitalizer(["SQL", "PostgreSQL", "C++", "GitHub Actions"])
+        self.assertEqual(
+            capitalizer.apply("postgresql, sql, c++ and github actions"),
+            "PostgreSQL, SQL, C++ and GitHub Actions",
+        )
+
+    def test_terms_can_be_replaced(self):
+        capitalizer = TermCapitalizer(["API"])
+        capitalizer.terms = [" GmbH ", ""]
+        self.assertEqual(capitalizer.terms, ["GmbH"])
+        self.assertEqual(capitalizer.apply("api gmbh"), "api GmbH")
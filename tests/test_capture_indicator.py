@@ -0,0 +1,73 @@
+"""
+Tests for the "dictation active" indicator published on D-Bus.
+"""
+
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.capture_indicator import BUS_NAME, INTERFACE, OBJECT_PATH, CaptureIndicator
+from vocalinux.common_types import RecognitionState
+
+
+@patch("vocalinux.capture_indicator.GLib")
+@patch("vocalinux.capture_indicator.Gio")
+class TestCaptureIndicator(unittest.TestCase):
+    """Tests for CaptureIndicator."""
+
+    def _make_indicator(self, mock_gio, mock_glib, **kwargs):
+        mock_glib.idle_add.side_effect = lambda func, *args: func(*args)
+        mock_glib.Variant.side_effect = lambda signature, value: (signature, value)
+        indicator = CaptureIndicator(**kwargs)
+        connection = MagicMock()
+        indicator._on_bus_acquired(connection, BUS_NAME)
+        return indicator, connection
+
+    def test_owns_name_and_exports_object(self, mock_gio, mock_glib):
+        indicator, connection = self._make_indicator(mock_gio, mock_glib)
+
+        self.assertEqual(mock_gio.bus_own_name.call_args[0][1], BUS_NAME)
+        self.assertEqual(connection.register_object.call_args[0][0], OBJECT_PATH)
+        get_property = connection.register_object.call_args[0][3]
+        self.assertEqual(
+            get_property(connection, ":1.2", OBJECT_PATH, INTERFACE, "Capturing"),
+            ("b", False),
+        )
+
+        indicator.shutdown()
+        connection.unregister_object.assert_called_once()
+        mock_gio.bus_unown_name.assert_called_once_with(mock_gio.bus_own_name.return_value)
+
+    def test_capturing_follows_recognition_state(self, mock_gio, mock_glib):
+        changes = []
+        indicator, connection = self._make_indicator(
+            mock_gio, mock_glib, on_capturing_changed=changes.append
+        )
+
+        indicator.on_state_changed(RecognitionState.LISTENING)
+        indicator.on_state_changed(RecognitionState.PROCESSING)
+        self.assertTrue(indicator.capturing)
+        connection.emit_signal.assert_called_once()
+        signal_args = connection.emit_signal.call_args[0]
+        self.assertEqual(signal_args[3], "PropertiesChanged")
+        self.assertEqual(signal_args[4][1][1]["Capturing"], ("b", True))
+
+        indicator.on_state_changed(RecognitionState.IDLE)
+        self.assertFalse(indicator.capturing)
+        self.assertEqual(changes, [True, False])
+        self.assertEqual(connection.emit_signal.call_count, 2)
+
+    def test_works_without_session_bus(self, mock_gio, mock_glib):
+        mock_gio.bus_own_name.side_effect = RuntimeError("no session bus")
+        mock_glib.idle_add.side_effect = lambda func, *args: func(*args)
+        changes = []
+
+        indicator = CaptureIndicator(on_capturing_changed=changes.append)
+        indicator.on_state_changed(RecognitionState.LISTENING)
+        indicator.shutdown()
+
+        self.assertEqual(changes, [True])
+        mock_gio.bus_unown_name.assert_not_called()
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -343,3 +343,144 @@ class TestCommandProcessorFallback(unittest.TestCase):
         """Test paste action through generic path."""
         result, actions = self.processor.process_text("paste content")
         self.assertIn("paste", actions)
+
+    def test_read_that_back_action(self):
+        """Test read that back maps to the read_back action."""
+        result, actions = self.processor.process_text("read that back")
+        self.assertEqual(result, "")
+        self.assertEqual(actions, ["read_back"])
+
+    def test_settings_voice_commands(self):
+        """Test voice-controlled settings map to their actions."""
+        result, actions = self.processor.process_text("increase sensitivity")
+        self.assertEqual(actions, ["increase_sensitivity"])
+        result, actions = self.processor.process_text("lower sensitivity")
+        self.assertEqual(actions, ["decrease_sensitivity"])
+        result, actions = self.processor.process_text("decrease silence timeout")
+        self.assertEqual(actions, ["decrease_silence_timeout"])
+        result, actions = self.processor.process_text("switch to medium model")
+        self.assertEqual(result, "")
+        self.assertEqual(actions, ["switch_model_medium"])
+        result, actions = self.processor.process_text("translate to English")
+        self.assertEqual(actions, ["enable_translation"])
+        result, actions = self.processor.process_text("stop translating")
+        self.assertEqual(actions, ["disable_translation"])
+
+    def test_numbers_mode_converts_spoken_numbers(self):
+        """Test numbers mode types digits only and persists across utterances."""
+        text, actions = self.processor.process_text("numbers mode four two one")
+        self.assertEqual(text, "421")
+        self.assertEqual(actions, [])
+        self.assertEqual(self.processor.field_mode, "numbers")
+
+        self.assertEqual(self.processor.process_text("forty two")[0], "42")
+        self.assertEqual(self.processor.process_text("one hundred and five")[0], "105")
+        self.assertEqual(self.processor.process_text("nineteen eighty four")[0], "1984")
+        self.assertEqual(self.processor.process_text("three point one four")[0], "3.14")
+        self.assertEqual(self.processor.process_text("It's 555-1234.")[0], "5551234")
+
+    def test_letters_mode_spells_letters(self):
+        """Test letters mode accepts single letters and phonetic words."""
+        self.processor.process_text("letters mode")
+        self.assertEqual(self.processor.field_mode, "letters")
+        self.assertEqual(self.processor.process_text("A, B, C.")[0], "abc")
+        self.assertEqual(self.processor.process_text("capital alpha bravo")[0], "Ab")
+
+    def test_normal_mode_restores_dictation(self):
+        """Test leaving a field mode returns to normal command processing."""
+        self.processor.set_field_mode("numbers")
+        text, _ = self.processor.process_text("seven exit numbers mode hello period")
+        self.assertEqual(text, "7 hello.")
+        self.assertIsNone(self.processor.field_mode)
+
+    def test_set_field_mode_rejects_unknown_mode(self):
+        """Test an unknown field mode raises ValueError."""
+        with self.assertRaises(ValueError):
+            self.processor.set_field_mode("emoji")
+
+
+class TestLiteralEscape(unittest.TestCase):
+    """Test dictating command phrases as plain words."""
+
+    def setUp(self):
+        self.processor = CommandProcessor()
+
+    def test_literal_prefix_types_the_phrase(self):
+        """Test "literal" and "type the word" keep the next command's words."""
+        self.assertEqual(
+            self.processor.process_text("the word literal period is ambiguous"),
+            ("the word period is ambiguous", []),
+        )
+        self.assertEqual(
+            self.processor.process_text("type the word new line please")[0], "new line please"
+        )
+        self.assertEqual(self.processor.process_text("Literal Period.")[0], "Period.")
+
+    def test_other_commands_still_run(self):
+        """Test only the escaped phrase is kept as words."""
+        self.assertEqual(
+            self.processor.process_text("literal comma, then comma")[0], "comma, then,"
+        )
+        self.assertEqual(
+            self.processor.process_text("literal undo then delete that"), ("", ["delete_last"])
+        )
+
+    def test_prefix_without_command_is_ordinary_text(self):
+        """Test "literal" before ordinary words is typed as is."""
+        self.assertEqual(
+            self.processor.process_text("a literal translation")[0], "a literal translation"
+        )
+
+    def test_escapes_mode_and_renamed_commands(self):
+        """Test field mode and renamed phrases can be escaped too."""
+        self.assertEqual(self.processor.process_text("literal numbers mode")[0], "numbers mode")
+        self.assertIsNone(self.processor.field_mode)
+
+        processor = CommandProcessor(renamed_commands={"period": "full stop now"})
+        self.assertEqual(processor.process_text("literal full stop now")[0], "full stop now")
+
+
+class TestCustomizedCommands(unittest.TestCase):
+    """Test disabling and renaming built-in commands."""
+
+    def test_disabled_command_typed_as_word(self):
+        """Test a disabled command is left in the text as a word."""
+        processor = CommandProcessor(disabled_commands=["Period"])
+        self.assertEqual(
+            processor.process_text("end of sentence period")[0], "end of sentence period"
+        )
+        self.assertEqual(processor.process_text("really question mark")[0], "really?")
+        self.assertNotIn("period", dict((p, v) for _, p, v in processor.get_command_reference()))
+
+    def test_renamed_command_uses_new_phrase(self):
+        """Test a renamed command answers only to its new phrase."""
+        processor = CommandProcessor(
+            renamed_commands={"period": "full stop now", "scratch that": "nope"}
+        )
+        self.assertEqual(processor.process_text("the end full stop now")[0], "the end.")
+        self.assertEqual(processor.process_text("a period of time")[0], "a period of time")
+        self.assertEqual(processor.process_text("nope"), ("", ["delete_last"]))
+
+    def test_customize_replaces_earlier_customization(self):
+        """Test customize() starts again from the built-in commands."""
+        processor = CommandProcessor(disabled_commands=["period"])
+        processor.customize(renamed_commands={"comma": "pause here"})
+        self.assertEqual(processor.disabled_commands, [])
+        self.assertEqual(processor.renamed_commands, {"comma": "pause here"})
+        self.assertEqual(processor.process_text("stop period")[0], "stop.")
+        self.assertEqual(processor.process_text("wait pause here")[0], "wait,")
+
+    def test_unknown_phrases_ignored(self):
+        """Test phrases that aren't built-in commands are ignored."""
+        processor = CommandProcessor(
+            disabled_commands=["teleport"], renamed_commands={"warp": "engage"}
+        )
+        self.assertEqual(processor.disabled_commands, [])
+        self.assertEqual(processor.renamed_commands, {})
+        self.assertEqual(processor.process_text("new line")[0], "\n")
+
+    def test_all_mode_commands_disabled(self):
+        """Test turning off every field mode command leaves dictation working."""
+        processor = CommandProcessor(disabled_commands=list(CommandProcessor().mode_commands))
+        self.assertEqual(processor.process_text("hello world comma")[0], "hello world,")
+        self.assertIsNone(processor.field_mode)
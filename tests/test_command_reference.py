@@ -0,0 +1,82 @@
+"""
+Tests for the voice command reference.
+"""
+
+import os
+import tempfile
+import unittest
+
+from vocalinux.speech_recognition.command_processor import CommandProcessor
+from vocalinux.ui.command_reference import (
+    CommandEntry,
+    build_command_reference,
+    export_html,
+    filter_commands,
+    render_html,
+)
+
+
+class TestCommandReference(unittest.TestCase):
+    """Tests for building, filtering and exporting the command reference."""
+
+    def setUp(self):
+        self.processor = CommandProcessor()
+        self.entries = build_command_reference(self.processor)
+
+    def test_includes_every_command(self):
+        expected = (
+            len(self.processor.text_commands)
+            + len(self.processor.action_commands)
+            + len(self.processor.format_commands)
+            + len(self.processor.mode_commands)
+        )
+        self.assertEqual(len(self.entries), expected)
+
+    def test_reflects_processor_changes(self):
+        self.processor.text_commands["smiley face"] = ":)"
+        entries = build_command_reference(self.processor)
+        self.assertIn(CommandEntry("text", "smiley face", "Types :)"), entries)
+
+    def test_grouped_by_category(self):
+        categories = [e.category for e in self.entries]
+        order = ["text", "action", "format", "mode"]
+        self.assertEqual(categories, sorted(categories, key=order.index))
+
+    def test_descriptions(self):
+        by_phrase = {e.phrase: e for e in self.entries}
+        self.assertEqual(by_phrase["new line"].description, "Line break")
+        self.assertEqual(by_phrase["comma"].description, "Types ,")
+        self.assertEqual(by_phrase["undo"].description, "Undo")
+        self.assertEqual(
+            by_phrase["switch to small model"].description, "Switch to the small model"
+        )
+        self.assertEqual(by_phrase["capitalize"].category_title, "Formatting Commands")
+
+    def test_filter_by_phrase_and_description(self):
+        matches = filter_commands(self.entries, "PARENTHESIS")
+        self.assertEqual({e.phrase for e in matches}, {"open parenthesis", "close parenthesis"})
+        self.assertIn("read that back", {e.phrase for e in filter_commands(self.entries, "aloud")})
+
+    def test_empty_filter_returns_all(self):
+        self.assertEqual(filter_commands(self.entries, "  "), self.entries)
+
+    def test_render_html_escapes(self):
+        entries = [CommandEntry("text", "less than", "Types <")]
+        page = render_html(entries)
+        self.assertIn("<h2>Text Commands</h2>", page)
+        self.assertIn("Types &lt;", page)
+        self.assertNotIn("Action Commands", page)
+
+    def test_export_html(self):
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = os.path.join(tmpdir, "commands.html")
+            self.assertTrue(export_html(path, self.entries))
+            with open(path, encoding="utf-8") as f:
+                self.assertIn("delete that", f.read())
+
+    def test_export_html_failure(self):
+        self.assertFalse(export_html("/nonexistent/dir/commands.html", self.entries))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -10,7 +10,12 @@ import unittest
 from unittest.mock import patch
 
 # Update import path to use the new package structure
-from vocalinux.ui.config_manager import DEFAULT_CONFIG, ConfigManager
+from vocalinux.ui.config_manager import (
+    DEFAULT_CONFIG,
+    DEFAULT_CONFIG_COMMENTS,
+    ConfigManager,
+    read_user_config,
+)
 
 
 def _ensure_test_config_dir(path: str):
@@ -32,13 +37,17 @@ class TestConfigManager(unittest.TestCase):
         self.temp_config_dir = os.path.join(self.temp_dir.name, ".config/vocalinux")
         _ensure_test_config_dir(self.temp_config_dir)
         self.temp_config_file = os.path.join(self.temp_config_dir, "config.json")
+        self.temp_toml_file = os.path.join(self.temp_config_dir, "config.toml")
 
         # Patch the config paths to use our temporary directory
         self.config_dir_patcher = patch(
             "vocalinux.ui.config_manager.CONFIG_DIR", self.temp_config_dir
         )
         self.config_file_patcher = patch(
-            "vocalinux.ui.config_manager.CONFIG_FILE", self.temp_config_file
+            "vocalinux.ui.config_manager.CONFIG_FILE", self.temp_toml_file
+        )
+        self.legacy_config_file_patcher = patch(
+            "vocalinux.ui.config_manager.LEGACY_CONFIG_FILE", self.temp_config_file
         )
         self.makedirs_patcher = patch(
             "vocalinux.ui.config_manager.os.makedirs",
@@ -47,6 +56,7 @@ class TestConfigManager(unittest.TestCase):
 
         self.config_dir_patcher.start()
         self.config_file_patcher.start()
+        self.legacy_config_file_patcher.start()
         self.makedirs_patcher.start()
 
         # Recreate after patching so each test starts from a known config path.
@@ -60,6 +70,7 @@ class TestConfigManager(unittest.TestCase):
         """Clean up after tests."""
         self.config_dir_patcher.stop()
         self.config_file_patcher.stop()
+        self.legacy_config_file_patcher.stop()
         self.makedirs_patcher.stop()
         self.logger_patcher.stop()
         self.temp_dir.cleanup()
@@ -68,11 +79,56 @@ class TestConfigManager(unittest.TestCase):
         """Test initialization with default configuration."""
         config_manager = ConfigManager()
         self.assertEqual(config_manager.config, DEFAULT_CONFIG)
-        self.mock_logger.info.assert_called_with(
-            f"Config file not found at {self.temp_config_file}. Using defaults."
+        self.mock_logger.info.assert_any_call(
+            f"Config file not found at {self.temp_toml_file}. Using defaults."
         )
         self.assertTrue(os.path.exists(self.temp_config_dir))
 
+    def test_first_run_writes_commented_defaults(self):
+        """Test the defaults are written to config.toml with their comments."""
+        ConfigManager()
+
+        with open(self.temp_toml_file) as f:
+            text = f.read()
+        self.assertIn("[speech_recognition]", text)
+        self.assertIn("vad_sensitivity = 3  # Voice Activity Detection sensitivity (1-5)", text)
+        # TOML has no null, so unset settings are listed as comments
+        self.assertIn("# device_index = (not set)", text)
+        self.assertEqual(ConfigManager().config, DEFAULT_CONFIG)
+
+    def test_default_comments_name_existing_settings(self):
+        """Test every comment in DEFAULT_CONFIG_COMMENTS belongs to a default setting."""
+        for path in DEFAULT_CONFIG_COMMENTS:
+            table = DEFAULT_CONFIG
+            for key in path[:-1]:
+                table = table[key]
+            self.assertIn(path[-1], table, path)
+            self.assertNotIsInstance(table[path[-1]], dict, path)
+
+    def test_legacy_json_is_converted_once(self):
+        """Test a config.json is loaded, written as config.toml and set aside."""
+        with open(self.temp_config_file, "w") as f:
+            json.dump({"speech_recognition": {"engine": "vosk"}, "audio": {"device_index": 2}}, f)
+
+        config_manager = ConfigManager()
+
+        self.assertEqual(config_manager.config["speech_recognition"]["engine"], "vosk")
+        self.assertFalse(os.path.exists(self.temp_config_file))
+        self.assertTrue(os.path.exists(self.temp_config_file + ".bak"))
+        reloaded = ConfigManager()
+        self.assertEqual(reloaded.config["speech_recognition"]["engine"], "vosk")
+        self.assertEqual(reloaded.config["audio"]["device_index"], 2)
+
+    def test_broken_toml_uses_defaults(self):
+        """Test a malformed config.toml is reported and the defaults are kept."""
+        with open(self.temp_toml_file, "w") as f:
+            f.write("[speech_recognition\nengine = ")
+
+        config_manager = ConfigManager()
+
+        self.assertEqual(config_manager.config, DEFAULT_CONFIG)
+        self.mock_logger.error.assert_called()
+
     def test_ensure_config_dir(self):
         """Test that _ensure_config_dir creates the directory."""
         # Delete the config directory to test creation
@@ -111,6 +167,26 @@ class TestConfigManager(unittest.TestCase):
         self.assertEqual(config_manager.config["ui"]["start_minimized"], True)
         self.assertEqual(config_manager.config["ui"]["show_notifications"], True)  # From defaults
 
+    def test_invalid_settings_reset_individually(self):
+        """Test an invalid setting falls back to its default without losing the others."""
+        test_config = {
+            "speech_recognition": {"engine": "whisper", "vad_sensitivity": 12},
+            "ui": {"start_minimized": True},
+        }
+        with open(self.temp_config_file, "w") as f:
+            json.dump(test_config, f)
+
+        config_manager = ConfigManager()
+
+        self.assertEqual(config_manager.config["speech_recognition"]["vad_sensitivity"], 3)
+        self.assertEqual(config_manager.config["speech_recognition"]["engine"], "whisper")
+        self.assertEqual(config_manager.config["ui"]["start_minimized"], True)
+        self.assertEqual(
+            [problem.path for problem in config_manager.config_problems],
+            ["speech_recognition.vad_sensitivity"],
+        )
+        self.mock_logger.warning.assert_called()
+
     def test_load_config_file_error(self):
         """Test handling of errors when loading config file."""
         # Create a broken config file
@@ -141,9 +217,8 @@ class TestConfigManager(unittest.TestCase):
         self.assertTrue(result)
 
         # Verify file was created with correct content
-        self.assertTrue(os.path.exists(self.temp_config_file))
-        with open(self.temp_config_file, "r") as f:
-            saved_config = json.load(f)
+        self.assertTrue(os.path.exists(self.temp_toml_file))
+        saved_config = read_user_config(self.temp_toml_file)
 
         self.assertEqual(saved_config["speech_recognition"]["engine"], "whisper")
         self.assertEqual(saved_config["ui"]["start_minimized"], True)
@@ -297,6 +372,26 @@ class TestConfigManager(unittest.TestCase):
         # Verify the vosk setting wasn't changed
         self.assertEqual(config_manager.config["speech_recognition"]["vosk_model_size"], "large")
 
+    def test_vad_settings_go_to_engine_override(self):
+        """Test VAD settings change an engine's engine_vad override when it has one."""
+        config_manager = ConfigManager()
+        sr = config_manager.config["speech_recognition"]
+        sr["silence_timeout"] = 2.0
+        sr["engine_vad"] = {"whisper_cpp": {"silence_timeout": 3.0}}
+
+        self.assertEqual(config_manager.get_vad_setting("whisper_cpp", "silence_timeout"), 3.0)
+        self.assertEqual(config_manager.get_vad_setting("vosk", "silence_timeout"), 2.0)
+
+        config_manager.update_speech_recognition_settings(
+            {"engine": "whisper_cpp", "silence_timeout": 4.0, "vad_sensitivity": 2}
+        )
+        self.assertEqual(sr["engine_vad"]["whisper_cpp"]["silence_timeout"], 4.0)
+        self.assertEqual(sr["silence_timeout"], 2.0)
+        self.assertEqual(sr["vad_sensitivity"], 2)
+
+        config_manager.set_vad_setting("vosk", "silence_timeout", 1.0)
+        self.assertEqual(sr["silence_timeout"], 1.0)
+
     def test_save_settings(self):
         """Test the save_settings method (alias for save_config)."""
         config_manager = ConfigManager()
@@ -309,9 +404,8 @@ class TestConfigManager(unittest.TestCase):
         self.assertTrue(result)
 
         # Verify file was saved
-        self.assertTrue(os.path.exists(self.temp_config_file))
-        with open(self.temp_config_file, "r") as f:
-            saved_config = json.load(f)
+        self.assertTrue(os.path.exists(self.temp_toml_file))
+        saved_config = read_user_config(self.temp_toml_file)
         self.assertEqual(saved_config["speech_recognition"]["engine"], "vosk")
 
     def test_get_settings(self):
@@ -416,6 +510,22 @@ class TestConfigManager(unittest.TestCase):
         config_manager = ConfigManager()
         self.assertEqual(config_manager.config["shortcuts"]["toggle_recognition"], "ctrl+ctrl")
 
+    def test_super_tap_key_kept_with_tap_settings(self):
+        test_config = {
+            "shortcuts": {
+                "toggle_recognition": "super+super",
+                "mode": "toggle",
+                "tap_count": 3,
+            }
+        }
+
+        with open(self.temp_config_file, "w") as f:
+            json.dump(test_config, f)
+
+        config_manager = ConfigManager()
+        self.assertEqual(config_manager.config["shortcuts"]["toggle_recognition"], "super+super")
+        self.assertEqual(config_manager.config["shortcuts"]["tap_count"], 3)
+
     def test_non_super_shortcut_unchanged(self):
         test_config = {
             "shortcuts": {
@@ -436,6 +546,13 @@ class TestConfigManager(unittest.TestCase):
         config_manager = ConfigManager()
         self.assertTrue(config_manager.is_sound_effects_enabled())
 
+    def test_utterance_sound_off_and_built_in_sounds_by_default(self):
+        """Test the utterance earcon is opt-in and no custom sounds are set."""
+        sound_effects = ConfigManager().get_settings()["sound_effects"]
+        self.assertFalse(sound_effects["utterance"])
+        for event in ("start", "stop", "utterance", "error"):
+            self.assertEqual(sound_effects[f"{event}_sound"], "")
+
     def test_set_sound_effects_enabled(self):
         """Test setting sound effects enabled state."""
         config_manager = ConfigManager()
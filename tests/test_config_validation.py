@@ -0,0 +1,86 @@
+"""Tests for validating the settings read from the config file."""
+
+import copy
+import unittest
+
+from vocalinux.ui.config_manager import DEFAULT_CONFIG
+from vocalinux.ui.config_validation import validate_config
+
+
+def _config(**sections):
+    config = copy.deepcopy(DEFAULT_CONFIG)
+    for section, values in sections.items():
+        config[section].update(values)
+    return config
+
+
+class TestValidateConfig(unittest.TestCase):
+    """Test each invalid setting is reported and reset on its own."""
+
+    def test_defaults_are_valid(self):
+        self.assertEqual(validate_config(_config(), DEFAULT_CONFIG), [])
+
+    def test_out_of_range_values_reset_per_field(self):
+        config = _config(
+            speech_recognition={"vad_sensitivity": 9, "silence_timeout": 3.5},
+            audio={"sample_rate": 1000, "queue_depth": 64},
+        )
+
+        problems = validate_config(config, DEFAULT_CONFIG)
+
+        self.assertEqual(
+            [problem.path for problem in problems],
+            ["speech_recognition.vad_sensitivity", "audio.sample_rate"],
+        )
+        self.assertIn("9 is not between 1 and 5; using 3", str(problems[0]))
+        self.assertEqual(config["speech_recognition"]["vad_sensitivity"], 3)
+        self.assertIsNone(config["audio"]["sample_rate"])
+        # Valid neighbours are kept
+        self.assertEqual(config["speech_recognition"]["silence_timeout"], 3.5)
+        self.assertEqual(config["audio"]["queue_depth"], 64)
+
+    def test_wrong_types_and_unknown_choices(self):
+        config = _config(
+            speech_recognition={"engine": "deepspeech", "silence_timeout": "long"},
+            ui={"start_minimized": "yes"},
+            shortcuts={"mode": "hold"},
+        )
+        config["audio"] = "usb"
+
+        problems = validate_config(config, DEFAULT_CONFIG)
+
+        self.assertEqual(
+            sorted(problem.path for problem in problems),
+            [
+                "audio",
+                "shortcuts.mode",
+                "speech_recognition.engine",
+                "speech_recognition.silence_timeout",
+                "ui.start_minimized",
+            ],
+        )
+        self.assertEqual(config["speech_recognition"]["engine"], "whisper_cpp")
+        self.assertEqual(config["speech_recognition"]["silence_timeout"], 2.0)
+        self.assertIs(config["ui"]["start_minimized"], False)
+        self.assertEqual(config["audio"], DEFAULT_CONFIG["audio"])
+
+    def test_unsupported_language_for_engine(self):
+        config = _config(speech_recognition={"engine": "vosk", "model_size": "tiny"})
+
+        problems = validate_config(config, DEFAULT_CONFIG)
+
+        self.assertEqual(
+            [problem.path for problem in problems],
+            ["speech_recognition.model_size", "speech_recognition.language"],
+        )
+        self.assertEqual(config["speech_recognition"]["model_size"], "small")
+        self.assertEqual(config["speech_recognition"]["language"], "en-us")
+
+        config = _config(speech_recognition={"model_size": "tiny.en", "language": "de"})
+        problems = validate_config(config, DEFAULT_CONFIG)
+        self.assertEqual([problem.path for problem in problems], ["speech_recognition.language"])
+        self.assertEqual(config["speech_recognition"]["language"], "auto")
+
+
+if __name__ == "__main__":
+    unittest.main()
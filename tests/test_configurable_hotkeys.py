@@ -206,6 +206,17 @@ class TestEvdevComboDetection:
         backend._handle_key_event(self._event(KEY_LEFTCTRL, 1), None)
         assert self._wait(fired)
 
+    def test_hold_to_cancel(self):
+        from vocalinux.ui.keyboard_backends.evdev_backend import KEY_LEFTCTRL
+
+        backend = evdev_backend.EvdevKeyboardBackend(shortcut="ctrl+ctrl", mode="toggle")
+        cancelled = threading.Event()
+        backend.register_cancel_callback(cancelled.set)
+        backend._handle_key_event(self._event(KEY_LEFTCTRL, 1), None)
+        backend._hold_start_time -= 1.5  # Simulate holding the key
+        backend._handle_key_event(self._event(KEY_LEFTCTRL, 0), None)
+        assert self._wait(cancelled)
+
 
 # --------------------------------------------------------------------------
 # pynput combo detection
@@ -0,0 +1,130 @@
+"""
+Tests for per-account API key storage in the keyring.
+"""
+
+import sys
+import threading
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.utils import credentials
+
+
+class PasswordDeleteError(Exception):
+    pass
+
+
+def _fake_keyring():
+    store = {}
+    keyring = MagicMock()
+    keyring.get_password.side_effect = lambda service, name: store.get((service, name))
+    keyring.set_password.side_effect = lambda service, name, value: store.__setitem__(
+        (service, name), value
+    )
+
+    def delete_password(service, name):
+        if (service, name) not in store:
+            raise PasswordDeleteError("not found")
+        del store[(service, name)]
+
+    keyring.delete_password.side_effect = delete_password
+    return keyring, store
+
+
+class TestCredentials(unittest.TestCase):
+    """Tests for the credentials helpers."""
+
+    def setUp(self):
+        self.keyring, self.store = _fake_keyring()
+        patcher = patch.dict(sys.modules, {"keyring": self.keyring})
+        patcher.start()
+        self.addCleanup(patcher.stop)
+
+    def test_accounts_keep_separate_keys(self):
+        self.assertTrue(credentials.set_secret("work", "sk-work"))
+        self.assertTrue(credentials.set_secret("personal", "sk-home"))
+
+        self.assertEqual(credentials.get_secret("work"), "sk-work")
+        self.assertEqual(credentials.get_secret("personal"), "sk-home")
+        self.assertIn(("vocalinux", "work/remote_api_key"), self.store)
+
+    def test_empty_value_deletes_and_missing_delete_is_fine(self):
+        credentials.set_secret("work", "sk-work")
+        self.assertTrue(credentials.set_secret("work", ""))
+        self.assertIsNone(credentials.get_secret("work"))
+        self.assertTrue(credentials.delete_secret("work"))
+
+    def test_resolve_prefers_keyring_over_config(self):
+        credentials.set_secret("work", "sk-work")
+        settings = {"remote_api_account": "work", "remote_api_key": "plain"}
+        self.assertEqual(credentials.resolve_remote_api_key(settings), "sk-work")
+
+        settings["remote_api_account"] = "personal"
+        self.assertEqual(credentials.resolve_remote_api_key(settings), "plain")
+
+    def test_keyring_errors_fall_back(self):
+        self.keyring.get_password.side_effect = RuntimeError("locked")
+        self.keyring.set_password.side_effect = RuntimeError("locked")
+        self.assertIsNone(credentials.get_secret("work"))
+        self.assertFalse(credentials.set_secret("work", "sk-work"))
+        self.assertEqual(credentials.resolve_remote_api_key({"remote_api_key": "plain"}), "plain")
+
+    def test_without_keyring(self):
+        with patch.dict(sys.modules, {"keyring": None}):
+            self.assertFalse(credentials.keyring_available())
+            self.assertFalse(credentials.set_secret("work", "sk-work"))
+            self.assertIsNone(credentials.get_secret("work"))
+
+    def test_account_names(self):
+        self.assertTrue(credentials.is_valid_account_name("work"))
+        self.assertTrue(credentials.is_valid_account_name("me@example.com"))
+        self.assertFalse(credentials.is_valid_account_name(""))
+        self.assertFalse(credentials.is_valid_account_name("a/b"))
+        self.assertFalse(credentials.is_valid_account_name(" padded "))
+
+
+class TestDeferredSecret(unittest.TestCase):
+    """Tests for reading a key from the keyring in the background."""
+
+    def setUp(self):
+        self.keyring, self.store = _fake_keyring()
+        patcher = patch.dict(sys.modules, {"keyring": self.keyring})
+        patcher.start()
+        self.addCleanup(patcher.stop)
+
+    def test_locked_until_the_keyring_answers(self):
+        unlocked = threading.Event()
+        self.store[("vocalinux", "work/remote_api_key")] = "sk-work"
+        read = self.keyring.get_password.side_effect
+
+        def wait_for_unlock(service, name):
+            unlocked.wait(5)
+            return read(service, name)
+
+        self.keyring.get_password.side_effect = wait_for_unlock
+        secret = credentials.DeferredSecret("work")
+
+        self.assertTrue(secret.locked)
+        self.assertIsNone(secret.get(wait=0.01))
+        unlocked.set()
+        self.assertEqual(secret.get(wait=5), "sk-work")
+        self.assertFalse(secret.locked)
+
+    def test_missing_key_reads_as_empty(self):
+        self.assertEqual(credentials.DeferredSecret("personal").get(wait=5), "")
+
+    def test_failed_read_is_retried_on_use(self):
+        self.keyring.get_password.side_effect = RuntimeError("prompt dismissed")
+        secret = credentials.DeferredSecret("work")
+        self.assertIsNone(secret.get(wait=5))
+        self.assertTrue(secret.locked)
+
+        secret._thread.join(5)
+
+        # The next use starts another read, which now succeeds
+        self.keyring.get_password.side_effect = lambda service, name: "sk-work"
+        self.assertEqual(secret.get(wait=5), "sk-work")
+
+
+if __name__ == "__main__":
+    unittest.main()
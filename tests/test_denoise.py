@@ -0,0 +1,113 @@
+"""
+Tests for the RNNoise noise suppression stage.
+"""
+
+import unittest
+from unittest.mock import MagicMock, patch
+
+import numpy as np
+
+from vocalinux.speech_recognition import denoise
+from vocalinux.speech_recognition.denoise import (
+    RNNOISE_FRAME_SAMPLES,
+    RNNoiseDenoiser,
+    create_denoiser,
+    load_rnnoise,
+)
+
+
+def _fake_rnnoise(gain=0.5):
+    """A librnnoise stand-in that scales each frame and records the frames it saw."""
+    library = MagicMock()
+    library.frames = []
+
+    def process_frame(state, out, frame):
+        samples = np.ctypeslib.as_array(frame, shape=(RNNOISE_FRAME_SAMPLES,))
+        library.frames.append(samples.copy())
+        np.ctypeslib.as_array(out, shape=(RNNOISE_FRAME_SAMPLES,))[:] = samples * gain
+        return 0.9
+
+    library.rnnoise_process_frame.side_effect = process_frame
+    return library
+
+
+def _tone(seconds, amplitude=10000, rate=16000):
+    t = np.arange(int(rate * seconds)) / rate
+    return (amplitude * np.sin(2 * np.pi * 440 * t)).astype(np.int16)
+
+
+class TestRNNoiseDenoiser(unittest.TestCase):
+    """Tests for RNNoiseDenoiser."""
+
+    def test_frames_are_48khz_and_carried_over(self):
+        library = _fake_rnnoise()
+        denoiser = RNNoiseDenoiser(library)
+
+        # 100 samples at 16 kHz are ~300 at 48 kHz: not a full frame yet
+        self.assertEqual(denoiser.process(_tone(100 / 16000).tobytes()), b"")
+        self.assertEqual(library.frames, [])
+
+        denoiser.process(_tone(0.02).tobytes())
+        self.assertEqual(len(library.frames), 2)
+        self.assertAlmostEqual(denoiser.speech_probability, 0.9, places=5)
+
+    def test_output_keeps_the_rate_and_applies_the_filter(self):
+        denoiser = RNNoiseDenoiser(_fake_rnnoise(gain=0.5))
+        chunk = _tone(0.064)
+
+        output = b"".join(denoiser.process(chunk.tobytes()) for _ in range(20))
+        samples = np.frombuffer(output, dtype=np.int16)
+
+        # Same duration apart from the carried-over remainder
+        self.assertLess(abs(len(samples) - 20 * len(chunk)), 2 * RNNOISE_FRAME_SAMPLES)
+        steady = samples[len(samples) // 2 :]
+        self.assertAlmostEqual(
+            np.sqrt(np.mean(steady.astype(np.float64) ** 2)) / np.sqrt(np.mean(chunk**2.0)),
+            0.5,
+            delta=0.05,
+        )
+
+    def test_reset_and_close_manage_the_state(self):
+        library = _fake_rnnoise()
+        denoiser = RNNoiseDenoiser(library)
+        denoiser.process(_tone(0.01).tobytes())
+
+        denoiser.reset()
+        self.assertEqual(library.rnnoise_create.call_count, 2)
+        self.assertEqual(library.rnnoise_destroy.call_count, 1)
+
+        denoiser.close()
+        denoiser.close()
+        self.assertEqual(library.rnnoise_destroy.call_count, 2)
+        data = _tone(0.01).tobytes()
+        self.assertEqual(denoiser.process(data), data)
+
+
+class TestLoadRNNoise(unittest.TestCase):
+    """Tests for finding librnnoise."""
+
+    def setUp(self):
+        patcher = patch.multiple(denoise, _library=None, _library_loaded=False)
+        patcher.start()
+        self.addCleanup(patcher.stop)
+
+    def test_missing_library(self):
+        with patch("ctypes.util.find_library", return_value=None):
+            self.assertIsNone(load_rnnoise())
+            self.assertIsNone(create_denoiser())
+        with self.assertRaises(RuntimeError):
+            RNNoiseDenoiser()
+
+    def test_library_is_loaded_once(self):
+        library = _fake_rnnoise()
+        with patch("ctypes.util.find_library", return_value="librnnoise.so.0"), patch(
+            "ctypes.CDLL", return_value=library
+        ) as cdll:
+            self.assertIs(load_rnnoise(), library)
+            self.assertIs(load_rnnoise(), library)
+            self.assertIsInstance(create_denoiser(), RNNoiseDenoiser)
+        cdll.assert_called_once_with("librnnoise.so.0")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,142 @@
+"""
+Tests for audio device hotplug detection.
+"""
+
+import subprocess
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.utils.device_monitor import (
+    DeviceChange,
+    DeviceMonitor,
+    get_default_source,
+    list_input_sources,
+)
+
+BUILT_IN = "alsa_input.pci-0000_00_1f.3.analog-stereo"
+HEADSET = "alsa_input.usb-Logitech_USB_Headset-00.mono-fallback"
+MONITOR = "alsa_output.pci-0000_00_1f.3.analog-stereo.monitor"
+
+
+def _short_sources(*names):
+    return "".join(
+        f"{index}\t{name}\tPipeWire\ts32le 2ch 48000Hz\tSUSPENDED\n"
+        for index, name in enumerate(names, start=50)
+    )
+
+
+def _run(*outputs, returncode=0):
+    """A subprocess.run stand-in returning one `pactl list short sources` output per call."""
+    return MagicMock(
+        side_effect=[
+            subprocess.CompletedProcess(
+                ["pactl"], returncode, stdout=output, stderr="Connection failure"
+            )
+            for output in outputs
+        ]
+    )
+
+
+class TestListInputSources(unittest.TestCase):
+    """Tests for list_input_sources."""
+
+    def test_monitors_are_skipped(self):
+        sources = list_input_sources(_run(_short_sources(MONITOR, BUILT_IN, HEADSET)))
+        self.assertEqual(sources, frozenset({BUILT_IN, HEADSET}))
+
+    def test_pactl_unavailable(self):
+        self.assertIsNone(list_input_sources(_run("", returncode=1)))
+        self.assertIsNone(list_input_sources(MagicMock(side_effect=FileNotFoundError("pactl"))))
+
+
+class TestGetDefaultSource(unittest.TestCase):
+    """Tests for get_default_source."""
+
+    def test_get_default_source(self):
+        self.assertEqual(get_default_source(_run(f"{HEADSET}\n")), HEADSET)
+
+    def test_falls_back_to_pactl_info(self):
+        # pactl before version 15 has no get-default-source
+        run = MagicMock(
+            side_effect=[
+                subprocess.CompletedProcess(["pactl"], 1, stdout="", stderr="No valid command"),
+                subprocess.CompletedProcess(
+                    ["pactl"], 0, stdout=f"Server Name: pulseaudio\nDefault Source: {BUILT_IN}\n"
+                ),
+            ]
+        )
+        self.assertEqual(get_default_source(run), BUILT_IN)
+        self.assertIsNone(get_default_source(MagicMock(side_effect=FileNotFoundError("pactl"))))
+
+
+class TestDeviceMonitor(unittest.TestCase):
+    """Tests for DeviceMonitor."""
+
+    def test_reports_added_and_removed_sources_once(self):
+        run = _run(
+            _short_sources(BUILT_IN, HEADSET),
+            _short_sources(BUILT_IN, HEADSET),
+            _short_sources(BUILT_IN),
+            _short_sources(BUILT_IN, HEADSET),
+        )
+        monitor = DeviceMonitor(run=run)
+        monitor._sources = list_input_sources(run)
+
+        monitor.poll()
+        self.assertFalse(monitor.take_change())
+
+        monitor.poll()
+        change = monitor.take_change()
+        self.assertEqual(change, DeviceChange(removed=frozenset({HEADSET})))
+        self.assertEqual(change.describe(), f"-{HEADSET}")
+        self.assertFalse(monitor.take_change())
+
+        # Both sides are kept until the recording loop looks
+        monitor._removed.add(BUILT_IN)
+        monitor.poll()
+        self.assertEqual(
+            monitor.take_change(),
+            DeviceChange(added=frozenset({HEADSET}), removed=frozenset({BUILT_IN})),
+        )
+
+    def test_reports_a_new_default_source(self):
+        run = _run(
+            _short_sources(BUILT_IN, HEADSET),
+            BUILT_IN,
+            _short_sources(BUILT_IN, HEADSET),
+            BUILT_IN,
+            _short_sources(BUILT_IN, HEADSET),
+            HEADSET,
+        )
+        monitor = DeviceMonitor(run=run)
+        monitor._sources = list_input_sources(run)
+        monitor._default_source = get_default_source(run)
+
+        monitor.poll()
+        self.assertFalse(monitor.take_change())
+
+        monitor.poll()
+        change = monitor.take_change()
+        self.assertEqual(change, DeviceChange(default_source=HEADSET))
+        self.assertEqual(change.describe(), f"default {HEADSET}")
+        self.assertFalse(monitor.take_change())
+
+    def test_start_without_pactl_watches_nothing(self):
+        monitor = DeviceMonitor(run=MagicMock(side_effect=FileNotFoundError("pactl")))
+        self.assertFalse(monitor.start())
+        monitor.poll()
+        self.assertFalse(monitor.take_change())
+        monitor.stop()
+
+    def test_start_and_stop_the_thread(self):
+        monitor = DeviceMonitor(interval=60.0, run=_run(_short_sources(BUILT_IN), BUILT_IN))
+        self.assertTrue(monitor.start())
+        self.assertEqual(monitor._default_source, BUILT_IN)
+        self.assertTrue(monitor._thread.is_alive())
+        thread = monitor._thread
+        monitor.stop()
+        self.assertFalse(thread.is_alive())
+
+
+if __name__ == "__main__":
+    unittest.main()
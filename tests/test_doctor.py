@@ -0,0 +1,196 @@
+"""
+Tests for the doctor health checks.
+"""
+
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux import doctor
+from vocalinux.doctor import HealthCheck
+
+
+class TestTextInjectionCheck(unittest.TestCase):
+    """Test cases for the text injection check."""
+
+    @patch.dict("os.environ", {"XDG_SESSION_TYPE": "x11"}, clear=True)
+    @patch("vocalinux.doctor.shutil.which", return_value="/usr/bin/xdotool")
+    def test_x11_with_xdotool(self, mock_which):
+        result = doctor.check_text_injection()
+        self.assertTrue(result.ok)
+        self.assertIn("xdotool", result.message)
+
+    @patch.dict("os.environ", {"XDG_SESSION_TYPE": "wayland"}, clear=True)
+    @patch("vocalinux.doctor.shutil.which", return_value=None)
+    def test_wayland_without_tools(self, mock_which):
+        result = doctor.check_text_injection()
+        self.assertFalse(result.ok)
+        self.assertIn("ydotool", result.hint)
+
+
+class TestKeyringCheck(unittest.TestCase):
+    """Test cases for the keyring check."""
+
+    @patch("vocalinux.utils.credentials.get_secret", return_value=None)
+    def test_no_api_key_is_ok(self, mock_get_secret):
+        self.assertTrue(doctor.check_keyring({}).ok)
+
+    @patch("vocalinux.utils.credentials.get_secret", return_value="secret")
+    def test_key_in_keyring_is_ok(self, mock_get_secret):
+        result = doctor.check_keyring({"remote_api_account": "work"})
+        self.assertTrue(result.ok)
+        self.assertIn("keyring", result.message)
+        mock_get_secret.assert_called_once_with("work")
+
+    @patch("vocalinux.utils.credentials.keyring_available", return_value=False)
+    @patch("vocalinux.utils.credentials.get_secret", return_value=None)
+    def test_missing_keyring_with_api_key_is_limitation(self, mock_get_secret, mock_available):
+        result = doctor.check_keyring({"remote_api_key": "secret"})
+        self.assertFalse(result.ok)
+        self.assertIn("unencrypted", result.message)
+        self.assertIn("pip install keyring", result.hint)
+
+    @patch("vocalinux.utils.credentials.keyring_available", return_value=True)
+    @patch("vocalinux.utils.credentials.get_secret", return_value=None)
+    def test_plain_text_key_with_keyring_is_limitation(self, mock_get_secret, mock_available):
+        result = doctor.check_keyring({"remote_api_key": "secret"})
+        self.assertFalse(result.ok)
+        self.assertIn("move it into the keyring", result.hint)
+
+
+class TestMicrophoneCheck(unittest.TestCase):
+    """Test cases for the microphone check."""
+
+    DEVICES = [
+        {"index": 0, "name": "Built-in", "is_default": True, "rates": [48000]},
+        {"index": 2, "name": "Float Only", "is_default": False, "rates": []},
+    ]
+
+    @patch(
+        "vocalinux.speech_recognition.recognition_manager.get_input_device_capabilities",
+        return_value=DEVICES,
+    )
+    def test_default_device(self, mock_capabilities):
+        result = doctor.check_microphone(None)
+        self.assertTrue(result.ok)
+        self.assertIn("Built-in", result.message)
+
+    @patch(
+        "vocalinux.speech_recognition.recognition_manager.get_input_device_capabilities",
+        return_value=DEVICES,
+    )
+    def test_device_without_rates_is_limitation(self, mock_capabilities):
+        result = doctor.check_microphone(2)
+        self.assertFalse(result.ok)
+        self.assertIn("Float Only", result.message)
+
+    @patch(
+        "vocalinux.speech_recognition.recognition_manager.get_input_device_capabilities",
+        return_value=DEVICES,
+    )
+    def test_missing_device_is_limitation(self, mock_capabilities):
+        self.assertFalse(doctor.check_microphone(7).ok)
+
+    @patch("vocalinux.speech_recognition.recognition_manager.get_input_device_capabilities")
+    def test_opened_device_is_not_probed_again(self, mock_capabilities):
+        engine = MagicMock()
+        engine.capture_format.describe.return_value = "48000Hz, 2 ch, float32"
+
+        result = doctor.check_microphone(None, engine)
+
+        self.assertTrue(result.ok)
+        self.assertIn("float32", result.message)
+        mock_capabilities.assert_not_called()
+
+
+class TestGpuCheck(unittest.TestCase):
+    """Test cases for the GPU check."""
+
+    def test_vosk_does_not_need_gpu(self):
+        self.assertTrue(doctor.check_gpu("vosk").ok)
+
+    @patch("vocalinux.doctor.os.listdir", return_value=["libggml-vulkan.so"])
+    @patch(
+        "vocalinux.speech_recognition.recognition_manager._find_pywhispercpp_shared_library_dirs",
+        return_value=["/lib"],
+    )
+    def test_whisper_cpp_with_vulkan(self, mock_dirs, mock_listdir):
+        result = doctor.check_gpu("whisper_cpp")
+        self.assertTrue(result.ok)
+        self.assertIn("VULKAN", result.message)
+
+    @patch("vocalinux.doctor.os.listdir", return_value=["libggml-cpu.so"])
+    @patch(
+        "vocalinux.speech_recognition.recognition_manager._find_pywhispercpp_shared_library_dirs",
+        return_value=["/lib"],
+    )
+    def test_whisper_cpp_cpu_only(self, mock_dirs, mock_listdir):
+        result = doctor.check_gpu("whisper_cpp")
+        self.assertFalse(result.ok)
+        self.assertIn("--rebuild-whispercpp", result.hint)
+
+
+class TestHealthReport(unittest.TestCase):
+    """Test cases for running checks and formatting the report."""
+
+    def test_failing_check_does_not_raise(self):
+        def broken():
+            raise RuntimeError("boom")
+
+        result = doctor._safe_check("Broken", broken)
+        self.assertTrue(result.ok)
+        self.assertIn("boom", result.message)
+
+    def test_run_health_checks_reads_config(self):
+        config = MagicMock()
+        config.get.side_effect = lambda section, key, default=None: {
+            "engine": "vosk",
+            "device_index": 3,
+        }.get(key, default)
+        config.get_settings.return_value = {"speech_recognition": {"remote_api_key": ""}}
+        engine = MagicMock()
+
+        with patch.object(doctor, "check_microphone") as mock_mic, patch.object(
+            doctor, "check_text_injection"
+        ), patch.object(doctor, "check_keyring") as mock_keyring:
+            mock_mic.return_value = HealthCheck("Microphone", True, "ok")
+            mock_keyring.return_value = HealthCheck("Keyring", True, "ok")
+            results = doctor.run_health_checks(config, engine)
+
+        mock_mic.assert_called_once_with(3, engine)
+        mock_keyring.assert_called_once_with({"remote_api_key": ""})
+        self.assertEqual(len(results), 5)
+
+    def test_invalid_settings_are_a_limitation(self):
+        self.assertTrue(doctor.check_config([]).ok)
+
+        result = doctor.check_config(["audio.queue_depth: 0 is not between 4 and 256; using 32"])
+        self.assertFalse(result.ok)
+        self.assertIn("1 invalid setting(s)", result.message)
+        self.assertIn("audio.queue_depth", result.message)
+
+    def test_format_report(self):
+        results = [
+            HealthCheck("Keyring", True, "available"),
+            HealthCheck("Microphone", False, "No device", "Connect a microphone"),
+        ]
+        report = doctor.format_report(results)
+        self.assertIn("Fix: Connect a microphone", report)
+        self.assertIn("1 feature(s) running with limitations.", report)
+        self.assertEqual(doctor.get_limitations(results), [results[1]])
+
+    def test_main_exit_code(self):
+        healthy = [HealthCheck("Keyring", True, "available")]
+        degraded = [HealthCheck("Microphone", False, "No device")]
+
+        with patch.object(doctor, "run_health_checks", return_value=healthy), patch(
+            "builtins.print"
+        ):
+            self.assertEqual(doctor.main([]), 0)
+        with patch.object(doctor, "run_health_checks", return_value=degraded), patch(
+            "builtins.print"
+        ):
+            self.assertEqual(doctor.main([]), 1)
+
+
+if __name__ == "__main__":
+    unittest.main()
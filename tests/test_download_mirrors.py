@@ -0,0 +1,70 @@
+"""
+Tests for model download mirrors and bandwidth limiting.
+"""
+
+import unittest
+
+from vocalinux.utils.download_mirrors import BandwidthLimiter, mirrored_url
+
+HF_URL = "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin?download=true"
+VOSK_URL = "https://alphacephei.com/vosk/models/vosk-model-small-en-us-0.15.zip"
+
+
+class TestMirroredUrl(unittest.TestCase):
+    """Tests for mirrored_url."""
+
+    def test_official_urls_are_unchanged(self):
+        self.assertEqual(mirrored_url(HF_URL), HF_URL)
+        self.assertEqual(mirrored_url(VOSK_URL, "hf-mirror"), VOSK_URL)
+        self.assertEqual(mirrored_url(HF_URL, "unknown"), HF_URL)
+
+    def test_hf_mirror(self):
+        self.assertEqual(
+            mirrored_url(HF_URL, "hf-mirror"),
+            "https://hf-mirror.com/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin?download=true",
+        )
+
+    def test_vosk_mirror(self):
+        self.assertEqual(
+            mirrored_url(VOSK_URL, vosk_mirror_url="http://nas.local/vosk/"),
+            "http://nas.local/vosk/vosk-model-small-en-us-0.15.zip",
+        )
+        self.assertEqual(mirrored_url(HF_URL, vosk_mirror_url="http://nas.local/vosk"), HF_URL)
+
+
+class TestBandwidthLimiter(unittest.TestCase):
+    """Tests for BandwidthLimiter."""
+
+    def setUp(self):
+        self.now = 0.0
+        self.sleeps = []
+
+    def _clock(self):
+        return self.now
+
+    def _sleep(self, seconds):
+        self.sleeps.append(seconds)
+        self.now += seconds
+
+    def test_unlimited_never_sleeps(self):
+        limiter = BandwidthLimiter(0, self._clock, self._sleep)
+        limiter.throttle(10**9)
+        self.assertEqual(self.sleeps, [])
+
+    def test_holds_the_average_speed(self):
+        limiter = BandwidthLimiter(100, self._clock, self._sleep)
+        limiter.throttle(51200)  # Half a second's worth, received instantly
+        self.assertEqual(self.sleeps, [0.5])
+
+        # A slow chunk doesn't need a pause
+        self.now += 2.0
+        limiter.throttle(51200)
+        self.assertEqual(self.sleeps, [0.5])
+
+        # Time lost to the slow chunk is made up, keeping the overall average
+        limiter.throttle(307200)
+        self.assertAlmostEqual(self.now, 4.0)
+
+
+if __name__ == "__main__":
+    unittest.main()
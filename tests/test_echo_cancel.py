@@ -0,0 +1,107 @@
+"""
+Tests for echo cancellation.
+"""
+
+import os
+import subprocess
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.utils.echo_cancel import (
+    EC_SINK_NAME,
+    EC_SOURCE_NAME,
+    EchoCanceller,
+    pulse_source,
+)
+
+
+class FakePactl:
+    """Stands in for subprocess.run, recording pactl invocations."""
+
+    def __init__(self, load_result="536870913", default_sink="alsa_output.speakers"):
+        self.load_result = load_result
+        self.default_sink = default_sink
+        self.calls = []
+
+    def __call__(self, args, **kwargs):
+        command = args[1:]
+        self.calls.append(command)
+        if command[0] == "get-default-sink":
+            return subprocess.CompletedProcess(args, 0, stdout=f"{self.default_sink}\n")
+        if command[0] == "set-default-sink":
+            self.default_sink = command[1]
+        if command[0] == "load-module" and self.load_result is None:
+            return subprocess.CompletedProcess(args, 1, stdout="", stderr="Failure")
+        stdout = f"{self.load_result}\n" if command[0] == "load-module" else ""
+        return subprocess.CompletedProcess(args, 0, stdout=stdout, stderr="")
+
+
+class TestEchoCanceller(unittest.TestCase):
+    """Tests for EchoCanceller."""
+
+    def test_start_loads_module_and_routes_playback(self):
+        pactl = FakePactl()
+        canceller = EchoCanceller(run=pactl)
+
+        self.assertEqual(canceller.start(), EC_SOURCE_NAME)
+        self.assertTrue(canceller.active)
+        load = next(call for call in pactl.calls if call[0] == "load-module")
+        self.assertIn("module-echo-cancel", load)
+        self.assertEqual(pactl.default_sink, EC_SINK_NAME)
+
+        # Starting again reuses the loaded module
+        canceller.start()
+        self.assertEqual(sum(call[0] == "load-module" for call in pactl.calls), 1)
+
+    def test_stop_restores_sink_and_unloads(self):
+        pactl = FakePactl()
+        canceller = EchoCanceller(run=pactl)
+        canceller.start()
+
+        canceller.stop()
+        self.assertFalse(canceller.active)
+        self.assertEqual(pactl.default_sink, "alsa_output.speakers")
+        self.assertEqual(pactl.calls[-1], ["unload-module", "536870913"])
+
+    def test_stop_keeps_sink_the_user_picked(self):
+        pactl = FakePactl()
+        canceller = EchoCanceller(run=pactl)
+        canceller.start()
+        pactl.default_sink = "bluez_output.headphones"
+
+        canceller.stop()
+        self.assertEqual(pactl.default_sink, "bluez_output.headphones")
+
+    def test_load_failure(self):
+        canceller = EchoCanceller(run=FakePactl(load_result=None))
+        self.assertIsNone(canceller.start())
+        self.assertFalse(canceller.active)
+
+    def test_missing_pactl(self):
+        canceller = EchoCanceller(run=MagicMock(side_effect=FileNotFoundError("pactl")))
+        self.assertIsNone(canceller.start())
+        canceller.stop()
+
+
+class TestPulseSource(unittest.TestCase):
+    """Tests for the pulse_source context manager."""
+
+    def test_sets_and_restores_environment(self):
+        with patch.dict(os.environ, {"PULSE_SOURCE": "mic"}):
+            with pulse_source(EC_SOURCE_NAME):
+                self.assertEqual(os.environ["PULSE_SOURCE"], EC_SOURCE_NAME)
+            self.assertEqual(os.environ["PULSE_SOURCE"], "mic")
+
+        with patch.dict(os.environ, {}, clear=True):
+            with pulse_source(EC_SOURCE_NAME):
+                pass
+            self.assertNotIn("PULSE_SOURCE", os.environ)
+
+    def test_none_leaves_environment(self):
+        with patch.dict(os.environ, {}, clear=True):
+            with pulse_source(None):
+                self.assertNotIn("PULSE_SOURCE", os.environ)
+
+
+if __name__ == "__main__":
+    unittest.main()
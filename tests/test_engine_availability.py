@@ -0,0 +1,64 @@
+"""
+Tests for speech engine availability detection.
+"""
+
+import unittest
+from unittest.mock import patch
+
+from vocalinux.speech_recognition.engine_availability import (
+    EngineNotInstalledError,
+    get_available_engines,
+    is_engine_available,
+    pick_available_engine,
+)
+
+
+def fake_import(installed):
+    """An import_module replacement that only knows the given modules."""
+
+    def import_module(name):
+        if name not in installed:
+            raise ImportError(f"No module named {name!r}")
+        return object()
+
+    return import_module
+
+
+class TestEngineAvailability(unittest.TestCase):
+    """Tests for engine detection."""
+
+    def test_detects_installed_engines(self):
+        with patch("importlib.import_module", fake_import({"vosk", "requests"})):
+            self.assertEqual(
+                get_available_engines(),
+                {"vosk": True, "whisper": False, "whisper_cpp": False, "remote_api": True},
+            )
+
+    def test_broken_install_counts_as_missing(self):
+        with patch("importlib.import_module", side_effect=OSError("libwhisper.so missing")):
+            self.assertFalse(is_engine_available("whisper_cpp"))
+
+    def test_unknown_engine_unavailable(self):
+        self.assertFalse(is_engine_available("soniox"))
+
+    def test_pick_available_engine_skips_excluded_and_remote(self):
+        with patch("importlib.import_module", fake_import({"pywhispercpp.model", "vosk"})):
+            self.assertEqual(pick_available_engine(), "whisper_cpp")
+            self.assertEqual(pick_available_engine(exclude=["whisper_cpp"]), "vosk")
+        with patch("importlib.import_module", fake_import({"requests"})):
+            self.assertIsNone(pick_available_engine())
+
+
+class TestEngineNotInstalledError(unittest.TestCase):
+    """Tests for EngineNotInstalledError."""
+
+    def test_message_includes_install_hint(self):
+        error = EngineNotInstalledError("vosk", "No module named 'vosk'")
+        self.assertIsInstance(error, ImportError)
+        self.assertEqual(error.engine, "vosk")
+        self.assertIn("pip install vosk", str(error))
+        self.assertIn("No module named 'vosk'", str(error))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,38 @@
+"""
+Tests for engine comparison mode.
+"""
+
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.speech_recognition.engine_comparison import EngineTranscript, UtteranceComparison
+
+
+class TestUtteranceComparison(unittest.TestCase):
+    """Tests for UtteranceComparison."""
+
+    def test_reports_once_both_transcripts_arrive(self):
+        on_complete = MagicMock()
+        comparison = UtteranceComparison(on_complete)
+        primary = EngineTranscript("vosk", "hello", 0.1)
+        other = EngineTranscript("whisper_cpp", "Hello.", 0.9)
+
+        comparison.set_comparison(other)
+        on_complete.assert_not_called()
+        comparison.set_primary(primary)
+        on_complete.assert_called_once_with(primary, other)
+
+        # A late duplicate does not report again
+        comparison.set_primary(primary)
+        on_complete.assert_called_once()
+
+    def test_describe(self):
+        self.assertEqual(EngineTranscript("vosk", " hi ", 0.25).describe(), "hi [0.25s]")
+        self.assertEqual(
+            EngineTranscript("vosk", "", 0.0).describe(), "(nothing recognized) [0.00s]"
+        )
+        self.assertEqual(EngineTranscript("vosk", None, 0.0).describe(), "(not available) [0.00s]")
+
+
+if __name__ == "__main__":
+    unittest.main()
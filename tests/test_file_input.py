@@ -0,0 +1,134 @@
+"""
+Tests for replaying a WAV file in place of the microphone.
+"""
+
+import os
+import struct
+import sys
+import tempfile
+import unittest
+import wave
+from unittest.mock import MagicMock, patch
+
+from vocalinux.speech_recognition.file_input import FileAudio, WavFileStream
+
+
+def _write_wav(path, samples, rate=16000, channels=1):
+    with wave.open(path, "wb") as wav_file:
+        wav_file.setnchannels(channels)
+        wav_file.setsampwidth(2)
+        wav_file.setframerate(rate)
+        wav_file.writeframes(struct.pack(f"<{len(samples)}h", *samples))
+
+
+class _FakeClock:
+    def __init__(self):
+        self.now = 0.0
+        self.slept = []
+
+    def __call__(self):
+        return self.now
+
+    def sleep(self, seconds):
+        self.slept.append(seconds)
+        self.now += seconds
+
+
+class TestWavFileStream(unittest.TestCase):
+    """Tests for reading a WAV file like an input stream."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.temp_dir.name, "take.wav")
+
+    def tearDown(self):
+        self.temp_dir.cleanup()
+
+    def test_file_then_silent_tail_then_finished(self):
+        _write_wav(self.path, [1, 2, 3], rate=10)
+        stream = WavFileStream(self.path, speed=0, tail_seconds=0.4)
+        self.assertEqual(stream.read(2), struct.pack("<2h", 1, 2))
+        self.assertEqual(stream.read(2), struct.pack("<2h", 3, 0))
+        self.assertFalse(stream.finished)
+        self.assertEqual(stream.read(4), b"\x00" * 6)
+        self.assertTrue(stream.finished)
+        with self.assertRaises(EOFError):
+            stream.read(2)
+        self.assertTrue(stream.is_active())
+        stream.close()
+        self.assertFalse(stream.is_active())
+
+    def test_paced_at_speed(self):
+        _write_wav(self.path, [0] * 1600)
+        clock = _FakeClock()
+        stream = WavFileStream(self.path, speed=2.0, clock=clock, sleep=clock.sleep)
+        stream.read(800)
+        self.assertAlmostEqual(clock.now, 0.025)
+        stream.read(800)
+        self.assertAlmostEqual(clock.now, 0.05)
+        stream.close()
+
+    def test_unpaced_at_speed_zero(self):
+        _write_wav(self.path, [0] * 1600)
+        clock = _FakeClock()
+        stream = WavFileStream(self.path, speed=0, clock=clock, sleep=clock.sleep)
+        stream.read(1600)
+        self.assertEqual(clock.slept, [])
+        stream.close()
+
+
+class TestFileAudio(unittest.TestCase):
+    """Tests for the PyAudio stand-in."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.temp_dir.name, "take.wav")
+        _write_wav(self.path, [0] * 4800, rate=48000, channels=2)
+        self.pyaudio = MagicMock()
+        self.pyaudio.get_format_from_width.side_effect = lambda width: f"int{width * 8}"
+        self.modules = patch.dict(sys.modules, {"pyaudio": self.pyaudio})
+        self.modules.start()
+
+    def tearDown(self):
+        self.modules.stop()
+        self.temp_dir.cleanup()
+
+    def test_single_device_in_file_format(self):
+        audio = FileAudio(self.path)
+        self.assertEqual(audio.get_device_count(), 1)
+        info = audio.get_default_input_device_info()
+        self.assertEqual(info["name"], "File: take.wav")
+        self.assertEqual(info["maxInputChannels"], 2)
+        self.assertEqual(info["defaultSampleRate"], 48000)
+        self.assertEqual(audio.sample_format, "int16")
+        self.assertAlmostEqual(audio.duration, 0.05)
+        with self.assertRaises(OSError):
+            audio.get_device_info_by_index(1)
+
+    def test_opens_only_in_file_format(self):
+        audio = FileAudio(self.path)
+        with self.assertRaises(OSError):
+            audio.open(format="int16", channels=1, rate=48000, input=True)
+        with self.assertRaises(OSError):
+            audio.open(format="int16", channels=2, rate=16000, input=True)
+        with self.assertRaises(OSError):
+            audio.open(format="int16", channels=2, rate=48000, output=True)
+        stream = audio.open(format="int16", channels=2, rate=48000, input=True)
+        self.assertFalse(audio.finished)
+        stream.speed = 0
+        while not stream.finished:
+            stream.read(1024)
+        self.assertTrue(audio.finished)
+        stream.close()
+
+    def test_not_a_wav_file(self):
+        with open(self.path, "wb") as f:
+            f.write(b"not audio")
+        with self.assertRaises(ValueError):
+            FileAudio(self.path)
+        with self.assertRaises(OSError):
+            FileAudio(os.path.join(self.temp_dir.name, "missing.wav"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,137 @@
+"""
+Tests for the dictation focus monitor.
+"""
+
+import subprocess
+import threading
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.text_injection.focus_monitor import FocusMonitor
+from vocalinux.text_injection.text_injector import DesktopEnvironment, TextInjector
+
+
+class TestFocusMonitor(unittest.TestCase):
+    """Tests for FocusMonitor."""
+
+    def test_start_fails_without_active_window(self):
+        monitor = FocusMonitor(get_window_id=lambda: None, on_focus_lost=MagicMock())
+        self.assertFalse(monitor.start())
+        self.assertFalse(monitor.active)
+
+    def test_check_focus_same_window(self):
+        monitor = FocusMonitor(get_window_id=lambda: "42", on_focus_lost=MagicMock())
+        monitor._target_window = "42"
+        self.assertTrue(monitor.check_focus())
+
+    def test_check_focus_other_window(self):
+        window_ids = iter(["42", "99"])
+        monitor = FocusMonitor(get_window_id=lambda: next(window_ids), on_focus_lost=MagicMock())
+        monitor._target_window = next(window_ids)
+        self.assertFalse(monitor.check_focus())
+
+    def test_check_focus_unknown_window_is_ignored(self):
+        monitor = FocusMonitor(get_window_id=lambda: None, on_focus_lost=MagicMock())
+        monitor._target_window = "42"
+        self.assertTrue(monitor.check_focus())
+
+    def test_focus_change_triggers_callback_once(self):
+        window_ids = iter(["42", "42", "99", "99"])
+        lost = threading.Event()
+        callback = MagicMock(side_effect=lambda: lost.set())
+        monitor = FocusMonitor(
+            get_window_id=lambda: next(window_ids, "99"),
+            on_focus_lost=callback,
+            poll_interval=0.01,
+        )
+
+        self.assertTrue(monitor.start())
+        self.assertEqual(monitor.target_window, "42")
+        self.assertTrue(lost.wait(timeout=2))
+        monitor._thread.join(timeout=1)
+
+        callback.assert_called_once()
+        self.assertFalse(monitor.active)
+
+    def test_stop_clears_target(self):
+        monitor = FocusMonitor(
+            get_window_id=lambda: "42", on_focus_lost=MagicMock(), poll_interval=0.01
+        )
+        monitor.start()
+        monitor.stop()
+        self.assertFalse(monitor.active)
+        self.assertIsNone(monitor.target_window)
+
+    def test_callback_errors_are_contained(self):
+        window_ids = iter(["42"])
+        done = threading.Event()
+
+        def failing_callback():
+            done.set()
+            raise RuntimeError("boom")
+
+        monitor = FocusMonitor(
+            get_window_id=lambda: next(window_ids, "99"),
+            on_focus_lost=failing_callback,
+            poll_interval=0.01,
+        )
+        monitor.start()
+        self.assertTrue(done.wait(timeout=2))
+        monitor._thread.join(timeout=1)
+        self.assertFalse(monitor.active)
+
+
+class TestGetActiveWindowId(unittest.TestCase):
+    """Tests for TextInjector.get_active_window_id."""
+
+    def _make_injector(self, environment):
+        injector = TextInjector.__new__(TextInjector)
+        injector.environment = environment
+        return injector
+
+    def test_pure_wayland_returns_none(self):
+        injector = self._make_injector(DesktopEnvironment.WAYLAND)
+        self.assertIsNone(injector.get_active_window_id())
+
+    def test_x11_uses_xdotool(self):
+        injector = self._make_injector(DesktopEnvironment.X11)
+        result = MagicMock(stdout="12345\n")
+        with patch(
+            "vocalinux.text_injection.text_injector.shutil.which", return_value="/usr/bin/xdotool"
+        ), patch(
+            "vocalinux.text_injection.text_injector.subprocess.run", return_value=result
+        ) as mock_run:
+            self.assertEqual(injector.get_active_window_id(), "12345")
+        self.assertEqual(mock_run.call_args[0][0], ["xdotool", "getactivewindow"])
+
+    def test_xdotool_failure_returns_none(self):
+        injector = self._make_injector(DesktopEnvironment.X11)
+        with patch(
+            "vocalinux.text_injection.text_injector.shutil.which", return_value="/usr/bin/xdotool"
+        ), patch(
+            "vocalinux.text_injection.text_injector.subprocess.run",
+            side_effect=subprocess.CalledProcessError(1, "xdotool"),
+        ):
+            self.assertIsNone(injector.get_active_window_id())
+
+    def test_missing_xdotool_returns_none(self):
+        injector = self._make_injector(DesktopEnvironment.X11)
+        with patch("vocalinux.text_injection.text_injector.shutil.which", return_value=None):
+            self.assertIsNone(injector.get_active_window_id())
+
+    def test_active_window_class(self):
+        injector = self._make_injector(DesktopEnvironment.X11)
+        results = [MagicMock(stdout="12345\n"), MagicMock(stdout="Thunderbird\n")]
+        with patch(
+            "vocalinux.text_injection.text_injector.shutil.which", return_value="/usr/bin/xdotool"
+        ), patch(
+            "vocalinux.text_injection.text_injector.subprocess.run", side_effect=results
+        ) as mock_run:
+            self.assertEqual(injector.get_active_window_class(), "Thunderbird")
+        self.assertEqual(mock_run.call_args[0][0], ["xdotool", "getwindowclassname", "12345"])
+
+        self.assertIsNone(self._make_injector(DesktopEnvironment.WAYLAND).get_active_window_class())
+
+
+if __name__ == "__main__":
+    unittest.main()
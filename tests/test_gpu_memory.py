@@ -0,0 +1,56 @@
+"""Tests for the GPU memory budget of speech models."""
+
+import subprocess
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.speech_recognition.gpu_memory import (
+    WHISPER_VRAM_MB,
+    fit_model_to_budget,
+    total_vram_mb,
+    vram_budget_mb,
+    whispercpp_vram_mb,
+)
+
+SIZES = list(WHISPER_VRAM_MB)
+
+
+class TestGpuMemory(unittest.TestCase):
+    """Test choosing a model within the VRAM budget."""
+
+    def test_budget_is_total_minus_reserve(self):
+        self.assertEqual(vram_budget_mb(4096, 12288), 8192)
+        self.assertEqual(vram_budget_mb(16384, 12288), 0)
+        self.assertIsNone(vram_budget_mb(0, 12288))
+        self.assertIsNone(vram_budget_mb(4096, None))
+
+    def test_requested_model_kept_when_it_fits(self):
+        self.assertEqual(fit_model_to_budget("medium", SIZES, WHISPER_VRAM_MB.get, 8192), "medium")
+
+    def test_largest_smaller_model_that_fits(self):
+        self.assertEqual(fit_model_to_budget("large", SIZES, WHISPER_VRAM_MB.get, 6000), "medium")
+        self.assertEqual(fit_model_to_budget("large", SIZES, WHISPER_VRAM_MB.get, 1500), "base")
+
+    def test_nothing_fits(self):
+        self.assertIsNone(fit_model_to_budget("small", SIZES, WHISPER_VRAM_MB.get, 500))
+
+    def test_whispercpp_estimate(self):
+        # whisper.cpp README: the 1.5 GB medium model uses about 2.1 GB
+        self.assertEqual(whispercpp_vram_mb(1500), 2150)
+
+    def test_total_vram_from_torch(self):
+        torch = MagicMock()
+        torch.cuda.is_available.return_value = True
+        torch.cuda.get_device_properties.return_value.total_memory = 8 * 1024**3
+        self.assertEqual(total_vram_mb(torch), 8192)
+
+    def test_total_vram_from_nvidia_smi(self):
+        result = subprocess.CompletedProcess([], 0, stdout="12288\n", stderr="")
+        with patch("subprocess.run", return_value=result):
+            self.assertEqual(total_vram_mb(), 12288)
+        with patch("subprocess.run", side_effect=FileNotFoundError("nvidia-smi")):
+            self.assertIsNone(total_vram_mb())
+
+
+if __name__ == "__main__":
+    unittest.main()
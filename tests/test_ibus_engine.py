@@ -137,12 +137,15 @@ class TestIBusEngineHelpers(unittest.TestCase):
 
     def test_ensure_ibus_dir_creates_directory(self):
         """Test that ensure_ibus_dir creates the directory."""
-        with patch("vocalinux.text_injection.ibus_engine.VOCALINUX_IBUS_DIR") as mock_dir:
-            mock_dir.mkdir = MagicMock()
+        with tempfile.TemporaryDirectory() as tmp, patch(
+            "vocalinux.text_injection.ibus_engine.VOCALINUX_IBUS_DIR",
+            Path(tmp) / "vocalinux-ibus",
+        ):
             from vocalinux.text_injection.ibus_engine import ensure_ibus_dir
 
             ensure_ibus_dir()
-            mock_dir.mkdir.assert_called_once_with(parents=True, exist_ok=True)
+            self.assertTrue((Path(tmp) / "vocalinux-ibus").is_dir())
+            self.assertEqual((Path(tmp) / "vocalinux-ibus").stat().st_mode & 0o777, 0o700)
 
     def test_is_ibus_available_returns_constant(self):
         """Test is_ibus_available returns the module constant."""
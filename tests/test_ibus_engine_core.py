@@ -258,12 +258,11 @@ class TestIBusEngineModuleFunctions(unittest.TestCase):
 
     def test_ensure_ibus_dir(self):
         """Test directory creation and permissions."""
-        with patch("pathlib.Path.mkdir") as mock_mkdir, patch("pathlib.Path.chmod") as mock_chmod:
-            from vocalinux.text_injection.ibus_engine import ensure_ibus_dir
+        with patch("vocalinux.text_injection.ibus_engine.ensure_private_dir") as mock_ensure:
+            from vocalinux.text_injection.ibus_engine import VOCALINUX_IBUS_DIR, ensure_ibus_dir
 
             ensure_ibus_dir()
-            mock_mkdir.assert_called_once_with(parents=True, exist_ok=True)
-            mock_chmod.assert_called_once_with(0o700)
+            mock_ensure.assert_called_once_with(str(VOCALINUX_IBUS_DIR))
 
     def test_verify_peer_credentials_same_user(self):
         """Test peer credential verification for same user."""
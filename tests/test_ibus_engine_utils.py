@@ -55,16 +55,25 @@ class TestIBusSetupError(unittest.TestCase):
 class TestEnsureIBusDir(unittest.TestCase):
     """Tests for ensure_ibus_dir function."""
 
-    @patch("pathlib.Path.mkdir")
-    @patch("pathlib.Path.chmod")
-    def test_ensure_ibus_dir_creates_and_secures(self, mock_chmod, mock_mkdir):
+    @patch("vocalinux.text_injection.ibus_engine.ensure_private_dir")
+    def test_ensure_ibus_dir_creates_and_secures(self, mock_ensure_private_dir):
         """Test that ensure_ibus_dir creates directory with proper permissions."""
-        from vocalinux.text_injection.ibus_engine import ensure_ibus_dir
+        from vocalinux.text_injection.ibus_engine import VOCALINUX_IBUS_DIR, ensure_ibus_dir
 
         ensure_ibus_dir()
 
-        mock_mkdir.assert_called_once_with(parents=True, exist_ok=True)
-        mock_chmod.assert_called_once_with(0o700)
+        mock_ensure_private_dir.assert_called_once_with(str(VOCALINUX_IBUS_DIR))
+
+    @patch(
+        "vocalinux.text_injection.ibus_engine.ensure_private_dir",
+        side_effect=PermissionError("/tmp/runtime-1000 is not a directory owned by you"),
+    )
+    def test_ensure_ibus_dir_refuses_other_users_directory(self, mock_ensure_private_dir):
+        """Test a directory created by another user is reported as a setup error."""
+        from vocalinux.text_injection.ibus_engine import IBusSetupError, ensure_ibus_dir
+
+        with self.assertRaises(IBusSetupError):
+            ensure_ibus_dir()
 
 
 class TestIsIBusAvailable(unittest.TestCase):
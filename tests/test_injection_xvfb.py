@@ -0,0 +1,191 @@
+"""
+Integration tests for text injection against a headless X server.
+
+A GTK text view runs on Xvfb in its own process, TextInjector types into it
+through each backend that works on plain X, and the text that arrived is
+compared with the text sent. This catches regressions in escaping, Unicode
+and key combination handling that the mocked unit tests can't see.
+
+Needs Xvfb, xdotool and PyGObject with GTK 3, and only runs with
+--run-xvfb-tests (see `make test-xvfb`).
+"""
+
+import os
+import select
+import shutil
+import signal
+import subprocess
+import sys
+import time
+from unittest.mock import patch
+
+import pytest
+
+from vocalinux.text_injection.text_injector import DesktopEnvironment, TextInjector
+
+TARGET_SCRIPT = os.path.join(os.path.dirname(__file__), "xvfb_injection_target.py")
+TARGET_TITLE = "Vocalinux injection target"
+STARTUP_TIMEOUT = 10.0
+TEXT_TIMEOUT = 5.0
+
+pytestmark = [
+    pytest.mark.integration,
+    pytest.mark.xvfb,
+    pytest.mark.timeout(60),
+    pytest.mark.skipif(
+        not (shutil.which("Xvfb") and shutil.which("xdotool")), reason="needs Xvfb and xdotool"
+    ),
+]
+
+# Backends that can type into an X server; IBus needs its daemon and the
+# Wayland tools a compositor, so they are covered by the unit tests only
+X_BACKENDS = [DesktopEnvironment.X11, DesktopEnvironment.WAYLAND_XDOTOOL]
+
+
+def _read_line(stream, timeout: float) -> str:
+    ready, _, _ = select.select([stream], [], [], timeout)
+    return stream.readline() if ready else ""
+
+
+class InjectionTarget:
+    """The GTK text view on the Xvfb display, seen through the file it writes."""
+
+    def __init__(self, process: subprocess.Popen, output_path: str):
+        self.process = process
+        self.output_path = output_path
+
+    def text(self) -> str:
+        try:
+            with open(self.output_path, encoding="utf-8") as output:
+                return output.read()
+        except FileNotFoundError:
+            return ""
+
+    def wait_for(self, expected: str, timeout: float = TEXT_TIMEOUT) -> str:
+        """Wait until the text view holds the expected text; returns what it holds."""
+        deadline = time.monotonic() + timeout
+        while time.monotonic() < deadline:
+            if self.text() == expected:
+                break
+            time.sleep(0.05)
+        return self.text()
+
+    def clear(self):
+        self.process.send_signal(signal.SIGUSR1)
+        assert self.wait_for("") == ""
+
+
+@pytest.fixture(scope="module")
+def x_display():
+    """Start Xvfb on a free display and return its name, e.g. ":99"."""
+    read_fd, write_fd = os.pipe()
+    server = subprocess.Popen(
+        ["Xvfb", "-displayfd", str(write_fd), "-screen", "0", "1024x768x24", "-nolisten", "tcp"],
+        pass_fds=(write_fd,),
+        stdout=subprocess.DEVNULL,
+        stderr=subprocess.DEVNULL,
+    )
+    os.close(write_fd)
+    with os.fdopen(read_fd) as display_pipe:
+        display = _read_line(display_pipe, STARTUP_TIMEOUT).strip()
+    if not display:
+        server.kill()
+        pytest.skip("Xvfb did not start")
+    try:
+        yield f":{display}"
+    finally:
+        server.terminate()
+        server.wait(timeout=5)
+
+
+@pytest.fixture(scope="module")
+def target(x_display, tmp_path_factory):
+    """Show the text view on the Xvfb display and give it the keyboard focus."""
+    output_path = str(tmp_path_factory.mktemp("xvfb") / "typed.txt")
+    env = dict(os.environ, DISPLAY=x_display, GDK_BACKEND="x11")
+    env.pop("WAYLAND_DISPLAY", None)
+    process = subprocess.Popen(
+        [sys.executable, TARGET_SCRIPT, output_path],
+        env=env,
+        stdout=subprocess.PIPE,
+        stderr=subprocess.PIPE,
+        text=True,
+    )
+    try:
+        if _read_line(process.stdout, STARTUP_TIMEOUT).strip() != "ready":
+            process.kill()
+            pytest.skip(f"GTK target did not start: {process.stderr.read()[-500:]}")
+
+        window_id = subprocess.run(
+            ["xdotool", "search", "--sync", "--name", TARGET_TITLE],
+            env=env,
+            capture_output=True,
+            text=True,
+            timeout=STARTUP_TIMEOUT,
+            check=True,
+        ).stdout.split()[0]
+        # There is no window manager to hand out focus, so set it directly
+        subprocess.run(
+            ["xdotool", "windowfocus", "--sync", window_id],
+            env=env,
+            timeout=STARTUP_TIMEOUT,
+            check=True,
+        )
+        yield InjectionTarget(process, output_path)
+    finally:
+        process.terminate()
+        process.wait(timeout=5)
+
+
+@pytest.fixture(params=X_BACKENDS, ids=lambda environment: environment.value)
+def injector(request, x_display, target, monkeypatch):
+    """A TextInjector using one X backend, typing into a cleared target."""
+    monkeypatch.setenv("DISPLAY", x_display)
+    monkeypatch.setenv("XDG_SESSION_TYPE", "x11")
+    monkeypatch.delenv("WAYLAND_DISPLAY", raising=False)
+    with patch("vocalinux.text_injection.text_injector.is_ibus_available", return_value=False):
+        text_injector = TextInjector()
+    text_injector.environment = request.param
+    # Keep the backend under test: no switching tools, no clipboard fallback
+    monkeypatch.setattr(text_injector, "_try_recover_from_fallback", lambda: None)
+    monkeypatch.setattr(text_injector, "_should_copy_to_clipboard", lambda: False)
+    monkeypatch.setattr(text_injector, "_copy_to_clipboard", lambda text: False)
+    target.clear()
+    return text_injector
+
+
+@pytest.mark.parametrize(
+    "text",
+    [
+        "Hello world",
+        "It's \"quoted\" $HOME `ls` ; & | \\ * ~ {braces} [brackets] 100%",
+        "café naïve über straße – €5 ¿qué?",
+        "日本語のテキスト",
+        "A sentence long enough to be typed in several chunks, split mid-word.",
+        "line one\nline two\tafter a tab",
+    ],
+    ids=["ascii", "shell-characters", "latin-accents", "cjk", "chunked", "newline-tab"],
+)
+def test_injected_text_arrives_unchanged(injector, target, text):
+    assert injector.inject_text(text)
+    assert target.wait_for(text) == text
+
+
+def test_successive_injections_append(injector, target):
+    assert injector.inject_text("first")
+    assert target.wait_for("first") == "first"
+    assert injector.inject_text(" second")
+    assert target.wait_for("first second") == "first second"
+
+
+def test_key_combinations(injector, target):
+    assert injector.inject_text("hello world")
+    assert target.wait_for("hello world") == "hello world"
+
+    assert injector._inject_keyboard_shortcut("BackSpace")
+    assert target.wait_for("hello worl") == "hello worl"
+
+    # Select all, then typing replaces the selection
+    assert injector._inject_keyboard_shortcut("ctrl+a")
+    assert injector.inject_text("replaced")
+    assert target.wait_for("replaced") == "replaced"
@@ -0,0 +1,90 @@
+"""
+Tests for assistant-style intent commands.
+"""
+
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.speech_recognition.intent_commands import (
+    Intent,
+    IntentHandler,
+    find_application,
+    match_intent,
+)
+
+APPS = ["Firefox Web Browser", "Files", "Terminal", "Text Editor", "LibreOffice Writer"]
+
+
+class TestMatchIntent(unittest.TestCase):
+    """Tests for recognizing intents."""
+
+    def test_open_app(self):
+        self.assertEqual(match_intent("open firefox"), Intent("open_app", "firefox"))
+        self.assertEqual(match_intent("Launch the Terminal."), Intent("open_app", "Terminal"))
+
+    def test_web_search(self):
+        self.assertEqual(
+            match_intent("Search for pipewire echo cancel?"),
+            Intent("web_search", "pipewire echo cancel"),
+        )
+        self.assertEqual(match_intent("look up gtk docs"), Intent("web_search", "gtk docs"))
+
+    def test_ordinary_dictation(self):
+        self.assertIsNone(match_intent("please open the window"))
+        self.assertIsNone(match_intent("open"))
+        self.assertIsNone(match_intent(""))
+
+
+class TestFindApplication(unittest.TestCase):
+    """Tests for matching spoken names to applications."""
+
+    def test_exact_match(self):
+        self.assertEqual(find_application("terminal", APPS), "Terminal")
+
+    def test_prefix_match(self):
+        self.assertEqual(find_application("firefox", APPS), "Firefox Web Browser")
+
+    def test_fuzzy_match(self):
+        self.assertEqual(find_application("text editer", APPS), "Text Editor")
+
+    def test_no_match(self):
+        self.assertIsNone(find_application("the door", APPS))
+
+
+class TestIntentHandler(unittest.TestCase):
+    """Tests for carrying out intents."""
+
+    def setUp(self):
+        self.launch_app = MagicMock(return_value=True)
+        self.open_url = MagicMock()
+        self.handler = IntentHandler(launch_app=self.launch_app, open_url=self.open_url)
+
+    def test_search_opens_browser(self):
+        self.assertTrue(self.handler.handle("search for rust & python"))
+        self.open_url.assert_called_once_with("https://duckduckgo.com/?q=rust+%26+python")
+
+    def test_custom_search_url(self):
+        self.handler.search_url = "https://www.google.com/search?q={query}"
+        self.handler.handle("search for vocalinux")
+        self.open_url.assert_called_once_with("https://www.google.com/search?q=vocalinux")
+
+    def test_search_url_without_placeholder_uses_default(self):
+        self.handler.search_url = "https://example.com"
+        self.assertEqual(self.handler.search_url_for("a b"), "https://duckduckgo.com/?q=a+b")
+
+    def test_open_launches_app(self):
+        self.assertTrue(self.handler.handle("open firefox"))
+        self.launch_app.assert_called_once_with("firefox")
+
+    def test_unknown_app_is_typed(self):
+        self.launch_app.return_value = False
+        self.assertFalse(self.handler.handle("open the door"))
+
+    def test_dictation_is_typed(self):
+        self.assertFalse(self.handler.handle("hello world"))
+        self.launch_app.assert_not_called()
+        self.open_url.assert_not_called()
+
+
+if __name__ == "__main__":
+    unittest.main()
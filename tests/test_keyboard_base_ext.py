@@ -365,3 +365,72 @@ class TestKeyboardBackend:
         assert backend.active is True
         backend.active = False
         assert backend.active is False
+
+
+class TestTapSettings:
+    """Test configurable tap count and timing."""
+
+    def test_default_double_tap(self):
+        """Test two quick taps trigger by default."""
+        backend = ConcreteKeyboardBackend()
+        assert backend._register_tap(10.0) is False
+        backend.last_key_press_time = 10.0
+        assert backend._register_tap(10.2) is True
+
+    def test_slow_taps_do_not_trigger(self):
+        """Test taps further apart than the threshold restart the sequence."""
+        backend = ConcreteKeyboardBackend()
+        backend._register_tap(10.0)
+        backend.last_key_press_time = 10.0
+        assert backend._register_tap(10.5) is False
+
+    def test_triple_tap(self):
+        """Test a tap count of three needs three quick taps."""
+        backend = ConcreteKeyboardBackend()
+        backend.set_tap_settings(3, 500)
+        results = []
+        for press_time in (10.0, 10.4, 10.8):
+            results.append(backend._register_tap(press_time))
+            backend.last_key_press_time = press_time
+        assert results == [False, False, True]
+        assert backend.double_tap_threshold == 0.5
+
+    def test_invalid_tap_settings(self):
+        """Test out-of-range tap settings are rejected."""
+        backend = ConcreteKeyboardBackend()
+        with pytest.raises(ValueError):
+            backend.set_tap_settings(1, 300)
+        with pytest.raises(ValueError):
+            backend.set_tap_settings(2, 5000)
+        assert backend.tap_count == 2
+
+
+class TestHoldToCancel:
+    """Test the hold-to-cancel gesture tracking."""
+
+    def test_long_hold_cancels(self):
+        """Test holding the key past the threshold reports a cancel."""
+        backend = ConcreteKeyboardBackend()
+        backend._begin_hold(10.0)
+        backend._begin_hold(10.5)  # Auto-repeat keeps the original start
+        assert backend._end_hold(11.1) is True
+
+    def test_short_press_does_not_cancel(self):
+        """Test a normal tap is not a cancel."""
+        backend = ConcreteKeyboardBackend()
+        backend._begin_hold(10.0)
+        assert backend._end_hold(10.2) is False
+
+    def test_other_key_interrupts_hold(self):
+        """Test pressing another key during the hold (e.g. Ctrl+C) is not a cancel."""
+        backend = ConcreteKeyboardBackend()
+        backend._begin_hold(10.0)
+        backend._interrupt_hold()
+        assert backend._end_hold(12.0) is False
+
+    def test_register_cancel_callback(self):
+        """Test registering the cancel callback."""
+        backend = ConcreteKeyboardBackend()
+        callback = MagicMock()
+        backend.register_cancel_callback(callback)
+        assert backend.cancel_callback is callback
@@ -447,3 +447,78 @@ class TestCreateBackendFallback:
                         result = create_backend(preferred_backend="pynput")
                         # Falls back to auto-detection which selects evdev on Wayland
                         assert result == mock_evdev_backend
+
+
+class TestDetectShortcutCapability:
+    """Test detect_shortcut_capability() for each session type."""
+
+    def test_x11_uses_pynput(self):
+        with patch.object(DesktopEnvironment, "detect", return_value=DesktopEnvironment.X11):
+            with patch.dict(os.environ, {}, clear=False):
+                os.environ.pop("FLATPAK_ID", None)
+                with patch.object(kb_module, "PYNPUT_AVAILABLE", True):
+                    capability = kb_module.detect_shortcut_capability()
+        assert capability.available
+        assert capability.backend == "pynput"
+        assert capability.guidance == ""
+
+    def test_wayland_with_usable_evdev(self):
+        mock_backend = MagicMock()
+        mock_backend.is_available.return_value = True
+        with patch.object(DesktopEnvironment, "detect", return_value=DesktopEnvironment.WAYLAND):
+            with patch.object(kb_module, "EVDEV_AVAILABLE", True), patch.object(
+                kb_module, "EvdevKeyboardBackend", return_value=mock_backend
+            ):
+                capability = kb_module.detect_shortcut_capability("alt+alt")
+        assert capability.backend == "evdev"
+
+    def test_wayland_ignores_pynput(self):
+        with patch.object(DesktopEnvironment, "detect", return_value=DesktopEnvironment.WAYLAND):
+            with patch.object(kb_module, "EVDEV_AVAILABLE", False), patch.object(
+                kb_module, "PYNPUT_AVAILABLE", True
+            ):
+                capability = kb_module.detect_shortcut_capability()
+        assert not capability.available
+        assert "pip install evdev" in capability.guidance
+        assert "tray menu" in capability.guidance
+
+    def test_wayland_permission_denied_guidance(self):
+        mock_backend = MagicMock()
+        mock_backend.is_available.return_value = False
+        mock_backend.get_permission_hint.return_value = (
+            "Add your user to the 'input' group and log out/in:\nsudo usermod -a -G input $USER"
+        )
+        with patch.object(DesktopEnvironment, "detect", return_value=DesktopEnvironment.WAYLAND):
+            with patch.dict(os.environ, {}, clear=False):
+                os.environ.pop("FLATPAK_ID", None)
+                with patch.object(kb_module, "EVDEV_AVAILABLE", True), patch.object(
+                    kb_module, "EvdevKeyboardBackend", return_value=mock_backend
+                ):
+                    capability = kb_module.detect_shortcut_capability()
+        assert not capability.available
+        assert "usermod" in capability.guidance
+        assert "\n" not in capability.guidance
+
+    def test_flatpak_guidance_mentions_device_override(self):
+        mock_backend = MagicMock()
+        mock_backend.is_available.return_value = False
+        env = {"FLATPAK_ID": "com.vocalinux.Vocalinux"}
+        with patch.object(DesktopEnvironment, "detect", return_value=DesktopEnvironment.X11):
+            with patch.dict(os.environ, env, clear=False):
+                with patch.object(kb_module, "EVDEV_AVAILABLE", True), patch.object(
+                    kb_module, "EvdevKeyboardBackend", return_value=mock_backend
+                ):
+                    capability = kb_module.detect_shortcut_capability()
+        assert not capability.available
+        assert "--device=input com.vocalinux.Vocalinux" in capability.guidance
+
+    def test_x11_without_backends(self):
+        with patch.object(DesktopEnvironment, "detect", return_value=DesktopEnvironment.X11):
+            with patch.dict(os.environ, {}, clear=False):
+                os.environ.pop("FLATPAK_ID", None)
+                with patch.object(kb_module, "PYNPUT_AVAILABLE", False), patch.object(
+                    kb_module, "EVDEV_AVAILABLE", False
+                ):
+                    capability = kb_module.detect_shortcut_capability()
+        assert capability.backend is None
+        assert "pip install pynput evdev" in capability.guidance
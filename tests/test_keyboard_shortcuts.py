@@ -57,6 +57,22 @@ class TestKeyboardShortcuts(unittest.TestCase):
             preferred_backend=None, shortcut="alt+alt", mode="toggle"
         )
 
+    def test_set_tap_settings(self):
+        """Test tap settings are passed to the backend."""
+        self.assertTrue(self.ksm.set_tap_settings(3, 400))
+        self.mock_backend.set_tap_settings.assert_called_once_with(3, 400)
+
+    def test_set_tap_settings_invalid(self):
+        """Test invalid tap settings are reported as failure."""
+        self.mock_backend.set_tap_settings.side_effect = ValueError("bad")
+        self.assertFalse(self.ksm.set_tap_settings(9, 400))
+
+    def test_register_cancel_callback(self):
+        """Test the hold-to-cancel callback is passed to the backend."""
+        callback = MagicMock()
+        self.ksm.register_cancel_callback(callback)
+        self.mock_backend.register_cancel_callback.assert_called_once_with(callback)
+
     def test_default_shortcut(self):
         """Test that default shortcut is ctrl+ctrl."""
         self.assertEqual(DEFAULT_SHORTCUT, "ctrl+ctrl")
@@ -283,6 +299,33 @@ class TestKeyboardShortcuts(unittest.TestCase):
         self.assertFalse(result)
         self.assertFalse(self.ksm.active)
 
+    def test_unavailable_guidance_none_when_active(self):
+        """Test no guidance is given while the listener runs."""
+        self.ksm.start()
+        self.assertIsNone(self.ksm.get_unavailable_guidance())
+
+    def test_unavailable_guidance_from_capability(self):
+        """Test guidance comes from capability detection when shortcuts can't work."""
+        from vocalinux.ui.keyboard_backends import ShortcutCapability
+
+        capability = ShortcutCapability("wayland", None, "Install python-evdev")
+        with patch(
+            "vocalinux.ui.keyboard_shortcuts.detect_shortcut_capability", return_value=capability
+        ):
+            self.assertEqual(self.ksm.get_unavailable_guidance(), "Install python-evdev")
+
+    def test_unavailable_guidance_falls_back_to_permission_hint(self):
+        """Test the backend permission hint is used when detection finds a backend."""
+        from vocalinux.ui.keyboard_backends import ShortcutCapability
+
+        self.mock_backend.get_permission_hint.return_value = "Add yourself to 'input'"
+        capability = ShortcutCapability("x11", "pynput")
+        with patch(
+            "vocalinux.ui.keyboard_shortcuts.detect_shortcut_capability", return_value=capability
+        ):
+            guidance = self.ksm.get_unavailable_guidance()
+        self.assertTrue(guidance.startswith("Add yourself to 'input'"))
+
     def test_stop_listener(self):
         """Test stopping the keyboard listener."""
         # Setup an active listener
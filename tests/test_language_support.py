@@ -0,0 +1,109 @@
+"""
+Tests for engine/model/language capability validation.
+"""
+
+import unittest
+from unittest.mock import patch
+
+from vocalinux.utils.language_support import get_supported_languages, validate_language_selection
+
+
+class TestGetSupportedLanguages(unittest.TestCase):
+    """Tests for get_supported_languages."""
+
+    def test_vosk_excludes_auto(self):
+        languages = get_supported_languages("vosk", "small")
+        self.assertIn("en-us", languages)
+        self.assertIn("hi", languages)
+        self.assertNotIn("auto", languages)
+
+    def test_vosk_unknown_size_has_no_languages(self):
+        self.assertEqual(get_supported_languages("vosk", "tiny"), [])
+
+    def test_vosk_medium_limited_to_available_models(self):
+        with patch.dict(
+            "vocalinux.utils.language_support.VOSK_MODEL_INFO",
+            {"medium": {"languages": {"en-us": "vosk-model-en-us-0.22"}}},
+        ):
+            self.assertEqual(get_supported_languages("vosk", "medium"), ["en-us"])
+
+    def test_whisper_supports_auto(self):
+        self.assertIn("auto", get_supported_languages("whisper", "small"))
+        self.assertIn("auto", get_supported_languages("remote_api"))
+
+    def test_english_only_whispercpp_model(self):
+        self.assertEqual(get_supported_languages("whisper_cpp", "base.en"), ["en-us"])
+
+    def test_unknown_engine(self):
+        self.assertEqual(get_supported_languages("nope"), [])
+
+
+class TestValidateLanguageSelection(unittest.TestCase):
+    """Tests for validate_language_selection."""
+
+    def test_valid_combinations(self):
+        self.assertIsNone(validate_language_selection("vosk", "small", "hi"))
+        self.assertIsNone(validate_language_selection("vosk", "small", "en-in"))
+        self.assertIsNone(validate_language_selection("whisper_cpp", "small", "auto"))
+        self.assertIsNone(validate_language_selection("whisper", "small", "ja"))
+        self.assertIsNone(validate_language_selection("whisper_cpp", "base.en", "en-us"))
+
+    def test_vosk_auto_is_rejected(self):
+        self.assertIn("auto-detect", validate_language_selection("vosk", "small", "auto"))
+
+    def test_vosk_unknown_size(self):
+        self.assertIn("'tiny'", validate_language_selection("vosk", "tiny", "en-us"))
+
+    def test_vosk_language_missing_for_size_suggests_small(self):
+        with patch.dict(
+            "vocalinux.utils.language_support.VOSK_MODEL_INFO",
+            {"medium": {"languages": {"en-us": "vosk-model-en-us-0.22"}}},
+        ):
+            warning = validate_language_selection("vosk", "medium", "hi")
+        self.assertEqual(
+            warning, "Hindi is not available for the VOSK medium model. Use the small model."
+        )
+
+    def test_vosk_unsupported_language(self):
+        self.assertEqual(
+            validate_language_selection("vosk", "small", "ja"), "ja is not supported by VOSK."
+        )
+
+    def test_english_only_model_with_other_language(self):
+        warning = validate_language_selection("whisper_cpp", "small.en", "fr")
+        self.assertIn("English-only", warning)
+        self.assertIn("French", warning)
+
+    def test_missing_language_is_valid(self):
+        self.assertIsNone(validate_language_selection("vosk", "small", None))
+
+
+class TestManagerLanguageWarning(unittest.TestCase):
+    """Tests for the recognition manager's unsupported-language warning."""
+
+    def test_manager_notifies_on_unsupported_selection(self):
+        from vocalinux.speech_recognition import recognition_manager
+        from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+        with patch.object(SpeechRecognitionManager, "_init_vosk"), patch.object(
+            recognition_manager, "_show_notification"
+        ) as mock_notify:
+            SpeechRecognitionManager(engine="vosk", model_size="small", language="auto")
+
+        mock_notify.assert_called_once()
+        self.assertEqual(mock_notify.call_args[0][0], "Language not supported")
+
+    def test_manager_silent_on_valid_selection(self):
+        from vocalinux.speech_recognition import recognition_manager
+        from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+        with patch.object(SpeechRecognitionManager, "_init_vosk"), patch.object(
+            recognition_manager, "_show_notification"
+        ) as mock_notify:
+            SpeechRecognitionManager(engine="vosk", model_size="small", language="en-us")
+
+        mock_notify.assert_not_called()
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,94 @@
+"""Tests for the live microphone level on the Audio settings page."""
+
+import sys
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.speech_recognition import level_monitor
+from vocalinux.speech_recognition.level_monitor import LevelMonitor
+from vocalinux.speech_recognition.recognition_manager import CaptureFormat
+
+
+class TestLevelMonitor(unittest.TestCase):
+    """Test LevelMonitor reading a (mocked) microphone."""
+
+    def _run_once(self, monitor, stream, capture_format=None):
+        """Run the monitor loop until the first chunk has been read."""
+
+        def read(frames, exception_on_overflow=False):
+            monitor._stop_event.set()
+            return b"\x00\x00" * frames
+
+        stream.read.side_effect = read
+        audio = MagicMock()
+        audio.open.return_value = stream
+        audio.get_default_input_device_info.return_value = {"index": 4}
+        pyaudio = self.pyaudio = MagicMock()
+        pyaudio.PyAudio.return_value = audio
+        self.to_int16 = MagicMock(side_effect=lambda data, sample_format: data)
+        with patch.dict(sys.modules, {"pyaudio": pyaudio, "numpy": MagicMock()}), patch.multiple(
+            level_monitor,
+            to_int16=self.to_int16,
+            ChannelDownmixer=MagicMock(return_value=MagicMock(process=lambda data: data)),
+            apply_gain=MagicMock(side_effect=lambda data, gain: data),
+            rms_dbfs=MagicMock(return_value=-20.0),
+        ), patch(
+            "vocalinux.speech_recognition.recognition_manager.negotiate_capture_format",
+            return_value=capture_format or CaptureFormat(rate=16000, channels=1),
+        ):
+            monitor._run()
+        return audio
+
+    def test_reports_levels_then_zero(self):
+        """Each chunk's level is reported, then 0 once the microphone is closed."""
+        levels = []
+        stream = MagicMock()
+        monitor = LevelMonitor(levels.append, device_index=2, gain=2.0)
+
+        audio = self._run_once(monitor, stream)
+
+        self.assertEqual(len(levels), 2)
+        self.assertGreater(levels[0], 0.0)
+        self.assertEqual(levels[-1], 0.0)
+        self.assertEqual(audio.open.call_args.kwargs["input_device_index"], 2)
+        stream.close.assert_called_once()
+        audio.terminate.assert_called_once()
+
+    def test_unsmoothed_levels_for_measuring(self):
+        """Each chunk's level in dBFS goes to on_dbfs as well."""
+        levels = []
+        monitor = LevelMonitor(lambda level: None, on_dbfs=levels.append)
+
+        self._run_once(monitor, MagicMock())
+
+        self.assertEqual(levels, [-20.0])
+
+    def test_default_device_is_not_forced(self):
+        """With no device chosen, the stream opens on the default input."""
+        monitor = LevelMonitor(lambda level: None)
+
+        audio = self._run_once(monitor, MagicMock())
+
+        self.assertNotIn("input_device_index", audio.open.call_args.kwargs)
+
+    def test_opens_the_negotiated_format(self):
+        """A device that only delivers float stereo is opened that way and converted."""
+        monitor = LevelMonitor(lambda level: None)
+        capture_format = CaptureFormat(rate=48000, channels=2, sample_format="float32")
+
+        audio = self._run_once(monitor, MagicMock(), capture_format)
+
+        kwargs = audio.open.call_args.kwargs
+        self.assertEqual(kwargs["format"], self.pyaudio.paFloat32)
+        self.assertEqual((kwargs["channels"], kwargs["rate"]), (2, 48000))
+        self.assertEqual(self.to_int16.call_args.args[1], "float32")
+
+    def test_stop_without_start(self):
+        """Stopping a monitor that never started does nothing."""
+        monitor = LevelMonitor(lambda level: None)
+        monitor.stop()
+        self.assertFalse(monitor.running)
+
+
+if __name__ == "__main__":
+    unittest.main()
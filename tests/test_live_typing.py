@@ -0,0 +1,108 @@
+"""
+Tests for typing partial results and correcting them in place.
+"""
+
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.text_injection.live_typing import (
+    DraftRewriter,
+    LiveTyper,
+    common_prefix_length,
+    correction_keys,
+)
+
+
+class TestCorrectionKeys(unittest.TestCase):
+    """Tests for the diff helpers."""
+
+    def test_common_prefix_length(self):
+        self.assertEqual(common_prefix_length("hello word", "hello world"), 9)
+        self.assertEqual(common_prefix_length("", "hello"), 0)
+        self.assertEqual(common_prefix_length("same", "same"), 4)
+
+    def test_extension_only_types_new_text(self):
+        self.assertEqual(correction_keys("hello", "hello world"), " world")
+
+    def test_revision_deletes_only_the_changed_tail(self):
+        self.assertEqual(correction_keys("hello word", "hello world."), "\bld.")
+        self.assertEqual(correction_keys("i scream", "ice cream"), "\b" * 7 + "ce cream")
+
+    def test_no_change(self):
+        self.assertEqual(correction_keys("done", "done"), "")
+
+
+class TestLiveTyper(unittest.TestCase):
+    """Tests for LiveTyper."""
+
+    def setUp(self):
+        self.inject = MagicMock(return_value=True)
+        self.typer = LiveTyper(self.inject)
+
+    def test_partials_are_corrected_in_place(self):
+        self.typer.update("the quick")
+        self.typer.update("the quick brown")
+        self.typer.update("the quick brow fox")
+
+        self.assertEqual(
+            [c.args[0] for c in self.inject.call_args_list],
+            ["the quick", " brown", "\b fox"],
+        )
+        self.assertTrue(self.typer.active)
+
+    def test_finish_replaces_partial_and_resets(self):
+        self.typer.update("hello word")
+        self.assertTrue(self.typer.finish("Hello world."))
+        self.inject.assert_called_with("\b" * 10 + "Hello world.")
+        self.assertFalse(self.typer.active)
+
+    def test_discard_removes_partial(self):
+        self.typer.update("um")
+        self.typer.discard()
+        self.inject.assert_called_with("\b\b")
+        self.assertFalse(self.typer.active)
+
+    def test_failed_injection_keeps_previous_state(self):
+        self.typer.update("hello")
+        self.inject.return_value = False
+        self.assertFalse(self.typer.update("hello there"))
+        self.inject.return_value = True
+        self.typer.update("hello there")
+        self.inject.assert_called_with(" there")
+
+
+
+class TestDraftRewriter(unittest.TestCase):
+    """Tests for DraftRewriter."""
+
+    def setUp(self):
+        self.inject = MagicMock(return_value=True)
+        self.rewriter = DraftRewriter(self.inject)
+
+    def test_last_draft_is_corrected_in_place(self):
+        self.rewriter.typed(" I red the book")
+        self.assertTrue(self.rewriter.rewrite("I red the book", "I read the book."))
+        self.inject.assert_called_once_with("\b" * 10 + "ad the book.")
+
+        # A second rewrite of the same utterance starts from the corrected text
+        self.assertTrue(self.rewriter.rewrite("I read the book.", "I read the book!"))
+        self.inject.assert_called_with("\b!")
+
+    def test_draft_no_longer_last_is_kept(self):
+        self.rewriter.typed(" second sentence")
+        self.assertFalse(self.rewriter.rewrite("first sentence", "First sentence."))
+
+        self.rewriter.forget()
+        self.assertFalse(self.rewriter.rewrite("second sentence", "Second sentence."))
+        self.inject.assert_not_called()
+
+    def test_failed_injection_stops_corrections(self):
+        self.rewriter.typed("hello word")
+        self.inject.return_value = False
+        self.assertFalse(self.rewriter.rewrite("hello word", "hello world"))
+        self.inject.return_value = True
+        self.assertFalse(self.rewriter.rewrite("hello word", "hello world"))
+
+
+if __name__ == "__main__":
+    unittest.main()
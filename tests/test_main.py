@@ -57,6 +57,24 @@ class TestMainModule(unittest.TestCase):
             self.assertTrue(args.wayland)
             self.assertTrue(args.start_minimized)
 
+    def test_parse_arguments_input_file(self):
+        """Test --input-file and --input-speed for replaying a recording."""
+        with patch("sys.argv", ["vocalinux", "--input-file", "take1.wav", "--input-speed", "4"]):
+            args = parse_arguments()
+            self.assertEqual(args.input_file, "take1.wav")
+            self.assertEqual(args.input_speed, 4.0)
+        with patch("sys.argv", ["vocalinux"]):
+            args = parse_arguments()
+            self.assertIsNone(args.input_file)
+            self.assertEqual(args.input_speed, 1.0)
+
+    def test_parse_arguments_dump_audio(self):
+        """Test --dump-audio takes the directory to write utterances to."""
+        with patch("sys.argv", ["vocalinux", "--dump-audio", "/tmp/vocalinux-audio"]):
+            self.assertEqual(parse_arguments().dump_audio, "/tmp/vocalinux-audio")
+        with patch("sys.argv", ["vocalinux"]):
+            self.assertIsNone(parse_arguments().dump_audio)
+
     def test_parse_arguments_model_values(self):
         """Test model parsing for base and exact whisper.cpp model IDs."""
         with patch("sys.argv", ["vocalinux", "--model", "small"]):
@@ -147,6 +165,7 @@ class TestMainModule(unittest.TestCase):
         mock_args.engine = "vosk"
         mock_args.language = "en-us"
         mock_args.wayland = False
+        mock_args.kiosk = False
         mock_parse.return_value = mock_args
 
         # Mock config
@@ -173,8 +192,10 @@ class TestMainModule(unittest.TestCase):
     @patch("vocalinux.main.logging")
     @patch("vocalinux.ui.config_manager.ConfigManager")
     @patch("vocalinux.ui.logging_manager.initialize_logging")
+    @patch("vocalinux.status_output.StatusWriter")
     def test_main_initializes_components(
         self,
+        mock_status_writer,
         mock_init_logging,
         mock_config_manager,
         mock_logging,
@@ -216,6 +237,10 @@ class TestMainModule(unittest.TestCase):
             mock_args.engine = "vosk"
             mock_args.language = "en-us"
             mock_args.wayland = True
+            mock_args.kiosk = False
+            mock_args.dump_audio = None
+            mock_args.input_file = None
+            mock_args.input_speed = 1.0
             mock_parse.return_value = mock_args
 
             # Call main function
@@ -227,11 +252,67 @@ class TestMainModule(unittest.TestCase):
                 model_size="medium",
                 language="en-us",
                 vad_sensitivity=3,
+                vad_backend="auto",
                 silence_timeout=2.0,
+                engine_vad={},
+                adaptive_silence_timeout=False,
+                max_utterance_seconds=30,
+                speech_pad_before_ms=200,
+                speech_pad_after_ms=200,
                 stop_sound_guard_ms=200,
                 voice_commands_enabled=None,
+                disabled_commands=[],
+                renamed_commands={},
+                punctuation=None,
+                utterance_join_window=0.0,
+                vosk_speaker_profile=False,
+                long_form_mode=False,
+                partial_results=False,
+                translate=False,
+                tag_language=False,
+                comparison_engine=None,
+                comparison_model_size=None,
+                rewrite_engine=None,
+                rewrite_model_size="small",
+                retry_audio=False,
+                retry_model_size="large",
+                offline_fallback_engine=None,
+                offline_fallback_model_size="tiny",
+                offline_fallback_standby=False,
+                gpu_memory_reserve_mb=0,
+                intent_commands_enabled=False,
+                intent_search_url=None,
+                format_ordinals=False,
+                format_fractions=False,
+                format_roman_numerals=False,
+                preserved_terms=[],
+                profanity_filter="off",
+                profanity_words=[],
+                text_pipeline=None,
+                vocabulary_terms=[],
+                prompt_presets={},
+                app_prompts={},
                 audio_device_index=None,
                 audio_device_name=None,
+                sample_rate=None,
+                input_file=None,
+                input_file_speed=1.0,
+                sidetone_enabled=False,
+                sidetone_volume=0.3,
+                duck_media=False,
+                duck_level=0.3,
+                echo_cancel=False,
+                mute_own_sounds=True,
+                noise_suppression=False,
+                device_gain={},
+                extra_device_names=[],
+                buffer_frames=1024,
+                queue_depth=32,
+                download_mirror="official",
+                vosk_mirror_url="",
+                download_limit_kbps=0,
+                session_word_target=0,
+                utterance_log="off",
                 whispercpp_no_timestamps=True,
                 whispercpp_no_context=True,
                 whispercpp_initial_prompt="",
@@ -241,15 +322,20 @@ class TestMainModule(unittest.TestCase):
                 whispercpp_logprob_thold=-1.0,
                 whispercpp_no_speech_thold=0.6,
                 whispercpp_n_threads=0,
+                dump_audio_dir="",
                 remote_api_url="",
                 remote_api_key="",
+                remote_api_account="default",
                 remote_api_endpoint="/inference",
                 remote_api_model="whisper-1",
             )
             mock_text.assert_called_once_with(wayland_mode=True)
             mock_action_handler.assert_called_once_with(mock_text_instance)
             mock_tray.assert_called_once_with(
-                speech_engine=mock_speech_instance, text_injector=mock_text_instance
+                speech_engine=mock_speech_instance,
+                text_injector=mock_text_instance,
+                kiosk=False,
+                config_manager=mock_config_instance,
             )
 
             # Verify callbacks were registered
@@ -257,7 +343,17 @@ class TestMainModule(unittest.TestCase):
             mock_speech_instance.register_action_callback.assert_called_once_with(
                 mock_action_instance.handle_action
             )
-            mock_speech_instance.register_state_callback.assert_called_once()
+            self.assertEqual(mock_speech_instance.register_state_callback.call_count, 2)
+            status_writer = mock_status_writer.return_value
+            mock_speech_instance.register_state_callback.assert_any_call(
+                status_writer.on_state_changed
+            )
+            mock_speech_instance.register_audio_level_callback.assert_called_once_with(
+                status_writer.on_audio_level
+            )
+            mock_speech_instance.set_focused_app_provider.assert_called_once_with(
+                mock_text_instance.get_active_window_class
+            )
 
             # Verify the tray indicator was started
             mock_tray_instance.run.assert_called_once()
@@ -303,6 +399,7 @@ class TestMainModule(unittest.TestCase):
             mock_args.engine = "vosk"
             mock_args.language = "en-us"
             mock_args.wayland = False
+            mock_args.kiosk = False
             mock_args.start_minimized = False
             mock_parse.return_value = mock_args
 
@@ -319,11 +416,193 @@ class TestMainModule(unittest.TestCase):
         calls = [call.args[0] for call in mock_text_instance.inject_text.call_args_list]
         self.assertEqual(calls, ["Hello.", " World"])
 
+        # A punctuation-only segment attaches to the previous one
+        mock_text_instance.inject_text.reset_mock()
+        text_callback(".")
+        mock_text_instance.inject_text.assert_called_once_with(".")
+
+        # Partial results are typed, then corrected in place by the final text
+        partial_callback = mock_speech_instance.register_partial_callback.call_args.args[0]
+        mock_text_instance.inject_text.reset_mock()
+        partial_callback("Good mor")
+        text_callback("Good morning.")
+        calls = [call.args[0] for call in mock_text_instance.inject_text.call_args_list]
+        self.assertEqual(calls, [" Good mor", "ning."])
+
         state_callback(RecognitionState.IDLE)
         mock_text_instance.inject_text.reset_mock()
         text_callback("Next session")
         mock_text_instance.inject_text.assert_called_once_with("Next session")
 
+    @patch("vocalinux.main.check_dependencies")
+    @patch("vocalinux.speech_recognition.recognition_manager.SpeechRecognitionManager")
+    @patch("vocalinux.text_injection.text_injector.TextInjector")
+    @patch("vocalinux.ui.tray_indicator.TrayIndicator")
+    @patch("vocalinux.ui.config_manager.ConfigManager")
+    @patch("vocalinux.ui.logging_manager.initialize_logging")
+    def test_results_routed_to_output_profile_sinks(
+        self,
+        mock_init_logging,
+        mock_config_manager,
+        mock_tray,
+        mock_text,
+        mock_speech,
+        mock_check_deps,
+    ):
+        """Test the --output-profile sinks receive each final transcript."""
+        mock_check_deps.return_value = True
+
+        mock_config_instance = MagicMock()
+        mock_config_instance.get_settings.return_value = {
+            "speech_recognition": {},
+            "general": {"first_run": False},
+            "output": {
+                "profile": "default",
+                "profiles": {
+                    "default": {"sinks": ["inject"]},
+                    "both": {"sinks": ["inject", "clipboard"], "translate": True},
+                },
+            },
+        }
+        mock_config_manager.return_value = mock_config_instance
+
+        mock_speech_instance = MagicMock()
+        mock_text_instance = MagicMock()
+        mock_text_instance.inject_text.return_value = True
+        mock_speech.return_value = mock_speech_instance
+        mock_text.return_value = mock_text_instance
+
+        with patch("vocalinux.main.parse_arguments") as mock_parse:
+            mock_args = MagicMock()
+            mock_args.debug = False
+            mock_args.model = "medium"
+            mock_args.engine = "vosk"
+            mock_args.language = "en-us"
+            mock_args.wayland = False
+            mock_args.kiosk = False
+            mock_args.start_minimized = False
+            mock_args.output_profile = "both"
+            mock_parse.return_value = mock_args
+
+            main()
+
+        text_callback = mock_speech_instance.register_text_callback.call_args.args[0]
+        text_callback(" Hello ")
+
+        mock_text_instance.inject_text.assert_called_once_with("Hello")
+        mock_text_instance._copy_to_clipboard.assert_called_once_with("Hello")
+        # The profile turns on translation
+        self.assertTrue(mock_speech.call_args.kwargs["translate"])
+
+    @patch("vocalinux.main.check_dependencies")
+    @patch("vocalinux.speech_recognition.recognition_manager.SpeechRecognitionManager")
+    @patch("vocalinux.text_injection.text_injector.TextInjector")
+    @patch("vocalinux.ui.tray_indicator.TrayIndicator")
+    @patch("vocalinux.ui.config_manager.ConfigManager")
+    @patch("vocalinux.ui.logging_manager.initialize_logging")
+    def test_kiosk_mode_types_nothing(
+        self,
+        mock_init_logging,
+        mock_config_manager,
+        mock_tray,
+        mock_text,
+        mock_speech,
+        mock_check_deps,
+    ):
+        """Test --kiosk keeps results out of the focused window and turns off commands."""
+        mock_check_deps.return_value = True
+
+        mock_config_instance = MagicMock()
+        mock_config_instance.get_settings.return_value = {
+            "speech_recognition": {"intent_commands_enabled": True},
+            "general": {"first_run": False},
+            "output": {"profiles": {"default": {"sinks": ["inject", "clipboard"]}}},
+        }
+        mock_config_manager.return_value = mock_config_instance
+
+        mock_speech_instance = MagicMock()
+        mock_text_instance = MagicMock()
+        mock_speech.return_value = mock_speech_instance
+        mock_text.return_value = mock_text_instance
+
+        with patch("vocalinux.main.parse_arguments") as mock_parse:
+            mock_args = MagicMock()
+            mock_args.debug = False
+            mock_args.model = "medium"
+            mock_args.engine = "vosk"
+            mock_args.language = "en-us"
+            mock_args.wayland = False
+            mock_args.kiosk = True
+            mock_args.start_minimized = False
+            mock_args.output_profile = None
+            mock_parse.return_value = mock_args
+
+            main()
+
+        text_callback = mock_speech_instance.register_text_callback.call_args.args[0]
+        text_callback("Hello")
+
+        mock_text_instance.inject_text.assert_not_called()
+        mock_text_instance._copy_to_clipboard.assert_called_once_with("Hello")
+        mock_speech_instance.register_action_callback.assert_not_called()
+        mock_speech_instance.register_partial_callback.assert_not_called()
+        self.assertFalse(mock_speech.call_args.kwargs["intent_commands_enabled"])
+        self.assertTrue(mock_tray.call_args.kwargs["kiosk"])
+
+    @patch("vocalinux.main.check_dependencies")
+    @patch(
+        "vocalinux.speech_recognition.engine_availability.pick_available_engine",
+        return_value="whisper_cpp",
+    )
+    @patch("vocalinux.speech_recognition.recognition_manager._show_notification")
+    @patch("vocalinux.speech_recognition.recognition_manager.SpeechRecognitionManager")
+    @patch("vocalinux.text_injection.text_injector.TextInjector")
+    @patch("vocalinux.ui.tray_indicator.TrayIndicator")
+    @patch("vocalinux.ui.config_manager.ConfigManager")
+    @patch("vocalinux.ui.logging_manager.initialize_logging")
+    def test_missing_engine_falls_back_to_installed_one(
+        self,
+        mock_init_logging,
+        mock_config_manager,
+        mock_tray,
+        mock_text,
+        mock_speech,
+        mock_notify,
+        mock_pick,
+        mock_check_deps,
+    ):
+        """Test an engine that is not installed is replaced for the session."""
+        from vocalinux.speech_recognition.engine_availability import EngineNotInstalledError
+
+        mock_check_deps.return_value = True
+        mock_config_instance = MagicMock()
+        mock_config_instance.get_settings.return_value = {
+            "speech_recognition": {"engine": "vosk"},
+            "general": {"first_run": False},
+        }
+        mock_config_instance.get_model_size_for_engine.return_value = "tiny"
+        mock_config_manager.return_value = mock_config_instance
+        mock_speech.side_effect = [EngineNotInstalledError("vosk"), MagicMock()]
+
+        with patch("vocalinux.main.parse_arguments") as mock_parse, patch.object(
+            sys, "argv", ["vocalinux"]
+        ):
+            mock_args = MagicMock()
+            mock_args.debug = False
+            mock_args.engine = "whisper_cpp"
+            mock_args.model = "tiny"
+            mock_args.language = "en-us"
+            mock_args.start_minimized = False
+            mock_parse.return_value = mock_args
+
+            main()
+
+        self.assertEqual(mock_speech.call_count, 2)
+        self.assertEqual(mock_speech.call_args.kwargs["engine"], "whisper_cpp")
+        self.assertEqual(mock_speech.call_args.kwargs["model_size"], "tiny")
+        mock_pick.assert_called_once_with(exclude=["vosk"])
+        mock_notify.assert_called_once()
+
     @patch("vocalinux.main.check_dependencies")
     @patch("vocalinux.ui.action_handler.ActionHandler")
     @patch("vocalinux.speech_recognition.recognition_manager.SpeechRecognitionManager")
@@ -375,6 +654,7 @@ class TestMainModule(unittest.TestCase):
             mock_args.engine = "vosk"
             mock_args.language = "en-us"
             mock_args.wayland = False
+            mock_args.kiosk = False
             mock_parse.return_value = mock_args
 
             # Create mock loggers
@@ -430,6 +710,7 @@ class TestMainModule(unittest.TestCase):
             mock_args.engine = "vosk"
             mock_args.language = "en-us"
             mock_args.wayland = False
+            mock_args.kiosk = False
             mock_parse.return_value = mock_args
 
             with patch("vocalinux.main.logger"):
@@ -483,6 +764,7 @@ class TestMainModule(unittest.TestCase):
             mock_args.engine = "vosk"
             mock_args.language = "en-us"
             mock_args.wayland = False
+            mock_args.kiosk = False
             mock_args.start_minimized = True
             mock_parse.return_value = mock_args
 
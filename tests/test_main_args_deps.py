@@ -276,12 +276,39 @@ class TestMainFunction(unittest.TestCase):
 
         mock_single_instance = MagicMock()
         mock_single_instance.acquire_lock.return_value = False
+        mock_single_instance.signal_running_instance.return_value = False
 
         with patch.dict(sys.modules, {"vocalinux.single_instance": mock_single_instance}):
             with pytest.raises(SystemExit) as exc_info:
                 main()
             assert exc_info.value.code == 1
 
+    @patch("vocalinux.main.logging")
+    @patch("vocalinux.main.check_dependencies")
+    @patch("vocalinux.main.check_display_available")
+    @patch("vocalinux.main.parse_arguments")
+    @patch("vocalinux.main.atexit")
+    def test_main_second_launch_shows_running_instance(
+        self,
+        mock_atexit,
+        mock_parse_args,
+        mock_check_display,
+        mock_check_deps,
+        mock_logging,
+    ):
+        """Test a second launch asks the running instance to show its status window."""
+        from vocalinux.main import main
+
+        mock_single_instance = MagicMock()
+        mock_single_instance.acquire_lock.return_value = False
+        mock_single_instance.signal_running_instance.return_value = True
+
+        with patch.dict(sys.modules, {"vocalinux.single_instance": mock_single_instance}):
+            with pytest.raises(SystemExit) as exc_info:
+                main()
+            assert exc_info.value.code == 0
+        mock_parse_args.assert_not_called()
+
     @patch("vocalinux.main.check_display_available")
     @patch("vocalinux.main.logging")
     @patch("vocalinux.main.check_dependencies")
@@ -86,6 +86,7 @@ class TestMainFunctionExtra:
 
         mock_single_instance = MagicMock()
         mock_single_instance.acquire_lock.return_value = False
+        mock_single_instance.signal_running_instance.return_value = False
 
         with patch.dict(sys.modules, {"vocalinux.single_instance": mock_single_instance}):
             with pytest.raises(SystemExit) as exc_info:
@@ -0,0 +1,137 @@
+"""
+Tests for media ducking.
+"""
+
+import os
+import subprocess
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.utils.media_ducking import (
+    MediaDucker,
+    is_media_ducking_available,
+    parse_sink_inputs,
+)
+
+SINK_INPUTS = f"""Sink Input #41
+\tDriver: protocol-native.c
+\tVolume: front-left: 65536 / 100% / 0.00 dB,   front-right: 52429 /  80% / -5.81 dB
+\t        balance -0.20
+\tProperties:
+\t\tapplication.name = "Firefox"
+\t\tapplication.process.id = "1234"
+\t\tapplication.process.binary = "firefox"
+Sink Input #42
+\tVolume: mono: 65536 / 100% / 0.00 dB
+\tProperties:
+\t\tapplication.process.binary = "paplay"
+Sink Input #43
+\tVolume: mono: 32768 /  50% / -18.06 dB
+\tProperties:
+\t\tapplication.process.id = "{os.getpid()}"
+"""
+
+
+class FakePactl:
+    """Stands in for subprocess.run, tracking stream volumes like pactl would."""
+
+    def __init__(self, output=SINK_INPUTS):
+        self.output = output
+        self.volume_calls = []
+
+    def __call__(self, args, **kwargs):
+        if args[1] == "list":
+            return subprocess.CompletedProcess(args, 0, stdout=self.output, stderr="")
+        self.volume_calls.append(args[2:])
+        return subprocess.CompletedProcess(args, 0, stdout="", stderr="")
+
+
+class TestParseSinkInputs(unittest.TestCase):
+    """Tests for parsing pactl output."""
+
+    def test_parses_streams(self):
+        streams = parse_sink_inputs(SINK_INPUTS)
+        self.assertEqual([stream["index"] for stream in streams], [41, 42, 43])
+        self.assertEqual(streams[0]["volumes"], [65536, 52429])
+        self.assertEqual(streams[0]["properties"]["application.name"], "Firefox")
+        self.assertEqual(streams[1]["volumes"], [65536])
+
+    def test_empty_output(self):
+        self.assertEqual(parse_sink_inputs(""), [])
+
+
+class TestMediaDucker(unittest.TestCase):
+    """Tests for MediaDucker."""
+
+    def test_duck_skips_own_sounds(self):
+        pactl = FakePactl()
+        ducker = MediaDucker(level=0.5, run=pactl)
+
+        self.assertEqual(ducker.duck(), 1)
+        self.assertTrue(ducker.active)
+        self.assertEqual(pactl.volume_calls, [["41", "32768", "26214"]])
+
+    def test_duck_skips_readback_voice(self):
+        pactl = FakePactl(
+            SINK_INPUTS
+            + "Sink Input #44\n\tVolume: mono: 65536 / 100% / 0.00 dB\n\tProperties:\n"
+            + '\t\tapplication.process.binary = "espeak-ng"\n'
+        )
+        ducker = MediaDucker(level=0.5, run=pactl)
+
+        self.assertEqual(ducker.duck(), 1)
+        self.assertEqual([call[0] for call in pactl.volume_calls], ["41"])
+
+    def test_duck_twice_is_noop(self):
+        pactl = FakePactl()
+        ducker = MediaDucker(level=0.5, run=pactl)
+        ducker.duck()
+        ducker.duck()
+        self.assertEqual(len(pactl.volume_calls), 1)
+
+    def test_duck_again_lowers_new_streams(self):
+        pactl = FakePactl()
+        ducker = MediaDucker(level=0.5, run=pactl)
+        ducker.duck()
+        pactl.output += "Sink Input #44\n\tVolume: mono: 65536 / 100% / 0.00 dB\n"
+
+        self.assertEqual(ducker.duck(), 2)
+        self.assertEqual(pactl.volume_calls[-1], ["44", "32768"])
+        self.assertEqual(len(pactl.volume_calls), 2)
+
+    def test_restore_puts_volume_back(self):
+        pactl = FakePactl()
+        ducker = MediaDucker(level=0.5, run=pactl)
+        ducker.duck()
+        pactl.output = SINK_INPUTS.replace("65536 / 100%", "32768 / 50%", 1).replace(
+            "52429 /  80%", "26214 / 40%"
+        )
+
+        ducker.restore()
+        self.assertEqual(pactl.volume_calls[-1], ["41", "65536", "52429"])
+        self.assertFalse(ducker.active)
+
+    def test_restore_leaves_user_changes(self):
+        pactl = FakePactl()
+        ducker = MediaDucker(level=0.5, run=pactl)
+        ducker.duck()
+
+        # The stream still reports its original volume, as if the user raised it
+        ducker.restore()
+        self.assertEqual(len(pactl.volume_calls), 1)
+        self.assertFalse(ducker.active)
+
+    def test_level_is_clamped(self):
+        self.assertEqual(MediaDucker(level=1.5).level, 1.0)
+        self.assertEqual(MediaDucker(level=-1).level, 0.0)
+
+    def test_missing_pactl(self):
+        ducker = MediaDucker(run=MagicMock(side_effect=FileNotFoundError("pactl")))
+        self.assertEqual(ducker.duck(), 0)
+        self.assertFalse(ducker.active)
+        with patch("shutil.which", return_value=None):
+            self.assertFalse(is_media_ducking_available())
+
+
+if __name__ == "__main__":
+    unittest.main()
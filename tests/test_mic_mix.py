@@ -0,0 +1,92 @@
+"""
+Tests for mixing extra microphones into the capture.
+"""
+
+import threading
+import unittest
+from unittest.mock import MagicMock
+
+import numpy as np
+
+from vocalinux.speech_recognition.mic_mix import MAX_MIX_LAG_SECONDS, MicMixer, _ExtraInput
+from vocalinux.speech_recognition.recognition_manager import CaptureFormat
+
+
+def _pcm(*samples):
+    return np.array(samples, dtype=np.int16).tobytes()
+
+
+def _samples(data):
+    return np.frombuffer(data, dtype=np.int16).tolist()
+
+
+def _extra(capture_format=None, gain=1.0):
+    return _ExtraInput(
+        "Desk Mic", MagicMock(), capture_format or CaptureFormat(16000, 1), gain, 1024
+    )
+
+
+class TestExtraInput(unittest.TestCase):
+    """Tests for the queue of one extra microphone."""
+
+    def test_converts_and_queues(self):
+        extra = _extra(CaptureFormat(16000, 2, "int16"), gain=2.0)
+        extra.feed(_pcm(100, 300, -100, -300))
+        self.assertIsNone(extra.take(6))
+        self.assertEqual(_samples(extra.take(4)), [400, -400])
+
+    def test_lag_is_bounded(self):
+        extra = _extra()
+        limit = int(16000 * MAX_MIX_LAG_SECONDS)
+        extra.feed(np.arange(limit + 10, dtype=np.int16).tobytes())
+        self.assertEqual(_samples(extra.take(4)), [10, 11])
+        self.assertEqual(len(extra.take(2 * (limit - 2))), 2 * (limit - 2))
+
+    def test_read_error_stops_the_thread(self):
+        extra = _extra()
+        extra._stream.read.side_effect = OSError("Device unavailable")
+        extra.start()
+        extra._thread.join(timeout=1.0)
+        self.assertFalse(extra._thread.is_alive())
+        extra.stop()
+        extra._stream.close.assert_called_once()
+
+
+class TestMicMixer(unittest.TestCase):
+    """Tests for MicMixer."""
+
+    def test_averages_the_microphones_that_have_audio(self):
+        mixer = MicMixer()
+        desk, webcam = _extra(), _extra()
+        mixer._inputs = [desk, webcam]
+
+        self.assertEqual(_samples(mixer.mix(_pcm(1000, -1000))), [1000, -1000])
+
+        desk.feed(_pcm(3000, 1000))
+        self.assertEqual(_samples(mixer.mix(_pcm(1000, -1000))), [2000, 0])
+
+        desk.feed(_pcm(3000, 1000))
+        webcam.feed(_pcm(-4000, 30000))
+        self.assertEqual(_samples(mixer.mix(_pcm(1000, -1000))), [0, 10000])
+
+    def test_add_and_stop(self):
+        stream = MagicMock()
+        read = threading.Event()
+
+        def read_chunk(frames, exception_on_overflow=True):
+            read.set()
+            return _pcm(*[500] * frames)
+
+        stream.read.side_effect = read_chunk
+        mixer = MicMixer()
+        mixer.add("Desk Mic", stream, CaptureFormat(16000, 1), frames_per_buffer=4)
+        self.assertTrue(read.wait(1.0))
+        self.assertEqual(mixer.device_names, ["Desk Mic"])
+
+        mixer.stop()
+        stream.close.assert_called_once()
+        self.assertEqual(mixer.device_names, [])
+
+
+if __name__ == "__main__":
+    unittest.main()
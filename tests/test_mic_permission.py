@@ -0,0 +1,44 @@
+"""
+Tests for the microphone permission pre-flight.
+"""
+
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.utils.mic_permission import MicrophonePermission, denied_message
+
+
+class TestMicrophonePermission(unittest.TestCase):
+    """Tests for MicrophonePermission."""
+
+    def test_not_sandboxed_never_asks(self):
+        request = MagicMock()
+        permission = MicrophonePermission(request=request, sandboxed=lambda: False)
+        self.assertTrue(permission.check())
+        request.assert_not_called()
+
+    def test_grant_is_remembered(self):
+        request = MagicMock(return_value=True)
+        permission = MicrophonePermission(request=request, sandboxed=lambda: True)
+        self.assertTrue(permission.check())
+        self.assertTrue(permission.check())
+        request.assert_called_once()
+
+    def test_denial_is_asked_again(self):
+        request = MagicMock(side_effect=[False, True])
+        permission = MicrophonePermission(request=request, sandboxed=lambda: True)
+        self.assertFalse(permission.check())
+        self.assertTrue(permission.check())
+        self.assertEqual(request.call_count, 2)
+
+    def test_unreachable_portal_lets_capture_try(self):
+        permission = MicrophonePermission(request=lambda: None, sandboxed=lambda: True)
+        self.assertTrue(permission.check())
+
+    def test_denied_message_names_the_app(self):
+        with patch.dict("os.environ", {"FLATPAK_ID": "org.example.Vocalinux"}):
+            self.assertIn("microphone org.example.Vocalinux yes", denied_message())
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,95 @@
+"""
+Tests for downloaded model listing and cleanup.
+"""
+
+import os
+import tempfile
+import unittest
+
+from vocalinux.utils.model_storage import (
+    InstalledModel,
+    configured_model_path,
+    delete_model,
+    format_bytes,
+    list_installed_models,
+    unused_models,
+)
+
+
+def _write(path, size):
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "wb") as f:
+        f.write(b"\0" * size)
+
+
+class TestModelStorage(unittest.TestCase):
+    """Tests for the model storage helpers."""
+
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.root = self._tmp.name
+        _write(os.path.join(self.root, "vosk-model-small-en-us-0.15", "am", "final.mdl"), 300)
+        _write(os.path.join(self.root, "vosk-model-small-en-us-0.15", "conf", "model.conf"), 20)
+        _write(os.path.join(self.root, "whisper", "base.pt"), 500)
+        _write(os.path.join(self.root, "whispercpp", "ggml-tiny.bin"), 100)
+        _write(os.path.join(self.root, "whispercpp", "ggml-base.bin.tmp"), 50)
+        _write(os.path.join(self.root, "notes.txt"), 10)
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def test_lists_models_with_sizes(self):
+        models = list_installed_models(self.root)
+        self.assertEqual(
+            [(m.engine, m.name, m.size_bytes) for m in models],
+            [
+                ("vosk", "vosk-model-small-en-us-0.15", 320),
+                ("whisper", "base", 500),
+                ("whisper_cpp", "tiny", 100),
+            ],
+        )
+
+    def test_missing_directory_lists_nothing(self):
+        self.assertEqual(list_installed_models(os.path.join(self.root, "missing")), [])
+
+    def test_configured_model_path(self):
+        self.assertEqual(
+            configured_model_path("vosk", "small", "en-us", self.root),
+            os.path.join(self.root, "vosk-model-small-en-us-0.15"),
+        )
+        self.assertEqual(
+            configured_model_path("whisper", "base", root=self.root),
+            os.path.join(self.root, "whisper", "base.pt"),
+        )
+        self.assertEqual(
+            configured_model_path("whisper_cpp", "tiny", root=self.root),
+            os.path.join(self.root, "whispercpp", "ggml-tiny.bin"),
+        )
+        self.assertIsNone(configured_model_path("remote_api", "small", root=self.root))
+        self.assertIsNone(configured_model_path(None, None, root=self.root))
+
+    def test_unused_models_keep_the_configured_ones(self):
+        models = list_installed_models(self.root)
+        in_use = [
+            configured_model_path("whisper_cpp", "tiny", root=self.root),
+            configured_model_path("vosk", "small", "en-us", self.root),
+            None,
+        ]
+        self.assertEqual([m.name for m in unused_models(models, in_use)], ["base"])
+
+    def test_delete_model(self):
+        for model in list_installed_models(self.root):
+            self.assertTrue(delete_model(model))
+            self.assertFalse(os.path.exists(model.path))
+        self.assertEqual(list_installed_models(self.root), [])
+        gone = InstalledModel("whisper", "base", os.path.join(self.root, "gone.pt"), 1)
+        self.assertFalse(delete_model(gone))
+
+    def test_format_bytes(self):
+        self.assertEqual(format_bytes(0), "0 KB")
+        self.assertEqual(format_bytes(466_000_000), "466 MB")
+        self.assertEqual(format_bytes(1_500_000_000), "1.5 GB")
+
+
+if __name__ == "__main__":
+    unittest.main()
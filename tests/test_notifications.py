@@ -0,0 +1,170 @@
+"""
+Tests for rate-limited desktop notifications.
+"""
+
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.utils.notifications import (
+    NotificationManager,
+    send_action_notification,
+    send_notification,
+    wait_for_action,
+)
+
+
+class FakeClock:
+    """Manually advanced monotonic clock."""
+
+    def __init__(self):
+        self.now = 1000.0
+
+    def __call__(self):
+        return self.now
+
+
+class TestNotificationManager(unittest.TestCase):
+    """Tests for NotificationManager."""
+
+    def setUp(self):
+        self.send = MagicMock()
+        self.clock = FakeClock()
+        self.manager = NotificationManager(cooldown=30, send=self.send, clock=self.clock)
+        # Keep flush timers from firing on their own; tests call flush() directly
+        timer_patcher = patch("vocalinux.utils.notifications.threading.Timer")
+        self.mock_timer = timer_patcher.start()
+        self.addCleanup(timer_patcher.stop)
+
+    def test_uncategorized_notifications_always_shown(self):
+        for _ in range(3):
+            self.assertTrue(self.manager.notify("Done", "Saved"))
+        self.assertEqual(self.send.call_count, 3)
+
+    def test_repeats_within_cooldown_are_held_back(self):
+        self.assertTrue(self.manager.notify("Error", "first", category="remote"))
+        self.assertFalse(self.manager.notify("Error", "second", category="remote"))
+        self.assertFalse(self.manager.notify("Error", "third", category="remote"))
+
+        self.send.assert_called_once_with("Error", "first", "dialog-warning", tag="remote")
+        self.mock_timer.assert_called_once()
+        self.assertEqual(self.mock_timer.call_args.args[0], 30)
+
+    def test_flush_summarizes_repeats_with_count(self):
+        self.manager.notify("Error", "first", category="remote")
+        self.manager.notify("Error", "second", category="remote")
+        self.manager.notify("Error", "third", category="remote")
+
+        self.assertTrue(self.manager.flush("remote"))
+        self.send.assert_called_with(
+            "Error", "third\n(repeated 2 more times)", "dialog-warning", tag="remote"
+        )
+        # Nothing left to summarize
+        self.assertFalse(self.manager.flush("remote"))
+
+    def test_shown_again_after_cooldown(self):
+        self.manager.notify("Error", "first", category="remote")
+        self.clock.now += 31
+        self.assertTrue(self.manager.notify("Error", "later", category="remote"))
+        self.assertEqual(self.send.call_count, 2)
+
+    def test_categories_cool_down_independently(self):
+        manager = NotificationManager(
+            cooldown=30, cooldowns={"mic": 5}, send=self.send, clock=self.clock
+        )
+        manager.notify("Mic", "gone", category="mic")
+        self.assertTrue(manager.notify("Error", "remote", category="remote"))
+        self.clock.now += 6
+        self.assertTrue(manager.notify("Mic", "gone again", category="mic"))
+        self.assertFalse(manager.notify("Error", "remote again", category="remote"))
+
+    def test_nothing_shown_when_turned_off(self):
+        self.assertTrue(self.manager.notify("Error", "first", category="remote"))
+        self.assertFalse(self.manager.notify("Error", "second", category="remote"))
+        self.manager.enabled = False
+        self.assertFalse(self.manager.notify("Done", "Saved"))
+        self.clock.now += 31
+        self.assertFalse(self.manager.flush("remote"))
+        self.assertEqual(self.send.call_count, 1)
+
+
+class TestSendNotification(unittest.TestCase):
+    """Tests for send_notification."""
+
+    def test_tag_replaces_earlier_notification(self):
+        with patch("subprocess.Popen") as mock_popen:
+            send_notification("Title", "Message", tag="remote")
+        command = mock_popen.call_args.args[0]
+        self.assertIn("string:x-canonical-private-synchronous:vocalinux-remote", command)
+        self.assertEqual(command[-2:], ["Title", "Message"])
+
+    def test_missing_notify_send_is_ignored(self):
+        with patch("subprocess.Popen", side_effect=FileNotFoundError("no notify-send")):
+            send_notification("Title", "Message")
+
+
+
+class TestSendActionNotification(unittest.TestCase):
+    """Tests for send_action_notification."""
+
+    def _send(self, returncode, output, fallback_message=None):
+        on_action = MagicMock()
+        thread = MagicMock()
+        process = MagicMock(returncode=returncode)
+        process.communicate.return_value = (output, "")
+        with patch("threading.Thread", return_value=thread) as mock_thread, patch(
+            "subprocess.Popen", return_value=process
+        ) as mock_popen:
+            send_action_notification(
+                "Title", "Message", "Open", on_action, fallback_message=fallback_message
+            )
+            mock_thread.call_args.kwargs["target"]()
+        return on_action, mock_popen
+
+    def test_clicking_the_button_runs_the_action(self):
+        on_action, mock_popen = self._send(0, "default\n")
+        on_action.assert_called_once_with()
+        command = mock_popen.call_args.args[0]
+        self.assertIn("--action=default=Open", command)
+        self.assertIn("--wait", command)
+
+    def test_closing_without_clicking_does_nothing(self):
+        on_action, mock_popen = self._send(0, "")
+        on_action.assert_not_called()
+        mock_popen.assert_called_once()
+
+    def test_old_notify_send_shows_plain_notification(self):
+        on_action, mock_popen = self._send(1, "", fallback_message="Run the report")
+        on_action.assert_not_called()
+        self.assertEqual(mock_popen.call_args.args[0][-2:], ["Title", "Run the report"])
+
+
+class TestWaitForAction(unittest.TestCase):
+    """Tests for wait_for_action."""
+
+    def test_resident_notification_with_several_buttons(self):
+        process = MagicMock(returncode=0)
+        process.communicate.return_value = ("status\n", "")
+        on_started = MagicMock()
+        with patch("subprocess.Popen", return_value=process) as mock_popen:
+            action = wait_for_action(
+                "Title",
+                "Message",
+                {"toggle": "Start/Stop", "status": "Show Status"},
+                resident=True,
+                on_started=on_started,
+            )
+
+        self.assertEqual(action, "status")
+        on_started.assert_called_once_with(process)
+        command = mock_popen.call_args.args[0]
+        self.assertIn("boolean:resident:true", command)
+        self.assertIn("--action=toggle=Start/Stop", command)
+        self.assertIn("--action=status=Show Status", command)
+
+    def test_missing_notify_send_returns_none(self):
+        with patch("subprocess.Popen", side_effect=FileNotFoundError("no notify-send")):
+            self.assertIsNone(wait_for_action("Title", "Message", {"default": "Open"}))
+
+
+if __name__ == "__main__":
+    unittest.main()
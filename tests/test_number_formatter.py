@@ -0,0 +1,71 @@
+"""
+Tests for number formatting.
+"""
+
+import unittest
+
+from vocalinux.speech_recognition.number_formatter import (
+    NumberFormatter,
+    ordinal_suffix,
+    spoken_to_digits,
+    to_roman,
+)
+
+
+class TestSpokenToDigits(unittest.TestCase):
+    """Tests for converting spoken numbers to digits."""
+
+    def test_digit_by_digit(self):
+        self.assertEqual(spoken_to_digits("four two one"), "421")
+
+    def test_compound_numbers(self):
+        self.assertEqual(spoken_to_digits("forty two"), "42")
+        self.assertEqual(spoken_to_digits("one hundred twenty three"), "123")
+        self.assertEqual(spoken_to_digits("two thousand twenty four"), "2024")
+        self.assertEqual(spoken_to_digits("nineteen eighty four"), "1984")
+
+    def test_decimal_point_and_other_words(self):
+        self.assertEqual(spoken_to_digits("three point one four"), "3.14")
+        self.assertEqual(spoken_to_digits("Call 555-1234."), "5551234")
+
+
+class TestNumberFormatter(unittest.TestCase):
+    """Tests for the optional ordinal, fraction and Roman numeral styles."""
+
+    def test_disabled_by_default(self):
+        text = "the third of three quarters, roman numeral four"
+        self.assertEqual(NumberFormatter().format(text), text)
+
+    def test_ordinals(self):
+        formatter = NumberFormatter(ordinals=True)
+        self.assertEqual(formatter.format("the third time"), "the 3rd time")
+        self.assertEqual(formatter.format("the twenty-first of May"), "the 21st of May")
+        self.assertEqual(formatter.format("The second paragraph"), "The 2nd paragraph")
+        self.assertEqual(formatter.format("wait a second"), "wait a second")
+
+    def test_ordinal_suffix(self):
+        self.assertEqual(
+            [ordinal_suffix(n) for n in (1, 2, 3, 4, 11, 12, 13, 21, 22, 101)],
+            ["st", "nd", "rd", "th", "th", "th", "th", "st", "nd", "st"],
+        )
+
+    def test_fractions(self):
+        formatter = NumberFormatter(fractions=True)
+        self.assertEqual(formatter.format("three quarters of patients"), "3/4 of patients")
+        self.assertEqual(formatter.format("one and a half cups"), "1 1/2 cups")
+        self.assertEqual(formatter.format("a quarter past five"), "a quarter past five")
+
+    def test_fractions_take_precedence_over_ordinals(self):
+        formatter = NumberFormatter(ordinals=True, fractions=True)
+        self.assertEqual(formatter.format("one third of the third group"), "1/3 of the 3rd group")
+
+    def test_roman_numerals(self):
+        formatter = NumberFormatter(roman_numerals=True)
+        self.assertEqual(formatter.format("Title roman numeral seven"), "Title VII")
+        self.assertEqual(formatter.format("roman numeral 1984"), "MCMLXXXIV")
+        self.assertEqual(formatter.format("roman numeral one hundred and five"), "CV")
+
+    def test_to_roman_range(self):
+        self.assertEqual(to_roman(3999), "MMMCMXCIX")
+        with self.assertRaises(ValueError):
+            to_roman(0)
@@ -1,8 +1,11 @@
 """Tests for the XDG base-directory helpers in vocalinux.utils.paths."""
 
 import os
+import tempfile
 from unittest.mock import patch
 
+import pytest
+
 from vocalinux.utils import paths
 
 
@@ -36,3 +39,50 @@ class TestXdgHelpers:
         flatpak_data = "/home/user/.var/app/com.vocalinux.Vocalinux/data"
         with patch.dict(os.environ, {"XDG_DATA_HOME": flatpak_data}, clear=True):
             assert paths.models_dir() == os.path.join(flatpak_data, "vocalinux", "models")
+
+    def test_runtime_dir(self):
+        with patch.dict(os.environ, {"XDG_RUNTIME_DIR": "/run/user/1000"}, clear=True):
+            assert paths.runtime_dir() == "/run/user/1000/vocalinux"
+        with patch.dict(os.environ, {"XDG_RUNTIME_DIR": ""}, clear=True):
+            assert paths.runtime_dir() == os.path.join(
+                tempfile.gettempdir(), f"runtime-{os.getuid()}", "vocalinux"
+            )
+
+    def test_runtime_dirs_differ_between_users(self):
+        # Two accounts on one machine, or one home shared by several machines,
+        # must never share the lock, socket or status file
+        with patch.dict(os.environ, {}, clear=True):
+            with patch("os.getuid", return_value=1000):
+                first = paths.runtime_dir()
+            with patch("os.getuid", return_value=1001):
+                second = paths.runtime_dir()
+        assert first != second
+        with patch.dict(os.environ, {"XDG_DATA_HOME": "/nfs/home/lab/.local/share"}, clear=True):
+            with patch.dict(os.environ, {"XDG_RUNTIME_DIR": "/run/user/1000"}):
+                first = paths.runtime_dir()
+            with patch.dict(os.environ, {"XDG_RUNTIME_DIR": "/run/user/1001"}):
+                second = paths.runtime_dir()
+        assert first != second
+        assert not first.startswith(paths.xdg_data_home())
+
+
+class TestEnsurePrivateDir:
+    def test_creates_directories_only_the_user_can_use(self, tmp_path):
+        path = str(tmp_path / "runtime-1000" / "vocalinux")
+
+        assert paths.ensure_private_dir(path) == path
+        assert os.stat(path).st_mode & 0o777 == 0o700
+        assert os.stat(os.path.dirname(path)).st_mode & 0o777 == 0o700
+
+        os.chmod(path, 0o755)
+        paths.ensure_private_dir(path)
+        assert os.stat(path).st_mode & 0o777 == 0o700
+
+    def test_refuses_another_users_directory(self, tmp_path):
+        path = str(tmp_path / "runtime-1000" / "vocalinux")
+        paths.ensure_private_dir(path)
+
+        # As seen by another account, the directory it didn't create isn't its own
+        with patch("os.getuid", return_value=os.getuid() + 1):
+            with pytest.raises(PermissionError):
+                paths.ensure_private_dir(path)
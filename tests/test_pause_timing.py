@@ -0,0 +1,66 @@
+"""
+Tests for adapting the silence timeout to the speaker.
+"""
+
+import unittest
+
+from vocalinux.speech_recognition.pause_timing import (
+    MAX_ADAPTIVE_TIMEOUT,
+    MIN_ADAPTIVE_TIMEOUT,
+    MIN_PAUSES,
+    PAUSE_HISTORY,
+    AdaptiveSilenceTimeout,
+)
+
+
+class TestAdaptiveSilenceTimeout(unittest.TestCase):
+    """Tests for AdaptiveSilenceTimeout."""
+
+    def test_fixed_timeout_until_enough_pauses(self):
+        timing = AdaptiveSilenceTimeout()
+        for _ in range(MIN_PAUSES - 1):
+            timing.add_pause(0.3)
+        self.assertEqual(timing.timeout(2.0), 2.0)
+        timing.add_pause(0.3)
+        self.assertAlmostEqual(timing.timeout(2.0), 0.5)
+
+    def test_slow_speaker_gets_longer_timeout(self):
+        timing = AdaptiveSilenceTimeout()
+        for pause in (0.8, 1.0, 1.2, 1.4, 1.6, 1.8, 2.0, 2.2, 2.4, 2.6):
+            timing.add_pause(pause)
+        self.assertAlmostEqual(timing.timeout(2.0), 3.9)
+
+    def test_fast_speaker_gets_shorter_timeout(self):
+        timing = AdaptiveSilenceTimeout()
+        for pause in (0.2, 0.3, 0.4, 0.5, 0.6):
+            timing.add_pause(pause)
+        self.assertAlmostEqual(timing.timeout(2.0), 0.9)
+
+    def test_timeout_is_bounded(self):
+        timing = AdaptiveSilenceTimeout()
+        for _ in range(MIN_PAUSES):
+            timing.add_pause(4.5)
+        self.assertEqual(timing.timeout(2.0), MAX_ADAPTIVE_TIMEOUT)
+        timing.reset()
+        for _ in range(MIN_PAUSES):
+            timing.add_pause(0.16)
+        self.assertEqual(timing.timeout(2.0), MIN_ADAPTIVE_TIMEOUT)
+
+    def test_gaps_inside_words_ignored(self):
+        timing = AdaptiveSilenceTimeout()
+        for _ in range(MIN_PAUSES):
+            timing.add_pause(0.05)
+        self.assertEqual(timing.pause_count, 0)
+
+    def test_only_recent_pauses_count(self):
+        timing = AdaptiveSilenceTimeout()
+        for _ in range(PAUSE_HISTORY):
+            timing.add_pause(3.0)
+        for _ in range(PAUSE_HISTORY):
+            timing.add_pause(0.4)
+        self.assertEqual(timing.pause_count, PAUSE_HISTORY)
+        self.assertAlmostEqual(timing.timeout(2.0), 0.6)
+
+
+if __name__ == "__main__":
+    unittest.main()
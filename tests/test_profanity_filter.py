@@ -0,0 +1,43 @@
+"""
+Tests for profanity filtering.
+"""
+
+import unittest
+
+from vocalinux.speech_recognition.profanity_filter import ProfanityFilter
+
+
+class TestProfanityFilter(unittest.TestCase):
+    """Tests for masking and removing profanity."""
+
+    def test_off_leaves_text_unchanged(self):
+        self.assertEqual(ProfanityFilter().apply("oh shit"), "oh shit")
+
+    def test_mask_keeps_first_letter(self):
+        profanity_filter = ProfanityFilter("mask")
+        self.assertEqual(profanity_filter.apply("Shit, the build failed"), "S***, the build failed")
+
+    def test_remove_tidies_spacing(self):
+        profanity_filter = ProfanityFilter("remove")
+        self.assertEqual(profanity_filter.apply("this is fucking great, shit."), "this is great.")
+
+    def test_whole_words_only(self):
+        profanity_filter = ProfanityFilter("mask")
+        text = "Scunthorpe passed the class"
+        self.assertEqual(profanity_filter.apply(text), text)
+
+    def test_language_lists(self):
+        profanity_filter = ProfanityFilter("mask")
+        self.assertEqual(profanity_filter.apply("merde", "fr"), "m****")
+        self.assertEqual(profanity_filter.apply("merde", "en-us"), "merde")
+        self.assertEqual(profanity_filter.apply("merde and shit", "auto"), "m**** and s***")
+
+    def test_custom_words(self):
+        profanity_filter = ProfanityFilter("mask", ["frak"])
+        self.assertEqual(profanity_filter.apply("frak this"), "f*** this")
+        profanity_filter.custom_words = []
+        self.assertEqual(profanity_filter.apply("frak this"), "frak this")
+
+    def test_unknown_mode_rejected(self):
+        with self.assertRaises(ValueError):
+            ProfanityFilter("bleep")
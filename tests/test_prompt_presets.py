@@ -0,0 +1,46 @@
+"""
+Tests for the Whisper prompt presets picked by the focused application.
+"""
+
+import unittest
+
+from vocalinux.speech_recognition.prompt_presets import BUILTIN_PROMPT_PRESETS, AppPrompts
+
+
+class TestAppPrompts(unittest.TestCase):
+    """Tests for matching app rules to prompt presets."""
+
+    def test_no_rules(self):
+        prompts = AppPrompts()
+        self.assertFalse(prompts.enabled)
+        self.assertEqual(prompts.prompt_for("gnome-terminal-server"), "")
+
+    def test_rule_matches_window_class(self):
+        prompts = AppPrompts(rules={"Terminal": "shell", "thunderbird": "email"})
+        self.assertTrue(prompts.enabled)
+        self.assertEqual(prompts.preset_for("Gnome-terminal-server"), "shell")
+        self.assertEqual(prompts.prompt_for("Thunderbird"), BUILTIN_PROMPT_PRESETS["email"])
+        self.assertIsNone(prompts.preset_for("firefox"))
+        self.assertIsNone(prompts.preset_for(None))
+
+    def test_first_matching_rule_wins(self):
+        prompts = AppPrompts(rules={"code": "shell", "vscode": "email"})
+        self.assertEqual(prompts.preset_for("vscode"), "shell")
+
+    def test_custom_presets(self):
+        prompts = AppPrompts(
+            presets={"Chat": "lol, brb, see you tomorrow", "shell": "$ make test"},
+            rules={"element": "chat", "konsole": "shell"},
+        )
+        self.assertEqual(prompts.prompt_for("Element"), "lol, brb, see you tomorrow")
+        self.assertEqual(prompts.prompt_for("konsole"), "$ make test")
+        self.assertIn("email", prompts.presets)
+
+    def test_unknown_preset_is_ignored(self):
+        with self.assertLogs("vocalinux.speech_recognition.prompt_presets", "WARNING"):
+            prompts = AppPrompts(rules={"firefox": "forum", " ": "shell"})
+        self.assertFalse(prompts.enabled)
+
+
+if __name__ == "__main__":
+    unittest.main()
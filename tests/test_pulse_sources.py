@@ -0,0 +1,132 @@
+"""
+Tests for finding PulseAudio/PipeWire monitor sources.
+"""
+
+import subprocess
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.utils.pulse_sources import (
+    find_app_stream,
+    is_app_stream,
+    is_monitor_source,
+    is_source_muted,
+    list_monitor_sources,
+    list_playback_apps,
+    parse_sources,
+)
+
+PACTL_SOURCES = """Source #55
+\tState: SUSPENDED
+\tName: alsa_output.pci-0000_00_1f.3.analog-stereo.monitor
+\tDescription: Monitor of Built-in Audio Analog Stereo
+\tDriver: PipeWire
+\tMonitor of Sink: alsa_output.pci-0000_00_1f.3.analog-stereo
+\tProperties:
+\t\tdevice.class = "monitor"
+
+Source #56
+\tState: RUNNING
+\tName: alsa_input.usb-Blue_Yeti-00.analog-stereo
+\tDescription: Yeti Stereo Microphone Analog Stereo
+\tDriver: PipeWire
+\tMonitor of Sink: n/a
+"""
+
+PACTL_SINK_INPUTS = """Sink Input #101
+\tDriver: PipeWire
+\tSink: 54
+\tProperties:
+\t\tapplication.name = "Firefox"
+\t\tmedia.name = "Weekly sync - YouTube"
+\t\tobject.serial = "812"
+
+Sink Input #102
+\tDriver: PipeWire
+\tSink: 54
+\tProperties:
+\t\tapplication.name = "ZOOM VoiceEngine"
+\t\tmedia.name = "playStream"
+\t\tobject.serial = "940"
+
+Sink Input #103
+\tDriver: PipeWire
+\tSink: 54
+\tProperties:
+\t\tapplication.name = "Firefox"
+\t\tmedia.name = "Meet - Standup"
+\t\tobject.serial = "1207"
+"""
+
+
+def _run(stdout="", returncode=0):
+    return MagicMock(
+        return_value=subprocess.CompletedProcess(
+            ["pactl"], returncode, stdout=stdout, stderr="no server"
+        )
+    )
+
+
+class TestPulseSources(unittest.TestCase):
+    """Tests for the monitor source helpers."""
+
+    def test_parse_sources_flags_monitors(self):
+        sources = parse_sources(PACTL_SOURCES)
+        self.assertEqual([s["monitor"] for s in sources], [True, False])
+        self.assertEqual(sources[0]["description"], "Monitor of Built-in Audio Analog Stereo")
+        self.assertEqual(sources[1]["name"], "alsa_input.usb-Blue_Yeti-00.analog-stereo")
+
+    def test_list_monitor_sources(self):
+        run = _run(PACTL_SOURCES)
+        self.assertEqual(
+            list_monitor_sources(run),
+            [
+                (
+                    "alsa_output.pci-0000_00_1f.3.analog-stereo.monitor",
+                    "Monitor of Built-in Audio Analog Stereo",
+                )
+            ],
+        )
+        self.assertEqual(run.call_args[0][0], ["pactl", "list", "sources"])
+
+    def test_list_monitor_sources_without_pactl(self):
+        self.assertEqual(list_monitor_sources(_run(returncode=1)), [])
+        self.assertEqual(list_monitor_sources(MagicMock(side_effect=FileNotFoundError)), [])
+
+    def test_is_source_muted(self):
+        run = _run("Mute: yes\n")
+        self.assertTrue(is_source_muted(run=run))
+        self.assertEqual(run.call_args[0][0], ["pactl", "get-source-mute", "@DEFAULT_SOURCE@"])
+
+        run = _run("Mute: no\n")
+        self.assertFalse(is_source_muted("alsa_input.usb-Blue_Yeti-00.analog-stereo", run))
+        self.assertEqual(run.call_args[0][0][-1], "alsa_input.usb-Blue_Yeti-00.analog-stereo")
+
+    def test_is_source_muted_unknown(self):
+        self.assertIsNone(is_source_muted(run=_run(returncode=1)))
+        self.assertIsNone(is_source_muted(run=MagicMock(side_effect=FileNotFoundError)))
+        self.assertIsNone(is_source_muted(run=_run("No valid command specified.\n")))
+
+    def test_is_monitor_source(self):
+        self.assertTrue(is_monitor_source("alsa_output.pci.analog-stereo.monitor"))
+        self.assertFalse(is_monitor_source("USB Microphone"))
+        self.assertFalse(is_monitor_source(None))
+
+    def test_list_playback_apps(self):
+        run = _run(PACTL_SINK_INPUTS)
+        self.assertEqual(list_playback_apps(run), ["Firefox", "ZOOM VoiceEngine"])
+        self.assertEqual(run.call_args[0][0], ["pactl", "list", "sink-inputs"])
+        # Plain PulseAudio reports no object.serial to record a stream by
+        self.assertEqual(list_playback_apps(_run(PACTL_SINK_INPUTS.replace("object", "x"))), [])
+        self.assertEqual(list_playback_apps(_run(returncode=1)), [])
+
+    def test_find_app_stream_picks_newest(self):
+        self.assertEqual(find_app_stream("Firefox", _run(PACTL_SINK_INPUTS)), "1207")
+        self.assertEqual(find_app_stream("ZOOM VoiceEngine", _run(PACTL_SINK_INPUTS)), "940")
+        self.assertIsNone(find_app_stream("Spotify", _run(PACTL_SINK_INPUTS)))
+        self.assertTrue(is_app_stream("app:Firefox"))
+        self.assertFalse(is_app_stream("alsa_output.pci.analog-stereo.monitor"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,53 @@
+"""
+Tests for auto-punctuation levels.
+"""
+
+import unittest
+
+from vocalinux.speech_recognition.punctuation import (
+    restore_punctuation,
+    strip_punctuation,
+    valid_punctuation_level,
+)
+
+
+class TestPunctuationLevels(unittest.TestCase):
+    """Tests for validating the punctuation level."""
+
+    def test_valid_punctuation_level(self):
+        self.assertEqual(valid_punctuation_level("off"), "off")
+        self.assertIsNone(valid_punctuation_level(None))
+        self.assertIsNone(valid_punctuation_level("loud"))
+
+
+class TestStripPunctuation(unittest.TestCase):
+    """Tests for strip_punctuation."""
+
+    def test_removes_sentence_punctuation(self):
+        self.assertEqual(strip_punctuation("Hello, world. How are you?"), "Hello world How are you")
+
+    def test_keeps_numbers_and_words(self):
+        self.assertEqual(
+            strip_punctuation("It's 3.5 kg at 10:30, isn't it?"), "It's 3.5 kg at 10:30 isn't it"
+        )
+
+    def test_keeps_line_breaks(self):
+        self.assertEqual(strip_punctuation("one.\ntwo!"), "one\ntwo")
+
+
+class TestRestorePunctuation(unittest.TestCase):
+    """Tests for restore_punctuation."""
+
+    def test_statement(self):
+        self.assertEqual(restore_punctuation("the meeting is at noon"), "The meeting is at noon.")
+
+    def test_question(self):
+        self.assertEqual(restore_punctuation("where is the file"), "Where is the file?")
+
+    def test_already_punctuated(self):
+        self.assertEqual(restore_punctuation("see you soon,"), "see you soon,")
+        self.assertEqual(restore_punctuation(""), "")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -64,6 +64,7 @@ class TestSpeechRecognition(unittest.TestCase):
         mock_audio_feedback.play_start_sound.reset_mock()
         mock_audio_feedback.play_stop_sound.reset_mock()
         mock_audio_feedback.play_error_sound.reset_mock()
+        mock_audio_feedback.play_utterance_sound.reset_mock()
 
         # Patch os.makedirs to avoid creating directories
         self.patcher_makedirs = patch("os.makedirs")
@@ -272,6 +273,37 @@ class TestSpeechRecognition(unittest.TestCase):
             # Restore the original method
             manager._process_final_buffer = original_process
 
+    def test_whisper_model_downgraded_to_keep_gpu_memory_free(self):
+        """Test a Whisper model that would break the VRAM reserve is swapped for a smaller one."""
+        whisper_mock = MagicMock()
+        torch_mock = MagicMock()
+        torch_mock.cuda.is_available.return_value = True
+
+        with patch.dict("sys.modules", {"whisper": whisper_mock, "torch": torch_mock}), patch(
+            "vocalinux.speech_recognition.recognition_manager.total_vram_mb", return_value=8192
+        ), patch("vocalinux.speech_recognition.recognition_manager._show_notification") as notify:
+            manager = SpeechRecognitionManager(
+                engine="whisper", model_size="medium", gpu_memory_reserve_mb=4096
+            )
+            self.assertEqual(manager.model_size, "small")
+            self.assertEqual(whisper_mock.load_model.call_args.args[0], "small")
+            self.assertEqual(whisper_mock.load_model.call_args.kwargs["device"], "cuda")
+            notify.assert_called_once()
+
+            # Settings still say "medium"; that is not a change that reloads the model
+            manager.reconfigure(model_size="medium", force_download=False)
+            self.assertEqual(whisper_mock.load_model.call_count, 1)
+
+            # Without the reserve the configured model is loaded again
+            manager.reconfigure(gpu_memory_reserve_mb=0, force_download=False)
+            self.assertEqual(manager.model_size, "medium")
+            self.assertEqual(whisper_mock.load_model.call_args.args[0], "medium")
+
+            # When not even the smallest model fits, Whisper stays off the GPU
+            manager.reconfigure(gpu_memory_reserve_mb=8000, force_download=False)
+            self.assertEqual(manager.model_size, "medium")
+            self.assertEqual(whisper_mock.load_model.call_args.kwargs["device"], "cpu")
+
     def test_vosk_model_path(self):
         """Test model path generation."""
         # Disable our path mock to test actual implementation
@@ -488,6 +520,892 @@ class TestSpeechRecognition(unittest.TestCase):
         manager.stop_recognition()
         self.assertEqual(manager.state, RecognitionState.IDLE)
 
+    def test_cancel_utterance_when_idle(self):
+        """Test cancelling with nothing recording does nothing."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        self.assertFalse(manager.cancel_utterance())
+
+    def test_cancel_utterance_discards_pending_audio(self):
+        """Test cancelling drops buffered and queued audio but keeps listening."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager.state = RecognitionState.LISTENING
+        manager.should_record = True
+        manager.audio_buffer = [b"data", b"data"]
+        manager._recording_segment_has_speech = True
+        manager._segment_queue.put_nowait([b"queued"])
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            self.assertTrue(manager.cancel_utterance())
+
+        self.assertEqual(manager.audio_buffer, [])
+        self.assertFalse(manager._recording_segment_has_speech)
+        self.assertTrue(manager._segment_queue.empty())
+        self.assertTrue(manager.should_record)
+        mock_notify.assert_called_once()
+
+    def test_panic_stop_discards_everything(self):
+        """Test a panic stop drops all audio, goes idle and resets the HTTP session."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager.state = RecognitionState.LISTENING
+        manager.should_record = True
+        manager.audio_buffer = [b"data", b"data"]
+        manager._pending_fragment = "held back"
+        manager._segment_queue.put_nowait([b"queued"])
+        old_session = MagicMock()
+        manager._http_session = old_session
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            self.assertTrue(manager.panic_stop())
+
+        self.assertFalse(manager.should_record)
+        self.assertEqual(manager.audio_buffer, [])
+        self.assertEqual(manager._pending_fragment, "")
+        # Only the stop signal for the recognition thread is left
+        self.assertIsNone(manager._segment_queue.get_nowait())
+        self.assertTrue(manager._segment_queue.empty())
+        self.assertEqual(manager.state, RecognitionState.IDLE)
+        old_session.close.assert_called_once()
+        self.assertIsNot(manager._http_session, old_session)
+        mock_notify.assert_called_once()
+
+    def test_panic_stop_when_idle(self):
+        """Test a panic stop while idle reports nothing was active."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            self.assertFalse(manager.panic_stop())
+        mock_notify.assert_not_called()
+
+    def test_shutdown_stops_and_releases_resources(self):
+        """Test shutdown finishes dictation, restores ducked media and closes the session."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        executor = MagicMock()
+        manager._ducking_executor = executor
+        manager._echo_canceller = MagicMock()
+        session = MagicMock()
+        manager._http_session = session
+
+        with patch.object(manager, "stop_recognition") as mock_stop:
+            manager.shutdown()
+
+        mock_stop.assert_called_once()
+        # Waiting lets the queued restore of ducked media finish
+        executor.shutdown.assert_called_once_with(wait=True)
+        self.assertIsNone(manager._ducking_executor)
+        manager._echo_canceller.stop.assert_called_once()
+        session.close.assert_called_once()
+        self.assertIsNone(manager._http_session)
+
+    def test_panic_stop_discards_in_flight_transcription(self):
+        """Test text decoded across a panic stop is never emitted."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager._voice_commands_enabled = False
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        def finish_after_panic():
+            manager.panic_stop()
+            return '{"text": "should not be typed"}'
+
+        self.recognizerMock.FinalResult.side_effect = finish_after_panic
+        manager._process_audio_buffer([b"data"])
+
+        text_callback.assert_not_called()
+
+    def _partial_ready_manager(self):
+        """A VOSK manager recording a second of speech with partial results on."""
+        manager = SpeechRecognitionManager(engine="vosk", partial_results=True)
+        manager._voice_commands_enabled = False
+        manager.should_record = True
+        manager._recording_segment_has_speech = True
+        manager.audio_buffer = [b"\x00" * 32000]
+        return manager
+
+    def test_partial_result_emitted_while_recording(self):
+        """Test the segment being recorded is decoded and reported as a partial."""
+        manager = self._partial_ready_manager()
+        partial_callback = MagicMock()
+        manager.register_partial_callback(partial_callback)
+
+        self.recognizerMock.FinalResult.return_value = '{"text": "hello wor"}'
+        manager._maybe_emit_partial()
+        partial_callback.assert_called_once_with("hello wor")
+
+        # Rate-limited: nothing new until the interval has passed
+        manager._maybe_emit_partial()
+        partial_callback.assert_called_once()
+
+    def test_partial_results_off_or_remote(self):
+        """Test no partials are decoded when disabled or for remote engines."""
+        manager = self._partial_ready_manager()
+        partial_callback = MagicMock()
+        manager.register_partial_callback(partial_callback)
+
+        manager.reconfigure(partial_results=False, force_download=False)
+        manager._maybe_emit_partial()
+        manager.partial_results = True
+        manager.engine = "remote_api"
+        manager._maybe_emit_partial()
+
+        partial_callback.assert_not_called()
+
+    def test_partial_withdrawn_when_final_is_empty(self):
+        """Test a partial is withdrawn when the final transcript has no words."""
+        manager = self._partial_ready_manager()
+        partial_callback = MagicMock()
+        text_callback = MagicMock()
+        manager.register_partial_callback(partial_callback)
+        manager.register_text_callback(text_callback)
+
+        self.recognizerMock.FinalResult.return_value = '{"text": "uh"}'
+        manager._maybe_emit_partial()
+        self.recognizerMock.FinalResult.return_value = '{"text": ""}'
+        manager._process_audio_buffer([b"data"])
+
+        self.assertEqual([c.args[0] for c in partial_callback.call_args_list], ["uh", ""])
+        text_callback.assert_not_called()
+
+    def test_final_text_replaces_partial(self):
+        """Test the final text is delivered and the partial is not withdrawn."""
+        manager = self._partial_ready_manager()
+        partial_callback = MagicMock()
+        text_callback = MagicMock()
+        manager.register_partial_callback(partial_callback)
+        manager.register_text_callback(text_callback)
+
+        self.recognizerMock.FinalResult.return_value = '{"text": "hello wor"}'
+        manager._maybe_emit_partial()
+        self.recognizerMock.FinalResult.return_value = '{"text": "hello world"}'
+        manager._process_audio_buffer([b"data"])
+
+        partial_callback.assert_called_once_with("hello wor")
+        text_callback.assert_called_once_with("hello world")
+        self.assertEqual(manager._partial_text, "")
+
+    def test_comparison_engine_reports_both_transcripts(self):
+        """Test comparison mode decodes with both engines but only types the primary text."""
+        manager = SpeechRecognitionManager(engine="vosk", comparison_engine="whisper_cpp")
+        manager._voice_commands_enabled = False
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+        comparison_callback = MagicMock()
+        manager.register_comparison_callback(comparison_callback)
+        comparison_manager = MagicMock(_model_initialized=True)
+        comparison_manager._transcribe_buffer.return_value = "hello world"
+        self.recognizerMock.FinalResult.return_value = '{"text": "hello word"}'
+
+        with patch.object(manager, "_get_comparison_manager", return_value=comparison_manager):
+            manager._process_audio_buffer([b"data"])
+            text_callback.assert_called_once_with("hello word")
+            comparison_callback.assert_not_called()
+
+            # Run the background decode (threads are mocked in these tests)
+            thread_kwargs = self.threadMock.call_args.kwargs
+            thread_kwargs["target"](*thread_kwargs["args"])
+
+        primary, comparison = comparison_callback.call_args.args
+        self.assertEqual((primary.engine, primary.text), ("vosk", "hello word"))
+        self.assertEqual((comparison.engine, comparison.text), ("whisper_cpp", "hello world"))
+
+    def test_comparison_off_without_listeners_or_same_engine(self):
+        """Test no comparison runs unless it is on, for another engine, and shown somewhere."""
+        manager = SpeechRecognitionManager(engine="vosk", comparison_engine="whisper_cpp")
+        self.assertIsNone(manager._start_comparison([b"data"]))
+
+        manager.register_comparison_callback(MagicMock())
+        manager.reconfigure(comparison_engine="vosk", force_download=False)
+        self.assertIsNone(manager._start_comparison([b"data"]))
+
+    def test_rewrite_replaces_the_draft(self):
+        """Test a draft is typed at once and the larger model's text reported to replace it."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", rewrite_engine="whisper_cpp", rewrite_model_size="medium"
+        )
+        manager._voice_commands_enabled = False
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+        rewrite_callback = MagicMock()
+        manager.register_rewrite_callback(rewrite_callback)
+        rewrite_manager = MagicMock(_model_initialized=True)
+        rewrite_manager._transcribe_buffer.return_value = "I read the book"
+        self.recognizerMock.FinalResult.return_value = '{"text": "i red the book"}'
+
+        with patch.object(manager, "_get_rewrite_manager", return_value=rewrite_manager):
+            manager._process_audio_buffer([b"data"])
+            text_callback.assert_called_once_with("i red the book")
+            rewrite_callback.assert_not_called()
+
+            # Run the background decode (threads are mocked in these tests)
+            thread_kwargs = self.threadMock.call_args.kwargs
+            thread_kwargs["target"](*thread_kwargs["args"])
+
+        rewrite_manager._transcribe_buffer.assert_called_once_with([b"data"])
+        rewrite_callback.assert_called_once_with("i red the book", "I read the book")
+        self.assertFalse(manager._rewrite_busy)
+
+    def test_rewrite_skipped_for_commands_same_model_or_busy(self):
+        """Test drafts are only rewritten by another model and when no command ran."""
+        manager = SpeechRecognitionManager(engine="vosk", rewrite_engine="whisper_cpp")
+        self.assertFalse(manager._start_rewrite([b"data"], "hello"))
+
+        manager.register_rewrite_callback(MagicMock())
+        self.assertTrue(manager._start_rewrite([b"data"], "hello"))
+        self.assertFalse(manager._start_rewrite([b"data"], "hello"))
+
+        manager._rewrite_busy = False
+        manager.reconfigure(rewrite_engine="vosk", rewrite_model_size="small", force_download=False)
+        self.assertFalse(manager._start_rewrite([b"data"], "hello"))
+
+        manager.reconfigure(rewrite_engine="whisper_cpp", force_download=False)
+        manager.command_processor.process_text = MagicMock(return_value=("", ["new_line"]))
+        with patch.object(manager, "_start_rewrite") as start_rewrite:
+            manager._hold_or_emit_text("new line", [b"data"])
+        start_rewrite.assert_not_called()
+
+    def test_retry_utterance_with_larger_model(self):
+        """Test kept audio of a recent transcript is decoded again with the retry model."""
+        manager = SpeechRecognitionManager(engine="vosk", retry_audio=True)
+        manager._voice_commands_enabled = False
+        self.recognizerMock.FinalResult.return_value = '{"text": "i red the book"}'
+        manager._process_audio_buffer([b"data"])
+        self.assertTrue(manager.can_retry("i red the book"))
+        self.assertFalse(manager.can_retry("something else"))
+
+        retry_manager = MagicMock(_model_initialized=True)
+        retry_manager._transcribe_buffer.return_value = "I read the book"
+        with patch.object(manager, "_get_retry_manager", return_value=retry_manager):
+            self.assertEqual(manager.retry_utterance("i red the book"), "I read the book")
+            self.assertIsNone(manager.retry_utterance("something else"))
+        retry_manager._transcribe_buffer.assert_called_once_with([b"data"])
+
+        manager.reconfigure(retry_audio=False, force_download=False)
+        self.assertFalse(manager.can_retry("i red the book"))
+
+    def test_offline_fallback_when_remote_server_unreachable(self):
+        """Test segments the remote server can't be reached for are decoded locally."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", offline_fallback_engine="whisper_cpp", offline_fallback_model_size="tiny"
+        )
+        manager.engine = "remote_api"
+        manager._http_session = MagicMock()
+        fallback_manager = MagicMock(_model_initialized=True, engine="whisper_cpp")
+        fallback_manager._transcribe_buffer.return_value = "offline text"
+
+        def unreachable(audio_buffer, session):
+            manager._remote_api_unreachable = True
+            return ""
+
+        notify = patch("vocalinux.speech_recognition.recognition_manager._show_notification")
+        with patch.object(manager, "_get_fallback_manager", return_value=fallback_manager):
+            with patch.object(
+                manager, "_transcribe_with_remote_api", side_effect=unreachable
+            ), notify as mock_notify:
+                self.assertEqual(manager._transcribe_buffer([b"data"]), "offline text")
+            fallback_manager._transcribe_buffer.assert_called_once_with([b"data"])
+            # Dictation carried on offline, so there's nothing to report
+            mock_notify.assert_not_called()
+
+            with patch.object(manager, "_transcribe_with_remote_api", return_value="server text"):
+                self.assertEqual(manager._transcribe_buffer([b"data"]), "server text")
+            fallback_manager._transcribe_buffer.assert_called_once()
+
+        manager.reconfigure(offline_fallback_engine=None, force_download=False)
+        with patch.object(
+            manager, "_transcribe_with_remote_api", side_effect=unreachable
+        ), notify as mock_notify:
+            self.assertEqual(manager._transcribe_buffer([b"data"]), "")
+        self.assertEqual(mock_notify.call_args.args[0], "Remote Server Unreachable")
+
+    def test_offline_fallback_standby_loads_model_in_background(self):
+        """Test the fallback model is only loaded ahead of time with standby on."""
+        manager = SpeechRecognitionManager(engine="vosk", offline_fallback_engine="whisper_cpp")
+        manager.engine = "remote_api"
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager.threading.Thread"
+        ) as thread_class:
+            manager._warm_offline_fallback()
+            thread_class.assert_not_called()
+
+            manager.reconfigure(offline_fallback_standby=True, force_download=False)
+        thread_class.assert_called_once_with(target=manager._load_offline_fallback, daemon=True)
+        thread_class.return_value.start.assert_called_once()
+
+    def test_retry_audio_not_kept_by_default(self):
+        """Test utterance audio is only kept when retrying is enabled."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager._voice_commands_enabled = False
+        self.recognizerMock.FinalResult.return_value = '{"text": "hello"}'
+        manager._process_audio_buffer([b"data"])
+        self.assertFalse(manager.can_retry("hello"))
+        self.assertIsNone(manager.retry_utterance("hello"))
+
+    def test_long_form_only_active_for_whisper_engines(self):
+        """Test long-form mode is ignored by engines that segment on their own."""
+        manager = SpeechRecognitionManager(engine="vosk", long_form_mode=True)
+        self.assertFalse(manager._long_form_active())
+
+        manager.engine = "whisper_cpp"
+        self.assertTrue(manager._long_form_active())
+
+        manager.reconfigure(long_form_mode=False, force_download=False)
+        self.assertFalse(manager._long_form_active())
+
+    def test_roll_long_form_window_keeps_overlap(self):
+        """Test a full window is queued and its tail starts the next one."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        chunk = b"\x00" * 32000  # One second of 16 kHz 16-bit audio
+        manager.audio_buffer = [chunk] * 29
+        manager._recording_segment_has_speech = True
+
+        self.assertFalse(manager._roll_long_form_window())
+        self.assertTrue(manager._segment_queue.empty())
+
+        manager.audio_buffer.append(chunk)
+        self.assertTrue(manager._roll_long_form_window())
+        self.assertEqual(len(manager._segment_queue.get_nowait()), 30)
+        self.assertEqual(len(manager.audio_buffer), 5)
+        self.assertFalse(manager._recording_segment_has_speech)
+
+    def test_roll_long_form_window_drops_silent_window(self):
+        """Test a window without speech is not transcribed."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager.audio_buffer = [b"\x00" * 32000] * 30
+        manager._recording_segment_has_speech = False
+
+        self.assertTrue(manager._roll_long_form_window())
+        self.assertTrue(manager._segment_queue.empty())
+
+    def test_merge_overlapping_transcript(self):
+        """Test words repeated from the previous window are removed."""
+        from vocalinux.speech_recognition.recognition_manager import (
+            _merge_overlapping_transcript,
+        )
+
+        self.assertEqual(
+            _merge_overlapping_transcript(
+                "The mitochondria is the powerhouse of the cell.",
+                "the cell. Next we look at ribosomes.",
+            ),
+            "Next we look at ribosomes.",
+        )
+        # A garbled first word at the window edge still lines up
+        self.assertEqual(
+            _merge_overlapping_transcript(
+                "we measured the reaction rate", "Uh, the reaction rate doubled"
+            ),
+            "doubled",
+        )
+        self.assertEqual(_merge_overlapping_transcript("", "Hello there."), "Hello there.")
+        self.assertEqual(
+            _merge_overlapping_transcript("nothing shared", "completely new text"),
+            "completely new text",
+        )
+
+    def test_process_audio_buffer_merges_long_form_windows(self):
+        """Test consecutive long-form windows emit only the new words."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager._long_form_session = True
+        manager._voice_commands_enabled = False
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        self.recognizerMock.FinalResult.return_value = '{"text": "one two three four"}'
+        manager._process_audio_buffer([b"data"])
+        self.recognizerMock.FinalResult.return_value = '{"text": "three four five six"}'
+        manager._process_audio_buffer([b"data"])
+
+        self.assertEqual(
+            [call.args[0] for call in text_callback.call_args_list],
+            ["one two three four", "five six"],
+        )
+
+    def test_join_utterance_fragments(self):
+        """Test fragments split at a pause are re-formatted as one sentence."""
+        from vocalinux.speech_recognition.recognition_manager import _join_utterance_fragments
+
+        self.assertEqual(
+            _join_utterance_fragments("I went to the store.", "And bought some milk."),
+            "I went to the store and bought some milk.",
+        )
+        self.assertEqual(
+            _join_utterance_fragments("The results were...", "Surprisingly good."),
+            "The results were surprisingly good.",
+        )
+        self.assertEqual(
+            _join_utterance_fragments("It was late,", "I think."),
+            "It was late, I think.",
+        )
+        # Complete sentences and questions are left alone
+        self.assertEqual(
+            _join_utterance_fragments("That is done.", "Next item."),
+            "That is done. Next item.",
+        )
+        self.assertEqual(
+            _join_utterance_fragments("Is it ready?", "And the tests?"),
+            "Is it ready? And the tests?",
+        )
+
+    def test_join_window_holds_and_joins_fragments(self):
+        """Test a held fragment is joined with a continuation that follows it."""
+        manager = SpeechRecognitionManager(engine="vosk", utterance_join_window=1.0)
+        manager.should_record = True
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        manager._hold_or_emit_text("I went to the store.")
+        text_callback.assert_not_called()
+
+        manager.should_record = False
+        manager._voice_commands_enabled = False
+        manager._hold_or_emit_text("And bought milk.")
+        text_callback.assert_called_once_with("I went to the store and bought milk.")
+
+    def test_flush_pending_fragment_waits_for_resumed_speech(self):
+        """Test held text is emitted only when no continuation is coming."""
+        manager = SpeechRecognitionManager(engine="vosk", utterance_join_window=1.0)
+        manager._voice_commands_enabled = False
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+        manager._pending_fragment = "Hello."
+
+        with patch("vocalinux.speech_recognition.recognition_manager.time.time") as mock_time:
+            mock_time.return_value = 100.5
+            manager._last_segment_cut_time = 100.0
+            manager._flush_pending_fragment()
+            text_callback.assert_not_called()
+
+            # Speech resumed inside the window, so keep waiting past it
+            mock_time.return_value = 105.0
+            manager._speech_resumed_time = 100.4
+            manager._recording_segment_has_speech = True
+            manager._flush_pending_fragment()
+            text_callback.assert_not_called()
+
+            manager._recording_segment_has_speech = False
+            manager._flush_pending_fragment()
+            text_callback.assert_called_once_with("Hello.")
+
+    def test_emit_text_applies_number_formatting(self):
+        """Test the number formatting styles are applied and can be reconfigured."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", format_ordinals=True, voice_commands_enabled=False
+        )
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        manager._emit_text("the third item")
+        text_callback.assert_called_with("the 3rd item")
+
+        manager.reconfigure(format_ordinals=False, force_download=False)
+        manager._emit_text("the third item")
+        text_callback.assert_called_with("the third item")
+
+    def test_emit_text_follows_the_text_pipeline(self):
+        """Test stages left out of the text pipeline are skipped and can be reconfigured."""
+        manager = SpeechRecognitionManager(
+            engine="vosk",
+            format_ordinals=True,
+            voice_commands_enabled=False,
+            text_pipeline=["commands", "capitalization"],
+        )
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        manager._emit_text("the third item")
+        text_callback.assert_called_with("the third item")
+
+        manager.reconfigure(text_pipeline=["numbers"], force_download=False)
+        manager._emit_text("the third item")
+        text_callback.assert_called_with("the 3rd item")
+
+    def test_emit_text_plays_utterance_sound(self):
+        """Test the utterance earcon plays once per delivered utterance, not for empty text."""
+        manager = SpeechRecognitionManager(engine="vosk", voice_commands_enabled=False)
+        manager.register_text_callback(MagicMock())
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager.play_utterance_sound"
+        ) as play_utterance_sound:
+            manager._emit_text("")
+            play_utterance_sound.assert_not_called()
+
+            manager._emit_text("hello world")
+            play_utterance_sound.assert_called_once()
+
+    def test_own_sounds_are_muted_only_between_utterances(self):
+        """Test audio is muted while our sounds play, unless mid-speech, replaying or turned off."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        self.assertTrue(manager.mute_own_sounds)
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager.is_self_playback_active",
+            return_value=True,
+        ):
+            self.assertTrue(manager._is_own_sound_playing(None))
+            # A recording played with --input-file doesn't contain our sounds
+            self.assertFalse(manager._is_own_sound_playing(b"\x00\x00"))
+
+            # The user is talking over it; silencing would cut their segment short
+            manager._recording_segment_has_speech = True
+            self.assertFalse(manager._is_own_sound_playing(None))
+            manager._recording_segment_has_speech = False
+
+            manager.reconfigure(mute_own_sounds=False, force_download=False)
+            self.assertFalse(manager._is_own_sound_playing(None))
+
+    def test_emit_text_runs_intents_when_enabled(self):
+        """Test an intent is carried out instead of typed, only when enabled."""
+        manager = SpeechRecognitionManager(engine="vosk", voice_commands_enabled=False)
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+        manager._intent_handler = MagicMock()
+        manager._intent_handler.handle.return_value = True
+
+        manager._emit_text("search for vocalinux")
+        text_callback.assert_called_once_with("search for vocalinux")
+        manager._intent_handler.handle.assert_not_called()
+
+        text_callback.reset_mock()
+        manager.reconfigure(intent_commands_enabled=True, force_download=False)
+        manager._emit_text("search for vocalinux")
+        manager._intent_handler.handle.assert_called_once_with("search for vocalinux")
+        text_callback.assert_not_called()
+
+    def test_session_counts_typed_words(self):
+        """Test the session timer starts with dictation and counts typed words."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", voice_commands_enabled=False, session_word_target=3
+        )
+        self.assertEqual(manager.session.word_target, 3)
+        manager._update_state(RecognitionState.LISTENING)
+        self.assertTrue(manager.session.started)
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            manager._emit_text("hello there world")
+            mock_notify.assert_called_once()
+        self.assertEqual(manager.session.word_count, 3)
+
+        manager.reconfigure(session_word_target=0, force_download=False)
+        self.assertEqual(manager.session.word_target, 0)
+
+    def test_process_audio_buffer_records_utterance_event(self):
+        """Test each decoded utterance is handed to the utterance log."""
+        manager = SpeechRecognitionManager(engine="vosk", utterance_log="hashed")
+        self.assertEqual(manager.utterance_log.mode, "hashed")
+        manager.reconfigure(utterance_log="full", force_download=False)
+        self.assertEqual(manager.utterance_log.mode, "full")
+
+        manager.utterance_log = MagicMock()
+        self.recognizerMock.FinalResult.return_value = '{"text": "hello world"}'
+
+        manager._process_audio_buffer([b"\x00" * 32000])
+
+        args, kwargs = manager.utterance_log.record.call_args
+        self.assertEqual(args, ("hello world", "vosk"))
+        self.assertEqual(kwargs["audio_seconds"], 1.0)
+        self.assertIsNone(kwargs["confidence"])
+
+    def test_emit_text_punctuation_levels(self):
+        """Test each punctuation level keeps, strips or restores punctuation."""
+        manager = SpeechRecognitionManager(engine="vosk", voice_commands_enabled=False)
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        self.assertIsNone(manager.punctuation_level)
+        manager._emit_text("where is it")
+        text_callback.assert_called_with("where is it")
+
+        manager.reconfigure(punctuation="auto", force_download=False)
+        manager._emit_text("where is it")
+        text_callback.assert_called_with("Where is it?")
+
+        manager.reconfigure(punctuation="off", force_download=False)
+        manager._emit_text("Where is it?")
+        text_callback.assert_called_with("Where is it")
+
+    def test_detected_language_tags_text(self):
+        """Test the detected language is recorded and optionally prefixed to the text."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", voice_commands_enabled=False, tag_language=True
+        )
+        manager.language = "auto"
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        manager._record_detected_language({"text": "Guten Morgen", "language": "de"})
+        self.assertEqual(manager.detected_language, "de")
+        manager._emit_text("Guten Morgen")
+        text_callback.assert_called_with("[de] Guten Morgen")
+        self.assertEqual(manager.session.word_count, 2)
+
+        manager.reconfigure(tag_language=False, force_download=False)
+        manager._emit_text("Guten Morgen")
+        text_callback.assert_called_with("Guten Morgen")
+
+        # A fixed language is not "detected"
+        manager.language = "en-us"
+        manager._record_detected_language({"text": "Hello", "language": "en"})
+        self.assertIsNone(manager.detected_language)
+
+    def test_remote_api_key_waits_for_locked_keyring(self):
+        """Test a locked keyring skips the request instead of sending no key."""
+        manager = SpeechRecognitionManager(engine="vosk", remote_api_url="http://server")
+        manager._remote_api_secret = MagicMock(locked=True)
+        manager._remote_api_secret.get.return_value = None
+        manager._try_whispercpp_server_api = MagicMock(return_value="hello")
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as notify:
+            self.assertEqual(manager._transcribe_with_remote_api([b"\x00\x00"], None), "")
+        notify.assert_called_once()
+        self.assertTrue(manager.remote_api_key_locked)
+        manager._try_whispercpp_server_api.assert_not_called()
+
+        # Once unlocked, the keyring's key is sent
+        manager._remote_api_secret.locked = False
+        manager._remote_api_secret.get.return_value = "sk-work"
+        manager._transcribe_with_remote_api([b"\x00\x00"], None)
+        headers = manager._try_whispercpp_server_api.call_args[0][2]
+        self.assertEqual(headers["Authorization"], "Bearer sk-work")
+        self.assertFalse(manager.remote_api_key_locked)
+
+    def test_emit_text_strips_engine_punctuation_for_commands_level(self):
+        """Test the engine's own punctuation is dropped at the commands level."""
+        manager = SpeechRecognitionManager(engine="vosk", voice_commands_enabled=False)
+        manager.engine = "whisper_cpp"
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        manager._emit_text("Hello, world.")
+        text_callback.assert_called_with("Hello, world.")
+
+        manager.reconfigure(punctuation="commands", force_download=False)
+        manager._emit_text("Hello, world.")
+        text_callback.assert_called_with("Hello world")
+
+    def test_emit_text_filters_profanity(self):
+        """Test profanity is masked for the manager language and the mode can change."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", profanity_filter="mask", voice_commands_enabled=False
+        )
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        manager._emit_text("well shit")
+        text_callback.assert_called_with("well s***")
+
+        manager.reconfigure(profanity_filter="remove", force_download=False)
+        manager._emit_text("well shit")
+        text_callback.assert_called_with("well")
+
+    def test_unknown_profanity_filter_mode_disables_filter(self):
+        """Test an invalid configured mode falls back to no filtering."""
+        manager = SpeechRecognitionManager(engine="vosk", profanity_filter="bleep")
+        self.assertEqual(manager.profanity_filter.mode, "off")
+
+    def test_emit_text_preserves_listed_capitalization(self):
+        """Test preserved terms are restored after the other formatting passes."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", preserved_terms=["API"], voice_commands_enabled=False
+        )
+        text_callback = MagicMock()
+        manager.register_text_callback(text_callback)
+
+        manager._emit_text("the api is down")
+        text_callback.assert_called_with("the API is down")
+
+        manager.reconfigure(preserved_terms=["PostgreSQL"], force_download=False)
+        manager._emit_text("the api uses postgresql")
+        text_callback.assert_called_with("the api uses PostgreSQL")
+
+    def test_set_sidetone(self):
+        """Test sidetone settings are stored and the volume is clamped."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        self.assertFalse(manager.sidetone_enabled)
+
+        manager.set_sidetone(True, 1.5)
+        self.assertTrue(manager.sidetone_enabled)
+        self.assertEqual(manager.sidetone_volume, 1.0)
+
+    def test_sync_sidetone_follows_setting(self):
+        """Test the sidetone monitor starts and stops with the setting."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        audio = MagicMock()
+
+        self.assertIsNone(manager._sync_sidetone(None, audio, 16000))
+
+        manager.set_sidetone(True, 0.2)
+        with patch("vocalinux.speech_recognition.sidetone.SidetoneMonitor") as mock_monitor:
+            sidetone = manager._sync_sidetone(None, audio, 16000)
+            mock_monitor.return_value.start.assert_called_once_with(audio, 16000)
+            self.assertIs(manager._sync_sidetone(sidetone, audio, 16000), sidetone)
+            mock_monitor.assert_called_once()
+
+        manager.set_sidetone(False)
+        self.assertIsNone(manager._sync_sidetone(sidetone, audio, 16000))
+        sidetone.stop.assert_called_once()
+
+    def test_sidetone_restarts_on_new_instance_after_reopen(self):
+        """Test a stopped sidetone is started again on the new PyAudio instance and rate."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager.set_sidetone(True, 0.2)
+        old_audio, new_audio = MagicMock(), MagicMock()
+
+        with patch("vocalinux.speech_recognition.sidetone.SidetoneMonitor") as mock_monitor:
+            old_sidetone = MagicMock()
+            mock_monitor.return_value = old_sidetone
+            sidetone = manager._sync_sidetone(None, old_audio, 48000)
+            self.assertIsNone(manager._stop_sidetone(sidetone))
+            old_sidetone.stop.assert_called_once()
+
+            mock_monitor.return_value = MagicMock()
+            sidetone = manager._sync_sidetone(None, new_audio, 44100)
+            sidetone.start.assert_called_once_with(new_audio, 44100)
+
+    def test_media_ducking_follows_dictation(self):
+        """Test media is ducked while listening and restored when dictation ends."""
+        manager = SpeechRecognitionManager(engine="vosk", duck_media=True, duck_level=0.5)
+        self.assertEqual(manager._media_ducker.level, 0.5)
+        manager._media_ducker = MagicMock()
+
+        with patch.object(manager, "_run_media_ducking") as mock_run:
+            manager._update_state(RecognitionState.LISTENING)
+            mock_run.assert_called_once_with(manager._media_ducker.duck)
+
+            mock_run.reset_mock()
+            manager._ducking_executor = MagicMock()
+            manager._update_state(RecognitionState.IDLE)
+            mock_run.assert_called_once_with(manager._media_ducker.restore)
+
+            mock_run.reset_mock()
+            manager.set_media_ducking(False)
+            manager._update_state(RecognitionState.LISTENING)
+            mock_run.assert_called_once_with(manager._media_ducker.restore)
+
+    def test_set_echo_cancel(self):
+        """Test echo cancellation can be toggled for the next dictation."""
+        manager = SpeechRecognitionManager(engine="vosk", echo_cancel=True)
+        self.assertTrue(manager.echo_cancel)
+
+        manager.set_echo_cancel(False)
+        self.assertFalse(manager.echo_cancel)
+
+    def test_set_noise_suppression(self):
+        """Test noise suppression can be toggled for the next dictation."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        self.assertFalse(manager.noise_suppression)
+
+        manager.set_noise_suppression(True)
+        self.assertTrue(manager.noise_suppression)
+
+    def test_device_gain_follows_the_selected_device(self):
+        """Test the software gain is looked up per device and kept in range."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", device_gain={"default": 2.0, "USB Webcam": 50, "Yeti": "loud"}
+        )
+        self.assertEqual(manager.device_gain, {"default": 2.0, "USB Webcam": 8.0})
+        self.assertEqual(manager._input_gain(), 2.0)
+
+        manager.set_audio_device(3, "USB Webcam")
+        self.assertEqual(manager._input_gain(), 8.0)
+        manager.set_audio_device(4, "Yeti")
+        self.assertEqual(manager._input_gain(), 1.0)
+
+        manager.set_device_gain({"Yeti": 0.0})
+        self.assertEqual(manager._input_gain(), 0.25)
+
+    def test_extra_microphones_are_opened_by_name(self):
+        """Test the extra microphones are opened next to the selected one."""
+        manager = SpeechRecognitionManager(
+            engine="vosk", extra_device_names=["Headset", "Desk Mic", "Unplugged"]
+        )
+        manager._capture_device_name = "Headset"
+        audio = MagicMock()
+        audio.get_device_count.return_value = 2
+        audio.get_device_info_by_index.side_effect = lambda i: [
+            {"name": "Headset", "maxInputChannels": 1},
+            {"name": "Desk Mic", "maxInputChannels": 2},
+        ][i]
+        capture_format = MagicMock(pyaudio_format=8, channels=2, rate=48000)
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager.negotiate_capture_format",
+            return_value=capture_format,
+        ), patch("vocalinux.speech_recognition.mic_mix.MicMixer") as mock_mixer:
+            mock_mixer.return_value.device_names = ["Desk Mic"]
+            mixer = manager._start_mic_mixer(audio, 1024)
+
+        self.assertIs(mixer, mock_mixer.return_value)
+        audio.open.assert_called_once_with(
+            format=8,
+            channels=2,
+            rate=48000,
+            input=True,
+            frames_per_buffer=1024,
+            input_device_index=1,
+        )
+        mixer.add.assert_called_once_with(
+            "Desk Mic", audio.open.return_value, capture_format, 1.0, 1024
+        )
+
+        manager.set_extra_devices([])
+        self.assertIsNone(manager._start_mic_mixer(audio, 1024))
+
+    def test_record_audio_stops_when_microphone_access_denied(self):
+        """Test a denied microphone permission is explained instead of opening a stream."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        manager._microphone_permission = MagicMock()
+        manager._microphone_permission.check.return_value = False
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            manager._record_audio()
+
+        self.assertEqual(manager.state, RecognitionState.ERROR)
+        self.assertEqual(mock_notify.call_args[0][0], "Microphone Access Denied")
+        self.assertIsNone(manager._pyaudio_instance)
+
+    def test_warn_if_microphone_muted(self):
+        """Test a microphone muted in the sound settings is reported."""
+        manager = SpeechRecognitionManager(engine="vosk")
+        module = "vocalinux.speech_recognition.recognition_manager"
+
+        for muted, notified in ((True, True), (False, False), (None, False)):
+            with patch(f"{module}.is_source_muted", return_value=muted), patch(
+                f"{module}._show_notification"
+            ) as mock_notify:
+                manager._warn_if_microphone_muted()
+            self.assertEqual(mock_notify.called, notified)
+            if notified:
+                self.assertEqual(mock_notify.call_args[0][0], "Microphone Muted")
+
+    def test_record_audio_stops_when_input_file_unreadable(self):
+        """Test an unreadable input file is reported instead of recording the microphone."""
+        manager = SpeechRecognitionManager(engine="vosk", input_file="/nonexistent/take.wav")
+        manager._microphone_permission = MagicMock()
+
+        with patch(
+            "vocalinux.speech_recognition.file_input.FileAudio",
+            side_effect=OSError("No such file"),
+        ), patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            manager._record_audio()
+
+        self.assertEqual(manager.state, RecognitionState.ERROR)
+        self.assertEqual(mock_notify.call_args[0][0], "Cannot Play Input File")
+        manager._microphone_permission.check.assert_not_called()
+        self.assertIsNone(manager._pyaudio_instance)
+
     def test_process_empty_buffer(self):
         """Test processing empty buffer does nothing."""
         manager = SpeechRecognitionManager(engine="vosk")
@@ -1351,6 +2269,76 @@ class TestReconfigureMethod(unittest.TestCase):
         manager.reconfigure(silence_timeout=0.1)
         self.assertEqual(manager.silence_timeout, 0.5)
 
+    def test_adaptive_silence_timeout(self):
+        """Test the adaptive timeout follows the speaker's pauses only when enabled."""
+        from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+        manager = SpeechRecognitionManager(engine="vosk", silence_timeout=2.0)
+        for _ in range(10):
+            manager._pause_timing.add_pause(0.4)
+        self.assertEqual(manager._current_silence_timeout(), 2.0)
+
+        manager.reconfigure(adaptive_silence_timeout=True)
+        self.assertAlmostEqual(manager._current_silence_timeout(), 0.6)
+
+    def test_engine_vad_overrides(self):
+        """Test each engine uses its own VAD settings when the config has them."""
+        from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+        manager = SpeechRecognitionManager(
+            engine="vosk",
+            vad_sensitivity=3,
+            silence_timeout=2.0,
+            engine_vad={
+                "whisper": {"silence_timeout": 3.5, "vad_sensitivity": 9},
+                "vosk": {"vad_sensitivity": 4},
+                "broken": {"silence_timeout": "long"},
+            },
+        )
+        self.assertNotIn("broken", manager.engine_vad)
+        self.assertEqual(manager._engine_vad_sensitivity(), 4)
+        self.assertEqual(manager._current_silence_timeout(), 2.0)
+
+        manager.engine = "whisper"
+        self.assertEqual(manager._engine_vad_sensitivity(), 5)
+        self.assertEqual(manager._current_silence_timeout(), 3.5)
+
+        # A new value changes the override in effect, not the hidden global one
+        manager.reconfigure(silence_timeout=4.0, vad_sensitivity=2)
+        self.assertEqual(manager._current_silence_timeout(), 4.0)
+        self.assertEqual(manager._engine_vad_sensitivity(), 2)
+        self.assertEqual(manager.silence_timeout, 2.0)
+        self.assertEqual(manager.vad_sensitivity, 3)
+
+        manager.reconfigure(engine_vad={})
+        self.assertEqual(manager._engine_vad_sensitivity(), 3)
+        self.assertEqual(manager._current_silence_timeout(), 2.0)
+
+    def test_custom_commands(self):
+        """Test disabled and renamed commands reach the command processor."""
+        from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+        manager = SpeechRecognitionManager(engine="vosk", disabled_commands=["period"])
+        self.assertEqual(manager.command_processor.disabled_commands, ["period"])
+
+        manager.reconfigure(renamed_commands={"comma": "pause here"})
+        self.assertEqual(manager.command_processor.disabled_commands, ["period"])
+        self.assertEqual(manager.command_processor.renamed_commands, {"comma": "pause here"})
+
+    def test_utterances_dumped_before_transcription(self):
+        """Test each utterance goes to the audio dumper before it is transcribed."""
+        from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+        manager = SpeechRecognitionManager(engine="vosk", dump_audio_dir="/tmp/dumps")
+        self.assertEqual(manager.audio_dumper.directory, "/tmp/dumps")
+        manager.audio_dumper = MagicMock()
+        manager._transcribe_buffer = MagicMock(return_value=None)
+        manager._process_audio_buffer([b"\x00\x00"])
+        manager.audio_dumper.dump.assert_called_once_with([b"\x00\x00"])
+
+        manager.reconfigure(dump_audio_dir="")
+        self.assertEqual(manager.audio_dumper.directory, "")
+
     def test_reconfigure_audio_device_clear(self):
         """Test clearing audio device with -1."""
         from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
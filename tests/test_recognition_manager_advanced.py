@@ -377,6 +377,23 @@ class TestTranscribeWhispercpp(unittest.TestCase):
         with patch.dict("sys.modules", {"numpy": mock_np}):
             result = mgr._transcribe_with_whispercpp([b"\x00\x00" * 512])
 
+    def test_transcribe_translate(self):
+        mgr = _make_manager(engine="whisper_cpp")
+        mgr.language = "auto"
+        mgr.model = MagicMock()
+        mgr.model.transcribe.return_value = []
+        mgr.reconfigure(translate=True, force_download=False)
+
+        mock_np = MagicMock()
+        mock_np.frombuffer.return_value = MagicMock(__len__=lambda s: 16000)
+        mock_np.frombuffer.return_value.astype.return_value = mock_np.frombuffer.return_value
+
+        with patch.dict("sys.modules", {"numpy": mock_np}):
+            mgr._transcribe_with_whispercpp([b"\x00\x00" * 512])
+        self.assertTrue(mgr.model.transcribe.call_args.kwargs["translate"])
+        # Translated text is English, whatever the spoken language
+        self.assertEqual(mgr._output_language(), "en")
+
 
 class TestTranscribeWhisper(unittest.TestCase):
     def test_transcribe_success(self):
@@ -514,6 +531,29 @@ class TestInitWhispercpp(unittest.TestCase):
             {"n_threads": 4},
         )
 
+    def test_effective_backend_is_reported(self):
+        from vocalinux.utils.whispercpp_load_report import parse_load_log
+
+        mgr = _make_manager(engine="whisper_cpp")
+        notify = "vocalinux.speech_recognition.recognition_manager._show_notification"
+
+        mgr.whispercpp_load_report = parse_load_log(
+            "whisper_backend_init_gpu: using CUDA0 backend\n"
+        )
+        with patch(notify) as mock_notify:
+            mgr._report_whispercpp_backend(expected_gpu=True, fell_back=False)
+        self.assertEqual(mock_notify.call_args[0][0], "whisper.cpp Using GPU")
+
+        # GPU expected but whisper.cpp found none: warn, unless the fallback already did
+        mgr.whispercpp_load_report = parse_load_log("whisper_backend_init_gpu: no GPU found\n")
+        with patch(notify) as mock_notify:
+            mgr._report_whispercpp_backend(expected_gpu=True, fell_back=False)
+            self.assertEqual(mock_notify.call_args[0][0], "whisper.cpp Running on CPU")
+            mock_notify.reset_mock()
+            mgr._report_whispercpp_backend(expected_gpu=True, fell_back=True)
+            mgr._report_whispercpp_backend(expected_gpu=False, fell_back=False)
+        mock_notify.assert_not_called()
+
 
 class TestDownloadVoskModel(unittest.TestCase):
     pass
@@ -579,6 +619,49 @@ class TestWhispercppModelKwargs(unittest.TestCase):
         kwargs = mgr._build_whispercpp_model_kwargs(n_threads=4)
         self.assertEqual(kwargs.get("initial_prompt"), "test prompt")
 
+    def test_model_kwargs_with_vocabulary(self):
+        mgr = _make_manager(
+            engine="whisper_cpp",
+            whispercpp_initial_prompt="Clinic notes.",
+            vocabulary_terms=["metoprolol", "apixaban"],
+        )
+        kwargs = mgr._build_whispercpp_model_kwargs(n_threads=4)
+        self.assertEqual(
+            kwargs.get("initial_prompt"), "Clinic notes. Glossary: metoprolol, apixaban."
+        )
+
+    def test_app_prompt_preset(self):
+        mgr = _make_manager(
+            engine="whisper_cpp",
+            whispercpp_initial_prompt="Clinic notes.",
+            prompt_presets={"chat": "lol, brb"},
+            app_prompts={"element": "chat"},
+        )
+        self.assertEqual(mgr._whispercpp_prompt_params(), {"initial_prompt": "Clinic notes."})
+
+        mgr.set_focused_app_provider(lambda: "Element")
+        mgr._update_app_prompt()
+        self.assertEqual(
+            mgr._whispercpp_prompt_params(), {"initial_prompt": "Clinic notes. lol, brb"}
+        )
+        self.assertEqual(mgr._session_prompt(), "lol, brb")
+
+        mgr.set_focused_app_provider(lambda: "firefox")
+        mgr._update_app_prompt()
+        self.assertEqual(mgr._session_prompt(), "")
+
+    def test_app_prompt_params_without_rules(self):
+        mgr = _make_manager(engine="whisper_cpp", whispercpp_initial_prompt="Clinic notes.")
+        mgr.set_focused_app_provider(MagicMock(return_value="Element"))
+        mgr._update_app_prompt()
+        self.assertEqual(mgr._whispercpp_prompt_params(), {})
+        mgr._focused_app_provider.assert_not_called()
+
+    def test_reconfigure_app_prompts(self):
+        mgr = _make_manager(engine="whisper_cpp")
+        mgr.reconfigure(app_prompts={"konsole": "shell"}, force_download=False)
+        self.assertEqual(mgr.app_prompts.preset_for("konsole"), "shell")
+
     def test_model_kwargs_custom_numerics(self):
         mgr = _make_manager(
             engine="whisper_cpp",
@@ -26,12 +26,16 @@ from unittest.mock import MagicMock, Mock, patch
 import pytest
 
 from vocalinux.common_types import RecognitionState
+from vocalinux.speech_recognition.audio_chunk import AudioChunk
 from vocalinux.speech_recognition.recognition_manager import (
+    CaptureFormat,
     SpeechRecognitionManager,
     _filter_non_speech,
     _get_supported_channels,
     _get_supported_sample_rate,
     get_audio_input_devices,
+    get_input_device_capabilities,
+    negotiate_capture_format,
 )
 
 
@@ -48,6 +52,61 @@ def _make_manager(engine="whisper_cpp", **kw):
 class TestAudioDeviceDetection(unittest.TestCase):
     """Test audio device enumeration functions."""
 
+    def test_get_input_device_capabilities(self):
+        """Test the capability report lists rates, channels and formats per device."""
+        mock_pyaudio = MagicMock(paInt16=8, paInt24=4, paInt32=2, paFloat32=1, paUInt8=32)
+        mock_audio = mock_pyaudio.PyAudio.return_value
+        mock_audio.get_default_input_device_info.return_value = {"index": 0}
+        mock_audio.get_device_count.return_value = 3
+        mock_audio.get_device_info_by_index.side_effect = [
+            {"name": "USB Mic", "maxInputChannels": 2, "defaultSampleRate": 48000.0},
+            {"name": "HDMI Out", "maxInputChannels": 0, "defaultSampleRate": 48000.0},
+            {"name": "Monitor of Speakers", "maxInputChannels": 2, "defaultSampleRate": 48000.0},
+        ]
+
+        def is_format_supported(rate, input_device, input_channels, input_format):
+            if rate != 48000 or input_format not in (8, 1):
+                raise ValueError("Invalid sample rate")
+            return True
+
+        mock_audio.is_format_supported.side_effect = is_format_supported
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            capabilities = get_input_device_capabilities()
+
+        self.assertEqual(len(capabilities), 1)
+        device = capabilities[0]
+        self.assertEqual(device["name"], "USB Mic")
+        self.assertTrue(device["is_default"])
+        self.assertEqual(device["rates"], [48000])
+        self.assertEqual(device["channels"], [1, 2])
+        self.assertEqual(device["formats"], ["16-bit int", "32-bit float"])
+        mock_audio.terminate.assert_called_once()
+
+    def test_device_capabilities_probe_every_sample_format(self):
+        """Test a device that only opens in 32-bit float still lists its rates and channels."""
+        mock_pyaudio = MagicMock(paInt16=8, paInt24=4, paInt32=2, paFloat32=1, paUInt8=32)
+        mock_audio = mock_pyaudio.PyAudio.return_value
+        mock_audio.get_default_input_device_info.return_value = {"index": 0}
+        mock_audio.get_device_count.return_value = 1
+        mock_audio.get_device_info_by_index.side_effect = [
+            {"name": "PipeWire Node", "maxInputChannels": 2, "defaultSampleRate": 48000.0},
+        ]
+
+        def is_format_supported(rate, input_device, input_channels, input_format):
+            if rate != 48000 or input_format != 1:
+                raise ValueError("Invalid sample format")
+            return True
+
+        mock_audio.is_format_supported.side_effect = is_format_supported
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            device = get_input_device_capabilities()[0]
+
+        self.assertEqual(device["rates"], [48000])
+        self.assertEqual(device["channels"], [1, 2])
+        self.assertEqual(device["formats"], ["32-bit float"])
+
     def test_get_supported_channels_mono_success(self):
         """Test mono channel support detection."""
         mock_audio = MagicMock()
@@ -77,6 +136,27 @@ class TestAudioDeviceDetection(unittest.TestCase):
             channels = _get_supported_channels(mock_audio, 0)
             assert channels == 2
 
+    def test_get_supported_channels_four_channel_interface(self):
+        """Test interfaces that only open with all their inputs are probed up to the maximum."""
+        mock_audio = MagicMock()
+        mock_stream = MagicMock()
+
+        def open_side_effect(**kwargs):
+            if kwargs.get("channels") != 4:
+                raise IOError("[Errno -9998] Invalid number of channels")
+            return mock_stream
+
+        mock_audio.open.side_effect = open_side_effect
+        mock_audio.get_device_info_by_index.return_value = {
+            "defaultSampleRate": 48000,
+            "maxInputChannels": 4,
+        }
+        mock_pyaudio = MagicMock(paInt16=8)
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            channels = _get_supported_channels(mock_audio, 0)
+            assert channels == 4
+
     def test_get_supported_channels_all_fail(self):
         """Test fallback to mono when all channels fail."""
         mock_audio = MagicMock()
@@ -206,6 +286,298 @@ class TestAudioDeviceDetection(unittest.TestCase):
             rate = _get_supported_sample_rate(mock_audio, None, 1)
             assert rate == 16000  # Default fallback
 
+    def test_get_supported_sample_rate_preferred_rate(self):
+        """Test a configured override is used when the device accepts it."""
+        mock_audio = MagicMock()
+        mock_audio.get_device_info_by_index.return_value = {"defaultSampleRate": 48000}
+        mock_pyaudio = MagicMock(paInt16=8)
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            rate = _get_supported_sample_rate(mock_audio, 0, 1, preferred_rate=22050)
+            assert rate == 22050
+            assert mock_audio.open.call_args.kwargs["rate"] == 22050
+
+    def test_get_supported_sample_rate_preferred_rate_unsupported(self):
+        """Test an override the device rejects falls back to automatic selection."""
+        mock_audio = MagicMock()
+
+        def open_side_effect(**kwargs):
+            if kwargs.get("rate") == 96000:
+                raise IOError("unsupported rate")
+            return MagicMock()
+
+        mock_audio.open.side_effect = open_side_effect
+        mock_audio.get_device_info_by_index.return_value = {"defaultSampleRate": 44100}
+        mock_pyaudio = MagicMock(paInt16=8)
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            rate = _get_supported_sample_rate(mock_audio, 0, 1, preferred_rate=96000)
+            assert rate == 44100
+
+    def test_valid_sample_rate(self):
+        """Test validation of the sample rate override."""
+        validate = SpeechRecognitionManager._valid_sample_rate
+        assert validate(None) is None
+        assert validate("48000") == 48000
+        assert validate(100) is None
+        assert validate("fast") is None
+
+    def test_buffer_frames_and_queue_depth_bounded(self):
+        """Test the latency settings are kept within bounds and can be reconfigured."""
+        manager = _make_manager(buffer_frames=64, queue_depth="deep")
+        assert manager.buffer_frames == 256
+        assert manager.queue_depth == 32
+        assert manager._segment_queue.maxsize == 32
+
+        manager.reconfigure(buffer_frames=2048, queue_depth=1000, force_download=False)
+        assert manager.buffer_frames == 2048
+        assert manager.queue_depth == 256
+        # The stop-sound guard covers the same time with fewer, larger chunks
+        manager.stop_sound_guard_ms = 256
+        assert manager._get_stop_sound_guard_chunks() == 2
+
+    def test_long_utterance_cut_at_gap_between_words(self):
+        """Test non-stop speech is queued in parts once it reaches the maximum length."""
+        manager = _make_manager(max_utterance_seconds=30)
+        manager.audio_buffer = [b"\x00\x01"]
+        manager._recording_segment_has_speech = True
+        with patch.object(manager, "_enqueue_audio_segment") as enqueue:
+            assert not manager._cut_long_utterance(False, 29.0)
+            # Mid-word: wait for a gap, up to the grace period
+            assert not manager._cut_long_utterance(True, 31.0)
+            assert manager._cut_long_utterance(False, 31.5)
+            enqueue.assert_called_once_with([b"\x00\x01"])
+        assert manager.audio_buffer == []
+        assert not manager._recording_segment_has_speech
+
+        manager.audio_buffer = [b"\x00\x02"]
+        manager._recording_segment_has_speech = True
+        with patch.object(manager, "_enqueue_audio_segment") as enqueue:
+            assert manager._cut_long_utterance(True, 32.5)
+        enqueue.assert_called_once()
+        assert manager._recording_segment_has_speech
+
+    def test_max_utterance_seconds_bounded(self):
+        """Test the maximum utterance length is kept within bounds and 0 turns it off."""
+        assert _make_manager().max_utterance_seconds == 30
+        assert _make_manager(max_utterance_seconds=1).max_utterance_seconds == 5
+        manager = _make_manager(max_utterance_seconds=0)
+        assert manager.max_utterance_seconds == 0
+        manager.audio_buffer = [b"\x00\x01"]
+        manager._recording_segment_has_speech = True
+        assert not manager._cut_long_utterance(False, 600.0)
+
+        manager.reconfigure(max_utterance_seconds=1000, force_download=False)
+        assert manager.max_utterance_seconds == 600
+
+    def test_segment_trimmed_to_speech_plus_padding(self):
+        """Test a segment cut at a pause keeps only the padding around its speech."""
+        manager = _make_manager(speech_pad_before_ms=200, speech_pad_after_ms=100)
+        chunk_seconds = 0.064  # 1024 frames at 16kHz
+        # 10 chunks of silence, speech in chunks 10-14, then 1.28 s of silence
+        buffer = [bytes([i]) for i in range(35)]
+        manager._segment_speech_start = 10
+
+        segment = manager._padded_segment(buffer, 20 * chunk_seconds, chunk_seconds)
+        # 4 chunks (256 ms) before the speech, 2 chunks (128 ms) after it
+        assert segment == buffer[6:17]
+
+        # The next segment starts with the onset padding
+        assert manager._onset_padding(buffer, chunk_seconds) == buffer[-4:]
+        assert manager._segment_speech_start == 0
+
+    def test_speech_padding_off_and_bounded(self):
+        """Test padding of 0 keeps only the speech and lengths are kept within bounds."""
+        manager = _make_manager(speech_pad_before_ms=0, speech_pad_after_ms=-50)
+        assert manager.speech_pad_after_ms == 0
+        buffer = [bytes([i]) for i in range(10)]
+        manager._segment_speech_start = 3
+        assert manager._padded_segment(buffer, 0.256, 0.064) == buffer[3:6]
+        assert manager._onset_padding(buffer, 0.064) == []
+
+        # Speech that started before the buffer was trimmed is kept whole
+        manager._segment_speech_start = 0
+        assert manager._padded_segment(buffer, 0.0, 0.064) == buffer
+
+        manager.reconfigure(speech_pad_before_ms=5000, force_download=False)
+        assert manager.speech_pad_before_ms == 1000
+        assert _make_manager().speech_pad_after_ms == 200
+
+    def test_segment_chunk_gaps_and_device_changes_logged(self):
+        """Test dropped chunks and a change of microphone within a segment are logged."""
+        segment = [
+            AudioChunk(b"\x00\x00", "Laptop Microphone", 44100, 10),
+            AudioChunk(b"\x00\x00", "Laptop Microphone", 44100, 11),
+            AudioChunk(b"\x00\x00", "USB Headset", 48000, 14),
+        ]
+        with patch("vocalinux.speech_recognition.recognition_manager.logger") as log:
+            SpeechRecognitionManager._check_segment_chunks(segment)
+            log.warning.assert_called_once_with("2 audio chunks are missing from this segment")
+            log.info.assert_called_once_with(
+                "Segment was recorded from Laptop Microphone, USB Headset"
+            )
+
+            log.reset_mock()
+            SpeechRecognitionManager._check_segment_chunks([b"\x00\x00", b"\x00\x00"])
+            log.warning.assert_not_called()
+            log.debug.assert_not_called()
+
+    def test_negotiate_capture_format_stereo_48khz_device(self):
+        """Test a stereo-only 48kHz device is opened as such and flagged for conversion."""
+        mock_audio = MagicMock()
+
+        def open_side_effect(**kwargs):
+            if kwargs.get("channels") == 2 and kwargs.get("rate") == 48000:
+                return MagicMock()
+            raise IOError("[Errno -9998] Invalid number of channels")
+
+        mock_audio.open.side_effect = open_side_effect
+        mock_audio.get_device_info_by_index.return_value = {"defaultSampleRate": 48000}
+        mock_pyaudio = MagicMock(paInt16=8)
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            capture_format = negotiate_capture_format(mock_audio, 0)
+
+        assert capture_format == CaptureFormat(rate=48000, channels=2)
+        assert capture_format.needs_downmix
+        assert capture_format.needs_resampling
+        assert capture_format.describe() == "48000Hz, 2 ch, int16"
+
+    def test_monitor_source_recorded_through_default_input(self):
+        """Test a monitor source skips name matching and opens the default input."""
+        manager = _make_manager(
+            audio_device_index=None, audio_device_name="alsa_output.analog-stereo.monitor"
+        )
+        mock_audio = MagicMock()
+        mock_audio.get_default_input_device_info.return_value = {"index": 3}
+        mock_audio.get_device_count.return_value = 4
+        mock_audio.get_device_info_by_index.side_effect = lambda i: {
+            "name": f"Device {i}",
+            "maxInputChannels": 2,
+        }
+
+        assert manager._monitor_source() == "alsa_output.analog-stereo.monitor"
+        assert manager._resolve_capture_device(mock_audio) == 3
+
+        manager.set_audio_device(1, "Device 1")
+        assert manager._monitor_source() is None
+        assert manager._resolve_capture_device(mock_audio) == 1
+
+    def test_speaker_profile_skips_other_voices(self):
+        """Test VOSK utterances in another voice than the enrolled one aren't typed."""
+        from vocalinux.speech_recognition.speaker_profile import SpeakerProfile
+
+        manager = _make_manager(engine="vosk", vosk_speaker_profile=True)
+        manager.recognizer = MagicMock()
+        manager.recognizer.FinalResult.side_effect = [
+            json.dumps({"text": "hello there", "spk": [1.0, 0.1], "spk_frames": 120}),
+            json.dumps({"text": "turn it up", "spk": [-1.0, 0.9], "spk_frames": 90}),
+        ]
+        manager._speaker_profile = SpeakerProfile([1.0, 0.0], 900)
+
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            assert manager._transcribe_buffer([b"\x00\x00"]) == "hello there"
+            mock_notify.assert_not_called()
+            assert manager._transcribe_buffer([b"\x00\x00"]) == ""
+
+        # The skipped text is shown, not dropped without a word
+        mock_notify.assert_called_once()
+        assert '"turn it up"' in mock_notify.call_args[0][1]
+
+    def test_enroll_speaker_saves_profile(self):
+        """Test enrollment averages the speaker model's x-vectors and saves them."""
+        manager = _make_manager(engine="vosk")
+        manager.model = MagicMock()
+        recognizer = MagicMock()
+        recognizer.AcceptWaveform.side_effect = [True, False]
+        recognizer.Result.return_value = json.dumps({"spk": [1.0, 0.0], "spk_frames": 400})
+        recognizer.FinalResult.return_value = json.dumps({"spk": [0.0, 1.0], "spk_frames": 400})
+        vosk = MagicMock()
+        vosk.KaldiRecognizer.return_value = recognizer
+        manager._spk_model = MagicMock()
+
+        with tempfile.TemporaryDirectory() as tmp, patch.dict(sys.modules, {"vosk": vosk}), patch(
+            "vocalinux.speech_recognition.speaker_profile.profile_path",
+            return_value=os.path.join(tmp, "speaker_profile.json"),
+        ):
+            profile = manager.enroll_speaker(b"\x00\x00" * 24000)
+            assert os.path.exists(os.path.join(tmp, "speaker_profile.json"))
+
+        assert profile.xvector == [0.5, 0.5]
+        vosk.KaldiRecognizer.assert_any_call(manager.model, 16000, manager._spk_model)
+        assert recognizer.AcceptWaveform.call_count == 2  # 1.5 s in one-second pieces
+
+        recognizer.AcceptWaveform.side_effect = None
+        recognizer.AcceptWaveform.return_value = False
+        recognizer.FinalResult.return_value = json.dumps({"text": ""})
+        with patch.dict(sys.modules, {"vosk": vosk}):
+            with pytest.raises(RuntimeError):
+                manager.enroll_speaker(b"\x00\x00" * 1600)
+
+    def test_app_stream_follows_the_application(self):
+        """Test an application's playback is found again each time it re-creates its stream."""
+        manager = _make_manager(audio_device_index=None, audio_device_name="app:Firefox")
+        target = "vocalinux.speech_recognition.recognition_manager.find_app_stream"
+
+        with patch(target, return_value=None), patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as notify:
+            assert manager._find_app_stream() is False
+        notify.assert_called_once()
+        assert manager._monitor_source() is None
+
+        with patch(target, return_value="812") as find:
+            assert manager._find_app_stream() is True
+        find.assert_called_once_with("Firefox")
+        assert manager._monitor_source() == "812"
+        assert manager._capture_source() == "app:Firefox"
+
+        manager._reopen_capture_stream = MagicMock(return_value=True)
+        with patch(target, return_value="812"):
+            assert manager._follow_app_stream(MagicMock()) is None
+        manager._reopen_capture_stream.assert_not_called()
+        with patch(target, return_value="1207"):
+            assert manager._follow_app_stream(MagicMock()) is None
+        manager._reopen_capture_stream.assert_called_once()
+        assert manager._monitor_source() == "1207"
+        with patch(target, return_value=None):
+            assert manager._follow_app_stream(MagicMock()) == "Firefox stopped playing audio."
+
+    def test_negotiate_capture_format_float_only_device(self):
+        """Test a device that only delivers 32-bit float is opened in that format."""
+        mock_audio = MagicMock()
+
+        def open_side_effect(**kwargs):
+            if kwargs.get("format") == 1:
+                return MagicMock()
+            raise IOError("[Errno -9994] Sample format not supported")
+
+        mock_audio.open.side_effect = open_side_effect
+        mock_audio.get_device_info_by_index.return_value = {"defaultSampleRate": 48000}
+        mock_pyaudio = MagicMock(paInt16=8, paFloat32=1, paInt32=2, paInt24=4, paUInt8=32)
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            capture_format = negotiate_capture_format(mock_audio, 0)
+            assert capture_format.pyaudio_format == 1
+
+        assert capture_format == CaptureFormat(rate=48000, channels=1, sample_format="float32")
+        assert capture_format.describe() == "48000Hz, 1 ch, float32"
+
+    def test_negotiate_capture_format_native_16khz_mono(self):
+        """Test a device that takes 16kHz mono needs no conversion."""
+        mock_audio = MagicMock()
+        mock_audio.get_device_info_by_index.return_value = {"defaultSampleRate": 16000}
+        mock_pyaudio = MagicMock(paInt16=8)
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pyaudio}):
+            capture_format = negotiate_capture_format(mock_audio, 0)
+
+        assert capture_format == CaptureFormat(rate=16000, channels=1)
+        assert not capture_format.needs_downmix
+        assert not capture_format.needs_resampling
+
 
 class TestFilterNonSpeech(unittest.TestCase):
     """Test the _filter_non_speech function."""
@@ -749,6 +1121,16 @@ class TestEnqueueAudioSegment(unittest.TestCase):
         manager._enqueue_audio_segment([b"new"])
         assert manager._segment_queue.get_nowait() == [b"new"]
 
+    def test_short_segment_waits_behind_earlier_paragraph(self):
+        """Segments are decoded in capture order, so "delete that" follows its paragraph."""
+        manager = _make_manager()
+        paragraph = [b"\x00" * 32000] * 10
+        command = [b"\x01" * 32000]
+        manager._enqueue_audio_segment(paragraph)
+        manager._enqueue_audio_segment(command)
+        assert manager._segment_queue.get_nowait() == paragraph
+        assert manager._segment_queue.get_nowait() == command
+
 
 class TestPerformRecognition(unittest.TestCase):
     """Direct tests for _perform_recognition (not mocked)."""
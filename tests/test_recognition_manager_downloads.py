@@ -183,6 +183,29 @@ class TestDownloadWhispercppModel:
         assert "download=true" in called_url
         assert mock_requests.get.call_args[1].get("timeout") == manager._MODEL_DOWNLOAD_TIMEOUT
 
+    def test_download_whispercpp_from_mirror(self, tmp_path):
+        """The hf-mirror download source replaces the Hugging Face host."""
+        manager = _make_manager(engine="whisper_cpp", download_mirror="hf-mirror")
+        model_file = str(tmp_path / "ggml-small.bin")
+
+        mock_requests = MagicMock()
+        mock_response = MagicMock()
+        mock_response.headers = {"content-length": "4", "content-type": "application/octet-stream"}
+        mock_response.iter_content.return_value = [b"data"]
+        mock_requests.get.return_value = mock_response
+        mock_requests.exceptions.RequestException = Exception
+
+        with patch.dict("sys.modules", {"requests": mock_requests}):
+            with patch(
+                "vocalinux.speech_recognition.recognition_manager.get_model_path",
+                return_value=model_file,
+            ):
+                manager._download_whispercpp_model()
+
+        called_url = mock_requests.get.call_args[0][0]
+        assert called_url.startswith("https://hf-mirror.com/ggerganov/whisper.cpp/")
+        assert "download=true" in called_url
+
     def test_download_whispercpp_timeout_message(self, tmp_path):
         """Timeout-like errors surface a dedicated user-facing message."""
         manager = _make_manager(engine="whisper_cpp")
@@ -323,6 +346,25 @@ class TestDownloadVoskModel:
         assert len(call_args[0]) > 0 or "url" in call_args[1]
         assert len(progress_calls) >= 1
 
+    def test_download_vosk_from_mirror_url(self, tmp_path):
+        """A VOSK mirror URL replaces alphacephei.com."""
+        manager = _make_manager(engine="vosk")
+        manager.set_download_options(vosk_mirror_url="https://mirror.example/vosk/")
+
+        mock_requests = MagicMock()
+        mock_requests.get.side_effect = Exception("Network error")
+        mock_requests.exceptions.RequestException = Exception
+
+        with patch.dict("sys.modules", {"requests": mock_requests}):
+            with patch(
+                "vocalinux.speech_recognition.recognition_manager.MODELS_DIR", str(tmp_path)
+            ):
+                with pytest.raises(RuntimeError):
+                    manager._download_vosk_model()
+
+        called_url = mock_requests.get.call_args[0][0]
+        assert called_url == "https://mirror.example/vosk/model-en-us-0.22-lgraph.zip"
+
     def test_download_vosk_request_error(self, tmp_path):
         """Test Vosk download request error handling."""
         manager = _make_manager(engine="vosk")
@@ -361,6 +403,32 @@ class TestAudioReconnection:
         assert result is True
         assert manager._audio_stream == mock_stream
 
+    def test_attempt_audio_reconnection_reloads_echo_canceller(self):
+        """Test the echo-cancelled source is loaded again and recorded from after reconnecting."""
+        manager = _make_manager(engine="whisper_cpp", echo_cancel=True)
+        manager._echo_canceller = MagicMock()
+        manager._echo_canceller.start.return_value = "vocalinux_ec_source"
+        mock_audio_instance = MagicMock()
+        mock_audio_instance.get_default_input_device_info.return_value = {"index": 0}
+        mock_audio_instance.open.return_value.read.return_value = b"\x00" * 1024
+
+        with (
+            patch.dict("sys.modules", {"pyaudio": MagicMock()}),
+            patch("time.sleep"),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._resolve_valid_input_device",
+                return_value=0,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager.pulse_source"
+            ) as mock_pulse_source,
+        ):
+            assert manager._attempt_audio_reconnection(mock_audio_instance) is True
+
+        manager._echo_canceller.stop.assert_called_once()
+        manager._echo_canceller.start.assert_called_once()
+        mock_pulse_source.assert_called_once_with("vocalinux_ec_source")
+
     def test_attempt_audio_reconnection_falls_back_to_default_resolver(self):
         """Test reconnection falls back when saved device name/index cannot resolve."""
         manager = _make_manager(engine="whisper_cpp", audio_device_name="Missing Mic")
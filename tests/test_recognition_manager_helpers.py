@@ -242,6 +242,69 @@ class TestTestAudioInput(unittest.TestCase):
             result = _test_audio_input()
         self.assertIsInstance(result, dict)
 
+    def test_audio_input_plays_recording_back(self):
+        mock_pa_mod = MagicMock()
+        mock_pa_inst = MagicMock()
+        mock_pa_mod.PyAudio.return_value = mock_pa_inst
+        mock_pa_mod.paInt16 = 8
+        mock_pa_inst.get_default_input_device_info.return_value = {
+            "name": "Test Mic",
+            "index": 0,
+            "defaultSampleRate": 16000,
+        }
+        input_stream, output_stream = MagicMock(), MagicMock()
+        mock_pa_inst.open.side_effect = [input_stream, output_stream]
+        input_stream.read.return_value = b"\x00\x10" * 1024
+        on_playback = MagicMock()
+
+        mock_np = MagicMock()
+        mock_np.abs.return_value = [4096] * 1024
+        mock_np.max.return_value = 4096.0
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pa_mod, "numpy": mock_np}), patch.multiple(
+            rm,
+            negotiate_capture_format=MagicMock(return_value=rm.CaptureFormat(16000, 1)),
+            rms_dbfs=MagicMock(return_value=-20.0),
+            ChannelDownmixer=MagicMock(return_value=MagicMock(process=lambda data: data)),
+            apply_gain=lambda data, gain: data,
+        ):
+            result = _test_audio_input(duration=0.2, playback=True, on_playback=on_playback)
+
+        self.assertTrue(result["played_back"])
+        on_playback.assert_called_once()
+        self.assertTrue(mock_pa_inst.open.call_args_list[1].kwargs["output"])
+        # Everything recorded is played back in one piece
+        played = output_stream.write.call_args.args[0]
+        self.assertEqual(played, b"\x00\x10" * 1024 * 3)
+        output_stream.close.assert_called_once()
+
+    def test_audio_input_opens_the_negotiated_format(self):
+        mock_pa_mod = MagicMock()
+        mock_pa_inst = MagicMock()
+        mock_pa_mod.PyAudio.return_value = mock_pa_inst
+        mock_pa_inst.get_default_input_device_info.return_value = {"name": "Mic", "index": 0}
+        mock_pa_inst.open.return_value.read.return_value = b"\x00" * 8192
+        to_int16 = MagicMock(side_effect=lambda data, sample_format: data[: len(data) // 2])
+        mock_np = MagicMock()
+
+        with patch.dict("sys.modules", {"pyaudio": mock_pa_mod, "numpy": mock_np}), patch.multiple(
+            rm,
+            negotiate_capture_format=MagicMock(
+                return_value=rm.CaptureFormat(48000, 2, sample_format="float32")
+            ),
+            to_int16=to_int16,
+            rms_dbfs=MagicMock(return_value=-20.0),
+            ChannelDownmixer=MagicMock(return_value=MagicMock(process=lambda data: data)),
+            apply_gain=lambda data, gain: data,
+        ):
+            result = _test_audio_input(duration=0.1)
+
+        kwargs = mock_pa_inst.open.call_args.kwargs
+        self.assertEqual(kwargs["format"], mock_pa_mod.paFloat32)
+        self.assertEqual((kwargs["channels"], kwargs["rate"]), (2, 48000))
+        self.assertEqual(to_int16.call_args.args[1], "float32")
+        self.assertEqual(result["sample_rate"], 48000)
+
     def test_audio_input_import_error(self):
         # When pyaudio is not available
         with patch.dict("sys.modules", {"pyaudio": None}):
@@ -0,0 +1,68 @@
+"""
+Tests for decoding performance reporting (real-time factor and queue depth).
+"""
+
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+
+def _make_manager(engine="whisper_cpp"):
+    """Create a SpeechRecognitionManager with engine initialization patched out."""
+    with patch.object(SpeechRecognitionManager, "_init_vosk"), patch.object(
+        SpeechRecognitionManager, "_init_whisper"
+    ), patch.object(SpeechRecognitionManager, "_init_whispercpp"):
+        return SpeechRecognitionManager(engine=engine, model_size="small", language="en-us")
+
+
+class TestPerformanceStats(unittest.TestCase):
+    """Tests for get_performance_stats and get_performance_summary."""
+
+    def setUp(self):
+        self.manager = _make_manager()
+
+    def test_initial_stats(self):
+        self.assertEqual(self.manager.get_performance_stats(), {"rtf": None, "queued_segments": 0})
+        self.assertEqual(
+            self.manager.get_performance_summary(),
+            "no utterances decoded yet, no utterances queued",
+        )
+
+    def test_summary_slower_than_realtime(self):
+        self.manager._last_rtf = 1.8
+        self.manager._segment_queue.put_nowait([b"\x00"])
+        self.manager._segment_queue.put_nowait([b"\x00"])
+        self.assertEqual(
+            self.manager.get_performance_summary(),
+            "decoding 1.8× slower than realtime, 2 utterances queued",
+        )
+
+    def test_summary_faster_than_realtime(self):
+        self.manager._last_rtf = 0.25
+        self.manager._segment_queue.put_nowait([b"\x00"])
+        self.assertEqual(
+            self.manager.get_performance_summary(),
+            "decoding 4.0× faster than realtime, 1 utterance queued",
+        )
+
+    def test_process_audio_buffer_records_rtf(self):
+        self.manager._transcribe_with_whispercpp = MagicMock(return_value="")
+        # One second of 16 kHz 16-bit audio decoded in half a second
+        audio = [b"\x00\x00" * 16000]
+        with patch(
+            "vocalinux.speech_recognition.recognition_manager.time.time",
+            side_effect=[100.0, 100.5],
+        ):
+            self.manager._process_audio_buffer(audio)
+
+        self.assertAlmostEqual(self.manager.get_performance_stats()["rtf"], 0.5)
+
+    def test_unknown_engine_does_not_record_rtf(self):
+        self.manager.engine = "unknown"
+        self.manager._process_audio_buffer([b"\x00\x00"])
+        self.assertIsNone(self.manager.get_performance_stats()["rtf"])
+
+
+if __name__ == "__main__":
+    unittest.main()
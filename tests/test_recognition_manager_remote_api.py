@@ -38,6 +38,7 @@ def _mock_heavy_deps(monkeypatch):
     # requests needs to keep real exception classes for except statements to work properly
     mock_requests = MagicMock()
     mock_requests.exceptions.ConnectionError = ConnectionError
+    mock_requests.exceptions.Timeout = TimeoutError
     mock_requests.exceptions.RequestException = Exception
     monkeypatch.setitem(sys.modules, "requests", mock_requests)
 
@@ -379,6 +380,43 @@ class TestRemoteAPITranscription(unittest.TestCase):
             # Should pass None for auto detection
             mock_server.assert_called_once_with(ANY, None, ANY, ANY)
 
+    def test_transcribe_rejected_request_notifies(self):
+        """Test a request the server rejects is reported, and not as unreachable."""
+        from unittest.mock import patch
+
+        with patch.object(
+            self.manager, "_try_whispercpp_server_api", return_value=None
+        ), patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            result = self.manager._transcribe_with_remote_api(
+                [b"audio"], self.manager._http_session
+            )
+
+        self.assertEqual(result, "")
+        self.assertFalse(self.manager._remote_api_unreachable)
+        self.assertEqual(mock_notify.call_args.args[0], "Remote Transcription Failed")
+
+    def test_transcribe_unreachable_leaves_notifying_to_caller(self):
+        """Test an unreachable server isn't reported here; the offline fallback may cover it."""
+        from unittest.mock import patch
+
+        def unreachable(*args):
+            self.manager._remote_api_unreachable = True
+            return None
+
+        with patch.object(
+            self.manager, "_try_whispercpp_server_api", side_effect=unreachable
+        ), patch(
+            "vocalinux.speech_recognition.recognition_manager._show_notification"
+        ) as mock_notify:
+            result = self.manager._transcribe_with_remote_api(
+                [b"audio"], self.manager._http_session
+            )
+
+        self.assertEqual(result, "")
+        mock_notify.assert_not_called()
+
     def test_transcribe_unexpected_exception_returns_empty(self):
         """An unexpected exception inside transcription is caught and returns ''."""
         from unittest.mock import patch
@@ -391,6 +429,7 @@ class TestRemoteAPITranscription(unittest.TestCase):
             )
 
             self.assertEqual(result, "")
+            self.assertFalse(self.manager._remote_api_unreachable)
 
 
 class TestOpenAIAPIFormat(unittest.TestCase):
@@ -509,6 +548,27 @@ class TestOpenAIAPIFormat(unittest.TestCase):
         result = self.manager._try_openai_api(b"wav", "en", {}, self.manager._http_session)
 
         self.assertIsNone(result)
+        # A rejected key is not a reason to fall back to a local model
+        self.assertFalse(self.manager._remote_api_unreachable)
+
+    def test_try_openai_api_unreachable_on_connection_error_or_timeout(self):
+        """Test only connection errors and timeouts mark the server unreachable."""
+        for error in (ConnectionError("Connection refused"), TimeoutError("Read timed out")):
+            self.manager._remote_api_unreachable = False
+            _setup_requests_post_error(error)
+
+            result = self.manager._try_openai_api(b"wav", "en", {}, self.manager._http_session)
+
+            self.assertIsNone(result)
+            self.assertTrue(self.manager._remote_api_unreachable)
+
+        self.manager._remote_api_unreachable = False
+        _setup_requests_post_error(None)
+        _setup_requests_post_ok({}).return_value.json.side_effect = ValueError("malformed")
+        self.assertIsNone(
+            self.manager._try_openai_api(b"wav", "en", {}, self.manager._http_session)
+        )
+        self.assertFalse(self.manager._remote_api_unreachable)
 
 
 class TestWhisperCppServerAPIFormat(unittest.TestCase):
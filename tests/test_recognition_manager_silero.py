@@ -39,7 +39,9 @@ if "gi.repository" not in sys.modules:
     sys.modules["gi.repository"] = MagicMock()
 
 from vocalinux.common_types import RecognitionState  # noqa: E402
+from vocalinux.utils.device_monitor import DeviceChange  # noqa: E402
 from vocalinux.speech_recognition.recognition_manager import (  # noqa: E402
+    MAX_CONSECUTIVE_CHUNK_ERRORS,
     SpeechRecognitionManager,
 )
 
@@ -336,6 +338,294 @@ class TestRecordAudioAmplitudeFallback(unittest.TestCase):
         self.assertEqual(len(self.enqueued), 0)
 
 
+class TestRecordAudioChunkErrors(unittest.TestCase):
+    """A chunk that fails to process must not end capture silently."""
+
+    def setUp(self):
+        self.mgr = _make_manager()
+        self.mgr.state = RecognitionState.LISTENING
+        self.mgr.should_record = True
+        self.mgr.audio_buffer = []
+        self.mgr.silence_timeout = 10.0
+        self.mgr._silero_vad = None
+        self.mgr._enqueue_audio_segment = lambda buf: None
+        self.states = []
+        self.mgr._update_state = self.states.append
+
+    def _drive(self, convert, max_iters):
+        stream = MagicMock()
+        counter = {"n": 0}
+
+        def _read(*a, **kw):
+            counter["n"] += 1
+            if counter["n"] >= max_iters:
+                self.mgr.should_record = False
+            return b"\x00" * (1024 * 2)
+
+        stream.read.side_effect = _read
+        pyaudio_mod, _ = _make_pyaudio_module(stream)
+        with (
+            patch.dict(sys.modules, {"pyaudio": pyaudio_mod, "numpy": np}),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._get_supported_channels",
+                return_value=1,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._get_supported_sample_rate",
+                return_value=16000,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager.to_int16",
+                side_effect=convert,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._show_notification"
+            ) as notify,
+            patch("vocalinux.ui.audio_feedback.play_error_sound"),
+        ):
+            self.mgr._record_audio()
+        return stream, notify
+
+    def test_bad_chunk_is_dropped_and_recording_continues(self):
+        calls = {"n": 0}
+
+        def _convert(data, _fmt):
+            calls["n"] += 1
+            if calls["n"] == 2:
+                raise ValueError("buffer size must be a multiple of element size")
+            return data
+
+        stream, notify = self._drive(_convert, max_iters=6)
+
+        self.assertEqual(stream.read.call_count, 6)
+        self.assertEqual(len(self.mgr.audio_buffer), 5)
+        self.assertNotIn(RecognitionState.ERROR, self.states)
+        notify.assert_not_called()
+
+    def test_repeated_failures_stop_recording_with_an_error(self):
+        stream, notify = self._drive(MagicMock(side_effect=ValueError("bad")), max_iters=1000)
+
+        self.assertEqual(stream.read.call_count, MAX_CONSECUTIVE_CHUNK_ERRORS)
+        self.assertEqual(self.states, [RecognitionState.ERROR])
+        self.assertEqual(notify.call_args[0][0], "Recording Stopped")
+
+
+class TestRecordAudioStreamErrors(unittest.TestCase):
+    """A stream error rebuilds the stream instead of leaving capture deaf."""
+
+    def setUp(self):
+        self.mgr = _make_manager()
+        self.mgr.state = RecognitionState.LISTENING
+        self.mgr.should_record = True
+        self.mgr.audio_buffer = []
+        self.mgr.silence_timeout = 10.0
+        self.mgr._silero_vad = None
+        self.mgr._enqueue_audio_segment = lambda buf: None
+        self.states = []
+        self.mgr._update_state = self.states.append
+
+    def _drive(self, stream, reconnect):
+        pyaudio_mod, _ = _make_pyaudio_module(stream)
+        with (
+            patch.dict(sys.modules, {"pyaudio": pyaudio_mod, "numpy": np}),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._get_supported_channels",
+                return_value=1,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._get_supported_sample_rate",
+                return_value=16000,
+            ),
+            patch.object(self.mgr, "_attempt_audio_reconnection", side_effect=reconnect),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._show_notification"
+            ) as notify,
+            patch("vocalinux.ui.audio_feedback.play_error_sound"),
+        ):
+            self.mgr._record_audio()
+        return notify
+
+    def _failing_stream(self):
+        stream = MagicMock()
+        stream.read.side_effect = IOError("Stream closed")
+        return stream
+
+    def test_stream_is_rebuilt_after_an_error(self):
+        new_stream = MagicMock()
+        reads = {"n": 0}
+
+        def _read(*a, **kw):
+            reads["n"] += 1
+            if reads["n"] >= 3:
+                self.mgr.should_record = False
+            return SILENT_CHUNK
+
+        new_stream.read.side_effect = _read
+
+        def _reconnect(_audio):
+            self.mgr._reconnection_attempts += 1
+            self.mgr._audio_stream = new_stream
+            return True
+
+        notify = self._drive(self._failing_stream(), _reconnect)
+
+        self.assertEqual(new_stream.read.call_count, 3)
+        self.assertEqual(len(self.mgr.audio_buffer), 3)
+        self.assertNotIn(RecognitionState.ERROR, self.states)
+        notify.assert_not_called()
+
+    def test_gives_up_after_bounded_retries(self):
+        attempts = []
+
+        def _reconnect(_audio):
+            self.mgr._reconnection_attempts += 1
+            attempts.append(self.mgr._reconnection_attempts)
+            return False
+
+        notify = self._drive(self._failing_stream(), _reconnect)
+
+        self.assertEqual(attempts, [1, 2, 3, 4, 5])
+        self.assertEqual(self.states, [RecognitionState.ERROR])
+        self.assertEqual(notify.call_args[0][0], "Recording Stopped")
+        self.assertIn("Stream closed", notify.call_args[0][1])
+
+    def test_errors_in_quick_succession_share_the_retry_budget(self):
+        self.mgr._attempt_audio_reconnection = MagicMock(return_value=True)
+        with patch("vocalinux.speech_recognition.recognition_manager.time.time") as clock:
+            clock.return_value = 1000.0
+            self.mgr._reconnection_attempts = 3
+            self.assertTrue(self.mgr._recover_capture_stream(MagicMock()))
+            self.assertEqual(self.mgr._reconnection_attempts, 0)
+
+            self.mgr._reconnection_attempts = 3
+            clock.return_value = 1002.0
+            self.mgr._recover_capture_stream(MagicMock())
+            self.assertEqual(self.mgr._reconnection_attempts, 3)
+
+        self.mgr.should_record = False
+        self.assertFalse(self.mgr._recover_capture_stream(MagicMock()))
+
+
+class TestRecordAudioDeviceHotplug(unittest.TestCase):
+    """Plugging an input device in or out reopens the microphone."""
+
+    def setUp(self):
+        self.mgr = _make_manager()
+        self.mgr.state = RecognitionState.LISTENING
+        self.mgr.should_record = True
+        self.mgr.audio_buffer = []
+        self.mgr.silence_timeout = 10.0
+        self.mgr._silero_vad = None
+        self.mgr._enqueue_audio_segment = lambda buf: None
+        self.states = []
+        self.mgr._update_state = self.states.append
+
+    def _stream(self, reads):
+        stream = MagicMock()
+        counter = {"n": 0}
+
+        def _read(*a, **kw):
+            counter["n"] += 1
+            if counter["n"] >= reads:
+                self.mgr.should_record = False
+            return b"\x00" * (1024 * 2)
+
+        stream.read.side_effect = _read
+        return stream
+
+    def _drive(self, first_audio, second_audio, change):
+        monitor = MagicMock()
+        monitor.take_change.side_effect = [DeviceChange()] * 2 + [change] + [DeviceChange()] * 50
+        pyaudio_mod = MagicMock(
+            paInt16=8, PyAudio=MagicMock(side_effect=[first_audio, second_audio])
+        )
+        with (
+            patch.dict(sys.modules, {"pyaudio": pyaudio_mod, "numpy": np}),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._get_supported_channels",
+                return_value=1,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._get_supported_sample_rate",
+                return_value=16000,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager.DeviceMonitor",
+                return_value=monitor,
+            ),
+            patch(
+                "vocalinux.speech_recognition.recognition_manager._show_notification"
+            ) as notify,
+            patch("vocalinux.ui.audio_feedback.play_error_sound"),
+        ):
+            self.mgr._record_audio()
+        monitor.stop.assert_called_once()
+        return notify
+
+    def test_unplugged_device_switches_to_the_new_default(self):
+        old_stream = self._stream(reads=1000)
+        first_audio = _make_pyaudio_module(old_stream)[1]
+        new_stream = self._stream(reads=4)
+        second_audio = _make_pyaudio_module(new_stream)[1]
+        second_audio.get_device_info_by_index.return_value = {
+            "index": 0,
+            "name": "Built-in Microphone",
+            "maxInputChannels": 1,
+            "defaultSampleRate": 16000,
+        }
+
+        notify = self._drive(
+            first_audio, second_audio, DeviceChange(removed=frozenset({"usb-headset"}))
+        )
+
+        self.assertEqual(old_stream.read.call_count, 2)
+        first_audio.terminate.assert_called_once()
+        self.assertEqual(new_stream.read.call_count, 4)
+        self.assertNotIn(RecognitionState.ERROR, self.states)
+        self.assertEqual(notify.call_args[0][0], "Microphone Changed")
+        self.assertIn("Built-in Microphone", notify.call_args[0][1])
+
+    def test_no_input_left_stops_with_an_error(self):
+        first_audio = _make_pyaudio_module(self._stream(reads=1000))[1]
+        second_audio = _make_pyaudio_module(MagicMock())[1]
+        second_audio.open.side_effect = IOError("No Default Input Device Available")
+
+        notify = self._drive(
+            first_audio, second_audio, DeviceChange(removed=frozenset({"usb-headset"}))
+        )
+
+        self.assertEqual(self.states, [RecognitionState.ERROR])
+        self.assertEqual(notify.call_args[0][0], "Recording Stopped")
+        second_audio.terminate.assert_called_once()
+
+    def test_only_a_returning_selected_device_reopens_on_plug_in(self):
+        added = DeviceChange(added=frozenset({"usb-headset"}))
+        self.mgr._capture_device_name = "Built-in Microphone"
+        self.assertFalse(self.mgr._device_change_affects_capture(added))
+
+        self.mgr.audio_device_name = "USB Headset: Audio (hw:2,0)"
+        self.assertTrue(self.mgr._device_change_affects_capture(added))
+        self.mgr._capture_device_name = "USB Headset: Audio (hw:2,0)"
+        self.assertFalse(self.mgr._device_change_affects_capture(added))
+        self.assertTrue(
+            self.mgr._device_change_affects_capture(DeviceChange(removed=frozenset({"x"})))
+        )
+
+    def test_default_input_follows_a_new_default_source(self):
+        switched = DeviceChange(default_source="bluez_input.headset")
+        self.mgr.audio_device_name = None
+        self.mgr.audio_device_index = None
+        self.assertTrue(self.mgr._device_change_affects_capture(switched))
+
+        # A microphone picked in Settings stays in use
+        self.mgr.audio_device_index = 3
+        self.assertFalse(self.mgr._device_change_affects_capture(switched))
+        self.mgr.audio_device_index = None
+        self.mgr.audio_device_name = "USB Headset: Audio (hw:2,0)"
+        self.mgr._capture_device_name = "USB Headset: Audio (hw:2,0)"
+        self.assertFalse(self.mgr._device_change_affects_capture(switched))
+
+
 class TestAudioLevelCallback(unittest.TestCase):
     """The audio-level callback list is iterated every chunk with normalized
     level in [0, 100]. Verify a registered callback receives values.
@@ -0,0 +1,73 @@
+"""
+Tests for the remote server connection test.
+"""
+
+import unittest
+from unittest.mock import MagicMock
+
+import requests
+
+from vocalinux.speech_recognition.remote_connection import test_connection as run_test
+
+
+def _response(status_code):
+    return MagicMock(status_code=status_code)
+
+
+def _session(*results):
+    session = MagicMock()
+    session.get.side_effect = list(results)
+    return session
+
+
+class TestRemoteConnection(unittest.TestCase):
+    """Tests for test_connection."""
+
+    def test_whispercpp_server_connected(self):
+        session = _session(_response(200), _response(405))
+        diagnosis = run_test("http://server:8080/", "", "/inference", session)
+        self.assertTrue(diagnosis.ok)
+        self.assertEqual(diagnosis.server_info, "whisper.cpp server")
+        self.assertEqual(session.get.call_args_list[1][0][0], "http://server:8080/inference")
+
+    def test_dns_failure(self):
+        error = requests.exceptions.ConnectionError(
+            "Failed to establish a new connection: [Errno -2] Name or service not known"
+        )
+        diagnosis = run_test("http://nosuchhost", session=_session(error))
+        self.assertFalse(diagnosis.ok)
+        self.assertEqual(diagnosis.cause, "dns")
+
+    def test_refused_and_tls_and_timeout(self):
+        cases = {
+            "refused": requests.exceptions.ConnectionError("[Errno 111] Connection refused"),
+            "tls": requests.exceptions.SSLError("certificate verify failed"),
+            "timeout": requests.exceptions.ConnectTimeout("timed out"),
+        }
+        for cause, error in cases.items():
+            diagnosis = run_test("https://server", session=_session(error))
+            self.assertEqual(diagnosis.cause, cause)
+
+    def test_bad_key_and_quota_from_openai_models(self):
+        for status, cause in ((401, "unauthorized"), (429, "quota"), (402, "quota")):
+            session = _session(_response(404), _response(status))
+            diagnosis = run_test("https://api", "sk-bad", "/v1/audio/transcriptions", session)
+            self.assertEqual(diagnosis.cause, cause)
+            self.assertEqual(diagnosis.status_code, status)
+            headers = session.get.call_args[1]["headers"]
+            self.assertEqual(headers["Authorization"], "Bearer sk-bad")
+
+    def test_wrong_endpoint_format(self):
+        session = _session(_response(200), _response(404))
+        diagnosis = run_test("http://server", endpoint="/inference", session=session)
+        self.assertEqual(diagnosis.cause, "not_found")
+        self.assertIn("/inference", diagnosis.detail)
+
+    def test_invalid_url(self):
+        self.assertEqual(run_test("").cause, "invalid_url")
+        error = requests.exceptions.MissingSchema("No scheme supplied")
+        self.assertEqual(run_test("server:8080", session=_session(error)).cause, "invalid_url")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,187 @@
+"""
+Tests for resampling between capture and the speech engines.
+"""
+
+import unittest
+
+import numpy as np
+
+from vocalinux.speech_recognition.buffer_pool import BufferPool
+from vocalinux.speech_recognition.resample import (
+    DOWNMIX_CHOOSE_CHUNKS,
+    ChannelDownmixer,
+    StreamResampler,
+    apply_gain,
+    downmix_to_mono,
+    lowpass_taps,
+    resample_audio,
+    to_int16,
+)
+
+
+def _tone(frequency, rate, seconds=1.0, amplitude=10000):
+    t = np.arange(int(rate * seconds)) / rate
+    return (amplitude * np.sin(2 * np.pi * frequency * t)).astype(np.int16).tobytes()
+
+
+def _samples(data):
+    return np.frombuffer(data, dtype=np.int16)
+
+
+class TestToInt16(unittest.TestCase):
+    """Tests for to_int16."""
+
+    def test_float32_scaled_and_clipped(self):
+        data = np.array([0.0, 0.5, -1.0, 2.0], dtype="<f4").tobytes()
+        self.assertEqual(_samples(to_int16(data, "float32")).tolist(), [0, 16384, -32767, 32767])
+
+    def test_wider_ints_keep_top_bits(self):
+        int32 = np.array([0x12340000, -0x10000], dtype="<i4").tobytes()
+        self.assertEqual(_samples(to_int16(int32, "int32")).tolist(), [0x1234, -1])
+        int24 = bytes([0x00, 0x34, 0x12, 0xFF, 0xFF, 0xFF])
+        self.assertEqual(_samples(to_int16(int24, "int24")).tolist(), [0x1234, -1])
+
+    def test_uint8_recentred(self):
+        data = bytes([128, 255, 0])
+        self.assertEqual(_samples(to_int16(data, "uint8")).tolist(), [0, 127 << 8, -32768])
+
+    def test_int16_unchanged(self):
+        data = b"\x01\x00\x02\x00"
+        self.assertIs(to_int16(data, "int16"), data)
+
+    def test_partial_trailing_sample_dropped(self):
+        self.assertEqual(to_int16(b"\x01\x00\x02", "int16"), b"\x01\x00")
+        data = np.array([0.5], dtype="<f4").tobytes()
+        self.assertEqual(_samples(to_int16(data + b"\x00\x00", "float32")).tolist(), [16384])
+        self.assertEqual(to_int16(b"\x00\x00", "int32"), b"")
+
+
+class TestDownmix(unittest.TestCase):
+    """Tests for downmix_to_mono."""
+
+    def test_averages_channels(self):
+        stereo = np.array([100, 300, -200, 0], dtype=np.int16).tobytes()
+        self.assertEqual(_samples(downmix_to_mono(stereo, 2)).tolist(), [200, -100])
+
+    def test_unused_inputs_left_out(self):
+        # Microphone on input 1 of a 4-channel interface, hiss on the others
+        frames = np.array([[8000, 3, -2, 1], [-6000, -1, 2, 0]], dtype=np.int16)
+        self.assertEqual(_samples(downmix_to_mono(frames.tobytes(), 4)).tolist(), [8000, -6000])
+        silent = np.zeros(8, dtype=np.int16).tobytes()
+        self.assertEqual(_samples(downmix_to_mono(silent, 4)).tolist(), [0, 0])
+
+    def test_mono_unchanged(self):
+        data = b"\x01\x00\x02\x00"
+        self.assertIs(downmix_to_mono(data, 1), data)
+
+    def test_partial_frame_dropped(self):
+        stereo = np.array([100, 300, 5], dtype=np.int16).tobytes() + b"\x01"
+        self.assertEqual(_samples(downmix_to_mono(stereo, 2)).tolist(), [200])
+
+
+class TestChannelDownmixer(unittest.TestCase):
+    """Tests for ChannelDownmixer."""
+
+    def test_channels_chosen_once_per_stream(self):
+        downmixer = ChannelDownmixer(2)
+        # Hiss only: both channels are averaged until the stream gets loud
+        hiss = np.array([[2, 4], [-2, -4]], dtype=np.int16).tobytes()
+        self.assertEqual(_samples(downmixer.process(hiss)).tolist(), [3, -3])
+
+        speech = np.array([[8000, 40], [-6000, -40]], dtype=np.int16).tobytes()
+        for _ in range(DOWNMIX_CHOOSE_CHUNKS):
+            downmixer.process(speech)
+        self.assertEqual(_samples(downmixer.process(speech)).tolist(), [8000, -6000])
+        # The second channel getting louder for a moment doesn't change the level
+        burst = np.array([[8000, 4000], [-6000, -4000]], dtype=np.int16).tobytes()
+        self.assertEqual(_samples(downmixer.process(burst)).tolist(), [8000, -6000])
+
+        downmixer.reset()
+        self.assertEqual(_samples(downmixer.process(burst)).tolist(), [6000, -5000])
+
+    def test_mono_unchanged(self):
+        data = b"\x01\x00\x02\x00"
+        self.assertIs(ChannelDownmixer(1).process(data), data)
+
+
+class TestApplyGain(unittest.TestCase):
+    """Tests for apply_gain."""
+
+    def test_scales_and_clips(self):
+        data = np.array([1000, -1000, 20000, -20000], dtype=np.int16).tobytes()
+        self.assertIs(apply_gain(data, 1.0), data)
+        self.assertEqual(_samples(apply_gain(data, 2.0)).tolist(), [2000, -2000, 32767, -32768])
+        self.assertEqual(_samples(apply_gain(data, 0.5)).tolist(), [500, -500, 10000, -10000])
+
+    def test_pooled_chunks_stay_independent(self):
+        pool = BufferPool()
+        first = apply_gain(np.array([1000, -1000], dtype=np.int16).tobytes(), 2.0, pool)
+        second = apply_gain(np.array([300, 20000], dtype=np.int16).tobytes(), 2.0, pool)
+        self.assertEqual(_samples(first).tolist(), [2000, -2000])
+        self.assertEqual(_samples(second).tolist(), [600, 32767])
+
+
+class TestLowpassTaps(unittest.TestCase):
+    """Tests for lowpass_taps."""
+
+    def test_unity_gain_and_symmetric(self):
+        taps = lowpass_taps(0.15)
+        self.assertAlmostEqual(taps.sum(), 1.0)
+        np.testing.assert_allclose(taps, taps[::-1])
+
+
+class TestStreamResampler(unittest.TestCase):
+    """Tests for StreamResampler."""
+
+    def test_same_rate_passes_through(self):
+        resampler = StreamResampler(16000)
+        data = _tone(440, 16000, 0.1)
+        self.assertTrue(resampler.passthrough)
+        self.assertIs(resampler.process(data), data)
+
+    def test_chunked_output_length_matches_duration(self):
+        for rate in (48000, 44100, 22050, 8000):
+            resampler = StreamResampler(rate)
+            data = _tone(440, rate)
+            out = b"".join(
+                resampler.process(data[i : i + 2048]) for i in range(0, len(data), 2048)
+            )
+            self.assertAlmostEqual(len(out) // 2, 16000, delta=2, msg=f"{rate}Hz")
+
+    def test_chunking_matches_one_pass(self):
+        data = _tone(440, 44100)
+        whole = resample_audio(data, 44100)
+        resampler = StreamResampler(44100)
+        chunked = b"".join(
+            resampler.process(data[i : i + 2000]) for i in range(0, len(data), 2000)
+        )
+        np.testing.assert_allclose(_samples(chunked), _samples(whole), atol=1)
+
+    def test_keeps_speech_band(self):
+        out = _samples(resample_audio(_tone(440, 48000), 48000))
+        # Skip the filter's start-up; a 440 Hz tone keeps its level
+        self.assertGreater(np.abs(out[500:]).max(), 9000)
+
+    def test_removes_frequencies_above_new_nyquist(self):
+        # 12 kHz can't be represented at 16 kHz; linear interpolation alone
+        # would fold it back to 4 kHz at nearly full level
+        out = _samples(resample_audio(_tone(12000, 48000), 48000))
+        self.assertLess(np.abs(out[500:]).max(), 200)
+
+    def test_reset_forgets_previous_stream(self):
+        resampler = StreamResampler(48000)
+        first = resampler.process(_tone(440, 48000, 0.1))
+        resampler.process(_tone(1000, 48000, 0.05))
+        resampler.reset()
+        self.assertEqual(resampler.process(_tone(440, 48000, 0.1)), first)
+
+    def test_tiny_and_odd_length_chunks(self):
+        resampler = StreamResampler(48000)
+        self.assertEqual(resampler.process(b"\x01"), b"")
+        out = resampler.process(_tone(440, 48000, 0.1) + b"\x01")
+        self.assertEqual(len(out) % 2, 0)
+        self.assertGreater(len(out), 0)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,133 @@
+"""
+Tests for routing final transcripts to output sinks.
+"""
+
+import json
+import os
+import socket
+import tempfile
+import threading
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.text_injection.result_router import (
+    ResultRouter,
+    expand_file_path,
+    parse_socket_address,
+    profile_settings,
+)
+
+
+class TestResultRouter(unittest.TestCase):
+    """Tests for ResultRouter."""
+
+    def setUp(self):
+        self.inject = MagicMock(return_value=True)
+        self.copy = MagicMock(return_value=True)
+        self.tmpdir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.tmpdir.cleanup)
+
+    def test_default_only_injects(self):
+        router = ResultRouter(self.inject, self.copy)
+        self.assertTrue(router.route("hello"))
+        self.inject.assert_called_once_with("hello")
+        self.copy.assert_not_called()
+
+    def test_multiple_sinks_receive_the_text(self):
+        path = os.path.join(self.tmpdir.name, "notes", "today.md")
+        router = ResultRouter(
+            self.inject, self.copy, sinks=["inject", "clipboard", "file"], file_path=path
+        )
+        router.route("first")
+        router.route("second")
+
+        self.assertEqual(self.inject.call_count, 2)
+        self.assertEqual(self.copy.call_count, 2)
+        with open(path, encoding="utf-8") as f:
+            self.assertEqual(f.read(), "first\nsecond\n")
+
+    def test_failing_sink_does_not_block_others(self):
+        self.copy.side_effect = RuntimeError("no clipboard tool")
+        router = ResultRouter(self.inject, self.copy, sinks=["clipboard", "inject"])
+        self.assertTrue(router.route("hello"))
+        self.inject.assert_called_once_with("hello")
+
+    def test_without_inject_sink_nothing_is_typed(self):
+        router = ResultRouter(self.inject, self.copy, sinks=["clipboard"])
+        self.assertFalse(router.route("hello"))
+        self.inject.assert_not_called()
+
+    def test_unknown_and_unconfigured_sinks_are_dropped(self):
+        router = ResultRouter(self.inject, self.copy, sinks=["inject", "printer", "file", "inject"])
+        self.assertEqual(router.sinks, ["inject"])
+
+    def test_socket_sink_sends_json_line(self):
+        server = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
+        self.addCleanup(server.close)
+        server.bind(("127.0.0.1", 0))
+        server.listen(1)
+        received = []
+
+        def accept():
+            conn, _ = server.accept()
+            with conn:
+                received.append(conn.makefile().readline())
+
+        thread = threading.Thread(target=accept)
+        thread.start()
+        port = server.getsockname()[1]
+        router = ResultRouter(
+            self.inject, self.copy, sinks=["socket"], socket_address=f"127.0.0.1:{port}"
+        )
+        router.route("over the wire")
+        thread.join(timeout=2)
+
+        self.assertEqual(json.loads(received[0])["text"], "over the wire")
+
+    def test_from_config_uses_requested_profile(self):
+        output = {
+            "profile": "default",
+            "profiles": {
+                "default": {"sinks": ["inject"]},
+                "notes": {"sinks": ["file"], "file_path": "~/notes.md"},
+            },
+        }
+        router = ResultRouter.from_config(output, self.inject, self.copy, profile="notes")
+        self.assertEqual(router.sinks, ["file"])
+        self.assertEqual(router.file_path, "~/notes.md")
+
+        router = ResultRouter.from_config(output, self.inject, self.copy)
+        self.assertEqual(router.sinks, ["inject"])
+
+    def test_profile_settings(self):
+        output = {"profile": "notes", "profiles": {"notes": {"translate": True}}}
+        self.assertEqual(profile_settings(output), {"translate": True})
+        self.assertIsNone(profile_settings(output, "missing"))
+
+    def test_from_config_unknown_profile_falls_back_to_inject(self):
+        router = ResultRouter.from_config({"profiles": {}}, self.inject, self.copy, "missing")
+        self.assertEqual(router.sinks, ["inject"])
+
+
+class TestSinkHelpers(unittest.TestCase):
+    """Tests for sink path and address parsing."""
+
+    def test_expand_file_path(self):
+        path = expand_file_path("~/Notes/%Y.md", now=0)
+        self.assertTrue(path.startswith(os.path.expanduser("~")))
+        self.assertRegex(path, r"/Notes/19(69|70)\.md$")
+
+    def test_parse_socket_address(self):
+        self.assertEqual(
+            parse_socket_address("localhost:8765"), (socket.AF_INET, ("localhost", 8765))
+        )
+        self.assertEqual(
+            parse_socket_address("unix:/run/user/1000/vocalinux.sock"),
+            (socket.AF_UNIX, "/run/user/1000/vocalinux.sock"),
+        )
+        with self.assertRaises(ValueError):
+            parse_socket_address("localhost")
+
+
+if __name__ == "__main__":
+    unittest.main()
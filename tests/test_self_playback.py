@@ -0,0 +1,53 @@
+"""
+Tests for tracking the sounds Vocalinux plays itself.
+"""
+
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.utils.self_playback import PLAYBACK_TAIL_SECONDS, SelfPlayback
+
+
+class TestSelfPlayback(unittest.TestCase):
+    """Tests for SelfPlayback."""
+
+    def setUp(self):
+        self.now = 100.0
+        self.playback = SelfPlayback(clock=lambda: self.now)
+
+    def test_playing_while_a_player_runs_and_for_the_tail(self):
+        player = MagicMock()
+        player.poll.return_value = None
+        self.assertFalse(self.playback.is_playing())
+
+        self.playback.add(player)
+        self.assertTrue(self.playback.is_playing())
+
+        player.poll.return_value = 0
+        self.now += 1.0
+        self.assertTrue(self.playback.is_playing())  # Echo may still reach the microphone
+        self.now += PLAYBACK_TAIL_SECONDS + 0.01
+        self.assertFalse(self.playback.is_playing())
+
+    def test_playing_until_the_last_of_several_players_exits(self):
+        short, long = MagicMock(), MagicMock()
+        short.poll.return_value = None
+        long.poll.return_value = None
+        self.playback.add(short)
+        self.playback.add(long)
+
+        short.poll.return_value = 0
+        self.now += 1.0
+        self.assertTrue(self.playback.is_playing())
+        self.now += 1.0
+        self.assertTrue(self.playback.is_playing())
+
+        long.poll.return_value = 0
+        self.now += 1.0
+        self.assertTrue(self.playback.is_playing())
+        self.now += 1.0
+        self.assertFalse(self.playback.is_playing())
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,97 @@
+"""
+Tests for dictation session statistics.
+"""
+
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.speech_recognition.session_stats import (
+    DictationSession,
+    count_words,
+    format_elapsed,
+)
+
+
+class FakeClock:
+    """A clock the test advances by hand."""
+
+    def __init__(self):
+        self.now = 1000.0
+
+    def __call__(self):
+        return self.now
+
+
+class TestHelpers(unittest.TestCase):
+    """Tests for the formatting helpers."""
+
+    def test_format_elapsed(self):
+        self.assertEqual(format_elapsed(0), "0:00")
+        self.assertEqual(format_elapsed(65.9), "1:05")
+        self.assertEqual(format_elapsed(3725), "1:02:05")
+        self.assertEqual(format_elapsed(-3), "0:00")
+
+    def test_count_words(self):
+        self.assertEqual(count_words("Hello, world. It's 5 o'clock"), 5)
+        self.assertEqual(count_words(" . ! "), 0)
+        self.assertEqual(count_words(""), 0)
+
+
+class TestDictationSession(unittest.TestCase):
+    """Tests for DictationSession."""
+
+    def setUp(self):
+        self.clock = FakeClock()
+        self.on_target = MagicMock()
+
+    def test_timer_starts_with_dictation(self):
+        session = DictationSession(clock=self.clock)
+        self.clock.now += 30
+        self.assertFalse(session.started)
+        self.assertEqual(session.elapsed, 0.0)
+
+        session.start()
+        self.clock.now += 90
+        session.start()  # Already running
+        self.assertEqual(session.elapsed, 90)
+
+    def test_counts_words(self):
+        session = DictationSession(clock=self.clock)
+        session.add_text("one two three")
+        session.add_text("four.")
+        self.assertEqual(session.word_count, 4)
+        self.assertTrue(session.started)
+        self.assertEqual(session.summary(), "0:00 · 4 words")
+
+    def test_target_announced_once(self):
+        session = DictationSession(3, on_target_reached=self.on_target, clock=self.clock)
+        session.add_text("one two")
+        self.on_target.assert_not_called()
+
+        self.clock.now += 61
+        session.add_text("three four")
+        self.on_target.assert_called_once_with(4, 61)
+
+        session.add_text("five")
+        self.on_target.assert_called_once()
+        self.assertEqual(session.summary(), "1:01 · 5 / 3 words")
+
+    def test_reset_starts_new_session(self):
+        session = DictationSession(2, on_target_reached=self.on_target, clock=self.clock)
+        session.add_text("one two")
+        session.reset()
+        self.assertFalse(session.started)
+        self.assertEqual(session.word_count, 0)
+
+        session.add_text("three four")
+        self.assertEqual(self.on_target.call_count, 2)
+
+    def test_invalid_target_disables_it(self):
+        session = DictationSession("lots")
+        self.assertEqual(session.word_target, 0)
+        session.word_target = None
+        self.assertEqual(session.word_target, 0)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -526,13 +526,13 @@ class TestSettingsDialogInstantApply(unittest.TestCase):
             "..",
             "src",
             "vocalinux",
-            "ui",
-            "settings_dialog.py",
+            "speech_recognition",
+            "remote_connection.py",
         )
         with open(source_path, "r") as f:
             source_code = f.read()
 
-        self.assertIn("session = requests.Session()", source_code)
+        self.assertIn("session = session or requests.Session()", source_code)
         self.assertIn("session.close()", source_code)
 
 
@@ -564,6 +564,52 @@ class TestSettingsDialogHelperFunctions(unittest.TestCase):
         self.assertEqual(_format_size(1000), "1.0 GB")
         self.assertEqual(_format_size(2500), "2.5 GB")
 
+    def test_format_device_capabilities_empty(self):
+        """Test _format_device_capabilities with no devices."""
+        from vocalinux.ui.settings_dialog import _format_device_capabilities
+
+        self.assertIn("No input devices found", _format_device_capabilities([]))
+
+    def test_format_device_capabilities_warns_without_usable_rates(self):
+        """Test _format_device_capabilities flags devices with no usable rates."""
+        from vocalinux.ui.settings_dialog import _format_device_capabilities
+
+        markup = _format_device_capabilities(
+            [
+                {
+                    "index": 3,
+                    "name": "Pro Interface",
+                    "is_default": False,
+                    "default_rate": 96000,
+                    "max_channels": 8,
+                    "rates": [],
+                    "channels": [],
+                    "formats": ["32-bit float"],
+                }
+            ]
+        )
+
+        self.assertIn("none in any supported format", markup)
+        self.assertIn("Formats: 32-bit float", markup)
+        self.assertIn("Invalid sample rate", markup)
+
+    def test_parse_term_list(self):
+        """Test _parse_term_list splits on commas and drops blanks and duplicates."""
+        from vocalinux.ui.settings_dialog import _parse_term_list
+
+        self.assertEqual(
+            _parse_term_list(" API, GmbH,, api , PostgreSQL "), ["API", "GmbH", "PostgreSQL"]
+        )
+
+    def test_parse_command_renames(self):
+        """Test _parse_command_renames reads old = new pairs and skips malformed ones."""
+        from vocalinux.ui.settings_dialog import _parse_command_renames
+
+        self.assertEqual(
+            _parse_command_renames("Period = full stop now, comma, = x, new line = next line"),
+            {"period": "full stop now", "new line": "next line"},
+        )
+
     def test_is_whisper_model_downloaded_function_exists(self):
         """Test that _is_whisper_model_downloaded function exists."""
         from vocalinux.ui.settings_dialog import _is_whisper_model_downloaded
@@ -693,5 +739,56 @@ class TestSettingsDialogHelperFunctions(unittest.TestCase):
         self.assertEqual(_default_whispercpp_variant_for_size("medium", "auto"), "medium")
 
 
+class TestSettingsSearchAndAccessibility(unittest.TestCase):
+    """Test cases for settings search and keyboard focus helpers."""
+
+    def test_matches_search_all_words(self):
+        """Test search requires every word, case-insensitively."""
+        from vocalinux.ui.settings_dialog import _matches_search
+
+        self.assertTrue(_matches_search("silence", "Silence Timeout", "Seconds of silence"))
+        self.assertTrue(_matches_search("SECONDS timeout", "Silence Timeout", "Seconds"))
+        self.assertFalse(_matches_search("silence model", "Silence Timeout", ""))
+        self.assertTrue(_matches_search("", "Anything"))
+
+    def test_strip_mnemonic_keeps_escaped_underscores(self):
+        """Test mnemonic marks are dropped and "__" becomes a literal underscore."""
+        from vocalinux.ui.settings_dialog import _strip_mnemonic
+
+        self.assertEqual(_strip_mnemonic("Input _Device"), "Input Device")
+        self.assertEqual(_strip_mnemonic("ggml__base__en"), "ggml_base_en")
+        self.assertEqual(_strip_mnemonic("_Model: large__v3"), "Model: large_v3")
+
+    def test_find_focus_target_returns_focusable_widget(self):
+        """Test the focusable control itself is used as mnemonic target."""
+        from vocalinux.ui.settings_dialog import _find_focus_target
+
+        switch = Mock()
+        switch.get_can_focus.return_value = True
+        self.assertIs(_find_focus_target(switch), switch)
+
+    def test_find_focus_target_searches_containers(self):
+        """Test the first focusable child of a box is found."""
+        from vocalinux.ui.settings_dialog import _find_focus_target
+
+        level_bar = Mock(spec=["get_can_focus"])
+        level_bar.get_can_focus.return_value = False
+        button = Mock(spec=["get_can_focus"])
+        button.get_can_focus.return_value = True
+        box = Mock()
+        box.get_can_focus.return_value = False
+        box.get_children.return_value = [level_bar, button]
+
+        self.assertIs(_find_focus_target(box), button)
+
+    def test_find_focus_target_none(self):
+        """Test widgets without focusable controls return None."""
+        from vocalinux.ui.settings_dialog import _find_focus_target
+
+        label = Mock(spec=["get_can_focus"])
+        label.get_can_focus.return_value = False
+        self.assertIsNone(_find_focus_target(label))
+
+
 if __name__ == "__main__":
     unittest.main()
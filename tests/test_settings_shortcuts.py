@@ -63,6 +63,12 @@ class TestSettingsDialogShortcutsSection(unittest.TestCase):
             'self.config_manager.get_str("shortcuts", "toggle_recognition"', self.source_code
         )
 
+    def test_panic_shortcut_requires_combo(self):
+        """Test the panic shortcut handler rejects bare modifier gestures."""
+        self.assertIn("def _on_panic_shortcut_apply(self, widget)", self.source_code)
+        self.assertIn("parse_shortcut_spec(shortcut).is_combo", self.source_code)
+        self.assertIn('self.config_manager.set("shortcuts", "panic", shortcut)', self.source_code)
+
     def test_shortcut_changed_handler_exists(self):
         """Test that shortcut changed handler exists."""
         self.assertIn("def _on_shortcut_changed(self, widget)", self.source_code)
@@ -159,6 +165,7 @@ class TestKeyboardBackendsBase(unittest.TestCase):
                 "ctrl+ctrl",
                 "alt+alt",
                 "shift+shift",
+                "super+super",
                 "left_ctrl+left_ctrl",
                 "left_alt+left_alt",
                 "left_shift+left_shift",
@@ -193,7 +200,8 @@ class TestKeyboardBackendsBase(unittest.TestCase):
         self.assertIn("Right Side", SHORTCUT_GROUPS)
         self.assertEqual(len(SHORTCUT_GROUPS.keys()), 3)
         self.assertEqual(list(SHORTCUT_GROUPS.keys()), ["Either Side", "Left Side", "Right Side"])
-        self.assertEqual(len(SHORTCUT_GROUPS["Either Side"]), 3)
+        self.assertEqual(len(SHORTCUT_GROUPS["Either Side"]), 4)
+        self.assertIn("super+super", SHORTCUT_GROUPS["Either Side"])
         self.assertEqual(len(SHORTCUT_GROUPS["Left Side"]), 3)
         self.assertEqual(len(SHORTCUT_GROUPS["Right Side"]), 3)
 
@@ -209,6 +217,12 @@ class TestConfigManagerShortcuts(unittest.TestCase):
         self.assertIn("toggle_recognition", DEFAULT_CONFIG["shortcuts"])
         self.assertEqual(DEFAULT_CONFIG["shortcuts"]["toggle_recognition"], "ctrl+ctrl")
 
+    def test_panic_shortcut_disabled_by_default(self):
+        """Test the panic shortcut is off unless configured."""
+        from vocalinux.ui.config_manager import DEFAULT_CONFIG
+
+        self.assertEqual(DEFAULT_CONFIG["shortcuts"]["panic"], "")
+
     def test_config_manager_get_shortcut(self):
         """Test getting shortcut from config manager."""
         from unittest.mock import patch
@@ -0,0 +1,72 @@
+"""
+Tests for the microphone sidetone monitor.
+"""
+
+import sys
+import unittest
+from unittest.mock import MagicMock, patch
+
+import numpy as np
+
+from vocalinux.speech_recognition.sidetone import SidetoneMonitor
+
+
+class TestSidetoneMonitor(unittest.TestCase):
+    """Test cases for SidetoneMonitor."""
+
+    def setUp(self):
+        self.pyaudio_patch = patch.dict(sys.modules, {"pyaudio": MagicMock(paInt16=8)})
+        self.pyaudio_patch.start()
+        self.stream = MagicMock()
+        self.stream.get_write_available.return_value = 4096
+        self.audio = MagicMock()
+        self.audio.open.return_value = self.stream
+
+    def tearDown(self):
+        self.pyaudio_patch.stop()
+
+    def test_start_opens_mono_output(self):
+        monitor = SidetoneMonitor()
+        self.assertTrue(monitor.start(self.audio, 48000))
+        self.assertTrue(monitor.active)
+        kwargs = self.audio.open.call_args.kwargs
+        self.assertTrue(kwargs["output"])
+        self.assertEqual(kwargs["channels"], 1)
+        self.assertEqual(kwargs["rate"], 48000)
+
+    def test_start_failure_leaves_monitor_inactive(self):
+        self.audio.open.side_effect = OSError("no output device")
+        monitor = SidetoneMonitor()
+        self.assertFalse(monitor.start(self.audio, 16000))
+        monitor.write(b"\x00\x00")  # Should not raise
+        self.assertFalse(monitor.active)
+
+    def test_write_applies_volume(self):
+        monitor = SidetoneMonitor(volume=0.5)
+        monitor.start(self.audio, 16000)
+        monitor.write(np.array([1000, -2000], dtype=np.int16).tobytes())
+
+        written = np.frombuffer(self.stream.write.call_args[0][0], dtype=np.int16)
+        self.assertEqual(written.tolist(), [500, -1000])
+
+    def test_write_drops_chunk_when_output_is_busy(self):
+        self.stream.get_write_available.return_value = 0
+        monitor = SidetoneMonitor()
+        monitor.start(self.audio, 16000)
+        monitor.write(np.zeros(1024, dtype=np.int16).tobytes())
+        self.stream.write.assert_not_called()
+
+    def test_volume_is_clamped(self):
+        self.assertEqual(SidetoneMonitor(volume=3).volume, 1.0)
+        self.assertEqual(SidetoneMonitor(volume=-1).volume, 0.0)
+
+    def test_stop_closes_stream(self):
+        monitor = SidetoneMonitor()
+        monitor.start(self.audio, 16000)
+        monitor.stop()
+        self.stream.close.assert_called_once()
+        self.assertFalse(monitor.active)
+
+
+if __name__ == "__main__":
+    unittest.main()
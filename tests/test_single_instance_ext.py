@@ -6,12 +6,15 @@ Tests cover lock acquisition, release, PID file handling, and process checking.
 
 import fcntl
 import os
+import subprocess
+import sys
 import tempfile
 import unittest
 from pathlib import Path
 from unittest.mock import MagicMock, Mock, call, mock_open, patch
 
 import vocalinux.single_instance as single_instance_module
+from vocalinux.utils.paths import runtime_dir
 
 
 class TestAcquireLock(unittest.TestCase):
@@ -46,6 +49,24 @@ class TestAcquireLock(unittest.TestCase):
         mock_write.assert_called_once_with(5, b"12345\n")
         mock_lseek.assert_called_once_with(5, 0, os.SEEK_SET)
 
+    @patch("vocalinux.single_instance._get_lock_file_fd")
+    @patch("os.ftruncate")
+    @patch("os.write")
+    @patch("os.lseek")
+    @patch("fcntl.flock")
+    @patch("vocalinux.single_instance.signal.signal")
+    def test_acquire_lock_ignores_show_status_signal_until_ready(
+        self, mock_signal, mock_flock, mock_lseek, mock_write, mock_ftruncate, mock_get_fd
+    ):
+        """Test a second launch during startup can't terminate the first instance."""
+        mock_get_fd.return_value = 5
+
+        self.assertTrue(single_instance_module.acquire_lock())
+
+        mock_signal.assert_called_once_with(
+            single_instance_module.SHOW_STATUS_SIGNAL, single_instance_module.signal.SIG_IGN
+        )
+
     @patch("vocalinux.single_instance._get_lock_file_fd")
     @patch("fcntl.flock")
     def test_acquire_lock_already_running_oserror(self, mock_flock, mock_get_fd):
@@ -149,21 +170,18 @@ class TestGetLockFileFd(unittest.TestCase):
         """Clean up after each test."""
         single_instance_module._lock_file = None
 
-    @patch("vocalinux.single_instance.LOCK_FILE_DIR")
+    @patch("vocalinux.single_instance.ensure_private_dir")
     @patch("os.open")
-    def test_get_lock_file_fd_creates_directory(self, mock_os_open, mock_lock_dir):
-        """Test that directory is created if it doesn't exist."""
-        mock_lock_dir_instance = MagicMock(spec=Path)
-        mock_lock_dir_instance.__truediv__ = MagicMock(return_value=MagicMock(spec=Path))
-        mock_lock_dir = mock_lock_dir_instance
+    def test_get_lock_file_fd_creates_directory(self, mock_os_open, mock_ensure_dir):
+        """Test that a private directory is created for the lock file."""
+        mock_os_open.return_value = 5
 
-        with patch("vocalinux.single_instance.LOCK_FILE_DIR", mock_lock_dir_instance):
-            mock_os_open.return_value = 5
-
-            fd = single_instance_module._get_lock_file_fd()
+        fd = single_instance_module._get_lock_file_fd()
 
-            self.assertEqual(fd, 5)
-            mock_os_open.assert_called_once()
+        self.assertEqual(fd, 5)
+        mock_ensure_dir.assert_called_once_with(str(single_instance_module.LOCK_FILE_DIR))
+        mock_os_open.assert_called_once()
+        self.assertEqual(mock_os_open.call_args[0][2], 0o600)
 
     @patch("vocalinux.single_instance.LOCK_FILE_DIR")
     @patch("os.open")
@@ -178,14 +196,51 @@ class TestGetLockFileFd(unittest.TestCase):
         mock_os_open.assert_not_called()
 
 
+class TestSignalRunningInstance(unittest.TestCase):
+    """Tests for asking the running instance to show its status window."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.lock_path = Path(self.temp_dir.name) / "instance.lock"
+        self.path_patcher = patch.object(single_instance_module, "LOCK_FILE_PATH", self.lock_path)
+        self.path_patcher.start()
+
+    def tearDown(self):
+        self.path_patcher.stop()
+        self.temp_dir.cleanup()
+
+    def test_get_running_pid(self):
+        """Test the PID is read from the lock file."""
+        self.lock_path.write_text("4321\n")
+        self.assertEqual(single_instance_module.get_running_pid(), 4321)
+
+    def test_get_running_pid_missing_file(self):
+        """Test a missing lock file yields no PID."""
+        self.assertIsNone(single_instance_module.get_running_pid())
+
+    @patch("os.kill")
+    def test_signal_running_instance(self, mock_kill):
+        """Test the running instance is sent the show-status signal."""
+        self.lock_path.write_text("4321\n")
+        self.assertTrue(single_instance_module.signal_running_instance())
+        mock_kill.assert_called_once_with(4321, single_instance_module.SHOW_STATUS_SIGNAL)
+
+    @patch("os.kill", side_effect=ProcessLookupError())
+    def test_signal_running_instance_stale_pid(self, mock_kill):
+        """Test a stale PID is reported as not signalled."""
+        self.lock_path.write_text("4321\n")
+        self.assertFalse(single_instance_module.signal_running_instance())
+
+
 class TestLockFileConstants(unittest.TestCase):
     """Tests for lock file constants."""
 
-    def test_lock_file_path_is_in_home_directory(self):
-        """Test that lock file is in ~/.local/share/vocalinux/."""
-        self.assertIn(".local", str(single_instance_module.LOCK_FILE_PATH))
-        self.assertIn("share", str(single_instance_module.LOCK_FILE_PATH))
-        self.assertIn("vocalinux", str(single_instance_module.LOCK_FILE_PATH))
+    def test_lock_file_path_is_in_runtime_directory(self):
+        """Test that lock file is in the per-user runtime directory, not the shared home."""
+        self.assertEqual(
+            single_instance_module.LOCK_FILE_PATH, Path(runtime_dir()) / "instance.lock"
+        )
+        self.assertNotIn(".local", str(single_instance_module.LOCK_FILE_PATH))
 
     def test_lock_file_path_is_path_object(self):
         """Test that lock file path is a Path object."""
@@ -196,5 +251,54 @@ class TestLockFileConstants(unittest.TestCase):
         self.assertIsInstance(single_instance_module.LOCK_FILE_DIR, Path)
 
 
+
+class TestConcurrentSessions(unittest.TestCase):
+    """Sessions of different users (each with its own runtime dir) don't block each other."""
+
+    _HOLD_LOCK = (
+        "import sys, time\n"
+        "from vocalinux.single_instance import acquire_lock\n"
+        "print(acquire_lock(), flush=True)\n"
+        "sys.stdin.readline()\n"
+    )
+
+    def _start_session(self, runtime_dir, data_home):
+        src = str(Path(__file__).resolve().parent.parent / "src")
+        env = {
+            **os.environ,
+            "PYTHONPATH": os.pathsep.join([src, os.environ.get("PYTHONPATH", "")]),
+            "XDG_RUNTIME_DIR": runtime_dir,
+            "XDG_DATA_HOME": data_home,
+        }
+        process = subprocess.Popen(
+            [sys.executable, "-c", self._HOLD_LOCK],
+            env=env,
+            stdin=subprocess.PIPE,
+            stdout=subprocess.PIPE,
+            stderr=subprocess.DEVNULL,
+            text=True,
+        )
+        self.addCleanup(process.wait, 10)
+        self.addCleanup(process.stdin.close)
+        return process, process.stdout.readline().strip()
+
+    def test_sessions_with_own_runtime_dirs_run_side_by_side(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            # One home directory (e.g. over NFS) shared by both sessions
+            data_home = os.path.join(tmp, "home", ".local", "share")
+            first_runtime = os.path.join(tmp, "run", "user", "1000")
+            second_runtime = os.path.join(tmp, "run", "user", "1001")
+
+            _first, first_result = self._start_session(first_runtime, data_home)
+            _second, second_result = self._start_session(second_runtime, data_home)
+            _again, again_result = self._start_session(first_runtime, data_home)
+
+            self.assertEqual(first_result, "True")
+            self.assertEqual(second_result, "True")
+            # A second instance in the same user's session is still refused
+            self.assertEqual(again_result, "False")
+            self.assertFalse(os.path.exists(os.path.join(data_home, "vocalinux", "instance.lock")))
+
+
 if __name__ == "__main__":
     unittest.main()
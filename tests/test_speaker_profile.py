@@ -0,0 +1,65 @@
+"""
+Tests for the VOSK speaker profile.
+"""
+
+import os
+import tempfile
+import unittest
+
+from vocalinux.speech_recognition.speaker_profile import (
+    SPK_MODEL_NAME,
+    SpeakerProfile,
+    cosine_distance,
+    find_spk_model,
+)
+
+
+class TestSpeakerProfile(unittest.TestCase):
+    """Tests for SpeakerProfile and its helpers."""
+
+    def test_from_results_weights_by_speech_frames(self):
+        profile = SpeakerProfile.from_results(
+            [
+                {"text": "hello", "spk": [1.0, 0.0], "spk_frames": 300},
+                {"text": ""},  # No speech, no x-vector
+                {"text": "there", "spk": [0.0, 1.0], "spk_frames": 100},
+            ]
+        )
+
+        self.assertEqual(profile.xvector, [0.75, 0.25])
+        self.assertEqual(profile.frames, 400)
+        self.assertIsNone(SpeakerProfile.from_results([{"spk": [1.0, 0.0], "spk_frames": 50}]))
+
+    def test_matches_by_cosine_distance(self):
+        profile = SpeakerProfile([1.0, 0.0, 1.0], 500)
+
+        self.assertAlmostEqual(cosine_distance([1.0, 0.0], [2.0, 0.0]), 0.0)
+        self.assertAlmostEqual(cosine_distance([1.0, 0.0], [0.0, 1.0]), 1.0)
+        self.assertTrue(profile.matches([0.9, 0.1, 1.1]))
+        self.assertFalse(profile.matches([-1.0, 1.0, 0.0]))
+        self.assertFalse(profile.matches([0.0, 0.0, 0.0]))
+
+    def test_save_and_load(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "vocalinux", "speaker_profile.json")
+            self.assertIsNone(SpeakerProfile.load(path))
+
+            SpeakerProfile([0.5, -0.25], 640).save(path)
+            loaded = SpeakerProfile.load(path)
+            self.assertEqual(loaded.xvector, [0.5, -0.25])
+            self.assertEqual(loaded.frames, 640)
+
+            with open(path, "w", encoding="utf-8") as f:
+                f.write("{not json")
+            self.assertIsNone(SpeakerProfile.load(path))
+
+    def test_find_spk_model(self):
+        with tempfile.TemporaryDirectory() as empty, tempfile.TemporaryDirectory() as models:
+            os.mkdir(os.path.join(models, SPK_MODEL_NAME))
+
+            self.assertEqual(find_spk_model([empty, models]), os.path.join(models, SPK_MODEL_NAME))
+            self.assertIsNone(find_spk_model([empty]))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,129 @@
+"""
+Tests for the status output read by window-manager bars.
+"""
+
+import json
+import os
+import tempfile
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.common_types import RecognitionState
+from vocalinux.status_output import StatusWriter, follow, format_status, main, read_status
+
+
+class TestStatusWriter(unittest.TestCase):
+    """Tests for keeping the status file up to date."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.temp_dir.name, "vocalinux", "status.json")
+        self.now = 0.0
+
+    def tearDown(self):
+        self.temp_dir.cleanup()
+
+    def _written(self):
+        with open(self.path, encoding="utf-8") as status_file:
+            return json.load(status_file)
+
+    def test_writes_state_and_level(self):
+        writer = StatusWriter(self.path, clock=lambda: self.now)
+        self.assertEqual(self._written(), {"state": "idle", "level": 0.0, "pid": os.getpid()})
+
+        writer.on_state_changed(RecognitionState.LISTENING)
+        self.now = 1.0
+        writer.on_audio_level(42.26)
+        self.assertEqual(self._written()["state"], "listening")
+        self.assertEqual(self._written()["level"], 42.3)
+
+        writer.on_state_changed(RecognitionState.PROCESSING)
+        self.assertEqual(self._written()["level"], 0.0)
+
+    def test_level_updates_are_throttled(self):
+        writer = StatusWriter(self.path, min_interval=0.1, clock=lambda: self.now)
+        writer.on_state_changed(RecognitionState.LISTENING)
+        self.now = 0.05
+        writer.on_audio_level(50)
+        self.assertEqual(self._written()["level"], 0.0)
+        self.now = 0.2
+        writer.on_audio_level(60)
+        self.assertEqual(self._written()["level"], 60.0)
+
+    def test_skips_directory_owned_by_another_user(self):
+        os.makedirs(os.path.dirname(self.path))
+        with patch("os.getuid", return_value=os.getuid() + 1):
+            StatusWriter(self.path)
+        self.assertFalse(os.path.exists(self.path))
+
+    def test_close_removes_file(self):
+        writer = StatusWriter(self.path)
+        writer.close()
+        self.assertFalse(os.path.exists(self.path))
+        writer.close()
+
+
+class TestReadStatus(unittest.TestCase):
+    """Tests for reading and formatting the status."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.temp_dir.name, "status.json")
+
+    def tearDown(self):
+        self.temp_dir.cleanup()
+
+    def _write(self, status):
+        with open(self.path, "w", encoding="utf-8") as status_file:
+            json.dump(status, status_file)
+
+    def test_running_instance(self):
+        self._write({"state": "listening", "level": 37.5, "pid": os.getpid()})
+        self.assertEqual(read_status(self.path), {"state": "listening", "level": 37.5})
+
+    def test_stopped_when_missing_or_stale(self):
+        self.assertEqual(read_status(self.path)["state"], "stopped")
+        with patch("vocalinux.status_output.os.kill", side_effect=ProcessLookupError):
+            self._write({"state": "listening", "level": 37.5, "pid": 999999})
+            self.assertEqual(read_status(self.path), {"state": "stopped", "level": 0.0})
+        with open(self.path, "w", encoding="utf-8") as status_file:
+            status_file.write("{")
+        self.assertEqual(read_status(self.path)["state"], "stopped")
+
+    def test_format_for_waybar(self):
+        line = json.loads(format_status({"state": "listening", "level": 37.5}))
+        self.assertEqual(line["text"], "Listening 38%")
+        self.assertEqual(line["class"], "listening")
+        self.assertEqual(line["alt"], "listening")
+        self.assertEqual(line["percentage"], 38)
+        self.assertEqual(line["level"], 37.5)
+
+    def test_format_text(self):
+        self.assertEqual(format_status({"state": "idle", "level": 0.0}, "text"), "Idle")
+        self.assertEqual(format_status({"state": "stopped", "level": 0.0}, "text"), "Not running")
+
+    def test_follow_prints_changes_only(self):
+        states = iter(["idle", "idle", "listening", "listening"])
+        lines = []
+
+        def fake_sleep(seconds):
+            state = next(states, None)
+            if state:
+                self._write({"state": state, "level": 0.0, "pid": os.getpid()})
+
+        self._write({"state": "idle", "level": 0.0, "pid": os.getpid()})
+        running = MagicMock(side_effect=[True] * 5 + [False])
+        follow("text", self.path, write=lines.append, sleep=fake_sleep, running=running)
+        self.assertEqual(lines, ["Idle", "Listening 0%"])
+
+    def test_main_prints_once(self):
+        status = {"state": "idle", "level": 0.0}
+        with patch("vocalinux.status_output.read_status", return_value=status), patch(
+            "builtins.print"
+        ) as mock_print:
+            self.assertEqual(main(["--format", "text"]), 0)
+        mock_print.assert_called_once_with("Idle", flush=True)
+
+
+if __name__ == "__main__":
+    unittest.main()
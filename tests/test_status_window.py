@@ -0,0 +1,102 @@
+"""
+Tests for the status window helpers.
+"""
+
+import sys
+import unittest
+from unittest.mock import MagicMock
+
+# Mock GTK before importing anything that might use it
+sys.modules["gi"] = MagicMock()
+sys.modules["gi.repository"] = MagicMock()
+
+from vocalinux.common_types import RecognitionState  # noqa: E402
+from vocalinux.speech_recognition.engine_comparison import EngineTranscript  # noqa: E402
+from vocalinux.speech_recognition.session_stats import DictationSession  # noqa: E402
+from vocalinux.ui.status_window import (  # noqa: E402
+    caption_text,
+    describe_comparison,
+    describe_engine,
+    describe_session,
+    describe_state,
+)
+
+
+class TestStatusWindowHelpers(unittest.TestCase):
+    """Tests for the state and engine descriptions shown in the status window."""
+
+    def test_describe_state_button_follows_state(self):
+        """Test idle offers Start and listening offers Stop."""
+        self.assertEqual(
+            describe_state(RecognitionState.IDLE),
+            ("Ready", "Start Voice Typing", "suggested-action"),
+        )
+        headline, button, style = describe_state(RecognitionState.LISTENING)
+        self.assertEqual(button, "Stop Voice Typing")
+        self.assertEqual(style, "destructive-action")
+
+    def test_describe_engine(self):
+        """Test the engine, model, language and microphone rows."""
+        speech_engine = MagicMock(engine="whisper_cpp", model_size="base", language="en-us")
+        speech_engine.get_audio_device_name.return_value = None
+
+        self.assertEqual(
+            describe_engine(speech_engine),
+            [
+                ("Engine", "Whisper_cpp"),
+                ("Model", "Base"),
+                ("Language", "en-us"),
+                ("Microphone", "System default"),
+            ],
+        )
+
+    def test_describe_engine_detected_language(self):
+        """Test auto-detect shows the language of the last utterance."""
+        speech_engine = MagicMock(language="auto", detected_language=None)
+        self.assertIn(("Language", "Auto-detect"), describe_engine(speech_engine))
+
+        speech_engine.detected_language = "de"
+        self.assertIn(("Language", "Auto-detect · de"), describe_engine(speech_engine))
+
+    def test_describe_engine_locked_keyring(self):
+        """Test a remote engine waiting for the keyring says so."""
+        speech_engine = MagicMock(engine="remote_api", remote_api_key_locked=True)
+        rows = describe_engine(speech_engine)
+        self.assertIn(("API key", "Locked until the keyring is unlocked"), rows)
+
+        speech_engine.remote_api_key_locked = False
+        self.assertNotIn("API key", [label for label, _value in describe_engine(speech_engine)])
+
+    def test_describe_comparison(self):
+        """Test both transcripts are listed with the typed engine first."""
+        rows = describe_comparison(
+            EngineTranscript("whisper_cpp", "Hello world.", 0.8),
+            EngineTranscript("vosk", None, 0.01),
+        )
+        self.assertEqual(
+            rows,
+            [
+                ("Whisper_cpp (typed)", "Hello world. [0.80s]"),
+                ("Vosk", "(not available) [0.01s]"),
+            ],
+        )
+
+    def test_describe_session(self):
+        """Test the session line before and after dictating."""
+        session = DictationSession(word_target=500, clock=lambda: 100.0)
+        self.assertEqual(describe_session(session), "Session: nothing dictated yet")
+
+        session.add_text("hello there")
+        self.assertEqual(describe_session(session), "Session: 0:00 · 2 / 500 words")
+
+    def test_caption_text(self):
+        """Test the kiosk captions join the latest transcripts and skip empty ones."""
+        self.assertEqual(
+            caption_text([" Good morning. ", "", "Welcome to the demo."]),
+            "Good morning. Welcome to the demo.",
+        )
+        self.assertEqual(caption_text([]), "")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -1910,6 +1910,24 @@ class TestCompositorIBusBridging(unittest.TestCase):
             with patch("time.sleep"):
                 self.assertTrue(injector._ensure_ydotoold())
         mock_popen.assert_called_once()
+        self.assertIs(injector._ydotoold_process, mock_popen.return_value)
+
+    def test_stop_terminates_started_ydotoold(self):
+        """Only a ydotoold this instance started is stopped on shutdown."""
+        injector = self._bare_injector()
+        injector._state_lock = threading.Lock()
+        injector._ibus_injector = None
+        process = MagicMock()
+        process.poll.return_value = None
+        injector._ydotoold_process = process
+
+        injector.stop()
+        process.terminate.assert_called_once()
+        self.assertIsNone(injector._ydotoold_process)
+
+        # A daemon that was already running when Vocalinux started is left alone
+        injector.stop()
+        process.terminate.assert_called_once()
 
     @patch("vocalinux.text_injection.text_injector.is_ibus_daemon_running", return_value=True)
     @patch("vocalinux.text_injection.text_injector.is_ibus_active_input_method", return_value=True)
@@ -1955,6 +1973,19 @@ class TestCompositorIBusBridging(unittest.TestCase):
                     mock_cls.return_value = MagicMock()
                     self.assertTrue(injector._is_ydotoold_running())
 
+    def test_ydotool_socket_of_another_user_is_skipped(self):
+        """A /tmp socket owned by another user's ydotoold is never used."""
+        injector = self._bare_injector()
+        with patch.dict("os.environ", {"XDG_RUNTIME_DIR": "/run/user/1001"}, clear=True):
+            with patch("os.getuid", return_value=1001):
+                with patch("os.stat", return_value=MagicMock(st_uid=1000)):
+                    paths = injector._ydotool_socket_paths()
+                self.assertEqual(paths, ["/run/user/1001/.ydotool_socket"])
+
+                with patch("os.stat", return_value=MagicMock(st_uid=0)):
+                    paths = injector._ydotool_socket_paths()
+                self.assertIn("/tmp/.ydotool_socket", paths)
+
     @patch("vocalinux.text_injection.text_injector.is_ibus_available", return_value=False)
     @patch("vocalinux.text_injection.text_injector.shutil.which")
     def test_ydotool_direct_mode_when_no_daemon_and_no_wtype(self, mock_which, _mock_ibus):
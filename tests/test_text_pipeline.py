@@ -0,0 +1,50 @@
+"""
+Tests for the ordered post-processing of recognized text.
+"""
+
+import unittest
+
+from vocalinux.speech_recognition.text_pipeline import (
+    PIPELINE_STAGES,
+    TextPipeline,
+    parse_pipeline,
+)
+
+
+class TestParsePipeline(unittest.TestCase):
+    """Tests for cleaning up configured stage lists."""
+
+    def test_default_is_every_stage(self):
+        self.assertEqual(parse_pipeline(None), list(PIPELINE_STAGES))
+
+    def test_unknown_and_repeated_stages_dropped(self):
+        with self.assertLogs("vocalinux.speech_recognition.text_pipeline", "WARNING"):
+            stages = parse_pipeline([" Numbers", "llm", "commands", "numbers"])
+        self.assertEqual(stages, ["numbers", "commands"])
+
+    def test_empty_list_skips_everything(self):
+        self.assertEqual(parse_pipeline([]), [])
+
+
+class TestTextPipeline(unittest.TestCase):
+    """Tests for running the stages."""
+
+    def test_runs_stages_in_configured_order(self):
+        handlers = {
+            "numbers": lambda text, actions: (text + " numbers", actions),
+            "commands": lambda text, actions: (text + " commands", actions + ["enter"]),
+        }
+        text, actions = TextPipeline(["numbers", "commands"]).run(" start", handlers)
+        self.assertEqual(text, "start numbers commands")
+        self.assertEqual(actions, ["enter"])
+
+        text, _ = TextPipeline(["commands", "numbers"]).run("start", handlers)
+        self.assertEqual(text, "start commands numbers")
+
+    def test_stages_without_handler_skipped(self):
+        handlers = {"profanity": lambda text, actions: (text.upper(), actions)}
+        self.assertEqual(TextPipeline().run("hello", handlers), ("HELLO", []))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,167 @@
+"""
+Tests for the text-to-speech readback module.
+"""
+
+import json
+import os
+import subprocess
+import tempfile
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.ui import text_to_speech
+
+
+def _which(available):
+    return lambda cmd: f"/usr/bin/{cmd}" if cmd in available else None
+
+
+class TestTtsBackend(unittest.TestCase):
+    """Tests for backend selection."""
+
+    def test_prefers_piper_with_model(self):
+        with tempfile.NamedTemporaryFile(suffix=".onnx") as model, patch(
+            "vocalinux.ui.text_to_speech.shutil.which", side_effect=_which({"piper", "aplay"})
+        ):
+            self.assertEqual(text_to_speech.get_tts_backend(model.name), "piper")
+
+    def test_piper_without_model_falls_back(self):
+        with patch(
+            "vocalinux.ui.text_to_speech.shutil.which",
+            side_effect=_which({"piper", "aplay", "espeak-ng"}),
+        ):
+            self.assertEqual(text_to_speech.get_tts_backend(""), "espeak-ng")
+
+    def test_piper_without_aplay_falls_back(self):
+        with tempfile.NamedTemporaryFile(suffix=".onnx") as model, patch(
+            "vocalinux.ui.text_to_speech.shutil.which", side_effect=_which({"piper", "spd-say"})
+        ):
+            self.assertEqual(text_to_speech.get_tts_backend(model.name), "spd-say")
+
+    def test_no_backend(self):
+        with patch("vocalinux.ui.text_to_speech.shutil.which", return_value=None):
+            self.assertIsNone(text_to_speech.get_tts_backend())
+
+
+class TestPiperSampleRate(unittest.TestCase):
+    """Tests for reading the Piper voice sample rate."""
+
+    def test_reads_rate_from_model_config(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            model = os.path.join(tmp, "voice.onnx")
+            with open(f"{model}.json", "w") as f:
+                json.dump({"audio": {"sample_rate": 16000}}, f)
+            self.assertEqual(text_to_speech._get_piper_sample_rate(model), 16000)
+
+    def test_missing_config_uses_default(self):
+        self.assertEqual(
+            text_to_speech._get_piper_sample_rate("/nonexistent/voice.onnx"),
+            text_to_speech.PIPER_DEFAULT_SAMPLE_RATE,
+        )
+
+
+class TestSpeak(unittest.TestCase):
+    """Tests for speak()."""
+
+    def test_empty_text_is_ignored(self):
+        with patch("vocalinux.ui.text_to_speech.subprocess.Popen") as mock_popen:
+            self.assertFalse(text_to_speech.speak("   "))
+        mock_popen.assert_not_called()
+
+    def test_fallback_backend_receives_text(self):
+        with patch.object(text_to_speech, "_get_tts_settings", return_value={}), patch.object(
+            text_to_speech, "get_tts_backend", return_value="espeak-ng"
+        ), patch("vocalinux.ui.text_to_speech.subprocess.Popen") as mock_popen:
+            self.assertTrue(text_to_speech.speak(" Hello world "))
+        self.assertEqual(mock_popen.call_args[0][0], ["espeak-ng", "--", "Hello world"])
+
+    def test_speech_dispatcher_waits_and_player_is_registered(self):
+        with patch.object(text_to_speech, "_get_tts_settings", return_value={}), patch.object(
+            text_to_speech, "get_tts_backend", return_value="spd-say"
+        ), patch("vocalinux.ui.text_to_speech.subprocess.Popen") as mock_popen, patch.object(
+            text_to_speech, "register_playback"
+        ) as register_playback:
+            self.assertTrue(text_to_speech.speak("Hello"))
+        # The process must last as long as the speech for the microphone to be muted
+        self.assertEqual(mock_popen.call_args[0][0], ["spd-say", "--wait", "--", "Hello"])
+        register_playback.assert_called_once_with(mock_popen.return_value)
+
+    def test_text_starting_with_a_dash_is_not_an_option(self):
+        with patch.object(text_to_speech, "_get_tts_settings", return_value={}), patch.object(
+            text_to_speech, "get_tts_backend", return_value="espeak-ng"
+        ), patch("vocalinux.ui.text_to_speech.subprocess.Popen") as mock_popen:
+            self.assertTrue(text_to_speech.speak("-5 degrees"))
+        self.assertEqual(mock_popen.call_args[0][0], ["espeak-ng", "--", "-5 degrees"])
+
+    def test_piper_pipes_into_aplay(self):
+        piper_process = MagicMock()
+        with patch.object(
+            text_to_speech, "_get_tts_settings", return_value={"piper_model": "/v.onnx"}
+        ), patch.object(text_to_speech, "get_tts_backend", return_value="piper"), patch(
+            "vocalinux.ui.text_to_speech.subprocess.Popen", side_effect=[piper_process, MagicMock()]
+        ) as mock_popen:
+            self.assertTrue(text_to_speech.speak("Hello"))
+
+        piper_cmd = mock_popen.call_args_list[0][0][0]
+        aplay_cmd = mock_popen.call_args_list[1][0][0]
+        self.assertEqual(piper_cmd[:3], ["piper", "--model", "/v.onnx"])
+        self.assertEqual(aplay_cmd[0], "aplay")
+        self.assertIn(str(text_to_speech.PIPER_DEFAULT_SAMPLE_RATE), aplay_cmd)
+        piper_process.stdin.write.assert_called_once_with(b"Hello")
+
+    def test_aplay_launch_failure_kills_piper(self):
+        piper_process = MagicMock()
+        with patch.object(
+            text_to_speech, "_get_tts_settings", return_value={"piper_model": "/v.onnx"}
+        ), patch.object(text_to_speech, "get_tts_backend", return_value="piper"), patch(
+            "vocalinux.ui.text_to_speech.subprocess.Popen",
+            side_effect=[piper_process, OSError("no aplay")],
+        ):
+            self.assertFalse(text_to_speech.speak("Hello"))
+
+        piper_process.kill.assert_called_once()
+        piper_process.wait.assert_called_once()
+
+    def test_players_are_reaped(self):
+        with patch.object(text_to_speech, "_get_tts_settings", return_value={}), patch.object(
+            text_to_speech, "get_tts_backend", return_value="espeak-ng"
+        ), patch("vocalinux.ui.text_to_speech.subprocess.Popen") as mock_popen, patch.object(
+            text_to_speech, "_reap_when_done"
+        ) as reap:
+            self.assertTrue(text_to_speech.speak("Hello"))
+        reap.assert_called_once_with(mock_popen.return_value)
+
+    def test_reap_when_done_waits_for_every_process(self):
+        first, second = MagicMock(), MagicMock()
+        with patch("vocalinux.ui.text_to_speech.threading.Thread") as mock_thread:
+            text_to_speech._reap_when_done(first, second)
+            mock_thread.call_args[1]["target"]()
+        first.wait.assert_called_once()
+        second.wait.assert_called_once()
+
+    def test_no_backend_returns_false(self):
+        with patch.object(text_to_speech, "_get_tts_settings", return_value={}), patch.object(
+            text_to_speech, "get_tts_backend", return_value=None
+        ):
+            self.assertFalse(text_to_speech.speak("Hello"))
+
+    def test_launch_failure_returns_false(self):
+        with patch.object(text_to_speech, "_get_tts_settings", return_value={}), patch.object(
+            text_to_speech, "get_tts_backend", return_value="espeak-ng"
+        ), patch(
+            "vocalinux.ui.text_to_speech.subprocess.Popen",
+            side_effect=subprocess.SubprocessError("boom"),
+        ):
+            self.assertFalse(text_to_speech.speak("Hello"))
+
+    def test_readback_enabled_from_config(self):
+        with patch.object(
+            text_to_speech, "_get_tts_settings", return_value={"readback_enabled": True}
+        ):
+            self.assertTrue(text_to_speech.is_readback_enabled())
+        with patch.object(text_to_speech, "_get_tts_settings", return_value={}):
+            self.assertFalse(text_to_speech.is_readback_enabled())
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,73 @@
+"""Tests for time-boxed dictation sessions."""
+
+import os
+import tempfile
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux.ui.timed_session import MAX_SESSION_MINUTES, TimedSession
+
+
+class FakeClock:
+    def __init__(self):
+        self.now = 100.0
+
+    def __call__(self):
+        return self.now
+
+
+class TestTimedSession(unittest.TestCase):
+    """Test collecting and saving a timed session's transcript."""
+
+    def setUp(self):
+        self.tmp = tempfile.TemporaryDirectory()
+        self.clock = FakeClock()
+        self.on_expired = MagicMock()
+        self.session = TimedSession(30, self.on_expired, directory=self.tmp.name, clock=self.clock)
+
+    def tearDown(self):
+        self.session.finish()
+        self.tmp.cleanup()
+
+    def test_transcript_saved_with_summary(self):
+        self.session.start()
+        self.session.add_text(" Hello there. ")
+        self.session.add_text("")
+        self.session.add_text("Second line, with more words")
+        self.clock.now += 90
+
+        path, words, elapsed = self.session.finish()
+
+        self.assertEqual(os.path.dirname(path), self.tmp.name)
+        self.assertTrue(os.path.basename(path).startswith("session-"))
+        with open(path, encoding="utf-8") as f:
+            self.assertEqual(f.read(), "Hello there.\nSecond line, with more words\n")
+        self.assertEqual(words, 7)
+        self.assertEqual(elapsed, 90)
+
+    def test_finish_only_once(self):
+        self.session.start()
+        self.assertIsNotNone(self.session.finish())
+        self.assertIsNone(self.session.finish())
+        self.session.add_text("too late")
+        self.assertFalse(self.session.running)
+
+    def test_timer_calls_on_expired(self):
+        with patch("threading.Timer") as mock_timer:
+            self.session.start()
+        self.assertEqual(mock_timer.call_args.args[0], 30 * 60)
+        mock_timer.call_args.args[1]()
+        self.on_expired.assert_called_once_with()
+
+    def test_length_is_clamped(self):
+        self.assertEqual(TimedSession(0, MagicMock()).minutes, 1)
+        self.assertEqual(TimedSession(10000, MagicMock()).minutes, MAX_SESSION_MINUTES)
+
+    def test_unwritable_directory_reports_failure(self):
+        self.session.start()
+        with patch("builtins.open", side_effect=PermissionError("read-only")):
+            self.assertIsNone(self.session.finish())
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,44 @@
+"""
+Tests for writing and reading the TOML config format.
+"""
+
+import unittest
+
+from vocalinux.utils import toml_format
+
+
+@unittest.skipUnless(toml_format.TOML_AVAILABLE, "no TOML reader installed")
+class TestTomlFormat(unittest.TestCase):
+    """Tests for toml_format.dumps and loads."""
+
+    def test_round_trip(self):
+        data = {
+            "audio": {"device_name": 'USB "Pro" Mic', "sample_rate": 48000, "duck_level": 0.3},
+            "output": {"profiles": {"notes": {"sinks": ["file", "inject"], "enabled": True}}},
+            "vocabulary": {"custom_terms": ["Kubernetes", "Ångström"]},
+        }
+        self.assertEqual(toml_format.loads(toml_format.dumps(data)), data)
+
+    def test_none_values_are_commented_out(self):
+        text = toml_format.dumps({"audio": {"device_index": None, "echo_cancel": False}})
+        self.assertIn("# device_index = (not set)", text)
+        self.assertEqual(toml_format.loads(text), {"audio": {"echo_cancel": False}})
+
+    def test_comments_and_header(self):
+        text = toml_format.dumps(
+            {"general": {"autostart": False}},
+            comments={("general", "autostart"): "Start on login"},
+            header="Vocalinux configuration",
+        )
+        self.assertEqual(
+            text, "# Vocalinux configuration\n\n[general]\nautostart = false  # Start on login\n"
+        )
+
+    def test_quotes_keys_that_are_not_bare(self):
+        text = toml_format.dumps({"output": {"profiles": {"my notes": {"sinks": []}}}})
+        self.assertIn('[output.profiles."my notes"]', text)
+        self.assertIn("my notes", toml_format.loads(text)["output"]["profiles"])
+
+
+if __name__ == "__main__":
+    unittest.main()
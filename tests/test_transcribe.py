@@ -0,0 +1,221 @@
+"""
+Tests for the offline ``vocalinux transcribe`` subcommand.
+"""
+
+import json
+import sys
+import unittest
+from unittest.mock import MagicMock, patch
+
+from vocalinux import transcribe
+from vocalinux.speech_recognition.recognition_manager import SpeechRecognitionManager
+
+
+def _make_manager(engine="whisper_cpp", language="en-us"):
+    """Create a SpeechRecognitionManager with engine initialization patched out."""
+    with patch.object(SpeechRecognitionManager, "_init_vosk"), patch.object(
+        SpeechRecognitionManager, "_init_whisper"
+    ), patch.object(SpeechRecognitionManager, "_init_whispercpp"), patch.object(
+        SpeechRecognitionManager, "_init_remote_api"
+    ):
+        return SpeechRecognitionManager(engine=engine, model_size="small", language=language)
+
+
+class TestTranscribeArguments(unittest.TestCase):
+    """Tests for transcribe argument parsing."""
+
+    def test_defaults_to_text_format(self):
+        args = transcribe.parse_transcribe_arguments(["speech.wav"])
+        self.assertEqual(args.file, "speech.wav")
+        self.assertEqual(args.format, "text")
+        self.assertIsNone(args.engine)
+
+    def test_json_format(self):
+        args = transcribe.parse_transcribe_arguments(["speech.wav", "--format", "json"])
+        self.assertEqual(args.format, "json")
+
+    def test_rejects_unknown_format(self):
+        with patch("sys.stderr"), self.assertRaises(SystemExit):
+            transcribe.parse_transcribe_arguments(["speech.wav", "--format", "xml"])
+
+
+class TestBuildResult(unittest.TestCase):
+    """Tests for the structured result schema."""
+
+    def test_segments_have_full_schema(self):
+        result = transcribe.build_result(
+            "speech.wav",
+            "vosk",
+            "small",
+            "en-us",
+            2.5,
+            [{"start": 0.1234, "end": 1.5, "text": "hello", "confidence": 0.91234}],
+        )
+        segment = result["segments"][0]
+        self.assertEqual(
+            segment,
+            {
+                "id": 0,
+                "start": 0.123,
+                "end": 1.5,
+                "text": "hello",
+                "confidence": 0.912,
+                "language": None,
+                "speaker": None,
+            },
+        )
+        self.assertEqual(result["text"], "hello")
+        self.assertEqual(result["duration"], 2.5)
+
+    def test_joins_segment_text(self):
+        result = transcribe.build_result(
+            "a.wav",
+            "whisper_cpp",
+            "tiny",
+            "auto",
+            3.0,
+            [
+                {"start": 0.0, "end": 1.0, "text": "first", "language": "en"},
+                {"start": 1.0, "end": 2.0, "text": "second", "language": "en"},
+            ],
+        )
+        self.assertEqual(result["text"], "first second")
+        self.assertEqual([s["id"] for s in result["segments"]], [0, 1])
+        self.assertIsNone(result["segments"][0]["confidence"])
+        self.assertEqual(result["segments"][1]["language"], "en")
+
+    def test_format_json_round_trips(self):
+        result = transcribe.build_result("a.wav", "vosk", "small", "en-us", 1.0, [])
+        self.assertEqual(json.loads(transcribe.format_result(result, "json")), result)
+
+    def test_format_text_returns_plain_text(self):
+        result = transcribe.build_result(
+            "a.wav", "vosk", "small", "en-us", 1.0, [{"start": 0, "end": 1, "text": "hi"}]
+        )
+        self.assertEqual(transcribe.format_result(result, "text"), "hi")
+
+
+class TestTranscribeMain(unittest.TestCase):
+    """Tests for the transcribe entry point."""
+
+    def setUp(self):
+        self.config = MagicMock()
+        self.config.get_settings.return_value = {
+            "speech_recognition": {"engine": "vosk", "model_size": "small", "language": "en-us"}
+        }
+
+    def test_prints_json_and_returns_zero(self):
+        manager = MagicMock()
+        manager.transcribe_segments.return_value = [
+            {"start": 0.0, "end": 1.0, "text": "hello world", "confidence": 0.8}
+        ]
+        with patch.object(
+            transcribe, "load_wav_chunks", return_value=([b"\x00\x00"], 1.0)
+        ), patch("vocalinux.ui.config_manager.ConfigManager", return_value=self.config), patch(
+            "vocalinux.speech_recognition.recognition_manager.SpeechRecognitionManager",
+            return_value=manager,
+        ) as manager_cls, patch(
+            "builtins.print"
+        ) as mock_print:
+            exit_code = transcribe.main(["speech.wav", "--format", "json"])
+
+        self.assertEqual(exit_code, 0)
+        self.assertEqual(manager_cls.call_args.kwargs["engine"], "vosk")
+        self.assertFalse(manager_cls.call_args.kwargs["defer_download"])
+        output = json.loads(mock_print.call_args[0][0])
+        self.assertEqual(output["text"], "hello world")
+        self.assertEqual(output["segments"][0]["confidence"], 0.8)
+        self.assertIn("speaker", output["segments"][0])
+
+    def test_cli_engine_overrides_config(self):
+        manager = MagicMock()
+        manager.transcribe_segments.return_value = []
+        with patch.object(
+            transcribe, "load_wav_chunks", return_value=([], 0.0)
+        ), patch("vocalinux.ui.config_manager.ConfigManager", return_value=self.config), patch(
+            "vocalinux.speech_recognition.recognition_manager.SpeechRecognitionManager",
+            return_value=manager,
+        ) as manager_cls, patch(
+            "builtins.print"
+        ):
+            transcribe.main(["speech.wav", "--engine", "whisper_cpp"])
+
+        self.assertEqual(manager_cls.call_args.kwargs["engine"], "whisper_cpp")
+
+    def test_unreadable_file_returns_error(self):
+        with patch.object(
+            transcribe, "load_wav_chunks", side_effect=OSError("missing")
+        ), patch("vocalinux.ui.config_manager.ConfigManager", return_value=self.config):
+            self.assertEqual(transcribe.main(["missing.wav"]), 1)
+
+    def test_main_dispatches_transcribe_subcommand(self):
+        from vocalinux import main as main_module
+
+        with patch.object(sys, "argv", ["vocalinux", "transcribe", "a.wav"]), patch.object(
+            transcribe, "main", return_value=0
+        ) as mock_transcribe, patch("vocalinux.single_instance.acquire_lock") as mock_lock:
+            with self.assertRaises(SystemExit) as ctx:
+                main_module.main()
+
+        self.assertEqual(ctx.exception.code, 0)
+        mock_transcribe.assert_called_once_with(["a.wav"])
+        mock_lock.assert_not_called()
+
+
+class TestTranscribeSegments(unittest.TestCase):
+    """Tests for SpeechRecognitionManager.transcribe_segments."""
+
+    def test_empty_buffer_returns_no_segments(self):
+        manager = _make_manager()
+        self.assertEqual(manager.transcribe_segments([]), [])
+
+    def test_whispercpp_segments_use_timestamps(self):
+        manager = _make_manager(engine="whisper_cpp")
+        manager.model = MagicMock()
+        manager.model.transcribe.return_value = [
+            MagicMock(text=" Hello there", t0=0, t1=150),
+            MagicMock(text="[BLANK_AUDIO]", t0=150, t1=200),
+        ]
+        with patch.dict(sys.modules, {"numpy": MagicMock()}):
+            segments = manager.transcribe_segments([b"\x00\x00" * 16000])
+
+        self.assertEqual(len(segments), 1)
+        self.assertEqual(segments[0]["text"], "Hello there")
+        self.assertEqual(segments[0]["start"], 0.0)
+        self.assertEqual(segments[0]["end"], 1.5)
+        self.assertIsNone(segments[0]["confidence"])
+        self.assertEqual(segments[0]["language"], "en-us")
+
+    def test_vosk_segment_averages_word_confidence(self):
+        manager = _make_manager(engine="vosk")
+        manager.recognizer = MagicMock()
+        manager.recognizer.FinalResult.return_value = json.dumps(
+            {
+                "text": "hello world",
+                "result": [
+                    {"word": "hello", "start": 0.2, "end": 0.5, "conf": 1.0},
+                    {"word": "world", "start": 0.6, "end": 0.9, "conf": 0.5},
+                ],
+            }
+        )
+
+        segments = manager.transcribe_segments([b"\x00\x00" * 1024])
+
+        self.assertEqual(segments[0]["start"], 0.2)
+        self.assertEqual(segments[0]["end"], 0.9)
+        self.assertAlmostEqual(segments[0]["confidence"], 0.75)
+        manager.recognizer.SetWords.assert_any_call(True)
+        manager.recognizer.SetWords.assert_called_with(False)
+
+    def test_auto_language_reported_as_unknown(self):
+        manager = _make_manager(engine="whisper_cpp", language="auto")
+        manager.model = MagicMock()
+        manager.model.transcribe.return_value = [MagicMock(text="Hola", t0=0, t1=50)]
+        with patch.dict(sys.modules, {"numpy": MagicMock()}):
+            segments = manager.transcribe_segments([b"\x00\x00"])
+
+        self.assertIsNone(segments[0]["language"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,67 @@
+"""
+Tests for the recent transcript history.
+"""
+
+import unittest
+
+from vocalinux.utils.transcript_history import TranscriptHistory, menu_label
+
+
+class TestTranscriptHistory(unittest.TestCase):
+    """Tests for TranscriptHistory."""
+
+    def test_newest_first_and_bounded(self):
+        history = TranscriptHistory(max_entries=2)
+        for text in ("one", "two", "three"):
+            history.add(text)
+        self.assertEqual(history.entries(), ["three", "two"])
+
+    def test_ignores_blank_and_repeated_text(self):
+        history = TranscriptHistory()
+        history.add("  ")
+        history.add(None)
+        history.add("hello")
+        history.add("hello ")
+        self.assertEqual(history.entries(), ["hello"])
+
+    def test_resize_keeps_newest(self):
+        history = TranscriptHistory(max_entries=5)
+        for text in ("one", "two", "three"):
+            history.add(text)
+        history.max_entries = 2
+        self.assertEqual(history.entries(), ["three", "two"])
+
+        history.max_entries = 0
+        history.add("four")
+        self.assertEqual(history.entries(), [])
+
+    def test_clear(self):
+        history = TranscriptHistory()
+        history.add("hello")
+        history.clear()
+        self.assertEqual(history.entries(), [])
+
+    def test_items_keep_detected_language(self):
+        history = TranscriptHistory()
+        history.add("hello")
+        history.add("guten Morgen", language="de")
+        self.assertEqual(history.items(), [("guten Morgen", "de"), ("hello", None)])
+
+
+class TestMenuLabel(unittest.TestCase):
+    """Tests for menu_label."""
+
+    def test_short_text_unchanged(self):
+        self.assertEqual(menu_label("Hello world."), "Hello world.")
+
+    def test_joins_lines_and_shortens(self):
+        self.assertEqual(menu_label("first line\nsecond line", max_chars=12), "first line…")
+
+    def test_language_tag(self):
+        self.assertEqual(menu_label("Guten Morgen", language="de"), "[de] Guten Morgen")
+        # Text typed with the tag already in it is not tagged twice
+        self.assertEqual(menu_label("[de] Guten Morgen", language="de"), "[de] Guten Morgen")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -6,6 +6,7 @@ The tests focus on the business logic of the TrayIndicator class.
 """
 
 import os
+import signal
 import sys
 import unittest
 from unittest.mock import MagicMock, PropertyMock, patch
@@ -161,6 +162,75 @@ class TestTrayIndicator(unittest.TestCase):
         self.assertEqual(self.tray_indicator.text_injector, self.mock_text_injector)
         self.mock_speech_engine.register_state_callback.assert_called_once()
 
+    def test_final_text_added_to_recent_transcripts(self):
+        """Test final transcripts are remembered and copied from the tray submenu."""
+        self.mock_speech_engine.register_text_callback.assert_called_once_with(
+            self.tray_indicator._on_final_text
+        )
+        self.tray_indicator.transcript_history.max_entries = 10
+        self.tray_indicator._on_final_text("first")
+        self.tray_indicator._on_final_text("second")
+        self.assertEqual(self.tray_indicator.transcript_history.entries(), ["second", "first"])
+
+        self.tray_indicator._on_history_item_clicked(None, "first")
+        self.mock_thread_class.assert_called_with(
+            target=self.mock_text_injector._copy_to_clipboard, args=("first",), daemon=True
+        )
+
+        self.tray_indicator._on_clear_history_clicked(None)
+        self.assertEqual(self.tray_indicator.transcript_history.entries(), [])
+
+    def test_retry_recent_transcript(self):
+        """Test a retried transcript is copied and failures are reported."""
+        self.tray_indicator._on_history_retry_clicked(None, "i red the book")
+        self.mock_thread_class.assert_called_with(
+            target=self.tray_indicator._retry_transcript, args=("i red the book",), daemon=True
+        )
+
+        self.mock_speech_engine.retry_utterance.return_value = "I read the book"
+        with patch("vocalinux.ui.tray_indicator.notifications") as mock_notifications:
+            self.tray_indicator._retry_transcript("i red the book")
+        self.mock_speech_engine.retry_utterance.assert_called_with("i red the book")
+        self.mock_text_injector._copy_to_clipboard.assert_called_once_with("I read the book")
+        self.assertEqual(mock_notifications.notify.call_args.args[1], "I read the book")
+
+        self.mock_speech_engine.retry_utterance.return_value = None
+        with patch("vocalinux.ui.tray_indicator.notifications") as mock_notifications:
+            self.tray_indicator._retry_transcript("i red the book")
+        self.assertEqual(mock_notifications.notify.call_args.args[0], "Retry Failed")
+        self.mock_text_injector._copy_to_clipboard.assert_called_once()
+
+    def test_timed_session_saves_transcript_when_dictation_stops(self):
+        """Test a timed session starts dictation and reports its saved transcript."""
+        self.mock_config_manager.get_float.return_value = 15.0
+        with patch("vocalinux.ui.tray_indicator.TimedSession") as mock_session_class:
+            self.tray_indicator._on_timed_session_clicked(None)
+        session = mock_session_class.return_value
+        self.assertEqual(mock_session_class.call_args.args[0], 15.0)
+        session.start.assert_called_once()
+        self.mock_speech_engine.start_recognition.assert_called_once()
+
+        self.tray_indicator._on_final_text("hello world")
+        session.add_text.assert_called_once_with("hello world")
+
+        session.finish.return_value = ("/data/sessions/session-1.txt", 2, 65.0)
+        with patch("vocalinux.ui.tray_indicator.notifications") as mock_notifications:
+            # Dictation hasn't started yet, so IDLE doesn't end the session
+            self.tray_indicator._on_recognition_state_changed(self.RecognitionState.IDLE)
+            session.finish.assert_not_called()
+            self.tray_indicator._on_recognition_state_changed(self.RecognitionState.LISTENING)
+            self.tray_indicator._on_recognition_state_changed(self.RecognitionState.IDLE)
+        session.finish.assert_called_once()
+        self.assertIsNone(self.tray_indicator._timed_session)
+        args = mock_notifications.notify_with_action.call_args.args
+        self.assertEqual(args[0], "Timed Session Finished")
+        self.assertEqual(args[1], "2 words in 1:05, saved to session-1.txt")
+
+        with patch("subprocess.Popen") as mock_popen:
+            args[3]()
+        mock_popen.assert_called_once()
+        self.assertEqual(mock_popen.call_args.args[0], ["xdg-open", "/data/sessions/session-1.txt"])
+
     def test_toggle_recognition_from_idle(self):
         """Test toggling recognition state from IDLE."""
         self.mock_speech_engine.state = self.RecognitionState.IDLE
@@ -182,6 +252,66 @@ class TestTrayIndicator(unittest.TestCase):
         self.mock_speech_engine.stop_recognition.assert_called_once()
         self.mock_speech_engine.start_recognition.assert_not_called()
 
+    def test_cancel_utterance_while_listening(self):
+        """Test the hold-to-cancel gesture discards the current utterance."""
+        self.mock_speech_engine.state = self.RecognitionState.LISTENING
+        self.tray_indicator._cancel_utterance()
+        self.mock_speech_engine.cancel_utterance.assert_called_once()
+        self.mock_speech_engine.stop_recognition.assert_not_called()
+
+    def test_cancel_utterance_when_idle(self):
+        """Test the hold-to-cancel gesture does nothing when idle."""
+        self.mock_speech_engine.state = self.RecognitionState.IDLE
+        self.tray_indicator._cancel_utterance()
+        self.mock_speech_engine.cancel_utterance.assert_not_called()
+
+    def test_toggle_mode_registers_cancel_callback(self):
+        """Test toggle mode registers the hold-to-cancel callback."""
+        self.tray_indicator.config_manager.get_str.return_value = "toggle"
+        self.tray_indicator._setup_keyboard_shortcuts()
+        self.mock_ksm.register_cancel_callback.assert_called_with(
+            self.tray_indicator._cancel_utterance
+        )
+
+    def test_panic_shortcut_registers_panic_stop(self):
+        """Test a configured panic combo gets its own listener."""
+        panic_ksm = MagicMock()
+        self.mock_ksm_class.return_value = panic_ksm
+        self.tray_indicator.config_manager.get_str.return_value = "ctrl+alt+m"
+
+        self.tray_indicator._setup_panic_shortcut()
+
+        self.mock_ksm_class.assert_called_with(shortcut="ctrl+alt+m", mode="toggle")
+        panic_ksm.register_toggle_callback.assert_called_once_with(
+            self.tray_indicator._panic_stop
+        )
+        panic_ksm.start.assert_called_once()
+        self.assertIs(self.tray_indicator.panic_shortcut_manager, panic_ksm)
+
+    def test_panic_shortcut_ignores_empty_and_modifier_gestures(self):
+        """Test the panic shortcut is off when unset or set to a bare modifier."""
+        for value in ("", "ctrl", "not a shortcut"):
+            self.mock_ksm_class.reset_mock()
+            self.tray_indicator.config_manager.get_str.return_value = value
+            self.tray_indicator._setup_panic_shortcut()
+            self.mock_ksm_class.assert_not_called()
+            self.assertIsNone(self.tray_indicator.panic_shortcut_manager)
+
+    def test_update_panic_shortcut_replaces_listener(self):
+        """Test changing the panic shortcut stops the old listener."""
+        old_ksm = MagicMock()
+        self.tray_indicator.panic_shortcut_manager = old_ksm
+        self.tray_indicator.config_manager.get_str.return_value = ""
+
+        self.assertTrue(self.tray_indicator.update_panic_shortcut(""))
+        old_ksm.stop.assert_called_once()
+        self.assertIsNone(self.tray_indicator.panic_shortcut_manager)
+
+    def test_panic_stop(self):
+        """Test the panic shortcut calls the engine's panic stop."""
+        self.tray_indicator._panic_stop()
+        self.mock_speech_engine.panic_stop.assert_called_once()
+
     def test_on_start_clicked(self):
         """Test start button click handler."""
         self.mock_speech_engine.start_recognition.reset_mock()
@@ -215,9 +345,18 @@ class TestTrayIndicator(unittest.TestCase):
 
         with patch("vocalinux.ui.tray_indicator.Gtk") as patched_gtk:
             self.tray_indicator._quit()
+            self.mock_speech_engine.shutdown.assert_called_once()
             self.mock_ksm.stop.assert_called_once()
             patched_gtk.main_quit.assert_called_once()
 
+    def test_quit_continues_when_shutdown_fails(self):
+        """A failing recognition shutdown still quits the application."""
+        self.mock_speech_engine.shutdown.side_effect = RuntimeError("audio gone")
+
+        with patch("vocalinux.ui.tray_indicator.Gtk") as patched_gtk:
+            self.tray_indicator._quit()
+            patched_gtk.main_quit.assert_called_once()
+
     def test_signal_handler(self):
         """Test signal handler calls GLib.idle_add with _quit."""
         with patch.object(self.tray_indicator, "_quit") as mock_quit:
@@ -231,9 +370,31 @@ class TestTrayIndicator(unittest.TestCase):
             with patch("vocalinux.ui.tray_indicator.Gtk") as patched_gtk:
                 patched_gtk.main.side_effect = lambda: None
                 self.tray_indicator.run()
-                self.assertEqual(mock_signal.call_count, 2)
+                self.assertEqual(mock_signal.call_count, 4)
+                mock_signal.assert_any_call(signal.SIGHUP, self.tray_indicator._signal_handler)
+                mock_signal.assert_any_call(
+                    signal.SIGUSR1, self.tray_indicator._show_status_signal_handler
+                )
                 patched_gtk.main.assert_called_once()
 
+    def test_show_status_signal_handler(self):
+        """Test a second launch schedules the status window."""
+        with patch("vocalinux.ui.tray_indicator.GLib") as patched_glib:
+            self.tray_indicator._show_status_signal_handler(10, None)
+            patched_glib.idle_add.assert_called_once_with(self.tray_indicator.show_status_window)
+
+    def test_show_status_window_reuses_open_window(self):
+        """Test the status window is created once and presented on each request."""
+        with patch("vocalinux.ui.status_window.StatusWindow") as mock_window_class:
+            self.tray_indicator.show_status_window()
+            self.tray_indicator.show_status_window()
+
+            mock_window_class.assert_called_once()
+            self.assertEqual(mock_window_class.return_value.present.call_count, 2)
+
+            self.tray_indicator._on_status_window_destroyed(mock_window_class.return_value)
+            self.assertIsNone(self.tray_indicator._status_window)
+
     def test_settings_callback(self):
         """Test settings callback."""
         # Import the tray_indicator module to patch SettingsDialog on it directly
@@ -327,6 +488,30 @@ class TestTrayIndicator(unittest.TestCase):
         )
         self.assertEqual(result, False)
 
+    def test_update_ui_sets_performance_tooltip(self):
+        """Test _update_ui shows decoding speed and queue depth in the tooltip."""
+        self.tray_indicator.indicator = MagicMock()
+        self.tray_indicator.menu = MagicMock()
+        self.tray_indicator.menu.get_children.return_value = []
+        self.mock_speech_engine.get_performance_summary.return_value = (
+            "decoding 1.8× slower than realtime, 2 utterances queued"
+        )
+
+        self.tray_indicator._update_ui(self.RecognitionState.PROCESSING)
+
+        self.tray_indicator.indicator.set_title.assert_called_once_with(
+            "Vocalinux: decoding 1.8× slower than realtime, 2 utterances queued"
+        )
+
+    def test_update_tooltip_ignores_summary_errors(self):
+        """Test the tooltip update tolerates engines without performance stats."""
+        self.tray_indicator.indicator = MagicMock()
+        self.mock_speech_engine.get_performance_summary.side_effect = AttributeError
+
+        self.tray_indicator._update_tooltip()
+
+        self.tray_indicator.indicator.set_title.assert_not_called()
+
     def test_update_ui_processing_state(self):
         """Test _update_ui for PROCESSING state."""
         self.tray_indicator.indicator = MagicMock()
@@ -382,6 +567,82 @@ class TestTrayIndicator(unittest.TestCase):
 
             mock_menu_item.set_sensitive.assert_called_with(False)
 
+    def test_shortcut_failure_shows_notification_once(self):
+        """Test a notification with guidance is shown when shortcuts can't start."""
+        self.tray_indicator._shortcut_warning_shown = False
+        self.mock_ksm.start.return_value = False
+        self.mock_ksm.get_unavailable_guidance.return_value = "Install python-evdev"
+
+        with patch("vocalinux.ui.tray_indicator.notifications") as mock_notifications:
+            self.tray_indicator._setup_keyboard_shortcuts()
+            self.tray_indicator._setup_keyboard_shortcuts()
+
+        mock_notifications.notify.assert_called_once()
+        self.assertIn("Install python-evdev", mock_notifications.notify.call_args.args)
+
+    def test_shortcut_success_shows_no_notification(self):
+        """Test no notification when the shortcut listener starts."""
+        self.tray_indicator._shortcut_warning_shown = False
+        self.mock_ksm.start.return_value = True
+
+        with patch("vocalinux.ui.tray_indicator.notifications") as mock_notifications:
+            self.tray_indicator._setup_keyboard_shortcuts()
+
+        mock_notifications.notify.assert_not_called()
+
+    def test_startup_health_notifies_limitations(self):
+        """Test degraded features are summarized in one notification."""
+        from vocalinux.doctor import HealthCheck
+
+        results = [
+            HealthCheck("Text injection", False, "No typing tool found", "Install xdotool"),
+            HealthCheck("Keyring", True, "Python keyring is available"),
+        ]
+
+        with patch("vocalinux.doctor.run_health_checks", return_value=results), patch(
+            "vocalinux.ui.tray_indicator.notifications"
+        ) as mock_notifications, patch("vocalinux.ui.tray_indicator.GLib") as patched_glib:
+            self.tray_indicator._check_startup_health()
+            call = mock_notifications.notify_with_action.call_args
+            title, message, label, on_action, _ = call.args
+            on_action()
+
+        self.assertEqual(title, "Vocalinux is running with limitations")
+        self.assertEqual(label, "Show Report")
+        self.assertIn("Text injection", message)
+        self.assertNotIn("Keyring", message)
+        self.assertIn("vocalinux doctor", call.kwargs["fallback_message"])
+        patched_glib.idle_add.assert_called_once_with(
+            self.tray_indicator._show_health_report, results
+        )
+
+    def test_startup_health_silent_when_healthy(self):
+        """Test no notification when every check passes."""
+        from vocalinux.doctor import HealthCheck
+
+        results = [HealthCheck("Keyring", True, "Python keyring is available")]
+
+        with patch("vocalinux.doctor.run_health_checks", return_value=results), patch(
+            "vocalinux.ui.tray_indicator.notifications"
+        ) as mock_notifications:
+            self.tray_indicator._check_startup_health()
+
+        mock_notifications.notify_with_action.assert_not_called()
+
+    def test_on_commands_clicked(self):
+        """Test Voice Commands menu item opens the cheat sheet."""
+        mock_dialog = MagicMock()
+        mock_module = MagicMock()
+        mock_module.CommandReferenceDialog = MagicMock(return_value=mock_dialog)
+
+        with patch.dict(sys.modules, {"vocalinux.ui.command_reference_dialog": mock_module}):
+            self.tray_indicator._on_commands_clicked(None)
+
+        mock_module.CommandReferenceDialog.assert_called_once_with(
+            parent=None, command_processor=self.tray_indicator.speech_engine.command_processor
+        )
+        mock_dialog.show.assert_called_once()
+
     def test_on_logs_clicked(self):
         """Test View Logs menu item click handler."""
         # The LoggingDialog is imported inside the method, so we need to patch
@@ -399,6 +660,31 @@ class TestTrayIndicator(unittest.TestCase):
             mock_logging_dialog_class.assert_called_once_with(parent=None)
             mock_dialog.show.assert_called_once()
 
+    def test_quick_toggles_save_and_apply(self):
+        """Test the tray checkboxes flip partial results and notifications."""
+        self.mock_config_manager.reset_mock()
+        widget = MagicMock()
+        widget.get_active.return_value = True
+        self.tray_indicator._on_partial_results_toggled(widget)
+        self.mock_config_manager.set.assert_called_with(
+            "speech_recognition", "partial_results", True
+        )
+        self.mock_speech_engine.reconfigure.assert_called_once_with(
+            partial_results=True, force_download=False
+        )
+
+        widget.get_active.return_value = False
+        with patch("vocalinux.ui.tray_indicator.notifications.set_enabled") as mock_set_enabled:
+            self.tray_indicator._on_notifications_toggled(widget)
+        self.mock_config_manager.set.assert_called_with("ui", "show_notifications", False)
+        mock_set_enabled.assert_called_once_with(False)
+        self.assertEqual(self.mock_config_manager.save_settings.call_count, 2)
+
+        # Syncing the checkboxes to the config doesn't save it again
+        self.tray_indicator._syncing_quick_toggles = True
+        self.tray_indicator._on_notifications_toggled(widget)
+        self.assertEqual(self.mock_config_manager.save_settings.call_count, 2)
+
     def test_on_settings_dialog_response_close(self):
         """Test settings dialog response handler for CLOSE."""
         with patch("vocalinux.ui.tray_indicator.Gtk") as patched_gtk:
@@ -498,6 +784,74 @@ class TestTrayIndicator(unittest.TestCase):
                 self.assertEqual(result, False)
                 mock_dialog.assert_called_once()
 
+    def test_init_indicator_missing_watcher_schedules_fallback(self):
+        with patch.object(
+            self.tray_indicator,
+            "_check_status_notifier_watcher",
+            return_value=False,
+        ):
+            with patch.object(self.tray_indicator, "_show_missing_watcher_dialog"):
+                with patch("vocalinux.ui.tray_indicator.GLib") as patched_glib:
+                    self.tray_indicator._init_indicator()
+                    patched_glib.timeout_add_seconds.assert_called_once_with(
+                        5, self.tray_indicator._maybe_start_trayless_fallback
+                    )
+
+    def test_check_status_notifier_host_false_when_unregistered(self):
+        mock_proxy = MagicMock()
+        mock_proxy.get_cached_property.return_value.unpack.return_value = False
+
+        with patch(
+            "vocalinux.ui.tray_indicator.Gio.DBusProxy.new_for_bus_sync",
+            return_value=mock_proxy,
+        ):
+            assert self.tray_indicator._check_status_notifier_host() is False
+
+    def test_maybe_start_trayless_fallback_skipped_when_tray_appears(self):
+        with (
+            patch.object(self.tray_indicator, "_check_status_notifier_watcher", return_value=True),
+            patch.object(self.tray_indicator, "_check_status_notifier_host", return_value=True),
+            patch.object(self.tray_indicator, "_start_trayless_fallback") as mock_fallback,
+        ):
+            self.assertFalse(self.tray_indicator._maybe_start_trayless_fallback())
+            mock_fallback.assert_not_called()
+
+    def test_start_trayless_fallback_shows_status_and_notification(self):
+        with patch.object(self.tray_indicator, "show_status_window") as mock_show:
+            self.tray_indicator._start_trayless_fallback()
+            self.tray_indicator._start_trayless_fallback()
+
+            self.assertEqual(mock_show.call_count, 2)
+            # Only one notification loop runs at a time
+            loop_calls = [
+                c
+                for c in self.mock_thread_class.call_args_list
+                if c.kwargs.get("target") == self.tray_indicator._run_fallback_notification
+            ]
+            self.assertEqual(len(loop_calls), 1)
+
+    def test_fallback_notification_actions_until_dismissed(self):
+        self.tray_indicator._fallback_notifications = True
+        with patch(
+            "vocalinux.ui.tray_indicator.notifications.wait_for_action", side_effect=["toggle", ""]
+        ) as mock_wait:
+            with patch.object(self.tray_indicator, "_toggle_recognition") as mock_toggle:
+                self.tray_indicator._run_fallback_notification()
+
+        self.assertEqual(mock_wait.call_count, 2)
+        self.assertTrue(mock_wait.call_args.kwargs["resident"])
+        mock_toggle.assert_called_once()
+        self.assertFalse(self.tray_indicator._fallback_notifications)
+
+    def test_no_fallback_notification_when_notifications_are_off(self):
+        with patch.object(self.tray_indicator, "show_status_window") as mock_show, patch.object(
+            self.tray_indicator, "_show_notifications", return_value=False
+        ):
+            self.tray_indicator._start_trayless_fallback()
+
+        mock_show.assert_called_once()
+        self.assertFalse(self.tray_indicator._fallback_notifications)
+
     def test_init_indicator_creation_failure_shows_error_dialog(self):
         with patch(
             "vocalinux.ui.tray_indicator.AppIndicator3.Indicator.new_with_path",
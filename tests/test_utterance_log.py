@@ -0,0 +1,82 @@
+"""
+Tests for the per-utterance JSON event log.
+"""
+
+import json
+import os
+import tempfile
+import unittest
+
+from vocalinux.utils.utterance_log import UtteranceLog, text_hash
+
+
+class TestUtteranceLog(unittest.TestCase):
+    """Tests for UtteranceLog."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.path = os.path.join(self.temp_dir.name, "logs", "utterances.jsonl")
+
+    def _lines(self, path=None):
+        with open(path or self.path, encoding="utf-8") as log_file:
+            return [json.loads(line) for line in log_file]
+
+    def test_off_writes_nothing(self):
+        log = UtteranceLog("off", path=self.path)
+        self.assertIsNone(log.record("hello world", "vosk"))
+        self.assertFalse(os.path.exists(self.path))
+
+    def test_hashed_mode_keeps_no_text(self):
+        log = UtteranceLog("hashed", path=self.path)
+        log.record(
+            "hello world",
+            "whisper_cpp",
+            model="tiny",
+            language="en",
+            audio_seconds=2.0,
+            decode_seconds=0.5,
+            confidence=0.91234,
+            timestamp=0,
+        )
+
+        (event,) = self._lines()
+        self.assertNotIn("text", event)
+        self.assertEqual(event["text_sha256"], text_hash("hello world"))
+        self.assertEqual(event["engine"], "whisper_cpp")
+        self.assertEqual(event["model"], "tiny")
+        self.assertEqual(event["rtf"], 0.25)
+        self.assertEqual(event["confidence"], 0.912)
+        self.assertEqual((event["words"], event["chars"]), (2, 11))
+
+    def test_full_mode_keeps_text_one_line_per_utterance(self):
+        log = UtteranceLog("full", path=self.path)
+        log.record("first", "vosk")
+        log.record("", "vosk")
+
+        events = self._lines()
+        self.assertEqual([event["text"] for event in events], ["first", ""])
+        self.assertIsNone(events[1]["rtf"])
+
+    def test_unknown_mode_turns_log_off(self):
+        log = UtteranceLog("verbose", path=self.path)
+        self.assertEqual(log.mode, "off")
+        self.assertFalse(log.enabled)
+
+    def test_rotates_when_full(self):
+        log = UtteranceLog("hashed", path=self.path, max_bytes=10)
+        log.record("one", "vosk")
+        log.record("two", "vosk")
+
+        self.assertEqual(len(self._lines()), 1)
+        self.assertEqual(len(self._lines(self.path + ".1")), 1)
+
+    def test_unwritable_path_is_not_fatal(self):
+        blocker = os.path.join(self.temp_dir.name, "file")
+        open(blocker, "w").close()
+        log = UtteranceLog("full", path=os.path.join(blocker, "utterances.jsonl"))
+        self.assertIsNone(log.record("hello", "vosk"))
+
+
+if __name__ == "__main__":
+    unittest.main()
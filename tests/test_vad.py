@@ -0,0 +1,138 @@
+"""
+Tests for the voice activity detection backends.
+"""
+
+import sys
+import unittest
+from unittest.mock import MagicMock, patch
+
+import numpy as np
+
+from vocalinux.speech_recognition.audio_level import vad_threshold_dbfs
+from vocalinux.speech_recognition.vad import (
+    WEBRTC_FRAME_SAMPLES,
+    EnergyVAD,
+    SileroDetector,
+    WebRtcVAD,
+    create_vad,
+)
+
+
+def _fake_webrtcvad(speech=True):
+    module = MagicMock()
+    module.Vad.return_value.is_speech.return_value = speech
+    return module
+
+
+class TestEnergyVAD(unittest.TestCase):
+    """Tests for the level-based detector."""
+
+    def test_compares_level_with_sensitivity_threshold(self):
+        vad = EnergyVAD()
+        chunk = np.zeros(1024, dtype=np.int16)
+        threshold = vad_threshold_dbfs(3)
+        self.assertTrue(vad.is_speech(chunk, threshold + 1, 3))
+        self.assertFalse(vad.is_speech(chunk, threshold - 1, 3))
+        # A more sensitive setting lowers the threshold
+        self.assertTrue(vad.is_speech(chunk, threshold - 1, 5))
+        self.assertIn("threshold=", vad.describe_decision())
+
+    def test_steady_noise_stops_reading_as_speech(self):
+        vad = EnergyVAD()
+        chunk = np.zeros(1024, dtype=np.int16)
+        fan = vad_threshold_dbfs(3) + 8
+
+        # Until the floor is calibrated, the fan is above the fixed threshold
+        self.assertTrue(vad.is_speech(chunk, fan, 3))
+        for _ in range(20):
+            vad.is_speech(chunk, fan, 3)
+        self.assertEqual(vad.noise_floor_dbfs, fan)
+        self.assertFalse(vad.is_speech(chunk, fan, 3))
+        # Speech over the fan still counts
+        self.assertTrue(vad.is_speech(chunk, fan + 15, 3))
+
+        vad.reset()
+        self.assertIsNone(vad.noise_floor_dbfs)
+
+
+class TestWebRtcVAD(unittest.TestCase):
+    """Tests for the WebRTC detector."""
+
+    def test_mode_follows_sensitivity(self):
+        self.assertEqual(WebRtcVAD.mode_for_sensitivity(1), 3)
+        self.assertEqual(WebRtcVAD.mode_for_sensitivity(3), 2)
+        self.assertEqual(WebRtcVAD.mode_for_sensitivity(5), 0)
+        self.assertEqual(WebRtcVAD.mode_for_sensitivity("bad"), 2)
+
+    def test_frames_carry_over_between_chunks(self):
+        module = _fake_webrtcvad(speech=True)
+        with patch.dict(sys.modules, {"webrtcvad": module}):
+            vad = WebRtcVAD()
+
+        # 300 samples is less than a 30 ms frame: no decision yet
+        self.assertIsNone(vad.is_speech(np.zeros(300, dtype=np.int16), -60.0, 3))
+        # With the carried-over 300, 1024 more make two frames and leave 364
+        self.assertTrue(vad.is_speech(np.zeros(1024, dtype=np.int16), -60.0, 3))
+        calls = module.Vad.return_value.is_speech.call_args_list
+        self.assertEqual(len(calls), 2)
+        self.assertEqual(len(calls[0][0][0]), WEBRTC_FRAME_SAMPLES * 2)
+        self.assertEqual(calls[0][0][1], 16000)
+        module.Vad.return_value.set_mode.assert_called_once_with(2)
+
+    def test_silence(self):
+        with patch.dict(sys.modules, {"webrtcvad": _fake_webrtcvad(speech=False)}):
+            vad = WebRtcVAD()
+        self.assertFalse(vad.is_speech(np.zeros(960, dtype=np.int16), -20.0, 5))
+        self.assertEqual(vad.describe_decision(), "webrtc frames=0/2, mode=0")
+
+
+class TestSileroDetector(unittest.TestCase):
+    """Tests for the Silero detector."""
+
+    def test_threshold_follows_sensitivity(self):
+        self.assertAlmostEqual(SileroDetector.threshold_for_sensitivity(1), 0.8)
+        self.assertAlmostEqual(SileroDetector.threshold_for_sensitivity(5), 0.3)
+        self.assertAlmostEqual(SileroDetector.threshold_for_sensitivity("bad"), 0.55)
+
+    def test_waits_for_full_chunks_and_keeps_highest_probability(self):
+        model = MagicMock()
+        model.process.side_effect = [0.2, 0.9]
+        vad = SileroDetector(model)
+
+        # 341 samples (one 48 kHz read, resampled) is less than a 512-sample chunk
+        self.assertIsNone(vad.is_speech(np.zeros(341, dtype=np.int16), -60.0, 3))
+        # 341 + 700 samples make two chunks, and one of them is speech
+        self.assertTrue(vad.is_speech(np.zeros(700, dtype=np.int16), -60.0, 3))
+        self.assertEqual(model.process.call_count, 2)
+        self.assertEqual(vad.describe_decision(), "silero_prob=0.90, threshold=0.550")
+
+        vad.reset()
+        model.reset.assert_called_once()
+
+
+class TestCreateVad(unittest.TestCase):
+    """Tests for picking a backend from the setting."""
+
+    def test_backends(self):
+        self.assertIsNone(create_vad("auto"))
+        self.assertIsInstance(create_vad("energy"), EnergyVAD)
+        with patch.dict(sys.modules, {"webrtcvad": _fake_webrtcvad()}):
+            self.assertIsInstance(create_vad("webrtc"), WebRtcVAD)
+
+    def test_missing_webrtcvad_falls_back_to_energy(self):
+        with patch.dict(sys.modules, {"webrtcvad": None}):
+            self.assertIsInstance(create_vad("webrtc"), EnergyVAD)
+
+    def test_silero_reuses_a_loaded_model(self):
+        model = MagicMock()
+        vad = create_vad("silero", silero_model=model)
+        self.assertIsInstance(vad, SileroDetector)
+        self.assertIs(vad.model, model)
+
+    def test_missing_silero_falls_back_to_energy(self):
+        with patch("vocalinux.speech_recognition.vad.load_silero_vad", return_value=None):
+            self.assertIsInstance(create_vad("silero"), EnergyVAD)
+
+
+if __name__ == "__main__":
+    unittest.main()
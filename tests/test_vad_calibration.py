@@ -0,0 +1,48 @@
+"""Tests for picking the VAD sensitivity from measured noise and speech."""
+
+import unittest
+
+from vocalinux.speech_recognition.audio_level import adaptive_vad_threshold_dbfs
+from vocalinux.speech_recognition.vad_calibration import calibrate_sensitivity
+
+
+class TestCalibrateSensitivity(unittest.TestCase):
+    """Test calibrate_sensitivity."""
+
+    def test_quiet_room_and_clear_speech(self):
+        silence = [-58.0, -57.0, -56.0, -55.0] * 10
+        # Reading aloud: words with pauses between them
+        speech = [-22.0, -25.0, -28.0, -56.0] * 15
+
+        result = calibrate_sensitivity(silence, speech)
+
+        self.assertTrue(result.ok)
+        self.assertEqual(result.noise_dbfs, -55.0)
+        self.assertEqual(result.speech_dbfs, -25.0)
+        self.assertEqual(result.sensitivity, 3)
+        self.assertGreater(result.threshold_dbfs, result.noise_dbfs)
+        self.assertLess(result.threshold_dbfs, result.speech_dbfs)
+
+    def test_noisy_room_keeps_threshold_above_noise(self):
+        silence = [-40.0, -39.0, -38.0] * 10
+        speech = [-18.0, -20.0, -39.0] * 20
+
+        result = calibrate_sensitivity(silence, speech)
+
+        self.assertTrue(result.ok)
+        threshold = adaptive_vad_threshold_dbfs(result.sensitivity, min(silence))
+        self.assertGreater(threshold, -38.0)
+
+    def test_speech_too_close_to_noise(self):
+        result = calibrate_sensitivity([-40.0, -39.0] * 10, [-37.0, -36.0] * 10)
+
+        self.assertFalse(result.ok)
+        self.assertIsNone(result.sensitivity)
+
+    def test_nothing_recorded(self):
+        self.assertFalse(calibrate_sensitivity([], [-20.0]).ok)
+        self.assertFalse(calibrate_sensitivity([-50.0], []).ok)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,43 @@
+"""
+Tests for domain vocabulary packs.
+"""
+
+import unittest
+
+from vocalinux.utils.vocabulary_packs import (
+    MAX_VOCABULARY_PROMPT_CHARS,
+    VOCABULARY_PACKS,
+    build_vocabulary_prompt,
+    get_vocabulary_terms,
+)
+
+
+class TestVocabularyPacks(unittest.TestCase):
+    """Tests for combining packs and building the Whisper prompt."""
+
+    def test_ships_medical_and_legal_packs(self):
+        self.assertIn("medical", VOCABULARY_PACKS)
+        self.assertIn("legal", VOCABULARY_PACKS)
+        for pack in VOCABULARY_PACKS.values():
+            self.assertTrue(pack.terms)
+
+    def test_custom_terms_come_first_without_duplicates(self):
+        terms = get_vocabulary_terms(["legal", "unknown"], ["Vocalinux", " Plaintiff "])
+        self.assertEqual(terms[:2], ["Vocalinux", "Plaintiff"])
+        self.assertEqual([t.lower() for t in terms].count("plaintiff"), 1)
+        self.assertIn("habeas corpus", terms)
+
+    def test_build_prompt(self):
+        self.assertEqual(build_vocabulary_prompt([], "Meeting notes."), "Meeting notes.")
+        self.assertEqual(
+            build_vocabulary_prompt(["estoppel", "voir dire"]), "Glossary: estoppel, voir dire."
+        )
+        self.assertEqual(
+            build_vocabulary_prompt(["estoppel"], "Court memo."), "Court memo. Glossary: estoppel."
+        )
+
+    def test_build_prompt_is_truncated(self):
+        terms = get_vocabulary_terms(["medical", "legal"])
+        prompt = build_vocabulary_prompt(terms)
+        self.assertLess(len(prompt), MAX_VOCABULARY_PROMPT_CHARS + 20)
+        self.assertTrue(prompt.startswith("Glossary: acetaminophen"))
@@ -0,0 +1,140 @@
+"""
+Tests for voice-controlled settings.
+"""
+
+import threading
+import unittest
+from unittest.mock import MagicMock
+
+from vocalinux.ui.voice_settings import VoiceSettingsHandler
+
+
+class TestVoiceSettingsHandler(unittest.TestCase):
+    """Tests for VoiceSettingsHandler."""
+
+    def setUp(self):
+        self.speech_engine = MagicMock()
+        self.speech_engine.engine = "whisper_cpp"
+        self.speech_engine.model_size = "tiny"
+        self.speech_engine.vad_sensitivity = 3
+        self.speech_engine.silence_timeout = 2.0
+        self.speech_engine.engine_vad = {}
+        self.speech_engine.vad_setting.side_effect = lambda key: self.speech_engine.engine_vad.get(
+            self.speech_engine.engine, {}
+        ).get(key, getattr(self.speech_engine, key))
+        self.speech_engine.overrides_vad_setting.side_effect = (
+            lambda key: key in self.speech_engine.engine_vad.get(self.speech_engine.engine, {})
+        )
+        self.config_manager = MagicMock()
+        self.notify = MagicMock()
+        self.handler = VoiceSettingsHandler(
+            self.speech_engine, self.config_manager, notify=self.notify
+        )
+
+    def test_action_handlers_cover_all_commands(self):
+        handlers = self.handler.get_action_handlers()
+        for action in (
+            "increase_sensitivity",
+            "decrease_sensitivity",
+            "increase_silence_timeout",
+            "decrease_silence_timeout",
+            "switch_model_tiny",
+            "switch_model_large",
+            "enable_translation",
+            "disable_translation",
+        ):
+            self.assertIn(action, handlers)
+
+    def test_increase_sensitivity(self):
+        self.assertTrue(self.handler.adjust_vad_sensitivity(1))
+        self.speech_engine.reconfigure.assert_called_once_with(
+            vad_sensitivity=4, force_download=False
+        )
+        self.config_manager.set_vad_setting.assert_called_once_with(
+            "whisper_cpp", "vad_sensitivity", 4
+        )
+        self.config_manager.save_config.assert_called_once()
+        self.notify.assert_called_once()
+
+    def test_sensitivity_steps_from_engine_override(self):
+        """The engine's engine_vad override is the value changed and reported."""
+        self.speech_engine.engine_vad = {"whisper_cpp": {"vad_sensitivity": 5}}
+        self.assertTrue(self.handler.adjust_vad_sensitivity(-1))
+        self.speech_engine.reconfigure.assert_called_once_with(
+            vad_sensitivity=4, force_download=False
+        )
+        self.config_manager.set_vad_setting.assert_called_once_with(
+            "whisper_cpp", "vad_sensitivity", 4
+        )
+        self.notify.assert_called_once_with(
+            "Sensitivity changed", "VAD sensitivity for whisper_cpp set to 4"
+        )
+
+    def test_sensitivity_at_limit(self):
+        self.speech_engine.vad_sensitivity = 1
+        self.assertFalse(self.handler.adjust_vad_sensitivity(-1))
+        self.speech_engine.reconfigure.assert_not_called()
+        self.config_manager.save_config.assert_not_called()
+
+    def test_decrease_silence_timeout(self):
+        self.assertTrue(self.handler.adjust_silence_timeout(-0.5))
+        self.speech_engine.reconfigure.assert_called_once_with(
+            silence_timeout=1.5, force_download=False
+        )
+        self.config_manager.set_vad_setting.assert_called_once_with(
+            "whisper_cpp", "silence_timeout", 1.5
+        )
+
+    def test_silence_timeout_clamped(self):
+        self.speech_engine.silence_timeout = 4.8
+        self.assertTrue(self.handler.adjust_silence_timeout(0.5))
+        self.speech_engine.reconfigure.assert_called_once_with(
+            silence_timeout=5.0, force_download=False
+        )
+
+    def test_enable_translation(self):
+        self.speech_engine.translate = False
+        self.assertTrue(self.handler.set_translate(True))
+        self.speech_engine.reconfigure.assert_called_once_with(translate=True, force_download=False)
+        self.config_manager.set.assert_called_once_with("speech_recognition", "translate", True)
+
+        self.speech_engine.translate = True
+        self.assertFalse(self.handler.set_translate(True))
+
+    def test_translation_unavailable_for_vosk(self):
+        self.speech_engine.engine = "vosk"
+        self.speech_engine.translate = False
+        self.assertFalse(self.handler.set_translate(True))
+        self.speech_engine.reconfigure.assert_not_called()
+        self.notify.assert_called_once()
+
+    def test_switch_model_runs_in_background(self):
+        done = threading.Event()
+        self.speech_engine.reconfigure.side_effect = lambda **kwargs: done.set()
+
+        self.assertTrue(self.handler.switch_model("base"))
+        self.assertTrue(done.wait(timeout=2))
+
+        self.speech_engine.reconfigure.assert_called_once_with(
+            model_size="base", force_download=False
+        )
+        self.config_manager.set_model_size_for_engine.assert_called_once_with("whisper_cpp", "base")
+        self.config_manager.save_config.assert_called_once()
+
+    def test_switch_model_rejects_invalid_size(self):
+        self.speech_engine.engine = "vosk"
+        self.assertFalse(self.handler.switch_model("tiny"))
+        self.speech_engine.reconfigure.assert_not_called()
+        self.config_manager.save_config.assert_not_called()
+
+    def test_switch_model_remote_api(self):
+        self.speech_engine.engine = "remote_api"
+        self.assertFalse(self.handler.switch_model("small"))
+
+    def test_switch_to_current_model_is_noop(self):
+        self.assertTrue(self.handler.switch_model("tiny"))
+        self.speech_engine.reconfigure.assert_not_called()
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,72 @@
+"""
+Tests for reading whisper.cpp's effective backend from its load log.
+"""
+
+import os
+import unittest
+
+from vocalinux.utils.whispercpp_load_report import capture_native_stderr, parse_load_log
+
+VULKAN_LOG = """whisper_init_with_params_no_state: use gpu    = 1
+ggml_vulkan: Found 1 Vulkan devices:
+ggml_vulkan: 0 = AMD Radeon RX 6600 (RADV NAVI23) (radv) | uma: 0 | fp16: 1 | warp size: 32
+whisper_model_load:      Vulkan0 total size =   147.37 MB
+whisper_model_load: model size    =  147.37 MB
+whisper_backend_init_gpu: using Vulkan0 backend
+"""
+
+CUDA_LOG = """ggml_cuda_init: found 1 CUDA devices:
+  Device 0: NVIDIA GeForce RTX 3060, compute capability 8.6, VMM: yes
+whisper_model_load:    CUDA0 total size =   487.00 MB
+whisper_backend_init_gpu: using CUDA0 backend
+"""
+
+CPU_LOG = """whisper_init_with_params_no_state: use gpu    = 1
+whisper_model_load:      CPU total size =    77.11 MB
+whisper_backend_init_gpu: no GPU found
+"""
+
+
+class TestParseLoadLog(unittest.TestCase):
+    """Tests for parse_load_log."""
+
+    def test_vulkan(self):
+        report = parse_load_log(VULKAN_LOG)
+        self.assertEqual(report.backend, "vulkan")
+        self.assertTrue(report.uses_gpu)
+        self.assertEqual(report.device, "AMD Radeon RX 6600")
+        self.assertEqual(report.summary(), "Vulkan (AMD Radeon RX 6600), 147 of 147 MB on the GPU")
+
+    def test_cuda(self):
+        report = parse_load_log(CUDA_LOG)
+        self.assertEqual(report.backend, "cuda")
+        self.assertEqual(report.device, "NVIDIA GeForce RTX 3060")
+        self.assertEqual(report.gpu_mb, 487.0)
+
+    def test_cpu_even_though_gpu_requested(self):
+        report = parse_load_log(CPU_LOG)
+        self.assertEqual(report.backend, "cpu")
+        self.assertFalse(report.uses_gpu)
+        self.assertEqual(report.summary(), "CPU")
+
+    def test_older_log_without_backend_line_uses_buffers(self):
+        report = parse_load_log("whisper_model_load: Vulkan0 buffer size =   147.37 MB\n")
+        self.assertEqual(report.backend, "vulkan")
+
+    def test_empty_log_is_unknown(self):
+        report = parse_load_log("")
+        self.assertEqual(report.backend, "unknown")
+        self.assertFalse(report.uses_gpu)
+
+
+class TestCaptureNativeStderr(unittest.TestCase):
+    """Tests for capturing file descriptor 2."""
+
+    def test_captures_fd_writes(self):
+        with capture_native_stderr() as captured:
+            os.write(2, b"whisper_backend_init_gpu: no GPU found\n")
+        self.assertIn("no GPU found", captured.text)
+
+
+if __name__ == "__main__":
+    unittest.main()
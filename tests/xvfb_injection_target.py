@@ -0,0 +1,58 @@
+"""
+A GTK window with one text view, typed into by the Xvfb injection tests.
+
+Run by test_injection_xvfb.py in its own process (the test suite replaces gi
+with a mock). Prints "ready" once the window is shown, writes the text
+view's contents to the file given as the first argument whenever they
+change, and clears the text on SIGUSR1.
+"""
+
+import os
+import signal
+import sys
+
+import gi
+
+gi.require_version("Gtk", "3.0")
+from gi.repository import GLib, Gtk  # noqa: E402
+
+WINDOW_TITLE = "Vocalinux injection target"
+
+
+def main():
+    output_path = sys.argv[1]
+
+    window = Gtk.Window(title=WINDOW_TITLE)
+    window.set_default_size(600, 200)
+    text_view = Gtk.TextView()
+    window.add(text_view)
+    text_buffer = text_view.get_buffer()
+
+    def save(buffer):
+        text = buffer.get_text(buffer.get_start_iter(), buffer.get_end_iter(), True)
+        # Written whole and renamed, so the test never reads half a file
+        with open(output_path + ".tmp", "w", encoding="utf-8") as output:
+            output.write(text)
+        os.replace(output_path + ".tmp", output_path)
+
+    def clear():
+        text_buffer.set_text("")
+        return GLib.SOURCE_CONTINUE
+
+    def announce():
+        print("ready", flush=True)
+        return GLib.SOURCE_REMOVE
+
+    text_buffer.connect("changed", save)
+    GLib.unix_signal_add(GLib.PRIORITY_DEFAULT, signal.SIGUSR1, clear)
+    window.connect("destroy", Gtk.main_quit)
+
+    save(text_buffer)
+    window.show_all()
+    text_view.grab_focus()
+    GLib.idle_add(announce)
+    Gtk.main()
+
+
+if __name__ == "__main__":
+    main()